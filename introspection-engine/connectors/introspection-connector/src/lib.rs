@@ -18,7 +18,75 @@ pub trait IntrospectionConnector: Send + Sync + 'static {
 
     async fn get_database_version(&self) -> ConnectorResult<String>;
 
-    async fn introspect(&self, existing_data_model: &Datamodel) -> ConnectorResult<IntrospectionResult>;
+    async fn introspect(
+        &self,
+        existing_data_model: &Datamodel,
+        options: IntrospectionOptions,
+    ) -> ConnectorResult<IntrospectionResult>;
+}
+
+/// The choices left to the user when translating the database schema to a data model.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct IntrospectionOptions {
+    pub relation_field_naming: RelationFieldNaming,
+    /// Whether columns defaulting to a known UUID generating function, e.g. `gen_random_uuid()`,
+    /// get `@default(uuid())` rather than `@default(dbgenerated())`.
+    pub uuid_defaults: bool,
+    /// Whether DateTime columns set to the current timestamp on every update, e.g. with MySQL's
+    /// `ON UPDATE CURRENT_TIMESTAMP`, or named like the `@updatedAt` fields of the existing data
+    /// model, get `@updatedAt`.
+    pub updated_at_heuristics: bool,
+    /// Whether database views get models. They are read-only, and documented as based on a view.
+    pub views: bool,
+    /// Whether fields get the native type of their column, e.g. `@db.VarChar(255)`. Set from the
+    /// `nativeTypes` preview feature of the datasource.
+    pub native_types: bool,
+    /// Whether the join tables of implicit many-to-many relations get models, related to both
+    /// sides, instead of list fields on both sides. Columns added to the join table, e.g. to order
+    /// the related records by, then become fields of that model.
+    pub explicit_many_to_many: bool,
+    pub reserved_model_naming: ReservedModelNaming,
+}
+
+/// How relation fields that would have the same name are told apart, e.g. the fields for the
+/// `authorId` and `editorId` foreign keys of `Post`, both pointing to `User`. Fields kept from the
+/// existing data model during re-introspection are never renamed.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum RelationFieldNaming {
+    /// The relation name, based on the foreign key columns, is appended to the field names:
+    /// `User_Post_authorIdToUser` and `Post_Post_authorIdToUser`.
+    ForeignKeyColumns,
+    /// The foreign key columns are appended to the name of the related model:
+    /// `User_authorId` on `Post`, and `Post_authorId` on `User`.
+    ReferencedModel,
+    /// The related model name, with a numeric suffix from the second field on: `User` and `User2`.
+    Short,
+}
+
+impl Default for RelationFieldNaming {
+    fn default() -> Self {
+        RelationFieldNaming::ForeignKeyColumns
+    }
+}
+
+/// How models for tables named like a reserved word of the Prisma schema, e.g. `StringFilter`, are
+/// renamed. The table name is always kept with `@@map`. If the new name is already taken by another
+/// model, a numeric suffix is appended, from 2 on: `RenamedStringFilter2`, `StringFilter_2`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ReservedModelNaming {
+    /// `Renamed` is prepended to the name: `RenamedStringFilter`.
+    Prefix,
+    /// An underscore is appended to the name, keeping it recognizable: `StringFilter_`.
+    Escape,
+}
+
+impl Default for ReservedModelNaming {
+    fn default() -> Self {
+        ReservedModelNaming::Prefix
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -43,6 +111,8 @@ pub struct IntrospectionResult {
     pub warnings: Vec<Warning>,
     /// version
     pub version: Version,
+    /// names changed during sanitization
+    pub sanitized_names: Vec<SanitizedName>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -60,16 +130,59 @@ pub struct IntrospectionResultOutput {
     pub warnings: Vec<Warning>,
     /// version
     pub version: Version,
+    /// names changed during sanitization
+    #[serde(rename = "sanitizedNames")]
+    pub sanitized_names: Vec<SanitizedName>,
+    /// The migration script creating the introspected schema from an empty database, when it was
+    /// requested.
+    #[serde(rename = "baselineMigration", skip_serializing_if = "Option::is_none")]
+    pub baseline_migration: Option<String>,
+    /// The unified diff from the schema given to the introspection to the introspected one, when it
+    /// was requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+}
+
+/// A name from the database that had to be changed to be valid in the datamodel. The original
+/// name is always kept as the database name (`@map`, `@@map`) of the renamed item.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SanitizedName {
+    pub kind: SanitizedNameKind,
+    /// The model or enum the renamed field or enum value belongs to, after sanitization.
+    pub parent: Option<String>,
+    pub original: String,
+    pub sanitized: String,
+    pub reason: SanitizationReason,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum SanitizedNameKind {
+    Model,
+    Field,
+    Enum,
+    EnumValue,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum SanitizationReason {
+    /// The name starts with a character other than a letter or contains characters outside of `[_a-zA-Z0-9]`.
+    InvalidCharacters,
+    /// The name is reserved in the Prisma schema.
+    ReservedName,
+    /// Empty enum values are replaced with a placeholder.
+    EmptyEnumValue,
 }
 
 impl fmt::Display for IntrospectionResultOutput {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{{\"datamodel\": \"{}\", \"warnings\": {}, \"version\": \"{}\"}}",
+            "{{\"datamodel\": \"{}\", \"warnings\": {}, \"version\": \"{}\", \"sanitizedNames\": {}}}",
             self.datamodel,
             serde_json::to_string(&self.warnings).unwrap(),
             serde_json::to_string(&self.version).unwrap(),
+            serde_json::to_string(&self.sanitized_names).unwrap(),
         )
     }
 }
@@ -1,13 +1,15 @@
 use crate::commenting_out_guardrails::commenting_out_guardrails;
 use crate::introspection::introspect;
 use crate::misc_helpers::*;
+use crate::missing_privileges::annotate_missing_privileges;
 use crate::prisma_1_defaults::*;
 use crate::re_introspection::enrich;
-use crate::sanitize_datamodel_names::sanitize_datamodel_names;
+use crate::sanitize_datamodel_names::{sanitize_datamodel_names, warn_about_reserved_model_names};
 use crate::version_checker::VersionChecker;
+use crate::views::{annotate_views, introspect_views};
 use crate::SqlIntrospectionResult;
 use datamodel::Datamodel;
-use introspection_connector::IntrospectionResult;
+use introspection_connector::{IntrospectionOptions, IntrospectionResult};
 use quaint::connector::SqlFamily;
 use sql_schema_describer::*;
 use tracing::debug;
@@ -17,6 +19,7 @@ pub fn calculate_datamodel(
     schema: &SqlSchema,
     family: &SqlFamily,
     previous_data_model: &Datamodel,
+    options: IntrospectionOptions,
 ) -> SqlIntrospectionResult<IntrospectionResult> {
     debug!("Calculating data model.");
 
@@ -24,18 +27,44 @@ pub fn calculate_datamodel(
     let mut data_model = Datamodel::new();
 
     // 1to1 translation of the sql schema
-    introspect(schema, &mut version_check, &mut data_model)?;
+    introspect(schema, &mut version_check, &mut data_model, &options)?;
+
+    // generated columns are computed by the database, they can not be written to
+    mark_generated_columns_read_only(schema, &mut data_model);
+
+    // read-only models for views, if asked for
+    if options.views {
+        introspect_views(schema, &mut data_model);
+    }
+
+    // generated UUIDs as Prisma level defaults, if asked for
+    if options.uuid_defaults {
+        replace_uuid_generating_defaults(schema, &mut data_model);
+    }
+
+    // native type attributes with the lengths and precisions of the columns, if asked for
+    if options.native_types {
+        add_native_types(schema, family, &mut data_model);
+    }
+
+    // `@updatedAt` from automatically updated timestamp columns and naming conventions, if asked for
+    if options.updated_at_heuristics {
+        add_updated_at_heuristics(schema, previous_data_model, &mut data_model);
+    }
 
     // our opinionation about valid names
-    sanitize_datamodel_names(&mut data_model, family);
+    let sanitized_names = sanitize_datamodel_names(&mut data_model, family, options.reserved_model_naming);
 
     // deduplicating relation field names
-    deduplicate_relation_field_names(&mut data_model);
+    deduplicate_relation_field_names(&mut data_model, options.relation_field_naming);
 
     let mut warnings = vec![];
     warnings.append(&mut enrich(previous_data_model, &mut data_model));
     tracing::debug!("Enriching datamodel is done: {:?}", data_model);
 
+    // the models renamed because of reserved names, after re-introspection could restore previous names
+    warnings.append(&mut warn_about_reserved_model_names(&sanitized_names, &data_model));
+
     // commenting out models, fields, enums, enum values
     warnings.append(&mut commenting_out_guardrails(&mut data_model));
 
@@ -45,11 +74,20 @@ pub fn calculate_datamodel(
     // if based on a previous Prisma version add id default opinionations
     add_prisma_1_id_defaults(family, &version, &mut data_model, schema, &mut warnings);
 
+    // what the introspecting user can not read or write, this does not tell anything about the version
+    warnings.append(&mut annotate_missing_privileges(schema, &mut data_model));
+
+    // marking the models of views as such
+    if options.views {
+        warnings.append(&mut annotate_views(schema, &mut data_model));
+    }
+
     // renderer -> parser -> validator, is_commented_out gets lost between renderer and parser
     debug!("Done calculating data model {:?}", data_model);
     Ok(IntrospectionResult {
         data_model,
         version,
         warnings,
+        sanitized_names,
     })
 }
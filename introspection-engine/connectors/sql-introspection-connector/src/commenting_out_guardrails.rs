@@ -64,13 +64,16 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel) -> Vec<Warning> {
         }
     }
 
-    // use unsupported types to drop @@id / @@unique /@@index
+    // use unsupported types to drop @@id / @@unique /@@index / @@distributedBy
     for mf in &unsupported_types {
         let model = datamodel.find_model_mut(&mf.model);
         model.indices.retain(|i| !i.fields.contains(&mf.field));
         if model.id_fields.contains(&mf.field) {
             model.id_fields = vec![]
         };
+        if model.distribution_field.as_ref() == Some(&mf.field) {
+            model.distribution_field = None
+        };
     }
 
     // models without uniques / ids
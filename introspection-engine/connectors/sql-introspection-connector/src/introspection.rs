@@ -1,11 +1,12 @@
 use crate::misc_helpers::{
     calculate_backrelation_field, calculate_index, calculate_many_to_many_field, calculate_relation_field,
-    calculate_scalar_field, is_migration_table, is_prisma_1_point_0_join_table, is_prisma_1_point_1_or_2_join_table,
-    is_relay_table,
+    calculate_scalar_field, is_field_level_unique, is_migration_table, is_nonclustered_primary_key,
+    is_prisma_1_point_0_join_table, is_prisma_1_point_1_or_2_join_table, is_relay_table,
 };
 use crate::version_checker::VersionChecker;
 use crate::SqlError;
 use datamodel::{dml, walkers::find_model_by_db_name, Datamodel, Field, FieldType, Model, RelationField};
+use introspection_connector::IntrospectionOptions;
 use sql_schema_describer::{SqlSchema, Table};
 use tracing::debug;
 
@@ -13,20 +14,28 @@ pub fn introspect(
     schema: &SqlSchema,
     version_check: &mut VersionChecker,
     data_model: &mut Datamodel,
+    options: &IntrospectionOptions,
 ) -> Result<(), SqlError> {
     for table in schema
         .tables
         .iter()
         .filter(|table| !is_migration_table(&table))
-        .filter(|table| !is_prisma_1_point_1_or_2_join_table(&table))
-        .filter(|table| !is_prisma_1_point_0_join_table(&table))
+        .filter(|table| options.explicit_many_to_many || !is_prisma_join_table(&table))
         .filter(|table| !is_relay_table(&table))
+        // The tables of other databases that foreign keys reference become models mapped to their qualified name.
+        .chain(schema.external_tables.iter())
     {
         debug!("Calculating model: {}", table.name);
         let mut model = Model::new(table.name.clone(), None);
 
+        // The version checker already looks for join tables, they must not count as regular tables too. Neither
+        // do the tables of other databases.
+        let check_version = !is_prisma_join_table(table) && schema.has_table(&table.name);
+
         for column in &table.columns {
-            version_check.check_column_for_type_and_default_value(&column);
+            if check_version {
+                version_check.check_column_for_type_and_default_value(&column);
+            }
             let field = calculate_scalar_field(&table, &column);
             model.add_field(Field::ScalarField(field));
         }
@@ -43,26 +52,44 @@ pub fn introspect(
                 )
             })
         }) {
-            version_check.has_inline_relations(table);
-            version_check.uses_on_delete(foreign_key, table);
+            if check_version {
+                version_check.has_inline_relations(table);
+                version_check.uses_on_delete(foreign_key, table);
+            }
             let relation_field = calculate_relation_field(schema, table, foreign_key)?;
             model.add_field(Field::RelationField(relation_field));
         }
 
-        for index in table
-            .indices
-            .iter()
-            .filter(|i| !(i.columns.len() == 1 && i.is_unique()))
-        {
+        for index in table.indices.iter().filter(|i| !is_field_level_unique(i)) {
             model.add_index(calculate_index(index));
         }
 
-        if table.primary_key_columns().len() > 1 {
+        if table.primary_key_columns().len() > 1 || is_nonclustered_primary_key(table) {
             model.id_fields = table.primary_key_columns();
         }
 
-        version_check.always_has_created_at_updated_at(table, &model);
-        version_check.has_p1_compatible_primary_key_column(table);
+        if is_nonclustered_primary_key(table) {
+            model.id_clustered = Some(false);
+        }
+
+        // Without `@@distributedBy`, migrating the introspected schema would undistribute the table.
+        if let Some(distributed_table) = schema.get_distributed_table(&table.name) {
+            model.distribution_field = Some(distributed_table.distribution_column.clone());
+        }
+
+        // Without `@@temporal`, migrating the introspected schema would drop the system versioning of the table.
+        if let Some(temporal_table) = schema.get_temporal_table(&table.name) {
+            model.is_temporal = true;
+
+            if temporal_table.history_table != format!("{}History", table.name) {
+                model.history_table_name = Some(temporal_table.history_table.clone());
+            }
+        }
+
+        if check_version {
+            version_check.always_has_created_at_updated_at(table, &model);
+            version_check.has_p1_compatible_primary_key_column(table);
+        }
 
         data_model.add_model(model);
     }
@@ -90,11 +117,11 @@ pub fn introspect(
         }
     }
 
-    // add prisma many to many relation fields
+    // add prisma many to many relation fields, unless the join tables got models
     for table in schema
         .tables
         .iter()
-        .filter(|table| is_prisma_1_point_1_or_2_join_table(&table) || is_prisma_1_point_0_join_table(&table))
+        .filter(|table| !options.explicit_many_to_many && is_prisma_join_table(&table))
     {
         calculate_fields_for_prisma_join_table(&table, &mut fields_to_be_added, data_model)
     }
@@ -106,6 +133,10 @@ pub fn introspect(
     Ok(())
 }
 
+fn is_prisma_join_table(table: &Table) -> bool {
+    is_prisma_1_point_1_or_2_join_table(table) || is_prisma_1_point_0_join_table(table)
+}
+
 fn calculate_fields_for_prisma_join_table(
     join_table: &Table,
     fields_to_be_added: &mut Vec<(String, RelationField)>,
@@ -3,17 +3,20 @@ mod commenting_out_guardrails;
 mod error;
 mod introspection;
 mod misc_helpers;
+mod missing_privileges;
 mod prisma_1_defaults;
 mod re_introspection;
 mod sanitize_datamodel_names;
 mod schema_describer_loading;
 mod version_checker;
+mod views;
 mod warnings;
 
 use datamodel::Datamodel;
 pub use error::*;
 use introspection_connector::{
-    ConnectorError, ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionResult,
+    ConnectorError, ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionOptions,
+    IntrospectionResult,
 };
 use quaint::prelude::ConnectionInfo;
 use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend};
@@ -101,14 +104,20 @@ impl IntrospectionConnector for SqlIntrospectionConnector {
         Ok(description)
     }
 
-    async fn introspect(&self, previous_data_model: &Datamodel) -> ConnectorResult<IntrospectionResult> {
+    async fn introspect(
+        &self,
+        previous_data_model: &Datamodel,
+        options: IntrospectionOptions,
+    ) -> ConnectorResult<IntrospectionResult> {
         let sql_schema = self.catch(self.describe()).await?;
         tracing::debug!("SQL Schema Describer is done: {:?}", sql_schema);
 
         let family = self.connection_info.sql_family();
 
-        let introspection_result = calculate_datamodel::calculate_datamodel(&sql_schema, &family, &previous_data_model)
-            .map_err(|sql_introspection_error| sql_introspection_error.into_connector_error(&self.connection_info))?;
+        let introspection_result =
+            calculate_datamodel::calculate_datamodel(&sql_schema, &family, &previous_data_model, options).map_err(
+                |sql_introspection_error| sql_introspection_error.into_connector_error(&self.connection_info),
+            )?;
 
         tracing::debug!("Calculating datamodel is done: {:?}", introspection_result.data_model);
 
@@ -1,11 +1,18 @@
 use crate::SqlError;
 use datamodel::{
-    common::RelationNames, Datamodel, DefaultValue as DMLDef, FieldArity, FieldType, IndexDefinition, Model,
-    OnDeleteStrategy, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator as VG,
+    common::RelationNames, Datamodel, DefaultValue as DMLDef, Field, FieldArity, FieldType, IndexDefinition, Model,
+    OnDeleteStrategy, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator as VG, WithDatabaseName,
 };
+use datamodel_connector::Connector;
+use introspection_connector::RelationFieldNaming;
+use native_types::{MySqlType, NativeType, PostgresType};
+use quaint::connector::SqlFamily;
+use sql_datamodel_connector::SqlDatamodelConnectors;
 use sql_schema_describer::{
-    Column, ColumnArity, ColumnTypeFamily, DefaultValue as SQLDef, ForeignKey, Index, IndexType, SqlSchema, Table,
+    Column, ColumnArity, ColumnTypeFamily, DefaultValue as SQLDef, ForeignKey, ForeignKeyAction, Index, IndexType,
+    SqlSchema, Table,
 };
+use std::convert::TryInto;
 use tracing::debug;
 
 //checks
@@ -116,19 +123,38 @@ pub(crate) fn calculate_index(index: &Index) -> IndexDefinition {
         name: Some(index.name.clone()),
         fields: index.columns.clone(),
         tpe,
+        // Indexes are nonclustered by default.
+        clustered: index.clustered.filter(|clustered| *clustered),
+        nulls_not_distinct: index.nulls_not_distinct,
+        predicate: index.partial_predicate().map(String::from),
     }
 }
 
+/// Single column unique indexes are expressed with `@unique`, unless they consider NULLs equal:
+/// that can only be expressed with `@@unique`.
+pub(crate) fn is_field_level_unique(index: &Index) -> bool {
+    index.is_unique() && !index.is_partial() && index.columns.len() == 1 && !index.nulls_not_distinct
+}
+
 pub(crate) fn calculate_scalar_field(table: &Table, column: &Column) -> ScalarField {
     debug!("Handling column {:?}", column);
     let field_type = calculate_scalar_field_type(&column);
-    let (is_commented_out, documentation) = match field_type {
-        FieldType::Unsupported(_) => (true, Some("This type is currently not supported.".to_string())),
+    let (is_commented_out, documentation) = match (&field_type, &column.default) {
+        (FieldType::Unsupported(_), _) => (true, Some("This type is currently not supported.".to_string())),
+        // Identity columns are introspected as `autoincrement()`, the comment keeps how they are generated.
+        (_, Some(SQLDef::IDENTITY(generation))) => (false, Some(generation.to_string())),
         _ => (false, None),
     };
 
     let arity = match column.tpe.arity {
-        _ if column.auto_increment && field_type == FieldType::Base(ScalarType::Int, None) => FieldArity::Required,
+        _ if column.auto_increment
+            && matches!(
+                field_type,
+                FieldType::Base(ScalarType::Int, None) | FieldType::Base(ScalarType::BigInt, None)
+            ) =>
+        {
+            FieldArity::Required
+        }
         ColumnArity::Required => FieldArity::Required,
         ColumnArity::Nullable => FieldArity::Optional,
         ColumnArity::List => FieldArity::List,
@@ -136,7 +162,11 @@ pub(crate) fn calculate_scalar_field(table: &Table, column: &Column) -> ScalarFi
 
     let is_id = is_id(&column, &table);
     let default_value = calculate_default(table, &column, &arity);
-    let is_unique = table.is_column_unique(&column.name) && !is_id;
+    let is_unique = table
+        .indices
+        .iter()
+        .any(|index| is_field_level_unique(index) && index.columns[0] == column.name)
+        && !is_id;
 
     ScalarField {
         name: column.name.clone(),
@@ -149,7 +179,9 @@ pub(crate) fn calculate_scalar_field(table: &Table, column: &Column) -> ScalarFi
         documentation,
         is_generated: false,
         is_updated_at: false,
+        is_read_only: false,
         is_commented_out,
+        deprecation: None,
     }
 }
 
@@ -160,14 +192,6 @@ pub(crate) fn calculate_relation_field(
 ) -> Result<RelationField, SqlError> {
     debug!("Handling foreign key  {:?}", foreign_key);
 
-    let relation_info = RelationInfo {
-        name: calculate_relation_name(schema, foreign_key, table)?,
-        fields: foreign_key.columns.clone(),
-        to: foreign_key.referenced_table.clone(),
-        to_fields: foreign_key.referenced_columns.clone(),
-        on_delete: OnDeleteStrategy::None,
-    };
-
     let columns: Vec<&Column> = foreign_key
         .columns
         .iter()
@@ -179,9 +203,31 @@ pub(crate) fn calculate_relation_field(
         false => FieldArity::Required,
     };
 
+    let relation_info = RelationInfo {
+        name: calculate_relation_name(schema, foreign_key, table)?,
+        fields: foreign_key.columns.clone(),
+        to: foreign_key.referenced_table.clone(),
+        to_fields: foreign_key.referenced_columns.clone(),
+        on_delete: calculate_on_delete(&foreign_key.on_delete_action, arity),
+    };
+
     Ok(RelationField::new(&foreign_key.referenced_table, arity, relation_info))
 }
 
+/// The action is left out when it is the one migrate creates without `onDelete`, `CASCADE` for required
+/// relations and `SET_NULL` for optional ones, or the one of foreign keys defined without an action.
+fn calculate_on_delete(action: &ForeignKeyAction, arity: FieldArity) -> OnDeleteStrategy {
+    match (action, arity) {
+        (ForeignKeyAction::NoAction, _) => OnDeleteStrategy::None,
+        (ForeignKeyAction::Cascade, FieldArity::Required) => OnDeleteStrategy::None,
+        (ForeignKeyAction::SetNull, FieldArity::Optional) => OnDeleteStrategy::None,
+        (ForeignKeyAction::Restrict, _) => OnDeleteStrategy::Restrict,
+        (ForeignKeyAction::Cascade, _) => OnDeleteStrategy::Cascade,
+        (ForeignKeyAction::SetNull, _) => OnDeleteStrategy::SetNull,
+        (ForeignKeyAction::SetDefault, _) => OnDeleteStrategy::SetDefault,
+    }
+}
+
 pub(crate) fn calculate_backrelation_field(
     schema: &SqlSchema,
     model: &Model,
@@ -207,10 +253,13 @@ pub(crate) fn calculate_backrelation_field(
                     let column_name = &relation_info.fields.first().unwrap();
                     table.is_column_unique(column_name)
                 }
-                _ => table
-                    .indices
-                    .iter()
-                    .any(|i| columns_match(&i.columns, &relation_info.fields) && i.tpe == IndexType::Unique),
+                // A compound foreign key is unique as soon as a unique index covers some of its columns,
+                // be it an index on exactly these columns or separate uniques on a subset of them.
+                _ => table.indices.iter().any(|i| {
+                    i.tpe == IndexType::Unique
+                        && !i.is_partial()
+                        && columns_are_covered(&i.columns, &relation_info.fields)
+                }),
             };
 
             let arity = match relation_field.arity {
@@ -234,9 +283,16 @@ pub(crate) fn calculate_backrelation_field(
 pub(crate) fn calculate_default(table: &Table, column: &Column, arity: &FieldArity) -> Option<DMLDef> {
     match (&column.default, &column.tpe.family) {
         (_, _) if *arity == FieldArity::List => None,
-        (_, ColumnTypeFamily::Int) if column.auto_increment => Some(DMLDef::Expression(VG::new_autoincrement())),
-        (_, ColumnTypeFamily::Int) if is_sequence(column, table) => Some(DMLDef::Expression(VG::new_autoincrement())),
-        (Some(SQLDef::SEQUENCE(_)), _) => Some(DMLDef::Expression(VG::new_autoincrement())),
+        (_, ColumnTypeFamily::Int) | (_, ColumnTypeFamily::BigInt) if column.auto_increment => {
+            Some(DMLDef::Expression(VG::new_autoincrement()))
+        }
+        (_, ColumnTypeFamily::Int) | (_, ColumnTypeFamily::BigInt) if is_sequence(column, table) => {
+            Some(DMLDef::Expression(VG::new_autoincrement()))
+        }
+        (Some(SQLDef::SEQUENCE(_)), _) | (Some(SQLDef::IDENTITY(_)), _) => {
+            Some(DMLDef::Expression(VG::new_autoincrement()))
+        }
+        (Some(SQLDef::NEXTVAL(sequence_name)), _) => Some(DMLDef::Expression(VG::new_sequence(sequence_name))),
         (Some(SQLDef::NOW), ColumnTypeFamily::DateTime) => Some(DMLDef::Expression(VG::new_now())),
         (Some(SQLDef::DBGENERATED(_)), _) => Some(DMLDef::Expression(VG::new_dbgenerated())),
         (Some(SQLDef::VALUE(val)), _) => Some(DMLDef::Single(val.clone())),
@@ -244,11 +300,249 @@ pub(crate) fn calculate_default(table: &Table, column: &Column, arity: &FieldAri
     }
 }
 
+/// Columns defaulting to a known UUID generating function get `@default(uuid())`. The migration
+/// engine renders it back to a database function, so the default survives round-trips.
+pub(crate) fn replace_uuid_generating_defaults(schema: &SqlSchema, datamodel: &mut Datamodel) {
+    for table in &schema.tables {
+        let model = match datamodel.models_mut().find(|model| model.name == table.name) {
+            Some(model) => model,
+            None => continue,
+        };
+
+        for column in table.columns.iter().filter(|column| {
+            column
+                .default
+                .as_ref()
+                .map(|default| default.is_uuid_generation())
+                .unwrap_or(false)
+        }) {
+            let field = model.scalar_fields_mut().find(|field| {
+                field.name == column.name
+                    && matches!(
+                        field.field_type,
+                        FieldType::Base(ScalarType::String, _) | FieldType::NativeType(ScalarType::String, _)
+                    )
+            });
+
+            if let Some(field) = field {
+                field.default_value = Some(DMLDef::Expression(VG::new_uuid()));
+            }
+        }
+    }
+}
+
+/// DateTime columns the database sets to the current timestamp on every update, and the ones named
+/// like the `@updatedAt` fields of the previous data model, get `@updatedAt`.
+pub(crate) fn add_updated_at_heuristics(schema: &SqlSchema, previous_datamodel: &Datamodel, datamodel: &mut Datamodel) {
+    let previous_column_names: Vec<&str> = previous_datamodel
+        .models()
+        .flat_map(|model| model.scalar_fields())
+        .filter(|field| field.is_updated_at)
+        .map(|field| field.final_database_name())
+        .collect();
+
+    for table in &schema.tables {
+        let model = match datamodel.models_mut().find(|model| model.name == table.name) {
+            Some(model) => model,
+            None => continue,
+        };
+
+        for field in model.scalar_fields_mut().filter(|field| {
+            matches!(
+                field.field_type,
+                FieldType::Base(ScalarType::DateTime, _) | FieldType::NativeType(ScalarType::DateTime, _)
+            )
+        }) {
+            if schema.is_on_update_timestamp(&table.name, &field.name)
+                || previous_column_names.contains(&field.name.as_str())
+            {
+                field.is_updated_at = true;
+            }
+        }
+    }
+}
+
+/// Fields get the native type of their column, e.g. `@db.VarChar(255)`, so that lengths and
+/// precisions survive introspection. This is the reverse of `column_type_for_native_type` in the
+/// migration engine. Native types belonging to another Prisma type than the field, e.g. `Decimal`
+/// for the `Float` fields of `decimal` columns, are left out.
+pub(crate) fn add_native_types(schema: &SqlSchema, family: &SqlFamily, datamodel: &mut Datamodel) {
+    let connector: Box<dyn Connector> = match family {
+        SqlFamily::Postgres => Box::new(SqlDatamodelConnectors::postgres()),
+        SqlFamily::Mysql => Box::new(SqlDatamodelConnectors::mysql()),
+        _ => return,
+    };
+
+    for table in &schema.tables {
+        let model = match datamodel.models_mut().find(|model| model.name == table.name) {
+            Some(model) => model,
+            None => continue,
+        };
+
+        for column in &table.columns {
+            let field = match model.scalar_fields_mut().find(|field| field.name == column.name) {
+                Some(field) => field,
+                None => continue,
+            };
+
+            let scalar_type = match field.field_type {
+                FieldType::Base(scalar_type, _) => scalar_type,
+                _ => continue,
+            };
+
+            let native_type: Option<Box<dyn NativeType>> = match family {
+                SqlFamily::Postgres => postgres_native_type(column).map(|tpe| Box::new(tpe) as Box<dyn NativeType>),
+                SqlFamily::Mysql => mysql_native_type(column).map(|tpe| Box::new(tpe) as Box<dyn NativeType>),
+                _ => None,
+            };
+
+            let native_type = match native_type.and_then(|tpe| connector.introspect_native_type(tpe).ok()) {
+                Some(native_type) => native_type,
+                None => continue,
+            };
+
+            let compatible = connector
+                .find_native_type_constructor(&native_type.name)
+                .map(|constructor| constructor.prisma_type == scalar_type)
+                .unwrap_or(false);
+
+            if compatible {
+                field.field_type = FieldType::NativeType(scalar_type, native_type);
+            }
+        }
+    }
+}
+
+fn postgres_native_type(column: &Column) -> Option<PostgresType> {
+    let length = || {
+        column
+            .tpe
+            .character_maximum_length
+            .and_then(|length| length.try_into().ok())
+    };
+
+    let native_type = match column.tpe.full_data_type.as_str() {
+        "int2" => PostgresType::SmallInt,
+        "int4" => PostgresType::Integer,
+        "int8" => PostgresType::BigInt,
+        "float4" => PostgresType::Real,
+        "float8" => PostgresType::DoublePrecision,
+        "varchar" => PostgresType::VarChar(length()?),
+        "bpchar" => PostgresType::Char(length()?),
+        "text" => PostgresType::Text,
+        "bytea" => PostgresType::ByteA,
+        "date" => PostgresType::Date,
+        "bool" => PostgresType::Boolean,
+        "bit" => PostgresType::Bit(length()?),
+        "varbit" => PostgresType::VarBit(length()?),
+        "uuid" => PostgresType::UUID,
+        "json" => PostgresType::JSON,
+        "jsonb" => PostgresType::JSONB,
+        _ => return None,
+    };
+
+    Some(native_type)
+}
+
+fn mysql_native_type(column: &Column) -> Option<MySqlType> {
+    let full_data_type = column.tpe.full_data_type.as_str();
+    let unsigned = full_data_type.ends_with(" unsigned");
+    let length = || {
+        column
+            .tpe
+            .character_maximum_length
+            .and_then(|length| length.try_into().ok())
+    };
+    let args = type_arguments(full_data_type);
+    let precision = args.first().copied();
+
+    let native_type = match (column.tpe.data_type.as_str(), unsigned) {
+        ("int", false) => MySqlType::Int,
+        ("int", true) => MySqlType::UnsignedInt,
+        ("smallint", false) => MySqlType::SmallInt,
+        ("smallint", true) => MySqlType::UnsignedSmallInt,
+        ("tinyint", false) => MySqlType::TinyInt,
+        ("tinyint", true) => MySqlType::UnsignedTinyInt,
+        ("mediumint", false) => MySqlType::MediumInt,
+        ("mediumint", true) => MySqlType::UnsignedMediumInt,
+        ("bigint", false) => MySqlType::BigInt,
+        ("bigint", true) => MySqlType::UnsignedBigInt,
+        ("decimal", _) => MySqlType::Decimal(*args.get(0)? as u8, *args.get(1)? as u8),
+        ("numeric", _) => MySqlType::Numeric(*args.get(0)? as u8, *args.get(1)? as u8),
+        ("float", _) => MySqlType::Float,
+        ("double", _) => MySqlType::Double,
+        ("char", _) => MySqlType::Char(length()?),
+        ("varchar", _) => MySqlType::VarChar(length()?),
+        ("tinytext", _) => MySqlType::TinyText,
+        ("text", _) => MySqlType::Text,
+        ("mediumtext", _) => MySqlType::MediumText,
+        ("longtext", _) => MySqlType::LongText,
+        ("date", _) => MySqlType::Date,
+        // The precision of `Time` is not optional in the Prisma schema.
+        ("time", _) => MySqlType::Time(Some(precision.unwrap_or(0))),
+        ("datetime", _) => MySqlType::DateTime(precision),
+        ("timestamp", _) => MySqlType::Timestamp(precision),
+        ("year", _) => MySqlType::Year,
+        ("json", _) => MySqlType::JSON,
+        // `SET` columns are strings, the native type preserves their allowed values.
+        ("set", _) => MySqlType::Set(sql_schema_describer::mysql::extract_set_values(full_data_type)?),
+        _ => return None,
+    };
+
+    Some(native_type)
+}
+
+/// The numeric arguments of a full data type, e.g. `[10, 2]` for `decimal(10,2)`.
+fn type_arguments(full_data_type: &str) -> Vec<u32> {
+    let start = match full_data_type.find('(') {
+        Some(start) => start + 1,
+        None => return Vec::new(),
+    };
+
+    let end = match full_data_type[start..].find(')') {
+        Some(end) => start + end,
+        None => return Vec::new(),
+    };
+
+    full_data_type[start..end]
+        .split(',')
+        .filter_map(|arg| arg.trim().parse().ok())
+        .collect()
+}
+
+pub(crate) fn mark_generated_columns_read_only(schema: &SqlSchema, datamodel: &mut Datamodel) {
+    for generated_column in &schema.generated_columns {
+        let model = match datamodel
+            .models_mut()
+            .find(|model| model.name == generated_column.table)
+        {
+            Some(model) => model,
+            None => continue,
+        };
+
+        if let Some(field) = model
+            .scalar_fields_mut()
+            .find(|field| field.name == generated_column.column)
+        {
+            field.is_read_only = true;
+        }
+    }
+}
+
 pub(crate) fn is_id(column: &Column, table: &Table) -> bool {
     table
         .primary_key
         .as_ref()
-        .map(|pk| pk.is_single_primary_key(&column.name))
+        .map(|pk| pk.is_single_primary_key(&column.name) && !is_nonclustered_primary_key(table))
+        .unwrap_or(false)
+}
+
+/// Nonclustered primary keys can only be expressed with `@@id`, even on a single column.
+pub(crate) fn is_nonclustered_primary_key(table: &Table) -> bool {
+    table
+        .primary_key
+        .as_ref()
+        .map(|pk| pk.clustered == Some(false))
         .unwrap_or(false)
 }
 
@@ -302,42 +596,142 @@ pub(crate) fn calculate_scalar_field_type(column: &Column) -> FieldType {
         ColumnTypeFamily::DateTime => FieldType::Base(ScalarType::DateTime, None),
         ColumnTypeFamily::Float => FieldType::Base(ScalarType::Float, None),
         ColumnTypeFamily::Int => FieldType::Base(ScalarType::Int, None),
+        ColumnTypeFamily::BigInt => FieldType::Base(ScalarType::BigInt, None),
+        ColumnTypeFamily::String if sql_schema_describer::postgres::is_range_type(range_type_name(column)) => {
+            calculate_range_field_type(column)
+        }
         ColumnTypeFamily::String => FieldType::Base(ScalarType::String, None),
         ColumnTypeFamily::Enum(name) => FieldType::Enum(name.clone()),
         ColumnTypeFamily::Uuid => FieldType::Base(ScalarType::String, None),
         ColumnTypeFamily::Json => FieldType::Base(ScalarType::Json, None),
+        ColumnTypeFamily::Binary => FieldType::Base(ScalarType::Bytes, None),
+        ColumnTypeFamily::Xml => calculate_xml_field_type(),
+        ColumnTypeFamily::TextSearch => calculate_text_search_field_type(column),
         x => FieldType::Unsupported(x.to_string()),
     }
 }
 
+/// Postgres range columns are represented as strings in their text form, e.g. `[1,10)`, with the range
+/// type preserved in the native type.
+fn calculate_range_field_type(column: &Column) -> FieldType {
+    let range_type = match range_type_name(column) {
+        "int4range" => PostgresType::Int4Range,
+        "int8range" => PostgresType::Int8Range,
+        "numrange" => PostgresType::NumRange,
+        "tsrange" => PostgresType::TsRange,
+        "tstzrange" => PostgresType::TsTzRange,
+        "daterange" => PostgresType::DateRange,
+        _ => return FieldType::Base(ScalarType::String, None),
+    };
+
+    match SqlDatamodelConnectors::postgres().introspect_native_type(Box::new(range_type)) {
+        Ok(native_type) => FieldType::NativeType(ScalarType::String, native_type),
+        Err(_) => FieldType::Base(ScalarType::String, None),
+    }
+}
+
+/// Postgres XML columns are represented as strings, with the `Xml` native type.
+fn calculate_xml_field_type() -> FieldType {
+    match SqlDatamodelConnectors::postgres().introspect_native_type(Box::new(PostgresType::XML)) {
+        Ok(native_type) => FieldType::NativeType(ScalarType::String, native_type),
+        Err(_) => FieldType::Base(ScalarType::String, None),
+    }
+}
+
+/// Postgres text search columns are represented as strings, with the `TsVector` or `TsQuery` native type.
+fn calculate_text_search_field_type(column: &Column) -> FieldType {
+    let text_search_type = match column.tpe.full_data_type.trim_start_matches('_') {
+        "tsvector" => PostgresType::TsVector,
+        "tsquery" => PostgresType::TsQuery,
+        _ => return FieldType::Base(ScalarType::String, None),
+    };
+
+    match SqlDatamodelConnectors::postgres().introspect_native_type(Box::new(text_search_type)) {
+        Ok(native_type) => FieldType::NativeType(ScalarType::String, native_type),
+        Err(_) => FieldType::Base(ScalarType::String, None),
+    }
+}
+
+/// The element type name of range columns and range array columns.
+fn range_type_name(column: &Column) -> &str {
+    column.tpe.full_data_type.trim_start_matches('_')
+}
+
 // misc
 
-pub fn deduplicate_relation_field_names(datamodel: &mut Datamodel) {
-    let mut duplicated_relation_fields = vec![];
+pub fn deduplicate_relation_field_names(datamodel: &mut Datamodel, naming: RelationFieldNaming) {
+    let mut renamed_relation_fields = vec![];
 
     for model in datamodel.models() {
-        for field in model.relation_fields() {
-            if model.fields().filter(|f| field.name == f.name()).count() > 1 {
-                duplicated_relation_fields.push((
-                    model.name.clone(),
-                    field.name.clone(),
-                    field.relation_info.name.clone(),
-                ));
+        // The names of the fields in the model, including the ones given in this loop.
+        let mut taken_names: Vec<String> = model.fields().map(|f| f.name().to_owned()).collect();
+        // With short names, the first relation field of each name keeps it.
+        let mut kept_names: Vec<&str> = vec![];
+
+        for (idx, field) in model.relation_fields().enumerate() {
+            let same_name_fields: Vec<&Field> = model.fields().filter(|f| field.name == f.name()).collect();
+
+            if same_name_fields.len() < 2 {
+                continue;
             }
+
+            let new_name = match naming {
+                RelationFieldNaming::ForeignKeyColumns => format!("{}_{}", field.name, &field.relation_info.name),
+                RelationFieldNaming::ReferencedModel => {
+                    format!("{}_{}", field.name, relation_columns(datamodel, model, field).join("_"))
+                }
+                RelationFieldNaming::Short => {
+                    let name_is_free =
+                        same_name_fields.iter().all(|f| f.is_relation()) && !kept_names.contains(&field.name.as_str());
+
+                    if name_is_free {
+                        kept_names.push(&field.name);
+                        continue;
+                    }
+
+                    let suffix = (2..)
+                        .find(|suffix| !taken_names.contains(&format!("{}{}", field.name, suffix)))
+                        .unwrap();
+
+                    format!("{}{}", field.name, suffix)
+                }
+            };
+
+            taken_names.push(new_name.clone());
+            renamed_relation_fields.push((model.name.clone(), idx, new_name));
         }
     }
 
-    duplicated_relation_fields
-        .iter()
-        .for_each(|(model, field, relation_name)| {
-            let mut field = datamodel.find_model_mut(model).find_relation_field_mut(field);
-            //todo self vs normal relation?
-            field.name = format!("{}_{}", field.name, &relation_name);
-        });
+    for (model, idx, new_name) in renamed_relation_fields {
+        if let Some(field) = datamodel.find_model_mut(&model).relation_fields_mut().nth(idx) {
+            field.name = new_name;
+        }
+    }
 }
+
+/// The foreign key columns of a relation, from either side. Many to many relations have none, their
+/// relation name is used instead.
+fn relation_columns(datamodel: &Datamodel, model: &Model, field: &RelationField) -> Vec<String> {
+    if !field.relation_info.fields.is_empty() {
+        return field.relation_info.fields.clone();
+    }
+
+    datamodel
+        .find_model(&field.relation_info.to)
+        .and_then(|other_model| {
+            other_model.relation_fields().find(|other_field| {
+                other_field.relation_info.name == field.relation_info.name
+                    && other_field.relation_info.to == model.name
+                    && !other_field.relation_info.fields.is_empty()
+            })
+        })
+        .map(|other_field| other_field.relation_info.fields.clone())
+        .unwrap_or_else(|| vec![field.relation_info.name.clone()])
+}
+
 /// Returns whether the elements of the two slices match, regardless of ordering.
-pub fn columns_match(a_cols: &[String], b_cols: &[String]) -> bool {
-    a_cols.len() == b_cols.len() && a_cols.iter().all(|a_col| b_cols.iter().any(|b_col| a_col == b_col))
+fn columns_are_covered(index_cols: &[String], fk_cols: &[String]) -> bool {
+    !index_cols.is_empty() && index_cols.iter().all(|index_col| fk_cols.contains(index_col))
 }
 
 pub fn replace_field_names(target: &mut Vec<String>, old_name: &str, new_name: &str) {
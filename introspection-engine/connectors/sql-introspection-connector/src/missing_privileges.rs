@@ -0,0 +1,115 @@
+use crate::warnings::{
+    warning_fields_with_missing_privileges, warning_models_with_missing_privileges, ModelAndFieldAndPrivileges,
+    ModelAndPrivileges,
+};
+use datamodel::{Datamodel, Model};
+use introspection_connector::Warning;
+use sql_schema_describer::{MissingPrivilege, SqlSchema};
+
+/// Tables and columns the introspecting user can not read or write are documented with the
+/// privileges the user is missing on them. The ones the user can not see at all are only part of
+/// the warnings, under their database names.
+pub fn annotate_missing_privileges(schema: &SqlSchema, datamodel: &mut Datamodel) -> Vec<Warning> {
+    let mut models_with_missing_privileges = vec![];
+    let mut fields_with_missing_privileges = vec![];
+
+    let mut table_names: Vec<&str> = vec![];
+
+    for missing in &schema.missing_privileges {
+        if !table_names.contains(&missing.table.as_str()) {
+            table_names.push(&missing.table);
+        }
+    }
+
+    for table_name in table_names {
+        let missing: Vec<&MissingPrivilege> = schema.missing_privileges_on(table_name).collect();
+        let mut model = datamodel.models_mut().find(|model| database_name(model) == table_name);
+        let model_name = model
+            .as_ref()
+            .map(|model| model.name.clone())
+            .unwrap_or_else(|| table_name.to_owned());
+
+        let table_privileges = privileges(missing.iter().filter(|missing| missing.column.is_none()));
+
+        if !table_privileges.is_empty() {
+            if let Some(model) = model.as_mut() {
+                append_documentation(&mut model.documentation, &table_privileges, "table");
+            }
+
+            models_with_missing_privileges.push(ModelAndPrivileges {
+                model: model_name.clone(),
+                privileges: table_privileges,
+            });
+        }
+
+        let mut column_names: Vec<&str> = vec![];
+
+        for column_name in missing.iter().filter_map(|missing| missing.column.as_deref()) {
+            if !column_names.contains(&column_name) {
+                column_names.push(column_name);
+            }
+        }
+
+        for column_name in column_names {
+            let column_privileges = privileges(
+                missing
+                    .iter()
+                    .filter(|missing| missing.column.as_deref() == Some(column_name)),
+            );
+
+            let field = model.as_mut().and_then(|model| {
+                model
+                    .scalar_fields_mut()
+                    .find(|field| field.database_name.as_deref().unwrap_or(&field.name) == column_name)
+            });
+
+            let field_name = match field {
+                Some(field) => {
+                    append_documentation(&mut field.documentation, &column_privileges, "column");
+                    field.name.clone()
+                }
+                None => column_name.to_owned(),
+            };
+
+            fields_with_missing_privileges.push(ModelAndFieldAndPrivileges {
+                model: model_name.clone(),
+                field: field_name,
+                privileges: column_privileges,
+            });
+        }
+    }
+
+    let mut warnings = vec![];
+
+    if !models_with_missing_privileges.is_empty() {
+        warnings.push(warning_models_with_missing_privileges(&models_with_missing_privileges))
+    }
+
+    if !fields_with_missing_privileges.is_empty() {
+        warnings.push(warning_fields_with_missing_privileges(&fields_with_missing_privileges))
+    }
+
+    warnings
+}
+
+fn database_name(model: &Model) -> &str {
+    model.database_name.as_deref().unwrap_or(&model.name)
+}
+
+fn privileges<'a>(missing: impl Iterator<Item = &'a &'a MissingPrivilege>) -> Vec<String> {
+    missing.map(|missing| missing.privilege.to_string()).collect()
+}
+
+fn append_documentation(documentation: &mut Option<String>, privileges: &[String], object: &str) {
+    let note = format!(
+        "The database user used for introspection is missing the {} privilege{} on this {}.",
+        privileges.join(", "),
+        if privileges.len() > 1 { "s" } else { "" },
+        object
+    );
+
+    *documentation = Some(match documentation.take() {
+        Some(existing) => format!("{}\n{}", existing, note),
+        None => note,
+    });
+}
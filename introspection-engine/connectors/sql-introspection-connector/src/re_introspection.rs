@@ -69,11 +69,14 @@ pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel) -> Vec
             };
         }
 
-        // change usages in @@id, @@index, @@unique and on RelationInfo.fields
+        // change usages in @@id, @@index, @@unique, @@distributedBy and on RelationInfo.fields
         for changed_field_name in &changed_scalar_field_names {
             let model = new_data_model.find_model_mut(&changed_field_name.0.model);
 
             replace_field_names(&mut model.id_fields, &changed_field_name.0.field, &changed_field_name.1);
+            if model.distribution_field.as_ref() == Some(&changed_field_name.0.field) {
+                model.distribution_field = Some(changed_field_name.1.clone());
+            }
             for index in &mut model.indices {
                 replace_field_names(&mut index.fields, &changed_field_name.0.field, &changed_field_name.1);
             }
@@ -256,7 +259,11 @@ pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel) -> Vec
                 if let Some(old_model) = old_data_model.find_model(&model.name) {
                     if let Some(old_field) = old_model.find_scalar_field(&field.name) {
                         if field.default_value.is_none()
-                            && field.field_type == FieldType::Base(ScalarType::String, None)
+                            && matches!(
+                                field.field_type,
+                                FieldType::Base(ScalarType::String, None)
+                                    | FieldType::NativeType(ScalarType::String, _)
+                            )
                         {
                             if old_field.default_value == Some(DefaultValue::Expression(ValueGenerator::new_cuid())) {
                                 re_introspected_prisma_level_cuids.push(ModelAndField::new(&model.name, &field.name));
@@ -267,7 +274,12 @@ pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel) -> Vec
                             }
                         }
 
-                        if field.field_type == FieldType::Base(ScalarType::DateTime, None) && old_field.is_updated_at {
+                        if matches!(
+                            field.field_type,
+                            FieldType::Base(ScalarType::DateTime, None)
+                                | FieldType::NativeType(ScalarType::DateTime, _)
+                        ) && old_field.is_updated_at
+                        {
                             re_introspected_updated_at.push(ModelAndField::new(&model.name, &field.name));
                         }
                     }
@@ -1,12 +1,14 @@
+use crate::warnings::{warning_models_with_reserved_names, ModelAndTable};
 use datamodel::{
     transform::ast_to_dml::reserved_model_names, Datamodel, DefaultValue, Field, FieldType, Model, WithDatabaseName,
     WithName,
 };
+use introspection_connector::{ReservedModelNaming, SanitizationReason, SanitizedName, SanitizedNameKind, Warning};
 use once_cell::sync::Lazy;
 use prisma_value::PrismaValue;
 use quaint::prelude::SqlFamily;
 use regex::Regex;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 static EMPTY_ENUM_PLACEHOLDER: &'static str = "EMPTY_ENUM_VALUE";
 static EMPTY_STRING: &'static str = "";
@@ -14,33 +16,95 @@ static EMPTY_STRING: &'static str = "";
 static RE_START: Lazy<Regex> = Lazy::new(|| Regex::new("^[^a-zA-Z]+").unwrap());
 static RE: Lazy<Regex> = Lazy::new(|| Regex::new("[^_a-zA-Z0-9]").unwrap());
 
-pub fn sanitize_datamodel_names(datamodel: &mut Datamodel, family: &SqlFamily) {
-    let enum_renames = sanitize_models(datamodel, family);
-    sanitize_enums(datamodel, &enum_renames);
+/// Makes all names in the datamodel valid and returns every rename that happened. The original
+/// name of a renamed item is always kept as its database name.
+pub fn sanitize_datamodel_names(
+    datamodel: &mut Datamodel,
+    family: &SqlFamily,
+    reserved_model_naming: ReservedModelNaming,
+) -> Vec<SanitizedName> {
+    let mut sanitized_names = Vec::new();
+
+    let reserved_renames = reserved_model_renames(datamodel, reserved_model_naming);
+    let enum_renames = sanitize_models(datamodel, family, &reserved_renames, &mut sanitized_names);
+    sanitize_enums(datamodel, &enum_renames, &mut sanitized_names);
+
+    sanitized_names
+}
+
+/// Warns about the models renamed because of their reserved names, unless re-introspection gave them
+/// back a name from the previous data model.
+pub fn warn_about_reserved_model_names(sanitized_names: &[SanitizedName], datamodel: &Datamodel) -> Vec<Warning> {
+    let affected: Vec<ModelAndTable> = sanitized_names
+        .iter()
+        .filter(|name| name.kind == SanitizedNameKind::Model && name.reason == SanitizationReason::ReservedName)
+        .filter(|name| datamodel.find_model(&name.sanitized).is_some())
+        .map(|name| ModelAndTable::new(&name.sanitized, &name.original))
+        .collect();
+
+    if affected.is_empty() {
+        vec![]
+    } else {
+        vec![warning_models_with_reserved_names(&affected)]
+    }
 }
 
 // Todo: Sanitizing might need to be adjusted to also change the fields in the RelationInfo
-fn sanitize_models(datamodel: &mut Datamodel, family: &SqlFamily) -> HashMap<String, (String, Option<String>)> {
+fn sanitize_models(
+    datamodel: &mut Datamodel,
+    family: &SqlFamily,
+    reserved_renames: &HashMap<String, String>,
+    sanitized_names: &mut Vec<SanitizedName>,
+) -> HashMap<String, (String, Option<String>)> {
     let mut enum_renames = HashMap::new();
 
     for model in datamodel.models_mut() {
-        rename_reserved(model);
-        sanitize_name(model);
+        if let Some(original) = rename_reserved(model, reserved_renames) {
+            sanitized_names.push(SanitizedName {
+                kind: SanitizedNameKind::Model,
+                parent: None,
+                original,
+                sanitized: model.name().to_owned(),
+                reason: SanitizationReason::ReservedName,
+            });
+        }
+
+        if let Some(original) = sanitize_name(model) {
+            sanitized_names.push(SanitizedName {
+                kind: SanitizedNameKind::Model,
+                parent: None,
+                original,
+                sanitized: model.name().to_owned(),
+                reason: SanitizationReason::InvalidCharacters,
+            });
+        }
 
         let model_name = model.name().to_owned();
         let model_db_name = model.database_name().map(|s| s.to_owned());
 
         model.id_fields = sanitize_strings(model.id_fields.as_slice());
+        model.distribution_field = model.distribution_field.as_deref().map(sanitize_string);
 
         for field in model.fields_mut() {
-            sanitize_name(field);
+            let original = sanitize_name(field);
+
+            // Relation fields have no database name, so their renames are not reported.
+            if let (Some(original), Field::ScalarField(sf)) = (original, &*field) {
+                sanitized_names.push(SanitizedName {
+                    kind: SanitizedNameKind::Field,
+                    parent: Some(model_name.clone()),
+                    original,
+                    sanitized: sf.name.clone(),
+                    reason: SanitizationReason::InvalidCharacters,
+                });
+            }
 
             match field {
                 Field::RelationField(rf) => {
                     let info = &mut rf.relation_info;
 
                     info.name = sanitize_string(&info.name);
-                    info.to = sanitize_string(&reformat_reserved_string(&info.to));
+                    info.to = sanitize_string(reserved_renames.get(&info.to).unwrap_or(&info.to));
 
                     info.to_fields = sanitize_strings(&info.to_fields);
                     info.fields = sanitize_strings(&info.fields);
@@ -102,24 +166,55 @@ fn sanitize_models(datamodel: &mut Datamodel, family: &SqlFamily) -> HashMap<Str
     enum_renames
 }
 
-fn sanitize_enums(datamodel: &mut Datamodel, enum_renames: &HashMap<String, (String, Option<String>)>) {
+fn sanitize_enums(
+    datamodel: &mut Datamodel,
+    enum_renames: &HashMap<String, (String, Option<String>)>,
+    sanitized_names: &mut Vec<SanitizedName>,
+) {
     for enm in datamodel.enums_mut() {
-        if let Some((sanitized_name, db_name)) = enum_renames.get(&enm.name) {
+        let original = if let Some((sanitized_name, db_name)) = enum_renames.get(&enm.name) {
             if let None = enm.database_name() {
                 enm.set_database_name(db_name.clone());
             }
 
+            let original = enm.name.clone();
             enm.set_name(sanitized_name);
+
+            Some(original).filter(|original| original != sanitized_name)
         } else {
-            sanitize_name(enm);
+            sanitize_name(enm)
+        };
+
+        if let Some(original) = original {
+            sanitized_names.push(SanitizedName {
+                kind: SanitizedNameKind::Enum,
+                parent: None,
+                original,
+                sanitized: enm.name.clone(),
+                reason: SanitizationReason::InvalidCharacters,
+            });
         }
 
+        let enum_name = enm.name.clone();
+
         for enum_value in enm.values_mut() {
-            if &enum_value.name == EMPTY_STRING {
+            let (original, reason) = if &enum_value.name == EMPTY_STRING {
                 enum_value.name = EMPTY_ENUM_PLACEHOLDER.to_string();
                 enum_value.database_name = Some(EMPTY_STRING.to_string());
+
+                (Some(EMPTY_STRING.to_string()), SanitizationReason::EmptyEnumValue)
             } else {
-                sanitize_name(enum_value);
+                (sanitize_name(enum_value), SanitizationReason::InvalidCharacters)
+            };
+
+            if let Some(original) = original {
+                sanitized_names.push(SanitizedName {
+                    kind: SanitizedNameKind::EnumValue,
+                    parent: Some(enum_name.clone()),
+                    original,
+                    sanitized: enum_value.name.clone(),
+                    reason,
+                });
             }
         }
     }
@@ -134,7 +229,8 @@ fn sanitize_strings(strings: &[String]) -> Vec<String> {
 // - Numbers only -> spell out first digit? 100 -> one00
 // - Only invalid characters?
 // - Underscore at start
-fn sanitize_name<T>(renameable: &mut T)
+/// Sanitizes the name and returns the original name if it had to be changed.
+fn sanitize_name<T>(renameable: &mut T) -> Option<String>
 where
     T: WithDatabaseName + WithName,
 {
@@ -149,7 +245,11 @@ where
         }
 
         renameable.set_name(&sanitized);
-    };
+
+        Some(name)
+    } else {
+        None
+    }
 }
 
 fn sanitize_string(s: &str) -> String {
@@ -165,36 +265,52 @@ fn sanitize_string(s: &str) -> String {
     }
 }
 
-fn rename_reserved(model: &mut Model) {
-    let name = reformat_reserved_string(model.name());
-
-    if &name != model.name() {
-        let comment = format!(
-            "This model has been renamed to '{}' during introspection, because the original name '{}' is reserved.",
-            name, model.name,
-        );
-
-        match model.documentation {
-            Some(ref docs) => model.documentation = Some(format!("{}\n{}", docs, comment)),
-            None => model.documentation = Some(comment.to_owned()),
-        }
-
-        // Only set @@map if there's no @@map already set.
-        if let None = model.database_name {
-            model.database_name = Some(model.name.clone());
+/// Picks the new names of the models with reserved names, keyed by their original names.
+fn reserved_model_renames(datamodel: &Datamodel, naming: ReservedModelNaming) -> HashMap<String, String> {
+    let validator = reserved_model_names::TypeNameValidator::new();
+    let mut taken: HashSet<String> = datamodel.models().map(|model| model.name.clone()).collect();
+    let mut renames = HashMap::new();
+
+    for model in datamodel.models().filter(|model| validator.is_reserved(&model.name)) {
+        let renamed = match naming {
+            ReservedModelNaming::Prefix => format!("Renamed{}", model.name),
+            ReservedModelNaming::Escape => format!("{}_", model.name),
+        };
+
+        // Tables are introspected in a stable order, so the suffixes are the same on every run.
+        let mut name = renamed.clone();
+        let mut suffix = 2;
+
+        while taken.contains(&name) || validator.is_reserved(&name) {
+            name = format!("{}{}", renamed, suffix);
+            suffix += 1;
         }
 
-        model.name = name;
+        taken.insert(name.clone());
+        renames.insert(model.name.clone(), name);
     }
+
+    renames
 }
 
-/// Reformats a reserved string as "Renamed{}"
-fn reformat_reserved_string(s: &str) -> String {
-    let validator = reserved_model_names::TypeNameValidator::new();
+/// Renames a model with a reserved name and returns the original name if it had to be changed.
+fn rename_reserved(model: &mut Model, reserved_renames: &HashMap<String, String>) -> Option<String> {
+    let name = reserved_renames.get(&model.name)?.clone();
 
-    if validator.is_reserved(s) {
-        format!("Renamed{}", s)
-    } else {
-        s.to_owned()
+    let comment = format!(
+        "This model has been renamed to '{}' during introspection, because the original name '{}' is reserved.",
+        name, model.name,
+    );
+
+    match model.documentation {
+        Some(ref docs) => model.documentation = Some(format!("{}\n{}", docs, comment)),
+        None => model.documentation = Some(comment.to_owned()),
     }
+
+    // Only set @@map if there's no @@map already set.
+    if let None = model.database_name {
+        model.database_name = Some(model.name.clone());
+    }
+
+    Some(std::mem::replace(&mut model.name, name))
 }
@@ -101,7 +101,7 @@ impl VersionChecker {
             _ => (),
         };
 
-        if !column.auto_increment && column.default.is_some() {
+        if !column.auto_increment && matches!(&column.default, Some(default) if !default.is_null()) {
             self.uses_default_values = true;
         };
     }
@@ -197,7 +197,9 @@ impl VersionChecker {
             SqlFamily::Postgres if self.is_prisma_1(warnings) => Version::Prisma1,
             SqlFamily::Postgres if self.is_prisma_1_1(warnings) => Version::Prisma11,
             SqlFamily::Postgres => Version::NonPrisma,
-            SqlFamily::Mssql => todo!("Greetings from Redmond"),
+            // Prisma 1 never supported SQL Server.
+            SqlFamily::Mssql if self.is_prisma_2(warnings) => Version::Prisma2,
+            SqlFamily::Mssql => Version::NonPrisma,
         }
     }
 }
@@ -0,0 +1,63 @@
+use crate::misc_helpers::calculate_scalar_field;
+use crate::warnings::{warning_models_for_views, Model};
+use datamodel::{walkers::find_model_by_db_name, Datamodel, Field};
+use introspection_connector::Warning;
+use sql_schema_describer::{SqlSchema, Table};
+use tracing::debug;
+
+/// Views get a model with a field per column. Views have no primary key, nor indexes, so their
+/// models are commented out like the ones of tables without a unique identifier, until the user
+/// gives them one.
+pub fn introspect_views(schema: &SqlSchema, datamodel: &mut Datamodel) {
+    for view in &schema.views {
+        debug!("Calculating model for view: {}", view.name);
+
+        // The fields are calculated like the ones of a table without constraints.
+        let table = Table {
+            name: view.name.clone(),
+            columns: view.columns.clone(),
+            indices: vec![],
+            primary_key: None,
+            foreign_keys: vec![],
+        };
+
+        let mut model = datamodel::Model::new(view.name.clone(), None);
+
+        for column in &table.columns {
+            model.add_field(Field::ScalarField(calculate_scalar_field(&table, column)));
+        }
+
+        datamodel.add_model(model);
+    }
+}
+
+/// The models of views are documented as read-only, once their names and comments are final.
+pub fn annotate_views(schema: &SqlSchema, datamodel: &mut Datamodel) -> Vec<Warning> {
+    let mut models_for_views = vec![];
+
+    for view in &schema.views {
+        let model_name = match find_model_by_db_name(datamodel, &view.name) {
+            Some(model) => model.name().to_owned(),
+            None => continue,
+        };
+
+        let model = datamodel.find_model_mut(&model_name);
+        let note = format!(
+            "This model is based on the database view `{}`, it is read-only.",
+            view.name
+        );
+
+        model.documentation = Some(match model.documentation.take() {
+            Some(existing) => format!("{}\n{}", existing, note),
+            None => note,
+        });
+
+        models_for_views.push(Model::new(&model_name));
+    }
+
+    if models_for_views.is_empty() {
+        vec![]
+    } else {
+        vec![warning_models_for_views(&models_for_views)]
+    }
+}
@@ -40,6 +40,21 @@ impl ModelAndField {
     }
 }
 
+#[derive(Serialize, Debug)]
+pub struct ModelAndTable {
+    pub(crate) model: String,
+    pub(crate) table: String,
+}
+
+impl ModelAndTable {
+    pub fn new(model: &str, table: &str) -> Self {
+        ModelAndTable {
+            model: model.to_owned(),
+            table: table.to_owned(),
+        }
+    }
+}
+
 #[derive(Serialize, Debug)]
 pub struct ModelAndFieldAndType {
     pub(crate) model: String,
@@ -47,6 +62,19 @@ pub struct ModelAndFieldAndType {
     pub(crate) tpe: String,
 }
 
+#[derive(Serialize, Debug)]
+pub struct ModelAndPrivileges {
+    pub(crate) model: String,
+    pub(crate) privileges: Vec<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ModelAndFieldAndPrivileges {
+    pub(crate) model: String,
+    pub(crate) field: String,
+    pub(crate) privileges: Vec<String>,
+}
+
 #[derive(Serialize, Debug, Clone)]
 pub struct EnumAndValue {
     pub(crate) enm: String,
@@ -178,3 +206,36 @@ pub fn warning_enriched_with_updated_at(affected: &Vec<ModelAndField>) -> Warnin
         affected: serde_json::to_value(&affected).unwrap(),
     }
 }
+
+pub fn warning_models_with_missing_privileges(affected: &Vec<ModelAndPrivileges>) -> Warning {
+    Warning {
+        code: 14,
+        message: "The database user used for introspection is missing privileges on the tables of these models. Reading or writing their data will fail.".into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
+
+pub fn warning_fields_with_missing_privileges(affected: &Vec<ModelAndFieldAndPrivileges>) -> Warning {
+    Warning {
+        code: 15,
+        message: "The database user used for introspection is missing privileges on the columns of these fields. Reading or writing their data will fail.".into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
+
+pub fn warning_models_for_views(affected: &Vec<Model>) -> Warning {
+    Warning {
+        code: 16,
+        message: "These models were generated from database views. They are read-only, writing their data will fail."
+            .into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
+
+pub fn warning_models_with_reserved_names(affected: &Vec<ModelAndTable>) -> Warning {
+    Warning {
+        code: 17,
+        message: "These models were renamed because the names of their tables are reserved in the Prisma schema. The table names are kept with `@@map`.".into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
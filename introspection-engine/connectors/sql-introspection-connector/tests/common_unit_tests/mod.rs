@@ -3,9 +3,13 @@ use datamodel::{
     dml, Datamodel, DefaultValue as DMLDefault, Field, FieldArity, FieldType, IndexDefinition, Model, OnDeleteStrategy,
     RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator,
 };
+use datamodel_connector::Connector;
+use introspection_connector::{IntrospectionOptions, ReservedModelNaming};
+use native_types::PostgresType;
 use pretty_assertions::assert_eq;
 use prisma_value::PrismaValue;
 use quaint::connector::SqlFamily;
+use sql_datamodel_connector::SqlDatamodelConnectors;
 use sql_introspection_connector::calculate_datamodel::calculate_datamodel;
 use sql_schema_describer::*;
 use test_macros::test_each_connector;
@@ -25,6 +29,7 @@ fn a_data_model_can_be_generated_from_a_schema() {
         ColumnTypeFamily::LogSequenceNumber,
         ColumnTypeFamily::TextSearch,
         ColumnTypeFamily::TransactionId,
+        ColumnTypeFamily::Xml,
     ];
 
     let ref_data_model = Datamodel {
@@ -40,6 +45,11 @@ fn a_data_model_can_be_generated_from_a_schema() {
             is_commented_out: true,
             indices: vec![],
             id_fields: vec![],
+            distribution_field: None,
+            id_clustered: None,
+            is_temporal: false,
+            history_table_name: None,
+            deprecation: None,
             fields: col_types
                 .iter()
                 .map(|col_type| {
@@ -52,6 +62,18 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         ColumnTypeFamily::Enum(name) => (FieldType::Enum(name.clone()), false, None),
                         ColumnTypeFamily::Uuid => (FieldType::Base(ScalarType::String, None), false, None),
                         ColumnTypeFamily::Json => (FieldType::Base(ScalarType::Json, None), false, None),
+                        ColumnTypeFamily::Binary => (FieldType::Base(ScalarType::Bytes, None), false, None),
+                        ColumnTypeFamily::TextSearch => (FieldType::Base(ScalarType::String, None), false, None),
+                        ColumnTypeFamily::Xml => (
+                            FieldType::NativeType(
+                                ScalarType::String,
+                                SqlDatamodelConnectors::postgres()
+                                    .introspect_native_type(Box::new(PostgresType::XML))
+                                    .unwrap(),
+                            ),
+                            false,
+                            None,
+                        ),
                         x => (
                             FieldType::Unsupported(x.to_string()),
                             true,
@@ -69,7 +91,9 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         documentation,
                         is_generated: false,
                         is_updated_at: false,
+                        is_read_only: false,
                         is_commented_out,
+                        deprecation: None,
                     })
                 })
                 .collect(),
@@ -95,9 +119,24 @@ fn a_data_model_can_be_generated_from_a_schema() {
         }],
         enums: vec![],
         sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
     };
-    let introspection_result =
-        calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+    let introspection_result = calculate_datamodel(
+        &schema,
+        &SqlFamily::Postgres,
+        &Datamodel::new(),
+        IntrospectionOptions::default(),
+    )
+    .expect("calculate data model");
 
     assert_eq!(introspection_result.data_model, ref_data_model);
 }
@@ -128,7 +167,9 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_read_only: false,
                     is_commented_out: false,
+                    deprecation: None,
                 }),
                 Field::ScalarField(ScalarField::new(
                     "list",
@@ -139,6 +180,11 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
             is_generated: false,
             indices: vec![],
             id_fields: vec![],
+            distribution_field: None,
+            id_clustered: None,
+            is_temporal: false,
+            history_table_name: None,
+            deprecation: None,
         }],
         enums: vec![],
     };
@@ -171,14 +217,30 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                 columns: vec!["required".to_string()],
                 sequence: None,
                 constraint_name: None,
+                clustered: None,
             }),
             foreign_keys: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
     };
-    let introspection_result =
-        calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+    let introspection_result = calculate_datamodel(
+        &schema,
+        &SqlFamily::Postgres,
+        &Datamodel::new(),
+        IntrospectionOptions::default(),
+    )
+    .expect("calculate data model");
 
     assert_eq!(introspection_result.data_model, ref_data_model);
 }
@@ -209,7 +271,9 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_read_only: false,
                     is_commented_out: false,
+                    deprecation: None,
                 }),
                 Field::ScalarField(ScalarField {
                     name: "bool_default".to_string(),
@@ -222,7 +286,9 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_read_only: false,
                     is_commented_out: false,
+                    deprecation: None,
                 }),
                 Field::ScalarField(ScalarField {
                     name: "float_default".to_string(),
@@ -235,7 +301,9 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_read_only: false,
                     is_commented_out: false,
+                    deprecation: None,
                 }),
                 Field::ScalarField(ScalarField {
                     name: "string_default".to_string(),
@@ -248,7 +316,9 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_read_only: false,
                     is_commented_out: false,
+                    deprecation: None,
                 }),
             ],
             is_generated: false,
@@ -256,8 +326,16 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                 name: Some("unique".into()),
                 fields: vec!["no_default".into(), "int_default".into()],
                 tpe: dml::IndexType::Unique,
+                clustered: None,
+                nulls_not_distinct: false,
+                predicate: None,
             }],
             id_fields: vec![],
+            distribution_field: None,
+            id_clustered: None,
+            is_temporal: false,
+            history_table_name: None,
+            deprecation: None,
         }],
         enums: vec![],
     };
@@ -301,15 +379,33 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                 name: "unique".to_string(),
                 columns: vec!["no_default".into(), "int_default".into()],
                 tpe: IndexType::Unique,
+                clustered: None,
+                predicate: None,
+                nulls_not_distinct: false,
             }],
             primary_key: None,
             foreign_keys: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
     };
-    let introspection_result =
-        calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+    let introspection_result = calculate_datamodel(
+        &schema,
+        &SqlFamily::Postgres,
+        &Datamodel::new(),
+        IntrospectionOptions::default(),
+    )
+    .expect("calculate data model");
 
     assert_eq!(introspection_result.data_model, ref_data_model);
 }
@@ -336,11 +432,18 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_read_only: false,
                     is_commented_out: false,
+                    deprecation: None,
                 })],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                distribution_field: None,
+                id_clustered: None,
+                is_temporal: false,
+                history_table_name: None,
+                deprecation: None,
             },
             // Model with non-auto-incrementing primary key
             Model {
@@ -360,11 +463,18 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_read_only: false,
                     is_commented_out: false,
+                    deprecation: None,
                 })],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                distribution_field: None,
+                id_clustered: None,
+                is_temporal: false,
+                history_table_name: None,
+                deprecation: None,
             },
             // Model with primary key seeded by sequence
             Model {
@@ -384,11 +494,18 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_read_only: false,
                     is_commented_out: false,
+                    deprecation: None,
                 })],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                distribution_field: None,
+                id_clustered: None,
+                is_temporal: false,
+                history_table_name: None,
+                deprecation: None,
             },
         ],
         enums: vec![],
@@ -415,6 +532,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     columns: vec!["primary".to_string()],
                     sequence: None,
                     constraint_name: None,
+                    clustered: None,
                 }),
                 foreign_keys: vec![],
             },
@@ -437,6 +555,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     columns: vec!["primary".to_string()],
                     sequence: None,
                     constraint_name: None,
+                    clustered: None,
                 }),
                 foreign_keys: vec![],
             },
@@ -464,15 +583,31 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                         allocation_size: 1,
                     }),
                     constraint_name: None,
+                    clustered: None,
                 }),
                 foreign_keys: vec![],
             },
         ],
         enums: vec![],
         sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
     };
-    let introspection_result =
-        calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+    let introspection_result = calculate_datamodel(
+        &schema,
+        &SqlFamily::Postgres,
+        &Datamodel::new(),
+        IntrospectionOptions::default(),
+    )
+    .expect("calculate data model");
 
     assert_eq!(introspection_result.data_model, ref_data_model);
 }
@@ -503,12 +638,19 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_read_only: false,
                     is_commented_out: false,
+                    deprecation: None,
                 }),
             ],
             is_generated: false,
             indices: vec![],
             id_fields: vec![],
+            distribution_field: None,
+            id_clustered: None,
+            is_temporal: false,
+            history_table_name: None,
+            deprecation: None,
         }],
         enums: vec![],
     };
@@ -534,15 +676,33 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "unique".to_string(),
                 columns: vec!["unique".to_string()],
                 tpe: IndexType::Unique,
+                clustered: None,
+                predicate: None,
+                nulls_not_distinct: false,
             }],
             primary_key: None,
             foreign_keys: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
     };
-    let introspection_result =
-        calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+    let introspection_result = calculate_datamodel(
+        &schema,
+        &SqlFamily::Postgres,
+        &Datamodel::new(),
+        IntrospectionOptions::default(),
+    )
+    .expect("calculate data model");
 
     assert_eq!(introspection_result.data_model, ref_data_model);
 }
@@ -569,7 +729,9 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_read_only: false,
                         is_commented_out: false,
+                        deprecation: None,
                     }),
                     Field::ScalarField(ScalarField::new(
                         "name",
@@ -591,6 +753,11 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                distribution_field: None,
+                id_clustered: None,
+                is_temporal: false,
+                history_table_name: None,
+                deprecation: None,
             },
             Model {
                 database_name: None,
@@ -610,7 +777,9 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_read_only: false,
                         is_commented_out: false,
+                        deprecation: None,
                     }),
                     Field::ScalarField(ScalarField {
                         name: "city_id".to_string(),
@@ -623,7 +792,9 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_read_only: false,
                         is_commented_out: false,
+                        deprecation: None,
                     }),
                     Field::ScalarField(ScalarField {
                         name: "city_name".to_string(),
@@ -636,7 +807,9 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_read_only: false,
                         is_commented_out: false,
+                        deprecation: None,
                     }),
                     Field::RelationField(RelationField::new(
                         "City",
@@ -653,6 +826,11 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                distribution_field: None,
+                id_clustered: None,
+                is_temporal: false,
+                history_table_name: None,
+                deprecation: None,
             },
         ],
         enums: vec![],
@@ -695,6 +873,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                     columns: vec!["id".to_string()],
                     sequence: None,
                     constraint_name: None,
+                    clustered: None,
                 }),
                 foreign_keys: vec![],
             },
@@ -746,6 +925,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                     columns: vec!["id".to_string()],
                     sequence: None,
                     constraint_name: None,
+                    clustered: None,
                 }),
                 foreign_keys: vec![ForeignKey {
                     // what does this mean? the from columns are not targeting a specific to column?
@@ -760,9 +940,24 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
         ],
         enums: vec![],
         sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
     };
-    let introspection_result =
-        calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+    let introspection_result = calculate_datamodel(
+        &schema,
+        &SqlFamily::Postgres,
+        &Datamodel::new(),
+        IntrospectionOptions::default(),
+    )
+    .expect("calculate data model");
 
     assert_eq!(introspection_result.data_model, expected_data_model);
 }
@@ -788,7 +983,9 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_read_only: false,
                     is_commented_out: false,
+                    deprecation: None,
                 }),
                 Field::ScalarField(ScalarField::new(
                     "name",
@@ -806,8 +1003,16 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                 name: Some("name_last_name_unique".to_string()),
                 fields: vec!["name".to_string(), "lastname".to_string()],
                 tpe: datamodel::dml::IndexType::Unique,
+                clustered: None,
+                nulls_not_distinct: false,
+                predicate: None,
             }],
             id_fields: vec![],
+            distribution_field: None,
+            id_clustered: None,
+            is_temporal: false,
+            history_table_name: None,
+            deprecation: None,
         }],
         enums: vec![],
     };
@@ -860,19 +1065,38 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                 name: "name_last_name_unique".to_string(),
                 columns: vec!["name".to_string(), "lastname".to_string()],
                 tpe: IndexType::Unique,
+                clustered: None,
+                predicate: None,
+                nulls_not_distinct: false,
             }],
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".to_string()],
                 sequence: None,
                 constraint_name: None,
+                clustered: None,
             }),
             foreign_keys: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
     };
-    let introspection_result =
-        calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+    let introspection_result = calculate_datamodel(
+        &schema,
+        &SqlFamily::Postgres,
+        &Datamodel::new(),
+        IntrospectionOptions::default(),
+    )
+    .expect("calculate data model");
 
     assert_eq!(introspection_result.data_model, ref_data_model);
 }
@@ -899,7 +1123,9 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_read_only: false,
                         is_commented_out: false,
+                        deprecation: None,
                     }),
                     Field::ScalarField(ScalarField::new(
                         "name",
@@ -921,6 +1147,11 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                distribution_field: None,
+                id_clustered: None,
+                is_temporal: false,
+                history_table_name: None,
+                deprecation: None,
             },
             Model {
                 database_name: None,
@@ -940,7 +1171,9 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_read_only: false,
                         is_commented_out: false,
+                        deprecation: None,
                     }),
                     Field::ScalarField(ScalarField::new(
                         "city_id",
@@ -962,6 +1195,11 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                distribution_field: None,
+                id_clustered: None,
+                is_temporal: false,
+                history_table_name: None,
+                deprecation: None,
             },
         ],
         enums: vec![],
@@ -1004,6 +1242,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                     columns: vec!["id".to_string()],
                     sequence: None,
                     constraint_name: None,
+                    clustered: None,
                 }),
                 foreign_keys: vec![],
             },
@@ -1042,6 +1281,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                     columns: vec!["id".to_string()],
                     sequence: None,
                     constraint_name: None,
+                    clustered: None,
                 }),
                 foreign_keys: vec![ForeignKey {
                     constraint_name: None,
@@ -1055,9 +1295,24 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
         ],
         enums: vec![],
         sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
     };
-    let introspection_result =
-        calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+    let introspection_result = calculate_datamodel(
+        &schema,
+        &SqlFamily::Postgres,
+        &Datamodel::new(),
+        IntrospectionOptions::default(),
+    )
+    .expect("calculate data model");
 
     assert_eq!(introspection_result.data_model, ref_data_model);
 }
@@ -1096,13 +1351,295 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
             values: enum_values,
         }],
         sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
     };
-    let introspection_result =
-        calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+    let introspection_result = calculate_datamodel(
+        &schema,
+        &SqlFamily::Postgres,
+        &Datamodel::new(),
+        IntrospectionOptions::default(),
+    )
+    .expect("calculate data model");
 
     assert_eq!(introspection_result.data_model, ref_data_model);
 }
 
+#[test]
+fn missing_privileges_are_documented_on_models_and_fields() {
+    let column = |name: &str, family: ColumnTypeFamily| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            data_type: "raw type".to_string(),
+            full_data_type: "raw type".to_string(),
+            character_maximum_length: None,
+            family,
+            arity: ColumnArity::Required,
+        },
+        default: None,
+        auto_increment: false,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Account".to_string(),
+            columns: vec![
+                column("id", ColumnTypeFamily::Int),
+                column("email", ColumnTypeFamily::String),
+                column("passwordHash", ColumnTypeFamily::String),
+            ],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+                constraint_name: None,
+                clustered: None,
+            }),
+            foreign_keys: vec![],
+        }],
+        enums: vec![],
+        sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![
+            MissingPrivilege {
+                table: "Account".to_string(),
+                column: None,
+                privilege: Privilege::Insert,
+            },
+            MissingPrivilege {
+                table: "Account".to_string(),
+                column: Some("passwordHash".to_string()),
+                privilege: Privilege::Select,
+            },
+        ],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
+    };
+
+    let introspection_result = calculate_datamodel(
+        &schema,
+        &SqlFamily::Postgres,
+        &Datamodel::new(),
+        IntrospectionOptions::default(),
+    )
+    .expect("calculate data model");
+
+    let model = introspection_result.data_model.find_model("Account").unwrap();
+
+    assert_eq!(
+        model.documentation.as_deref(),
+        Some("The database user used for introspection is missing the INSERT privilege on this table.")
+    );
+    assert_eq!(
+        model
+            .find_scalar_field("passwordHash")
+            .unwrap()
+            .documentation
+            .as_deref(),
+        Some("The database user used for introspection is missing the SELECT privilege on this column.")
+    );
+    assert_eq!(model.find_scalar_field("email").unwrap().documentation, None);
+
+    let warning_codes: Vec<i8> = introspection_result.warnings.iter().map(|w| w.code).collect();
+    assert_eq!(warning_codes, &[14, 15]);
+    assert_eq!(
+        introspection_result.warnings[1].affected,
+        serde_json::json!([{ "model": "Account", "field": "passwordHash", "privileges": ["SELECT"] }])
+    );
+}
+
+#[test]
+fn partial_indexes_are_introspected_with_their_predicate() {
+    let column = |name: &str, family: ColumnTypeFamily| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            data_type: "raw type".to_string(),
+            full_data_type: "raw type".to_string(),
+            character_maximum_length: None,
+            family,
+            arity: ColumnArity::Required,
+        },
+        default: None,
+        auto_increment: false,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Post".to_string(),
+            columns: vec![
+                column("id", ColumnTypeFamily::Int),
+                column("slug", ColumnTypeFamily::String),
+                column("title", ColumnTypeFamily::String),
+            ],
+            indices: vec![
+                Index {
+                    name: "live_slug_unique".to_string(),
+                    columns: vec!["slug".to_string()],
+                    tpe: IndexType::Unique,
+                    clustered: None,
+                    predicate: Some("(deleted_at IS NULL)".to_string()),
+                    nulls_not_distinct: false,
+                },
+                Index {
+                    name: "title_unique".to_string(),
+                    columns: vec!["title".to_string()],
+                    tpe: IndexType::Unique,
+                    clustered: None,
+                    predicate: Some("([title] IS NOT NULL)".to_string()),
+                    nulls_not_distinct: false,
+                },
+            ],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+                constraint_name: None,
+                clustered: None,
+            }),
+            foreign_keys: vec![],
+        }],
+        enums: vec![],
+        sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
+    };
+
+    let introspection_result = calculate_datamodel(
+        &schema,
+        &SqlFamily::Postgres,
+        &Datamodel::new(),
+        IntrospectionOptions::default(),
+    )
+    .expect("calculate data model");
+
+    let model = introspection_result.data_model.find_model("Post").unwrap();
+
+    assert_eq!(
+        model.indices,
+        vec![IndexDefinition {
+            name: Some("live_slug_unique".to_string()),
+            fields: vec!["slug".to_string()],
+            tpe: dml::IndexType::Unique,
+            clustered: None,
+            nulls_not_distinct: false,
+            predicate: Some("(deleted_at IS NULL)".to_string()),
+        }]
+    );
+    assert!(!model.find_scalar_field("slug").unwrap().is_unique);
+    // Only excluding the NULLs of its own column, the index covers all the rows that matter.
+    assert!(model.find_scalar_field("title").unwrap().is_unique);
+}
+
+#[test]
+fn unique_indexes_considering_nulls_equal_are_introspected_with_nulls_not_distinct() {
+    let column = |name: &str, arity: ColumnArity| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            data_type: "raw type".to_string(),
+            full_data_type: "raw type".to_string(),
+            character_maximum_length: None,
+            family: ColumnTypeFamily::String,
+            arity,
+        },
+        default: None,
+        auto_increment: false,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "User".to_string(),
+            columns: vec![
+                column("id", ColumnArity::Required),
+                column("email", ColumnArity::Nullable),
+                column("nickname", ColumnArity::Nullable),
+            ],
+            indices: vec![
+                Index {
+                    name: "User_email_key".to_string(),
+                    columns: vec!["email".to_string()],
+                    tpe: IndexType::Unique,
+                    clustered: None,
+                    predicate: None,
+                    nulls_not_distinct: true,
+                },
+                Index {
+                    name: "User_nickname_key".to_string(),
+                    columns: vec!["nickname".to_string()],
+                    tpe: IndexType::Unique,
+                    clustered: None,
+                    predicate: None,
+                    nulls_not_distinct: false,
+                },
+            ],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+                constraint_name: None,
+                clustered: None,
+            }),
+            foreign_keys: vec![],
+        }],
+        enums: vec![],
+        sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
+    };
+
+    let introspection_result = calculate_datamodel(
+        &schema,
+        &SqlFamily::Postgres,
+        &Datamodel::new(),
+        IntrospectionOptions::default(),
+    )
+    .expect("calculate data model");
+
+    let model = introspection_result.data_model.find_model("User").unwrap();
+
+    // `@unique` can't say how NULLs are compared, so the index stays a `@@unique`.
+    assert!(!model.find_scalar_field("email").unwrap().is_unique);
+    assert!(model.find_scalar_field("nickname").unwrap().is_unique);
+    assert_eq!(
+        model.indices,
+        vec![IndexDefinition {
+            name: Some("User_email_key".to_string()),
+            fields: vec!["email".to_string()],
+            tpe: dml::IndexType::Unique,
+            clustered: None,
+            nulls_not_distinct: true,
+            predicate: None,
+        }]
+    );
+}
+
 #[test_each_connector]
 async fn one_to_many_relation_field_names_do_not_conflict_with_many_to_many_relation_field_names(
     api: &TestApi,
@@ -1194,7 +1731,13 @@ async fn one_to_many_relation_field_names_do_not_conflict_with_many_to_many_rela
     let expected_dm =
         datamodel::render_schema_ast_to_string(&datamodel::parse_schema_ast(&expected_dm).unwrap()).unwrap();
 
-    let mut introspected_dm = calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new())?.data_model;
+    let mut introspected_dm = calculate_datamodel(
+        &schema,
+        &SqlFamily::Postgres,
+        &Datamodel::new(),
+        IntrospectionOptions::default(),
+    )?
+    .data_model;
     introspected_dm.models.sort_by(|a, b| b.name.cmp(&a.name));
 
     let introspected_dm_string = datamodel::render_datamodel_to_string(&introspected_dm).unwrap();
@@ -1253,7 +1796,13 @@ async fn many_to_many_relation_field_names_do_not_conflict_with_themselves(api:
     let expected_dm =
         datamodel::render_schema_ast_to_string(&datamodel::parse_schema_ast(&expected_dm).unwrap()).unwrap();
 
-    let mut introspected_dm = calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new())?.data_model;
+    let mut introspected_dm = calculate_datamodel(
+        &schema,
+        &SqlFamily::Postgres,
+        &Datamodel::new(),
+        IntrospectionOptions::default(),
+    )?
+    .data_model;
     introspected_dm.models.sort_by(|a, b| b.name.cmp(&a.name));
     for model in &mut introspected_dm.models {
         model.fields.sort_by(|a, b| a.name().cmp(b.name()));
@@ -1267,3 +1816,884 @@ async fn many_to_many_relation_field_names_do_not_conflict_with_themselves(api:
 
     Ok(())
 }
+
+#[test]
+fn uuid_generating_defaults_are_introspected_as_uuid_when_asked_for() {
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Post".to_string(),
+            columns: vec![
+                Column {
+                    name: "id".to_string(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::Uuid, ColumnArity::Required),
+                    default: Some(DefaultValue::DBGENERATED("gen_random_uuid()".to_string())),
+                    auto_increment: false,
+                },
+                Column {
+                    name: "token".to_string(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
+                    default: Some(DefaultValue::DBGENERATED("(uuid_generate_v4())::text".to_string())),
+                    auto_increment: false,
+                },
+                Column {
+                    name: "salt".to_string(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
+                    default: Some(DefaultValue::DBGENERATED("md5((random())::text)".to_string())),
+                    auto_increment: false,
+                },
+            ],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+                constraint_name: None,
+                clustered: None,
+            }),
+            foreign_keys: vec![],
+        }],
+        enums: vec![],
+        sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
+    };
+
+    let defaults = |options: IntrospectionOptions| {
+        let data_model = calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), options)
+            .expect("calculate data model")
+            .data_model;
+        let model = data_model.find_model("Post").unwrap();
+
+        ["id", "token", "salt"]
+            .iter()
+            .map(|name| model.find_scalar_field(name).unwrap().default_value.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let dbgenerated = Some(DMLDefault::Expression(ValueGenerator::new_dbgenerated()));
+    let uuid = Some(DMLDefault::Expression(ValueGenerator::new_uuid()));
+
+    assert_eq!(
+        defaults(IntrospectionOptions::default()),
+        vec![dbgenerated.clone(), dbgenerated.clone(), dbgenerated.clone()]
+    );
+    assert_eq!(
+        defaults(IntrospectionOptions {
+            uuid_defaults: true,
+            ..Default::default()
+        }),
+        vec![uuid.clone(), uuid, dbgenerated]
+    );
+}
+
+#[test]
+fn updated_at_heuristics_are_applied_when_asked_for() {
+    let column = |name: &str, family: ColumnTypeFamily| Column {
+        name: name.to_string(),
+        tpe: ColumnType::pure(family, ColumnArity::Required),
+        default: None,
+        auto_increment: false,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Post".to_string(),
+            columns: vec![
+                column("id", ColumnTypeFamily::Int),
+                column("createdAt", ColumnTypeFamily::DateTime),
+                column("modifiedAt", ColumnTypeFamily::DateTime),
+                column("lastChange", ColumnTypeFamily::DateTime),
+                column("lastEditor", ColumnTypeFamily::String),
+            ],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+                constraint_name: None,
+                clustered: None,
+            }),
+            foreign_keys: vec![],
+        }],
+        enums: vec![],
+        sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![OnUpdateTimestamp {
+            table: "Post".to_string(),
+            column: "modifiedAt".to_string(),
+        }],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
+    };
+
+    // The naming convention is taken from the previous data model.
+    let previous_data_model = datamodel::parse_datamodel(
+        r#"
+        model Comment {
+            id         Int      @id
+            changedAt  DateTime @updatedAt @map("lastChange")
+            lastEditor String
+        }
+        "#,
+    )
+    .unwrap();
+
+    let updated_at_fields = |options: IntrospectionOptions| {
+        let data_model = calculate_datamodel(&schema, &SqlFamily::Mysql, &previous_data_model, options)
+            .expect("calculate data model")
+            .data_model;
+        let model = data_model.find_model("Post").unwrap();
+
+        model
+            .scalar_fields()
+            .filter(|field| field.is_updated_at)
+            .map(|field| field.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    assert_eq!(updated_at_fields(IntrospectionOptions::default()), "");
+    assert_eq!(
+        updated_at_fields(IntrospectionOptions {
+            updated_at_heuristics: true,
+            ..Default::default()
+        }),
+        "modifiedAt, lastChange"
+    );
+}
+
+#[test]
+fn generated_columns_are_introspected_as_read_only_fields() {
+    let column = |name: &str, default: Option<DefaultValue>| Column {
+        name: name.to_string(),
+        tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
+        default,
+        auto_increment: false,
+    };
+
+    let expression = "concat(`firstName`,_utf8mb4' ',`lastName`)".to_string();
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Person".to_string(),
+            columns: vec![
+                column("id", None),
+                column("firstName", None),
+                column("lastName", None),
+                column("fullName", Some(DefaultValue::DBGENERATED(expression.clone()))),
+            ],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+                constraint_name: None,
+                clustered: None,
+            }),
+            foreign_keys: vec![],
+        }],
+        enums: vec![],
+        sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![GeneratedColumn {
+            table: "Person".to_string(),
+            column: "fullName".to_string(),
+            expression,
+            stored: false,
+        }],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
+    };
+
+    let data_model = calculate_datamodel(
+        &schema,
+        &SqlFamily::Mysql,
+        &Datamodel::new(),
+        IntrospectionOptions::default(),
+    )
+    .expect("calculate data model")
+    .data_model;
+    let model = data_model.find_model("Person").unwrap();
+
+    let read_only_fields: Vec<&str> = model
+        .scalar_fields()
+        .filter(|field| field.is_read_only)
+        .map(|field| field.name.as_str())
+        .collect();
+
+    assert_eq!(read_only_fields, &["fullName"]);
+    assert_eq!(
+        model.find_scalar_field("fullName").unwrap().default_value,
+        Some(DMLDefault::Expression(ValueGenerator::new_dbgenerated()))
+    );
+}
+
+#[test]
+fn distributed_tables_are_introspected_with_their_distribution_field() {
+    let column = |name: &str| Column {
+        name: name.to_string(),
+        tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+        default: None,
+        auto_increment: false,
+    };
+
+    let table = |name: &str| Table {
+        name: name.to_string(),
+        columns: vec![column("id"), column("tenant-id")],
+        indices: vec![],
+        primary_key: Some(PrimaryKey {
+            columns: vec!["id".to_string()],
+            sequence: None,
+            constraint_name: None,
+            clustered: None,
+        }),
+        foreign_keys: vec![],
+    };
+
+    let schema = SqlSchema {
+        tables: vec![table("Event"), table("Tenant")],
+        enums: vec![],
+        sequences: vec![],
+        distributed_tables: vec![DistributedTable {
+            table: "Event".to_string(),
+            distribution_column: "tenant-id".to_string(),
+        }],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
+    };
+
+    let data_model = calculate_datamodel(
+        &schema,
+        &SqlFamily::Postgres,
+        &Datamodel::new(),
+        IntrospectionOptions::default(),
+    )
+    .expect("calculate data model")
+    .data_model;
+
+    // The distribution field follows the sanitized name of the field of the column.
+    assert_eq!(
+        data_model.find_model("Event").unwrap().distribution_field.as_deref(),
+        Some("tenant_id")
+    );
+    assert_eq!(data_model.find_model("Tenant").unwrap().distribution_field, None);
+}
+
+#[test]
+fn temporal_tables_are_introspected_with_their_history_table() {
+    let table = |name: &str| Table {
+        name: name.to_string(),
+        columns: vec![Column {
+            name: "id".to_string(),
+            tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+            default: None,
+            auto_increment: false,
+        }],
+        indices: vec![],
+        primary_key: Some(PrimaryKey {
+            columns: vec!["id".to_string()],
+            sequence: None,
+            constraint_name: None,
+            clustered: None,
+        }),
+        foreign_keys: vec![],
+    };
+
+    let temporal_table = |name: &str, history_table: &str| TemporalTable {
+        table: name.to_string(),
+        history_table: history_table.to_string(),
+        period_start_column: "ValidFrom".to_string(),
+        period_end_column: "ValidTo".to_string(),
+    };
+
+    let schema = SqlSchema {
+        tables: vec![table("Account"), table("Order"), table("Tag")],
+        enums: vec![],
+        sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![
+            temporal_table("Account", "AccountHistory"),
+            temporal_table("Order", "OrderVersions"),
+        ],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
+    };
+
+    let data_model = calculate_datamodel(
+        &schema,
+        &SqlFamily::Mssql,
+        &Datamodel::new(),
+        IntrospectionOptions::default(),
+    )
+    .expect("calculate data model")
+    .data_model;
+
+    let temporal = |name: &str| {
+        let model = data_model.find_model(name).unwrap();
+        (model.is_temporal, model.history_table_name.clone())
+    };
+
+    // The default history table name is left out of the schema.
+    assert_eq!(temporal("Account"), (true, None));
+    assert_eq!(temporal("Order"), (true, Some("OrderVersions".to_string())));
+    assert_eq!(temporal("Tag"), (false, None));
+}
+
+#[test]
+fn views_get_read_only_models_when_asked_for() {
+    let column = |name: &str, family: ColumnTypeFamily| Column {
+        name: name.to_string(),
+        tpe: ColumnType::pure(family, ColumnArity::Required),
+        default: None,
+        auto_increment: false,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "User".to_string(),
+            columns: vec![
+                column("id", ColumnTypeFamily::Int),
+                column("name", ColumnTypeFamily::String),
+                column("active", ColumnTypeFamily::Boolean),
+            ],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+                constraint_name: None,
+                clustered: None,
+            }),
+            foreign_keys: vec![],
+        }],
+        enums: vec![],
+        sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![View {
+            name: "ActiveUser".to_string(),
+            definition: Some("SELECT id, name FROM \"User\" WHERE active".to_string()),
+            columns: vec![
+                column("id", ColumnTypeFamily::Int),
+                column("name", ColumnTypeFamily::String),
+            ],
+        }],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
+    };
+
+    let introspect = |options: IntrospectionOptions| {
+        calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), options).expect("calculate data model")
+    };
+
+    let without_views = introspect(IntrospectionOptions::default());
+
+    assert!(without_views.data_model.find_model("ActiveUser").is_none());
+
+    let with_views = introspect(IntrospectionOptions {
+        views: true,
+        ..Default::default()
+    });
+    let model = with_views.data_model.find_model("ActiveUser").unwrap();
+    let field_names: Vec<&str> = model.scalar_fields().map(|field| field.name.as_str()).collect();
+
+    assert_eq!(field_names, &["id", "name"]);
+    assert!(model.is_commented_out);
+    assert!(model
+        .documentation
+        .as_deref()
+        .unwrap()
+        .ends_with("This model is based on the database view `ActiveUser`, it is read-only."));
+    assert!(with_views.warnings.iter().any(|warning| warning.code == 16));
+}
+
+#[test]
+fn reserved_model_names_are_escaped_when_asked_for() {
+    let id = Column {
+        name: "id".to_string(),
+        tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+        default: None,
+        auto_increment: false,
+    };
+
+    let table = |name: &str, columns: Vec<Column>, foreign_keys: Vec<ForeignKey>| Table {
+        name: name.to_string(),
+        columns,
+        indices: vec![],
+        primary_key: Some(PrimaryKey {
+            columns: vec!["id".to_string()],
+            sequence: None,
+            constraint_name: None,
+            clustered: None,
+        }),
+        foreign_keys,
+    };
+
+    let filter_id = Column {
+        name: "filterId".to_string(),
+        ..id.clone()
+    };
+
+    let schema = SqlSchema {
+        tables: vec![
+            table("StringFilter", vec![id.clone()], vec![]),
+            // Already named like the escaped `StringFilter`.
+            table("StringFilter_", vec![id.clone()], vec![]),
+            table(
+                "Post",
+                vec![id.clone(), filter_id],
+                vec![ForeignKey {
+                    constraint_name: None,
+                    columns: vec!["filterId".to_string()],
+                    referenced_table: "StringFilter".to_string(),
+                    on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
+                    referenced_columns: vec!["id".to_string()],
+                }],
+            ),
+        ],
+        enums: vec![],
+        sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
+    };
+
+    let introspect = |reserved_model_naming: ReservedModelNaming| {
+        calculate_datamodel(
+            &schema,
+            &SqlFamily::Postgres,
+            &Datamodel::new(),
+            IntrospectionOptions {
+                reserved_model_naming,
+                ..Default::default()
+            },
+        )
+        .expect("calculate data model")
+    };
+
+    let prefixed = introspect(ReservedModelNaming::Prefix);
+    let model = prefixed.data_model.find_model("RenamedStringFilter").unwrap();
+
+    assert_eq!(model.database_name.as_deref(), Some("StringFilter"));
+
+    let escaped = introspect(ReservedModelNaming::Escape);
+    let model = escaped.data_model.find_model("StringFilter_2").unwrap();
+
+    assert_eq!(model.database_name.as_deref(), Some("StringFilter"));
+    assert!(escaped
+        .data_model
+        .find_model("StringFilter_")
+        .unwrap()
+        .database_name
+        .is_none());
+
+    let relation_targets: Vec<&str> = escaped
+        .data_model
+        .find_model("Post")
+        .unwrap()
+        .relation_fields()
+        .map(|field| field.relation_info.to.as_str())
+        .collect();
+
+    assert_eq!(relation_targets, &["StringFilter_2"]);
+
+    let warning = escaped.warnings.iter().find(|warning| warning.code == 17).unwrap();
+
+    assert_eq!(
+        warning.affected,
+        serde_json::json!([{ "model": "StringFilter_2", "table": "StringFilter" }])
+    );
+}
+
+#[test]
+fn native_types_are_introspected_when_asked_for() {
+    let column = |name: &str, family: ColumnTypeFamily, full_data_type: &str, length: Option<i64>| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            data_type: full_data_type.to_string(),
+            full_data_type: full_data_type.to_string(),
+            character_maximum_length: length,
+            family,
+            arity: ColumnArity::Required,
+        },
+        default: None,
+        auto_increment: false,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Product".to_string(),
+            columns: vec![
+                column("id", ColumnTypeFamily::Int, "int4", None),
+                column("name", ColumnTypeFamily::String, "varchar", Some(255)),
+                column("description", ColumnTypeFamily::String, "varchar", None),
+                column("price", ColumnTypeFamily::Float, "numeric", None),
+            ],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+                constraint_name: None,
+                clustered: None,
+            }),
+            foreign_keys: vec![],
+        }],
+        enums: vec![],
+        sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
+    };
+
+    let field_types = |options: IntrospectionOptions| {
+        let data_model = calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), options)
+            .expect("calculate data model")
+            .data_model;
+        let model = data_model.find_model("Product").unwrap();
+
+        model
+            .scalar_fields()
+            .map(|field| field.field_type.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let postgres = SqlDatamodelConnectors::postgres();
+    let native_type = |tpe: PostgresType| postgres.introspect_native_type(Box::new(tpe)).unwrap();
+
+    assert_eq!(
+        field_types(IntrospectionOptions::default()),
+        vec![
+            FieldType::Base(ScalarType::Int, None),
+            FieldType::Base(ScalarType::String, None),
+            FieldType::Base(ScalarType::String, None),
+            FieldType::Base(ScalarType::Float, None),
+        ]
+    );
+
+    // Unbounded varchars have no native type, and numeric columns are Float fields.
+    assert_eq!(
+        field_types(IntrospectionOptions {
+            native_types: true,
+            ..Default::default()
+        }),
+        vec![
+            FieldType::NativeType(ScalarType::Int, native_type(PostgresType::Integer)),
+            FieldType::NativeType(ScalarType::String, native_type(PostgresType::VarChar(255))),
+            FieldType::Base(ScalarType::String, None),
+            FieldType::Base(ScalarType::Float, None),
+        ]
+    );
+}
+
+#[test]
+fn join_tables_of_implicit_many_to_many_relations_get_models_when_asked_for() {
+    let column = |name: &str| Column {
+        name: name.to_string(),
+        tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+        default: None,
+        auto_increment: false,
+    };
+
+    let table = |name: &str| Table {
+        name: name.to_string(),
+        columns: vec![column("id")],
+        indices: vec![],
+        primary_key: Some(PrimaryKey {
+            columns: vec!["id".to_string()],
+            sequence: None,
+            constraint_name: None,
+            clustered: None,
+        }),
+        foreign_keys: vec![],
+    };
+
+    let index = |name: &str, columns: &[&str], tpe: IndexType| Index {
+        name: name.to_string(),
+        columns: columns.iter().map(|column| column.to_string()).collect(),
+        tpe,
+        clustered: None,
+        predicate: None,
+        nulls_not_distinct: false,
+    };
+
+    let foreign_key = |column: &str, referenced_table: &str| ForeignKey {
+        constraint_name: None,
+        columns: vec![column.to_string()],
+        referenced_table: referenced_table.to_string(),
+        on_delete_action: ForeignKeyAction::Cascade,
+        on_update_action: ForeignKeyAction::Cascade,
+        referenced_columns: vec!["id".to_string()],
+    };
+
+    let schema = SqlSchema {
+        tables: vec![
+            table("Category"),
+            table("Post"),
+            Table {
+                name: "_CategoryToPost".to_string(),
+                columns: vec![column("A"), column("B")],
+                indices: vec![
+                    index("_CategoryToPost_AB_unique", &["A", "B"], IndexType::Unique),
+                    index("_CategoryToPost_B_index", &["B"], IndexType::Normal),
+                ],
+                primary_key: None,
+                foreign_keys: vec![foreign_key("A", "Category"), foreign_key("B", "Post")],
+            },
+        ],
+        enums: vec![],
+        sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
+    };
+
+    let introspect = |options: IntrospectionOptions| {
+        calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), options)
+            .expect("calculate data model")
+            .data_model
+    };
+
+    let related_models = |data_model: &Datamodel, model: &str| {
+        data_model
+            .find_model(model)
+            .unwrap()
+            .relation_fields()
+            .map(|field| field.relation_info.to.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let implicit = introspect(IntrospectionOptions::default());
+
+    assert_eq!(implicit.models().count(), 2);
+    assert_eq!(related_models(&implicit, "Category"), vec!["Post".to_string()]);
+
+    let explicit = introspect(IntrospectionOptions {
+        explicit_many_to_many: true,
+        ..Default::default()
+    });
+
+    let join_model = explicit
+        .models()
+        .find(|model| model.database_name.as_deref() == Some("_CategoryToPost"))
+        .expect("a model for the join table");
+
+    assert_eq!(
+        join_model
+            .relation_fields()
+            .map(|field| field.relation_info.to.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Category", "Post"]
+    );
+    assert_eq!(related_models(&explicit, "Category"), vec![join_model.name.clone()]);
+    assert_eq!(related_models(&explicit, "Post"), vec![join_model.name.clone()]);
+}
+
+#[test]
+fn referential_actions_are_introspected_unless_they_are_the_default_ones() {
+    let column = |name: &str, arity: ColumnArity| Column {
+        name: name.to_string(),
+        tpe: ColumnType::pure(ColumnTypeFamily::Int, arity),
+        default: None,
+        auto_increment: false,
+    };
+
+    let foreign_key = |column: &str, on_delete_action: ForeignKeyAction| ForeignKey {
+        constraint_name: None,
+        columns: vec![column.to_string()],
+        referenced_table: "User".to_string(),
+        on_delete_action,
+        on_update_action: ForeignKeyAction::Cascade,
+        referenced_columns: vec!["id".to_string()],
+    };
+
+    let table = |name: &str, columns: Vec<Column>, foreign_keys: Vec<ForeignKey>| Table {
+        name: name.to_string(),
+        columns,
+        indices: vec![],
+        primary_key: Some(PrimaryKey {
+            columns: vec!["id".to_string()],
+            sequence: None,
+            constraint_name: None,
+            clustered: None,
+        }),
+        foreign_keys,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![
+            table("User", vec![column("id", ColumnArity::Required)], vec![]),
+            table(
+                "Post",
+                vec![
+                    column("id", ColumnArity::Required),
+                    column("authorId", ColumnArity::Required),
+                    column("editorId", ColumnArity::Nullable),
+                    column("reviewerId", ColumnArity::Required),
+                    column("translatorId", ColumnArity::Nullable),
+                ],
+                vec![
+                    foreign_key("authorId", ForeignKeyAction::Cascade),
+                    foreign_key("editorId", ForeignKeyAction::SetNull),
+                    foreign_key("reviewerId", ForeignKeyAction::Restrict),
+                    foreign_key("translatorId", ForeignKeyAction::Cascade),
+                ],
+            ),
+        ],
+        enums: vec![],
+        sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
+    };
+
+    let data_model = calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), Default::default())
+        .expect("calculate data model")
+        .data_model;
+
+    let on_delete = |column: &str| {
+        data_model
+            .find_model("Post")
+            .unwrap()
+            .relation_fields()
+            .find(|field| field.relation_info.fields == vec![column.to_string()])
+            .unwrap()
+            .relation_info
+            .on_delete
+    };
+
+    assert_eq!(on_delete("authorId"), OnDeleteStrategy::None);
+    assert_eq!(on_delete("editorId"), OnDeleteStrategy::None);
+    assert_eq!(on_delete("reviewerId"), OnDeleteStrategy::Restrict);
+    assert_eq!(on_delete("translatorId"), OnDeleteStrategy::Cascade);
+}
+
+#[test]
+fn tables_of_other_databases_referenced_by_foreign_keys_become_models_mapped_to_their_qualified_name() {
+    let table = |name: &str, columns: &[&str], foreign_keys: Vec<ForeignKey>| Table {
+        name: name.to_string(),
+        columns: columns
+            .iter()
+            .map(|column| Column {
+                name: column.to_string(),
+                tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                default: None,
+                auto_increment: false,
+            })
+            .collect(),
+        indices: vec![],
+        primary_key: Some(PrimaryKey {
+            columns: vec!["id".to_string()],
+            sequence: None,
+            constraint_name: None,
+            clustered: None,
+        }),
+        foreign_keys,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![table(
+            "Post",
+            &["id", "authorId"],
+            vec![ForeignKey {
+                constraint_name: Some("Post_authorId_fkey".to_string()),
+                columns: vec!["authorId".to_string()],
+                referenced_table: "accounts.User".to_string(),
+                referenced_columns: vec!["id".to_string()],
+                on_delete_action: ForeignKeyAction::Cascade,
+                on_update_action: ForeignKeyAction::Cascade,
+            }],
+        )],
+        enums: vec![],
+        sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![table("accounts.User", &["id"], vec![])],
+    };
+
+    let data_model = calculate_datamodel(&schema, &SqlFamily::Mysql, &Datamodel::new(), Default::default())
+        .expect("calculate data model")
+        .data_model;
+
+    let user = data_model.find_model("accounts_User").unwrap();
+    assert_eq!(user.database_name.as_deref(), Some("accounts.User"));
+    assert_eq!(
+        user.relation_fields()
+            .map(|field| field.relation_info.to.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Post"]
+    );
+
+    let author = data_model.find_model("Post").unwrap().relation_fields().next().unwrap();
+    assert_eq!(author.relation_info.to, "accounts_User");
+    assert_eq!(author.relation_info.fields, vec!["authorId".to_string()]);
+}
@@ -1,5 +1,7 @@
 use crate::*;
 use barrel::types;
+use datamodel::{FieldType, ScalarType};
+use native_types::MySqlType;
 use test_harness::*;
 
 #[test_each_connector(tags("mysql"))]
@@ -364,3 +366,49 @@ async fn introspecting_a_table_with_an_index_that_contains_expressions_should_ig
     let result = dbg!(api.introspect().await);
     custom_assert(&result, dm);
 }
+
+#[test_each_connector(tags("mysql"))]
+async fn introspecting_a_table_with_a_set_column_should_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute_with_schema(
+            |migration| {
+                migration.create_table("Test", |t| {
+                    t.inject_custom("id Integer  Not Null Primary Key");
+                    t.inject_custom("tags SET('red', 'green', 'blue') Not Null");
+                });
+            },
+            api.db_name(),
+        )
+        .await;
+
+    let dm = r#"
+            model Test {
+              id   Int    @id
+              tags String
+            }
+        "#;
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+
+    // The allowed values are only kept in the native type, when native types are asked for.
+    let data_model = api.introspect_with_native_types().await;
+    let field = data_model
+        .find_model("Test")
+        .unwrap()
+        .find_scalar_field("tags")
+        .unwrap();
+    let values = vec!["red".to_owned(), "green".to_owned(), "blue".to_owned()];
+
+    match &field.field_type {
+        FieldType::NativeType(ScalarType::String, native_type) => {
+            assert_eq!(native_type.name, "Set");
+            assert_eq!(native_type.string_args, values);
+            assert_eq!(
+                native_type.deserialize_native_type::<MySqlType>(),
+                MySqlType::Set(values)
+            );
+        }
+        other => panic!("Expected a String field with a native type, found {:?}", other),
+    }
+}
@@ -1,5 +1,6 @@
 use crate::*;
 use barrel::types;
+use introspection_connector::RelationFieldNaming;
 use quaint::prelude::Queryable;
 use test_harness::*;
 
@@ -704,3 +705,77 @@ async fn introspecting_relations_should_avoid_name_clashes_2(api: &TestApi) {
     let result = dbg!(api.introspect().await);
     custom_assert(&result, dm);
 }
+
+async fn setup_two_relations_between_the_same_models(api: &TestApi) {
+    let barrel = api.barrel();
+    barrel
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("Post", types::integer().nullable(true));
+            });
+            migration.create_table("Post", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("author_id", types::foreign("User", "id").nullable(false));
+                t.add_column("editor_id", types::foreign("User", "id").nullable(true));
+            });
+        })
+        .await;
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn relation_fields_can_be_named_after_the_referenced_model(api: &TestApi) {
+    setup_two_relations_between_the_same_models(api).await;
+
+    let dm = r#"
+        model Post {
+            id                  Int   @id @default(autoincrement())
+            author_id           Int
+            editor_id           Int?
+            User_author_id      User  @relation("Post_author_idToUser", fields: [author_id], references: [id])
+            User_editor_id      User? @relation("Post_editor_idToUser", fields: [editor_id], references: [id])
+        }
+
+        model User {
+            id                  Int    @id @default(autoincrement())
+            Post                Int?
+            Post_author_id      Post[] @relation("Post_author_idToUser")
+            Post_editor_id      Post[] @relation("Post_editor_idToUser")
+        }
+    "#;
+
+    let result = dbg!(
+        api.introspect_with_relation_field_naming(RelationFieldNaming::ReferencedModel)
+            .await
+    );
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn relation_fields_can_be_given_short_names(api: &TestApi) {
+    setup_two_relations_between_the_same_models(api).await;
+
+    // The scalar `Post` field keeps its name on `User`.
+    let dm = r#"
+        model Post {
+            id          Int   @id @default(autoincrement())
+            author_id   Int
+            editor_id   Int?
+            User        User  @relation("Post_author_idToUser", fields: [author_id], references: [id])
+            User2       User? @relation("Post_editor_idToUser", fields: [editor_id], references: [id])
+        }
+
+        model User {
+            id          Int    @id @default(autoincrement())
+            Post        Int?
+            Post2       Post[] @relation("Post_author_idToUser")
+            Post3       Post[] @relation("Post_editor_idToUser")
+        }
+    "#;
+
+    let result = dbg!(
+        api.introspect_with_relation_field_naming(RelationFieldNaming::Short)
+            .await
+    );
+    custom_assert(&result, dm);
+}
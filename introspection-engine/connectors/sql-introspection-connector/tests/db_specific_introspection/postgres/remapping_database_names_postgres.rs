@@ -72,6 +72,36 @@ async fn remapping_tables_with_invalid_characters_should_work(api: &TestApi) {
     custom_assert(&result, dm);
 }
 
+#[test_each_connector(tags("postgres"))]
+async fn remapping_names_with_invalid_characters_should_be_reported(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("?User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("h-a", types::text());
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model User {
+               id  Int    @id @default(autoincrement())
+               h_a String @map("h-a")
+
+               @@map("?User")
+            }
+        "#;
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+
+    let sanitized_names = dbg!(api.introspection_sanitized_names().await);
+    assert_eq!(
+        &sanitized_names,
+        "[{\"kind\":\"Model\",\"parent\":null,\"original\":\"?User\",\"sanitized\":\"User\",\"reason\":\"InvalidCharacters\"},{\"kind\":\"Field\",\"parent\":\"User\",\"original\":\"h-a\",\"sanitized\":\"h_a\",\"reason\":\"InvalidCharacters\"}]"
+    );
+}
+
 #[test_each_connector(tags("postgres"))]
 async fn remapping_fk_columns_with_invalid_characters_should_work(api: &TestApi) {
     let barrel = api.barrel();
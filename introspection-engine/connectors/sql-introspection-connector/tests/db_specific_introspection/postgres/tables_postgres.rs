@@ -368,7 +368,7 @@ async fn introspecting_default_values_should_work(api: &TestApi) {
                 id                  Int         @id @default(autoincrement())
                 numeric_int2        Int?            @default(2)
                 numeric_int4        Int?            @default(4)
-                numeric_int8        Int?            @default(8)
+                numeric_int8        BigInt?         @default(8)
                 numeric_decimal     Float?          @default(1234.1234)
                 numeric_float4      Float?          @default(123.1234)
                 numeric_float8      Float?          @default(123.1234)
@@ -408,7 +408,7 @@ async fn introspecting_a_default_value_as_dbgenerated_should_work(api: &TestApi)
                 t.inject_custom("int_static Integer DEFAULT 2");
                 t.inject_custom("int_serial Serial4");
                 t.inject_custom("int_function Integer DEFAULT EXTRACT(year from TIMESTAMP '2001-02-16 20:38:40')");
-                t.inject_custom("int_sequence Integer DEFAULT nextval('test_seq')"); // todo this is not recognized as autoincrement
+                t.inject_custom("int_sequence Integer DEFAULT nextval('test_seq')");
                 t.inject_custom("float_static Float DEFAULT 1.43");
                 t.inject_custom("boolean_static Boolean DEFAULT true");
                 t.inject_custom("datetime_now_current TIMESTAMP DEFAULT CURRENT_TIMESTAMP");
@@ -430,7 +430,7 @@ async fn introspecting_a_default_value_as_dbgenerated_should_work(api: &TestApi)
                 int_static              Int?        @default(2)
                 int_serial              Int        @default(autoincrement())
                 int_function            Int?        @default(dbgenerated())
-                int_sequence            Int?        @default(dbgenerated())
+                int_sequence            Int?        @default(sequence("test_seq"))
                 float_static            Float?      @default(1.43)
                 boolean_static          Boolean?    @default(true)
                 datetime_now_current    DateTime?   @default(now())
@@ -543,7 +543,7 @@ async fn introspecting_a_table_non_id_autoincrement_should_work(api: &TestApi) {
 }
 
 #[test_each_connector(tags("postgres"))]
-async fn introspecting_a_table_with_partial_indexes_should_ignore_them(api: &TestApi) {
+async fn introspecting_a_table_with_partial_indexes_should_keep_their_predicate(api: &TestApi) {
     let barrel = api.barrel();
     let _setup_schema = barrel
         .execute(|migration| {
@@ -569,6 +569,137 @@ async fn introspecting_a_table_with_partial_indexes_should_ignore_them(api: &Tes
               staticid Int
               islatest Boolean
               other    Int     @unique
+
+              @@unique([staticid], name: "idx_pages_unique_staticId_partial", where: "(islatest = true)")
+            }
+        "#;
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_a_table_with_range_and_interval_columns_should_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Reservation", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("seats int4range Not Null");
+                t.inject_custom("during tstzrange");
+                t.inject_custom("days daterange[]");
+                t.inject_custom("duration interval");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model Reservation {
+                id       Int      @id @default(autoincrement())
+                seats    String
+                during   String?
+                days     String[]
+                duration String?
+            }
+        "#;
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_a_table_with_xml_columns_should_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Document", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("body xml Not Null");
+                t.inject_custom("attachments xml[]");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model Document {
+                id          Int      @id @default(autoincrement())
+                body        String
+                attachments String[]
+            }
+        "#;
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_defaults_using_shared_sequences_should_work(api: &TestApi) {
+    let sql = format!(
+        r#"
+            CREATE SEQUENCE "{0}"."shared_ids";
+            CREATE TABLE "{0}"."Cat" (id INTEGER PRIMARY KEY DEFAULT nextval('"{0}"."shared_ids"'));
+            CREATE TABLE "{0}"."Dog" (id INTEGER PRIMARY KEY DEFAULT nextval('"{0}"."shared_ids"'));
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await.unwrap();
+
+    let dm = r#"
+            model Cat {
+                id Int @id @default(sequence("shared_ids"))
+            }
+
+            model Dog {
+                id Int @id @default(sequence("shared_ids"))
+            }
+        "#;
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"), ignore("postgres9"))]
+async fn introspecting_identity_columns_should_work(api: &TestApi) {
+    let sql = format!(
+        r#"
+            CREATE TABLE "{0}"."Cat" (id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY);
+            CREATE TABLE "{0}"."Dog" (id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY);
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await.unwrap();
+
+    let dm = r#"
+            model Cat {
+                /// GENERATED ALWAYS AS IDENTITY
+                id Int @id @default(autoincrement())
+            }
+
+            model Dog {
+                /// GENERATED BY DEFAULT AS IDENTITY
+                id BigInt @id @default(autoincrement())
+            }
+        "#;
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_a_table_with_text_search_columns_should_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Article", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("document tsvector Not Null");
+                t.inject_custom("saved_query tsquery");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model Article {
+                id          Int     @id @default(autoincrement())
+                document    String
+                saved_query String?
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -1,13 +1,18 @@
 use super::misc_helpers::*;
 use datamodel::Datamodel;
-use introspection_connector::{DatabaseMetadata, IntrospectionConnector, Version};
+use introspection_connector::{
+    DatabaseMetadata, IntrospectionConnector, IntrospectionOptions, RelationFieldNaming, Version,
+};
 use quaint::{
     prelude::{ConnectionInfo, SqlFamily},
     single::Quaint,
 };
 use sql_introspection_connector::SqlIntrospectionConnector;
 use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend};
-use test_setup::*;
+use test_setup::{
+    connectors::{ExternalConnector, ExternalConnectorFamily, TestApiFactory, TestApiFuture},
+    *,
+};
 
 pub type TestResult = Result<(), anyhow::Error>;
 
@@ -61,28 +66,65 @@ impl TestApi {
     pub async fn introspect(&self) -> String {
         let introspection_result = self
             .introspection_connector
-            .introspect(&Datamodel::new())
+            .introspect(&Datamodel::new(), IntrospectionOptions::default())
             .await
             .unwrap();
         datamodel::render_datamodel_to_string(&introspection_result.data_model).expect("Datamodel rendering failed")
     }
 
+    pub async fn introspect_with_relation_field_naming(&self, relation_field_naming: RelationFieldNaming) -> String {
+        let introspection_result = self
+            .introspection_connector
+            .introspect(
+                &Datamodel::new(),
+                IntrospectionOptions {
+                    relation_field_naming,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        datamodel::render_datamodel_to_string(&introspection_result.data_model).expect("Datamodel rendering failed")
+    }
+
+    pub async fn introspect_with_native_types(&self) -> Datamodel {
+        self.introspection_connector
+            .introspect(
+                &Datamodel::new(),
+                IntrospectionOptions {
+                    native_types: true,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap()
+            .data_model
+    }
+
     pub async fn re_introspect(&self, data_model_string: &str) -> String {
         let data_model = datamodel::parse_datamodel(data_model_string).unwrap();
-        let introspection_result = self.introspection_connector.introspect(&data_model).await.unwrap();
+        let introspection_result = self
+            .introspection_connector
+            .introspect(&data_model, IntrospectionOptions::default())
+            .await
+            .unwrap();
         datamodel::render_datamodel_to_string(&introspection_result.data_model).expect("Datamodel rendering failed")
     }
 
     pub async fn re_introspect_warnings(&self, data_model_string: &str) -> String {
         let data_model = datamodel::parse_datamodel(data_model_string).unwrap();
-        let introspection_result = self.introspection_connector.introspect(&data_model).await.unwrap();
+        let introspection_result = self
+            .introspection_connector
+            .introspect(&data_model, IntrospectionOptions::default())
+            .await
+            .unwrap();
         serde_json::to_string(&introspection_result.warnings).unwrap()
     }
 
     pub async fn introspect_version(&self) -> Version {
         let introspection_result = self
             .introspection_connector
-            .introspect(&Datamodel::new())
+            .introspect(&Datamodel::new(), IntrospectionOptions::default())
             .await
             .unwrap();
         introspection_result.version
@@ -91,12 +133,21 @@ impl TestApi {
     pub async fn introspection_warnings(&self) -> String {
         let introspection_result = self
             .introspection_connector
-            .introspect(&Datamodel::new())
+            .introspect(&Datamodel::new(), IntrospectionOptions::default())
             .await
             .unwrap();
         serde_json::to_string(&introspection_result.warnings).unwrap()
     }
 
+    pub async fn introspection_sanitized_names(&self) -> String {
+        let introspection_result = self
+            .introspection_connector
+            .introspect(&Datamodel::new(), IntrospectionOptions::default())
+            .await
+            .unwrap();
+        serde_json::to_string(&introspection_result.sanitized_names).unwrap()
+    }
+
     pub async fn get_metadata(&self) -> DatabaseMetadata {
         self.introspection_connector.get_metadata().await.unwrap()
     }
@@ -135,6 +186,49 @@ impl TestApi {
     }
 }
 
+impl TestApiFactory for TestApi {
+    fn external_test_api(connector: &'static ExternalConnector, test_name: &'static str) -> TestApiFuture<Self> {
+        Box::pin(async move {
+            let api = match connector.family() {
+                ExternalConnectorFamily::Postgres => {
+                    test_api_helper_for_postgres(connector.url(test_name), test_name).await
+                }
+                ExternalConnectorFamily::Mysql => {
+                    let db_name = test_setup::mysql_safe_identifier(test_name);
+                    let url = connector.url(db_name);
+                    let conn = create_mysql_database(&url.parse().unwrap()).await.unwrap();
+                    let introspection_connector = SqlIntrospectionConnector::new(&url).await.unwrap();
+
+                    TestApi {
+                        connection_info: conn.connection_info().to_owned(),
+                        db_name,
+                        database: conn,
+                        sql_family: SqlFamily::Mysql,
+                        introspection_connector,
+                    }
+                }
+                ExternalConnectorFamily::Sqlite => {
+                    let url = connector.url(test_name);
+                    let database = Quaint::new(&url).await.unwrap();
+                    let introspection_connector = SqlIntrospectionConnector::new(&url).await.unwrap();
+
+                    TestApi {
+                        db_name: test_name,
+                        connection_info: database.connection_info().to_owned(),
+                        database,
+                        sql_family: SqlFamily::Sqlite,
+                        introspection_connector,
+                    }
+                }
+                // The introspection tests can't describe SQL Server schemas yet.
+                ExternalConnectorFamily::Mssql => return Err(connector.unsupported_by("introspection")),
+            };
+
+            Ok(api)
+        })
+    }
+}
+
 pub async fn mysql_test_api(db_name: &'static str) -> TestApi {
     let db_name = test_setup::mysql_safe_identifier(db_name);
     let url = mysql_url(db_name.as_ref());
@@ -8,6 +8,9 @@ pub enum Error {
     #[error("Error in connector: {0}")]
     ConnectorError(ConnectorError),
 
+    #[error("Error in migration connector: {0}")]
+    MigrationConnectorError(migration_connector::ConnectorError),
+
     #[error("Failure during an introspection command: {0}")]
     CommandError(CommandError),
 
@@ -27,6 +30,12 @@ impl From<ConnectorError> for Error {
     }
 }
 
+impl From<migration_connector::ConnectorError> for Error {
+    fn from(e: migration_connector::ConnectorError) -> Self {
+        Error::MigrationConnectorError(e)
+    }
+}
+
 impl From<CommandError> for Error {
     fn from(e: CommandError) -> Self {
         Error::CommandError(e)
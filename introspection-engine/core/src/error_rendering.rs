@@ -11,6 +11,10 @@ pub fn render_error(crate_error: Error) -> UserFacingError {
             user_facing_error: Some(user_facing_error),
             ..
         }) => user_facing_error.into(),
+        Error::MigrationConnectorError(migration_connector::ConnectorError {
+            user_facing_error: Some(user_facing_error),
+            ..
+        }) => user_facing_error.into(),
         Error::CommandError(CommandError::IntrospectionResultEmpty(connection_string)) => {
             KnownError::new(IntrospectionResultEmpty { connection_string })
                 .unwrap()
@@ -2,6 +2,7 @@ mod command_error;
 mod error;
 mod error_rendering;
 mod rpc;
+mod unified_diff;
 
 pub use error::Error;
 pub use rpc::RpcImpl;
@@ -2,28 +2,90 @@ mod command_error;
 mod error;
 mod error_rendering;
 mod rpc;
+mod unified_diff;
 
+use introspection_connector::IntrospectionOptions;
 use jsonrpc_core::*;
 use rpc::{Rpc, RpcImpl};
+use std::path::{Path, PathBuf};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt, Clone)]
 #[structopt(version = env!("GIT_HASH"))]
-pub struct IntrospectionOpt {}
+pub struct IntrospectionOpt {
+    #[structopt(subcommand)]
+    cli_subcommand: Option<SubCommand>,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+enum SubCommand {
+    /// Introspect the database of the datasource of a schema once, instead of serving JSON-RPC. The
+    /// introspected schema is printed to stdout.
+    Introspect {
+        /// The path to the schema.
+        #[structopt(long, parse(from_os_str))]
+        schema: PathBuf,
+        /// Introspect the database even when the schema is not valid, discarding the models in it.
+        #[structopt(long)]
+        force: bool,
+        /// Overwrite the schema file with the introspected schema.
+        #[structopt(long, conflicts_with = "print-diff")]
+        write: bool,
+        /// Print the unified diff from the schema file to the introspected schema, as a patch.
+        #[structopt(long)]
+        print_diff: bool,
+    },
+}
 
 #[tokio::main]
 async fn main() {
     init_logger();
 
-    let _ = IntrospectionOpt::from_args();
+    let opts = IntrospectionOpt::from_args();
     user_facing_errors::set_panic_hook();
 
+    if let Some(SubCommand::Introspect {
+        schema,
+        force,
+        write,
+        print_diff,
+    }) = opts.cli_subcommand
+    {
+        if let Err(err) = introspect_once(&schema, force, write, print_diff).await {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+
+        return;
+    }
+
     let mut io_handler = IoHandler::new();
     io_handler.extend_with(RpcImpl::new().to_delegate());
 
     json_rpc_stdio::run(&io_handler).await.unwrap();
 }
 
+async fn introspect_once(schema_path: &Path, force: bool, write: bool, print_diff: bool) -> anyhow::Result<()> {
+    let schema = std::fs::read_to_string(schema_path)?;
+    let output = RpcImpl::introspect_internal(schema.clone(), force, IntrospectionOptions::default())
+        .await
+        .map_err(|err| anyhow::anyhow!("{}", err.data.map(|data| data.to_string()).unwrap_or(err.message)))?;
+
+    for warning in &output.warnings {
+        eprintln!("warning: {}", warning.message);
+    }
+
+    if write {
+        std::fs::write(schema_path, &output.datamodel)?;
+    } else if print_diff {
+        print!("{}", RpcImpl::schema_diff(&schema, &output.datamodel));
+    } else {
+        print!("{}", output.datamodel);
+    }
+
+    Ok(())
+}
+
 fn init_logger() {
     use tracing_subscriber::{EnvFilter, FmtSubscriber};
 
@@ -1,11 +1,17 @@
 use crate::command_error::CommandError;
 use crate::error::Error;
+use crate::unified_diff::unified_diff;
 use datamodel::{Configuration, Datamodel};
 use futures::{FutureExt, TryFutureExt};
-use introspection_connector::{ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionResultOutput};
+use introspection_connector::{
+    ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionOptions, IntrospectionResultOutput,
+    RelationFieldNaming, ReservedModelNaming,
+};
 use jsonrpc_derive::rpc;
+use migration_connector::{DiffTarget, MigrationConnector};
 use serde_derive::*;
 use sql_introspection_connector::SqlIntrospectionConnector;
+use sql_migration_connector::SqlMigrationConnector;
 
 type RpcError = jsonrpc_core::Error;
 type RpcResult<T> = Result<T, RpcError>;
@@ -49,7 +55,25 @@ impl Rpc for RpcImpl {
     }
 
     fn introspect(&self, input: IntrospectionInput) -> RpcFutureResult<IntrospectionResultOutput> {
-        Box::new(Self::introspect_internal(input.schema, input.force).boxed().compat())
+        let options = input.options();
+
+        Box::new(
+            async move {
+                let mut output = Self::introspect_internal(input.schema.clone(), input.force, options).await?;
+
+                if input.baseline_migration {
+                    output.baseline_migration = Some(Self::baseline_migration_internal(&input.schema).await?);
+                }
+
+                if input.print_diff {
+                    output.diff = Some(Self::schema_diff(&input.schema, &output.datamodel));
+                }
+
+                Ok(output)
+            }
+            .boxed()
+            .compat(),
+        )
     }
 }
 
@@ -67,9 +91,8 @@ impl RpcImpl {
             .datasources
             .first()
             .ok_or_else(|| CommandError::Generic(anyhow::anyhow!("There is no datasource in the schema.")))?
-            .url()
-            .to_owned()
-            .value;
+            .connection_url()
+            .to_owned();
 
         Ok((
             config,
@@ -85,9 +108,27 @@ impl RpcImpl {
         }
     }
 
-    pub async fn introspect_internal(schema: String, force: bool) -> RpcResult<IntrospectionResultOutput> {
+    pub async fn introspect_internal(
+        schema: String,
+        force: bool,
+        options: IntrospectionOptions,
+    ) -> RpcResult<IntrospectionResultOutput> {
         let (config, url, connector) = RpcImpl::load_connector(&schema).await?;
 
+        let options = IntrospectionOptions {
+            native_types: config
+                .datasources
+                .first()
+                .map(|datasource| {
+                    datasource
+                        .preview_features
+                        .iter()
+                        .any(|feature| feature == "nativeTypes")
+                })
+                .unwrap_or(false),
+            ..options
+        };
+
         let input_data_model = if !force {
             datamodel::parse_datamodel(&schema).map_err(|err| {
                 Error::from(CommandError::ReceivedBadDatamodel(
@@ -98,7 +139,7 @@ impl RpcImpl {
             Datamodel::new()
         };
 
-        let result = match connector.introspect(&input_data_model).await {
+        let result = match connector.introspect(&input_data_model, options).await {
             Ok(introspection_result) => {
                 if introspection_result.data_model.is_empty() {
                     Err(Error::from(CommandError::IntrospectionResultEmpty(url.to_string())))
@@ -109,6 +150,9 @@ impl RpcImpl {
                             datamodel: dm,
                             warnings: introspection_result.warnings,
                             version: introspection_result.version,
+                            sanitized_names: introspection_result.sanitized_names,
+                            baseline_migration: None,
+                            diff: None,
                         }),
                     }
                 }
@@ -119,6 +163,40 @@ impl RpcImpl {
         result.map_err(RpcError::from)
     }
 
+    /// Renders the migration script creating the current schema of the database from an empty
+    /// database, with the migration engine. Users adopting migrations on an existing database
+    /// start their migrations history with it.
+    pub async fn baseline_migration_internal(schema: &str) -> RpcResult<String> {
+        let url = datamodel::parse_configuration(schema)
+            .map_err(Error::from)?
+            .datasources
+            .first()
+            .ok_or_else(|| {
+                Error::from(CommandError::Generic(anyhow::anyhow!(
+                    "There is no datasource in the schema."
+                )))
+            })?
+            .connection_url()
+            .to_owned();
+
+        let connector = SqlMigrationConnector::new(&url).await.map_err(Error::from)?;
+        let migration = connector
+            .database_migration_inferrer()
+            .diff_with_auto_increment_values(DiffTarget::Empty, DiffTarget::Database(&url))
+            .await
+            .map_err(Error::from)?;
+        let diagnostics = connector.destructive_change_checker().pure_check(&migration);
+
+        Ok(connector
+            .database_migration_step_applier()
+            .render_script(&migration, &diagnostics))
+    }
+
+    /// The changes introspection makes to `schema`, as a patch applying with `git apply` or `patch -p1`.
+    pub fn schema_diff(schema: &str, introspected: &str) -> String {
+        unified_diff(schema, introspected, "a/schema.prisma", "b/schema.prisma")
+    }
+
     pub async fn list_databases_internal(schema: String) -> RpcResult<Vec<String>> {
         let (_, _, connector) = RpcImpl::load_connector(&schema).await?;
         RpcImpl::catch(connector.list_databases()).await
@@ -145,6 +223,37 @@ pub struct IntrospectionInput {
     pub(crate) schema: String,
     #[serde(default = "default_false")]
     pub(crate) force: bool,
+    #[serde(default, rename = "relationFieldNaming")]
+    pub(crate) relation_field_naming: RelationFieldNaming,
+    #[serde(default, rename = "uuidDefaults")]
+    pub(crate) uuid_defaults: bool,
+    #[serde(default, rename = "updatedAtHeuristics")]
+    pub(crate) updated_at_heuristics: bool,
+    #[serde(default)]
+    pub(crate) views: bool,
+    #[serde(default, rename = "explicitManyToMany")]
+    pub(crate) explicit_many_to_many: bool,
+    #[serde(default, rename = "reservedModelNaming")]
+    pub(crate) reserved_model_naming: ReservedModelNaming,
+    #[serde(default, rename = "baselineMigration")]
+    pub(crate) baseline_migration: bool,
+    #[serde(default, rename = "printDiff")]
+    pub(crate) print_diff: bool,
+}
+
+impl IntrospectionInput {
+    fn options(&self) -> IntrospectionOptions {
+        IntrospectionOptions {
+            relation_field_naming: self.relation_field_naming,
+            uuid_defaults: self.uuid_defaults,
+            updated_at_heuristics: self.updated_at_heuristics,
+            views: self.views,
+            // Set from the datasource, once the schema is parsed.
+            native_types: false,
+            explicit_many_to_many: self.explicit_many_to_many,
+            reserved_model_naming: self.reserved_model_naming,
+        }
+    }
 }
 
 fn default_false() -> bool {
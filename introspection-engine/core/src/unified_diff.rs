@@ -0,0 +1,185 @@
+//! Unified diffs of schemas, as `diff -u` renders them, to show what changed in the database since
+//! the previous introspection.
+
+/// The lines of context around the changes of a hunk.
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// The unified diff from `previous` to `next`, empty when they have the same lines.
+pub(crate) fn unified_diff(previous: &str, next: &str, previous_name: &str, next_name: &str) -> String {
+    let previous_lines: Vec<&str> = previous.lines().collect();
+    let next_lines: Vec<&str> = next.lines().collect();
+    let lines = diff_lines(&previous_lines, &next_lines);
+
+    if lines.iter().all(|line| matches!(line, Line::Same(_))) {
+        return String::new();
+    }
+
+    let mut out = format!("--- {}\n+++ {}\n", previous_name, next_name);
+
+    for (start, end) in hunks(&lines) {
+        let hunk = &lines[start..end];
+        let previous_start = lines[..start]
+            .iter()
+            .filter(|line| !matches!(line, Line::Added(_)))
+            .count();
+        let next_start = lines[..start]
+            .iter()
+            .filter(|line| !matches!(line, Line::Removed(_)))
+            .count();
+        let previous_len = hunk.iter().filter(|line| !matches!(line, Line::Added(_))).count();
+        let next_len = hunk.iter().filter(|line| !matches!(line, Line::Removed(_))).count();
+
+        out.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            hunk_range(previous_start, previous_len),
+            hunk_range(next_start, next_len)
+        ));
+
+        for line in hunk {
+            match line {
+                Line::Same(line) => out.push_str(&format!(" {}\n", line)),
+                Line::Removed(line) => out.push_str(&format!("-{}\n", line)),
+                Line::Added(line) => out.push_str(&format!("+{}\n", line)),
+            }
+        }
+    }
+
+    out
+}
+
+/// The range of a hunk in one of the files: 1-based, and pointing at the line before for empty ranges.
+fn hunk_range(start: usize, len: usize) -> String {
+    match len {
+        0 => format!("{},0", start),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, len),
+    }
+}
+
+/// The line ranges of the hunks: the changes, with their context, merged when their contexts overlap.
+fn hunks(lines: &[Line<'_>]) -> Vec<(usize, usize)> {
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+
+    for (idx, _) in lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Line::Same(_)))
+    {
+        let start = idx.saturating_sub(CONTEXT_LINES);
+        let end = (idx + 1 + CONTEXT_LINES).min(lines.len());
+
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    hunks
+}
+
+/// The longest common subsequence of the lines, after setting the common prefix and suffix aside.
+fn diff_lines<'a>(previous: &[&'a str], next: &[&'a str]) -> Vec<Line<'a>> {
+    let prefix = previous.iter().zip(next).take_while(|(a, b)| a == b).count();
+    let suffix = previous[prefix..]
+        .iter()
+        .rev()
+        .zip(next[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let old = &previous[prefix..previous.len() - suffix];
+    let new = &next[prefix..next.len() - suffix];
+
+    // lcs[i][j] is the length of the longest common subsequence of old[i..] and new[j..].
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines: Vec<Line<'a>> = previous[..prefix].iter().map(|line| Line::Same(line)).collect();
+    let (mut i, mut j) = (0, 0);
+
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(Line::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(Line::Removed(old[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(new[j]));
+            j += 1;
+        }
+    }
+
+    lines.extend(previous[previous.len() - suffix..].iter().map(|line| Line::Same(line)));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_schemas_have_an_empty_diff() {
+        let schema = "model A {\n  id Int @id\n}\n";
+
+        assert_eq!(unified_diff(schema, schema, "a", "b"), "");
+    }
+
+    #[test]
+    fn changes_are_rendered_in_hunks_with_context() {
+        let previous = "model User {\n  id    Int    @id\n  name  String\n}\n\nmodel Post {\n  id Int @id\n}\n";
+        let next =
+            "model User {\n  id    Int     @id\n  name  String\n  email String?\n}\n\nmodel Post {\n  id Int @id\n}\n";
+
+        let expected = [
+            "--- schema.prisma",
+            "+++ schema.prisma (introspected)",
+            "@@ -1,6 +1,7 @@",
+            " model User {",
+            "-  id    Int    @id",
+            "+  id    Int     @id",
+            "   name  String",
+            "+  email String?",
+            " }",
+            " ",
+            " model Post {",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            unified_diff(previous, next, "schema.prisma", "schema.prisma (introspected)"),
+            expected
+        );
+    }
+
+    #[test]
+    fn distant_changes_get_separate_hunks() {
+        let previous: Vec<String> = (1..=20).map(|n| format!("line {}", n)).collect();
+        let mut next = previous.clone();
+        next[1] = String::from("changed 2");
+        next.remove(17);
+
+        let diff = unified_diff(&previous.join("\n"), &next.join("\n"), "a", "b");
+        let headers: Vec<&str> = diff.lines().filter(|line| line.starts_with("@@")).collect();
+
+        assert_eq!(headers, vec!["@@ -1,5 +1,5 @@", "@@ -15,6 +15,5 @@"]);
+    }
+}
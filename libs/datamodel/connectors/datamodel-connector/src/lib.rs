@@ -31,6 +31,16 @@ pub trait Connector: Send + Sync {
     /// This powers the use of native types for QE + ME.
     fn parse_native_type(&self, name: &str, args: Vec<u32>) -> Result<NativeTypeInstance, ConnectorError>;
 
+    /// Same as `parse_native_type`, but for native types taking string arguments (e.g. the allowed values of a MySQL `SET`).
+    /// Only called for constructors that declare `takes_string_args`.
+    fn parse_native_type_with_string_args(
+        &self,
+        _name: &str,
+        _args: Vec<String>,
+    ) -> Result<NativeTypeInstance, ConnectorError> {
+        unreachable!("The core must only call this with names of native types taking string arguments.")
+    }
+
     /// This function is used during introspection to turn an introspected native type into an instance that can be put into the Prisma schema.
     /// powers IE
     fn introspect_native_type(&self, native_type: Box<dyn NativeType>) -> Result<NativeTypeInstance, ConnectorError>;
@@ -55,6 +65,10 @@ pub trait Connector: Send + Sync {
         self.has_capability(ConnectorCapability::Json)
     }
 
+    fn supports_decimal(&self) -> bool {
+        self.has_capability(ConnectorCapability::Decimal)
+    }
+
     fn supports_non_id_auto_increment(&self) -> bool {
         self.has_capability(ConnectorCapability::AutoIncrementAllowedOnNonId)
     }
@@ -66,6 +80,42 @@ pub trait Connector: Send + Sync {
     fn supports_non_indexed_auto_increment(&self) -> bool {
         self.has_capability(ConnectorCapability::AutoIncrementNonIndexedAllowed)
     }
+
+    fn supports_sequences(&self) -> bool {
+        self.has_capability(ConnectorCapability::Sequences)
+    }
+
+    fn supports_clustered_indexes(&self) -> bool {
+        self.has_capability(ConnectorCapability::ClusteredIndexes)
+    }
+
+    fn supports_nulls_not_distinct(&self) -> bool {
+        self.has_capability(ConnectorCapability::NullsNotDistinct)
+    }
+
+    fn supports_partial_indexes(&self) -> bool {
+        self.has_capability(ConnectorCapability::PartialIndexes)
+    }
+
+    fn supports_distributed_tables(&self) -> bool {
+        self.has_capability(ConnectorCapability::DistributedTables)
+    }
+
+    fn supports_temporal_tables(&self) -> bool {
+        self.has_capability(ConnectorCapability::TemporalTables)
+    }
+
+    fn supports_trailing_spaces_in_enum_values(&self) -> bool {
+        self.has_capability(ConnectorCapability::EnumValuesWithTrailingSpaces)
+    }
+
+    fn supports_restrict_referential_action(&self) -> bool {
+        self.has_capability(ConnectorCapability::ReferentialActionRestrict)
+    }
+
+    fn supports_set_default_referential_action(&self) -> bool {
+        self.has_capability(ConnectorCapability::ReferentialActionSetDefault)
+    }
 }
 
 /// Not all Databases are created equal. Hence connectors for our datasources support different capabilities.
@@ -77,16 +127,41 @@ pub enum ConnectorCapability {
     RelationsOverNonUniqueCriteria,
     MultipleIndexesWithSameName,
     Enums,
+    /// Enum values keep their trailing spaces. MySQL strips them from the values of `ENUM` columns.
+    EnumValuesWithTrailingSpaces,
     Json,
+    /// Decimal values are stored without losing precision.
+    Decimal,
     AutoIncrementAllowedOnNonId,
     AutoIncrementMultipleAllowed,
     AutoIncrementNonIndexedAllowed,
+    Sequences,
+    ClusteredIndexes,
+    /// Unique indexes can consider NULLs equal, allowing only one row with NULLs in their columns.
+    NullsNotDistinct,
+    /// Indexes can only cover the rows matching a condition.
+    PartialIndexes,
+    /// Tables can be distributed across the nodes of a cluster by a column, with Citus.
+    DistributedTables,
+    /// Tables can be system-versioned, keeping the previous versions of their rows in a history table.
+    TemporalTables,
+    ReferentialActionRestrict,
+    ReferentialActionSetDefault,
     // start of Query Engine Capabilities
     InsensitiveFilters,
+    UpsertMany,
+    /// Full-text search on any text column, without an index.
+    FullTextSearchWithoutIndex,
+    /// Full-text search on the columns of a full-text index.
+    FullTextSearchWithIndex,
+    /// Json filters address values with a path of keys and array indexes, e.g. `["a", "0"]`.
+    JsonFilteringArrayPath,
+    /// Json filters address values with a JSON path string, e.g. `$.a[0]`.
+    JsonFilteringJsonPath,
 }
 
 /// Contains all capabilities that the connector is able to serve.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct ConnectorCapabilities {
     capabilities: Vec<ConnectorCapability>,
 }
@@ -112,6 +187,8 @@ pub struct NativeTypeInstance {
     pub name: String,
     /// the arguments that were provided
     pub args: Vec<u32>,
+    /// the string arguments that were provided, for native types taking string arguments
+    pub string_args: Vec<String>,
     /// the serialized representation of this native type. The serialized format is generated by the `native-types` library
     serialized_native_type: serde_json::Value,
 }
@@ -121,6 +198,16 @@ impl NativeTypeInstance {
         NativeTypeInstance {
             name: name.to_string(),
             args,
+            string_args: vec![],
+            serialized_native_type: native_type.to_json(),
+        }
+    }
+
+    pub fn new_with_string_args(name: &str, string_args: Vec<String>, native_type: &dyn NativeType) -> Self {
+        NativeTypeInstance {
+            name: name.to_string(),
+            args: vec![],
+            string_args,
             serialized_native_type: native_type.to_json(),
         }
     }
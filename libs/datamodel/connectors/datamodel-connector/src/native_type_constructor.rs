@@ -9,6 +9,8 @@ pub struct NativeTypeConstructor {
     pub _number_of_args: usize,
     /// the number of optional arguments
     pub _number_of_optional_args: usize,
+    /// whether the native type takes an arbitrary, non-empty list of string arguments instead of numeric ones
+    pub takes_string_args: bool,
     /// the scalar type this native type is compatible with
     pub prisma_type: ScalarType,
 }
@@ -19,6 +21,7 @@ impl NativeTypeConstructor {
             name: name.to_string(),
             _number_of_args: 0,
             _number_of_optional_args: 0,
+            takes_string_args: false,
             prisma_type,
         }
     }
@@ -28,6 +31,7 @@ impl NativeTypeConstructor {
             name: name.to_string(),
             _number_of_args: number_of_args,
             _number_of_optional_args: 0,
+            takes_string_args: false,
             prisma_type,
         }
     }
@@ -41,6 +45,17 @@ impl NativeTypeConstructor {
             name: name.to_string(),
             _number_of_args: 0,
             _number_of_optional_args: number_of_optional_args,
+            takes_string_args: false,
+            prisma_type,
+        }
+    }
+
+    pub fn with_string_args(name: &str, prisma_type: ScalarType) -> NativeTypeConstructor {
+        NativeTypeConstructor {
+            name: name.to_string(),
+            _number_of_args: 1,
+            _number_of_optional_args: 0,
+            takes_string_args: true,
             prisma_type,
         }
     }
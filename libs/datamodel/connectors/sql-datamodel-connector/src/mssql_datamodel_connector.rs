@@ -10,9 +10,15 @@ pub struct MsSqlDatamodelConnector {
 impl MsSqlDatamodelConnector {
     pub fn new() -> MsSqlDatamodelConnector {
         let capabilities = vec![
+            ConnectorCapability::Decimal,
             ConnectorCapability::AutoIncrementAllowedOnNonId,
             ConnectorCapability::AutoIncrementMultipleAllowed,
             ConnectorCapability::AutoIncrementNonIndexedAllowed,
+            ConnectorCapability::ClusteredIndexes,
+            ConnectorCapability::NullsNotDistinct,
+            ConnectorCapability::PartialIndexes,
+            ConnectorCapability::TemporalTables,
+            ConnectorCapability::ReferentialActionSetDefault,
         ];
 
         let constructors: Vec<NativeTypeConstructor> = vec![];
@@ -36,6 +36,7 @@ const DATETIME_TYPE_NAME: &str = "Datetime";
 const TIMESTAMP_TYPE_NAME: &str = "Timestamp";
 const YEAR_TYPE_NAME: &str = "Year";
 const JSON_TYPE_NAME: &str = "JSON";
+const SET_TYPE_NAME: &str = "Set";
 
 pub struct MySqlDatamodelConnector {
     capabilities: Vec<ConnectorCapability>,
@@ -48,8 +49,13 @@ impl MySqlDatamodelConnector {
             ConnectorCapability::RelationsOverNonUniqueCriteria,
             ConnectorCapability::Enums,
             ConnectorCapability::Json,
+            ConnectorCapability::Decimal,
             ConnectorCapability::MultipleIndexesWithSameName,
             ConnectorCapability::AutoIncrementAllowedOnNonId,
+            ConnectorCapability::ReferentialActionRestrict,
+            ConnectorCapability::UpsertMany,
+            ConnectorCapability::JsonFilteringJsonPath,
+            ConnectorCapability::FullTextSearchWithIndex,
         ];
 
         let int = NativeTypeConstructor::without_args(INT_TYPE_NAME, ScalarType::Int);
@@ -85,6 +91,7 @@ impl MySqlDatamodelConnector {
         let timestamp = NativeTypeConstructor::with_optional_args(TIMESTAMP_TYPE_NAME, 1, ScalarType::DateTime);
         let year = NativeTypeConstructor::without_args(YEAR_TYPE_NAME, ScalarType::Int);
         let json = NativeTypeConstructor::without_args(JSON_TYPE_NAME, ScalarType::Json);
+        let set = NativeTypeConstructor::with_string_args(SET_TYPE_NAME, ScalarType::String);
 
         let constructors: Vec<NativeTypeConstructor> = vec![
             int,
@@ -120,6 +127,7 @@ impl MySqlDatamodelConnector {
             timestamp,
             year,
             json,
+            set,
         ];
 
         MySqlDatamodelConnector {
@@ -259,8 +267,30 @@ impl Connector for MySqlDatamodelConnector {
         ))
     }
 
+    fn parse_native_type_with_string_args(
+        &self,
+        name: &str,
+        args: Vec<String>,
+    ) -> Result<NativeTypeInstance, ConnectorError> {
+        let native_type = match name {
+            SET_TYPE_NAME => MySqlType::Set(args.clone()),
+            _ => unreachable!("This code is unreachable as the core must guarantee to just call with known names."),
+        };
+
+        Ok(NativeTypeInstance::new_with_string_args(name, args, &native_type))
+    }
+
     fn introspect_native_type(&self, native_type: Box<dyn NativeType>) -> Result<NativeTypeInstance, ConnectorError> {
         let native_type: MySqlType = serde_json::from_value(native_type.to_json()).unwrap();
+
+        if let MySqlType::Set(values) = &native_type {
+            return Ok(NativeTypeInstance::new_with_string_args(
+                SET_TYPE_NAME,
+                values.clone(),
+                &native_type,
+            ));
+        }
+
         let (constructor_name, args) = match native_type {
             MySqlType::Int => (INT_TYPE_NAME, vec![]),
             MySqlType::UnsignedInt => (UNSIGNED_INT_TYPE_NAME, vec![]),
@@ -304,6 +334,7 @@ impl Connector for MySqlDatamodelConnector {
             },
             MySqlType::Year => (YEAR_TYPE_NAME, vec![]),
             MySqlType::JSON => (JSON_TYPE_NAME, vec![]),
+            MySqlType::Set(_) => unreachable!("Handled above."),
         };
 
         if let Some(constructor) = self.find_native_type_constructor(constructor_name) {
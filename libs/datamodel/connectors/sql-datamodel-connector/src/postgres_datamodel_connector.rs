@@ -23,11 +23,19 @@ const DATE_TYPE_NAME: &str = "Date";
 const TIME_TYPE_NAME: &str = "Time";
 const TIME_WITH_TIMEZONE_TYPE_NAME: &str = "TimeWithTimeZone";
 const INTERVAL_TYPE_NAME: &str = "Interval";
+const INT4_RANGE_TYPE_NAME: &str = "Int4Range";
+const INT8_RANGE_TYPE_NAME: &str = "Int8Range";
+const NUM_RANGE_TYPE_NAME: &str = "NumRange";
+const TS_RANGE_TYPE_NAME: &str = "TsRange";
+const TS_TZ_RANGE_TYPE_NAME: &str = "TsTzRange";
+const DATE_RANGE_TYPE_NAME: &str = "DateRange";
 const BOOLEAN_TYPE_NAME: &str = "Boolean";
 const BIT_TYPE_NAME: &str = "Bit";
 const VAR_BIT_TYPE_NAME: &str = "VarBit";
 const UUID_TYPE_NAME: &str = "Uuid";
 const XML_TYPE_NAME: &str = "Xml";
+const TS_VECTOR_TYPE_NAME: &str = "TsVector";
+const TS_QUERY_TYPE_NAME: &str = "TsQuery";
 const JSON_TYPE_NAME: &str = "Json";
 const JSON_B_TYPE_NAME: &str = "JsonB";
 
@@ -41,11 +49,22 @@ impl PostgresDatamodelConnector {
         let capabilities = vec![
             ConnectorCapability::ScalarLists,
             ConnectorCapability::Enums,
+            ConnectorCapability::EnumValuesWithTrailingSpaces,
             ConnectorCapability::Json,
+            ConnectorCapability::Decimal,
             ConnectorCapability::AutoIncrementMultipleAllowed,
             ConnectorCapability::AutoIncrementAllowedOnNonId,
             ConnectorCapability::AutoIncrementNonIndexedAllowed,
+            ConnectorCapability::Sequences,
+            ConnectorCapability::NullsNotDistinct,
+            ConnectorCapability::PartialIndexes,
+            ConnectorCapability::DistributedTables,
+            ConnectorCapability::ReferentialActionRestrict,
+            ConnectorCapability::ReferentialActionSetDefault,
             ConnectorCapability::InsensitiveFilters,
+            ConnectorCapability::UpsertMany,
+            ConnectorCapability::JsonFilteringArrayPath,
+            ConnectorCapability::FullTextSearchWithoutIndex,
         ];
 
         let small_int = NativeTypeConstructor::without_args(SMALL_INT_TYPE_NAME, ScalarType::Int);
@@ -70,11 +89,21 @@ impl PostgresDatamodelConnector {
         let time_with_timezone =
             NativeTypeConstructor::with_args(TIME_WITH_TIMEZONE_TYPE_NAME, 1, ScalarType::DateTime);
         let interval = NativeTypeConstructor::with_args(INTERVAL_TYPE_NAME, 1, ScalarType::Duration);
+        // Ranges are represented by their text form, e.g. `[1,10)`.
+        let int4_range = NativeTypeConstructor::without_args(INT4_RANGE_TYPE_NAME, ScalarType::String);
+        let int8_range = NativeTypeConstructor::without_args(INT8_RANGE_TYPE_NAME, ScalarType::String);
+        let num_range = NativeTypeConstructor::without_args(NUM_RANGE_TYPE_NAME, ScalarType::String);
+        let ts_range = NativeTypeConstructor::without_args(TS_RANGE_TYPE_NAME, ScalarType::String);
+        let ts_tz_range = NativeTypeConstructor::without_args(TS_TZ_RANGE_TYPE_NAME, ScalarType::String);
+        let date_range = NativeTypeConstructor::without_args(DATE_RANGE_TYPE_NAME, ScalarType::String);
         let boolean = NativeTypeConstructor::without_args(BOOLEAN_TYPE_NAME, ScalarType::Boolean);
         let bit = NativeTypeConstructor::with_args(BIT_TYPE_NAME, 1, ScalarType::String);
         let varbit = NativeTypeConstructor::with_args(VAR_BIT_TYPE_NAME, 1, ScalarType::String);
         let uuid = NativeTypeConstructor::without_args(UUID_TYPE_NAME, ScalarType::String);
-        let xml = NativeTypeConstructor::without_args(XML_TYPE_NAME, ScalarType::XML);
+        let xml = NativeTypeConstructor::without_args(XML_TYPE_NAME, ScalarType::String);
+        // Text search documents and queries are represented by their text form, e.g. `'cat':1 'dog':2`.
+        let ts_vector = NativeTypeConstructor::without_args(TS_VECTOR_TYPE_NAME, ScalarType::String);
+        let ts_query = NativeTypeConstructor::without_args(TS_QUERY_TYPE_NAME, ScalarType::String);
         let json = NativeTypeConstructor::without_args(JSON_TYPE_NAME, ScalarType::Json);
         let json_b = NativeTypeConstructor::without_args(JSON_B_TYPE_NAME, ScalarType::Json);
 
@@ -99,11 +128,19 @@ impl PostgresDatamodelConnector {
             time,
             time_with_timezone,
             interval,
+            int4_range,
+            int8_range,
+            num_range,
+            ts_range,
+            ts_tz_range,
+            date_range,
             boolean,
             bit,
             varbit,
             uuid,
             xml,
+            ts_vector,
+            ts_query,
             json,
             json_b,
         ];
@@ -210,6 +247,12 @@ impl Connector for PostgresDatamodelConnector {
                     ));
                 }
             }
+            INT4_RANGE_TYPE_NAME => PostgresType::Int4Range,
+            INT8_RANGE_TYPE_NAME => PostgresType::Int8Range,
+            NUM_RANGE_TYPE_NAME => PostgresType::NumRange,
+            TS_RANGE_TYPE_NAME => PostgresType::TsRange,
+            TS_TZ_RANGE_TYPE_NAME => PostgresType::TsTzRange,
+            DATE_RANGE_TYPE_NAME => PostgresType::DateRange,
             DATE_TYPE_NAME => PostgresType::Date,
             TIME_TYPE_NAME => {
                 if let Some(arg) = args.first() {
@@ -250,6 +293,8 @@ impl Connector for PostgresDatamodelConnector {
             }
             UUID_TYPE_NAME => PostgresType::UUID,
             XML_TYPE_NAME => PostgresType::XML,
+            TS_VECTOR_TYPE_NAME => PostgresType::TsVector,
+            TS_QUERY_TYPE_NAME => PostgresType::TsQuery,
             JSON_TYPE_NAME => PostgresType::JSON,
             JSON_B_TYPE_NAME => PostgresType::JSONB,
             _ => unreachable!("This code is unreachable as the core must guarantee to just call with known names."),
@@ -285,11 +330,19 @@ impl Connector for PostgresDatamodelConnector {
             PostgresType::Time(x) => (TIME_TYPE_NAME, vec![x as u32]),
             PostgresType::TimeWithTimeZone(x) => (TIME_WITH_TIMEZONE_TYPE_NAME, vec![x as u32]),
             PostgresType::Interval(x) => (INTERVAL_TYPE_NAME, vec![x as u32]),
+            PostgresType::Int4Range => (INT4_RANGE_TYPE_NAME, vec![]),
+            PostgresType::Int8Range => (INT8_RANGE_TYPE_NAME, vec![]),
+            PostgresType::NumRange => (NUM_RANGE_TYPE_NAME, vec![]),
+            PostgresType::TsRange => (TS_RANGE_TYPE_NAME, vec![]),
+            PostgresType::TsTzRange => (TS_TZ_RANGE_TYPE_NAME, vec![]),
+            PostgresType::DateRange => (DATE_RANGE_TYPE_NAME, vec![]),
             PostgresType::Boolean => (BOOLEAN_TYPE_NAME, vec![]),
             PostgresType::Bit(x) => (BIT_TYPE_NAME, vec![x]),
             PostgresType::VarBit(x) => (VAR_BIT_TYPE_NAME, vec![x]),
             PostgresType::UUID => (UUID_TYPE_NAME, vec![]),
             PostgresType::XML => (XML_TYPE_NAME, vec![]),
+            PostgresType::TsVector => (TS_VECTOR_TYPE_NAME, vec![]),
+            PostgresType::TsQuery => (TS_QUERY_TYPE_NAME, vec![]),
             PostgresType::JSON => (JSON_TYPE_NAME, vec![]),
             PostgresType::JSONB => (JSON_B_TYPE_NAME, vec![]),
         };
@@ -9,7 +9,12 @@ pub struct SqliteDatamodelConnector {
 
 impl SqliteDatamodelConnector {
     pub fn new() -> SqliteDatamodelConnector {
-        let capabilities = vec![];
+        let capabilities = vec![
+            ConnectorCapability::PartialIndexes,
+            ConnectorCapability::ReferentialActionRestrict,
+            ConnectorCapability::ReferentialActionSetDefault,
+            ConnectorCapability::UpsertMany,
+        ];
         let constructors: Vec<NativeTypeConstructor> = vec![];
 
         SqliteDatamodelConnector {
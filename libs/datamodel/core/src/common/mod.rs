@@ -2,10 +2,12 @@
 //!
 mod default_names;
 mod name_normalizer;
+mod sqlite_in_memory;
 mod string_helper;
 
 pub mod provider_names;
 
 pub use default_names::RelationNames;
 pub use name_normalizer::NameNormalizer;
+pub use sqlite_in_memory::SqliteInMemory;
 pub use string_helper::WritableString;
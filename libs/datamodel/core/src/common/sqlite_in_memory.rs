@@ -0,0 +1,123 @@
+/// The SQLite URI parameters describing the database, the other parameters are for the engines.
+const URI_PARAMS: &[&str] = &["mode", "cache"];
+
+/// An in-memory SQLite database, from a datasource URL: `file::memory:`, or URIs with `mode=memory`
+/// like `file:dev?mode=memory&cache=shared`.
+///
+/// Quaint attaches the file path of the URL without its parameters, which makes every connection
+/// get a private database. With a shared cache (`cache=shared`), the engines attach `uri` in its
+/// place, for all connections of the process to share the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SqliteInMemory {
+    uri: String,
+    shared_cache: bool,
+}
+
+impl SqliteInMemory {
+    /// `None` for the URLs of database files.
+    pub fn from_url(url: &str) -> Option<Self> {
+        let (path, params) = split_url(url);
+        let (uri_params, _) = partition_params(params);
+
+        if !path.starts_with(":memory:") && !uri_params.contains(&"mode=memory") {
+            return None;
+        }
+
+        let uri = if uri_params.is_empty() {
+            format!("file:{}", path)
+        } else {
+            format!("file:{}?{}", path, uri_params.join("&"))
+        };
+
+        Some(Self {
+            shared_cache: uri_params.contains(&"cache=shared"),
+            uri,
+        })
+    }
+
+    /// The URI filename to attach the database with.
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    /// Whether all connections of the process share the database, instead of each connection having
+    /// its own.
+    pub fn is_shared(&self) -> bool {
+        self.shared_cache
+    }
+
+    /// The URL to open the connections with: a private in-memory database and the engine parameters
+    /// of `url`, which keeps quaint from creating a file named after the path of URIs.
+    pub fn connection_url(url: &str) -> String {
+        let (_, params) = split_url(url);
+        let (_, engine_params) = partition_params(params);
+
+        if engine_params.is_empty() {
+            "file::memory:".to_owned()
+        } else {
+            format!("file::memory:?{}", engine_params.join("&"))
+        }
+    }
+}
+
+/// The path and the parameters of a SQLite URL.
+fn split_url(url: &str) -> (&str, Option<&str>) {
+    let url = url.trim_start_matches("sqlite:").trim_start_matches("file:");
+    let mut splitted = url.splitn(2, '?');
+
+    (splitted.next().unwrap_or_default(), splitted.next())
+}
+
+/// The SQLite URI parameters, and the rest.
+fn partition_params(params: Option<&str>) -> (Vec<&str>, Vec<&str>) {
+    params
+        .into_iter()
+        .flat_map(|params| params.split('&'))
+        .filter(|param| !param.is_empty())
+        .partition(|param| URI_PARAMS.iter().any(|name| param.split('=').next() == Some(*name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn database_files_are_not_in_memory() {
+        assert_eq!(SqliteInMemory::from_url("file:./dev.db?connection_limit=1"), None);
+        assert_eq!(SqliteInMemory::from_url("sqlite:dev.db"), None);
+    }
+
+    #[test]
+    fn memory_paths_are_private_in_memory_databases() {
+        let db = SqliteInMemory::from_url("file::memory:?connection_limit=1").unwrap();
+
+        assert_eq!(db.uri(), "file::memory:");
+        assert!(!db.is_shared());
+    }
+
+    #[test]
+    fn shared_caches_are_shared_in_memory_databases() {
+        let db = SqliteInMemory::from_url("file::memory:?cache=shared").unwrap();
+
+        assert_eq!(db.uri(), "file::memory:?cache=shared");
+        assert!(db.is_shared());
+
+        let db = SqliteInMemory::from_url("file:dev?mode=memory&cache=shared&db_name=main").unwrap();
+
+        assert_eq!(db.uri(), "file:dev?mode=memory&cache=shared");
+        assert!(db.is_shared());
+    }
+
+    #[test]
+    fn connections_are_opened_in_memory_with_the_engine_params() {
+        assert_eq!(
+            SqliteInMemory::connection_url("file:dev?mode=memory&cache=shared&connection_limit=5"),
+            "file::memory:?connection_limit=5"
+        );
+
+        assert_eq!(
+            SqliteInMemory::connection_url("file::memory:?cache=shared"),
+            "file::memory:"
+        );
+    }
+}
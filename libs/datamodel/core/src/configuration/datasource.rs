@@ -9,13 +9,50 @@ pub struct Datasource {
     pub provider: Vec<String>,
     /// the provider that was selected as active from all specified providers
     pub active_provider: String,
+    /// the url as written in the schema or read from the environment, for rendering and serialization
     pub url: StringFromEnvVar,
+    /// the url the connectors connect with, with secrets like the `password_file` parameter resolved.
+    /// It must never be rendered or serialized.
+    pub(crate) connection_url: String,
     pub documentation: Option<String>,
     /// a connector representing the intersection of all providers specified
     pub combined_connector: Box<dyn Connector>,
     /// the connector of the active provider
     pub active_connector: Box<dyn Connector>,
     pub preview_features: Vec<String>,
+    /// how the integrity of relations is maintained
+    pub relation_mode: RelationMode,
+}
+
+/// How the integrity of relations is maintained, set with the `relationMode` argument of the datasource.
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum RelationMode {
+    /// Foreign keys in the database. The default.
+    ForeignKeys,
+    /// No foreign keys, Prisma emulates the relations. For databases without foreign keys, like Vitess.
+    Prisma,
+}
+
+impl RelationMode {
+    pub fn uses_foreign_keys(&self) -> bool {
+        matches!(self, RelationMode::ForeignKeys)
+    }
+}
+
+impl Default for RelationMode {
+    fn default() -> Self {
+        RelationMode::ForeignKeys
+    }
+}
+
+impl std::fmt::Display for RelationMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RelationMode::ForeignKeys => f.write_str("foreignKeys"),
+            RelationMode::Prisma => f.write_str("prisma"),
+        }
+    }
 }
 
 impl Datasource {
@@ -23,6 +60,12 @@ impl Datasource {
         &self.url
     }
 
+    /// The url to connect with. Unlike `url`, it contains the secrets read from files, so it is only for the
+    /// connectors.
+    pub fn connection_url(&self) -> &str {
+        &self.connection_url
+    }
+
     pub fn capabilities(&self) -> ConnectorCapabilities {
         let capabilities = self.active_connector.capabilities().clone();
         ConnectorCapabilities::new(capabilities)
@@ -1,9 +1,71 @@
 use super::ScalarType;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
 use prisma_value::PrismaValue;
-use std::fmt;
+use std::{fmt, sync::Mutex};
 use uuid::Uuid;
 
+static DETERMINISTIC_GENERATORS: Lazy<Mutex<Option<DeterministicGenerators>>> = Lazy::new(|| Mutex::new(None));
+
+/// Makes the `now()`, `uuid()` and `cuid()` generators deterministic for the rest of the process,
+/// for snapshot tests: `now()` always returns `now`, and UUIDs and CUIDs are drawn from a random
+/// number generator seeded with `seed`. Calling it again restarts the sequences.
+pub fn enable_deterministic_generators(seed: u64, now: DateTime<Utc>) {
+    *DETERMINISTIC_GENERATORS.lock().unwrap() = Some(DeterministicGenerators { now, rng_state: seed });
+}
+
+/// The current time, as returned by `now()`.
+pub fn now() -> DateTime<Utc> {
+    with_deterministic_generators(|generators| generators.now).unwrap_or_else(Utc::now)
+}
+
+fn with_deterministic_generators<T>(f: impl FnOnce(&mut DeterministicGenerators) -> T) -> Option<T> {
+    DETERMINISTIC_GENERATORS.lock().unwrap().as_mut().map(f)
+}
+
+struct DeterministicGenerators {
+    now: DateTime<Utc>,
+    rng_state: u64,
+}
+
+impl DeterministicGenerators {
+    /// SplitMix64. Not suitable for anything but test data.
+    fn next_u64(&mut self) -> u64 {
+        self.rng_state = self.rng_state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.rng_state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        z ^ (z >> 31)
+    }
+
+    fn uuid(&mut self) -> Uuid {
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&self.next_u64().to_be_bytes());
+        bytes[8..].copy_from_slice(&self.next_u64().to_be_bytes());
+
+        uuid::Builder::from_bytes(bytes)
+            .set_variant(uuid::Variant::RFC4122)
+            .set_version(uuid::Version::Random)
+            .build()
+    }
+
+    /// A string shaped like a CUID: `c`, followed by 24 lowercase base 36 digits.
+    fn cuid(&mut self) -> String {
+        const DIGITS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+        let mut cuid = String::with_capacity(25);
+        cuid.push('c');
+
+        for _ in 0..24 {
+            cuid.push(DIGITS[(self.next_u64() % 36) as usize] as char);
+        }
+
+        cuid
+    }
+}
+
 /// Represents a default specified on a field.
 #[derive(Clone, PartialEq)]
 pub enum DefaultValue {
@@ -38,6 +100,7 @@ pub struct ValueGenerator {
 impl ValueGenerator {
     pub fn new(name: String, args: Vec<PrismaValue>) -> std::result::Result<Self, String> {
         let generator = ValueGeneratorFn::new(name.as_ref())?;
+        generator.check_args(&args)?;
 
         Ok(ValueGenerator { name, args, generator })
     }
@@ -62,6 +125,22 @@ impl ValueGenerator {
         ValueGenerator::new("uuid".to_owned(), vec![]).unwrap()
     }
 
+    pub fn new_sequence(sequence_name: &str) -> Self {
+        ValueGenerator::new(
+            "sequence".to_owned(),
+            vec![PrismaValue::String(sequence_name.to_owned())],
+        )
+        .unwrap()
+    }
+
+    /// The name of the sequence the values come from, for `sequence()` defaults.
+    pub fn sequence_name(&self) -> Option<&str> {
+        match (&self.generator, self.args.first()) {
+            (ValueGeneratorFn::Sequence, Some(PrismaValue::String(name))) => Some(name),
+            _ => None,
+        }
+    }
+
     fn name(&self) -> &str {
         &self.name
     }
@@ -94,6 +173,7 @@ pub enum ValueGeneratorFn {
     Now,
     Autoincrement,
     DbGenerated,
+    Sequence,
 }
 
 impl ValueGeneratorFn {
@@ -104,10 +184,22 @@ impl ValueGeneratorFn {
             "now" => Ok(Self::Now),
             "autoincrement" => Ok(Self::Autoincrement),
             "dbgenerated" => Ok(Self::DbGenerated),
+            "sequence" => Ok(Self::Sequence),
             _ => Err(format!("The function {} is not a known function.", name)),
         }
     }
 
+    fn check_args(&self, args: &[PrismaValue]) -> std::result::Result<(), String> {
+        match (self, args) {
+            (Self::Sequence, [PrismaValue::String(name)]) if !name.is_empty() => Ok(()),
+            (Self::Sequence, _) => Err(
+                "The function `sequence()` takes the name of the sequence as its only argument, e.g. `sequence(\"user_ids\")`."
+                    .to_owned(),
+            ),
+            _ => Ok(()),
+        }
+    }
+
     fn invoke(&self) -> Option<PrismaValue> {
         match self {
             Self::UUID => Self::generate_uuid(),
@@ -115,6 +207,7 @@ impl ValueGeneratorFn {
             Self::Now => Self::generate_now(),
             Self::Autoincrement => None,
             Self::DbGenerated => None,
+            Self::Sequence => None,
         }
     }
 
@@ -124,21 +217,29 @@ impl ValueGeneratorFn {
             (Self::CUID, ScalarType::String) => true,
             (Self::Now, ScalarType::DateTime) => true,
             (Self::Autoincrement, ScalarType::Int) => true,
+            (Self::Autoincrement, ScalarType::BigInt) => true,
             (Self::DbGenerated, _) => true,
+            (Self::Sequence, ScalarType::Int) => true,
+            (Self::Sequence, ScalarType::BigInt) => true,
             _ => false,
         }
     }
 
     fn generate_cuid() -> Option<PrismaValue> {
-        Some(PrismaValue::String(cuid::cuid().unwrap()))
+        let cuid =
+            with_deterministic_generators(|generators| generators.cuid()).unwrap_or_else(|| cuid::cuid().unwrap());
+
+        Some(PrismaValue::String(cuid))
     }
 
     fn generate_uuid() -> Option<PrismaValue> {
-        Some(PrismaValue::Uuid(Uuid::new_v4()))
+        let uuid = with_deterministic_generators(|generators| generators.uuid()).unwrap_or_else(Uuid::new_v4);
+
+        Some(PrismaValue::Uuid(uuid))
     }
 
     fn generate_now() -> Option<PrismaValue> {
-        Some(PrismaValue::DateTime(Utc::now()))
+        Some(PrismaValue::DateTime(now()))
     }
 }
 
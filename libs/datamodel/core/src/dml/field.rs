@@ -128,6 +128,20 @@ impl Field {
         }
     }
 
+    pub fn is_read_only(&self) -> bool {
+        match &self {
+            Field::ScalarField(sf) => sf.is_read_only,
+            Field::RelationField(_) => false,
+        }
+    }
+
+    pub fn deprecation(&self) -> Option<&str> {
+        match &self {
+            Field::ScalarField(sf) => sf.deprecation.as_deref(),
+            Field::RelationField(rf) => rf.deprecation.as_deref(),
+        }
+    }
+
     pub fn is_unique(&self) -> bool {
         match &self {
             Field::ScalarField(sf) => sf.is_unique,
@@ -201,6 +215,9 @@ pub struct RelationField {
 
     /// Indicates if this field has to be commented out.
     pub is_commented_out: bool,
+
+    /// The reason the field is deprecated for, from `@deprecated`.
+    pub deprecation: Option<String>,
 }
 
 impl RelationField {
@@ -213,6 +230,7 @@ impl RelationField {
             documentation: None,
             is_generated: false,
             is_commented_out: false,
+            deprecation: None,
         }
     }
     /// Creates a new field with the given name and type, marked as generated and optional.
@@ -278,8 +296,15 @@ pub struct ScalarField {
     /// automatically.
     pub is_updated_at: bool,
 
+    /// Signals that the database computes the value of this field, e.g. for MySQL generated
+    /// columns. It can not be written to.
+    pub is_read_only: bool,
+
     /// Indicates if this field has to be commented out.
     pub is_commented_out: bool,
+
+    /// The reason the field is deprecated for, from `@deprecated`.
+    pub deprecation: Option<String>,
 }
 
 impl ScalarField {
@@ -296,7 +321,9 @@ impl ScalarField {
             documentation: None,
             is_generated: false,
             is_updated_at: false,
+            is_read_only: false,
             is_commented_out: false,
+            deprecation: None,
         }
     }
     /// Creates a new field with the given name and type, marked as generated and optional.
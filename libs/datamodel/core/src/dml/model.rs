@@ -22,6 +22,16 @@ pub struct Model {
     pub is_generated: bool,
     /// Indicates if this model has to be commented out.
     pub is_commented_out: bool,
+    /// The field the rows of this model are distributed by on Citus.
+    pub distribution_field: Option<String>,
+    /// Whether the primary key is clustered, if set explicitly. Only supported on SQL Server.
+    pub id_clustered: Option<bool>,
+    /// Indicates if this model is a system-versioned temporal table. Only supported on SQL Server.
+    pub is_temporal: bool,
+    /// The name of the history table of a temporal table, if set explicitly.
+    pub history_table_name: Option<String>,
+    /// The reason the operations of this model are deprecated for, from `@@deprecated`.
+    pub deprecation: Option<String>,
 }
 
 /// Represents an index defined via `@@index` or `@@unique`.
@@ -30,6 +40,14 @@ pub struct IndexDefinition {
     pub name: Option<String>,
     pub fields: Vec<String>,
     pub tpe: IndexType,
+    /// Whether the index is clustered, if set explicitly. Only supported on SQL Server.
+    pub clustered: Option<bool>,
+    /// Whether the unique index considers NULLs equal, so only one row can have NULLs in its
+    /// fields. Only supported on PostgreSQL 15 and SQL Server.
+    pub nulls_not_distinct: bool,
+    /// The SQL condition of a partial index, given in the `where` argument: only the rows matching
+    /// it are indexed. Only supported on PostgreSQL, SQL Server and SQLite.
+    pub predicate: Option<String>,
 }
 
 impl IndexDefinition {
@@ -39,6 +57,12 @@ impl IndexDefinition {
             _ => false,
         }
     }
+
+    /// Whether the index only covers the rows matching its predicate. A partial unique index
+    /// doesn't make its fields a unique criteria.
+    pub fn is_partial(&self) -> bool {
+        self.predicate.is_some()
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -72,6 +96,11 @@ impl Model {
             is_embedded: false,
             is_generated: false,
             is_commented_out: false,
+            distribution_field: None,
+            id_clustered: None,
+            is_temporal: false,
+            history_table_name: None,
+            deprecation: None,
         }
     }
 
@@ -236,7 +265,7 @@ impl Model {
             let mut unique_field_combi = self
                 .indices
                 .iter()
-                .filter(|id| id.tpe == IndexType::Unique)
+                .filter(|id| id.tpe == IndexType::Unique && !id.is_partial())
                 .filter_map(|id| {
                     let fields: Vec<_> = id.fields.iter().map(|f| self.find_scalar_field(&f).unwrap()).collect();
                     let no_fields_are_commented_out = !fields.iter().any(|f| f.is_commented_out);
@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 /// Holds information about a relation field.
 #[derive(Debug, Clone)]
 pub struct RelationInfo {
@@ -38,18 +40,50 @@ impl RelationInfo {
     }
 }
 
-/// Describes what happens when related nodes are deleted.
+/// Describes what happens when related nodes are deleted: the referential action of the foreign key.
 #[derive(Debug, Copy, PartialEq, Clone)]
 pub enum OnDeleteStrategy {
+    /// Deleting a referenced record fails.
+    Restrict,
+    /// Deleting a referenced record fails, unless the reference is gone when the constraint is checked.
+    NoAction,
+    /// Deleting a referenced record deletes the records referencing it.
     Cascade,
+    /// Deleting a referenced record sets the relation fields of the records referencing it to null.
+    SetNull,
+    /// Deleting a referenced record sets the relation fields of the records referencing it to their default.
+    SetDefault,
+    /// Not specified: the action is left to the connector.
     None,
 }
 
 impl ToString for OnDeleteStrategy {
     fn to_string(&self) -> String {
         match self {
+            OnDeleteStrategy::Restrict => String::from("RESTRICT"),
+            OnDeleteStrategy::NoAction => String::from("NO_ACTION"),
             OnDeleteStrategy::Cascade => String::from("CASCADE"),
+            OnDeleteStrategy::SetNull => String::from("SET_NULL"),
+            OnDeleteStrategy::SetDefault => String::from("SET_DEFAULT"),
             OnDeleteStrategy::None => String::from("NONE"),
         }
     }
 }
+
+impl FromStr for OnDeleteStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "RESTRICT" => Ok(OnDeleteStrategy::Restrict),
+            "NO_ACTION" => Ok(OnDeleteStrategy::NoAction),
+            "CASCADE" => Ok(OnDeleteStrategy::Cascade),
+            "SET_NULL" => Ok(OnDeleteStrategy::SetNull),
+            "SET_DEFAULT" => Ok(OnDeleteStrategy::SetDefault),
+            _ => Err(format!(
+                "Invalid referential action: `{}`. Expected one of `RESTRICT`, `NO_ACTION`, `CASCADE`, `SET_NULL` or `SET_DEFAULT`.",
+                s
+            )),
+        }
+    }
+}
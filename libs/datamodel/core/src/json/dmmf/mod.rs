@@ -33,6 +33,8 @@ pub struct Field {
     pub is_updated_at: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<String>,
 }
 
 #[serde(rename_all = "camelCase")]
@@ -56,6 +58,8 @@ pub struct Model {
     pub id_fields: Vec<String>,
     pub unique_fields: Vec<Vec<String>>,
     pub unique_indexes: Vec<UniqueIndex>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<String>,
 }
 
 #[serde(rename_all = "camelCase")]
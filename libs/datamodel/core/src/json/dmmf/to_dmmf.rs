@@ -86,6 +86,7 @@ fn model_to_dmmf(model: &dml::Model) -> Model {
                 }
             })
             .collect(),
+        deprecation: model.deprecation.clone(),
     }
 }
 
@@ -100,7 +101,7 @@ fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
         is_required: *field.arity() == dml::FieldArity::Required,
         is_list: *field.arity() == dml::FieldArity::List,
         is_id: field.is_id(),
-        is_read_only: a_relation_field_is_based_on_this_field,
+        is_read_only: a_relation_field_is_based_on_this_field || field.is_read_only(),
         has_default_value: field.default_value().is_some(),
         default: default_value_to_serde(&field.default_value().cloned()),
         is_unique: field.is_unique(),
@@ -112,6 +113,7 @@ fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
         is_generated: Some(field.is_generated()),
         is_updated_at: Some(field.is_updated_at()),
         documentation: field.documentation().map(|v| v.to_owned()),
+        deprecation: field.deprecation().map(|v| v.to_owned()),
     }
 }
 
@@ -144,6 +146,7 @@ fn prisma_value_to_serde(value: &PrismaValue) -> serde_json::Value {
         PrismaValue::Null => serde_json::Value::Null,
         PrismaValue::Uuid(val) => serde_json::Value::String(val.to_string()),
         PrismaValue::Json(val) => serde_json::Value::String(val.to_string()),
+        PrismaValue::Bytes(_) => serde_json::Value::String(value.to_string()),
         PrismaValue::List(value_vec) => {
             serde_json::Value::Array(value_vec.iter().map(|pv| prisma_value_to_serde(pv)).collect())
         }
@@ -1,4 +1,4 @@
-use crate::{configuration, StringFromEnvVar};
+use crate::{configuration, RelationMode, StringFromEnvVar};
 
 #[serde(rename_all = "camelCase")]
 #[derive(Debug, serde::Serialize)]
@@ -9,6 +9,9 @@ pub struct SourceConfig {
     pub url: StringFromEnvVar,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
+    /// Only set when it is not the default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relation_mode: Option<RelationMode>,
 }
 
 pub fn render_sources_to_json_value(sources: &[configuration::Datasource]) -> serde_json::Value {
@@ -38,5 +41,6 @@ fn source_to_json_struct(source: &configuration::Datasource) -> SourceConfig {
         active_provider: source.active_provider.to_string(),
         url: source.url().clone(),
         documentation: source.documentation.clone(),
+        relation_mode: Some(source.relation_mode).filter(|mode| !mode.uses_foreign_keys()),
     }
 }
@@ -4,12 +4,13 @@ use super::{
     builtin_datasource_providers::{MySqlDatasourceProvider, PostgresDatasourceProvider, SqliteDatasourceProvider},
     datasource_provider::DatasourceProvider,
 };
-use crate::configuration::StringFromEnvVar;
+use crate::configuration::{RelationMode, StringFromEnvVar};
 use crate::error::{DatamodelError, ErrorCollection};
 use crate::{ast, Datasource};
 use datamodel_connector::{CombinedConnector, Connector};
 
 const PREVIEW_FEATURES_KEY: &str = "previewFeatures";
+const RELATION_MODE_KEY: &str = "relationMode";
 
 /// Is responsible for loading and validating Datasources defined in an AST.
 pub struct DatasourceLoader {
@@ -132,12 +133,40 @@ impl DatasourceLoader {
             ));
         }
 
+        // The password read from a `password_file` must not end up in the rendered schema or the JSON of the
+        // configuration, so the resolved url is kept apart from the one of the schema.
+        let connection_url = if ignore_datasource_urls {
+            url.clone()
+        } else {
+            resolve_password_file(&url).map_err(|err_msg| {
+                DatamodelError::new_source_validation_error(&err_msg, source_name, url_args.span())
+            })?
+        };
+
         let preview_features_arg = args.arg(PREVIEW_FEATURES_KEY);
         let preview_features = match preview_features_arg.ok() {
             Some(x) => x.as_array().to_str_vec()?,
             None => Vec::new(),
         };
 
+        let relation_mode = match args.optional_arg(RELATION_MODE_KEY) {
+            Some(relation_mode_arg) => match relation_mode_arg.as_str()?.as_str() {
+                "foreignKeys" => RelationMode::ForeignKeys,
+                "prisma" => RelationMode::Prisma,
+                other => {
+                    return Err(DatamodelError::new_source_validation_error(
+                        &format!(
+                            "Invalid relation mode `{}`. The relation mode must be either `foreignKeys` or `prisma`.",
+                            other
+                        ),
+                        source_name,
+                        relation_mode_arg.span(),
+                    ))
+                }
+            },
+            None => RelationMode::default(),
+        };
+
         let documentation = ast_source.documentation.clone().map(|comment| comment.text);
         let url = StringFromEnvVar {
             from_env_var: env_var_for_url,
@@ -181,10 +210,12 @@ impl DatasourceLoader {
                 provider: providers,
                 active_provider: first_successful_provider.canonical_name().to_string(),
                 url,
+                connection_url,
                 documentation: documentation.clone(),
                 combined_connector,
                 active_connector: first_successful_provider.connector(),
                 preview_features,
+                relation_mode,
             })
         } else {
             Err(errors.into_iter().next().unwrap().err().unwrap())
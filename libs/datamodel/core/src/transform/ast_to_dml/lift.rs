@@ -230,20 +230,9 @@ impl<'a> LiftAstToDml<'a> {
 
                 let name = type_specification.map(|dir| dir.name.name.trim_start_matches(&prefix));
 
-                // convert arguments to u32 if possible
-                let number_args = type_specification.map(|dir| dir.arguments.clone());
-                let args = if let Some(number) = number_args {
-                    let p = number
-                        .iter()
-                        .map(|arg| ValueValidator::new(&arg.value).as_int())
-                        .collect_vec();
-                    if let Some(error) = p.iter().find(|arg| arg.is_err()) {
-                        return Err(error.clone().err().unwrap());
-                    }
-                    p.iter().map(|arg| *arg.as_ref().unwrap() as u32).collect_vec()
-                } else {
-                    vec![]
-                };
+                let arguments = type_specification
+                    .map(|dir| dir.arguments.clone())
+                    .unwrap_or_else(Vec::new);
 
                 if let Some(x) = name {
                     let constructor = if let Some(cons) = connector.find_native_type_constructor(x) {
@@ -259,17 +248,43 @@ impl<'a> LiftAstToDml<'a> {
                         ));
                     };
 
-                    let number_of_args = args.iter().count();
-                    if number_of_args < constructor._number_of_args
-                        || number_of_args > constructor._number_of_args + constructor._number_of_optional_args
-                    {
-                        return Err(DatamodelError::new_argument_count_missmatch_error(
-                            x,
-                            constructor._number_of_args,
-                            number_of_args,
-                            type_specification.unwrap().span,
-                        ));
-                    }
+                    // string arguments are passed on as-is, numeric arguments are converted to u32 if possible
+                    let (string_args, args) = if constructor.takes_string_args {
+                        let string_args = arguments
+                            .iter()
+                            .map(|arg| ValueValidator::new(&arg.value).as_str())
+                            .collect::<Result<Vec<String>, DatamodelError>>()?;
+
+                        if string_args.is_empty() {
+                            return Err(DatamodelError::new_argument_count_missmatch_error(
+                                x,
+                                constructor._number_of_args,
+                                0,
+                                type_specification.unwrap().span,
+                            ));
+                        }
+
+                        (Some(string_args), vec![])
+                    } else {
+                        let args = arguments
+                            .iter()
+                            .map(|arg| ValueValidator::new(&arg.value).as_int().map(|arg| arg as u32))
+                            .collect::<Result<Vec<u32>, DatamodelError>>()?;
+
+                        let number_of_args = args.len();
+                        if number_of_args < constructor._number_of_args
+                            || number_of_args > constructor._number_of_args + constructor._number_of_optional_args
+                        {
+                            return Err(DatamodelError::new_argument_count_missmatch_error(
+                                x,
+                                constructor._number_of_args,
+                                number_of_args,
+                                type_specification.unwrap().span,
+                            ));
+                        }
+
+                        (None, args)
+                    };
 
                     // check for compatability with scalar type
                     let compatable_prisma_scalar_type = constructor.prisma_type;
@@ -285,7 +300,11 @@ impl<'a> LiftAstToDml<'a> {
                         ));
                     }
 
-                    let parse_native_type_result = connector.parse_native_type(x, args);
+                    let parse_native_type_result = match string_args {
+                        Some(string_args) => connector.parse_native_type_with_string_args(x, string_args),
+                        None => connector.parse_native_type(x, args),
+                    };
+
                     match parse_native_type_result {
                         Err(connector_error) => {
                             return Err(DatamodelError::new_connector_error(
@@ -84,6 +84,42 @@ impl<'a> Validator<'a> {
                 errors_for_model.append(the_errors);
             }
 
+            if let Err(ref mut the_errors) =
+                self.validate_sequence_defaults(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
+            if let Err(ref mut the_errors) =
+                self.validate_clustered_indexes(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
+            if let Err(ref mut the_errors) =
+                self.validate_nulls_not_distinct(ast_schema.find_model(&model.name).expect(STATE_ERROR))
+            {
+                errors_for_model.append(the_errors);
+            }
+
+            if let Err(ref mut the_errors) =
+                self.validate_partial_indexes(ast_schema.find_model(&model.name).expect(STATE_ERROR))
+            {
+                errors_for_model.append(the_errors);
+            }
+
+            if let Err(ref mut the_errors) =
+                self.validate_distributed_by(ast_schema.find_model(&model.name).expect(STATE_ERROR))
+            {
+                errors_for_model.append(the_errors);
+            }
+
+            if let Err(ref mut the_errors) =
+                self.validate_temporal(ast_schema.find_model(&model.name).expect(STATE_ERROR))
+            {
+                errors_for_model.append(the_errors);
+            }
+
             if let Err(ref mut the_errors) = self.validate_base_fields_for_relation(
                 schema,
                 ast_schema.find_model(&model.name).expect(STATE_ERROR),
@@ -92,6 +128,12 @@ impl<'a> Validator<'a> {
                 errors_for_model.append(the_errors);
             }
 
+            if let Err(ref mut the_errors) =
+                self.validate_referential_actions(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
             if let Err(ref mut the_errors) = self.validate_referenced_fields_for_relation(
                 schema,
                 ast_schema.find_model(&model.name).expect(STATE_ERROR),
@@ -100,6 +142,12 @@ impl<'a> Validator<'a> {
                 errors_for_model.append(the_errors);
             }
 
+            if let Err(ref mut the_errors) =
+                self.validate_relation_fields_are_indexed(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
             //            if !errors_for_model.has_errors() {
             //                let mut new_errors = self.validate_relation_arguments_bla(
             //                    schema,
@@ -271,6 +319,25 @@ impl<'a> Validator<'a> {
                     ));
                 }
             }
+
+            // SQLite only has floating point numbers, the values would lose their precision.
+            if let Some(dml::ScalarType::Decimal) = field.field_type.scalar_type() {
+                let supports_decimal_type = match self.source {
+                    Some(source) => source.combined_connector.supports_decimal(),
+                    None => false,
+                };
+                if !supports_decimal_type {
+                    errors.push(DatamodelError::new_field_validation_error(
+                        &format!(
+                            "Field `{}` in model `{}` can't be of type Decimal. The current connector does not support the Decimal type.",
+                            &field.name, &model.name
+                        ),
+                        &model.name,
+                        &field.name,
+                        ast_model.find_field(&field.name).span,
+                    ));
+                }
+            }
         }
 
         if errors.has_errors() {
@@ -287,20 +354,39 @@ impl<'a> Validator<'a> {
         model: &dml::Model,
     ) -> Result<(), ErrorCollection> {
         let mut errors = ErrorCollection::new();
+        let trailing_spaces_are_supported = self
+            .source
+            .map(|source| source.combined_connector.supports_trailing_spaces_in_enum_values())
+            .unwrap_or(true);
 
         for field in model.scalar_fields() {
             if let Some(DefaultValue::Single(PrismaValue::Enum(enum_value))) = &field.default_value {
                 if let FieldType::Enum(enum_name) = &field.field_type {
                     if let Some(dml_enum) = data_model.find_enum(&enum_name) {
-                        if !dml_enum.values.iter().any(|value| &value.name == enum_value) {
-                            errors.push(DatamodelError::new_directive_validation_error(
+                        match dml_enum.values.iter().find(|value| &value.name == enum_value) {
+                            None => errors.push(DatamodelError::new_directive_validation_error(
                                 &format!(
                                 "{}",
                                 "The defined default value is not a valid value of the enum specified for the field."
                             ),
                                 "default",
                                 ast_model.find_field(&field.name).span,
-                            ))
+                            )),
+                            // The database would store the default without the spaces, which never
+                            // matches the value of the datamodel.
+                            Some(value)
+                                if !trailing_spaces_are_supported && value.final_database_name().ends_with(' ') =>
+                            {
+                                errors.push(DatamodelError::new_directive_validation_error(
+                                    &format!(
+                                        "The database name of the default value `{}` ends with spaces, which the datasource strips from enum values.",
+                                        value.name
+                                    ),
+                                    "default",
+                                    ast_model.find_field(&field.name).span,
+                                ))
+                            }
+                            Some(_) => (),
                         }
                     }
                 }
@@ -314,6 +400,172 @@ impl<'a> Validator<'a> {
         }
     }
 
+    fn validate_sequence_defaults(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        if let Some(data_source) = self.source {
+            if data_source.combined_connector.supports_sequences() {
+                return Ok(());
+            }
+
+            for field in model.scalar_fields() {
+                let uses_sequence = match &field.default_value {
+                    Some(dml::DefaultValue::Expression(generator)) => generator.sequence_name().is_some(),
+                    _ => false,
+                };
+
+                if uses_sequence {
+                    errors.push(DatamodelError::new_directive_validation_error(
+                        "The `sequence()` default value is used even though the datasource does not support sequences.",
+                        "default",
+                        ast_model.find_field(&field.name).span,
+                    ))
+                }
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn validate_clustered_indexes(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        if let Some(data_source) = self.source {
+            if !data_source.combined_connector.supports_clustered_indexes() {
+                for directive in &ast_model.directives {
+                    if directive.arguments.iter().any(|arg| arg.name.name == "clustered") {
+                        errors.push(DatamodelError::new_directive_validation_error(
+                            "The `clustered` argument is used even though the datasource does not support clustered indexes.",
+                            &directive.name.name,
+                            directive.span,
+                        ))
+                    }
+                }
+            }
+        }
+
+        let clustered_indexes = model
+            .indices
+            .iter()
+            .filter(|index| index.clustered == Some(true))
+            .count();
+        let clustered_id = if model.id_clustered == Some(true) { 1 } else { 0 };
+
+        if clustered_indexes + clustered_id > 1 {
+            errors.push(DatamodelError::new_model_validation_error(
+                "A model can only have one clustered index or primary key.",
+                &model.name,
+                ast_model.span,
+            ))
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn validate_nulls_not_distinct(&self, ast_model: &ast::Model) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        if let Some(data_source) = self.source {
+            if !data_source.combined_connector.supports_nulls_not_distinct() {
+                for directive in &ast_model.directives {
+                    if directive
+                        .arguments
+                        .iter()
+                        .any(|arg| arg.name.name == "nullsNotDistinct")
+                    {
+                        errors.push(DatamodelError::new_directive_validation_error(
+                            "The `nullsNotDistinct` argument is used even though the datasource does not support it.",
+                            &directive.name.name,
+                            directive.span,
+                        ))
+                    }
+                }
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn validate_partial_indexes(&self, ast_model: &ast::Model) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        if let Some(data_source) = self.source {
+            if !data_source.combined_connector.supports_partial_indexes() {
+                for directive in &ast_model.directives {
+                    if directive.arguments.iter().any(|arg| arg.name.name == "where") {
+                        errors.push(DatamodelError::new_directive_validation_error(
+                            "The `where` argument is used even though the datasource does not support partial indexes.",
+                            &directive.name.name,
+                            directive.span,
+                        ))
+                    }
+                }
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn validate_distributed_by(&self, ast_model: &ast::Model) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        if let Some(data_source) = self.source {
+            if !data_source.combined_connector.supports_distributed_tables() {
+                for directive in ast_model.directives.iter().filter(|d| d.name.name == "distributedBy") {
+                    errors.push(DatamodelError::new_directive_validation_error(
+                        "The table can not be distributed, the datasource does not support distributed tables.",
+                        &directive.name.name,
+                        directive.span,
+                    ))
+                }
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn validate_temporal(&self, ast_model: &ast::Model) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        if let Some(data_source) = self.source {
+            if !data_source.combined_connector.supports_temporal_tables() {
+                for directive in ast_model.directives.iter().filter(|d| d.name.name == "temporal") {
+                    errors.push(DatamodelError::new_directive_validation_error(
+                        "The table can not be temporal, the datasource does not support temporal tables.",
+                        &directive.name.name,
+                        directive.span,
+                    ))
+                }
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
     fn validate_auto_increment(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), ErrorCollection> {
         let mut errors = ErrorCollection::new();
 
@@ -372,6 +624,63 @@ impl<'a> Validator<'a> {
         }
     }
 
+    /// Without foreign keys, nothing creates the indexes on the fields of relations in the database, so they have to
+    /// be in the schema.
+    fn validate_relation_fields_are_indexed(
+        &self,
+        ast_model: &ast::Model,
+        model: &dml::Model,
+    ) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        let uses_foreign_keys = match self.source {
+            Some(source) => source.relation_mode.uses_foreign_keys(),
+            None => true,
+        };
+
+        if uses_foreign_keys {
+            return Ok(());
+        }
+
+        for field in model.relation_fields() {
+            let fields = &field.relation_info.fields;
+
+            if fields.is_empty() {
+                continue;
+            }
+
+            let is_single_unique_field = match fields.as_slice() {
+                [field_name] => model
+                    .find_scalar_field(field_name)
+                    .map(|field| field.is_id || field.is_unique)
+                    .unwrap_or(false),
+                _ => false,
+            };
+
+            let is_indexed = is_single_unique_field
+                || model.id_fields.starts_with(fields)
+                || model.indices.iter().any(|index| index.fields.starts_with(fields));
+
+            if !is_indexed {
+                errors.push(DatamodelError::new_directive_validation_error(
+                    &format!(
+                        "The fields of the relation `{}` must be indexed when the datasource uses `relationMode = \"prisma\"`. Add `@@index([{}])` to the model.",
+                        &field.name,
+                        fields.join(", ")
+                    ),
+                    RELATION_DIRECTIVE_NAME,
+                    ast_model.find_field(&field.name).span,
+                ));
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
     fn validate_model_has_strict_unique_criteria(
         &self,
         ast_model: &ast::Model,
@@ -496,6 +805,77 @@ impl<'a> Validator<'a> {
         Ok(())
     }
 
+    fn validate_referential_actions(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        for field in model.relation_fields() {
+            let on_delete = field.relation_info.on_delete;
+
+            if on_delete == dml::OnDeleteStrategy::None {
+                continue;
+            }
+
+            let ast_field = ast_model.find_field(&field.name);
+            let span = ast_field
+                .directives
+                .iter()
+                .find(|directive| directive.name.name == RELATION_DIRECTIVE_NAME)
+                .map(|directive| directive.span)
+                .unwrap_or(ast_field.span);
+            let mut push_error = |message: &str| {
+                errors.push(DatamodelError::new_directive_validation_error(
+                    message,
+                    RELATION_DIRECTIVE_NAME,
+                    span,
+                ))
+            };
+
+            if field.relation_info.fields.is_empty() {
+                push_error(
+                    "The `onDelete` argument can only be set on the side of the relation that defines `fields`.",
+                );
+                continue;
+            }
+
+            if let Some(data_source) = self.source {
+                let connector = &data_source.combined_connector;
+
+                let supported = match on_delete {
+                    dml::OnDeleteStrategy::Restrict => connector.supports_restrict_referential_action(),
+                    dml::OnDeleteStrategy::SetDefault => connector.supports_set_default_referential_action(),
+                    _ => true,
+                };
+
+                if !supported {
+                    push_error(&format!(
+                        "The referential action `{}` is not supported by the datasource.",
+                        on_delete.to_string()
+                    ));
+                }
+            }
+
+            let all_underlying_fields_are_optional = field
+                .relation_info
+                .fields
+                .iter()
+                .filter_map(|base_field| model.find_scalar_field(&base_field))
+                .all(|f| f.is_optional());
+
+            if on_delete == dml::OnDeleteStrategy::SetNull && !all_underlying_fields_are_optional {
+                push_error(&format!(
+                    "The referential action `SET_NULL` requires the fields of the relation to be optional, but the relation field `{}` uses required fields.",
+                    &field.name
+                ));
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
     fn validate_base_fields_for_relation(
         &self,
         _datamodel: &dml::Datamodel,
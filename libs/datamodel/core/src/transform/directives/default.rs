@@ -84,6 +84,7 @@ pub fn lower_prisma_value(pv: &PrismaValue) -> ast::Expression {
         PrismaValue::Null => ast::Expression::ConstantValue("null".to_string(), ast::Span::empty()),
         PrismaValue::Uuid(val) => ast::Expression::StringValue(val.to_string(), ast::Span::empty()),
         PrismaValue::Json(val) => ast::Expression::StringValue(val.to_string(), ast::Span::empty()),
+        PrismaValue::Bytes(_) => ast::Expression::StringValue(pv.to_string(), ast::Span::empty()),
         PrismaValue::List(vec) => ast::Expression::Array(
             vec.iter().map(|pv| lower_prisma_value(pv)).collect(),
             ast::Span::empty(),
@@ -0,0 +1,72 @@
+use super::{super::helpers::*, DirectiveValidator};
+use crate::error::DatamodelError;
+use crate::{ast, dml};
+
+/// Prismas builtin `@deprecated` directive, marking fields as deprecated in the generated API, with the reason
+/// clients should stop using them for.
+pub struct DeprecatedDirectiveValidator {}
+
+impl DirectiveValidator<dml::Field> for DeprecatedDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        &"deprecated"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        let reason = Some(args.default_arg("reason")?.as_str()?);
+
+        match obj {
+            dml::Field::ScalarField(sf) => sf.deprecation = reason,
+            dml::Field::RelationField(rf) => rf.deprecation = reason,
+        }
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        field: &dml::Field,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        Ok(serialize_deprecation(self.directive_name(), field.deprecation()))
+    }
+}
+
+/// Prismas builtin `@@deprecated` directive, marking the queries and mutations of models as deprecated in the
+/// generated API.
+pub struct ModelLevelDeprecatedDirectiveValidator {}
+
+impl DirectiveValidator<dml::Model> for ModelLevelDeprecatedDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        &"deprecated"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        obj.deprecation = Some(args.default_arg("reason")?.as_str()?);
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        Ok(serialize_deprecation(
+            self.directive_name(),
+            model.deprecation.as_deref(),
+        ))
+    }
+}
+
+fn serialize_deprecation(directive_name: &str, deprecation: Option<&str>) -> Vec<ast::Directive> {
+    match deprecation {
+        Some(reason) => vec![ast::Directive::new(
+            directive_name,
+            vec![ast::Argument::new_unnamed(ast::Expression::StringValue(
+                reason.to_owned(),
+                ast::Span::empty(),
+            ))],
+        )],
+        None => Vec::new(),
+    }
+}
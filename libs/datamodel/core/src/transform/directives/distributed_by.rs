@@ -0,0 +1,62 @@
+use super::{super::helpers::*, DirectiveValidator};
+use crate::error::DatamodelError;
+use crate::{ast, dml};
+
+/// Prismas builtin `@@distributedBy` directive, declaring the column a table is sharded by on Citus.
+pub struct DistributedByDirectiveValidator {}
+
+impl DirectiveValidator<dml::Model> for DistributedByDirectiveValidator {
+    fn directive_name(&self) -> &str {
+        "distributedBy"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let field_name = args.default_arg("field")?.as_constant_literal()?;
+
+        match obj.find_scalar_field(&field_name) {
+            Some(field) if field.arity == dml::FieldArity::List => Err(DatamodelError::new_model_validation_error(
+                &format!("The table can not be distributed by the list field `{}`.", field_name),
+                &obj.name,
+                args.span(),
+            )),
+            Some(_) => {
+                obj.distribution_field = Some(field_name);
+
+                Ok(())
+            }
+            None if obj.find_relation_field(&field_name).is_some() => {
+                Err(DatamodelError::new_model_validation_error(
+                    &format!(
+                        "The table can not be distributed by the relation field `{}`. Use one of its underlying scalar fields instead.",
+                        field_name
+                    ),
+                    &obj.name,
+                    args.span(),
+                ))
+            }
+            None => Err(DatamodelError::new_model_validation_error(
+                &format!("The table is distributed by the unknown field `{}`.", field_name),
+                &obj.name,
+                args.span(),
+            )),
+        }
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        if let Some(field_name) = &model.distribution_field {
+            return Ok(vec![ast::Directive::new(
+                self.directive_name(),
+                vec![ast::Argument::new_unnamed(ast::Expression::ConstantValue(
+                    field_name.clone(),
+                    ast::Span::empty(),
+                ))],
+            )]);
+        }
+
+        Ok(vec![])
+    }
+}
@@ -60,6 +60,11 @@ impl DirectiveValidator<dml::Model> for ModelLevelIdDirectiveValidator {
             .collect();
         obj.id_fields = fields;
 
+        obj.id_clustered = match args.optional_arg("clustered") {
+            Some(clustered_arg) => Some(clustered_arg.as_bool()?),
+            None => None,
+        };
+
         let undefined_fields: Vec<String> = obj
             .id_fields
             .iter()
@@ -143,6 +148,13 @@ impl DirectiveValidator<dml::Model> for ModelLevelIdDirectiveValidator {
                     .collect(),
             ));
 
+            if let Some(clustered) = model.id_clustered {
+                args.push(ast::Argument::new(
+                    "clustered",
+                    ast::Expression::BooleanValue(clustered.to_string(), ast::Span::empty()),
+                ));
+            }
+
             return Ok(vec![ast::Directive::new(self.directive_name(), args)]);
         }
 
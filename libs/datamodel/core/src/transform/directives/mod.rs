@@ -1,9 +1,13 @@
 mod default;
+mod deprecated;
 mod directive_list_validator;
 mod directive_validator;
+mod distributed_by;
 mod id;
 mod map;
+mod read_only;
 mod relation;
+mod temporal;
 mod unique_and_index;
 mod updated_at;
 
@@ -38,8 +42,10 @@ fn new_builtin_field_directives() -> DirectiveListValidator<dml::Field> {
     validator.add(Box::new(unique_and_index::FieldLevelUniqueDirectiveValidator {}));
     validator.add(Box::new(default::DefaultDirectiveValidator {}));
     validator.add(Box::new(updated_at::UpdatedAtDirectiveValidator {}));
+    validator.add(Box::new(read_only::ReadOnlyDirectiveValidator {}));
     validator.add(Box::new(map::MapDirectiveValidatorForField {}));
     validator.add(Box::new(relation::RelationDirectiveValidator {}));
+    validator.add(Box::new(deprecated::DeprecatedDirectiveValidator {}));
 
     validator
 }
@@ -52,6 +58,9 @@ fn new_builtin_model_directives() -> DirectiveListValidator<dml::Model> {
     validator.add(Box::new(unique_and_index::ModelLevelUniqueDirectiveValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelIndexDirectiveValidator {}));
     validator.add(Box::new(map::MapDirectiveValidator {}));
+    validator.add(Box::new(distributed_by::DistributedByDirectiveValidator {}));
+    validator.add(Box::new(temporal::TemporalDirectiveValidator {}));
+    validator.add(Box::new(deprecated::ModelLevelDeprecatedDirectiveValidator {}));
 
     validator
 }
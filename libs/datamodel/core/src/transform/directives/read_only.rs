@@ -0,0 +1,41 @@
+use super::{super::helpers::*, DirectiveValidator};
+use crate::error::DatamodelError;
+use crate::{ast, dml};
+
+/// Prismas builtin `@readOnly` directive, for fields the database computes the value of.
+pub struct ReadOnlyDirectiveValidator {}
+
+impl DirectiveValidator<dml::Field> for ReadOnlyDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        &"readOnly"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        if let dml::Field::ScalarField(sf) = obj {
+            if sf.is_updated_at {
+                return self.new_directive_validation_error(
+                    "Fields that are marked with @updatedAt can not be read-only.",
+                    args.span(),
+                );
+            }
+
+            sf.is_read_only = true;
+
+            return Ok(());
+        }
+
+        self.new_directive_validation_error("Relation fields can not be read-only.", args.span())
+    }
+
+    fn serialize(
+        &self,
+        field: &dml::Field,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        if field.is_read_only() {
+            Ok(vec![ast::Directive::new(self.directive_name(), Vec::new())])
+        } else {
+            Ok(vec![])
+        }
+    }
+}
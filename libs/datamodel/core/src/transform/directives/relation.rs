@@ -31,10 +31,14 @@ impl DirectiveValidator<dml::Field> for RelationDirectiveValidator {
                 rf.relation_info.fields = base_fields.as_array().to_literal_vec()?;
             }
 
-            // TODO: bring `onDelete` back once `prisma migrate` is a thing
-            //            if let Ok(on_delete) = args.arg("onDelete") {
-            //                relation_info.on_delete = on_delete.parse_literal::<dml::OnDeleteStrategy>()?;
-            //            }
+            if let Ok(on_delete) = args.arg("onDelete") {
+                rf.relation_info.on_delete = on_delete
+                    .as_constant_literal()?
+                    .parse::<dml::OnDeleteStrategy>()
+                    .map_err(|err| {
+                        DatamodelError::new_directive_validation_error(&err, self.directive_name(), on_delete.span())
+                    })?;
+            }
 
             Ok(())
         } else {
@@ -0,0 +1,43 @@
+use super::{super::helpers::*, DirectiveValidator};
+use crate::error::DatamodelError;
+use crate::{ast, dml};
+
+/// Prismas builtin `@@temporal` directive, declaring a system-versioned temporal table on SQL
+/// Server. The history table is created and managed by the migration engine.
+pub struct TemporalDirectiveValidator {}
+
+impl DirectiveValidator<dml::Model> for TemporalDirectiveValidator {
+    fn directive_name(&self) -> &str {
+        "temporal"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        obj.is_temporal = true;
+        obj.history_table_name = match args.optional_arg("historyTable") {
+            Some(history_table_arg) => Some(history_table_arg.as_str()?),
+            None => None,
+        };
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        if !model.is_temporal {
+            return Ok(vec![]);
+        }
+
+        let args = match &model.history_table_name {
+            Some(history_table_name) => vec![ast::Argument::new(
+                "historyTable",
+                ast::Expression::StringValue(history_table_name.clone(), ast::Span::empty()),
+            )],
+            None => Vec::new(),
+        };
+
+        Ok(vec![ast::Directive::new(self.directive_name(), args)])
+    }
+}
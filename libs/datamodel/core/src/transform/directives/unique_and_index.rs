@@ -134,6 +134,9 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
             name: None,
             fields: vec![],
             tpe: index_type,
+            clustered: None,
+            nulls_not_distinct: false,
+            predicate: None,
         };
         let name = match args.optional_arg("name") {
             Some(name_arg) => Some(name_arg.as_str()?),
@@ -141,6 +144,37 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
         };
         index_def.name = name;
 
+        index_def.clustered = match args.optional_arg("clustered") {
+            Some(clustered_arg) => Some(clustered_arg.as_bool()?),
+            None => None,
+        };
+
+        if let Some(nulls_not_distinct_arg) = args.optional_arg("nullsNotDistinct") {
+            if index_type != IndexType::Unique {
+                return Err(DatamodelError::new_model_validation_error(
+                    "The `nullsNotDistinct` argument is only supported on unique indexes.",
+                    &obj.name,
+                    args.span(),
+                ));
+            }
+
+            index_def.nulls_not_distinct = nulls_not_distinct_arg.as_bool()?;
+        }
+
+        if let Some(where_arg) = args.optional_arg("where") {
+            let predicate = where_arg.as_str()?;
+
+            if predicate.trim().is_empty() {
+                return Err(DatamodelError::new_model_validation_error(
+                    "The `where` argument of an index must be a non-empty SQL condition.",
+                    &obj.name,
+                    args.span(),
+                ));
+            }
+
+            index_def.predicate = Some(predicate);
+        }
+
         let fields = args
             .default_arg("fields")?
             .as_array()
@@ -258,6 +292,21 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
                 if let Some(name) = &index_def.name {
                     args.push(ast::Argument::new_string("name", &name));
                 }
+                if let Some(clustered) = index_def.clustered {
+                    args.push(ast::Argument::new(
+                        "clustered",
+                        ast::Expression::BooleanValue(clustered.to_string(), ast::Span::empty()),
+                    ));
+                }
+                if index_def.nulls_not_distinct {
+                    args.push(ast::Argument::new(
+                        "nullsNotDistinct",
+                        ast::Expression::BooleanValue(String::from("true"), ast::Span::empty()),
+                    ));
+                }
+                if let Some(predicate) = &index_def.predicate {
+                    args.push(ast::Argument::new_string("where", &predicate));
+                }
 
                 ast::Directive::new(self.directive_name(), args)
             })
@@ -41,6 +41,13 @@ impl DatasourceSerializer {
             arguments.push(ast::Argument::new_array("previewFeatures", features));
         }
 
+        if !source.relation_mode.uses_foreign_keys() {
+            arguments.push(ast::Argument::new_string(
+                "relationMode",
+                &source.relation_mode.to_string(),
+            ));
+        }
+
         ast::SourceConfig {
             name: ast::Identifier::new(&source.name),
             properties: arguments,
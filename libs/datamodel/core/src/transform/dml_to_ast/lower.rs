@@ -100,6 +100,12 @@ impl<'a> LowerDmlToAst<'a> {
                             Span::empty(),
                         )));
                     }
+                    for arg in native_tpe.string_args {
+                        arguments.push(ast::Argument::new_unnamed(ast::Expression::StringValue(
+                            arg,
+                            Span::empty(),
+                        )));
+                    }
                     directives.push(ast::Directive::new(new_directive_name.as_str(), arguments));
                 }
             }
@@ -1,6 +1,6 @@
 use crate::ast;
 use crate::error::DatamodelError;
-use crate::transform::helpers::ValueValidator;
+use crate::transform::helpers::{read_secret_file, ValueValidator};
 
 pub struct EnvFunction {
     var_name: String,
@@ -44,15 +44,30 @@ impl EnvFunction {
         &self.var_name
     }
 
+    /// Reads the variable. When it is not set, the value is read from the file named by the
+    /// `<VAR>_FILE` variable instead, following the convention of Docker and Kubernetes secrets.
     pub fn evaluate(&self) -> Result<ValueValidator, DatamodelError> {
-        if let Ok(var) = std::env::var(&self.var_name) {
-            let value_validator = ValueValidator::new(&ast::Expression::StringValue(var, self.span));
-            Ok(value_validator)
+        let file_var_name = format!("{}_FILE", self.var_name);
+
+        let value = if let Ok(var) = std::env::var(&self.var_name) {
+            var
+        } else if let Ok(path) = std::env::var(&file_var_name) {
+            read_secret_file(&path).map_err(|err| {
+                DatamodelError::new_functional_evaluation_error(
+                    &format!(
+                        "Could not read the file `{}` given in the environment variable `{}`: {}",
+                        path, file_var_name, err
+                    ),
+                    self.span,
+                )
+            })?
         } else {
-            Err(DatamodelError::new_environment_functional_evaluation_error(
+            return Err(DatamodelError::new_environment_functional_evaluation_error(
                 &self.var_name,
                 self.span,
-            ))
-        }
+            ));
+        };
+
+        Ok(ValueValidator::new(&ast::Expression::StringValue(value, self.span)))
     }
 }
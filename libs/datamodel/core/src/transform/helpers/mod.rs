@@ -1,6 +1,8 @@
 mod arguments;
 mod env_function;
+mod secret_files;
 mod value_validator;
 
 pub use arguments::Arguments;
+pub use secret_files::{read_secret_file, resolve_password_file};
 pub use value_validator::{ValueListValidator, ValueValidator};
@@ -0,0 +1,103 @@
+//! Credentials read from files, like the secrets mounted by Docker and Kubernetes.
+
+use url::Url;
+
+/// The connection string parameter pointing to a file containing the database password.
+pub const PASSWORD_FILE_PARAM: &str = "password_file";
+
+/// Reads a secret file. Secrets mounted from files usually end with a newline, it is stripped.
+pub fn read_secret_file(path: &str) -> std::io::Result<String> {
+    let contents = std::fs::read_to_string(path)?;
+
+    Ok(contents.trim_end_matches(|c| c == '\n' || c == '\r').to_owned())
+}
+
+/// Replaces the `password_file` parameter of a connection string with the password read from the
+/// file it points to. Connection strings without the parameter are returned unchanged.
+pub fn resolve_password_file(url: &str) -> Result<String, String> {
+    if url.starts_with("sqlserver:") {
+        resolve_sql_server_password_file(url)
+    } else {
+        resolve_url_password_file(url)
+    }
+}
+
+fn resolve_url_password_file(url: &str) -> Result<String, String> {
+    let mut parsed = match Url::parse(url) {
+        Ok(parsed) => parsed,
+        // Not our job to validate the URL.
+        Err(_) => return Ok(url.to_owned()),
+    };
+
+    let path = match parsed.query_pairs().find(|(key, _)| key == PASSWORD_FILE_PARAM) {
+        Some((_, path)) => path.into_owned(),
+        None => return Ok(url.to_owned()),
+    };
+
+    if parsed.username().is_empty() {
+        return Err(format!(
+            "The `{}` parameter requires a user in the connection string.",
+            PASSWORD_FILE_PARAM
+        ));
+    }
+
+    let password = read_password_file(&path)?;
+
+    let remaining_params: Vec<(String, String)> = parsed
+        .query_pairs()
+        .filter(|(key, _)| key != PASSWORD_FILE_PARAM)
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+
+    if remaining_params.is_empty() {
+        parsed.set_query(None);
+    } else {
+        parsed.query_pairs_mut().clear().extend_pairs(remaining_params);
+    }
+
+    parsed
+        .set_password(Some(&password))
+        .map_err(|_| format!("The connection string `{}` can not have a password.", url))?;
+
+    Ok(parsed.to_string())
+}
+
+/// SQL Server connection strings are not URLs: `sqlserver://host:port;key=value;key=value`.
+fn resolve_sql_server_password_file(url: &str) -> Result<String, String> {
+    let mut parts: Vec<String> = url.split(';').map(String::from).collect();
+
+    let position = parts.iter().skip(1).position(|part| {
+        part.splitn(2, '=')
+            .next()
+            .map(|key| key.trim() == PASSWORD_FILE_PARAM)
+            .unwrap_or(false)
+    });
+
+    let position = match position {
+        Some(position) => position + 1,
+        None => return Ok(url.to_owned()),
+    };
+
+    let path = parts[position].splitn(2, '=').nth(1).unwrap_or("").trim().to_owned();
+    let password = read_password_file(&path)?;
+
+    // Values containing separators must be wrapped in braces, closing braces are doubled.
+    let password = if password.contains(|c| c == ';' || c == '=' || c == '{' || c == '}') {
+        format!("{{{}}}", password.replace('}', "}}"))
+    } else {
+        password
+    };
+
+    parts[position] = format!("password={}", password);
+
+    Ok(parts.join(";"))
+}
+
+fn read_password_file(path: &str) -> Result<String, String> {
+    read_secret_file(path).map_err(|err| {
+        format!(
+            "Could not read the password file `{}` given in the `{}` parameter: {}",
+            path, PASSWORD_FILE_PARAM, err
+        )
+    })
+}
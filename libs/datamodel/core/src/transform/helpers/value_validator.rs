@@ -53,8 +53,8 @@ impl ValueValidator {
     /// to a given prisma type.
     pub fn as_type(&self, scalar_type: ScalarType) -> Result<PrismaValue, DatamodelError> {
         match scalar_type {
-            ScalarType::Int => self.as_int().map(PrismaValue::Int),
-            ScalarType::Float => self.as_float().map(PrismaValue::Float),
+            ScalarType::Int | ScalarType::BigInt => self.as_int().map(PrismaValue::Int),
+            ScalarType::Float | ScalarType::Decimal => self.as_float().map(PrismaValue::Float),
             ScalarType::Boolean => self.as_bool().map(PrismaValue::Boolean),
             ScalarType::DateTime => self.as_date_time().map(PrismaValue::DateTime),
             ScalarType::String => self.as_str().map(PrismaValue::String),
@@ -172,8 +172,8 @@ impl ValueValidator {
 
     pub fn as_default_value_for_scalar_type(&self, scalar_type: ScalarType) -> Result<DefaultValue, DatamodelError> {
         match &self.value {
-            ast::Expression::Function(name, _, _) => {
-                let generator = self.get_value_generator(&name)?;
+            ast::Expression::Function(name, args, _) => {
+                let generator = self.get_value_generator(&name, &args)?;
                 generator
                     .check_compatibility_with_scalar_type(scalar_type)
                     .map_err(|err_msg| DatamodelError::new_functional_evaluation_error(&err_msg, self.span()))?;
@@ -188,13 +188,22 @@ impl ValueValidator {
 
     pub fn as_value_generator(&self) -> Result<ValueGenerator, DatamodelError> {
         match &self.value {
-            ast::Expression::Function(name, _, _) => self.get_value_generator(&name),
+            ast::Expression::Function(name, args, _) => self.get_value_generator(&name, &args),
             _ => Err(self.construct_type_mismatch_error("function")),
         }
     }
 
-    fn get_value_generator(&self, name: &str) -> Result<ValueGenerator, DatamodelError> {
-        ValueGenerator::new(name.to_string(), vec![])
+    fn get_value_generator(&self, name: &str, args: &[ast::Expression]) -> Result<ValueGenerator, DatamodelError> {
+        // Only `sequence()` uses its arguments, those of the other functions are ignored.
+        let args = if name == "sequence" {
+            args.iter()
+                .map(|arg| ValueValidator::new(arg).as_str().map(PrismaValue::String))
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            Vec::new()
+        };
+
+        ValueGenerator::new(name.to_string(), args)
             .map_err(|err_msg| DatamodelError::new_functional_evaluation_error(&err_msg, self.span()))
     }
 }
@@ -2,8 +2,8 @@
 //! The most prominent functionality is the pain free navigation of relations.
 use crate::{
     dml::{
-        Datamodel, DefaultValue, Enum, FieldArity, FieldType, IndexDefinition, Model, ScalarField, ScalarType,
-        WithDatabaseName,
+        Datamodel, DefaultValue, Enum, FieldArity, FieldType, IndexDefinition, Model, OnDeleteStrategy, ScalarField,
+        ScalarType, WithDatabaseName,
     },
     RelationField,
 };
@@ -101,6 +101,32 @@ impl<'a> ModelWalker<'a> {
         })
     }
 
+    pub fn distribution_field(&self) -> Option<ScalarFieldWalker<'a>> {
+        self.model
+            .distribution_field
+            .as_ref()
+            .and_then(|field_name| self.find_scalar_field(field_name))
+    }
+
+    pub fn id_clustered(&self) -> Option<bool> {
+        self.model.id_clustered
+    }
+
+    /// The name of the history table, if the model is a temporal table. Defaults to the name of
+    /// the table suffixed with `History`.
+    pub fn history_table_name(&self) -> Option<String> {
+        if !self.model.is_temporal {
+            return None;
+        }
+
+        Some(
+            self.model
+                .history_table_name
+                .clone()
+                .unwrap_or_else(|| format!("{}History", self.database_name())),
+        )
+    }
+
     pub fn indexes<'b>(&'b self) -> impl Iterator<Item = &'a IndexDefinition> + 'b {
         self.model.indices.iter()
     }
@@ -290,6 +316,11 @@ impl<'a> RelationFieldWalker<'a> {
         self.field.relation_info.name.as_ref()
     }
 
+    /// The referential action set with `onDelete`, `OnDeleteStrategy::None` when not specified.
+    pub fn on_delete(&self) -> OnDeleteStrategy {
+        self.field.relation_info.on_delete
+    }
+
     pub fn referenced_table_name(&self) -> &'a str {
         self.referenced_model().final_database_name()
     }
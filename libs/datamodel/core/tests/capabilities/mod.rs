@@ -137,6 +137,29 @@ fn test_json_support(providers: &[&str], must_error: bool) {
     test_capability_support(providers, must_error, dml, error_msg);
 }
 
+#[test]
+fn decimal_must_only_be_supported_if_all_specified_providers_support_them() {
+    // SQLite only has floating point numbers.
+    test_decimal_support(&["postgres", "sqlite", "mysql"], true);
+    test_decimal_support(&["sqlite"], true);
+    test_decimal_support(&["postgres", "mysql"], false);
+    test_decimal_support(&["postgres"], false);
+    test_decimal_support(&["mysql"], false);
+    test_decimal_support(&["sqlserver"], false);
+}
+
+fn test_decimal_support(providers: &[&str], must_error: bool) {
+    let dml = r#"
+    model Invoice {
+      id     Int      @id
+      total  Decimal
+    }
+    "#;
+
+    let error_msg = "Error validating field `total` in model `Invoice`: Field `total` in model `Invoice` can\'t be of type Decimal. The current connector does not support the Decimal type.";
+    test_capability_support(providers, must_error, dml, error_msg);
+}
+
 #[test]
 fn relations_over_non_unique_criteria_must_only_be_supported_if_all_specified_providers_support_them() {
     // Only MySQL supports that.
@@ -204,6 +227,147 @@ fn test_auto_increment_on_non_primary_columns(providers: &[&str], must_error: bo
     test_capability_support(providers, must_error, dml, error_msg);
 }
 
+#[test]
+fn sequences_must_only_be_supported_if_all_specified_providers_support_them() {
+    // Only Postgres supports sequences.
+    test_sequence_support(&["postgres", "sqlite", "mysql"], true);
+    test_sequence_support(&["postgres", "mysql"], true);
+    test_sequence_support(&["postgres"], false);
+
+    test_sequence_support(&["mysql"], true);
+    test_sequence_support(&["sqlite"], true);
+}
+
+fn test_sequence_support(providers: &[&str], must_error: bool) {
+    let dml = r#"
+    model Todo {
+      id     Int    @id @default(sequence("todo_ids"))
+    }
+    "#;
+
+    let error_msg = "Error parsing attribute \"@default\": The `sequence()` default value is used even though the datasource does not support sequences.";
+    test_capability_support(providers, must_error, dml, error_msg);
+}
+
+#[test]
+fn clustered_indexes_must_only_be_supported_if_all_specified_providers_support_them() {
+    // Only SQL Server supports clustered indexes.
+    test_clustered_index_support(&["sqlserver", "postgres"], true);
+    test_clustered_index_support(&["sqlserver"], false);
+
+    test_clustered_index_support(&["postgres"], true);
+    test_clustered_index_support(&["mysql"], true);
+    test_clustered_index_support(&["sqlite"], true);
+}
+
+fn test_clustered_index_support(providers: &[&str], must_error: bool) {
+    let dml = r#"
+    model Todo {
+      id    Int @id
+      title String
+
+      @@index([title], clustered: false)
+    }
+    "#;
+
+    let error_msg = "Error parsing attribute \"@index\": The `clustered` argument is used even though the datasource does not support clustered indexes.";
+    test_capability_support(providers, must_error, dml, error_msg);
+}
+
+#[test]
+fn nulls_not_distinct_must_only_be_supported_if_all_specified_providers_support_it() {
+    test_nulls_not_distinct_support(&["postgres", "sqlserver"], false);
+    test_nulls_not_distinct_support(&["postgres", "mysql"], true);
+
+    test_nulls_not_distinct_support(&["mysql"], true);
+    test_nulls_not_distinct_support(&["sqlite"], true);
+}
+
+fn test_nulls_not_distinct_support(providers: &[&str], must_error: bool) {
+    let dml = r#"
+    model Todo {
+      id    Int     @id
+      title String?
+
+      @@unique([title], nullsNotDistinct: true)
+    }
+    "#;
+
+    let error_msg = "Error parsing attribute \"@unique\": The `nullsNotDistinct` argument is used even though the datasource does not support it.";
+    test_capability_support(providers, must_error, dml, error_msg);
+}
+
+#[test]
+fn partial_indexes_must_only_be_supported_if_all_specified_providers_support_them() {
+    test_partial_index_support(&["postgres", "sqlserver", "sqlite"], false);
+    test_partial_index_support(&["postgres", "mysql"], true);
+
+    test_partial_index_support(&["mysql"], true);
+}
+
+fn test_partial_index_support(providers: &[&str], must_error: bool) {
+    let dml = r#"
+    model Todo {
+      id    Int    @id
+      title String
+      done  Boolean
+
+      @@index([title], where: "done = false")
+    }
+    "#;
+
+    let error_msg = "Error parsing attribute \"@index\": The `where` argument is used even though the datasource does not support partial indexes.";
+    test_capability_support(providers, must_error, dml, error_msg);
+}
+
+#[test]
+fn distributed_tables_must_only_be_supported_if_all_specified_providers_support_them() {
+    test_distributed_table_support(&["postgres"], false);
+    test_distributed_table_support(&["postgres", "mysql"], true);
+
+    test_distributed_table_support(&["mysql"], true);
+    test_distributed_table_support(&["sqlserver"], true);
+    test_distributed_table_support(&["sqlite"], true);
+}
+
+fn test_distributed_table_support(providers: &[&str], must_error: bool) {
+    let dml = r#"
+    model Todo {
+      id       Int @id
+      tenantId Int
+
+      @@distributedBy(tenantId)
+    }
+    "#;
+
+    let error_msg = "Error parsing attribute \"@distributedBy\": The table can not be distributed, the datasource does not support distributed tables.";
+    test_capability_support(providers, must_error, dml, error_msg);
+}
+
+#[test]
+fn temporal_tables_must_only_be_supported_if_all_specified_providers_support_them() {
+    test_temporal_table_support(&["sqlserver"], false);
+    test_temporal_table_support(&["sqlserver", "postgres"], true);
+
+    test_temporal_table_support(&["postgres"], true);
+    test_temporal_table_support(&["mysql"], true);
+    test_temporal_table_support(&["sqlite"], true);
+}
+
+fn test_temporal_table_support(providers: &[&str], must_error: bool) {
+    let dml = r#"
+    model Todo {
+      id    Int    @id
+      title String
+
+      @@temporal(historyTable: "TodoVersions")
+    }
+    "#;
+
+    let error_msg = "Error parsing attribute \"@temporal\": The table can not be temporal, the datasource does not support temporal tables.";
+    test_capability_support(providers, must_error, dml, error_msg);
+}
+
 fn test_capability_support(providers: &[&str], must_error: bool, datamodel: &str, error_msg: &str) {
     let provider_strings: Vec<_> = providers.iter().map(|x| format!("\"{}\"", x)).collect();
     let first_provider = providers.first().unwrap();
@@ -227,3 +391,46 @@ fn test_capability_support(providers: &[&str], must_error: bool, datamodel: &str
         parse(&dml);
     }
 }
+
+#[test]
+fn referential_actions_must_only_be_supported_if_all_specified_providers_support_them() {
+    // SQL Server doesn't support RESTRICT, MySQL doesn't support SET DEFAULT.
+    test_referential_action_support("RESTRICT", &["sqlserver", "postgres"], true);
+    test_referential_action_support("RESTRICT", &["sqlserver"], true);
+    test_referential_action_support("RESTRICT", &["postgres"], false);
+    test_referential_action_support("RESTRICT", &["mysql"], false);
+    test_referential_action_support("RESTRICT", &["sqlite"], false);
+
+    test_referential_action_support("SET_DEFAULT", &["mysql", "postgres"], true);
+    test_referential_action_support("SET_DEFAULT", &["mysql"], true);
+    test_referential_action_support("SET_DEFAULT", &["postgres"], false);
+    test_referential_action_support("SET_DEFAULT", &["sqlserver"], false);
+    test_referential_action_support("SET_DEFAULT", &["sqlite"], false);
+
+    test_referential_action_support("NO_ACTION", &["sqlserver", "mysql", "postgres", "sqlite"], false);
+    test_referential_action_support("CASCADE", &["sqlserver", "mysql", "postgres", "sqlite"], false);
+}
+
+fn test_referential_action_support(action: &str, providers: &[&str], must_error: bool) {
+    let dml = format!(
+        r#"
+    model User {{
+      id    Int    @id
+      posts Post[]
+    }}
+
+    model Post {{
+      id     Int  @id
+      userId Int  @default(1)
+      user   User @relation(fields: [userId], references: [id], onDelete: {})
+    }}
+    "#,
+        action
+    );
+
+    let error_msg = format!(
+        "Error parsing attribute \"@relation\": The referential action `{}` is not supported by the datasource.",
+        action
+    );
+    test_capability_support(providers, must_error, &dml, &error_msg);
+}
@@ -25,6 +25,7 @@ pub trait ScalarFieldAsserts {
     fn assert_is_id(&self) -> &Self;
     fn assert_is_unique(&self, b: bool) -> &Self;
     fn assert_is_updated_at(&self, b: bool) -> &Self;
+    fn assert_is_read_only(&self, b: bool) -> &Self;
 }
 
 pub trait RelationFieldAsserts {
@@ -147,6 +148,11 @@ impl ScalarFieldAsserts for dml::ScalarField {
         assert_eq!(self.is_updated_at, b);
         self
     }
+
+    fn assert_is_read_only(&self, b: bool) -> &Self {
+        assert_eq!(self.is_read_only, b);
+        self
+    }
 }
 
 impl FieldAsserts for dml::RelationField {
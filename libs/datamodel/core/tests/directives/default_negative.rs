@@ -131,6 +131,42 @@ fn must_error_if_autoincrement_function_is_used_for_fields_that_are_not_int() {
     ));
 }
 
+#[test]
+fn must_error_if_sequence_function_is_used_without_a_sequence_name() {
+    let dml = r#"
+    model Model {
+        id  Int @id
+        foo Int @default(sequence())
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The function `sequence()` takes the name of the sequence as its only argument, e.g. `sequence(\"user_ids\")`.",
+        "default",
+        Span::new(64, 74),
+    ));
+}
+
+#[test]
+fn must_error_if_sequence_function_is_used_for_fields_that_are_not_int() {
+    let dml = r#"
+    model Model {
+        id  Int    @id
+        foo String @default(sequence("foos"))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The function `sequence()` can not be used on fields of type `String`.",
+        "default",
+        Span::new(70, 86),
+    ));
+}
+
 #[test]
 fn must_error_if_default_value_for_enum_is_not_valid() {
     let dml = r#"
@@ -153,6 +189,33 @@ fn must_error_if_default_value_for_enum_is_not_valid() {
     ));
 }
 
+#[test]
+fn must_error_if_the_default_enum_value_ends_with_spaces_on_mysql() {
+    let dml = r#"
+    datasource db1 {
+        provider = "mysql"
+        url = "mysql://"
+    }
+
+    model Model {
+        id   Int @id
+        mood Mood @default(HAPPY)
+    }
+
+    enum Mood {
+        HAPPY @map("happy ")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The database name of the default value `HAPPY` ends with spaces, which the datasource strips from enum values.",
+        "default",
+        Span::new(128, 154),
+    ));
+}
+
 #[test]
 fn must_error_if_using_non_id_auto_increment_on_sqlite() {
     let dml = r#"
@@ -0,0 +1,68 @@
+use crate::common::*;
+
+#[test]
+fn should_apply_deprecated_directives() {
+    let dml = r#"
+    model User {
+        id       Int    @id
+        name     String
+        nickname String @deprecated("Use `name` instead.")
+        posts    Post[] @deprecated("Posts are moving to the blog service.")
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+
+        @@deprecated("Use the blog service.")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+
+    assert_eq!(user_model.deprecation, None);
+    assert_eq!(user_model.assert_has_scalar_field("name").deprecation, None);
+    assert_eq!(
+        user_model.assert_has_scalar_field("nickname").deprecation.as_deref(),
+        Some("Use `name` instead.")
+    );
+    assert_eq!(
+        user_model.assert_has_relation_field("posts").deprecation.as_deref(),
+        Some("Posts are moving to the blog service.")
+    );
+    assert_eq!(
+        schema.assert_has_model("Post").deprecation.as_deref(),
+        Some("Use the blog service.")
+    );
+}
+
+#[test]
+fn should_render_deprecated_directives() {
+    let dml = r#"model User {
+  id       Int    @id
+  nickname String @deprecated("Use `name` instead.")
+
+  @@deprecated("Use the accounts service.")
+}
+"#;
+
+    let rendered = datamodel::render_datamodel_to_string(&parse(dml)).unwrap();
+
+    assert_eq!(rendered, dml);
+}
+
+#[test]
+fn should_fail_without_a_reason() {
+    let dml = r#"
+    model User {
+        id       Int    @id
+        nickname String @deprecated
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    assert!(errors.to_pretty_string("schema.prisma", dml).contains("reason"));
+}
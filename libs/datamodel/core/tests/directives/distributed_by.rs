@@ -0,0 +1,64 @@
+use crate::common::*;
+use datamodel::ast::Span;
+use datamodel::error::DatamodelError;
+
+#[test]
+fn distributed_by_directive() {
+    let dml = r#"
+    model User {
+        id       Int @id
+        tenantId Int @map("tenant_id")
+
+        @@distributedBy(tenantId)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+
+    assert_eq!(user_model.distribution_field.as_deref(), Some("tenantId"));
+}
+
+#[test]
+fn distributed_by_must_error_for_unknown_fields() {
+    let dml = r#"
+    model User {
+        id       Int    @id
+        tenantId Int
+
+        @@distributedBy(organizationId)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The table is distributed by the unknown field `organizationId`.",
+        "User",
+        Span::new(78, 107),
+    ));
+}
+
+#[test]
+fn distributed_by_must_error_for_relation_fields() {
+    let dml = r#"
+    model User {
+        id       Int    @id
+        tenantId Int
+        tenant   Tenant @relation(fields: [tenantId], references: [id])
+
+        @@distributedBy(tenant)
+    }
+
+    model Tenant {
+        id    Int    @id
+        users User[]
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The table can not be distributed by the relation field `tenant`. Use one of its underlying scalar fields instead.",
+        "User",
+        Span::new(150, 171),
+    ));
+}
@@ -187,3 +187,25 @@ fn relation_fields_as_part_of_compound_id_must_error() {
         Span::new(136, 162),
     ));
 }
+
+#[test]
+fn id_clustering_can_be_set_on_sql_server() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlserver"
+        url      = "sqlserver://"
+    }
+
+    model Model {
+        id   Int
+        name String
+
+        @@id([id], clustered: false)
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    assert_eq!(user_model.id_fields, vec!["id"]);
+    assert_eq!(user_model.id_clustered, Some(false));
+}
@@ -20,6 +20,9 @@ fn basic_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        clustered: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 }
 
@@ -45,6 +48,9 @@ fn indexes_on_enum_fields_must_work() {
         name: None,
         fields: vec!["role".to_string()],
         tpe: IndexType::Normal,
+        clustered: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 }
 
@@ -91,6 +97,9 @@ fn the_name_argument_must_work() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        clustered: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 }
 
@@ -126,12 +135,18 @@ fn multiple_indexes_with_same_name_are_supported_by_mysql() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["id".to_string()],
         tpe: IndexType::Normal,
+        clustered: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 
     post_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["id".to_string()],
         tpe: IndexType::Normal,
+        clustered: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 }
 
@@ -222,12 +237,18 @@ fn multiple_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        clustered: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 
     user_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        clustered: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 }
 
@@ -265,3 +286,57 @@ fn index_directives_must_serialize_to_valid_dml() {
 
     assert!(datamodel::parse_datamodel(&render_datamodel_to_string(&schema).unwrap()).is_ok());
 }
+
+#[test]
+fn clustered_indexes_must_work_and_serialize() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlserver"
+        url      = "sqlserver://"
+    }
+
+    model User {
+        id        Int    @id
+        firstName String
+        lastName  String
+
+        @@index([firstName, lastName], clustered: true)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["firstName".to_string(), "lastName".to_string()],
+        tpe: IndexType::Normal,
+        clustered: Some(true),
+        nulls_not_distinct: false,
+        predicate: None,
+    });
+
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+    assert!(rendered.contains("@@index([firstName, lastName], clustered: true)"));
+}
+
+#[test]
+fn a_model_must_not_have_more_than_one_clustered_index() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlserver"
+        url      = "sqlserver://"
+    }
+
+    model User {
+        id        Int
+        firstName String
+        lastName  String
+
+        @@id([id], clustered: true)
+        @@unique([firstName, lastName], clustered: true)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is_message("A model can only have one clustered index or primary key.");
+}
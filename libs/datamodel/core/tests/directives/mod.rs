@@ -1,16 +1,20 @@
 pub mod builtin_directives;
 pub mod default_negative;
 pub mod default_positive;
+pub mod deprecated;
+pub mod distributed_by;
 pub mod id_negative;
 pub mod id_positive;
 pub mod index;
 pub mod map;
+pub mod read_only;
 pub mod relations_basic;
 pub mod relations_consistency;
 pub mod relations_legacy;
 pub mod relations_negative;
 pub mod relations_new;
 pub mod relations_positive;
+pub mod temporal;
 pub mod unique;
 pub mod unique_criteria;
 pub mod updated_at_negative;
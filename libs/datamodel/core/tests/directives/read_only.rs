@@ -0,0 +1,49 @@
+use crate::common::*;
+use datamodel::{ast::Span, error::DatamodelError};
+
+#[test]
+fn should_apply_read_only_directive() {
+    let dml = r#"
+    model User {
+        id       Int    @id
+        name     String
+        fullName String @default(dbgenerated()) @readOnly
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model.assert_has_scalar_field("fullName").assert_is_read_only(true);
+    user_model.assert_has_scalar_field("name").assert_is_read_only(false);
+}
+
+#[test]
+fn should_render_read_only_directive() {
+    let dml = r#"model User {
+  id       Int    @id
+  fullName String @default(dbgenerated()) @readOnly
+}
+"#;
+
+    let rendered = datamodel::render_datamodel_to_string(&parse(dml)).unwrap();
+
+    assert_eq!(rendered, dml);
+}
+
+#[test]
+fn should_fail_if_field_is_also_updated_at() {
+    let dml = r#"
+    model User {
+        id       Int      @id
+        lastSeen DateTime @updatedAt @readOnly
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "Fields that are marked with @updatedAt can not be read-only.",
+        "readOnly",
+        Span::new(86, 94),
+    ));
+}
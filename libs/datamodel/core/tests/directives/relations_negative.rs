@@ -205,3 +205,61 @@ fn should_fail_on_conflicting_generated_back_relation_fields() {
         Span::new(98, 152),
     ));
 }
+
+#[test]
+fn must_error_for_relation_fields_without_index_in_prisma_relation_mode() {
+    let dml = r#"
+    datasource db {
+        provider     = "mysql"
+        url          = "mysql://localhost"
+        relationMode = "prisma"
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_length(1);
+    assert!(errors.to_pretty_string("schema.prisma", dml).contains(
+        "The fields of the relation `author` must be indexed when the datasource uses `relationMode = \"prisma\"`. Add `@@index([authorId])` to the model."
+    ));
+}
+
+#[test]
+fn relation_fields_can_be_indexed_in_any_way_in_prisma_relation_mode() {
+    let dml = r#"
+    datasource db {
+        provider     = "mysql"
+        url          = "mysql://localhost"
+        relationMode = "prisma"
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        editorId Int  @unique
+        author   User @relation("author", fields: [authorId], references: [id])
+        editor   User @relation("editor", fields: [editorId], references: [id])
+
+        @@index([authorId, id])
+    }
+
+    model User {
+        id           Int    @id
+        posts        Post[] @relation("author")
+        editedPosts  Post[] @relation("editor")
+    }
+    "#;
+
+    parse(dml);
+}
@@ -725,3 +725,93 @@ fn must_error_when_many_to_many_is_not_possible_due_to_missing_id() {
         ),
     );
 }
+
+#[test]
+fn referential_actions_are_parsed_and_rendered() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int?
+        user   User? @relation(fields: [userId], references: [id], onDelete: SET_NULL)
+    }
+    "#;
+
+    let schema = parse(dml);
+    schema
+        .assert_has_model("Post")
+        .assert_has_relation_field("user")
+        .assert_relation_delete_strategy(dml::OnDeleteStrategy::SetNull);
+    schema
+        .assert_has_model("User")
+        .assert_has_relation_field("posts")
+        .assert_relation_delete_strategy(dml::OnDeleteStrategy::None);
+
+    let rendered = datamodel::render_datamodel_to_string(&schema).unwrap();
+
+    assert!(rendered.contains("@relation(fields: [userId], references: [id], onDelete: SET_NULL)"));
+}
+
+#[test]
+fn must_error_on_unknown_referential_actions() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id], onDelete: DELETE)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": Invalid referential action: `DELETE`. Expected one of `RESTRICT`, `NO_ACTION`, `CASCADE`, `SET_NULL` or `SET_DEFAULT`.",
+    );
+}
+
+#[test]
+fn must_error_when_set_null_is_used_on_required_fields() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id], onDelete: SET_NULL)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": The referential action `SET_NULL` requires the fields of the relation to be optional, but the relation field `user` uses required fields.",
+    );
+}
+
+#[test]
+fn must_error_when_referential_actions_are_set_on_the_side_without_fields() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[] @relation(onDelete: CASCADE)
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": The `onDelete` argument can only be set on the side of the relation that defines `fields`.",
+    );
+}
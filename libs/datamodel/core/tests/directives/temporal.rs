@@ -0,0 +1,66 @@
+use crate::common::*;
+
+#[test]
+fn temporal_directive() {
+    let dml = r#"
+    model Account {
+        id      Int @id
+        balance Int
+
+        @@temporal
+    }
+    "#;
+
+    let schema = parse(dml);
+    let account_model = schema.assert_has_model("Account");
+
+    assert!(account_model.is_temporal);
+    assert_eq!(account_model.history_table_name, None);
+}
+
+#[test]
+fn temporal_directive_with_history_table() {
+    let dml = r#"
+    model Account {
+        id      Int @id
+        balance Int
+
+        @@temporal(historyTable: "AccountAudit")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let account_model = schema.assert_has_model("Account");
+
+    assert!(account_model.is_temporal);
+    assert_eq!(account_model.history_table_name.as_deref(), Some("AccountAudit"));
+}
+
+#[test]
+fn models_are_not_temporal_by_default() {
+    let dml = r#"
+    model Account {
+        id      Int @id
+        balance Int
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    assert!(!schema.assert_has_model("Account").is_temporal);
+}
+
+#[test]
+fn should_render_temporal_directive() {
+    let dml = r#"model Account {
+  id      Int @id
+  balance Int
+
+  @@temporal(historyTable: "AccountAudit")
+}
+"#;
+
+    let rendered = datamodel::render_datamodel_to_string(&parse(dml)).unwrap();
+
+    assert_eq!(rendered, dml);
+}
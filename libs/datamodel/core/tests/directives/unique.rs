@@ -22,6 +22,9 @@ fn basic_unique_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        clustered: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 }
 
@@ -163,6 +166,9 @@ fn the_name_argument_must_work() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        clustered: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 }
 
@@ -186,12 +192,18 @@ fn multiple_unique_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        clustered: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 
     user_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        clustered: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 }
 
@@ -217,6 +229,9 @@ fn multi_field_unique_indexes_on_enum_fields_must_work() {
         name: None,
         fields: vec!["role".to_string()],
         tpe: IndexType::Unique,
+        clustered: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 }
 
@@ -274,3 +289,101 @@ fn unique_directives_must_serialize_to_valid_dml() {
 
     assert!(datamodel::parse_datamodel(&render_datamodel_to_string(&schema).unwrap()).is_ok());
 }
+
+#[test]
+fn unique_indexes_can_consider_nulls_not_distinct() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://"
+    }
+
+    model User {
+        id        Int     @id
+        firstName String?
+        lastName  String?
+
+        @@unique([firstName, lastName], nullsNotDistinct: true)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["firstName".to_string(), "lastName".to_string()],
+        tpe: IndexType::Unique,
+        clustered: None,
+        nulls_not_distinct: true,
+        predicate: None,
+    });
+
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+    assert!(rendered.contains("@@unique([firstName, lastName], nullsNotDistinct: true)"));
+}
+
+#[test]
+fn nulls_not_distinct_must_error_on_normal_indexes() {
+    let dml = r#"
+    model User {
+        id        Int     @id
+        firstName String?
+
+        @@index([firstName], nullsNotDistinct: true)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is_message("The `nullsNotDistinct` argument is only supported on unique indexes.");
+}
+
+#[test]
+fn unique_indexes_can_be_partial() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://"
+    }
+
+    model User {
+        id        Int       @id
+        email     String
+        deletedAt DateTime?
+
+        @@unique([email], where: "\"deletedAt\" IS NULL")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["email".to_string()],
+        tpe: IndexType::Unique,
+        clustered: None,
+        nulls_not_distinct: false,
+        predicate: Some(r#""deletedAt" IS NULL"#.to_string()),
+    });
+
+    // A partial unique index doesn't identify the records.
+    assert!(!user_model.find_scalar_field("email").unwrap().is_unique);
+    assert_eq!(user_model.strict_unique_criterias().len(), 1);
+
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+    assert!(rendered.contains(r#"@@unique([email], where: "\"deletedAt\" IS NULL")"#));
+}
+
+#[test]
+fn the_predicate_of_partial_indexes_must_not_be_empty() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        email String
+
+        @@unique([email], where: " ")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is_message("The `where` argument of an index must be a non-empty SQL condition.");
+}
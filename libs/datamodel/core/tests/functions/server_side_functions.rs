@@ -1,5 +1,7 @@
 use crate::common::*;
+use chrono::{TimeZone, Utc};
 use datamodel::{DefaultValue, ScalarType, ValueGenerator};
+use prisma_value::PrismaValue;
 
 #[test]
 fn correctly_handle_server_side_now_function() {
@@ -60,3 +62,52 @@ fn correctly_handle_server_side_uuid_function() {
         .assert_base_type(&ScalarType::String)
         .assert_default_value(DefaultValue::Expression(ValueGenerator::new_uuid()));
 }
+
+#[test]
+fn correctly_handle_server_side_sequence_function() {
+    let dml = r#"
+    model User {
+        id     Int @id @default(sequence("user_ids"))
+        number Int @default(sequence("numbers"))
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model
+        .assert_has_scalar_field("id")
+        .assert_base_type(&ScalarType::Int)
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_sequence("user_ids")));
+    user_model
+        .assert_has_scalar_field("number")
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_sequence("numbers")));
+}
+
+#[test]
+fn server_side_functions_can_be_made_deterministic() {
+    let now = Utc.ymd(2020, 1, 1).and_hms(0, 0, 0);
+    let generate = || {
+        (
+            ValueGenerator::new_uuid().generate(),
+            ValueGenerator::new_cuid().generate(),
+            ValueGenerator::new_now().generate(),
+        )
+    };
+
+    datamodel::enable_deterministic_generators(42, now);
+    let first = generate();
+
+    datamodel::enable_deterministic_generators(42, now);
+    let second = generate();
+
+    assert_eq!(first, second);
+    assert_eq!(first.2, Some(PrismaValue::DateTime(now)));
+
+    match first.1 {
+        Some(PrismaValue::String(cuid)) => {
+            assert_eq!(cuid.len(), 25);
+            assert!(cuid.starts_with('c'));
+        }
+        other => panic!("Expected a CUID, got {:?}", other),
+    }
+}
@@ -134,6 +134,37 @@ fn should_handle_type_specifications_on_postgres() {
     assert_eq!(postgres_type, PostgresType::VarChar(26));
 }
 
+#[test]
+fn should_handle_range_type_specifications_on_postgres() {
+    let dml = r#"
+        datasource pg {
+          provider = "postgres"
+          url = "postgresql://"
+          previewFeatures = ["nativeTypes"]
+        }
+
+        model Reservation {
+            id     Int    @id
+            seats  String @pg.Int4Range
+            during String @pg.TsTzRange
+        }
+    "#;
+
+    let datamodel = parse(dml);
+
+    let reservation_model = datamodel.assert_has_model("Reservation");
+
+    let sft = reservation_model.assert_has_scalar_field("seats").assert_native_type();
+
+    let postgres_type: PostgresType = sft.deserialize_native_type();
+    assert_eq!(postgres_type, PostgresType::Int4Range);
+
+    let sft = reservation_model.assert_has_scalar_field("during").assert_native_type();
+
+    let postgres_type: PostgresType = sft.deserialize_native_type();
+    assert_eq!(postgres_type, PostgresType::TsTzRange);
+}
+
 #[test]
 fn should_handle_type_specifications_on_mysql() {
     let dml = r#"
@@ -164,3 +195,31 @@ fn should_handle_type_specifications_on_mysql() {
     let mysql_type: MySqlType = sft.deserialize_native_type();
     assert_eq!(mysql_type, MySqlType::DateTime(Option::from(26)));
 }
+
+#[test]
+fn should_handle_set_type_specifications_on_mysql() {
+    let dml = r#"
+        datasource mys {
+          provider = "mysql"
+          url = "mysql://"
+          previewFeatures = ["nativeTypes"]
+        }
+
+        model Blog {
+            id   Int    @id
+            tags String @mys.Set("red", "green", "blue")
+        }
+    "#;
+
+    let datamodel = parse(dml);
+
+    let user_model = datamodel.assert_has_model("Blog");
+
+    let sft = user_model.assert_has_scalar_field("tags").assert_native_type();
+
+    let mysql_type: MySqlType = sft.deserialize_native_type();
+    assert_eq!(
+        mysql_type,
+        MySqlType::Set(vec!["red".to_owned(), "green".to_owned(), "blue".to_owned()])
+    );
+}
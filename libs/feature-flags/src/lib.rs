@@ -57,12 +57,24 @@ macro_rules! flags {
 // `insensitiveFilters`: Case insensitive scalar filters for supported DBs.
 // `atomicNumberOperations`: New and expanded number operations for updates.
 // `microsoftSqlServer`: Support for Microsoft SQL Server databases
+// `uniqueValidation`: Check unique constraints before creating, updating or upserting records, for precise violation errors.
+// `interactiveTransactions`: Transactions spanning several requests in the QE.
+// `upsertMany`: Bulk upserts with a single insert statement in the QE.
+// `filterJson`: Filters on the values at a path of Json fields in the QE.
+// `fullTextSearch`: Full-text search filters on String fields in the QE.
+// `scalarLists`: `has`, `hasSome`, `hasEvery` and `isEmpty` filters and `push` updates on scalar list fields in the QE.
 flags!(
     transaction,
     connectOrCreate,
     insensitiveFilters,
     atomicNumberOperations,
-    microsoftSqlServer
+    microsoftSqlServer,
+    uniqueValidation,
+    interactiveTransactions,
+    upsertMany,
+    filterJson,
+    fullTextSearch,
+    scalarLists
 );
 
 /// Initializes the feature flags with given flags.
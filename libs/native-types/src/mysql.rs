@@ -36,6 +36,7 @@ pub enum MySqlType {
     Timestamp(Option<u32>),
     Year,
     JSON,
+    Set(Vec<String>),
 }
 
 impl super::NativeType for MySqlType {
@@ -23,11 +23,19 @@ pub enum PostgresType {
     Time(u8),
     TimeWithTimeZone(u8),
     Interval(u8),
+    Int4Range,
+    Int8Range,
+    NumRange,
+    TsRange,
+    TsTzRange,
+    DateRange,
     Boolean,
     Bit(u32),
     VarBit(u32),
     UUID,
     XML,
+    TsVector,
+    TsQuery,
     JSON,
     JSONB,
 }
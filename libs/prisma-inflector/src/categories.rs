@@ -170,19 +170,23 @@ pub static CATEGORY_S_ES: &'static [&'static str] = &[
 
 pub static CATEGORY_MAN_MANS: &'static [&'static str] = &[
     "human",
-    "Alabaman",
-    "Bahaman",
-    "Burman",
-    "German",
-    "Hiroshiman",
-    "Liman",
-    "Nakayaman",
-    "Oklahoman",
-    "Panaman",
-    "Selman",
-    "Sonaman",
-    "Tacoman",
-    "Yakiman",
-    "Yokohaman",
-    "Yuman",
+    "alabaman",
+    "bahaman",
+    "burman",
+    "caiman",
+    "german",
+    "hiroshiman",
+    "liman",
+    "nakayaman",
+    "oklahoman",
+    "ottoman",
+    "panaman",
+    "selman",
+    "shaman",
+    "sonaman",
+    "tacoman",
+    "talisman",
+    "yakiman",
+    "yokohaman",
+    "yuman",
 ];
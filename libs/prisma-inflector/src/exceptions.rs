@@ -52,6 +52,10 @@ pub static UNCOUNTABLE: &'static [&'static str] = &[
     "elk",
     "pincers",
     "sugar",
+    "data",
+    "equipment",
+    "feedback",
+    "information",
 ];
 
 pub static STANDARD_IRREGULAR: &'static [(&'static str, &'static str)] = &[
@@ -63,8 +67,14 @@ pub static STANDARD_IRREGULAR: &'static [(&'static str, &'static str)] = &[
     ("trilby", "trilbys"),        // anglicized
     ("genus", "genera"),          // classical
     ("quiz", "quizzes"),
+    ("person", "people"),
+    ("thief", "thieves"),
 ];
 
+/// Irregular plurals of words that are too short to be matched as a suffix (`ox`, but not `box`).
+/// They only apply to the whole identifier or its last camel case / snake case word.
+pub static WORD_IRREGULAR: &'static [(&'static str, &'static str)] = &[("ox", "oxen"), ("die", "dice")];
+
 pub static IRREGULAR_ANGLICIZED: &'static [(&'static str, &'static str)] = &[
     ("beef", "beefs"),
     ("brother", "brothers"),
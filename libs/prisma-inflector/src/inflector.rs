@@ -34,6 +34,11 @@ impl Inflector {
         // Rules for words that do not inflect in the plural (such as fish, travois, chassis, nationality endings
         rules.push(Self::category_rule("", "", &exceptions::UNCOUNTABLE));
 
+        // Irregular plurals of short words that would match too many other words as a suffix (oxen, dice)
+        exceptions::WORD_IRREGULAR.iter().for_each(|(singular, plural)| {
+            rules.push(Rule::word(singular, plural));
+        });
+
         // Handle standard irregular plurals (mongooses, oxen, etc.)
         exceptions::STANDARD_IRREGULAR.iter().for_each(|irr| {
             Self::irregular(irr.0, irr.1).into_iter().for_each(|r| rules.push(r));
@@ -209,6 +214,23 @@ mod test {
             ("gastrostomy", "gastrostomies"),
             ("baculum", "bacula"),
             ("pancreas", "pancreases"),
+            ("Person", "People"),
+            ("SalesPerson", "SalesPeople"),
+            ("Ox", "Oxen"),
+            ("MuskOx", "MuskOxen"),
+            ("musk_ox", "musk_oxen"),
+            ("Paradox", "Paradoxes"),
+            ("Die", "Dice"),
+            ("Goodie", "Goodies"),
+            ("Goose", "Geese"),
+            ("Man", "Men"),
+            ("Policeman", "Policemen"),
+            ("German", "Germans"),
+            ("Shaman", "Shamans"),
+            ("Tooth", "Teeth"),
+            ("Thief", "Thieves"),
+            ("Information", "Information"),
+            ("UserData", "UserData"),
         ];
 
         let inflector = Inflector::new(Mode::Anglicized);
@@ -9,6 +9,7 @@ pub trait Pluralize {
 pub enum Rule {
     Category(CategoryRule),
     Regex(RegexRule),
+    Word(WordRule),
 }
 
 impl Rule {
@@ -23,6 +24,10 @@ impl Rule {
     pub fn regex(singular: Regex, plural: String) -> Rule {
         Rule::Regex(RegexRule { singular, plural })
     }
+
+    pub fn word(singular: &'static str, plural: &'static str) -> Rule {
+        Rule::Word(WordRule { singular, plural })
+    }
 }
 
 impl Pluralize for Rule {
@@ -30,6 +35,7 @@ impl Pluralize for Rule {
         match self {
             Rule::Category(c) => c.pluralize(s),
             Rule::Regex(r) => r.pluralize(s),
+            Rule::Word(w) => w.pluralize(s),
         }
     }
 }
@@ -71,11 +77,81 @@ pub struct RegexRule {
 
 impl Pluralize for RegexRule {
     fn pluralize(&self, s: &str) -> Option<String> {
-        let candidate = self.singular.replace(s, &self.plural as &str);
+        let captures = self.singular.captures(s)?;
+        let matched = captures.get(0).unwrap();
+
+        let mut replacement = String::new();
+        captures.expand(&self.plural, &mut replacement);
+
+        // The plurals of the rules are lowercase, keep the capitalization of the replaced word (`Man` -> `Men`).
+        if matched.as_str().starts_with(char::is_uppercase) {
+            replacement = capitalize(&replacement);
+        }
+
+        let candidate = format!("{}{}{}", &s[..matched.start()], replacement, &s[matched.end()..]);
+
         if candidate == s {
             None
         } else {
-            Some(candidate.to_string())
+            Some(candidate)
+        }
+    }
+}
+
+/// Matches only the whole last word of the input, where words are separated by camel case humps or
+/// non-alphanumeric characters.
+#[derive(Debug)]
+pub struct WordRule {
+    singular: &'static str,
+    plural: &'static str,
+}
+
+impl Pluralize for WordRule {
+    fn pluralize(&self, s: &str) -> Option<String> {
+        let word_start = last_word_start(s);
+        let word = &s[word_start..];
+
+        if !word.eq_ignore_ascii_case(self.singular) {
+            return None;
+        }
+
+        let plural = if word.len() > 1 && word.chars().all(char::is_uppercase) {
+            self.plural.to_uppercase()
+        } else if word.starts_with(char::is_uppercase) {
+            capitalize(self.plural)
+        } else {
+            self.plural.to_owned()
+        };
+
+        Some(format!("{}{}", &s[..word_start], plural))
+    }
+}
+
+fn last_word_start(s: &str) -> usize {
+    let mut start = 0;
+    let mut previous: Option<char> = None;
+
+    for (idx, c) in s.char_indices() {
+        if let Some(previous) = previous {
+            let after_separator = !previous.is_alphanumeric() && c.is_alphanumeric();
+            let camel_case_hump = c.is_uppercase() && (previous.is_lowercase() || previous.is_numeric());
+
+            if after_separator || camel_case_hump {
+                start = idx;
+            }
         }
+
+        previous = Some(c);
+    }
+
+    start
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
     }
 }
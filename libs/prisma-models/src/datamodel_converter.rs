@@ -1,7 +1,11 @@
 use crate::*;
 use datamodel::{dml, DefaultValue, WithDatabaseName};
+use datamodel_connector::ConnectorCapabilities;
 use itertools::Itertools;
 
+/// The name of the native UUID type of the Postgres connector.
+const UUID_NATIVE_TYPE_NAME: &str = "Uuid";
+
 pub struct DatamodelConverter<'a> {
     datamodel: &'a dml::Datamodel,
     relations: Vec<TempRelationHolder>,
@@ -30,6 +34,7 @@ impl<'a> DatamodelConverter<'a> {
             relations: self.convert_relations(),
             enums: self.convert_enums(),
             version: Some("v2".to_string()),
+            capabilities: ConnectorCapabilities::empty(),
         }
     }
 
@@ -87,6 +92,7 @@ impl<'a> DatamodelConverter<'a> {
                         relation_name: relation.name.clone(),
                         relation_side: relation.relation_side(rf),
                         relation_info: rf.relation_info.clone(),
+                        deprecation: rf.deprecation.clone(),
                     })
                 }
                 dml::Field::ScalarField(sf) => FieldTemplate::Scalar(ScalarFieldTemplate {
@@ -103,6 +109,9 @@ impl<'a> DatamodelConverter<'a> {
                     db_name: sf.database_name.clone(),
                     arity: sf.arity,
                     default_value: sf.default_value.clone(),
+                    is_computed: sf.is_read_only,
+                    deprecation: sf.deprecation.clone(),
+                    is_native_uuid: sf.is_native_uuid(),
                 }),
             })
             .collect()
@@ -130,9 +139,10 @@ impl<'a> DatamodelConverter<'a> {
             .map(|i| IndexTemplate {
                 name: i.name.clone(),
                 fields: i.fields.clone(),
+                // A partial unique index doesn't identify records.
                 typ: match i.tpe {
-                    dml::IndexType::Unique => IndexType::Unique,
-                    dml::IndexType::Normal => IndexType::Normal,
+                    dml::IndexType::Unique if !i.is_partial() => IndexType::Unique,
+                    _ => IndexType::Normal,
                 },
             })
             .collect()
@@ -336,6 +346,7 @@ trait DatamodelFieldExtensions {
     fn behaviour(&self) -> Option<FieldBehaviour>;
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum>;
     fn internal_enum_value(&self, enum_value: &dml::EnumValue) -> InternalEnumValue;
+    fn is_native_uuid(&self) -> bool;
     // fn default_value(&self) -> Option<dml::DefaultValue>; todo this is not applicable anymore
 }
 
@@ -348,9 +359,12 @@ impl DatamodelFieldExtensions for dml::ScalarField {
                 dml::ScalarType::Boolean => TypeIdentifier::Boolean,
                 dml::ScalarType::DateTime => TypeIdentifier::DateTime,
                 dml::ScalarType::Float => TypeIdentifier::Float,
+                dml::ScalarType::Decimal => TypeIdentifier::Decimal,
                 dml::ScalarType::Int => TypeIdentifier::Int,
+                dml::ScalarType::BigInt => TypeIdentifier::BigInt,
                 dml::ScalarType::String => TypeIdentifier::String,
                 dml::ScalarType::Json => TypeIdentifier::Json,
+                dml::ScalarType::Bytes => TypeIdentifier::Bytes,
                 _ => todo!(),
             },
             dml::FieldType::Unsupported(_) => panic!("These should always be commented out"),
@@ -363,7 +377,7 @@ impl DatamodelFieldExtensions for dml::ScalarField {
         let is_declared_as_unique_through_multi_field_unique = model
             .indices
             .iter()
-            .find(|id| id.fields == vec![self.name.clone()])
+            .find(|id| id.fields == vec![self.name.clone()] && !id.is_partial())
             .is_some();
 
         self.is_unique || is_declared_as_unique_through_multi_field_unique
@@ -414,4 +428,11 @@ impl DatamodelFieldExtensions for dml::ScalarField {
             database_name: enum_value.database_name.clone(),
         }
     }
+
+    fn is_native_uuid(&self) -> bool {
+        match &self.field_type {
+            dml::FieldType::NativeType(_, native_type) => native_type.name == UUID_NATIVE_TYPE_NAME,
+            _ => false,
+        }
+    }
 }
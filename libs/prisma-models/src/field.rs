@@ -66,6 +66,9 @@ pub enum TypeIdentifier {
     DateTime,
     UUID,
     Int,
+    BigInt,
+    Decimal,
+    Bytes,
 }
 
 impl Field {
@@ -97,6 +100,14 @@ impl Field {
         }
     }
 
+    /// The reason the field is deprecated for, if it is.
+    pub fn deprecation(&self) -> Option<&str> {
+        match self {
+            Field::Scalar(ref sf) => sf.deprecation.as_deref(),
+            Field::Relation(ref rf) => rf.deprecation.as_deref(),
+        }
+    }
+
     pub fn as_scalar(self) -> Option<ScalarFieldRef> {
         match self {
             Field::Scalar(scalar) => Some(scalar),
@@ -166,10 +177,15 @@ impl From<ScalarType> for TypeIdentifier {
         match st {
             ScalarType::String => Self::String,
             ScalarType::Int => Self::Int,
+            ScalarType::BigInt => Self::BigInt,
             ScalarType::Float => Self::Float,
+            ScalarType::Decimal => Self::Decimal,
             ScalarType::Boolean => Self::Boolean,
             ScalarType::DateTime => Self::DateTime,
             ScalarType::Json => Self::Json,
+            ScalarType::Bytes => Self::Bytes,
+            // Intervals and XML documents are read and written in their text form.
+            ScalarType::Duration | ScalarType::XML => Self::String,
             _ => todo!(),
         }
     }
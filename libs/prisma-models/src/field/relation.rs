@@ -21,6 +21,7 @@ pub struct RelationFieldTemplate {
     pub relation_name: String,
     pub relation_side: RelationSide,
     pub relation_info: RelationInfo,
+    pub deprecation: Option<String>,
 }
 
 #[derive(Clone)]
@@ -32,6 +33,8 @@ pub struct RelationField {
     pub relation_side: RelationSide,
     pub relation: OnceCell<RelationWeakRef>,
     pub relation_info: RelationInfo,
+    /// The reason the field is deprecated for (`@deprecated`).
+    pub deprecation: Option<String>,
 
     pub model: ModelWeakRef,
     pub(crate) fields: OnceCell<Vec<ScalarFieldWeak>>,
@@ -47,6 +50,7 @@ impl Debug for RelationField {
             .field("relation_side", &self.relation_side)
             .field("relation", &self.relation)
             .field("relation_info", &self.relation_info)
+            .field("deprecation", &self.deprecation)
             .field("model", &"#ModelWeakRef#")
             .field("fields", &self.fields)
             .finish()
@@ -111,6 +115,7 @@ impl RelationFieldTemplate {
             model,
             relation: OnceCell::new(),
             relation_info: self.relation_info,
+            deprecation: self.deprecation,
             fields: OnceCell::new(),
         })
     }
@@ -30,6 +30,9 @@ pub struct ScalarFieldTemplate {
     pub arity: FieldArity,
     pub db_name: Option<String>,
     pub default_value: Option<DefaultValue>,
+    pub is_computed: bool,
+    pub deprecation: Option<String>,
+    pub is_native_uuid: bool,
 }
 
 pub struct ScalarField {
@@ -45,6 +48,13 @@ pub struct ScalarField {
     pub arity: FieldArity,
     pub db_name: Option<String>,
     pub default_value: Option<DefaultValue>,
+    /// The value is computed by the database (`@readOnly`), the field can never be written to.
+    pub is_computed: bool,
+    /// The reason the field is deprecated for (`@deprecated`).
+    pub deprecation: Option<String>,
+    /// The column has the native UUID type (`@pg.Uuid`). The field is a String field, but its values have
+    /// to be UUIDs, and they are bound as such.
+    pub is_native_uuid: bool,
 
     pub model: ModelWeakRef,
     pub(crate) is_unique: bool,
@@ -66,6 +76,9 @@ impl Debug for ScalarField {
             .field("arity", &self.arity)
             .field("db_name", &self.db_name)
             .field("default_value", &self.default_value)
+            .field("is_computed", &self.is_computed)
+            .field("deprecation", &self.deprecation)
+            .field("is_native_uuid", &self.is_native_uuid)
             .field("model", &"#ModelWeakRef#")
             .field("is_unique", &self.is_unique)
             .field("read_only", &self.read_only)
@@ -125,6 +138,12 @@ pub enum ScalarListStrategy {
 
 impl ScalarFieldTemplate {
     pub fn build(self, model: ModelWeakRef) -> ScalarFieldRef {
+        let read_only = OnceCell::new();
+
+        if self.is_computed {
+            read_only.set(true).unwrap();
+        }
+
         let scalar = ScalarField {
             name: self.name,
             type_identifier: self.type_identifier,
@@ -133,13 +152,16 @@ impl ScalarFieldTemplate {
             is_list: self.is_list,
             is_autoincrement: self.is_autoincrement,
             is_auto_generated_int_id: self.is_auto_generated_int_id,
-            read_only: OnceCell::new(),
+            read_only,
             is_unique: self.is_unique,
             internal_enum: self.internal_enum,
             behaviour: self.behaviour,
             arity: self.arity,
             db_name: self.db_name,
             default_value: self.default_value,
+            is_computed: self.is_computed,
+            deprecation: self.deprecation,
+            is_native_uuid: self.is_native_uuid,
             model,
         };
 
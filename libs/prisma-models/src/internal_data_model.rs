@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use datamodel_connector::ConnectorCapabilities;
 use once_cell::sync::OnceCell;
 use std::sync::{Arc, Weak};
 
@@ -11,6 +12,7 @@ pub struct InternalDataModelTemplate {
     pub relations: Vec<RelationTemplate>,
     pub enums: Vec<InternalEnum>,
     pub version: Option<String>,
+    pub capabilities: ConnectorCapabilities,
 }
 
 #[derive(Debug)]
@@ -23,8 +25,12 @@ pub struct InternalDataModel {
     /// E.g. this influences the schema part of a postgres query: `database`.`schema`.`table`.
     /// Other connectors do not use `schema`, like postgres does, and this variable would
     /// influence the `database` part instead.
+    /// Empty when the engine serves one schema or database per tenant: the tables are then not qualified.
     pub db_name: String,
 
+    /// The capabilities of the connector of the datasource, for the query features that differ between them.
+    pub capabilities: ConnectorCapabilities,
+
     models: OnceCell<Vec<ModelRef>>,
     relations: OnceCell<Vec<RelationRef>>,
     relation_fields: OnceCell<Vec<RelationFieldRef>>,
@@ -98,6 +104,7 @@ impl InternalDataModelTemplate {
             enums: self.enums,
             version: self.version,
             db_name,
+            capabilities: self.capabilities,
             relation_fields: OnceCell::new(),
         });
 
@@ -139,6 +139,11 @@ impl Model {
             .collect()
     }
 
+    /// The reason the operations of the model are deprecated for (`@@deprecated`), if they are.
+    pub fn deprecation(&self) -> Option<&str> {
+        self.dml_model.deprecation.as_deref()
+    }
+
     pub fn is_legacy(&self) -> bool {
         self.internal_data_model().is_legacy()
     }
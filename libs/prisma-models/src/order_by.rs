@@ -1,15 +1,71 @@
-use crate::{ModelRef, ScalarFieldRef};
+use crate::{ModelRef, RelationFieldRef, ScalarFieldRef};
 use std::string::ToString;
 
+/// An ordering of records. `path` holds the relation fields leading from the ordered model to the model of `field`,
+/// and is empty when the records are ordered by a field of their own.
+///
+/// Orderings by an aggregation of a to-many relation end their `path` with that relation, and `field` is then a
+/// field of the related model the aggregation is computed over.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct OrderBy {
     pub field: ScalarFieldRef,
+    pub path: Vec<RelationFieldRef>,
     pub sort_order: SortOrder,
+    pub sort_aggregation: Option<SortAggregation>,
 }
 
 impl OrderBy {
     pub fn new(field: ScalarFieldRef, sort_order: SortOrder) -> Self {
-        Self { field, sort_order }
+        Self {
+            field,
+            path: vec![],
+            sort_order,
+            sort_aggregation: None,
+        }
+    }
+
+    /// Orders by a field of the model at the end of the given to-one relations.
+    pub fn new_through_relations(field: ScalarFieldRef, path: Vec<RelationFieldRef>, sort_order: SortOrder) -> Self {
+        Self {
+            field,
+            path,
+            sort_order,
+            sort_aggregation: None,
+        }
+    }
+
+    /// Orders by the number of records of the to-many relation at the end of `path`.
+    pub fn new_relation_count(path: Vec<RelationFieldRef>, sort_order: SortOrder) -> Self {
+        let relation = path.last().expect("Expected a relation to count the records of.");
+        let field = relation
+            .related_model()
+            .primary_identifier()
+            .scalar_fields()
+            .next()
+            .expect("Expected the related model to have a primary identifier.");
+
+        Self {
+            field,
+            path,
+            sort_order,
+            sort_aggregation: Some(SortAggregation::Count),
+        }
+    }
+
+    /// Whether the records are ordered by one of their own fields, as opposed to a related record or aggregation.
+    pub fn is_on_model(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    /// Whether the ordered value can be null: the field is optional, or one of the to-one relations leading to it is.
+    /// Counts are never null on their own.
+    pub fn is_nullable(&self) -> bool {
+        let optional_relation = self.path.iter().any(|rf| !rf.is_list && !rf.is_required);
+
+        match self.sort_aggregation {
+            Some(_) => optional_relation,
+            None => optional_relation || !self.field.is_required,
+        }
     }
 }
 
@@ -23,6 +79,12 @@ pub enum SortOrder {
     Descending,
 }
 
+/// Aggregations over the records of a to-many relation that records can be ordered by.
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum SortAggregation {
+    Count,
+}
+
 impl ToString for SortOrder {
     fn to_string(&self) -> String {
         match self {
@@ -34,9 +96,6 @@ impl ToString for SortOrder {
 
 impl From<ScalarFieldRef> for OrderBy {
     fn from(field: ScalarFieldRef) -> Self {
-        Self {
-            field,
-            sort_order: SortOrder::Ascending,
-        }
+        Self::new(field, SortOrder::Ascending)
     }
 }
@@ -16,11 +16,14 @@ impl PrismaValueExtensions for PrismaValue {
             (PrismaValue::Null, _) => PrismaValue::Null,
             (val @ PrismaValue::String(_), TypeIdentifier::String) => val,
             (val @ PrismaValue::Int(_), TypeIdentifier::Int) => val,
+            (val @ PrismaValue::Int(_), TypeIdentifier::BigInt) => val,
             (val @ PrismaValue::Float(_), TypeIdentifier::Float) => val,
+            (val @ PrismaValue::Float(_), TypeIdentifier::Decimal) => val,
             (val @ PrismaValue::Boolean(_), TypeIdentifier::Boolean) => val,
             (val @ PrismaValue::DateTime(_), TypeIdentifier::DateTime) => val,
             (val @ PrismaValue::Enum(_), TypeIdentifier::Enum(_)) => val,
             (val @ PrismaValue::Uuid(_), TypeIdentifier::UUID) => val,
+            (val @ PrismaValue::Bytes(_), TypeIdentifier::Bytes) => val,
 
             // Valid String coercions
             (PrismaValue::Int(i), TypeIdentifier::String) => PrismaValue::String(format!("{}", i)),
@@ -32,9 +35,55 @@ impl PrismaValueExtensions for PrismaValue {
             (PrismaValue::Enum(e), TypeIdentifier::String) => PrismaValue::String(e),
             (PrismaValue::Uuid(u), TypeIdentifier::String) => PrismaValue::String(u.to_string()),
 
+            // Valid UUID coercions
+            (PrismaValue::String(s), TypeIdentifier::UUID) => match s.parse() {
+                Ok(u) => PrismaValue::Uuid(u),
+                Err(_) => {
+                    return Err(DomainError::ConversionFailure(
+                        format!("{:?}", s),
+                        format!("{:?}", to_type),
+                    ))
+                }
+            },
+
+            // Valid Bytes coercions
+            (PrismaValue::String(s), TypeIdentifier::Bytes) => match base64::decode(&s) {
+                Ok(bytes) => PrismaValue::Bytes(bytes),
+                Err(_) => {
+                    return Err(DomainError::ConversionFailure(
+                        format!("{:?}", s),
+                        format!("{:?}", to_type),
+                    ))
+                }
+            },
+
             // Valid Int coersions
-            (PrismaValue::String(s), TypeIdentifier::Int) => match s.parse() {
-                Ok(i) => PrismaValue::Int(i),
+            (PrismaValue::String(s), TypeIdentifier::Int) | (PrismaValue::String(s), TypeIdentifier::BigInt) => {
+                match s.parse() {
+                    Ok(i) => PrismaValue::Int(i),
+                    Err(_) => {
+                        return Err(DomainError::ConversionFailure(
+                            format!("{:?}", s),
+                            format!("{:?}", to_type),
+                        ))
+                    }
+                }
+            }
+            (PrismaValue::Float(f), TypeIdentifier::Int) | (PrismaValue::Float(f), TypeIdentifier::BigInt) => {
+                match f.trunc().to_i64() {
+                    Some(i) => PrismaValue::Int(i),
+                    None => {
+                        return Err(DomainError::ConversionFailure(
+                            format!("{:?}", f),
+                            format!("{:?}", to_type),
+                        ))
+                    }
+                }
+            }
+
+            // Valid Decimal coercions
+            (PrismaValue::String(s), TypeIdentifier::Decimal) => match s.parse() {
+                Ok(d) => PrismaValue::Float(d),
                 Err(_) => {
                     return Err(DomainError::ConversionFailure(
                         format!("{:?}", s),
@@ -42,7 +91,7 @@ impl PrismaValueExtensions for PrismaValue {
                     ))
                 }
             },
-            (PrismaValue::Float(f), TypeIdentifier::Int) => PrismaValue::Int(f.trunc().to_i64().unwrap()),
+            (PrismaValue::Int(i), TypeIdentifier::Decimal) => PrismaValue::Float(i.into()),
 
             // Todo other coercions here
 
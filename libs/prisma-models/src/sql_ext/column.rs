@@ -1,3 +1,4 @@
+use super::table::{datasource_table, model_table};
 use crate::{Field, ModelProjection, RelationField, RelationLinkManifestation, ScalarField, ScalarFieldExt};
 use itertools::Itertools;
 use quaint::ast::{Column, Row};
@@ -96,24 +97,21 @@ impl AsColumns for RelationField {
         let internal_data_model = model.internal_data_model();
 
         let relation = self.relation();
-        let table_name = if relation.is_many_to_many() {
+        let table = if relation.is_many_to_many() {
             if let RelationLinkManifestation::RelationTable(ref rt) = relation.manifestation {
-                rt.table.clone()
+                datasource_table(&internal_data_model, rt.table.clone())
             } else {
                 unreachable!()
             }
         } else {
-            model.db_name().to_string()
+            model_table(&model)
         };
 
         let inner: Vec<_> = self
             .scalar_fields()
             .iter()
             .map(|f| {
-                let parts = (
-                    (internal_data_model.db_name.clone(), table_name.clone()),
-                    f.db_name().to_owned(),
-                );
+                let parts = (table.clone(), f.db_name().to_owned());
 
                 Column::from(parts)
             })
@@ -149,11 +147,9 @@ where
 {
     fn as_column(&self) -> Column<'static> {
         let sf = self.as_ref();
-        let db = sf.internal_data_model().db_name.clone();
-        let table = sf.model().db_name().to_string();
         let col = sf.db_name().to_string();
 
-        let column = Column::from(((db, table), col));
+        let column = Column::from((model_table(&sf.model()), col));
 
         match sf.default_value.as_ref().and_then(|d| d.get()) {
             Some(default) => column.default(sf.value(default)),
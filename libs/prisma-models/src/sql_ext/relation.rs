@@ -1,4 +1,5 @@
 use crate::sql_ext::column::AsColumns;
+use crate::sql_ext::table::datasource_table;
 use crate::RelationLinkManifestation::*;
 use crate::{AsTable, ColumnIterator, Relation, RelationField, RelationLinkManifestation, RelationSide};
 use quaint::{ast::Table, prelude::Column};
@@ -64,8 +65,7 @@ impl AsTable for Relation {
             // table, so MSSQL can convert the `INSERT .. ON CONFLICT IGNORE` into
             // a `MERGE` statement.
             RelationLinkManifestation::RelationTable(ref m) => {
-                let db = self.model_a().internal_data_model().db_name.clone();
-                let table: Table = (db, m.table.clone()).into();
+                let table = datasource_table(&self.model_a().internal_data_model(), m.table.clone());
 
                 table.add_unique_index(vec![Column::from("A"), Column::from("B")])
             }
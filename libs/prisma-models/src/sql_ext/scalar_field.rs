@@ -10,6 +10,14 @@ pub trait ScalarFieldExt {
 impl ScalarFieldExt for ScalarField {
     fn value<'a>(&self, pv: PrismaValue) -> Value<'a> {
         match (pv, &self.type_identifier) {
+            (PrismaValue::Uuid(u), TypeIdentifier::UUID) => Value::Uuid(Some(u)),
+            (PrismaValue::Uuid(u), _) if self.is_native_uuid => Value::Uuid(Some(u)),
+            // Native UUID columns are compared with UUIDs, which uses their indexes. The values of these
+            // fields are validated when the query is built, partial strings of `contains` filters don't get here.
+            (PrismaValue::String(s), _) if self.is_native_uuid => match s.parse() {
+                Ok(u) => Value::Uuid(Some(u)),
+                Err(_) => s.into(),
+            },
             (PrismaValue::String(s), _) => s.into(),
             (PrismaValue::Float(f), _) => f.into(),
             (PrismaValue::Boolean(b), _) => b.into(),
@@ -19,16 +27,19 @@ impl ScalarFieldExt for ScalarField {
             (PrismaValue::Uuid(u), _) => u.to_string().into(),
             (PrismaValue::List(l), _) => Value::Array(Some(l.into_iter().map(|x| self.value(x)).collect())),
             (PrismaValue::Json(s), _) => Value::Json(serde_json::from_str(&s).unwrap()),
+            (PrismaValue::Bytes(b), _) => Value::Bytes(Some(b.into())),
             (PrismaValue::Null, ident) => match ident {
                 _ if self.is_list => Value::Array(None),
+                _ if self.is_native_uuid => Value::Uuid(None),
                 TypeIdentifier::String => Value::Text(None),
-                TypeIdentifier::Float => Value::Real(None),
+                TypeIdentifier::Float | TypeIdentifier::Decimal => Value::Real(None),
                 TypeIdentifier::Boolean => Value::Boolean(None),
                 TypeIdentifier::Enum(_) => Value::Enum(None),
                 TypeIdentifier::Json => Value::Json(None),
+                TypeIdentifier::Bytes => Value::Bytes(None),
                 TypeIdentifier::DateTime => Value::DateTime(None),
                 TypeIdentifier::UUID => Value::Uuid(None),
-                TypeIdentifier::Int => Value::Integer(None),
+                TypeIdentifier::Int | TypeIdentifier::BigInt => Value::Integer(None),
             },
         }
     }
@@ -47,6 +58,7 @@ pub fn convert_lossy<'a>(pv: PrismaValue) -> Value<'a> {
         PrismaValue::Uuid(u) => u.to_string().into(),
         PrismaValue::List(l) => Value::Array(Some(l.into_iter().map(|x| convert_lossy(x)).collect())),
         PrismaValue::Json(s) => Value::Json(serde_json::from_str(&s).unwrap()),
+        PrismaValue::Bytes(b) => Value::Bytes(Some(b.into())),
         PrismaValue::Null => Value::Integer(None), // Can't tell which type the null is supposed to be.
     }
 }
@@ -1,14 +1,34 @@
 use super::AsColumn;
-use crate::Model;
+use crate::{InternalDataModel, Model};
 use quaint::ast::{Column, Table};
 
 pub trait AsTable {
     fn as_table(&self) -> Table<'static>;
 }
 
+/// The table of a model. Models mapped to a qualified `database.table` name live in another database of the
+/// server, on MySQL.
+pub(crate) fn model_table(model: &Model) -> Table<'static> {
+    let mut qualified = model.db_name().splitn(2, '.');
+
+    match (qualified.next(), qualified.next()) {
+        (Some(database), Some(table)) => (database.to_owned(), table.to_owned()).into(),
+        _ => datasource_table(&model.internal_data_model(), model.db_name().to_owned()),
+    }
+}
+
+/// A table in the schema or database of the datasource. Without one, when the engine serves several tenants, the
+/// table is not qualified and resolves to the schema or database the connection is switched to.
+pub(crate) fn datasource_table(internal_data_model: &InternalDataModel, table: String) -> Table<'static> {
+    match internal_data_model.db_name.as_str() {
+        "" => table.into(),
+        db_name => (db_name.to_owned(), table).into(),
+    }
+}
+
 impl AsTable for Model {
     fn as_table(&self) -> Table<'static> {
-        let table: Table<'static> = (self.internal_data_model().db_name.clone(), self.db_name().to_string()).into();
+        let table = model_table(self);
 
         self.unique_indexes().into_iter().fold(table, |table, index| {
             let index: Vec<Column<'static>> = index.fields().iter().map(AsColumn::as_column).collect();
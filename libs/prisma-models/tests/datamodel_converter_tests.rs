@@ -226,6 +226,57 @@ fn uuid_fields_must_work() {
         .assert_type_identifier(TypeIdentifier::String);
 }
 
+#[test]
+fn read_only_fields_must_be_computed_and_not_writable() {
+    let datamodel = convert(
+        r#"
+            model Person {
+                id       Int    @id
+                name     String
+                fullName String @default(dbgenerated()) @readOnly
+            }
+        "#,
+    );
+
+    let model = datamodel.assert_model("Person");
+    let full_name = model.assert_scalar_field("fullName");
+
+    assert!(full_name.is_computed);
+    assert!(full_name.is_read_only());
+    assert!(!model.assert_scalar_field("name").is_computed);
+
+    let writable: Vec<String> = model.fields().scalar_writable().map(|f| f.name.clone()).collect();
+    assert_eq!(writable, vec!["id", "name"]);
+}
+
+#[test]
+fn native_uuid_fields_must_stay_string_fields() {
+    let datamodel = convert(
+        r#"
+            datasource pg {
+                provider = "postgres"
+                url = "postgresql://localhost:5432"
+                previewFeatures = ["nativeTypes"]
+            }
+
+            model Test {
+                id    String @id @default(uuid()) @pg.Uuid
+                other String @pg.Uuid
+                text  String
+            }
+        "#,
+    );
+
+    let model = datamodel.assert_model("Test");
+
+    for (name, is_native_uuid) in &[("id", true), ("other", true), ("text", false)] {
+        let field = model.assert_scalar_field(name);
+
+        field.assert_type_identifier(TypeIdentifier::String);
+        assert_eq!(field.is_native_uuid, *is_native_uuid);
+    }
+}
+
 #[test]
 fn cuid_fields_must_work() {
     let datamodel = convert(
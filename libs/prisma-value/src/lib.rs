@@ -37,6 +37,9 @@ pub enum PrismaValue {
 
     #[serde(serialize_with = "serialize_decimal")]
     Float(Decimal),
+
+    #[serde(serialize_with = "serialize_bytes")]
+    Bytes(Vec<u8>),
 }
 
 pub fn stringify_date(date: &DateTime<Utc>) -> String {
@@ -100,6 +103,13 @@ where
     Option::<u8>::None.serialize(serializer)
 }
 
+fn serialize_bytes<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    base64::encode(bytes).serialize(serializer)
+}
+
 fn serialize_decimal<S>(decimal: &Decimal, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -150,6 +160,7 @@ impl fmt::Display for PrismaValue {
             PrismaValue::Null => "null".fmt(f),
             PrismaValue::Uuid(x) => x.fmt(f),
             PrismaValue::Json(x) => x.fmt(f),
+            PrismaValue::Bytes(x) => base64::encode(x).fmt(f),
             PrismaValue::List(x) => {
                 let as_string = format!("{:?}", x);
                 as_string.fmt(f)
@@ -0,0 +1,133 @@
+//! Parsing of the version strings returned by the database servers.
+
+use quaint::prelude::SqlFamily;
+
+/// The vendor of a database server, as far as it can be told from its version string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatabaseFlavour {
+    Mysql,
+    MariaDb,
+    /// Amazon Aurora, MySQL-compatible edition.
+    Aurora,
+    Postgres,
+    CockroachDb,
+    Sqlite,
+    Mssql,
+}
+
+/// The parsed version of a database server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DatabaseVersion {
+    pub flavour: DatabaseFlavour,
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl DatabaseVersion {
+    /// Parses the version string returned by quaint's `version()` for a connection of the given family, e.g.
+    /// `PostgreSQL 13.1 on x86_64-pc-linux-gnu, ...` or `10.5.8-MariaDB-1:10.5.8+maria~focal`.
+    /// Returns `None` if the string doesn't contain a version number.
+    pub fn parse(family: SqlFamily, version: &str) -> Option<Self> {
+        let (flavour, numbers) = match family {
+            SqlFamily::Postgres if version.starts_with("CockroachDB") => {
+                (DatabaseFlavour::CockroachDb, version.splitn(2, " v").nth(1)?)
+            }
+            SqlFamily::Postgres => (
+                DatabaseFlavour::Postgres,
+                version.trim_start_matches("PostgreSQL").trim_start(),
+            ),
+            // MariaDB servers may prefix their version with `5.5.5-` for replication compatibility.
+            SqlFamily::Mysql if version.contains("MariaDB") => {
+                (DatabaseFlavour::MariaDb, version.trim_start_matches("5.5.5-"))
+            }
+            SqlFamily::Mysql if version.to_lowercase().contains("aurora") => (DatabaseFlavour::Aurora, version),
+            SqlFamily::Mysql => (DatabaseFlavour::Mysql, version),
+            SqlFamily::Sqlite => (DatabaseFlavour::Sqlite, version),
+            // `Microsoft SQL Server 2019 (RTM-CU8) (KB4577194) - 15.0.4073.23 (X64) ...`
+            SqlFamily::Mssql => (DatabaseFlavour::Mssql, version.splitn(2, " - ").nth(1)?),
+        };
+
+        let mut numbers = numbers.split('.').map(|part| {
+            let digits: String = part.chars().take_while(|c| c.is_ascii_digit()).collect();
+            digits.parse::<u32>().ok()
+        });
+
+        let major = numbers.next().flatten()?;
+        let minor = numbers.next().flatten().unwrap_or(0);
+        let patch = numbers.next().flatten().unwrap_or(0);
+
+        Some(DatabaseVersion {
+            flavour,
+            major,
+            minor,
+            patch,
+        })
+    }
+
+    /// Whether the server speaks the MySQL protocol, whatever its vendor.
+    pub fn is_mysql_compatible(&self) -> bool {
+        matches!(
+            self.flavour,
+            DatabaseFlavour::Mysql | DatabaseFlavour::MariaDb | DatabaseFlavour::Aurora
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(family: SqlFamily, version: &str) -> (DatabaseFlavour, u32, u32, u32) {
+        let version = DatabaseVersion::parse(family, version).unwrap();
+
+        (version.flavour, version.major, version.minor, version.patch)
+    }
+
+    #[test]
+    fn parsing_version_strings_works() {
+        use DatabaseFlavour::*;
+
+        assert_eq!(
+            parse(
+                SqlFamily::Postgres,
+                "PostgreSQL 13.1 on x86_64-pc-linux-gnu, compiled by gcc"
+            ),
+            (Postgres, 13, 1, 0)
+        );
+        assert_eq!(
+            parse(SqlFamily::Postgres, "PostgreSQL 9.6.20 on x86_64-pc-linux-gnu"),
+            (Postgres, 9, 6, 20)
+        );
+        assert_eq!(
+            parse(
+                SqlFamily::Postgres,
+                "CockroachDB CCL v20.2.3 (x86_64-unknown-linux-gnu, built 2020/12/14)"
+            ),
+            (CockroachDb, 20, 2, 3)
+        );
+        assert_eq!(parse(SqlFamily::Mysql, "5.6.50"), (Mysql, 5, 6, 50));
+        assert_eq!(parse(SqlFamily::Mysql, "8.0.22-log"), (Mysql, 8, 0, 22));
+        assert_eq!(
+            parse(SqlFamily::Mysql, "10.5.8-MariaDB-1:10.5.8+maria~focal"),
+            (MariaDb, 10, 5, 8)
+        );
+        assert_eq!(parse(SqlFamily::Mysql, "5.5.5-10.3.27-MariaDB"), (MariaDb, 10, 3, 27));
+        assert_eq!(parse(SqlFamily::Mysql, "5.7.mysql_aurora.2.07.2"), (Aurora, 5, 7, 0));
+        assert_eq!(parse(SqlFamily::Sqlite, "3.33.0"), (Sqlite, 3, 33, 0));
+        assert_eq!(
+            parse(
+                SqlFamily::Mssql,
+                "Microsoft SQL Server 2019 (RTM-CU8) (KB4577194) - 15.0.4073.23 (X64)"
+            ),
+            (Mssql, 15, 0, 4073)
+        );
+    }
+
+    #[test]
+    fn version_strings_without_numbers_are_not_parsed() {
+        assert!(DatabaseVersion::parse(SqlFamily::Mysql, "").is_none());
+        assert!(DatabaseVersion::parse(SqlFamily::Postgres, "PostgreSQL").is_none());
+        assert!(DatabaseVersion::parse(SqlFamily::Mssql, "Microsoft SQL Server").is_none());
+    }
+}
@@ -12,6 +12,7 @@ use std::{fmt, str::FromStr};
 use thiserror::Error;
 use tracing::debug;
 
+pub mod database_version;
 pub mod mssql;
 pub mod mysql;
 pub mod postgres;
@@ -58,6 +59,37 @@ pub struct SqlSchema {
     pub enums: Vec<Enum>,
     /// The schema's sequences, unique to Postgres.
     pub sequences: Vec<Sequence>,
+    /// The schema's distributed tables, unique to Postgres with the Citus extension.
+    #[serde(default)]
+    pub distributed_tables: Vec<DistributedTable>,
+    /// The privileges the connecting user is missing on the schema's tables.
+    #[serde(default)]
+    pub missing_privileges: Vec<MissingPrivilege>,
+    /// The columns set to the current timestamp whenever their row is updated, unique to MySQL.
+    #[serde(default)]
+    pub on_update_timestamps: Vec<OnUpdateTimestamp>,
+    /// The schema's views, with the columns they return.
+    #[serde(default)]
+    pub views: Vec<View>,
+    /// The columns computed from other columns of their row, with `GENERATED ALWAYS AS`.
+    #[serde(default)]
+    pub generated_columns: Vec<GeneratedColumn>,
+    /// The CHECK constraints of the schema's tables.
+    #[serde(default)]
+    pub check_constraints: Vec<CheckConstraint>,
+    /// The values the auto-incrementing columns of the schema's tables will take next.
+    #[serde(default)]
+    pub auto_increment_values: Vec<AutoIncrementValue>,
+    /// The schema's system-versioned temporal tables, unique to SQL Server.
+    #[serde(default)]
+    pub temporal_tables: Vec<TemporalTable>,
+    /// The schema's memory-optimized tables, unique to SQL Server.
+    #[serde(default)]
+    pub memory_optimized_tables: Vec<MemoryOptimizedTable>,
+    /// The tables of other schemas of the server that foreign keys of the schema reference, named
+    /// `schema.table`, unique to MySQL. They are not part of the schema.
+    #[serde(default)]
+    pub external_tables: Vec<Table>,
 }
 
 impl SqlSchema {
@@ -75,8 +107,14 @@ impl SqlSchema {
         self.enums.iter().find(|x| x.name == name)
     }
 
+    /// Get a table, or an external table by its qualified name.
     pub fn table(&self, name: &str) -> core::result::Result<&Table, String> {
-        match self.tables.iter().find(|t| t.name == name) {
+        match self
+            .tables
+            .iter()
+            .chain(self.external_tables.iter())
+            .find(|t| t.name == name)
+        {
             Some(t) => Ok(t),
             None => Err(name.to_string()),
         }
@@ -86,27 +124,102 @@ impl SqlSchema {
         self.table(&name).unwrap()
     }
 
+    /// Get a view.
+    pub fn get_view(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|x| x.name == name)
+    }
+
     /// Get a sequence.
     pub fn get_sequence(&self, name: &str) -> Option<&Sequence> {
         self.sequences.iter().find(|x| x.name == name)
     }
 
+    /// Get the distribution of a table.
+    pub fn get_distributed_table(&self, table_name: &str) -> Option<&DistributedTable> {
+        self.distributed_tables.iter().find(|x| x.table == table_name)
+    }
+
+    /// Get the system versioning of a table.
+    pub fn get_temporal_table(&self, table_name: &str) -> Option<&TemporalTable> {
+        self.temporal_tables.iter().find(|x| x.table == table_name)
+    }
+
+    /// Whether the table is a memory-optimized table.
+    pub fn is_memory_optimized(&self, table_name: &str) -> bool {
+        self.memory_optimized_tables.iter().any(|x| x.table == table_name)
+    }
+
+    /// Whether the column is set to the current timestamp whenever its row is updated.
+    pub fn is_on_update_timestamp(&self, table_name: &str, column_name: &str) -> bool {
+        self.on_update_timestamps
+            .iter()
+            .any(|x| x.table == table_name && x.column == column_name)
+    }
+
+    /// Get the generation of a column, if it is computed by the database.
+    pub fn get_generated_column(&self, table_name: &str, column_name: &str) -> Option<&GeneratedColumn> {
+        self.generated_columns
+            .iter()
+            .find(|x| x.table == table_name && x.column == column_name)
+    }
+
+    /// Whether the column is computed by the database, and can not be written to.
+    pub fn is_generated_column(&self, table_name: &str, column_name: &str) -> bool {
+        self.get_generated_column(table_name, column_name).is_some()
+    }
+
+    /// Get the CHECK constraints of a table.
+    pub fn check_constraints_on<'a>(&'a self, table_name: &'a str) -> impl Iterator<Item = &'a CheckConstraint> + 'a {
+        self.check_constraints.iter().filter(move |x| x.table == table_name)
+    }
+
+    /// Get the value the auto-incrementing column of a table will take next, if it was used.
+    pub fn get_auto_increment_value(&self, table_name: &str) -> Option<&AutoIncrementValue> {
+        self.auto_increment_values.iter().find(|x| x.table == table_name)
+    }
+
+    /// Get the privileges the connecting user is missing on a table or its columns.
+    pub fn missing_privileges_on<'a>(&'a self, table_name: &'a str) -> impl Iterator<Item = &'a MissingPrivilege> + 'a {
+        self.missing_privileges.iter().filter(move |x| x.table == table_name)
+    }
+
     pub fn empty() -> SqlSchema {
         SqlSchema {
             tables: Vec::new(),
             enums: Vec::new(),
             sequences: Vec::new(),
+            distributed_tables: Vec::new(),
+            missing_privileges: Vec::new(),
+            on_update_timestamps: Vec::new(),
+            views: Vec::new(),
+            generated_columns: Vec::new(),
+            check_constraints: Vec::new(),
+            auto_increment_values: Vec::new(),
+            temporal_tables: Vec::new(),
+            memory_optimized_tables: Vec::new(),
+            external_tables: Vec::new(),
         }
     }
 }
 
+/// The schema and the name of a table referenced by its qualified `schema.table` name, when it lives in
+/// another schema of the server.
+pub fn split_qualified_name(name: &str) -> Option<(&str, &str)> {
+    let mut parts = name.splitn(2, '.');
+
+    match (parts.next(), parts.next()) {
+        (Some(schema), Some(table)) => Some((schema, table)),
+        _ => None,
+    }
+}
+
 /// A table found in a schema.
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Table {
     /// The table's name.
     pub name: String,
-    /// The table's columns.
+    /// The table's columns, in the order of their ordinal positions in the table.
     pub columns: Vec<Column>,
     /// The table's indices.
     pub indices: Vec<Index>,
@@ -154,14 +267,41 @@ impl Table {
         }
     }
 
+    /// Keeps `nulls_not_distinct` on the indexes with nullable columns only.
+    pub(crate) fn normalize_nulls_not_distinct(&mut self) {
+        let columns = &self.columns;
+
+        for index in self.indices.iter_mut() {
+            index.nulls_not_distinct = index.nulls_not_distinct
+                && index.columns.iter().any(|name| {
+                    columns
+                        .iter()
+                        .any(|column| &column.name == name && column.tpe.arity.is_nullable())
+                });
+        }
+    }
+
     pub fn is_column_unique(&self, column_name: &str) -> bool {
         self.indices.iter().any(|index| {
             index.tpe == IndexType::Unique
+                && !index.is_partial()
                 && index.columns.len() == 1
                 && index.columns.contains(&column_name.to_owned())
         })
     }
 }
+/// A view found in a schema.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    /// The view's name.
+    pub name: String,
+    /// The query defining the view, if the database exposes it.
+    pub definition: Option<String>,
+    /// The columns the view returns.
+    pub columns: Vec<Column>,
+}
+
 /// The type of an index.
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -191,12 +331,54 @@ pub struct Index {
     pub columns: Vec<String>,
     /// Type of index.
     pub tpe: IndexType,
+    /// Whether the index is clustered. Only described on SQL Server.
+    pub clustered: Option<bool>,
+    /// The WHERE clause of partial indexes on Postgres and filtered indexes on SQL Server, as
+    /// rendered by the database.
+    pub predicate: Option<String>,
+    /// Whether the unique index considers NULLs equal, so only one row can have NULLs in its
+    /// columns: `NULLS NOT DISTINCT` on Postgres 15, unique indexes without a filter on SQL Server.
+    /// Only set on indexes with nullable columns, where it makes a difference.
+    #[serde(default)]
+    pub nulls_not_distinct: bool,
 }
 
 impl Index {
     pub fn is_unique(&self) -> bool {
         self.tpe == IndexType::Unique
     }
+
+    /// The predicate of the index, unless it only excludes the NULLs of the unique index's own
+    /// columns: that is how unique indexes on nullable columns get the NULL semantics of the other
+    /// databases on SQL Server, they cover all the rows that matter.
+    pub fn partial_predicate(&self) -> Option<&str> {
+        let predicate = self.predicate.as_deref()?;
+
+        if self.is_unique() && self.only_excludes_nulls(predicate) {
+            return None;
+        }
+
+        Some(predicate)
+    }
+
+    /// Whether the index only covers the rows matching its predicate.
+    pub fn is_partial(&self) -> bool {
+        self.partial_predicate().is_some()
+    }
+
+    fn only_excludes_nulls(&self, predicate: &str) -> bool {
+        let predicate: String = predicate
+            .chars()
+            .filter(|c| !matches!(c, '(' | ')' | '[' | ']' | '"' | '`'))
+            .collect::<String>()
+            .to_lowercase();
+
+        predicate.split(" and ").all(|condition| {
+            self.columns
+                .iter()
+                .any(|column| condition.trim() == format!("{} is not null", column.to_lowercase()))
+        })
+    }
 }
 
 /// The primary key of a table.
@@ -209,6 +391,8 @@ pub struct PrimaryKey {
     pub sequence: Option<Sequence>,
     /// The name of the primary key constraint, when available.
     pub constraint_name: Option<String>,
+    /// Whether the primary key is clustered. Only described on SQL Server.
+    pub clustered: Option<bool>,
 }
 
 impl PrimaryKey {
@@ -272,6 +456,8 @@ impl ColumnType {
 pub enum ColumnTypeFamily {
     /// Integer types.
     Int,
+    /// 64 bit integer types.
+    BigInt,
     /// Floating point types.
     Float,
     /// Boolean types.
@@ -294,6 +480,8 @@ pub enum ColumnTypeFamily {
     TextSearch,
     /// Transaction ID types.
     TransactionId,
+    /// XML types.
+    Xml,
     ///Enum
     Enum(String),
     /// Unsupported
@@ -332,6 +520,7 @@ impl fmt::Display for ColumnTypeFamily {
             Self::LogSequenceNumber => "logSequenceNumber".to_string(),
             Self::TextSearch => "textSearch".to_string(),
             Self::TransactionId => "transactionId".to_string(),
+            Self::Xml => "xml".to_string(),
             Self::Enum(x) => format!("Enum({})", &x),
             Self::Unsupported(x) => x.to_string(),
         };
@@ -391,7 +580,7 @@ pub struct ForeignKey {
     pub constraint_name: Option<String>,
     /// Column names.
     pub columns: Vec<String>,
-    /// Referenced table.
+    /// Referenced table. Qualified as `schema.table` when it lives in another schema of the server.
     pub referenced_table: String,
     /// Referenced columns.
     pub referenced_columns: Vec<String>,
@@ -431,19 +620,223 @@ pub struct Sequence {
     pub allocation_size: u32,
 }
 
+/// The value an auto-incrementing column will take next, from MySQL's `AUTO_INCREMENT` table
+/// option or the current value of a Postgres serial sequence. Only counters that already moved
+/// are described.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoIncrementValue {
+    /// The name of the table.
+    pub table: String,
+    /// The name of the auto-incrementing column.
+    pub column: String,
+    /// The next value of the column.
+    pub next_value: u64,
+}
+
+/// A table sharded across the nodes of a Citus cluster.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DistributedTable {
+    /// The name of the table.
+    pub table: String,
+    /// The column the rows of the table are distributed by.
+    pub distribution_column: String,
+}
+
+/// A system-versioned temporal table on SQL Server. The history table and the period columns are
+/// managed by the database, they are not part of the described tables.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemporalTable {
+    /// The name of the table.
+    pub table: String,
+    /// The name of the table the previous versions of the rows are kept in.
+    pub history_table: String,
+    /// The column of the period a row version is valid from.
+    pub period_start_column: String,
+    /// The column of the period a row version is valid until.
+    pub period_end_column: String,
+}
+
+/// A table kept in memory on SQL Server, with `MEMORY_OPTIMIZED = ON`.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryOptimizedTable {
+    /// The name of the table.
+    pub table: String,
+    /// Whether the rows are persisted, or only the schema (`SCHEMA_ONLY` durability).
+    pub durable: bool,
+}
+
+/// A column set to the current timestamp whenever its row is updated, with MySQL's
+/// `ON UPDATE CURRENT_TIMESTAMP`.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnUpdateTimestamp {
+    /// The name of the table.
+    pub table: String,
+    /// The name of the column.
+    pub column: String,
+}
+
+/// A column computed from other columns of its row, with MySQL's `GENERATED ALWAYS AS`.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratedColumn {
+    /// The name of the table.
+    pub table: String,
+    /// The name of the column.
+    pub column: String,
+    /// The expression computing the value of the column.
+    pub expression: String,
+    /// Whether the value is stored, or computed when read.
+    pub stored: bool,
+}
+
+/// A CHECK constraint of a table.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckConstraint {
+    /// The name of the table.
+    pub table: String,
+    /// The name of the constraint.
+    pub name: String,
+    /// The checked expression, as rendered by the database.
+    pub definition: String,
+}
+
+impl CheckConstraint {
+    /// Whether the checked expression mentions the column.
+    pub fn references_column(&self, column_name: &str) -> bool {
+        self.definition
+            .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+            .any(|word| word.eq_ignore_ascii_case(column_name))
+    }
+}
+
+/// A privilege the connecting user is missing on a table, or only on some of its columns.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingPrivilege {
+    /// The name of the table.
+    pub table: String,
+    /// The name of the column, if the privilege is granted on other columns of the table.
+    pub column: Option<String>,
+    /// The missing privilege.
+    pub privilege: Privilege,
+}
+
+/// The table and column privileges relevant for reading and writing data.
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Privilege {
+    /// Needed to read data.
+    Select,
+    /// Needed to create records.
+    Insert,
+}
+
+impl fmt::Display for Privilege {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Privilege::Select => f.write_str("SELECT"),
+            Privilege::Insert => f.write_str("INSERT"),
+        }
+    }
+}
+
+/// The privileges of the connecting user on a column.
+#[derive(Debug)]
+pub(crate) struct ColumnPrivileges {
+    pub(crate) table: String,
+    pub(crate) column: String,
+    pub(crate) can_select: bool,
+    pub(crate) can_insert: bool,
+}
+
+/// Privileges missing on all the columns of a table are reported for the whole table.
+pub(crate) fn missing_privileges(columns: Vec<ColumnPrivileges>) -> Vec<MissingPrivilege> {
+    let mut tables: Vec<(String, Vec<ColumnPrivileges>)> = Vec::new();
+
+    for column in columns {
+        match tables.iter_mut().find(|(table, _)| *table == column.table) {
+            Some((_, table_columns)) => table_columns.push(column),
+            None => tables.push((column.table.clone(), vec![column])),
+        }
+    }
+
+    let mut missing = Vec::new();
+
+    for (table, columns) in tables {
+        for privilege in &[Privilege::Select, Privilege::Insert] {
+            let columns_missing_it: Vec<&ColumnPrivileges> = columns
+                .iter()
+                .filter(|column| match privilege {
+                    Privilege::Select => !column.can_select,
+                    Privilege::Insert => !column.can_insert,
+                })
+                .collect();
+
+            if columns_missing_it.len() == columns.len() {
+                missing.push(MissingPrivilege {
+                    table: table.clone(),
+                    column: None,
+                    privilege: *privilege,
+                });
+            } else {
+                missing.extend(columns_missing_it.into_iter().map(|column| MissingPrivilege {
+                    table: table.clone(),
+                    column: Some(column.column.clone()),
+                    privilege: *privilege,
+                }));
+            }
+        }
+    }
+
+    missing
+}
+
 /// A DefaultValue
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub enum DefaultValue {
     /// A constant value, parsed as String
     VALUE(PrismaValue),
+    /// An explicit `DEFAULT NULL`. It behaves like no default at all, but the database still records
+    /// it, e.g. as a default constraint on MSSQL.
+    NULL,
     /// An expression generating a current timestamp.
     NOW,
     /// An expression generating a sequence.
     SEQUENCE(String),
+    /// The next value of a sequence that is not the column's own, by sequence name, e.g. a sequence
+    /// shared by several tables.
+    NEXTVAL(String),
+    /// A PostgreSQL identity column, `GENERATED ... AS IDENTITY`. Its values come from a sequence,
+    /// like `SERIAL` columns, but there is no default expression.
+    IDENTITY(IdentityGeneration),
     /// An unrecognized Default Value
     DBGENERATED(String),
 }
 
+/// When the database generates the values of an identity column.
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum IdentityGeneration {
+    /// `GENERATED ALWAYS AS IDENTITY`: values can only be given with `OVERRIDING SYSTEM VALUE`.
+    Always,
+    /// `GENERATED BY DEFAULT AS IDENTITY`: given values take precedence, like with `SERIAL`.
+    ByDefault,
+}
+
+impl fmt::Display for IdentityGeneration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdentityGeneration::Always => f.write_str("GENERATED ALWAYS AS IDENTITY"),
+            IdentityGeneration::ByDefault => f.write_str("GENERATED BY DEFAULT AS IDENTITY"),
+        }
+    }
+}
+
 impl DefaultValue {
     pub fn as_value(&self) -> Option<&PrismaValue> {
         match self {
@@ -451,8 +844,42 @@ impl DefaultValue {
             _ => None,
         }
     }
+
+    /// Whether this is an explicit `DEFAULT NULL`.
+    pub fn is_null(&self) -> bool {
+        matches!(self, DefaultValue::NULL)
+    }
+
+    /// Whether this is the default of an identity column.
+    pub fn is_identity(&self) -> bool {
+        matches!(self, DefaultValue::IDENTITY(_))
+    }
+
+    /// Whether the default is a call to a known function generating random UUIDs, i.e.
+    /// `gen_random_uuid()` or `uuid_generate_v4()` on PostgreSQL. Schema-qualified and cast calls,
+    /// e.g. `(public.uuid_generate_v4())::text`, are recognized too.
+    pub fn is_uuid_generation(&self) -> bool {
+        let expression = match self {
+            DefaultValue::DBGENERATED(expression) => expression.to_lowercase(),
+            _ => return false,
+        };
+
+        let expression: String = expression.chars().filter(|c| !c.is_whitespace()).collect();
+        let call = expression.split("::").next().unwrap_or("");
+        let call = call.trim_start_matches('(');
+        let call = if call.ends_with("))") {
+            &call[..call.len() - 1]
+        } else {
+            call
+        };
+        let function = call.rsplit('.').next().unwrap_or(call);
+
+        UUID_GENERATING_FUNCTIONS.contains(&function)
+    }
 }
 
+const UUID_GENERATING_FUNCTIONS: &[&str] = &["gen_random_uuid()", "uuid_generate_v4()"];
+
 static RE_NUM: Lazy<Regex> = Lazy::new(|| Regex::new(r"^'?(\d+)'?$").expect("compile regex"));
 static RE_FLOAT: Lazy<Regex> = Lazy::new(|| Regex::new(r"^'?([^']+)'?$").expect("compile regex"));
 
@@ -507,4 +934,19 @@ mod tests {
 
         assert_eq!(unquote_string("heh ".into()), "heh ");
     }
+
+    #[test]
+    fn uuid_generating_defaults_are_recognized() {
+        let generated = |expression: &str| DefaultValue::DBGENERATED(expression.to_owned()).is_uuid_generation();
+
+        assert!(generated("gen_random_uuid()"));
+        assert!(generated("uuid_generate_v4()"));
+        assert!(generated("public.uuid_generate_v4()"));
+        assert!(generated("(gen_random_uuid())::text"));
+        assert!(generated("GEN_RANDOM_UUID( )"));
+
+        assert!(!generated("uuid_generate_v1()"));
+        assert!(!generated("md5(random()::text)"));
+        assert!(!DefaultValue::VALUE(PrismaValue::String("gen_random_uuid()".into())).is_uuid_generation());
+    }
 }
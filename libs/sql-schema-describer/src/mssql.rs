@@ -45,10 +45,24 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables.push(table);
         }
 
+        let check_constraints = self.get_check_constraints(schema).await;
+        let temporal_tables = self.get_temporal_tables(schema).await;
+        let memory_optimized_tables = self.get_memory_optimized_tables(schema).await;
+
         Ok(SqlSchema {
             tables,
             enums: vec![],
             sequences: vec![],
+            distributed_tables: vec![],
+            missing_privileges: vec![],
+            on_update_timestamps: vec![],
+            views: vec![],
+            generated_columns: vec![],
+            check_constraints,
+            auto_increment_values: vec![],
+            temporal_tables,
+            memory_optimized_tables,
+            external_tables: vec![],
         })
     }
 
@@ -95,6 +109,8 @@ impl SqlSchemaDescriber {
             WHERE table_schema = @P1
             AND st.is_ms_shipped = 'false'
             AND table_type = 'BASE TABLE'
+            -- The history tables of temporal tables are managed by the database.
+            AND st.temporal_type <> 1
             ORDER BY table_name ASC
         "#;
 
@@ -160,13 +176,16 @@ impl SqlSchemaDescriber {
 
         let foreign_keys = foreign_keys.remove(name).unwrap_or_default();
 
-        Table {
+        let mut table = Table {
             name: name.to_string(),
             columns,
             foreign_keys,
             indices: indices.into_iter().map(|(_k, v)| v).collect(),
             primary_key,
-        }
+        };
+
+        table.normalize_nulls_not_distinct();
+        table
     }
 
     async fn get_all_columns(&self, schema: &str) -> HashMap<String, Vec<Column>> {
@@ -184,6 +203,8 @@ impl SqlSchemaDescriber {
             ON c.TABLE_NAME = t.name AND SCHEMA_ID(c.TABLE_SCHEMA) = t.schema_id
             WHERE table_schema = @P1
             AND t.is_ms_shipped = 'false'
+            -- The period columns of temporal tables are described with the tables.
+            AND COLUMNPROPERTY(t.object_id, c.column_name, 'GeneratedAlwaysType') = 0
             ORDER BY ordinal_position
         "#;
 
@@ -253,7 +274,9 @@ impl SqlSchemaDescriber {
                             .expect("Couldn't parse default value");
 
                         Some(match &tpe.family {
-                            ColumnTypeFamily::Int => match parse_int(&default_string) {
+                            // `DEFAULT NULL` still creates a default constraint, as `(NULL)`.
+                            _ if default_string == "NULL" => DefaultValue::NULL,
+                            ColumnTypeFamily::Int | ColumnTypeFamily::BigInt => match parse_int(&default_string) {
                                 Some(int_value) => DefaultValue::VALUE(int_value),
                                 None => DefaultValue::DBGENERATED(default_string),
                             },
@@ -279,6 +302,7 @@ impl SqlSchemaDescriber {
                             ColumnTypeFamily::LogSequenceNumber => DefaultValue::DBGENERATED(default_string),
                             ColumnTypeFamily::TextSearch => DefaultValue::DBGENERATED(default_string),
                             ColumnTypeFamily::TransactionId => DefaultValue::DBGENERATED(default_string),
+                            ColumnTypeFamily::Xml => DefaultValue::DBGENERATED(default_string),
                             ColumnTypeFamily::Enum(_) => unreachable!("No enums in MSSQL"),
                             ColumnTypeFamily::Unsupported(_) => DefaultValue::DBGENERATED(default_string),
                         })
@@ -306,6 +330,8 @@ impl SqlSchemaDescriber {
                 ind.name AS index_name,
                 ind.is_unique AS is_unique,
                 ind.is_primary_key AS is_primary_key,
+                ind.type_desc AS index_type,
+                ind.filter_definition AS filter_definition,
                 col.name AS column_name,
                 ic.index_column_id AS seq_in_index,
                 t.name AS table_name
@@ -339,6 +365,11 @@ impl SqlSchemaDescriber {
                     let seq_in_index = row.get("seq_in_index").and_then(|x| x.as_i64()).expect("seq_in_index");
                     let pos = seq_in_index - 1;
                     let is_unique = row.get("is_unique").and_then(|x| x.as_bool()).expect("is_unique");
+                    let clustered = row
+                        .get("index_type")
+                        .and_then(|x| x.to_string())
+                        .map(|index_type| index_type == "CLUSTERED");
+                    let predicate = row.get("filter_definition").and_then(|x| x.to_string());
 
                     // Multi-column indices will return more than one row (with different column_name values).
                     // We cannot assume that one row corresponds to one index.
@@ -374,6 +405,7 @@ impl SqlSchemaDescriber {
                                     columns: vec![column_name],
                                     sequence: None,
                                     constraint_name: None,
+                                    clustered,
                                 });
                             }
                         };
@@ -391,6 +423,10 @@ impl SqlSchemaDescriber {
                                     true => IndexType::Unique,
                                     false => IndexType::Normal,
                                 },
+                                clustered,
+                                // Unique indexes consider NULLs equal, unless a filter leaves them out.
+                                nulls_not_distinct: is_unique && predicate.is_none(),
+                                predicate,
                             },
                         );
                     }
@@ -412,6 +448,139 @@ impl SqlSchemaDescriber {
         map
     }
 
+    async fn get_check_constraints(&self, schema: &str) -> Vec<CheckConstraint> {
+        debug!("Getting check constraints");
+
+        let sql = r#"
+            SELECT
+                t.name AS table_name,
+                cc.name AS constraint_name,
+                cc.definition AS definition
+            FROM sys.check_constraints cc
+            INNER JOIN sys.tables t ON cc.parent_object_id = t.object_id
+            WHERE SCHEMA_NAME(t.schema_id) = @P1
+                AND t.is_ms_shipped = 0
+            ORDER BY table_name, constraint_name
+        "#;
+
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into()])
+            .await
+            .expect("querying for check constraints");
+
+        let check_constraints = rows
+            .into_iter()
+            .map(|row| CheckConstraint {
+                table: row
+                    .get("table_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get table_name"),
+                name: row
+                    .get("constraint_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get constraint_name"),
+                definition: row
+                    .get("definition")
+                    .and_then(|x| x.to_string())
+                    .expect("get definition"),
+            })
+            .collect();
+
+        debug!("Found check constraints: {:?}", check_constraints);
+        check_constraints
+    }
+
+    async fn get_temporal_tables(&self, schema: &str) -> Vec<TemporalTable> {
+        debug!("Getting temporal tables");
+
+        let sql = r#"
+            SELECT
+                t.name AS table_name,
+                h.name AS history_table_name,
+                period_start.name AS period_start_column,
+                period_end.name AS period_end_column
+            FROM sys.tables t
+            INNER JOIN sys.tables h ON h.object_id = t.history_table_id
+            INNER JOIN sys.periods p ON p.object_id = t.object_id
+            INNER JOIN sys.columns period_start
+                ON period_start.object_id = t.object_id AND period_start.column_id = p.start_column_id
+            INNER JOIN sys.columns period_end
+                ON period_end.object_id = t.object_id AND period_end.column_id = p.end_column_id
+            WHERE SCHEMA_NAME(t.schema_id) = @P1
+                AND t.temporal_type = 2
+            ORDER BY table_name
+        "#;
+
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into()])
+            .await
+            .expect("querying for temporal tables");
+
+        let temporal_tables = rows
+            .into_iter()
+            .map(|row| TemporalTable {
+                table: row
+                    .get("table_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get table_name"),
+                history_table: row
+                    .get("history_table_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get history_table_name"),
+                period_start_column: row
+                    .get("period_start_column")
+                    .and_then(|x| x.to_string())
+                    .expect("get period_start_column"),
+                period_end_column: row
+                    .get("period_end_column")
+                    .and_then(|x| x.to_string())
+                    .expect("get period_end_column"),
+            })
+            .collect();
+
+        debug!("Found temporal tables: {:?}", temporal_tables);
+        temporal_tables
+    }
+
+    async fn get_memory_optimized_tables(&self, schema: &str) -> Vec<MemoryOptimizedTable> {
+        debug!("Getting memory-optimized tables");
+
+        let sql = r#"
+            SELECT t.name AS table_name, t.durability_desc AS durability
+            FROM sys.tables t
+            WHERE SCHEMA_NAME(t.schema_id) = @P1
+                AND t.is_memory_optimized = 1
+                AND t.is_ms_shipped = 0
+            ORDER BY table_name
+        "#;
+
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into()])
+            .await
+            .expect("querying for memory-optimized tables");
+
+        let memory_optimized_tables = rows
+            .into_iter()
+            .map(|row| MemoryOptimizedTable {
+                table: row
+                    .get("table_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get table_name"),
+                durable: row
+                    .get("durability")
+                    .and_then(|x| x.to_string())
+                    .expect("get durability")
+                    != "SCHEMA_ONLY",
+            })
+            .collect();
+
+        debug!("Found memory-optimized tables: {:?}", memory_optimized_tables);
+        memory_optimized_tables
+    }
+
     async fn get_foreign_keys(&self, schema: &str) -> HashMap<String, Vec<ForeignKey>> {
         // Foreign keys covering multiple columns will return multiple rows, which we need to
         // merge.
@@ -579,7 +748,8 @@ impl SqlSchemaDescriber {
             "date" | "time" | "datetime" | "datetime2" | "smalldatetime" | "datetimeoffset" => DateTime,
             "numeric" | "decimal" | "float" | "real" | "smallmoney" | "money" => Float,
             "char" | "nchar" | "varchar" | "nvarchar" | "text" | "ntext" => String,
-            "tinyint" | "smallint" | "int" | "bigint" => Int,
+            "tinyint" | "smallint" | "int" => Int,
+            "bigint" => BigInt,
             "binary" | "varbinary" | "image" => Binary,
             "uniqueidentifier" => Uuid,
             "bit" => Boolean,
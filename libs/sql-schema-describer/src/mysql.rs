@@ -1,27 +1,15 @@
 use super::*;
-use quaint::{prelude::Queryable, single::Quaint};
+use crate::database_version::{DatabaseFlavour, DatabaseVersion};
+use quaint::{
+    prelude::{Queryable, SqlFamily},
+    single::Quaint,
+};
 use std::borrow::Cow;
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use tracing::debug;
 
-fn is_mariadb(version: &str) -> bool {
-    version.contains("MariaDB")
-}
-
-enum Flavour {
-    Mysql,
-    MariaDb,
-}
-
-impl Flavour {
-    fn from_version(version_string: &str) -> Self {
-        if is_mariadb(version_string) {
-            Self::MariaDb
-        } else {
-            Self::Mysql
-        }
-    }
-}
+/// The character set prefix of string literals in expressions.
+const CHARSET_INTRODUCER: &str = "_utf8mb4";
 
 pub struct SqlSchemaDescriber {
     conn: Quaint,
@@ -47,9 +35,10 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         debug!("describing schema '{}'", schema);
         let version = self.conn.version().await.ok().flatten();
         let flavour = version
-            .as_ref()
-            .map(|s| Flavour::from_version(s))
-            .unwrap_or(Flavour::Mysql);
+            .as_deref()
+            .and_then(|version| DatabaseVersion::parse(SqlFamily::Mysql, version))
+            .map(|version| version.flavour)
+            .unwrap_or(DatabaseFlavour::Mysql);
 
         let table_names = self.get_table_names(schema).await;
         let mut tables = Vec::with_capacity(table_names.len());
@@ -64,10 +53,41 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             enums.extend(enms.iter().cloned());
         }
 
+        let external_tables = self.get_external_tables(&tables, &flavour, &mut enums).await;
+        let views = self.get_views(schema, &mut columns, &mut enums).await;
+        let missing_privileges = self.get_missing_privileges(schema).await;
+        let on_update_timestamps = self.get_on_update_timestamps(schema).await;
+        let generated_columns = self.get_generated_columns(schema).await;
+        let check_constraints = self.get_check_constraints(schema).await;
+        let auto_increment_values = self.get_auto_increment_values(schema).await;
+
+        // The generated columns have no default of their own, their expression is the closest.
+        for generated_column in generated_columns.iter() {
+            let column = tables
+                .iter_mut()
+                .filter(|table| table.name == generated_column.table)
+                .flat_map(|table| table.columns.iter_mut())
+                .find(|column| column.name == generated_column.column);
+
+            if let Some(column) = column {
+                column.default = Some(DefaultValue::DBGENERATED(generated_column.expression.clone()));
+            }
+        }
+
         Ok(SqlSchema {
             tables,
             enums,
             sequences: vec![],
+            distributed_tables: vec![],
+            missing_privileges,
+            on_update_timestamps,
+            views,
+            generated_columns,
+            check_constraints,
+            auto_increment_values,
+            temporal_tables: vec![],
+            memory_optimized_tables: vec![],
+            external_tables,
         })
     }
 
@@ -125,6 +145,267 @@ impl SqlSchemaDescriber {
         names
     }
 
+    /// Views take their columns, and the enums of their columns, from the ones described for all
+    /// the relations of the schema.
+    async fn get_views(
+        &self,
+        schema: &str,
+        columns: &mut HashMap<String, (Vec<Column>, Vec<Enum>)>,
+        enums: &mut Vec<Enum>,
+    ) -> Vec<View> {
+        debug!("Getting views");
+        let sql = "SELECT table_name AS view_name, view_definition AS view_definition
+            FROM information_schema.views
+            WHERE table_schema = ?
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await.expect("get views");
+
+        let views = rows
+            .into_iter()
+            .map(|row| {
+                let name = row.get("view_name").and_then(|x| x.to_string()).expect("get view_name");
+                let definition = row.get("view_definition").and_then(|x| x.to_string());
+                let (columns, view_enums) = columns.remove(&name).unwrap_or_default();
+                enums.extend(view_enums);
+
+                View {
+                    name,
+                    definition,
+                    columns,
+                }
+            })
+            .collect();
+
+        debug!("Found views: {:?}", views);
+        views
+    }
+
+    async fn get_missing_privileges(&self, schema: &str) -> Vec<MissingPrivilege> {
+        debug!("Getting missing privileges");
+
+        // The privileges column lists the privileges of the connecting user, e.g. `select,insert,update`.
+        // Columns the user has no privileges on at all are not listed.
+        let sql = "SELECT table_name AS table_name, column_name AS column_name, privileges AS privileges
+            FROM information_schema.columns
+            WHERE table_schema = ?
+            ORDER BY table_name, ordinal_position";
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into()])
+            .await
+            .expect("get column privileges");
+
+        let columns = rows
+            .into_iter()
+            .map(|row| {
+                let privileges = row
+                    .get("privileges")
+                    .and_then(|x| x.to_string())
+                    .unwrap_or_else(String::new)
+                    .to_lowercase();
+                let has_privilege = |name: &str| privileges.split(',').any(|privilege| privilege.trim() == name);
+
+                ColumnPrivileges {
+                    table: row
+                        .get("table_name")
+                        .and_then(|x| x.to_string())
+                        .expect("get table_name"),
+                    column: row
+                        .get("column_name")
+                        .and_then(|x| x.to_string())
+                        .expect("get column_name"),
+                    can_select: has_privilege("select"),
+                    can_insert: has_privilege("insert"),
+                }
+            })
+            .collect();
+
+        let missing_privileges = missing_privileges(columns);
+
+        debug!("Found missing privileges: {:?}", missing_privileges);
+        missing_privileges
+    }
+
+    async fn get_on_update_timestamps(&self, schema: &str) -> Vec<OnUpdateTimestamp> {
+        debug!("Getting on update timestamps");
+
+        // The extra column reads `on update CURRENT_TIMESTAMP`, or `DEFAULT_GENERATED on update
+        // CURRENT_TIMESTAMP` on MySQL 8, possibly with a precision.
+        let sql = "SELECT table_name AS table_name, column_name AS column_name
+            FROM information_schema.columns
+            WHERE table_schema = ? AND LOWER(extra) LIKE '%on update current_timestamp%'
+            ORDER BY table_name, ordinal_position";
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into()])
+            .await
+            .expect("get on update timestamps");
+
+        let on_update_timestamps = rows
+            .into_iter()
+            .map(|row| OnUpdateTimestamp {
+                table: row
+                    .get("table_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get table_name"),
+                column: row
+                    .get("column_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get column_name"),
+            })
+            .collect();
+
+        debug!("Found on update timestamps: {:?}", on_update_timestamps);
+        on_update_timestamps
+    }
+
+    async fn get_generated_columns(&self, schema: &str) -> Vec<GeneratedColumn> {
+        debug!("Getting generated columns");
+
+        // The extra column reads `VIRTUAL GENERATED` or `STORED GENERATED`, `PERSISTENT GENERATED`
+        // on older MariaDB versions. MySQL 8 marks expression defaults as `DEFAULT_GENERATED`, these
+        // are regular columns.
+        let sql = "SELECT table_name AS table_name, column_name AS column_name,
+                generation_expression AS generation_expression, extra AS extra
+            FROM information_schema.columns
+            WHERE table_schema = ?
+                AND UPPER(extra) IN ('VIRTUAL GENERATED', 'STORED GENERATED', 'PERSISTENT GENERATED')
+            ORDER BY table_name, ordinal_position";
+
+        // MySQL 5.6 has neither generated columns, nor the generation_expression column.
+        let rows = match self.conn.query_raw(sql, &[schema.into()]).await {
+            Ok(rows) => rows,
+            Err(err) => {
+                debug!("Could not get generated columns: {}", err);
+                return Vec::new();
+            }
+        };
+
+        let generated_columns = rows
+            .into_iter()
+            .map(|row| GeneratedColumn {
+                table: row
+                    .get("table_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get table_name"),
+                column: row
+                    .get("column_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get column_name"),
+                expression: row
+                    .get("generation_expression")
+                    .and_then(|x| x.to_string())
+                    .expect("get generation_expression"),
+                stored: !row
+                    .get("extra")
+                    .and_then(|x| x.to_string())
+                    .expect("get extra")
+                    .to_uppercase()
+                    .starts_with("VIRTUAL"),
+            })
+            .collect();
+
+        debug!("Found generated columns: {:?}", generated_columns);
+        generated_columns
+    }
+
+    async fn get_check_constraints(&self, schema: &str) -> Vec<CheckConstraint> {
+        debug!("Getting check constraints");
+
+        let sql = "SELECT tc.table_name AS table_name, cc.constraint_name AS constraint_name,
+                cc.check_clause AS check_clause
+            FROM information_schema.check_constraints cc
+            JOIN information_schema.table_constraints tc
+                ON tc.constraint_schema = cc.constraint_schema
+                AND tc.constraint_name = cc.constraint_name
+                AND tc.constraint_type = 'CHECK'
+            WHERE cc.constraint_schema = ?
+            ORDER BY tc.table_name, cc.constraint_name";
+
+        // The check constraints only exist since MySQL 8.0.16 and MariaDB 10.2, older versions
+        // parse and ignore them.
+        let rows = match self.conn.query_raw(sql, &[schema.into()]).await {
+            Ok(rows) => rows,
+            Err(err) => {
+                debug!("Could not get check constraints: {}", err);
+                return Vec::new();
+            }
+        };
+
+        let check_constraints = rows
+            .into_iter()
+            .map(|row| CheckConstraint {
+                table: row
+                    .get("table_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get table_name"),
+                name: row
+                    .get("constraint_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get constraint_name"),
+                definition: row
+                    .get("check_clause")
+                    .and_then(|x| x.to_string())
+                    .expect("get check_clause"),
+            })
+            .collect();
+
+        debug!("Found check constraints: {:?}", check_constraints);
+        check_constraints
+    }
+
+    async fn get_auto_increment_values(&self, schema: &str) -> Vec<AutoIncrementValue> {
+        debug!("Getting auto increment values");
+
+        // MySQL 8 caches the table statistics, including AUTO_INCREMENT, for a day by default. The
+        // variable does not exist on older versions and MariaDB, which do not cache them.
+        if let Err(err) = self
+            .conn
+            .raw_cmd("SET SESSION information_schema_stats_expiry = 0")
+            .await
+        {
+            debug!("Could not disable the table statistics cache: {}", err);
+        }
+
+        // Tables whose counter never moved report an AUTO_INCREMENT of 1, there is nothing to
+        // restore for these.
+        let sql = "SELECT tables.table_name AS table_name, columns.column_name AS column_name,
+                tables.auto_increment AS auto_increment
+            FROM information_schema.tables tables
+            JOIN information_schema.columns columns
+                ON columns.table_schema = tables.table_schema
+                AND columns.table_name = tables.table_name
+                AND LOWER(columns.extra) LIKE '%auto_increment%'
+            WHERE tables.table_schema = ? AND tables.auto_increment > 1
+            ORDER BY tables.table_name";
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into()])
+            .await
+            .expect("get auto increment values");
+
+        let auto_increment_values = rows
+            .into_iter()
+            .map(|row| AutoIncrementValue {
+                table: row
+                    .get("table_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get table_name"),
+                column: row
+                    .get("column_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get column_name"),
+                next_value: row
+                    .get("auto_increment")
+                    .and_then(|x| x.as_i64())
+                    .expect("get auto_increment") as u64,
+            })
+            .collect();
+
+        debug!("Found auto increment values: {:?}", auto_increment_values);
+        auto_increment_values
+    }
+
     async fn get_size(&self, schema: &str) -> usize {
         use rust_decimal::prelude::*;
 
@@ -149,6 +430,43 @@ impl SqlSchemaDescriber {
         size as usize
     }
 
+    /// The tables of other databases that the foreign keys reference, with their qualified names. Their own foreign
+    /// keys are left out, they are outside of the relations of the schema.
+    async fn get_external_tables(
+        &self,
+        tables: &[Table],
+        flavour: &DatabaseFlavour,
+        enums: &mut Vec<Enum>,
+    ) -> Vec<Table> {
+        let mut referenced_tables: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+
+        for foreign_key in tables.iter().flat_map(|table| table.foreign_keys.iter()) {
+            if let Some((schema, table)) = split_qualified_name(&foreign_key.referenced_table) {
+                referenced_tables.entry(schema).or_default().insert(table);
+            }
+        }
+
+        let mut external_tables = Vec::new();
+
+        for (schema, table_names) in referenced_tables {
+            let mut columns = get_all_columns(&self.conn, schema, flavour).await;
+            let mut indexes = get_all_indexes(&self.conn, schema).await;
+
+            // Tables the connecting user can not see are left out.
+            for table_name in table_names.into_iter().filter(|name| columns.contains_key(*name)) {
+                let (mut table, table_enums) =
+                    self.get_table(table_name, &mut columns, &mut indexes, &mut HashMap::new());
+                table.name = format!("{}.{}", schema, table_name);
+
+                external_tables.push(table);
+                enums.extend(table_enums);
+            }
+        }
+
+        debug!("Found external tables: {:?}", external_tables);
+        external_tables
+    }
+
     fn get_table(
         &self,
         name: &str,
@@ -177,7 +495,7 @@ impl SqlSchemaDescriber {
 async fn get_all_columns(
     conn: &dyn Queryable,
     schema_name: &str,
-    flavour: &Flavour,
+    flavour: &DatabaseFlavour,
 ) -> HashMap<String, (Vec<Column>, Vec<Enum>)> {
     // We alias all the columns because MySQL column names are case-insensitive in queries, but the
     // information schema column names became upper-case in MySQL 8, causing the code fetching
@@ -265,10 +583,11 @@ async fn get_all_columns(
             None => None,
             Some(param_value) => match param_value.to_string() {
                 None => None,
-                Some(x) if x == "NULL" => None,
+                // MariaDB returns the explicit `DEFAULT NULL`s, MySQL returns no default for them.
+                Some(x) if x == "NULL" => Some(DefaultValue::NULL),
                 Some(default_string) => {
                     Some(match &tpe.family {
-                        ColumnTypeFamily::Int => match parse_int(&default_string) {
+                        ColumnTypeFamily::Int | ColumnTypeFamily::BigInt => match parse_int(&default_string) {
                             Some(int_value) => DefaultValue::VALUE(int_value),
                             None => DefaultValue::DBGENERATED(default_string),
                         },
@@ -296,9 +615,17 @@ async fn get_all_columns(
                         ColumnTypeFamily::LogSequenceNumber => DefaultValue::DBGENERATED(default_string),
                         ColumnTypeFamily::TextSearch => DefaultValue::DBGENERATED(default_string),
                         ColumnTypeFamily::TransactionId => DefaultValue::DBGENERATED(default_string),
-                        ColumnTypeFamily::Enum(_) => DefaultValue::VALUE(PrismaValue::Enum(unquote_string(
-                            &default_string.replace("_utf8mb4", "").replace("\\\'", ""),
-                        ))),
+                        ColumnTypeFamily::Xml => DefaultValue::DBGENERATED(default_string),
+                        ColumnTypeFamily::Enum(_) if default_string.starts_with(CHARSET_INTRODUCER) => {
+                            // Defaults rendered as an expression: the escaped literal with its character set.
+                            let literal = default_string[CHARSET_INTRODUCER.len()..].replace("\\'", "'");
+
+                            DefaultValue::VALUE(PrismaValue::Enum(unquote_string(&literal)))
+                        }
+                        // Enum values are escaped and quoted like strings.
+                        ColumnTypeFamily::Enum(_) => DefaultValue::VALUE(PrismaValue::Enum(
+                            unescape_and_unquote_default_string(default_string, flavour),
+                        )),
                         ColumnTypeFamily::Unsupported(_) => DefaultValue::DBGENERATED(default_string),
                     })
                 }
@@ -381,6 +708,7 @@ async fn get_all_indexes(
                                 columns: vec![column_name],
                                 sequence: None,
                                 constraint_name: None,
+                                clustered: None,
                             });
                         }
                     };
@@ -398,6 +726,9 @@ async fn get_all_indexes(
                                 true => IndexType::Unique,
                                 false => IndexType::Normal,
                             },
+                            clustered: None,
+                            predicate: None,
+                            nulls_not_distinct: false,
                         },
                     );
                 }
@@ -436,6 +767,7 @@ async fn get_foreign_keys(conn: &dyn Queryable, schema_name: &str) -> HashMap<St
         SELECT
             kcu.constraint_name constraint_name,
             kcu.column_name column_name,
+            kcu.referenced_table_schema referenced_table_schema,
             kcu.referenced_table_name referenced_table_name,
             kcu.referenced_column_name referenced_column_name,
             kcu.ordinal_position ordinal_position,
@@ -475,6 +807,16 @@ async fn get_foreign_keys(conn: &dyn Queryable, schema_name: &str) -> HashMap<St
             .get("referenced_table_name")
             .and_then(|x| x.to_string())
             .expect("get referenced_table_name");
+        let referenced_schema = row
+            .get("referenced_table_schema")
+            .and_then(|x| x.to_string())
+            .expect("get referenced_table_schema");
+        // Tables of other databases on the server are referenced by their qualified name.
+        let referenced_table = if referenced_schema == schema_name {
+            referenced_table
+        } else {
+            format!("{}.{}", referenced_schema, referenced_table)
+        };
         let referenced_column = row
             .get("referenced_column_name")
             .and_then(|x| x.to_string())
@@ -565,7 +907,7 @@ fn get_column_type_and_enum(
         ("tinyint", "tinyint(1)") => ColumnTypeFamily::Boolean,
         ("tinyint", _) => ColumnTypeFamily::Int,
         ("mediumint", _) => ColumnTypeFamily::Int,
-        ("bigint", _) => ColumnTypeFamily::Int,
+        ("bigint", _) => ColumnTypeFamily::BigInt,
         ("decimal", _) => ColumnTypeFamily::Float,
         ("numeric", _) => ColumnTypeFamily::Float,
         ("float", _) => ColumnTypeFamily::Float,
@@ -583,7 +925,7 @@ fn get_column_type_and_enum(
         ("mediumtext", _) => ColumnTypeFamily::String,
         ("longtext", _) => ColumnTypeFamily::String,
         ("enum", _) => ColumnTypeFamily::Enum(format!("{}_{}", table, column_name)),
-        // XXX: Is this correct?
+        // The allowed values are preserved in the full data type, see `extract_set_values`.
         ("set", _) => ColumnTypeFamily::String,
         ("binary", _) => ColumnTypeFamily::Binary,
         ("varbinary", _) => ColumnTypeFamily::Binary,
@@ -623,21 +965,55 @@ fn get_column_type_and_enum(
     }
 }
 
+/// Extracts the values of an `ENUM` column from its full data type, e.g. `enum('a','it''s')`. The
+/// values are quoted string literals, that can contain commas and doubled quotes.
 fn extract_enum_values(full_data_type: &&str) -> Vec<String> {
     let len = &full_data_type.len() - 1;
     let vals = &full_data_type[5..len];
-    vals.split(',').map(|v| unquote_string(v)).collect()
+
+    let mut values = Vec::new();
+    let mut value = String::new();
+    let mut in_literal = false;
+    let mut chars = vals.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' if in_literal && chars.peek() == Some(&'\'') => {
+                value.push('\'');
+                chars.next();
+            }
+            '\'' => in_literal = !in_literal,
+            ',' if !in_literal => values.push(std::mem::take(&mut value)),
+            c if in_literal => value.push(c),
+            _ => (),
+        }
+    }
+
+    values.push(value);
+    values
+}
+
+/// Extracts the allowed values of a `SET` column from its full data type, e.g. `set('a','b')`.
+/// Returns `None` if the data type is not a `SET`.
+pub fn extract_set_values(full_data_type: &str) -> Option<Vec<String>> {
+    if !full_data_type.to_lowercase().starts_with("set(") || !full_data_type.ends_with(')') {
+        return None;
+    }
+
+    let vals = &full_data_type[4..full_data_type.len() - 1];
+
+    Some(vals.split(',').map(|v| unquote_string(v.trim())).collect())
 }
 
 // See https://dev.mysql.com/doc/refman/8.0/en/string-literals.html
 //
 // In addition, MariaDB will return string literals with the quotes and extra backslashes around
 // control characters like `\n`.
-fn unescape_and_unquote_default_string(default: String, flavour: &Flavour) -> String {
+fn unescape_and_unquote_default_string(default: String, flavour: &DatabaseFlavour) -> String {
     static MYSQL_ESCAPING_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"\\('|\\[^\\])|'(')"#).unwrap());
     static MARIADB_NEWLINE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"\\n"#).unwrap());
 
-    let maybe_unquoted: Cow<str> = if matches!(flavour, Flavour::MariaDb) {
+    let maybe_unquoted: Cow<str> = if matches!(flavour, DatabaseFlavour::MariaDb) {
         let unquoted: &str = &default[1..(default.len() - 1)];
 
         MARIADB_NEWLINE_RE.replace_all(unquoted, "\n")
@@ -40,10 +40,26 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables.push(self.get_table(&table_name, &mut columns, &mut foreign_keys, &mut indexes));
         }
 
+        let views = self.get_views(schema, &mut columns).await;
+        let distributed_tables = self.get_distributed_tables(schema).await?;
+        let missing_privileges = self.get_missing_privileges(schema).await?;
+        let check_constraints = self.get_check_constraints(schema).await?;
+        let auto_increment_values = self.get_auto_increment_values(schema).await?;
+
         Ok(SqlSchema {
             enums,
             sequences,
             tables,
+            distributed_tables,
+            missing_privileges,
+            on_update_timestamps: vec![],
+            views,
+            generated_columns: vec![],
+            check_constraints,
+            auto_increment_values,
+            temporal_tables: vec![],
+            memory_optimized_tables: vec![],
+            external_tables: vec![],
         })
     }
 
@@ -101,6 +117,34 @@ impl SqlSchemaDescriber {
         names
     }
 
+    /// Views take their columns from the ones described for all the relations of the schema.
+    async fn get_views(&self, schema: &str, columns: &mut HashMap<String, Vec<Column>>) -> Vec<View> {
+        debug!("Getting views");
+        let sql = "SELECT table_name AS view_name, view_definition AS view_definition
+            FROM information_schema.views
+            WHERE table_schema = $1
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await.expect("get views");
+
+        let views = rows
+            .into_iter()
+            .map(|row| {
+                let name = row.get("view_name").and_then(|x| x.to_string()).expect("get view_name");
+                let definition = row.get("view_definition").and_then(|x| x.to_string());
+                let columns = columns.remove(&name).unwrap_or_default();
+
+                View {
+                    name,
+                    definition,
+                    columns,
+                }
+            })
+            .collect();
+
+        debug!("Found views: {:?}", views);
+        views
+    }
+
     async fn get_size(&self, schema: &str) -> usize {
         debug!("Getting db size");
         let sql =
@@ -128,13 +172,16 @@ impl SqlSchemaDescriber {
         let (indices, primary_key) = indices.remove(name).unwrap_or_else(|| (Vec::new(), None));
         let foreign_keys = foreign_keys.remove(name).unwrap_or_else(Vec::new);
         let columns = columns.remove(name).expect("could not get columns");
-        Table {
+        let mut table = Table {
             name: name.to_string(),
             columns,
             foreign_keys,
             indices,
             primary_key,
-        }
+        };
+
+        table.normalize_nulls_not_distinct();
+        table
     }
 
     async fn get_columns(&self, schema: &str, enums: &[Enum]) -> HashMap<String, Vec<Column>> {
@@ -150,6 +197,7 @@ impl SqlSchemaDescriber {
                 column_default,
                 is_nullable,
                 is_identity,
+                identity_generation,
                 data_type
             FROM information_schema.columns
             WHERE table_schema = $1
@@ -215,17 +263,30 @@ impl SqlSchemaDescriber {
                 enums,
             );
 
+            let identity_generation = col
+                .get("identity_generation")
+                .and_then(|x| x.to_string())
+                .map(|generation| generation.to_uppercase());
+
             let default = match col.get("column_default") {
+                _ if is_identity => Some(DefaultValue::IDENTITY(match identity_generation.as_deref() {
+                    Some("ALWAYS") => IdentityGeneration::Always,
+                    _ => IdentityGeneration::ByDefault,
+                })),
                 None => None,
                 Some(param_value) => match param_value.to_string() {
                     None => None,
+                    Some(default_string) if is_null_default(&default_string) => Some(DefaultValue::NULL),
                     Some(default_string) => {
                         Some(match &tpe.family {
-                            ColumnTypeFamily::Int => match parse_int(&default_string) {
+                            ColumnTypeFamily::Int | ColumnTypeFamily::BigInt => match parse_int(&default_string) {
                                 Some(int_value) => DefaultValue::VALUE(int_value),
                                 None => match is_autoincrement(&default_string, schema, &table_name, &col_name) {
                                     true => DefaultValue::SEQUENCE(default_string),
-                                    false => DefaultValue::DBGENERATED(default_string),
+                                    false => match parse_nextval_sequence_name(&default_string, schema) {
+                                        Some(sequence_name) => DefaultValue::NEXTVAL(sequence_name),
+                                        None => DefaultValue::DBGENERATED(default_string),
+                                    },
                                 },
                             },
                             ColumnTypeFamily::Float => match parse_float(&default_string) {
@@ -261,19 +322,13 @@ impl SqlSchemaDescriber {
                             ColumnTypeFamily::LogSequenceNumber => DefaultValue::DBGENERATED(default_string),
                             ColumnTypeFamily::TextSearch => DefaultValue::DBGENERATED(default_string),
                             ColumnTypeFamily::TransactionId => DefaultValue::DBGENERATED(default_string),
+                            ColumnTypeFamily::Xml => DefaultValue::DBGENERATED(default_string),
                             ColumnTypeFamily::Enum(enum_name) => {
-                                let enum_suffix_without_quotes = format!("::{}", enum_name);
-                                let enum_suffix_with_quotes = format!("::\"{}\"", enum_name);
-                                if default_string.ends_with(&enum_suffix_with_quotes) {
-                                    DefaultValue::VALUE(PrismaValue::Enum(unquote_string(
-                                        &default_string.replace(&enum_suffix_with_quotes, ""),
-                                    )))
-                                } else if default_string.ends_with(&enum_suffix_without_quotes) {
-                                    DefaultValue::VALUE(PrismaValue::Enum(unquote_string(
-                                        &default_string.replace(&enum_suffix_without_quotes, ""),
-                                    )))
-                                } else {
-                                    DefaultValue::DBGENERATED(default_string)
+                                match unsuffix_enum_default_literal(&default_string, schema, enum_name) {
+                                    Some(default_literal) => DefaultValue::VALUE(PrismaValue::Enum(
+                                        process_string_literal(default_literal).into(),
+                                    )),
+                                    None => DefaultValue::DBGENERATED(default_string),
                                 }
                             }
                             ColumnTypeFamily::Unsupported(_) => DefaultValue::DBGENERATED(default_string),
@@ -454,6 +509,8 @@ impl SqlSchemaDescriber {
             rawIndex.indisprimary AS is_primary_key,
             tableInfos.relname AS table_name,
             rawIndex.indkeyidx,
+            rawIndex.predicate,
+            rawIndex.nulls_not_distinct,
             pg_get_serial_sequence('"' || $1 || '"."' || tableInfos.relname || '"', columnInfos.attname) AS sequence_name
         FROM
             -- pg_class stores infos about tables, indices etc: https://www.postgresql.org/docs/current/catalog-pg-class.html
@@ -467,11 +524,13 @@ impl SqlSchemaDescriber {
                     indisunique,
                     indisprimary,
                     pg_index.indkey AS indkey,
-                    generate_subscripts(pg_index.indkey, 1) AS indkeyidx
+                    generate_subscripts(pg_index.indkey, 1) AS indkeyidx,
+                    -- the WHERE clause of partial indexes
+                    pg_get_expr(pg_index.indpred, pg_index.indrelid) AS predicate,
+                    -- UNIQUE NULLS NOT DISTINCT, the column only exists from Postgres 15 on
+                    COALESCE((to_jsonb(pg_index) ->> 'indnullsnotdistinct')::boolean, false) AS nulls_not_distinct
                 FROM pg_index
-                -- ignores partial indexes
-                Where indpred is Null
-                GROUP BY indrelid, indexrelid, indisunique, indisprimary, indkeyidx, indkey
+                GROUP BY indrelid, indexrelid, indisunique, indisprimary, indkeyidx, indkey, predicate, nulls_not_distinct
                 ORDER BY indrelid, indexrelid, indkeyidx
             ) rawIndex,
             -- pg_attribute stores infos about columns: https://www.postgresql.org/docs/current/catalog-pg-attribute.html
@@ -491,7 +550,7 @@ impl SqlSchemaDescriber {
             -- we only consider stuff out of one specific schema
             AND tableInfos.relnamespace = schemaInfo.oid
             AND schemaInfo.nspname = $1
-        GROUP BY tableInfos.relname, indexInfos.relname, rawIndex.indisunique, rawIndex.indisprimary, columnInfos.attname, rawIndex.indkeyidx
+        GROUP BY tableInfos.relname, indexInfos.relname, rawIndex.indisunique, rawIndex.indisprimary, columnInfos.attname, rawIndex.indkeyidx, rawIndex.predicate, rawIndex.nulls_not_distinct
         ORDER BY rawIndex.indkeyidx
         "#;
         let rows = self
@@ -509,6 +568,8 @@ impl SqlSchemaDescriber {
                 name,
                 sequence_name,
                 table_name,
+                predicate,
+                nulls_not_distinct,
             } = quaint::serde::from_row::<IndexRow>(index).unwrap();
 
             if is_primary_key {
@@ -533,6 +594,7 @@ impl SqlSchemaDescriber {
                             columns: vec![column_name],
                             sequence,
                             constraint_name: Some(name.clone()),
+                            clustered: None,
                         });
                     }
                 }
@@ -549,6 +611,9 @@ impl SqlSchemaDescriber {
                             true => IndexType::Unique,
                             false => IndexType::Normal,
                         },
+                        clustered: None,
+                        predicate,
+                        nulls_not_distinct,
                     })
                 }
             }
@@ -593,6 +658,203 @@ impl SqlSchemaDescriber {
         Ok(sequences)
     }
 
+    async fn get_distributed_tables(&self, schema: &str) -> SqlSchemaDescriberResult<Vec<DistributedTable>> {
+        debug!("Getting distributed tables");
+
+        let sql = "SELECT 1 FROM pg_extension WHERE extname = 'citus'";
+        let rows = self
+            .conn
+            .query_raw(&sql, &[])
+            .await
+            .expect("querying for the citus extension");
+
+        if rows.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Reference tables are replicated to every node and have no distribution column.
+        let sql = "SELECT c.relname AS table_name,
+                   column_to_column_name(p.logicalrelid, p.partkey) AS distribution_column
+                   FROM pg_dist_partition p
+                   JOIN pg_class c ON c.oid = p.logicalrelid
+                   JOIN pg_namespace n ON n.oid = c.relnamespace
+                   WHERE n.nspname = $1 AND p.partkey IS NOT NULL
+                   ORDER BY c.relname";
+        let rows = self
+            .conn
+            .query_raw(&sql, &[schema.into()])
+            .await
+            .expect("querying for distributed tables");
+
+        let distributed_tables = rows
+            .into_iter()
+            .map(|row| DistributedTable {
+                table: row
+                    .get("table_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get table_name"),
+                distribution_column: row
+                    .get("distribution_column")
+                    .and_then(|x| x.to_string())
+                    .expect("get distribution_column"),
+            })
+            .collect();
+
+        debug!("Found distributed tables: {:?}", distributed_tables);
+        Ok(distributed_tables)
+    }
+
+    async fn get_missing_privileges(&self, schema: &str) -> SqlSchemaDescriberResult<Vec<MissingPrivilege>> {
+        debug!("Getting missing privileges");
+
+        // Column privileges include the privileges granted on the whole table.
+        let sql = "SELECT c.relname AS table_name, a.attname AS column_name,
+                   has_column_privilege(c.oid, a.attnum, 'SELECT') AS can_select,
+                   has_column_privilege(c.oid, a.attnum, 'INSERT') AS can_insert
+                   FROM pg_class c
+                   JOIN pg_namespace n ON n.oid = c.relnamespace
+                   JOIN pg_attribute a ON a.attrelid = c.oid
+                   WHERE n.nspname = $1 AND c.relkind = 'r' AND a.attnum > 0 AND NOT a.attisdropped
+                   ORDER BY c.relname, a.attnum";
+        let rows = self
+            .conn
+            .query_raw(&sql, &[schema.into()])
+            .await
+            .expect("querying for column privileges");
+
+        let columns = rows
+            .into_iter()
+            .map(|row| ColumnPrivileges {
+                table: row
+                    .get("table_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get table_name"),
+                column: row
+                    .get("column_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get column_name"),
+                can_select: row.get("can_select").and_then(|x| x.as_bool()).expect("get can_select"),
+                can_insert: row.get("can_insert").and_then(|x| x.as_bool()).expect("get can_insert"),
+            })
+            .collect();
+
+        let missing_privileges = missing_privileges(columns);
+
+        debug!("Found missing privileges: {:?}", missing_privileges);
+        Ok(missing_privileges)
+    }
+
+    async fn get_check_constraints(&self, schema: &str) -> SqlSchemaDescriberResult<Vec<CheckConstraint>> {
+        debug!("Getting check constraints");
+
+        let sql = "SELECT rel.relname AS table_name, con.conname AS constraint_name,
+                   pg_get_expr(con.conbin, con.conrelid) AS definition
+                   FROM pg_constraint con
+                   JOIN pg_class rel ON rel.oid = con.conrelid
+                   JOIN pg_namespace nsp ON nsp.oid = rel.relnamespace
+                   WHERE con.contype = 'c' AND nsp.nspname = $1
+                   ORDER BY rel.relname, con.conname";
+        let rows = self
+            .conn
+            .query_raw(&sql, &[schema.into()])
+            .await
+            .expect("querying for check constraints");
+
+        let check_constraints = rows
+            .into_iter()
+            .map(|row| CheckConstraint {
+                table: row
+                    .get("table_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get table_name"),
+                name: row
+                    .get("constraint_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get constraint_name"),
+                definition: row
+                    .get("definition")
+                    .and_then(|x| x.to_string())
+                    .expect("get definition"),
+            })
+            .collect();
+
+        debug!("Found check constraints: {:?}", check_constraints);
+        Ok(check_constraints)
+    }
+
+    async fn get_auto_increment_values(&self, schema: &str) -> SqlSchemaDescriberResult<Vec<AutoIncrementValue>> {
+        debug!("Getting auto increment values");
+
+        // The sequences owned by a column, through `serial` (deptype 'a') or `GENERATED AS
+        // IDENTITY` (deptype 'i').
+        let sql = "SELECT tbl.relname AS table_name, attr.attname AS column_name,
+                   seq.relname AS sequence_name, CAST(COALESCE(info.increment, '1') AS BIGINT) AS increment
+                   FROM pg_depend dep
+                   JOIN pg_class seq ON seq.oid = dep.objid AND seq.relkind = 'S'
+                   JOIN pg_class tbl ON tbl.oid = dep.refobjid
+                   JOIN pg_attribute attr ON attr.attrelid = tbl.oid AND attr.attnum = dep.refobjsubid
+                   JOIN pg_namespace nsp ON nsp.oid = tbl.relnamespace
+                   LEFT JOIN information_schema.sequences info
+                       ON info.sequence_schema = nsp.nspname AND info.sequence_name = seq.relname
+                   WHERE nsp.nspname = $1 AND dep.deptype IN ('a', 'i')
+                   ORDER BY tbl.relname";
+        let rows = self
+            .conn
+            .query_raw(&sql, &[schema.into()])
+            .await
+            .expect("querying for owned sequences");
+
+        let mut auto_increment_values = Vec::with_capacity(rows.len());
+
+        for row in rows.into_iter() {
+            let table = row
+                .get("table_name")
+                .and_then(|x| x.to_string())
+                .expect("get table_name");
+            let column = row
+                .get("column_name")
+                .and_then(|x| x.to_string())
+                .expect("get column_name");
+            let sequence_name = row
+                .get("sequence_name")
+                .and_then(|x| x.to_string())
+                .expect("get sequence_name");
+            let increment = row.get("increment").and_then(|x| x.as_i64()).expect("get increment");
+
+            // The current value of a sequence can only be read from the sequence itself.
+            let sql = format!(
+                r#"SELECT last_value, is_called FROM "{}"."{}""#,
+                schema.replace('"', "\"\""),
+                sequence_name.replace('"', "\"\"")
+            );
+            let sequence_rows = self
+                .conn
+                .query_raw(&sql, &[])
+                .await
+                .expect("querying for the current value of a sequence");
+            let sequence_row = sequence_rows.into_single().expect("get sequence row");
+
+            // A sequence that was never called starts from its start value.
+            if !sequence_row.get("is_called").and_then(|x| x.as_bool()).unwrap_or(false) {
+                continue;
+            }
+
+            let last_value = sequence_row
+                .get("last_value")
+                .and_then(|x| x.as_i64())
+                .expect("get last_value");
+
+            auto_increment_values.push(AutoIncrementValue {
+                table,
+                column,
+                next_value: (last_value + increment) as u64,
+            });
+        }
+
+        debug!("Found auto increment values: {:?}", auto_increment_values);
+        Ok(auto_increment_values)
+    }
+
     async fn get_enums(&self, schema: &str) -> SqlSchemaDescriberResult<Vec<Enum>> {
         debug!("Getting enums");
         let sql = "SELECT t.typname as name, e.enumlabel as value
@@ -632,6 +894,8 @@ struct IndexRow {
     is_primary_key: bool,
     table_name: String,
     sequence_name: Option<String>,
+    predicate: Option<String>,
+    nulls_not_distinct: bool,
 }
 
 fn get_column_type<'a>(
@@ -650,7 +914,7 @@ fn get_column_type<'a>(
         x if data_type == "ARRAY" && x.starts_with('_') && enum_exists(trim(x)) => Enum(trim(x).to_owned()),
         "int2" | "_int2" => Int,
         "int4" | "_int4" => Int,
-        "int8" | "_int8" => Int,
+        "int8" | "_int8" => BigInt,
         "oid" | "_oid" => Int,
         "float4" | "_float4" => Float,
         "float8" | "_float8" => Float,
@@ -674,6 +938,8 @@ fn get_column_type<'a>(
         "polygon" | "_polygon" => Geometric,
         "bpchar" | "_bpchar" => String,
         "interval" | "_interval" => String,
+        // Ranges are represented by their text form, see `is_range_type`.
+        x if is_range_type(trim(x)) => String,
         "numeric" | "_numeric" => Float,
         "money" | "_money" => Float,
         "pg_lsn" | "_pg_lsn" => LogSequenceNumber,
@@ -684,6 +950,7 @@ fn get_column_type<'a>(
         "tsquery" | "_tsquery" => TextSearch,
         "tsvector" | "_tsvector" => TextSearch,
         "txid_snapshot" | "_txid_snapshot" => TransactionId,
+        "xml" | "_xml" => Xml,
         "inet" | "_inet" => String,
         data_type => Unsupported(data_type.into()),
     };
@@ -696,6 +963,22 @@ fn get_column_type<'a>(
     }
 }
 
+/// The built-in Postgres range types.
+pub const RANGE_TYPES: &[&str] = &[
+    "int4range",
+    "int8range",
+    "numrange",
+    "tsrange",
+    "tstzrange",
+    "daterange",
+];
+
+/// Whether the data type (e.g. `int4range`) is one of the built-in range types. The comparison is case
+/// insensitive, since calculated schemas use upper case type names.
+pub fn is_range_type(data_type: &str) -> bool {
+    RANGE_TYPES.iter().any(|range| range.eq_ignore_ascii_case(data_type))
+}
+
 static RE_SEQ: Lazy<Regex> = Lazy::new(|| Regex::new("^(?:.+\\.)?\"?([^.\"]+)\"?").expect("compile regex"));
 
 static AUTOINCREMENT_REGEX: Lazy<Regex> = Lazy::new(|| {
@@ -739,6 +1022,42 @@ fn is_autoincrement(value: &str, schema_name: &str, table_name: &str, column_nam
         .unwrap_or(false)
 }
 
+static NEXTVAL_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"^nextval\('(?:(?:"(?P<quoted_schema>[^"]+)"|(?P<schema>[^."]+))\.)?(?:"(?P<quoted_name>[^"]+)"|(?P<name>[^."]+))'::regclass\)$"#,
+    )
+    .unwrap()
+});
+
+/// Whether the default is an explicit `DEFAULT NULL`, which Postgres returns cast to the column
+/// type, e.g. `NULL::character varying`.
+fn is_null_default(value: &str) -> bool {
+    value
+        .split("::")
+        .next()
+        .map(|value| value.trim().eq_ignore_ascii_case("null"))
+        .unwrap_or(false)
+}
+
+/// The name of the sequence in a `nextval('sequence_name'::regclass)` default, if the sequence is
+/// in the described schema.
+fn parse_nextval_sequence_name(value: &str, schema_name: &str) -> Option<String> {
+    let captures = NEXTVAL_REGEX.captures(value)?;
+    let sequence_schema = captures.name("quoted_schema").or_else(|| captures.name("schema"));
+
+    if sequence_schema
+        .map(|matched| matched.as_str() != schema_name)
+        .unwrap_or(false)
+    {
+        return None;
+    }
+
+    captures
+        .name("quoted_name")
+        .or_else(|| captures.name("name"))
+        .map(|matched| matched.as_str().to_owned())
+}
+
 fn unsuffix_default_literal<'a>(literal: &'a str, data_type: &str, full_data_type: &str) -> Option<Cow<'a, str>> {
     static POSTGRES_DATA_TYPE_SUFFIX_RE: Lazy<Regex> =
         Lazy::new(|| Regex::new(r#"(?ms)^(.*)::(\\")?(.*)(\\")?$"#).unwrap());
@@ -755,6 +1074,27 @@ fn unsuffix_default_literal<'a>(literal: &'a str, data_type: &str, full_data_typ
     Some(first_capture.into())
 }
 
+/// Strips the cast from an enum default. The enum type is only qualified with the schema when
+/// the schema is not on the search path, e.g. `'A'::"Color"` or `'A'::"prisma-tests"."Color"`.
+fn unsuffix_enum_default_literal<'a>(literal: &'a str, schema: &str, enum_name: &str) -> Option<&'a str> {
+    let quoted_schema = format!("\"{}\"", schema);
+    let quoted_enum_name = format!("\"{}\"", enum_name);
+
+    let suffixes = [
+        format!("::{}", quoted_enum_name),
+        format!("::{}", enum_name),
+        format!("::{}.{}", quoted_schema, quoted_enum_name),
+        format!("::{}.{}", schema, quoted_enum_name),
+        format!("::{}.{}", quoted_schema, enum_name),
+        format!("::{}.{}", schema, enum_name),
+    ];
+
+    suffixes
+        .iter()
+        .find(|suffix| literal.ends_with(suffix.as_str()))
+        .map(|suffix| &literal[..literal.len() - suffix.len()])
+}
+
 // See https://www.postgresql.org/docs/9.3/sql-syntax-lexical.html
 fn process_string_literal(literal: &str) -> Cow<'_, str> {
     static POSTGRES_STRING_DEFAULT_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?ms)^B?'(.*)'$"#).unwrap());
@@ -845,4 +1185,29 @@ mod tests {
             "compound_column_name",
         ));
     }
+    #[test]
+    fn postgres_nextval_sequence_names_are_parsed() {
+        assert_eq!(
+            parse_nextval_sequence_name("nextval('shared_ids'::regclass)", "prisma").as_deref(),
+            Some("shared_ids")
+        );
+        assert_eq!(
+            parse_nextval_sequence_name(r#"nextval('"Shared.Ids"'::regclass)"#, "prisma").as_deref(),
+            Some("Shared.Ids")
+        );
+        assert_eq!(
+            parse_nextval_sequence_name(r#"nextval('"prisma"."shared_ids"'::regclass)"#, "prisma").as_deref(),
+            Some("shared_ids")
+        );
+
+        // Sequences in other schemas are not part of the described schema.
+        assert_eq!(
+            parse_nextval_sequence_name("nextval('other.shared_ids'::regclass)", "prisma"),
+            None
+        );
+        assert_eq!(
+            parse_nextval_sequence_name("(nextval('a'::regclass) * 2)", "prisma"),
+            None
+        );
+    }
 }
@@ -50,11 +50,23 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables[table_index].foreign_keys[fk_index].referenced_columns = columns
         }
 
+        let views = self.get_views(schema).await;
+
         Ok(SqlSchema {
             // There's no enum type in SQLite.
             enums: vec![],
             // There are no sequences in SQLite.
             sequences: vec![],
+            distributed_tables: vec![],
+            missing_privileges: vec![],
+            on_update_timestamps: vec![],
+            views,
+            generated_columns: vec![],
+            check_constraints: vec![],
+            auto_increment_values: vec![],
+            temporal_tables: vec![],
+            memory_optimized_tables: vec![],
+            external_tables: vec![],
             tables,
         })
     }
@@ -101,6 +113,29 @@ impl SqlSchemaDescriber {
         names
     }
 
+    async fn get_views(&self, schema: &str) -> Vec<View> {
+        let sql = format!(r#"SELECT name, sql FROM "{}".sqlite_master WHERE type='view'"#, schema);
+        debug!("describing views with query: '{}'", sql);
+        let result_set = self.conn.query_raw(&sql, &[]).await.expect("get views");
+        let mut views = Vec::new();
+
+        for row in result_set.into_iter() {
+            let name = row.get("name").and_then(|x| x.to_string()).expect("get view name");
+            let definition = row.get("sql").and_then(|x| x.to_string());
+            // table_info describes the columns of views too, they have no primary key.
+            let (columns, _) = self.get_columns(schema, &name).await;
+
+            views.push(View {
+                name,
+                definition,
+                columns,
+            });
+        }
+
+        debug!("Found views: {:?}", views);
+        views
+    }
+
     async fn get_size(&self, _schema: &str) -> usize {
         debug!("Getting db size");
         let sql = r#"SELECT page_count * page_size as size FROM pragma_page_count(), pragma_page_size();"#;
@@ -152,10 +187,10 @@ impl SqlSchemaDescriber {
                         let default_string = cow_string.to_string();
 
                         if default_string.to_lowercase() == "null" {
-                            None
+                            Some(DefaultValue::NULL)
                         } else {
                             Some(match &tpe.family {
-                                ColumnTypeFamily::Int => match parse_int(&default_string) {
+                                ColumnTypeFamily::Int | ColumnTypeFamily::BigInt => match parse_int(&default_string) {
                                     Some(int_value) => DefaultValue::VALUE(int_value),
                                     None => DefaultValue::DBGENERATED(default_string),
                                 },
@@ -187,6 +222,7 @@ impl SqlSchemaDescriber {
                                 ColumnTypeFamily::LogSequenceNumber => DefaultValue::DBGENERATED(default_string),
                                 ColumnTypeFamily::TextSearch => DefaultValue::DBGENERATED(default_string),
                                 ColumnTypeFamily::TransactionId => DefaultValue::DBGENERATED(default_string),
+                                ColumnTypeFamily::Xml => DefaultValue::DBGENERATED(default_string),
                                 ColumnTypeFamily::Enum(_) => DefaultValue::VALUE(PrismaValue::Enum(default_string)),
                                 ColumnTypeFamily::Unsupported(_) => DefaultValue::DBGENERATED(default_string),
                             })
@@ -248,6 +284,7 @@ impl SqlSchemaDescriber {
                 columns,
                 sequence: None,
                 constraint_name: None,
+                clustered: None,
             })
         };
 
@@ -383,13 +420,18 @@ impl SqlSchemaDescriber {
         let filtered_rows = result_set
             .into_iter()
             // Exclude primary keys, they are inferred separately.
-            .filter(|row| row.get("origin").and_then(|origin| origin.as_str()).unwrap() != "pk")
-            // Exclude partial indices
-            .filter(|row| row.get("partial").and_then(|partial| partial.as_bool()).unwrap() != true);
+            .filter(|row| row.get("origin").and_then(|origin| origin.as_str()).unwrap() != "pk");
 
         for row in filtered_rows {
             let is_unique = row.get("unique").and_then(|x| x.as_bool()).expect("get unique");
+            let is_partial = row.get("partial").and_then(|x| x.as_bool()).expect("get partial");
             let name = row.get("name").and_then(|x| x.to_string()).expect("get name");
+            let predicate = if is_partial {
+                self.get_index_predicate(schema, &name).await
+            } else {
+                None
+            };
+
             let mut index = Index {
                 name: name.clone(),
                 tpe: match is_unique {
@@ -397,6 +439,9 @@ impl SqlSchemaDescriber {
                     false => IndexType::Normal,
                 },
                 columns: vec![],
+                clustered: None,
+                predicate,
+                nulls_not_distinct: false,
             };
 
             let sql = format!(r#"PRAGMA "{}".index_info("{}");"#, schema, name);
@@ -416,6 +461,32 @@ impl SqlSchemaDescriber {
 
         indices
     }
+
+    /// SQLite doesn't store the predicate of partial indexes separately, it is taken from the
+    /// statement that created the index.
+    async fn get_index_predicate(&self, schema: &str, index_name: &str) -> Option<String> {
+        let sql = format!(
+            r#"SELECT sql FROM "{}".sqlite_master WHERE type='index' AND name = ?"#,
+            schema
+        );
+        let result_set = self
+            .conn
+            .query_raw(&sql, &[index_name.into()])
+            .await
+            .expect("querying for index definition");
+        let definition = result_set.into_iter().next()?.get("sql").and_then(|x| x.to_string())?;
+
+        partial_index_predicate(&definition)
+    }
+}
+
+/// The condition after the WHERE keyword following the column list of a `CREATE INDEX` statement.
+fn partial_index_predicate(definition: &str) -> Option<String> {
+    static PARTIAL_INDEX_PREDICATE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?is)\)\s*WHERE\s+(.*)$"#).unwrap());
+
+    PARTIAL_INDEX_PREDICATE_RE
+        .captures(definition)
+        .map(|captures| captures[1].trim().trim_end_matches(';').trim_end().to_owned())
 }
 
 fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
@@ -426,6 +497,7 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         // It's tolerant though, and you can assign any data type you like to columns
         "int" => ColumnTypeFamily::Int,
         "integer" => ColumnTypeFamily::Int,
+        "bigint" => ColumnTypeFamily::BigInt,
         "real" => ColumnTypeFamily::Float,
         "float" => ColumnTypeFamily::Float,
         "serial" => ColumnTypeFamily::Int,
@@ -438,6 +510,7 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         "datetime" => ColumnTypeFamily::DateTime,
         "timestamp" => ColumnTypeFamily::DateTime,
         "binary" => ColumnTypeFamily::Binary,
+        "blob" => ColumnTypeFamily::Binary,
         "double" => ColumnTypeFamily::Float,
         "binary[]" => ColumnTypeFamily::Binary,
         "boolean[]" => ColumnTypeFamily::Boolean,
@@ -55,8 +55,34 @@ impl<'a> ColumnWalker<'a> {
         &self.column.name
     }
 
+    /// The position of the column in its table, starting at 0.
+    pub fn ordinal_position(&self) -> usize {
+        self.table
+            .columns
+            .iter()
+            .position(|column| column.name == self.column.name)
+            .expect("Column not found in its table.")
+    }
+
+    /// The column right before this one in the table, if any.
+    pub fn previous_column(&self) -> Option<ColumnWalker<'a>> {
+        let position = self.ordinal_position();
+
+        position.checked_sub(1).map(|previous| ColumnWalker {
+            schema: self.schema,
+            table: self.table,
+            column: &self.table.columns[previous],
+        })
+    }
+
+    /// Whether the column is the last one of its table.
+    pub fn is_last_column(&self) -> bool {
+        self.ordinal_position() + 1 == self.table.columns.len()
+    }
+
+    /// The default of the column. An explicit `DEFAULT NULL` is the same as no default here.
     pub fn default(&self) -> Option<&'a DefaultValue> {
-        self.column.default.as_ref()
+        self.column.default.as_ref().filter(|default| !default.is_null())
     }
 
     pub fn column_type(&self) -> &'a ColumnType {
@@ -67,6 +93,11 @@ impl<'a> ColumnWalker<'a> {
         self.column.auto_increment
     }
 
+    /// Whether the column is computed by the database, with `GENERATED ALWAYS AS`.
+    pub fn is_generated(&self) -> bool {
+        self.schema.is_generated_column(&self.table.name, self.name())
+    }
+
     pub fn is_same_column(&self, other: &ColumnWalker<'_>) -> bool {
         self.name() == other.name() && self.table().name() == other.table().name()
     }
@@ -35,6 +35,14 @@ fn int_data_type(api: &TestApi) -> String {
     }
 }
 
+/// Clustering is only described on SQL Server.
+fn clustered(api: &TestApi, clustered: bool) -> Option<bool> {
+    match api.sql_family() {
+        SqlFamily::Mssql => Some(clustered),
+        _ => None,
+    }
+}
+
 fn varchar_data_type(api: &TestApi, length: u64) -> String {
     match (api.sql_family(), api.connector_name()) {
         (SqlFamily::Postgres, _) => "character varying".to_string(),
@@ -146,6 +154,9 @@ async fn foreign_keys_must_work(api: &TestApi) {
             name: "city".to_owned(),
             columns: vec!["city".to_owned()],
             tpe: IndexType::Normal,
+            clustered: None,
+            predicate: None,
+            nulls_not_distinct: false,
         }]
     } else {
         vec![]
@@ -253,6 +264,9 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             name: "city_name".to_owned(),
             columns: vec!["city_name".to_owned(), "city".to_owned()],
             tpe: IndexType::Normal,
+            clustered: None,
+            predicate: None,
+            nulls_not_distinct: false,
         }]
     } else {
         vec![]
@@ -395,6 +409,7 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
                 } else {
                     None
                 },
+                clustered: clustered(api, true),
             }),
             foreign_keys: vec![],
         }
@@ -464,6 +479,9 @@ async fn indices_must_work(api: &TestApi) {
                 name: "count".to_string(),
                 columns: vec!["count".to_string()],
                 tpe: IndexType::Normal,
+                clustered: clustered(api, false),
+                predicate: None,
+                nulls_not_distinct: false,
             },],
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".to_string()],
@@ -473,6 +491,7 @@ async fn indices_must_work(api: &TestApi) {
                 } else {
                     None
                 },
+                clustered: clustered(api, true),
             }),
             foreign_keys: vec![],
         }
@@ -526,12 +545,18 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
         name: "uniq".to_string(),
         columns: vec!["uniq2".to_string()],
         tpe: IndexType::Unique,
+        clustered: clustered(api, false),
+        predicate: None,
+        nulls_not_distinct: false,
     }];
     match api.sql_family() {
         SqlFamily::Mysql => expected_indices.push(Index {
             name: "uniq1".to_string(),
             columns: vec!["uniq1".to_string()],
             tpe: IndexType::Unique,
+            clustered: None,
+            predicate: None,
+            nulls_not_distinct: false,
         }),
         SqlFamily::Postgres => expected_indices.insert(
             0,
@@ -539,12 +564,18 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 name: "User_uniq1_key".to_string(),
                 columns: vec!["uniq1".to_string()],
                 tpe: IndexType::Unique,
+                clustered: None,
+                predicate: None,
+                nulls_not_distinct: false,
             },
         ),
         SqlFamily::Sqlite => expected_indices.push(Index {
             name: "sqlite_autoindex_User_1".to_string(),
             columns: vec!["uniq1".to_string()],
             tpe: IndexType::Unique,
+            clustered: None,
+            predicate: None,
+            nulls_not_distinct: false,
         }),
         SqlFamily::Mssql => expected_indices.insert(
             0,
@@ -552,6 +583,9 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 name: "UQ__User__CD572100A176666B".to_string(),
                 columns: vec!["uniq1".to_string()],
                 tpe: IndexType::Unique,
+                clustered: Some(false),
+                predicate: None,
+                nulls_not_distinct: false,
             },
         ),
     };
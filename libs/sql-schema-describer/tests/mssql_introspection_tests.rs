@@ -430,6 +430,7 @@ async fn all_mssql_column_types_must_work() {
                 columns: vec!["primary_col".to_string()],
                 sequence: None,
                 constraint_name: None,
+                clustered: Some(true),
             }),
             foreign_keys: vec![],
         }
@@ -504,6 +505,7 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                 columns: vec!["id".to_string()],
                 sequence: None,
                 constraint_name: None,
+                clustered: Some(true),
             }),
             foreign_keys: vec![
                 ForeignKey {
@@ -550,7 +552,10 @@ async fn mssql_multi_field_indexes_must_be_inferred() {
         &[Index {
             name: "age_and_name_index".into(),
             columns: vec!["name".to_owned(), "age".to_owned()],
-            tpe: IndexType::Unique
+            tpe: IndexType::Unique,
+            clustered: Some(false),
+            predicate: None,
+            nulls_not_distinct: false,
         }]
     );
 }
@@ -590,6 +595,219 @@ async fn mssql_join_table_unique_indexes_must_be_inferred() {
             name: "cat_and_human_index".into(),
             columns: vec!["cat".to_owned(), "human".to_owned()],
             tpe: IndexType::Unique,
+            clustered: Some(false),
+            predicate: None,
+            // Without a filter, the unique index on nullable columns only allows one row with NULLs.
+            nulls_not_distinct: true,
         }]
     );
 }
+
+#[cfg(not(target_os = "macos"))]
+#[tokio::test]
+async fn mssql_index_clustering_must_be_inferred() {
+    let db_name = "mssql_index_clustering_must_be_inferred";
+
+    let full_sql = format!(
+        r#"
+        CREATE TABLE [{schema}].[Post] (
+            id INT NOT NULL,
+            author INT NOT NULL,
+            title NVARCHAR(100) NOT NULL,
+            CONSTRAINT [PK_Post_id] PRIMARY KEY NONCLUSTERED (id)
+        );
+        CREATE CLUSTERED INDEX [author_index] ON [{schema}].[Post] (author);
+        CREATE UNIQUE NONCLUSTERED INDEX [title_unique] ON [{schema}].[Post] (title)"#,
+        schema = db_name
+    );
+
+    let inspector = get_mssql_describer_for_schema(&full_sql, db_name).await;
+    let result = inspector.describe(db_name).await.expect("describing");
+    let table = result.get_table("Post").expect("couldn't get Post table");
+
+    assert_eq!(table.primary_key.as_ref().unwrap().clustered, Some(false));
+
+    let mut indices = table.indices.clone();
+    indices.sort_unstable_by_key(|index| index.name.to_owned());
+
+    assert_eq!(
+        indices,
+        &[
+            Index {
+                name: "author_index".into(),
+                columns: vec!["author".to_owned()],
+                tpe: IndexType::Normal,
+                clustered: Some(true),
+                predicate: None,
+                nulls_not_distinct: false,
+            },
+            Index {
+                name: "title_unique".into(),
+                columns: vec!["title".to_owned()],
+                tpe: IndexType::Unique,
+                clustered: Some(false),
+                predicate: None,
+                nulls_not_distinct: false,
+            },
+        ]
+    );
+}
+
+#[cfg(not(target_os = "macos"))]
+#[tokio::test]
+async fn mssql_filtered_indexes_must_be_inferred() {
+    let db_name = "mssql_filtered_indexes_must_be_inferred";
+
+    let full_sql = format!(
+        r#"
+        CREATE TABLE [{schema}].[Post] (
+            id INT NOT NULL PRIMARY KEY,
+            slug NVARCHAR(100) NULL,
+            published BIT NOT NULL
+        );
+        CREATE UNIQUE INDEX [slug_unique] ON [{schema}].[Post] (slug) WHERE slug IS NOT NULL;
+        CREATE INDEX [published_slug] ON [{schema}].[Post] (slug) WHERE published = 1"#,
+        schema = db_name
+    );
+
+    let inspector = get_mssql_describer_for_schema(&full_sql, db_name).await;
+    let result = inspector.describe(db_name).await.expect("describing");
+    let table = result.get_table("Post").expect("couldn't get Post table");
+
+    let published_slug = table.indices.iter().find(|i| i.name == "published_slug").unwrap();
+    assert_eq!(published_slug.predicate.as_deref(), Some("([published]=(1))"));
+    assert!(published_slug.is_partial());
+
+    // Filtering out the NULLs of a unique index is how the other databases behave anyway.
+    let slug_unique = table.indices.iter().find(|i| i.name == "slug_unique").unwrap();
+    assert_eq!(slug_unique.predicate.as_deref(), Some("([slug] IS NOT NULL)"));
+    assert!(!slug_unique.is_partial());
+}
+
+#[cfg(not(target_os = "macos"))]
+#[tokio::test]
+async fn mssql_check_constraints_must_be_inferred() {
+    let db_name = "mssql_check_constraints_must_be_inferred";
+
+    let full_sql = format!(
+        r#"
+        CREATE TABLE [{schema}].[Product] (
+            id INT NOT NULL PRIMARY KEY,
+            price INT NOT NULL CONSTRAINT [positive_price] CHECK (price > 0)
+        )"#,
+        schema = db_name
+    );
+
+    let inspector = get_mssql_describer_for_schema(&full_sql, db_name).await;
+    let result = inspector.describe(db_name).await.expect("describing");
+    let checks: Vec<_> = result.check_constraints_on("Product").collect();
+
+    assert_eq!(checks.len(), 1);
+    assert_eq!(checks[0].name, "positive_price");
+    assert_eq!(checks[0].definition, "([price]>(0))");
+}
+
+#[cfg(not(target_os = "macos"))]
+#[tokio::test]
+async fn mssql_explicit_null_defaults_must_be_inferred() {
+    let db_name = "mssql_explicit_null_defaults_must_be_inferred";
+
+    let full_sql = format!(
+        r#"
+        CREATE TABLE [{schema}].[User] (
+            id INT NOT NULL PRIMARY KEY,
+            nickname NVARCHAR(100) NULL DEFAULT NULL,
+            bio NVARCHAR(100) NULL
+        )"#,
+        schema = db_name
+    );
+
+    let inspector = get_mssql_describer_for_schema(&full_sql, db_name).await;
+    let result = inspector.describe(db_name).await.expect("describing");
+    let table = result.get_table("User").expect("couldn't get User table");
+
+    assert_eq!(table.column_bang("nickname").default, Some(DefaultValue::NULL));
+    assert_eq!(table.column_bang("bio").default, None);
+}
+
+#[cfg(not(target_os = "macos"))]
+#[tokio::test]
+async fn mssql_temporal_tables_must_be_inferred() {
+    let db_name = "mssql_temporal_tables_must_be_inferred";
+
+    let full_sql = format!(
+        r#"
+        CREATE TABLE [{schema}].[Product] (
+            id INT NOT NULL PRIMARY KEY,
+            name NVARCHAR(100) NOT NULL,
+            ValidFrom DATETIME2 GENERATED ALWAYS AS ROW START HIDDEN NOT NULL,
+            ValidTo DATETIME2 GENERATED ALWAYS AS ROW END HIDDEN NOT NULL,
+            PERIOD FOR SYSTEM_TIME (ValidFrom, ValidTo)
+        )
+        WITH (SYSTEM_VERSIONING = ON (HISTORY_TABLE = [{schema}].[ProductHistory]))"#,
+        schema = db_name
+    );
+
+    let inspector = get_mssql_describer_for_schema(&full_sql, db_name).await;
+    let result = inspector.describe(db_name).await.expect("describing");
+
+    assert_eq!(
+        result.get_temporal_table("Product"),
+        Some(&TemporalTable {
+            table: "Product".into(),
+            history_table: "ProductHistory".into(),
+            period_start_column: "ValidFrom".into(),
+            period_end_column: "ValidTo".into(),
+        })
+    );
+
+    // The history table and the period columns are managed by the database.
+    assert!(result.get_table("ProductHistory").is_none());
+
+    let columns: Vec<&str> = result
+        .get_table("Product")
+        .expect("couldn't get Product table")
+        .columns
+        .iter()
+        .map(|column| column.name.as_str())
+        .collect();
+
+    assert_eq!(columns, &["id", "name"]);
+}
+
+#[cfg(not(target_os = "macos"))]
+#[tokio::test]
+async fn mssql_unique_indexes_without_filter_must_not_consider_nulls_distinct() {
+    let db_name = "mssql_unique_indexes_without_filter_must_not_consider_nulls_distinct";
+
+    let full_sql = format!(
+        r#"
+        CREATE TABLE [{schema}].[User] (
+            id INT NOT NULL PRIMARY KEY,
+            email NVARCHAR(100) NULL,
+            nickname NVARCHAR(100) NULL,
+            login NVARCHAR(100) NOT NULL
+        );
+        CREATE UNIQUE INDEX [email_unique] ON [{schema}].[User] (email);
+        CREATE UNIQUE INDEX [nickname_unique] ON [{schema}].[User] (nickname) WHERE [nickname] IS NOT NULL;
+        CREATE UNIQUE INDEX [login_unique] ON [{schema}].[User] (login)"#,
+        schema = db_name
+    );
+
+    let inspector = get_mssql_describer_for_schema(&full_sql, db_name).await;
+    let result = inspector.describe(db_name).await.expect("describing");
+    let table = result.get_table("User").expect("couldn't get User table");
+    let nulls_not_distinct = |name: &str| {
+        table
+            .indices
+            .iter()
+            .find(|index| index.name == name)
+            .expect("index not found")
+            .nulls_not_distinct
+    };
+
+    assert!(nulls_not_distinct("email_unique"));
+    assert!(!nulls_not_distinct("nickname_unique"));
+    // NULLs make no difference on columns that can't be NULL.
+    assert!(!nulls_not_distinct("login_unique"));
+}
@@ -146,7 +146,7 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "bigint".to_string(),
                 full_data_type: "bigint(20)".to_string(),
                 character_maximum_length: None,
-                family: ColumnTypeFamily::Int,
+                family: ColumnTypeFamily::BigInt,
                 arity: ColumnArity::Required,
             },
 
@@ -583,6 +583,7 @@ async fn all_mysql_column_types_must_work() {
                 columns: vec!["primary_col".to_string()],
                 sequence: None,
                 constraint_name: None,
+                clustered: None,
             }),
             foreign_keys: vec![],
         }
@@ -684,27 +685,40 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     name: "city".to_owned(),
                     columns: vec!["city".to_owned(),],
                     tpe: IndexType::Normal,
+                    clustered: None,
+                    predicate: None,
+                    nulls_not_distinct: false,
                 },
                 Index {
                     name: "city_cascade".to_owned(),
                     columns: vec!["city_cascade".to_owned(),],
                     tpe: IndexType::Normal,
+                    clustered: None,
+                    predicate: None,
+                    nulls_not_distinct: false,
                 },
                 Index {
                     name: "city_restrict".to_owned(),
                     columns: vec!["city_restrict".to_owned(),],
                     tpe: IndexType::Normal,
+                    clustered: None,
+                    predicate: None,
+                    nulls_not_distinct: false,
                 },
                 Index {
                     name: "city_set_null".to_owned(),
                     columns: vec!["city_set_null".to_owned(),],
                     tpe: IndexType::Normal,
+                    clustered: None,
+                    predicate: None,
+                    nulls_not_distinct: false,
                 }
             ],
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".to_string()],
                 sequence: None,
                 constraint_name: None,
+                clustered: None,
             }),
             foreign_keys: vec![
                 ForeignKey {
@@ -767,6 +781,9 @@ async fn mysql_multi_field_indexes_must_be_inferred() {
             name: "age_and_name_index".into(),
             columns: vec!["name".to_owned(), "age".to_owned()],
             tpe: IndexType::Unique,
+            clustered: None,
+            predicate: None,
+            nulls_not_distinct: false,
         }]
     );
 }
@@ -805,6 +822,9 @@ async fn mysql_join_table_unique_indexes_must_be_inferred() {
             name: "cat_and_human_index".into(),
             columns: vec!["cat".to_owned(), "human".to_owned()],
             tpe: IndexType::Unique,
+            clustered: None,
+            predicate: None,
+            nulls_not_distinct: false,
         }]
     );
 }
@@ -990,3 +1010,115 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql"))]
+async fn on_update_current_timestamp_columns_must_be_described(api: &TestApi) -> TestResult {
+    let create_table = r#"
+        CREATE TABLE test (
+            id INTEGER PRIMARY KEY,
+            created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+            changed_at DATETIME(3) NULL ON UPDATE CURRENT_TIMESTAMP(3)
+        )
+    "#;
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+
+    assert_eq!(
+        schema.on_update_timestamps,
+        &[
+            OnUpdateTimestamp {
+                table: "test".to_string(),
+                column: "updated_at".to_string(),
+            },
+            OnUpdateTimestamp {
+                table: "test".to_string(),
+                column: "changed_at".to_string(),
+            },
+        ]
+    );
+    assert!(!schema.is_on_update_timestamp("test", "created_at"));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn views_must_be_described_with_their_columns(api: &TestApi) -> TestResult {
+    let create_table = r#"
+        CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL, active BOOLEAN NOT NULL)
+    "#;
+    let create_view = r#"
+        CREATE VIEW active_users AS SELECT id, name FROM users WHERE active
+    "#;
+
+    api.database().query_raw(&create_table, &[]).await?;
+    api.database().query_raw(&create_view, &[]).await?;
+
+    let schema = api.describe().await?;
+
+    assert_eq!(schema.tables.len(), 1);
+    assert!(schema.get_table("active_users").is_none());
+
+    let view = schema.get_view("active_users").expect("view not found");
+    let column_names: Vec<&str> = view.columns.iter().map(|c| c.name.as_str()).collect();
+
+    assert_eq!(column_names, &["id", "name"]);
+    assert!(view.definition.is_some());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"), ignore("mysql_5_6"))]
+async fn generated_columns_must_be_described(api: &TestApi) -> TestResult {
+    let create_table = r#"
+        CREATE TABLE people (
+            id INTEGER PRIMARY KEY,
+            first_name VARCHAR(100) NOT NULL,
+            last_name VARCHAR(100) NOT NULL,
+            full_name VARCHAR(201) AS (CONCAT(first_name, ' ', last_name)) VIRTUAL,
+            name_length INTEGER AS (CHAR_LENGTH(first_name)) STORED
+        )
+    "#;
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+
+    let full_name = schema.get_generated_column("people", "full_name").unwrap();
+    assert!(full_name.expression.to_lowercase().contains("concat"));
+    assert!(!full_name.stored);
+
+    let name_length = schema.get_generated_column("people", "name_length").unwrap();
+    assert!(name_length.stored);
+
+    assert!(!schema.is_generated_column("people", "first_name"));
+
+    let column = schema.table_bang("people").column_bang("full_name");
+    assert!(matches!(column.default, Some(DefaultValue::DBGENERATED(_))));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn check_constraints_must_be_described(api: &TestApi) -> TestResult {
+    let create_table = r#"
+        CREATE TABLE products (
+            id INTEGER PRIMARY KEY,
+            price INTEGER NOT NULL,
+            CONSTRAINT positive_price CHECK (price > 0)
+        )
+    "#;
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let checks: Vec<_> = schema.check_constraints_on("products").collect();
+
+    assert_eq!(checks.len(), 1);
+    assert_eq!(checks[0].name, "positive_price");
+    assert!(checks[0].references_column("price"));
+
+    Ok(())
+}
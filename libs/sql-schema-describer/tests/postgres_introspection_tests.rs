@@ -305,7 +305,7 @@ async fn all_postgres_column_types_must_work() {
                 full_data_type: "int8".into(),
                 character_maximum_length: None,
 
-                family: ColumnTypeFamily::Int,
+                family: ColumnTypeFamily::BigInt,
                 arity: ColumnArity::Required,
             },
 
@@ -318,7 +318,7 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "bigint".into(),
                 full_data_type: "int8".into(),
                 character_maximum_length: None,
-                family: ColumnTypeFamily::Int,
+                family: ColumnTypeFamily::BigInt,
                 arity: ColumnArity::Required,
             },
 
@@ -680,6 +680,9 @@ async fn all_postgres_column_types_must_work() {
                 name: "User_uuid_col_key".into(),
                 columns: vec!["uuid_col".into(),],
                 tpe: IndexType::Unique,
+                clustered: None,
+                predicate: None,
+                nulls_not_distinct: false,
             },],
             primary_key: Some(PrimaryKey {
                 columns: vec!["primary_col".into()],
@@ -689,6 +692,7 @@ async fn all_postgres_column_types_must_work() {
                     allocation_size: 1,
                 },),
                 constraint_name: Some("User_pkey".into()),
+                clustered: None,
             }),
             foreign_keys: vec![],
         }
@@ -806,6 +810,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                 columns: vec!["id".into()],
                 sequence: None,
                 constraint_name: Some("User_pkey".into()),
+                clustered: None,
             }),
             foreign_keys: vec![
                 ForeignKey {
@@ -1003,3 +1008,287 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn range_columns_must_be_described_as_strings(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."reservations" (
+                id INTEGER PRIMARY KEY,
+                seats INT4RANGE NOT NULL,
+                during TSTZRANGE,
+                days DATERANGE[]
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+
+    let table = schema.table_bang("reservations");
+
+    for (column_name, data_type) in &[("seats", "int4range"), ("during", "tstzrange"), ("days", "_daterange")] {
+        let column = table.column_bang(column_name);
+
+        assert_eq!(column.tpe.family, ColumnTypeFamily::String);
+        assert_eq!(&column.tpe.full_data_type, data_type);
+    }
+
+    assert_eq!(table.column_bang("days").tpe.arity, ColumnArity::List);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn xml_columns_must_be_described_as_xml(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."documents" (
+                id INTEGER PRIMARY KEY,
+                body XML NOT NULL,
+                attachments XML[]
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+
+    let table = schema.table_bang("documents");
+
+    for (column_name, data_type) in &[("body", "xml"), ("attachments", "_xml")] {
+        let column = table.column_bang(column_name);
+
+        assert_eq!(column.tpe.family, ColumnTypeFamily::Xml);
+        assert_eq!(&column.tpe.full_data_type, data_type);
+    }
+
+    assert_eq!(table.column_bang("attachments").tpe.arity, ColumnArity::List);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn missing_privileges_of_the_connecting_user_must_be_described(api: &TestApi) -> TestResult {
+    let role = format!("{}_restricted", api.schema_name());
+    let setup = format!(
+        r#"
+            CREATE TABLE "{schema}"."private_data" (id INTEGER PRIMARY KEY);
+            CREATE TABLE "{schema}"."public_data" (id INTEGER PRIMARY KEY, secret TEXT);
+            DO $$ BEGIN CREATE ROLE "{role}"; EXCEPTION WHEN duplicate_object THEN NULL; END $$;
+            GRANT USAGE ON SCHEMA "{schema}" TO "{role}";
+            GRANT SELECT (id), INSERT ON "{schema}"."public_data" TO "{role}";
+        "#,
+        schema = api.schema_name(),
+        role = role,
+    );
+
+    api.database().raw_cmd(&setup).await?;
+    api.database().raw_cmd(&format!("SET ROLE \"{}\"", role)).await?;
+
+    let schema = api.describe().await;
+
+    api.database().raw_cmd("RESET ROLE").await?;
+
+    let missing_privilege = |table: &str, column: Option<&str>, privilege| MissingPrivilege {
+        table: table.to_owned(),
+        column: column.map(String::from),
+        privilege,
+    };
+
+    assert_eq!(
+        schema?.missing_privileges,
+        &[
+            missing_privilege("private_data", None, Privilege::Select),
+            missing_privilege("private_data", None, Privilege::Insert),
+            missing_privilege("public_data", Some("secret"), Privilege::Select),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn defaults_using_shared_sequences_must_be_described_as_nextval(api: &TestApi) -> TestResult {
+    let sql = format!(
+        r#"
+            CREATE SEQUENCE "{0}"."shared_ids";
+            CREATE TABLE "{0}"."cats" (id INTEGER PRIMARY KEY DEFAULT nextval('"{0}"."shared_ids"'));
+            CREATE TABLE "{0}"."dogs" (id SERIAL PRIMARY KEY, number INTEGER DEFAULT nextval('"{0}"."shared_ids"'));
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let schema = api.describe().await?;
+
+    let cat_id = schema.table_bang("cats").column_bang("id");
+    assert_eq!(cat_id.default, Some(DefaultValue::NEXTVAL("shared_ids".into())));
+    assert!(!cat_id.auto_increment);
+
+    let dogs = schema.table_bang("dogs");
+    assert_eq!(
+        dogs.column_bang("number").default,
+        Some(DefaultValue::NEXTVAL("shared_ids".into()))
+    );
+    assert!(matches!(
+        dogs.column_bang("id").default,
+        Some(DefaultValue::SEQUENCE(_))
+    ));
+    assert!(schema.get_sequence("shared_ids").is_some());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn views_must_be_described_with_their_columns(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."users" (id INTEGER PRIMARY KEY, name TEXT NOT NULL, active BOOLEAN NOT NULL)
+        "#,
+        api.schema_name()
+    );
+    let create_view = format!(
+        r#"
+            CREATE VIEW "{0}"."active_users" AS SELECT id, name FROM "{0}"."users" WHERE active
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+    api.database().query_raw(&create_view, &[]).await?;
+
+    let schema = api.describe().await?;
+
+    assert_eq!(schema.tables.len(), 1);
+    assert!(schema.get_table("active_users").is_none());
+
+    let view = schema.get_view("active_users").expect("view not found");
+    let column_names: Vec<&str> = view.columns.iter().map(|c| c.name.as_str()).collect();
+
+    assert_eq!(column_names, &["id", "name"]);
+    assert!(view.definition.is_some());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn partial_indexes_must_be_described_with_their_predicate(api: &TestApi) -> TestResult {
+    let sql = format!(
+        r#"
+            CREATE TABLE "{0}"."posts" (id INTEGER PRIMARY KEY, slug TEXT NOT NULL, deleted_at TIMESTAMP);
+            CREATE UNIQUE INDEX "live_slug_unique" ON "{0}"."posts" (slug) WHERE deleted_at IS NULL;
+            CREATE INDEX "slug_idx" ON "{0}"."posts" (slug);
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let schema = api.describe().await?;
+    let posts = schema.table_bang("posts");
+
+    let live_slug_unique = posts.indices.iter().find(|i| i.name == "live_slug_unique").unwrap();
+    assert_eq!(live_slug_unique.columns, &["slug"]);
+    assert_eq!(live_slug_unique.predicate.as_deref(), Some("(deleted_at IS NULL)"));
+    assert!(live_slug_unique.is_partial());
+
+    let slug_idx = posts.indices.iter().find(|i| i.name == "slug_idx").unwrap();
+    assert_eq!(slug_idx.predicate, None);
+
+    // A partial unique index doesn't make the column unique.
+    assert!(!posts.is_column_unique("slug"));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn unique_indexes_must_consider_nulls_distinct_by_default(api: &TestApi) -> TestResult {
+    let sql = format!(
+        r#"
+            CREATE TABLE "{0}"."users" (id INTEGER PRIMARY KEY, email TEXT);
+            CREATE UNIQUE INDEX "users_email_key" ON "{0}"."users" (email);
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let schema = api.describe().await?;
+    let users = schema.table_bang("users");
+    let email_key = users.indices.iter().find(|i| i.name == "users_email_key").unwrap();
+
+    // `NULLS NOT DISTINCT` only exists from Postgres 15 on, the catalog column is missing before.
+    assert!(!email_key.nulls_not_distinct);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn check_constraints_must_be_described(api: &TestApi) -> TestResult {
+    let sql = format!(
+        r#"
+            CREATE TABLE "{0}"."products" (
+                id INTEGER PRIMARY KEY,
+                price INTEGER NOT NULL CONSTRAINT "positive_price" CHECK (price > 0),
+                discount INTEGER NOT NULL,
+                CONSTRAINT "discount_below_price" CHECK (discount < price)
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let schema = api.describe().await?;
+    let checks: Vec<_> = schema.check_constraints_on("products").collect();
+
+    assert_eq!(checks.len(), 2);
+    assert_eq!(checks[0].name, "discount_below_price");
+    assert_eq!(checks[0].definition, "(discount < price)");
+    assert!(checks[0].references_column("discount"));
+    assert!(checks[0].references_column("price"));
+    assert_eq!(checks[1].name, "positive_price");
+    assert_eq!(checks[1].definition, "(price > 0)");
+    assert!(!checks[1].references_column("discount"));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"), ignore("postgres9"))]
+async fn identity_columns_must_be_described_apart_from_serial_columns(api: &TestApi) -> TestResult {
+    let sql = format!(
+        r#"
+            CREATE TABLE "{0}"."always" (id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY);
+            CREATE TABLE "{0}"."by_default" (id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY);
+            CREATE TABLE "{0}"."serial" (id SERIAL PRIMARY KEY);
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let schema = api.describe().await?;
+
+    let always = schema.table_bang("always").column_bang("id");
+    assert!(always.auto_increment);
+    assert_eq!(always.default, Some(DefaultValue::IDENTITY(IdentityGeneration::Always)));
+
+    let by_default = schema.table_bang("by_default").column_bang("id");
+    assert!(by_default.auto_increment);
+    assert_eq!(
+        by_default.default,
+        Some(DefaultValue::IDENTITY(IdentityGeneration::ByDefault))
+    );
+
+    let serial = schema.table_bang("serial").column_bang("id");
+    assert!(serial.auto_increment);
+    assert!(matches!(serial.default, Some(DefaultValue::SEQUENCE(_))));
+
+    Ok(())
+}
@@ -68,11 +68,15 @@ fn database_schema_is_serializable() {
                     name: "column2".to_string(),
                     columns: vec!["column2".to_string()],
                     tpe: IndexType::Normal,
+                    clustered: None,
+                    predicate: None,
+                    nulls_not_distinct: false,
                 }],
                 primary_key: Some(PrimaryKey {
                     columns: vec!["column1".to_string()],
                     sequence: None,
                     constraint_name: None,
+                    clustered: None,
                 }),
                 foreign_keys: vec![ForeignKey {
                     constraint_name: None,
@@ -103,6 +107,7 @@ fn database_schema_is_serializable() {
                     columns: vec!["id".to_string()],
                     sequence: None,
                     constraint_name: None,
+                    clustered: None,
                 }),
                 foreign_keys: vec![],
             },
@@ -116,6 +121,16 @@ fn database_schema_is_serializable() {
             initial_value: 1,
             allocation_size: 32,
         }],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -153,6 +168,16 @@ fn database_schema_without_primary_key_is_serializable() {
         }],
         enums: vec![],
         sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-without-primary-key.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -182,6 +207,7 @@ fn database_schema_is_serializable_for_every_column_type_family() {
         ColumnTypeFamily::LogSequenceNumber,
         ColumnTypeFamily::TextSearch,
         ColumnTypeFamily::TransactionId,
+        ColumnTypeFamily::Xml,
     ]
     .iter()
     .enumerate()
@@ -209,6 +235,16 @@ fn database_schema_is_serializable_for_every_column_type_family() {
         }],
         enums: vec![],
         sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-type-families.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -252,6 +288,16 @@ fn database_schema_is_serializable_for_every_column_arity() {
         }],
         enums: vec![],
         sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-arities.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -385,6 +431,16 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
         }],
         enums: vec![],
         sequences: vec![],
+        distributed_tables: vec![],
+        missing_privileges: vec![],
+        on_update_timestamps: vec![],
+        views: vec![],
+        generated_columns: vec![],
+        check_constraints: vec![],
+        auto_increment_values: vec![],
+        temporal_tables: vec![],
+        memory_optimized_tables: vec![],
+        external_tables: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-foreign-key-actions.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -6,7 +6,7 @@ use barrel::{types, Migration};
 use common::*;
 use pretty_assertions::assert_eq;
 use quaint::prelude::Queryable;
-use sql_schema_describer::*;
+use sql_schema_describer::{walkers::SqlSchemaExt, *};
 use sqlite::*;
 use test_api::{sqlite_test_api, TestApi, TestResult};
 use test_macros::test_each_connector;
@@ -112,6 +112,7 @@ async fn sqlite_column_types_must_work() {
                 columns: vec!["primary_col".to_string()],
                 sequence: None,
                 constraint_name: None,
+                clustered: None,
             }),
             foreign_keys: vec![],
         }
@@ -222,6 +223,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                 columns: vec!["id".to_string()],
                 sequence: None,
                 constraint_name: None,
+                clustered: None,
             }),
             foreign_keys: vec![
                 ForeignKey {
@@ -300,6 +302,7 @@ async fn sqlite_text_primary_keys_must_be_inferred_on_table_and_not_as_separate_
             columns: vec!["primary_col".to_owned()],
             sequence: None,
             constraint_name: None,
+            clustered: None,
         }
     );
 }
@@ -385,3 +388,88 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn views_must_be_described_with_their_columns(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."users" (id INTEGER PRIMARY KEY, name TEXT NOT NULL, active BOOLEAN NOT NULL)
+        "#,
+        api.schema_name()
+    );
+    let create_view = format!(
+        r#"
+            CREATE VIEW "{0}"."active_users" AS SELECT id, name FROM "users" WHERE active
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+    api.database().query_raw(&create_view, &[]).await?;
+
+    let schema = api.describe().await?;
+
+    assert_eq!(schema.tables.len(), 1);
+    assert!(schema.get_table("active_users").is_none());
+
+    let view = schema.get_view("active_users").expect("view not found");
+    let column_names: Vec<&str> = view.columns.iter().map(|c| c.name.as_str()).collect();
+
+    assert_eq!(column_names, &["id", "name"]);
+    assert!(view.definition.is_some());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn explicit_null_defaults_must_be_described(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."users" (id INTEGER PRIMARY KEY, nickname TEXT DEFAULT NULL, bio TEXT)
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let users = schema.table_bang("users");
+
+    assert_eq!(users.column_bang("nickname").default, Some(DefaultValue::NULL));
+    assert_eq!(users.column_bang("bio").default, None);
+
+    // They are the same as no default for the walkers.
+    let nickname = schema.table_walker("users").unwrap().column("nickname").unwrap();
+    assert_eq!(nickname.default(), None);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn partial_indexes_must_be_described_with_their_predicate(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."posts" (id INTEGER PRIMARY KEY, slug TEXT NOT NULL, deleted_at DATETIME)
+        "#,
+        api.schema_name()
+    );
+    let create_index = format!(
+        r#"
+            CREATE UNIQUE INDEX "{0}"."live_slug_unique" ON "posts" (slug) WHERE deleted_at IS NULL
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+    api.database().query_raw(&create_index, &[]).await?;
+
+    let schema = api.describe().await?;
+    let posts = schema.table_bang("posts");
+    let live_slug_unique = posts.indices.iter().find(|i| i.name == "live_slug_unique").unwrap();
+
+    assert_eq!(live_slug_unique.columns, &["slug"]);
+    assert_eq!(live_slug_unique.predicate.as_deref(), Some("deleted_at IS NULL"));
+    assert!(!posts.is_column_unique("slug"));
+
+    Ok(())
+}
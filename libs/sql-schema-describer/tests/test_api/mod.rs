@@ -6,7 +6,10 @@ use quaint::{
     single::Quaint,
 };
 use sql_schema_describer::*;
-use test_setup::*;
+use test_setup::{
+    connectors::{ExternalConnector, ExternalConnectorFamily, TestApiFactory, TestApiFuture},
+    *,
+};
 
 pub type TestResult = anyhow::Result<()>;
 
@@ -71,6 +74,47 @@ impl TestApi {
     }
 }
 
+impl TestApiFactory for TestApi {
+    fn external_test_api(connector: &'static ExternalConnector, test_name: &'static str) -> TestApiFuture<Self> {
+        Box::pin(async move {
+            let api = match connector.family() {
+                ExternalConnectorFamily::Postgres => {
+                    test_api_helper_for_postgres(connector.url(test_name), test_name, connector.name()).await
+                }
+                ExternalConnectorFamily::Mysql => {
+                    let db_name = test_setup::mysql_safe_identifier(test_name);
+                    let url = connector.url(db_name);
+                    let conn = create_mysql_database(&url.parse().unwrap()).await.unwrap();
+
+                    TestApi {
+                        connector_name: connector.name(),
+                        connection_info: conn.connection_info().to_owned(),
+                        db_name,
+                        database: conn,
+                        sql_family: SqlFamily::Mysql,
+                    }
+                }
+                ExternalConnectorFamily::Sqlite => {
+                    let database = Quaint::new(&connector.url(test_name)).await.unwrap();
+
+                    TestApi {
+                        connector_name: connector.name(),
+                        db_name: test_name,
+                        connection_info: database.connection_info().to_owned(),
+                        database,
+                        sql_family: SqlFamily::Sqlite,
+                    }
+                }
+                ExternalConnectorFamily::Mssql => {
+                    mssql_test_api(connector.url("master"), test_name, connector.name()).await
+                }
+            };
+
+            Ok(api)
+        })
+    }
+}
+
 pub async fn mysql_test_api(db_name: &'static str) -> TestApi {
     let db_name = test_setup::mysql_safe_identifier(db_name);
     let url = mysql_url(db_name.as_ref());
@@ -5,6 +5,12 @@ mod test_each_connector;
 use proc_macro::TokenStream;
 use syn::ItemFn;
 
+/// Generates one test per connector from an `async fn(api: &TestApi)` test function.
+///
+/// The connectors can be filtered with `tags("postgres", ...)`, `ignore("sqlite", ...)` and
+/// `capabilities("enums", ...)`. Built-in connectors get their test API from the
+/// `<connector>_test_api` function in scope, external connectors from the
+/// `test_setup::connectors::TestApiFactory` implementation of the test API type.
 #[proc_macro_attribute]
 pub fn test_each_connector(attr: TokenStream, input: TokenStream) -> TokenStream {
     test_each_connector::test_each_connector_impl(attr, input, false)
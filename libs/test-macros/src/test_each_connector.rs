@@ -173,7 +173,33 @@ fn test_each_connector_async_wrapper_functions(
     for connector in args.connectors_to_test(with_mssql) {
         let connector_test_fn_name =
             Ident::new(&format!("{}_on_{}", test_fn_name, connector.name()), Span::call_site());
-        let connector_api_factory = Ident::new(connector.test_api(), Span::call_site());
+
+        let create_api = if connector.is_external() {
+            let api_type = match test_api_type(test_function) {
+                Some(api_type) => api_type,
+                None => {
+                    return vec![syn::Error::new_spanned(
+                        &test_function.sig,
+                        "Tests for external connectors need to take the test API by reference.",
+                    )
+                    .to_compile_error()]
+                }
+            };
+            let connector_name = connector.name();
+
+            quote! {
+                <#api_type as test_setup::connectors::TestApiFactory>::external_test_api(
+                    test_setup::connectors::external_connector(#connector_name),
+                    #test_fn_name_str,
+                )
+                .await
+                .unwrap_or_else(|err| panic!("{}", err))
+            }
+        } else {
+            let connector_api_factory = Ident::new(connector.test_api(), Span::call_site());
+
+            quote!(#connector_api_factory(#test_fn_name_str).await)
+        };
 
         let test = quote! {
             #[test]
@@ -181,7 +207,7 @@ fn test_each_connector_async_wrapper_functions(
                 #optional_logging_import
 
                 let fut = async {
-                    let api = #connector_api_factory(#test_fn_name_str).await;
+                    let api = #create_api;
                     #test_fn_name(&api).await#optional_unwrap
                 }#optional_logging;
 
@@ -201,3 +227,15 @@ fn test_each_connector_async_wrapper_functions(
 
     tests
 }
+
+/// The type of the test API the test function takes as its first argument, e.g. `TestApi` for
+/// `async fn my_test(api: &TestApi)`.
+fn test_api_type(test_function: &ItemFn) -> Option<&syn::Type> {
+    match test_function.sig.inputs.first()? {
+        syn::FnArg::Typed(pat_type) => match &*pat_type.ty {
+            syn::Type::Reference(reference) => Some(&reference.elem),
+            _ => None,
+        },
+        syn::FnArg::Receiver(_) => None,
+    }
+}
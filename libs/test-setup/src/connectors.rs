@@ -1,7 +1,9 @@
 mod capabilities;
+mod external;
 mod tags;
 
 pub use capabilities::*;
+pub use external::*;
 pub use tags::*;
 
 use enumflags2::BitFlags;
@@ -74,7 +76,9 @@ pub static CONNECTORS: Lazy<Connectors> = Lazy::new(|| {
             test_api_factory_name: format!("{}_test_api", name),
             capabilities: infer_capabilities(*tags),
             tags: *tags,
+            external: false,
         })
+        .chain(external_connectors())
         .collect();
 
     Connectors::new(connectors)
@@ -99,12 +103,24 @@ pub static CONNECTORS_MSSQL: Lazy<Connectors> = Lazy::new(|| {
             test_api_factory_name: format!("{}_test_api", name),
             capabilities: infer_capabilities(*tags),
             tags: *tags,
+            external: false,
         })
+        .chain(external_connectors())
         .collect();
 
     Connectors::new(connectors)
 });
 
+fn external_connectors() -> impl Iterator<Item = Connector> {
+    EXTERNAL_CONNECTORS.iter().map(|connector| Connector {
+        name: connector.name().to_owned(),
+        test_api_factory_name: String::new(),
+        capabilities: infer_capabilities(connector.tags()),
+        tags: connector.tags(),
+        external: true,
+    })
+}
+
 pub struct Connectors {
     connectors: Vec<Connector>,
 }
@@ -133,6 +149,7 @@ pub struct Connector {
     test_api_factory_name: String,
     pub capabilities: BitFlags<Capabilities>,
     pub tags: BitFlags<Tags>,
+    external: bool,
 }
 
 impl Connector {
@@ -141,8 +158,14 @@ impl Connector {
         &self.name
     }
 
-    /// The name of the API factory function for that connector.
+    /// The name of the API factory function for that connector. External connectors have none,
+    /// they are set up through `TestApiFactory`.
     pub fn test_api(&self) -> &str {
         &self.test_api_factory_name
     }
+
+    /// Whether the connector was registered through `TEST_EACH_CONNECTOR_EXTERNAL`.
+    pub fn is_external(&self) -> bool {
+        self.external
+    }
 }
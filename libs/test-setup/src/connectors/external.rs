@@ -0,0 +1,199 @@
+use super::Tags;
+use enumflags2::BitFlags;
+use once_cell::sync::Lazy;
+use std::{error::Error as StdError, fmt, future::Future, pin::Pin};
+
+/// Comma-separated list of connectors living outside of this repository that should run the
+/// engines' test suites, in the form `name:tag+tag`, for example `cockroachdb:postgres`.
+///
+/// The tags decide which tests run against the connector, in the same way as for the built-in
+/// connectors. The variable is read when the tests are compiled.
+pub const EXTERNAL_CONNECTORS_ENV_VAR: &str = "TEST_EACH_CONNECTOR_EXTERNAL";
+
+/// Prefix of the environment variables holding the connection string of an external connector.
+/// For a connector named `cockroachdb`, the variable is `TEST_EACH_CONNECTOR_EXTERNAL_URL_COCKROACHDB`.
+/// Every occurrence of `{db_name}` in the connection string is replaced with the name of the test.
+pub const EXTERNAL_CONNECTOR_URL_ENV_VAR_PREFIX: &str = "TEST_EACH_CONNECTOR_EXTERNAL_URL_";
+
+/// All registered external connectors.
+pub static EXTERNAL_CONNECTORS: Lazy<Vec<ExternalConnector>> = Lazy::new(|| {
+    let connectors_str = match std::env::var(EXTERNAL_CONNECTORS_ENV_VAR) {
+        Ok(connectors_str) => connectors_str,
+        Err(_) => return Vec::new(),
+    };
+
+    connectors_str
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|connector_str| {
+            let mut parts = connector_str.splitn(2, ':');
+            let name = parts.next().unwrap().trim();
+            let tags_str = parts.next().unwrap_or_else(|| {
+                panic!(
+                    "External connector `{}` has no tags. Expected the form `name:tag+tag` in {}.",
+                    name, EXTERNAL_CONNECTORS_ENV_VAR
+                )
+            });
+
+            let mut tags = Tags::empty();
+
+            for tag_str in tags_str.split('+') {
+                tags |= Tags::from_name(tag_str.trim()).unwrap_or_else(|err| panic!("{}", err));
+            }
+
+            let family = ExternalConnectorFamily::from_tags(tags).unwrap_or_else(|| {
+                panic!(
+                    "External connector `{}` has no database family tag (postgres, mysql, sqlite, mssql_2017 or mssql_2019).",
+                    name
+                )
+            });
+
+            ExternalConnector {
+                name: name.to_owned(),
+                tags,
+                family,
+            }
+        })
+        .collect()
+});
+
+/// Returns the registered external connector with the given name.
+pub fn external_connector(name: &str) -> &'static ExternalConnector {
+    EXTERNAL_CONNECTORS
+        .iter()
+        .find(|connector| connector.name == name)
+        .unwrap_or_else(|| panic!("No external connector named `{}` is registered.", name))
+}
+
+/// A connector implemented outside of this repository, registered through
+/// `TEST_EACH_CONNECTOR_EXTERNAL`.
+pub struct ExternalConnector {
+    name: String,
+    tags: BitFlags<Tags>,
+    family: ExternalConnectorFamily,
+}
+
+impl ExternalConnector {
+    /// The name of the connector.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The tags the connector was registered with.
+    pub fn tags(&self) -> BitFlags<Tags> {
+        self.tags
+    }
+
+    /// The database family the connector speaks.
+    pub fn family(&self) -> ExternalConnectorFamily {
+        self.family
+    }
+
+    /// The connection string for the test database with the given name.
+    pub fn url(&self, db_name: &str) -> String {
+        let var_name = format!("{}{}", EXTERNAL_CONNECTOR_URL_ENV_VAR_PREFIX, self.name.to_uppercase());
+        let url = std::env::var(&var_name).unwrap_or_else(|_| panic!("{} env var is not defined", var_name));
+
+        url.replace("{db_name}", db_name)
+    }
+
+    /// The datasource block for the test database with the given name.
+    pub fn test_config(&self, db_name: &str) -> String {
+        format!(
+            r#"
+        datasource my_db {{
+            provider = "{}"
+            url = "{}"
+            default = true
+        }}
+    "#,
+            self.family.provider(),
+            self.url(db_name)
+        )
+    }
+
+    /// The error to return from `TestApiFactory` when a test suite can't run against the family of
+    /// the connector.
+    pub fn unsupported_by(&self, test_suite: &'static str) -> ExternalConnectorError {
+        ExternalConnectorError {
+            connector_name: self.name.clone(),
+            family: self.family,
+            test_suite,
+        }
+    }
+}
+
+/// The database family of an external connector, derived from its tags. The test suites create
+/// the test database and connect to it in the same way as for the built-in connectors of that
+/// family, so the external connector has to speak its wire protocol.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExternalConnectorFamily {
+    Postgres,
+    Mysql,
+    Sqlite,
+    Mssql,
+}
+
+impl ExternalConnectorFamily {
+    fn from_tags(tags: BitFlags<Tags>) -> Option<Self> {
+        if tags.intersects(Tags::Postgres) {
+            Some(ExternalConnectorFamily::Postgres)
+        } else if tags.intersects(Tags::Mysql) {
+            Some(ExternalConnectorFamily::Mysql)
+        } else if tags.intersects(Tags::Sqlite) {
+            Some(ExternalConnectorFamily::Sqlite)
+        } else if tags.intersects(Tags::Mssql2017 | Tags::Mssql2019) {
+            Some(ExternalConnectorFamily::Mssql)
+        } else {
+            None
+        }
+    }
+
+    /// The datamodel provider of the family.
+    pub fn provider(self) -> &'static str {
+        match self {
+            ExternalConnectorFamily::Postgres => "postgresql",
+            ExternalConnectorFamily::Mysql => "mysql",
+            ExternalConnectorFamily::Sqlite => "sqlite",
+            ExternalConnectorFamily::Mssql => "sqlserver",
+        }
+    }
+}
+
+/// A test suite can't set up its test API for an external connector.
+#[derive(Debug)]
+pub struct ExternalConnectorError {
+    connector_name: String,
+    family: ExternalConnectorFamily,
+    test_suite: &'static str,
+}
+
+impl fmt::Display for ExternalConnectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The {} test suite does not support {:?} connectors, so it can't run against the external connector `{}`. Implement the family in its `TestApiFactory`, or register the connector with tags that exclude the suite's tests.",
+            self.test_suite, self.family, self.connector_name
+        )
+    }
+}
+
+impl StdError for ExternalConnectorError {}
+
+/// The future returned by `TestApiFactory`.
+pub type TestApiFuture<T> = Pin<Box<dyn Future<Output = Result<T, ExternalConnectorError>>>>;
+
+/// The contract between `test_each_connector` and a test suite for connectors registered
+/// through `TEST_EACH_CONNECTOR_EXTERNAL`.
+///
+/// The built-in connectors are set up by calling the `<connector>_test_api` functions in scope
+/// of the test. External connectors can't be known in advance, so the test API type taken by
+/// the test function (`async fn my_test(api: &TestApi)`) has to implement this trait instead.
+/// Implementations are expected to create a fresh database named after the test, connect to it
+/// with `connector.url(test_name)` and return the suite's test API. Suites that can't run against
+/// the `family()` of the connector return `connector.unsupported_by(..)`, which fails the test
+/// with an explanation.
+pub trait TestApiFactory: Sized {
+    fn external_test_api(connector: &'static ExternalConnector, test_name: &'static str) -> TestApiFuture<Self>;
+}
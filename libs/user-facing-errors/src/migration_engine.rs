@@ -44,6 +44,25 @@ pub struct MigrateSystemDatabase {
     pub database_name: String,
 }
 
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P3005",
+    message = "The database user is missing privileges required to run the migration: ${missing_privileges}. Please grant them or connect with another user."
+)]
+pub struct MissingMigrationPrivileges {
+    pub missing_privileges: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P3006",
+    message = "A statement of the migration was canceled by the `${setting}` timeout: ${database_error}. Other queries held locks on the migrated tables, or the statement ran for too long. Please retry the migration."
+)]
+pub struct MigrationTimedOut {
+    pub setting: String,
+    pub database_error: String,
+}
+
 // Tests
 
 #[cfg(test)]
@@ -244,3 +244,88 @@ pub struct TableDoesNotExist {
 pub struct ColumnDoesNotExist {
     pub column: String,
 }
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2023",
+    message = "Timed out after ${timeout} waiting for one of the ${limit} concurrent queries allowed on the database by the `query_concurrency_limit` setting."
+)]
+pub struct ConcurrencyLimitReached {
+    /// The maximum number of concurrent queries.
+    pub limit: usize,
+
+    /// How long the request waited, in s or ms (if <1000ms).
+    pub timeout: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2024",
+    message = "Transactions can not be nested. The batch at `${path}` is transactional, but the enclosing batch is not. Make the outer batch transactional, or send the transactional batch separately."
+)]
+pub struct NestedTransaction {
+    /// The position of the nested batch in the request, e.g. `batch[1]`.
+    pub path: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2025",
+    message = "Transaction `${id}` not found. It was committed, rolled back or expired, or it never existed."
+)]
+pub struct TransactionNotFound {
+    /// The id of the interactive transaction.
+    pub id: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2026",
+    message = "The value `${value}` of the field `${field_name}` on the model `${model_name}` is not a variant of the enum `${enum_name}`. It may have been added to the database without updating the datamodel."
+)]
+pub struct UnknownEnumValue {
+    /// Name of the model the record belongs to.
+    pub model_name: String,
+
+    /// Name of the enum field.
+    pub field_name: String,
+
+    /// Name of the enum in the datamodel.
+    pub enum_name: String,
+
+    /// The value read from the database.
+    pub value: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2027",
+    message = "The operation `${operation}` is not authorized for the claims of the request."
+)]
+pub struct OperationNotAuthorized {
+    /// The operation, e.g. `findMany` or `executeRaw`.
+    pub operation: String,
+
+    /// Name of the model of the operation, if it has one.
+    pub model_name: Option<String>,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2028",
+    message = "The tenant `${tenant}` is not one of the tenants the engine serves."
+)]
+pub struct UnknownTenant {
+    /// The tenant of the request.
+    pub tenant: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2029",
+    message = "There are ${limit} interactive transactions open already, the most the engine keeps open at once. Commit or roll back one of them before starting another."
+)]
+pub struct TooManyOpenTransactions {
+    /// The most interactive transactions the engine keeps open at once.
+    pub limit: usize,
+}
@@ -6,7 +6,7 @@ mod commands;
 mod error_tests;
 mod logger;
 
-use migration_core::{api::RpcApi, error::Error as CoreError};
+use migration_core::{api::RpcApi, error::Error as CoreError, MigrationsHistoryStorage};
 use structopt::StructOpt;
 
 /// When no subcommand is specified, the migration engine will default to starting as a JSON-RPC
@@ -20,6 +20,14 @@ struct MigrationEngineCli {
     /// Path to the datamodel
     #[structopt(short = "d", long, name = "FILE")]
     datamodel: Option<String>,
+    /// The connection string to the database storing the migrations history, when it should not be
+    /// stored in the datasource database
+    #[structopt(long)]
+    migrations_history_url: Option<String>,
+    /// The connection string to the database the migrations are replayed on to detect drift, when a
+    /// temporary database can't be created on the server
+    #[structopt(long)]
+    shadow_database_url: Option<String>,
     #[structopt(subcommand)]
     cli_subcommand: Option<SubCommand>,
 }
@@ -50,7 +58,13 @@ async fn main() {
     match input.cli_subcommand {
         None => {
             if let Some(datamodel_location) = input.datamodel.as_ref() {
-                start_engine(datamodel_location, input.single_cmd).await
+                start_engine(
+                    datamodel_location,
+                    input.migrations_history_url.as_deref(),
+                    input.shadow_database_url.as_deref(),
+                    input.single_cmd,
+                )
+                .await
             } else {
                 panic!("Missing --datamodel");
             }
@@ -62,7 +76,12 @@ async fn main() {
     }
 }
 
-async fn start_engine(datamodel_location: &str, single_cmd: bool) -> ! {
+async fn start_engine(
+    datamodel_location: &str,
+    migrations_history_url: Option<&str>,
+    shadow_database_url: Option<&str>,
+    single_cmd: bool,
+) -> ! {
     use std::io::Read as _;
 
     tracing::info!(git_hash = env!("GIT_HASH"), "Starting migration engine RPC server",);
@@ -71,13 +90,20 @@ async fn start_engine(datamodel_location: &str, single_cmd: bool) -> ! {
     let mut datamodel = String::new();
     file.read_to_string(&mut datamodel).unwrap();
 
+    let migrations_history = match migrations_history_url {
+        Some(migrations_history_url) => MigrationsHistoryStorage::SeparateDatabase(migrations_history_url.to_owned()),
+        None => MigrationsHistoryStorage::MigratedDatabase,
+    };
+
     if single_cmd {
-        let api = RpcApi::new(&datamodel).await.unwrap();
+        let api = RpcApi::new(&datamodel, migrations_history, shadow_database_url)
+            .await
+            .unwrap();
         let response = api.handle().unwrap();
 
         println!("{}", response);
     } else {
-        match RpcApi::new(&datamodel).await {
+        match RpcApi::new(&datamodel, migrations_history, shadow_database_url).await {
             // Block the thread and handle IO in async until EOF.
             Ok(api) => json_rpc_stdio::run(api.io_handler()).await.unwrap(),
             Err(err) => {
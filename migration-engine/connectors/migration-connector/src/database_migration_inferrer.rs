@@ -1,4 +1,4 @@
-use crate::{migrations_directory::MigrationDirectory, ConnectorResult, MigrationStep};
+use crate::{migrations_directory::MigrationDirectory, ConnectorResult, EnumValueRenames, MigrationStep};
 use datamodel::Datamodel;
 
 /// The component responsible for generating a
@@ -31,13 +31,45 @@ pub trait DatabaseMigrationInferrer<T>: Send + Sync {
 
     /// Look at the previous migrations and the target schema, and infer a
     /// database migration taking the database to the expected Prisma schema.
+    /// The values in `enum_value_renames` are renamed instead of being dropped and
+    /// created.
     async fn infer_next_migration(
         &self,
         previous_migrations: &[MigrationDirectory],
         target_schema: &Datamodel,
+        enum_value_renames: &EnumValueRenames,
     ) -> ConnectorResult<T>;
 
     /// Check that the current local database's schema matches its expected
     /// state at the end of the passed in migrations history.
     async fn detect_drift(&self, applied_migrations: &[MigrationDirectory]) -> ConnectorResult<bool>;
+
+    /// Infer the database migration taking the schema of `from` to the schema
+    /// of `to`. Nothing is applied, the database of the connector is only
+    /// touched as a shadow database for migrations histories.
+    async fn diff(&self, from: DiffTarget<'_>, to: DiffTarget<'_>) -> ConnectorResult<T>;
+
+    /// Like [diff](trait.DatabaseMigrationInferrer.html#tymethod.diff), but
+    /// the migration also sets the auto-incrementing counters of the tables to
+    /// their current values in `to`, when it is a live database. This is for
+    /// baseline migrations, so restoring a schema does not reset the counters
+    /// under existing data.
+    async fn diff_with_auto_increment_values(&self, from: DiffTarget<'_>, to: DiffTarget<'_>) -> ConnectorResult<T> {
+        self.diff(from, to).await
+    }
+}
+
+/// One side of a [diff](trait.DatabaseMigrationInferrer.html#tymethod.diff)
+/// between two database schemas.
+#[derive(Debug, Clone, Copy)]
+pub enum DiffTarget<'a> {
+    /// The schema a Prisma schema translates to.
+    Datamodel(&'a Datamodel),
+    /// The current schema of the database at this URL. It has to be a
+    /// database of the same kind as the one of the connector.
+    Database(&'a str),
+    /// The schema at the end of a migrations history.
+    Migrations(&'a [MigrationDirectory]),
+    /// An empty schema.
+    Empty,
 }
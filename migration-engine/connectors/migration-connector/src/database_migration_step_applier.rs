@@ -1,5 +1,6 @@
 use crate::*;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// Apply a single migration step to the connector's database. At this level, we are working with database migrations,
 /// i.e. the [associated type on MigrationConnector](trait.MigrationConnector.html#associatedtype.DatabaseMigration).
@@ -15,9 +16,27 @@ pub trait DatabaseMigrationStepApplier<T>: Send + Sync {
     /// Render the migration to a runnable script.
     fn render_script(&self, database_migration: &T, diagnostics: &DestructiveChangeDiagnostics) -> String;
 
+    /// Render the migration to a script that can be run again after it partially failed: the statements are
+    /// guarded with existence checks, where the database supports them.
+    fn render_idempotent_script(&self, database_migration: &T, diagnostics: &DestructiveChangeDiagnostics) -> String;
+
     /// Apply a migration script to the database. The migration persistence is
-    /// managed by the core.
-    async fn apply_script(&self, script: &str) -> ConnectorResult<()>;
+    /// managed by the core. A statement canceled by one of the `timeouts`
+    /// fails with a [retryable](struct.ConnectorError.html#method.is_retryable)
+    /// error.
+    async fn apply_script(&self, script: &str, timeouts: &MigrationTimeouts) -> ConnectorResult<()>;
+}
+
+/// How long each statement of a migration script may wait for locks held by
+/// other queries, and run, so that a migration stuck behind a long-running
+/// query fails instead of blocking indefinitely. The limits only apply while
+/// the script runs. Connectors ignore the limits their database can't enforce.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MigrationTimeouts {
+    /// The maximum time a statement waits for a lock.
+    pub lock_timeout: Option<Duration>,
+    /// The maximum time a statement runs.
+    pub statement_timeout: Option<Duration>,
 }
 
 /// A helper struct to serialize a database migration with an additional `raw` field containing the
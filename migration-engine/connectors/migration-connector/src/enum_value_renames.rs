@@ -0,0 +1,64 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Renamed enum values, provided by the user. Instead of dropping the previous value and creating
+/// the next one, which loses the data of the rows using the previous value, the connector renames
+/// the value in place.
+///
+/// The enums and values are identified by their names in the Prisma schema.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct EnumValueRenames(HashMap<String, HashMap<String, String>>);
+
+impl EnumValueRenames {
+    /// No renamed values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that `previous_value` of the enum is renamed to `next_value`.
+    pub fn insert(
+        &mut self,
+        enum_name: impl Into<String>,
+        previous_value: impl Into<String>,
+        next_value: impl Into<String>,
+    ) {
+        self.0
+            .entry(enum_name.into())
+            .or_default()
+            .insert(previous_value.into(), next_value.into());
+    }
+
+    /// Builder-style version of `insert`.
+    pub fn with(
+        mut self,
+        enum_name: impl Into<String>,
+        previous_value: impl Into<String>,
+        next_value: impl Into<String>,
+    ) -> Self {
+        self.insert(enum_name, previous_value, next_value);
+        self
+    }
+
+    /// Whether there are no renamed values at all.
+    pub fn is_empty(&self) -> bool {
+        self.0.values().all(|values| values.is_empty())
+    }
+
+    /// The next name of a renamed value of the enum.
+    pub fn renamed_value(&self, enum_name: &str, previous_value: &str) -> Option<&str> {
+        self.0
+            .get(enum_name)
+            .and_then(|values| values.get(previous_value))
+            .map(String::as_str)
+    }
+
+    /// Iterate over the renamed enums, with their `(previous_value, next_value)` pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, impl Iterator<Item = (&str, &str)>)> {
+        self.0.iter().map(|(enum_name, values)| {
+            (
+                enum_name.as_str(),
+                values.iter().map(|(previous, next)| (previous.as_str(), next.as_str())),
+            )
+        })
+    }
+}
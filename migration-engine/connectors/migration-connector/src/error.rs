@@ -2,7 +2,7 @@ use crate::migrations_directory::ReadMigrationScriptError;
 use std::fmt::Display;
 use thiserror::Error;
 use tracing_error::SpanTrace;
-use user_facing_errors::KnownError;
+use user_facing_errors::{migration_engine::MigrationTimedOut, KnownError};
 
 #[derive(Debug, Error)]
 #[error("{}\n{}", kind, context)]
@@ -46,6 +46,35 @@ impl ConnectorError {
         }
     }
 
+    /// Turns the error of a statement canceled by the `setting` timeout of a
+    /// migration into a retryable error.
+    pub fn into_migration_timed_out(self, setting: &'static str) -> Self {
+        let context = self.context.clone();
+        let user_facing_error = KnownError::new(MigrationTimedOut {
+            setting: setting.to_owned(),
+            database_error: self.kind.to_string(),
+        })
+        .unwrap();
+
+        ConnectorError {
+            user_facing_error: Some(user_facing_error),
+            kind: ErrorKind::MigrationTimedOut {
+                setting,
+                cause: self.into(),
+            },
+            context,
+        }
+    }
+
+    /// Whether the operation failed on a transient condition, like a lock held
+    /// by another query, and may succeed if retried.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self.kind,
+            ErrorKind::MigrationTimedOut { .. } | ErrorKind::ConnectTimeout | ErrorKind::Timeout
+        )
+    }
+
     pub fn query_error(error: anyhow::Error) -> Self {
         let kind = ErrorKind::QueryError(error);
 
@@ -114,6 +143,12 @@ pub enum ErrorKind {
     #[error("Operation timed out")]
     Timeout,
 
+    #[error("The statement was canceled by the `{}` migration timeout. {}", setting, cause)]
+    MigrationTimedOut {
+        setting: &'static str,
+        cause: anyhow::Error,
+    },
+
     #[error("Error opening a TLS connection. {}", message)]
     TlsError { message: String },
 
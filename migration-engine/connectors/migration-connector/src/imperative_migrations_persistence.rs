@@ -21,8 +21,34 @@ pub trait ImperativeMigrationsPersistence: Send + Sync {
     /// populating the `finished_at` field in the migration record.
     async fn record_migration_finished(&self, id: &str) -> ConnectorResult<()>;
 
+    /// Record that the migration was rolled back with its down migration
+    /// script. This means populating the `rolled_back_at` field in the
+    /// migration record.
+    async fn record_migration_rolled_back(&self, id: &str) -> ConnectorResult<()>;
+
+    /// Record that the applied migrations `squashed_migration_names` were
+    /// squashed into `migration_name`, the last of them, with the given script.
+    /// The other migrations are forgotten.
+    async fn record_squashed_migrations(
+        &self,
+        squashed_migration_names: &[String],
+        migration_name: &str,
+        script: &str,
+    ) -> ConnectorResult<()>;
+
     /// List all applied migrations, ordered by `started_at`.
     async fn list_migrations(&self) -> ConnectorResult<Vec<MigrationRecord>>;
+
+    /// List the applied migrations that were not rolled back, ordered by
+    /// `started_at`. This is the history the database is at.
+    async fn list_applied_migrations(&self) -> ConnectorResult<Vec<MigrationRecord>> {
+        let migrations = self.list_migrations().await?;
+
+        Ok(migrations
+            .into_iter()
+            .filter(|migration| !migration.is_rolled_back())
+            .collect())
+    }
 }
 
 /// An applied migration, as returned by list_migrations.
@@ -60,4 +86,9 @@ impl MigrationRecord {
     pub fn is_failed(&self) -> bool {
         self.finished_at.is_none()
     }
+
+    /// Was the migration rolled back?
+    pub fn is_rolled_back(&self) -> bool {
+        self.rolled_back_at.is_some()
+    }
 }
@@ -5,6 +5,7 @@
 mod database_migration_inferrer;
 mod database_migration_step_applier;
 mod destructive_change_checker;
+mod enum_value_renames;
 #[allow(missing_docs)]
 mod error;
 mod imperative_migrations_persistence;
@@ -21,6 +22,7 @@ mod migrations_directory;
 pub use database_migration_inferrer::*;
 pub use database_migration_step_applier::*;
 pub use destructive_change_checker::*;
+pub use enum_value_renames::EnumValueRenames;
 pub use error::*;
 pub use imperative_migrations_persistence::{ImperativeMigrationsPersistence, MigrationRecord, Timestamp};
 pub use migration_applier::*;
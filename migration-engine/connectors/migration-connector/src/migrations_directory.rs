@@ -8,9 +8,11 @@
 //! directorys, named after the migration id, and each containing:
 //!
 //! - A migration script
+//! - Optionally, a down migration script reverting it
 
 use sha2::{Digest, Sha256, Sha512};
 use std::{
+    convert::TryFrom,
     fs::{create_dir, read_dir, DirEntry},
     io::{self, Write as _},
     path::{Path, PathBuf},
@@ -23,6 +25,12 @@ use crate::FormatChecksum;
 /// The file name for migration scripts, not including the file extension.
 pub const MIGRATION_SCRIPT_FILENAME: &str = "migration";
 
+/// The file name for down migration scripts, not including the file extension.
+pub const DOWN_MIGRATION_SCRIPT_FILENAME: &str = "down";
+
+/// Characters that are path separators or not allowed in directory names on at least one platform.
+const FORBIDDEN_NAME_CHARACTERS: &[char] = &['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
+
 /// Create a directory for a new migration.
 pub fn create_migration_directory(
     migrations_directory_path: &Path,
@@ -32,9 +40,9 @@ pub fn create_migration_directory(
     let directory_name = format!(
         "{timestamp}_{migration_name}",
         timestamp = timestamp,
-        migration_name = migration_name
+        migration_name = sanitize_migration_name(migration_name)
     );
-    let directory_path = migrations_directory_path.join(directory_name);
+    let directory_path = migrations_directory_path.join(&directory_name);
 
     if directory_path.exists() {
         return Err(io::Error::new(
@@ -48,7 +56,23 @@ pub fn create_migration_directory(
 
     create_dir(&directory_path)?;
 
-    Ok(MigrationDirectory { path: directory_path })
+    Ok(MigrationDirectory {
+        path: directory_path,
+        name: directory_name,
+    })
+}
+
+/// Replaces the characters that would turn the migration name into a path, or make it an invalid
+/// directory name on Windows, so the migration always ends up in one directory with the same name
+/// on all platforms.
+fn sanitize_migration_name(migration_name: &str) -> String {
+    migration_name
+        .chars()
+        .map(|c| match c {
+            c if FORBIDDEN_NAME_CHARACTERS.contains(&c) || c.is_control() => '_',
+            c => c,
+        })
+        .collect()
 }
 
 /// An IO error that occured while reading the migrations directory.
@@ -68,7 +92,7 @@ pub fn list_migrations(migrations_directory_path: &Path) -> Result<Vec<Migration
         let entry = entry?;
 
         if entry.file_type()?.is_dir() {
-            entries.push(entry.into());
+            entries.push(MigrationDirectory::try_from(entry)?);
         }
     }
 
@@ -82,6 +106,7 @@ pub fn list_migrations(migrations_directory_path: &Path) -> Result<Vec<Migration
 #[derive(Debug, Clone)]
 pub struct MigrationDirectory {
     path: PathBuf,
+    name: String,
 }
 
 #[derive(Debug, Error)]
@@ -97,11 +122,7 @@ impl From<io::Error> for ReadMigrationScriptError {
 impl MigrationDirectory {
     /// The `{timestamp}_{name}` formatted migration name.
     pub fn migration_name(&self) -> &str {
-        self.path
-            .file_name()
-            .expect("MigrationDirectory::migration_id")
-            .to_str()
-            .expect("Migration directory name is not valid UTF-8.")
+        &self.name
     }
 
     /// Write the checksum of the migration script file to `buf`.
@@ -120,12 +141,18 @@ impl MigrationDirectory {
     /// Check whether the checksum of the migration script matches the provided one.
     #[tracing::instrument]
     pub fn matches_checksum(&self, checksum_str: &str) -> Result<bool, ReadMigrationScriptError> {
+        Ok(checksum_str == self.script_checksum()?)
+    }
+
+    /// The formatted SHA-256 checksum of the migration script, as recorded in
+    /// the migrations table when the migration is applied.
+    pub fn script_checksum(&self) -> Result<String, ReadMigrationScriptError> {
         let filesystem_script = self.read_migration_script()?;
         let mut hasher = Sha256::new();
         hasher.update(&filesystem_script);
         let filesystem_script_checksum: [u8; 32] = hasher.finalize().into();
 
-        Ok(checksum_str == filesystem_script_checksum.format_checksum())
+        Ok(filesystem_script_checksum.format_checksum())
     }
 
     /// Write the migration script to the directory.
@@ -146,7 +173,45 @@ impl MigrationDirectory {
     /// Read the migration script to a string.
     #[tracing::instrument]
     pub fn read_migration_script(&self) -> Result<String, ReadMigrationScriptError> {
-        Ok(std::fs::read_to_string(&self.path.join("migration.sql"))?)
+        let mut path = self.path.join(MIGRATION_SCRIPT_FILENAME);
+
+        path.set_extension("sql");
+
+        Ok(std::fs::read_to_string(&path)?)
+    }
+
+    /// Write the down migration script, reverting the migration, to the directory.
+    #[tracing::instrument]
+    pub fn write_down_migration_script(&self, script: &str, extension: &str) -> std::io::Result<()> {
+        let mut path = self.path.join(DOWN_MIGRATION_SCRIPT_FILENAME);
+
+        path.set_extension(extension);
+
+        tracing::debug!("Writing down migration script at {:?}", &path);
+
+        let mut file = std::fs::File::create(&path)?;
+        file.write_all(script.as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Read the down migration script to a string, if the directory has one.
+    #[tracing::instrument]
+    pub fn read_down_migration_script(&self) -> Result<Option<String>, ReadMigrationScriptError> {
+        let mut path = self.path.join(DOWN_MIGRATION_SCRIPT_FILENAME);
+
+        path.set_extension("sql");
+
+        match std::fs::read_to_string(&path) {
+            Ok(script) => Ok(Some(script)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Delete the directory and the migration script in it.
+    pub fn remove(self) -> io::Result<()> {
+        std::fs::remove_dir_all(&self.path)
     }
 
     /// The filesystem path to the directory.
@@ -155,8 +220,25 @@ impl MigrationDirectory {
     }
 }
 
-impl From<DirEntry> for MigrationDirectory {
-    fn from(entry: DirEntry) -> MigrationDirectory {
-        MigrationDirectory { path: entry.path() }
+impl TryFrom<DirEntry> for MigrationDirectory {
+    type Error = io::Error;
+
+    /// Fails on directory names that are not valid Unicode (non-UTF-8 on Unix, unpaired surrogates
+    /// on Windows), since migration names are stored in the database.
+    fn try_from(entry: DirEntry) -> io::Result<MigrationDirectory> {
+        let name = entry.file_name().into_string().map_err(|name| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "The migration directory name `{}` is not valid Unicode.",
+                    name.to_string_lossy()
+                ),
+            )
+        })?;
+
+        Ok(MigrationDirectory {
+            path: entry.path(),
+            name,
+        })
     }
 }
@@ -0,0 +1,72 @@
+use migration_connector::{create_migration_directory, list_migrations};
+use std::fs::create_dir;
+
+#[test]
+fn migration_names_are_listed_in_order() {
+    let dir = tempfile::tempdir().unwrap();
+
+    create_dir(dir.path().join("20201002120000_second")).unwrap();
+    create_dir(dir.path().join("20201001120000_first")).unwrap();
+    std::fs::write(dir.path().join("migration_lock.toml"), "").unwrap();
+
+    let names: Vec<String> = list_migrations(dir.path())
+        .unwrap()
+        .iter()
+        .map(|migration| migration.migration_name().to_owned())
+        .collect();
+
+    assert_eq!(names, &["20201001120000_first", "20201002120000_second"]);
+}
+
+#[test]
+fn migration_names_cannot_contain_path_separators() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let directory = create_migration_directory(dir.path(), r"add/users\and:posts").unwrap();
+
+    assert!(directory.migration_name().ends_with("_add_users_and_posts"));
+    assert_eq!(directory.path().parent().unwrap(), dir.path());
+
+    let migrations = list_migrations(dir.path()).unwrap();
+
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(migrations[0].migration_name(), directory.migration_name());
+}
+
+#[test]
+fn migration_scripts_can_be_read_back_from_nested_migrations_directories() {
+    let dir = tempfile::tempdir().unwrap();
+    let migrations_directory_path = dir.path().join("prisma").join("migrations");
+    std::fs::create_dir_all(&migrations_directory_path).unwrap();
+
+    let directory = create_migration_directory(&migrations_directory_path, "init").unwrap();
+    directory.write_migration_script("SELECT 1;", "sql").unwrap();
+
+    let migrations = list_migrations(&migrations_directory_path).unwrap();
+
+    assert_eq!(migrations[0].read_migration_script().unwrap(), "SELECT 1;");
+}
+
+#[cfg(unix)]
+#[test]
+fn listing_migrations_with_non_utf8_names_fails_without_panicking() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+    let dir = tempfile::tempdir().unwrap();
+    create_dir(dir.path().join(OsStr::from_bytes(b"20201001120000_\xff"))).unwrap();
+
+    assert!(list_migrations(dir.path()).is_err());
+}
+
+#[cfg(windows)]
+#[test]
+fn listing_migrations_with_unpaired_surrogates_in_names_fails_without_panicking() {
+    use std::{ffi::OsString, os::windows::ffi::OsStringExt};
+
+    let dir = tempfile::tempdir().unwrap();
+    let mut name: Vec<u16> = "20201001120000_".encode_utf16().collect();
+    name.push(0xD800);
+    create_dir(dir.path().join(OsString::from_wide(&name))).unwrap();
+
+    assert!(list_migrations(dir.path()).is_err());
+}
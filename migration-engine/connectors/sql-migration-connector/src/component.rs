@@ -1,5 +1,6 @@
 use crate::{connection_wrapper::Connection, flavour::SqlFlavour, DatabaseInfo, SqlMigrationConnector};
-use migration_connector::ConnectorResult;
+use datamodel::RelationMode;
+use migration_connector::{ConnectorResult, MigrationDirectory};
 use quaint::prelude::{ConnectionInfo, SqlFamily};
 use sql_schema_describer::SqlSchema;
 
@@ -29,6 +30,10 @@ pub(crate) trait Component {
         self.connector().describe_schema().await
     }
 
+    async fn sql_schema_from_migration_history(&self, migrations: &[MigrationDirectory]) -> ConnectorResult<SqlSchema> {
+        self.connector().sql_schema_from_migration_history(migrations).await
+    }
+
     fn sql_family(&self) -> SqlFamily {
         self.connection_info().sql_family()
     }
@@ -36,6 +41,10 @@ pub(crate) trait Component {
     fn flavour(&self) -> &(dyn SqlFlavour + Send + Sync + 'static) {
         self.connector().flavour.as_ref()
     }
+
+    fn relation_mode(&self) -> RelationMode {
+        self.connector().relation_mode
+    }
 }
 
 #[async_trait::async_trait]
@@ -1,4 +1,7 @@
-use crate::error::quaint_error_to_connector_error;
+use crate::{
+    error::quaint_error_to_connector_error,
+    sql_statement_splitter::{can_run_in_postgres_transaction, split_sql_statements},
+};
 use migration_connector::ConnectorResult;
 use quaint::{
     prelude::{ConnectionInfo, Query, Queryable, ResultSet},
@@ -58,4 +61,41 @@ impl Connection {
             .await
             .map_err(|err| quaint_error_to_connector_error(err, self.connection_info()))
     }
+
+    /// Runs a migration script, one statement at a time.
+    ///
+    /// Postgres used to run the scripts sent at once as a single implicit transaction, so the statements are
+    /// wrapped in a transaction there: a script failing partway leaves nothing applied. Scripts controlling
+    /// their transactions themselves, or with statements that can't run in a transaction, run as they are.
+    pub(crate) async fn raw_script(&self, script: &str) -> ConnectorResult<()> {
+        let sql_family = self.connection_info().sql_family();
+        let statements = split_sql_statements(sql_family, script);
+        let in_transaction = sql_family.is_postgres()
+            && statements
+                .iter()
+                .all(|statement| can_run_in_postgres_transaction(statement));
+
+        if in_transaction {
+            self.raw_cmd("BEGIN").await?;
+        }
+
+        for statement in statements {
+            tracing::debug!(%statement);
+
+            if let Err(err) = self.raw_cmd(statement).await {
+                if in_transaction {
+                    // Surface the error of the statement rather than a failed rollback.
+                    self.raw_cmd("ROLLBACK").await.ok();
+                }
+
+                return Err(err);
+            }
+        }
+
+        if in_transaction {
+            self.raw_cmd("COMMIT").await?;
+        }
+
+        Ok(())
+    }
 }
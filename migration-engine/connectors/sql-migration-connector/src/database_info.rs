@@ -5,11 +5,14 @@ use quaint::{
     prelude::{ConnectionInfo, Queryable, SqlFamily},
     single::Quaint,
 };
+use sql_schema_describer::database_version::{DatabaseFlavour, DatabaseVersion};
 
 #[derive(Debug, Clone)]
 pub struct DatabaseInfo {
     connection_info: ConnectionInfo,
     pub database_version: Option<String>,
+    /// The parsed `database_version`, read once when the connector is created.
+    pub version: Option<DatabaseVersion>,
 }
 
 impl DatabaseInfo {
@@ -19,28 +22,36 @@ impl DatabaseInfo {
             .await
             .map_err(|err| quaint_error_to_connector_error(err, &connection_info))?;
 
+        let version = database_version
+            .as_deref()
+            .and_then(|version| DatabaseVersion::parse(connection_info.sql_family(), version));
+
         Ok(DatabaseInfo {
             connection_info,
             database_version,
+            version,
         })
     }
 
     pub(crate) fn is_mysql_5_6(&self) -> bool {
-        self.connection_info.sql_family() == SqlFamily::Mysql
-            && self
-                .database_version
-                .as_ref()
-                .map(|version| version.contains("5.6"))
-                .unwrap_or(false)
+        self.version
+            .map(|version| version.is_mysql_compatible() && version.major == 5 && version.minor == 6)
+            .unwrap_or(false)
     }
 
     pub(crate) fn is_mariadb(&self) -> bool {
-        self.connection_info.sql_family() == SqlFamily::Mysql
-            && self
-                .database_version
-                .as_ref()
-                .map(|version| version.contains("MariaDB"))
-                .unwrap_or(false)
+        self.flavour() == Some(DatabaseFlavour::MariaDb)
+    }
+
+    /// The major version of the PostgreSQL server, e.g. `13` for `PostgreSQL 13.1 on x86_64-pc-linux-gnu...`.
+    pub(crate) fn postgres_major_version(&self) -> Option<u32> {
+        self.version
+            .filter(|version| version.flavour == DatabaseFlavour::Postgres)
+            .map(|version| version.major)
+    }
+
+    pub(crate) fn flavour(&self) -> Option<DatabaseFlavour> {
+        self.version.map(|version| version.flavour)
     }
 
     pub(crate) fn sql_family(&self) -> SqlFamily {
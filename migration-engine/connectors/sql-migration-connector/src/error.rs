@@ -5,7 +5,11 @@ use quaint::{
 };
 use thiserror::Error;
 use tracing_error::SpanTrace;
-use user_facing_errors::{migration_engine::MigrateSystemDatabase, quaint::render_quaint_error, KnownError};
+use user_facing_errors::{
+    migration_engine::{MigrateSystemDatabase, MissingMigrationPrivileges},
+    quaint::render_quaint_error,
+    KnownError,
+};
 
 pub(crate) fn quaint_error_to_connector_error(error: QuaintError, connection_info: &ConnectionInfo) -> ConnectorError {
     let user_facing_error = render_quaint_error(error.kind(), connection_info);
@@ -62,3 +66,21 @@ impl From<SystemDatabase> for ConnectorError {
         }
     }
 }
+
+#[derive(Debug, Error)]
+#[error("The database user is missing privileges required to run the migration: {}.", .0.join(", "))]
+pub(crate) struct MissingPrivileges(pub(crate) Vec<String>);
+
+impl From<MissingPrivileges> for ConnectorError {
+    fn from(err: MissingPrivileges) -> ConnectorError {
+        let user_facing = MissingMigrationPrivileges {
+            missing_privileges: err.0.join(", "),
+        };
+
+        ConnectorError {
+            user_facing_error: Some(KnownError::new(user_facing).unwrap()),
+            kind: ErrorKind::Generic(err.into()),
+            context: SpanTrace::capture(),
+        }
+    }
+}
@@ -19,7 +19,7 @@ use crate::{
     sql_destructive_change_checker::DestructiveChangeCheckerFlavour, sql_renderer::SqlRenderer,
     sql_schema_calculator::SqlSchemaCalculatorFlavour, sql_schema_differ::SqlSchemaDifferFlavour,
 };
-use migration_connector::{ConnectorResult, MigrationDirectory};
+use migration_connector::{ConnectorResult, MigrationDirectory, MigrationTimeouts};
 use quaint::{connector::ConnectionInfo, prelude::SqlFamily};
 use sql_schema_describer::SqlSchema;
 use std::fmt::Debug;
@@ -75,6 +75,31 @@ pub(crate) trait SqlFlavour:
     /// Drop the database and recreate it empty.
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()>;
 
+    /// The privileges the connecting user needs to run migrations, but is
+    /// missing, in a human-readable form. Defaults to none, for databases
+    /// without schema-level privileges.
+    async fn missing_migration_privileges(&self, _connection: &Connection) -> ConnectorResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    /// The statements limiting how long the statements of a migration may wait
+    /// for locks and run, on the connection applying it. Defaults to none, for
+    /// databases without such settings.
+    fn set_migration_timeouts(&self, _timeouts: &MigrationTimeouts) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// The statements lifting the limits of `set_migration_timeouts()`.
+    fn reset_migration_timeouts(&self, _timeouts: &MigrationTimeouts) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// The field of `MigrationTimeouts` that canceled a statement failing with
+    /// the given database error code, if any.
+    fn migration_timeout_setting(&self, _error_code: &str) -> Option<&'static str> {
+        None
+    }
+
     /// Apply the given migration history to a temporary database, and return
     /// the final introspected SQL schema.
     async fn sql_schema_from_migration_history(
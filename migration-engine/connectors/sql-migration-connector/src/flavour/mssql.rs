@@ -134,6 +134,34 @@ impl SqlFlavour for MssqlFlavour {
         Ok(())
     }
 
+    async fn missing_migration_privileges(&self, connection: &Connection) -> ConnectorResult<Vec<String>> {
+        let schema_name = connection.connection_info().schema_name();
+        let result = connection
+            .query_raw(
+                "SELECT HAS_PERMS_BY_NAME(DB_NAME(), 'DATABASE', 'CREATE TABLE') AS can_create_table,
+                HAS_PERMS_BY_NAME(@P1, 'SCHEMA', 'ALTER') AS can_alter_schema",
+                &[schema_name.into()],
+            )
+            .await?;
+
+        let row = match result.get(0) {
+            Some(row) => row,
+            None => return Ok(Vec::new()),
+        };
+
+        let mut missing = Vec::new();
+
+        if row.get("can_create_table").and_then(|value| value.as_i64()) == Some(0) {
+            missing.push("CREATE TABLE on the database".to_owned());
+        }
+
+        if row.get("can_alter_schema").and_then(|value| value.as_i64()) == Some(0) {
+            missing.push(format!("ALTER on schema `{}`", schema_name));
+        }
+
+        Ok(missing)
+    }
+
     fn sql_family(&self) -> SqlFamily {
         SqlFamily::Mssql
     }
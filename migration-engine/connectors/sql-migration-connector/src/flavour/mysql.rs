@@ -3,7 +3,7 @@ use crate::{
     connect, connection_wrapper::Connection, database_info::DatabaseInfo, error::CheckDatabaseInfoResult,
     error::SystemDatabase,
 };
-use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory};
+use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory, MigrationTimeouts};
 use once_cell::sync::Lazy;
 use quaint::{connector::MysqlUrl, prelude::SqlFamily};
 use regex::RegexSet;
@@ -122,6 +122,40 @@ impl SqlFlavour for MysqlFlavour {
         Ok(())
     }
 
+    // MySQL can't limit the execution time of DDL statements, `max_execution_time` only applies to
+    // SELECTs, so only the lock timeouts are set.
+    fn set_migration_timeouts(&self, timeouts: &MigrationTimeouts) -> Vec<String> {
+        let timeout = match timeouts.lock_timeout {
+            // In whole seconds, at least one.
+            Some(timeout) => std::cmp::max(1, (timeout.as_millis() + 999) / 1000),
+            None => return Vec::new(),
+        };
+
+        vec![
+            format!("SET SESSION lock_wait_timeout = {}", timeout),
+            format!("SET SESSION innodb_lock_wait_timeout = {}", timeout),
+        ]
+    }
+
+    fn reset_migration_timeouts(&self, timeouts: &MigrationTimeouts) -> Vec<String> {
+        if timeouts.lock_timeout.is_none() {
+            return Vec::new();
+        }
+
+        vec![
+            "SET SESSION lock_wait_timeout = DEFAULT".to_owned(),
+            "SET SESSION innodb_lock_wait_timeout = DEFAULT".to_owned(),
+        ]
+    }
+
+    fn migration_timeout_setting(&self, error_code: &str) -> Option<&'static str> {
+        match error_code {
+            // ER_LOCK_WAIT_TIMEOUT, for metadata and row locks.
+            "1205" => Some("lock_timeout"),
+            _ => None,
+        }
+    }
+
     fn sql_family(&self) -> SqlFamily {
         SqlFamily::Mysql
     }
@@ -155,7 +189,7 @@ impl SqlFlavour for MysqlFlavour {
                 migration.migration_name()
             );
 
-            temp_database.raw_cmd(&script).await.map_err(|connector_error| {
+            temp_database.raw_script(&script).await.map_err(|connector_error| {
                 connector_error.into_migration_failed(migration.migration_name().to_owned())
             })?;
         }
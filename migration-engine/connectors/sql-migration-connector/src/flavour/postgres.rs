@@ -1,6 +1,6 @@
 use super::SqlFlavour;
 use crate::{connect, connection_wrapper::Connection};
-use migration_connector::{ConnectorError, ConnectorResult, ErrorKind, MigrationDirectory};
+use migration_connector::{ConnectorError, ConnectorResult, ErrorKind, MigrationDirectory, MigrationTimeouts};
 use quaint::{connector::PostgresUrl, prelude::SqlFamily};
 use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend, SqlSchemaDescriberError};
 use std::collections::HashMap;
@@ -13,6 +13,15 @@ impl PostgresFlavour {
     pub(crate) fn schema_name(&self) -> &str {
         self.0.schema()
     }
+
+    /// Whether `autoincrement()` columns are created as identity columns instead of `SERIAL`, with
+    /// the `identity_columns=true` connection string parameter.
+    pub(crate) fn identity_columns(&self) -> bool {
+        self.0
+            .url()
+            .query_pairs()
+            .any(|(key, value)| key == "identity_columns" && value == "true")
+    }
 }
 
 #[async_trait::async_trait]
@@ -152,6 +161,72 @@ impl SqlFlavour for PostgresFlavour {
         Ok(())
     }
 
+    async fn missing_migration_privileges(&self, connection: &Connection) -> ConnectorResult<Vec<String>> {
+        let schema_name = connection.connection_info().schema_name();
+        let result = connection
+            .query_raw(
+                "SELECT has_schema_privilege(oid, 'USAGE') AS can_use, has_schema_privilege(oid, 'CREATE') AS can_create
+                FROM pg_namespace WHERE nspname = $1",
+                &[schema_name.into()],
+            )
+            .await?;
+
+        let row = match result.get(0) {
+            Some(row) => row,
+            None => return Ok(Vec::new()),
+        };
+
+        let mut missing = Vec::new();
+
+        if row.get("can_use").and_then(|value| value.as_bool()) == Some(false) {
+            missing.push(format!("USAGE on schema `{}`", schema_name));
+        }
+
+        if row.get("can_create").and_then(|value| value.as_bool()) == Some(false) {
+            missing.push(format!("CREATE on schema `{}`", schema_name));
+        }
+
+        Ok(missing)
+    }
+
+    fn set_migration_timeouts(&self, timeouts: &MigrationTimeouts) -> Vec<String> {
+        let mut statements = Vec::new();
+
+        if let Some(timeout) = timeouts.lock_timeout {
+            statements.push(format!("SET lock_timeout = {}", timeout.as_millis()));
+        }
+
+        if let Some(timeout) = timeouts.statement_timeout {
+            statements.push(format!("SET statement_timeout = {}", timeout.as_millis()));
+        }
+
+        statements
+    }
+
+    fn reset_migration_timeouts(&self, timeouts: &MigrationTimeouts) -> Vec<String> {
+        let mut statements = Vec::new();
+
+        if timeouts.lock_timeout.is_some() {
+            statements.push("RESET lock_timeout".to_owned());
+        }
+
+        if timeouts.statement_timeout.is_some() {
+            statements.push("RESET statement_timeout".to_owned());
+        }
+
+        statements
+    }
+
+    fn migration_timeout_setting(&self, error_code: &str) -> Option<&'static str> {
+        match error_code {
+            // lock_not_available
+            "55P03" => Some("lock_timeout"),
+            // query_canceled
+            "57014" => Some("statement_timeout"),
+            _ => None,
+        }
+    }
+
     fn sql_family(&self) -> SqlFamily {
         SqlFamily::Postgres
     }
@@ -189,9 +264,12 @@ impl SqlFlavour for PostgresFlavour {
                     migration.migration_name()
                 );
 
-                temporary_database.raw_cmd(&script).await.map_err(|connector_error| {
-                    connector_error.into_migration_failed(migration.migration_name().to_owned())
-                })?;
+                temporary_database
+                    .raw_script(&script)
+                    .await
+                    .map_err(|connector_error| {
+                        connector_error.into_migration_failed(migration.migration_name().to_owned())
+                    })?;
             }
 
             // the connection to the temporary database is dropped at the end of
@@ -1,5 +1,6 @@
 use super::SqlFlavour;
 use crate::{connect, connection_wrapper::Connection};
+use datamodel::common::SqliteInMemory;
 use migration_connector::{ConnectorError, ConnectorResult, ErrorKind, MigrationDirectory};
 use quaint::prelude::SqlFamily;
 use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend, SqlSchemaDescriberError};
@@ -15,6 +16,38 @@ impl SqliteFlavour {
     pub(crate) fn attached_name(&self) -> &str {
         &self.attached_name
     }
+
+    /// Whether the database of the connection lives in memory instead of a file. Connections to
+    /// in-memory databases are opened with the `:memory:` path (see `connect`).
+    pub(crate) fn is_in_memory(&self) -> bool {
+        SqliteInMemory::from_url(&self.file_path).is_some()
+    }
+
+    /// In-memory databases can't be deleted, so we drop all tables instead.
+    async fn drop_all_tables(&self, connection: &Connection) -> ConnectorResult<()> {
+        let sql = format!(
+            r#"SELECT name FROM "{}".sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'"#,
+            self.attached_name()
+        );
+
+        let table_names: Vec<String> = connection
+            .query_raw(&sql, &[])
+            .await?
+            .into_iter()
+            .filter_map(|row| row.get("name").and_then(|name| name.to_string()))
+            .collect();
+
+        connection.raw_cmd("PRAGMA foreign_keys = OFF").await?;
+
+        for table_name in table_names {
+            let drop_table = format!(r#"DROP TABLE "{}"."{}""#, self.attached_name(), table_name);
+            connection.raw_cmd(&drop_table).await?;
+        }
+
+        connection.raw_cmd("PRAGMA foreign_keys = ON").await?;
+
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -22,6 +55,12 @@ impl SqlFlavour for SqliteFlavour {
     async fn create_database(&self, database_str: &str) -> ConnectorResult<String> {
         use anyhow::Context;
 
+        // In-memory databases are created by the first connection to them and dropped with the last
+        // one, there is nothing to create upfront.
+        if SqliteInMemory::from_url(database_str).is_some() {
+            return Ok(self.file_path.clone());
+        }
+
         let path = Path::new(&self.file_path);
         if path.exists() {
             return Ok(self.file_path.clone());
@@ -76,13 +115,24 @@ impl SqlFlavour for SqliteFlavour {
         connection.raw_cmd(&sql).await
     }
 
-    async fn qe_setup(&self, _database_url: &str) -> ConnectorResult<()> {
+    async fn qe_setup(&self, database_url: &str) -> ConnectorResult<()> {
         use std::fs::File;
+
+        if SqliteInMemory::from_url(database_url).is_some() {
+            let connection = connect(database_url).await?;
+
+            return self.drop_all_tables(&connection).await;
+        }
+
         File::create(&self.file_path).expect("Failed to truncate SQLite database");
         Ok(())
     }
 
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
+        if self.is_in_memory() {
+            return self.drop_all_tables(connection).await;
+        }
+
         let file_path = connection.connection_info().file_path().unwrap();
 
         std::fs::remove_file(file_path).map_err(|err| {
@@ -118,9 +168,11 @@ impl SqlFlavour for SqliteFlavour {
         _connection: &Connection,
     ) -> ConnectorResult<SqlSchema> {
         let temp_dir = tempfile::tempdir().expect("Failed to create temporary directory.");
+        // Windows paths have to be written with forward slashes in the connection string.
+        let scratch_db_path = temp_dir.path().join("scratch.db");
         let database_url = format!(
-            "file:{}/scratch.db?db_name={}",
-            temp_dir.path().to_str().unwrap(),
+            "file:{}?db_name={}",
+            scratch_db_path.to_string_lossy().replace('\\', "/"),
             self.attached_name
         );
 
@@ -136,7 +188,7 @@ impl SqlFlavour for SqliteFlavour {
                 migration.migration_name()
             );
 
-            conn.raw_cmd(&script).await.map_err(|connector_error| {
+            conn.raw_script(&script).await.map_err(|connector_error| {
                 connector_error.into_migration_failed(migration.migration_name().to_owned())
             })?;
         }
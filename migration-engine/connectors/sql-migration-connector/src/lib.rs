@@ -1,14 +1,17 @@
 #![deny(rust_2018_idioms, unsafe_code)]
 #![allow(clippy::trivial_regex)] // these will grow
 
-// This is public for test purposes.
+// These are public for test purposes.
 pub mod sql_migration;
+pub mod sql_statement_splitter;
 
 mod component;
 mod connection_wrapper;
 mod database_info;
 mod error;
 mod flavour;
+mod migrations_history;
+mod shadow_database;
 mod sql_database_migration_inferrer;
 mod sql_database_step_applier;
 mod sql_destructive_change_checker;
@@ -20,13 +23,17 @@ mod sql_schema_differ;
 
 use connection_wrapper::Connection;
 use error::quaint_error_to_connector_error;
+pub use migrations_history::MigrationsHistoryStorage;
 pub use sql_migration_persistence::MIGRATION_TABLE_NAME;
 
 use component::Component;
 use database_info::DatabaseInfo;
+use datamodel::{common::SqliteInMemory, RelationMode};
 use flavour::SqlFlavour;
 use migration_connector::*;
+use migrations_history::MigrationsHistory;
 use quaint::{prelude::ConnectionInfo, single::Quaint};
+use shadow_database::ShadowDatabase;
 use sql_database_migration_inferrer::*;
 use sql_database_step_applier::*;
 use sql_destructive_change_checker::*;
@@ -38,6 +45,12 @@ pub struct SqlMigrationConnector {
     connection: Connection,
     database_info: DatabaseInfo,
     flavour: Box<dyn SqlFlavour + Send + Sync + 'static>,
+    /// Where the migrations tables are stored.
+    migrations_history: MigrationsHistory,
+    /// Where the migrations histories are replayed, when it is not a temporary database.
+    shadow_database: Option<ShadowDatabase>,
+    /// Whether relations get foreign keys in the database.
+    relation_mode: RelationMode,
 }
 
 impl SqlMigrationConnector {
@@ -49,13 +62,44 @@ impl SqlMigrationConnector {
         flavour.check_database_info(&database_info)?;
         flavour.ensure_connection_validity(&connection).await?;
 
+        let migrations_history = MigrationsHistory::in_migrated_database(&connection);
+
         Ok(Self {
             flavour,
             database_info,
             connection,
+            migrations_history,
+            shadow_database: None,
+            relation_mode: RelationMode::default(),
         })
     }
 
+    /// Store the migrations history as configured in `storage`, instead of the migrated database.
+    /// This is for databases where the migrations tables can't or shouldn't be created.
+    pub async fn with_migrations_history(mut self, storage: MigrationsHistoryStorage) -> ConnectorResult<Self> {
+        self.migrations_history = MigrationsHistory::new(storage, &self.connection).await?;
+
+        Ok(self)
+    }
+
+    /// Replay the migrations histories on the database at `shadow_database_str`, instead of a
+    /// temporary database created on the server. This is for database servers where the connecting
+    /// user can't create databases. The shadow database is reset every time it is used.
+    pub async fn with_shadow_database(mut self, shadow_database_str: &str) -> ConnectorResult<Self> {
+        let shadow_database = ShadowDatabase::new(shadow_database_str, self.database_info.connection_info()).await?;
+
+        self.shadow_database = Some(shadow_database);
+
+        Ok(self)
+    }
+
+    /// Maintain the integrity of relations as configured in the datasource. With `RelationMode::Prisma`, relations
+    /// don't get foreign keys, only indexes on their columns.
+    pub fn with_relation_mode(mut self, relation_mode: RelationMode) -> Self {
+        self.relation_mode = relation_mode;
+        self
+    }
+
     pub async fn create_database(database_str: &str) -> ConnectorResult<String> {
         let connection_info =
             ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
@@ -80,6 +124,19 @@ impl SqlMigrationConnector {
     async fn describe_schema(&self) -> ConnectorResult<SqlSchema> {
         self.flavour.describe_schema(&self.connection).await
     }
+
+    /// Apply the given migration history to the shadow database, and return the final
+    /// introspected SQL schema.
+    async fn sql_schema_from_migration_history(&self, migrations: &[MigrationDirectory]) -> ConnectorResult<SqlSchema> {
+        match &self.shadow_database {
+            Some(shadow_database) => shadow_database.sql_schema_from_migration_history(migrations).await,
+            None => {
+                self.flavour
+                    .sql_schema_from_migration_history(migrations, &self.connection)
+                    .await
+            }
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -108,7 +165,15 @@ impl MigrationConnector for SqlMigrationConnector {
     }
 
     async fn reset(&self) -> ConnectorResult<()> {
-        self.flavour.reset(self.conn()).await
+        self.flavour.reset(self.conn()).await?;
+
+        // Resetting the migrated database only clears the history when it lives there.
+        if self.migrations_history.is_separate() {
+            self.migration_persistence().reset().await?;
+            self.migrations_history.reset_imperative_migrations().await?;
+        }
+
+        Ok(())
     }
 
     /// Optionally check that the features implied by the provided datamodel are all compatible with
@@ -138,7 +203,7 @@ impl MigrationConnector for SqlMigrationConnector {
     }
 
     fn new_migration_persistence(&self) -> &dyn ImperativeMigrationsPersistence {
-        self
+        &self.migrations_history
     }
 }
 
@@ -146,9 +211,33 @@ async fn connect(database_str: &str) -> ConnectorResult<Connection> {
     let connection_info =
         ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
 
-    let connection = Quaint::new(database_str)
+    let in_memory = match connection_info {
+        ConnectionInfo::Sqlite { .. } => SqliteInMemory::from_url(database_str),
+        _ => None,
+    };
+
+    let url = match &in_memory {
+        Some(_) => SqliteInMemory::connection_url(database_str),
+        None => database_str.to_owned(),
+    };
+
+    let connection = Quaint::new(&url)
         .await
         .map_err(|err| quaint_error_to_connector_error(err, &connection_info))?;
 
-    Ok(Connection::new(connection))
+    let connection = Connection::new(connection);
+
+    // Quaint attached a private in-memory database, the shared one takes its place.
+    if let Some(db) = in_memory.filter(|db| db.is_shared()) {
+        let schema_name = connection.connection_info().schema_name().to_owned();
+
+        connection
+            .execute_raw("DETACH DATABASE ?", &[schema_name.clone().into()])
+            .await?;
+        connection
+            .execute_raw("ATTACH DATABASE ? AS ?", &[db.uri().into(), schema_name.into()])
+            .await?;
+    }
+
+    Ok(connection)
 }
@@ -0,0 +1,81 @@
+//! The migrations history is the content of the migrations tables. By default, they are created in
+//! the migrated database. When Prisma can't or shouldn't create tables there, like on read-only
+//! production databases, the history can be stored in a separate database instead.
+
+use crate::{connect, connection_wrapper::Connection, flavour::SqlFlavour};
+use migration_connector::ConnectorResult;
+
+/// Where the migrations history is stored. It is selected when the connector is set up.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MigrationsHistoryStorage {
+    /// The migrations tables are created in the migrated database.
+    MigratedDatabase,
+    /// The migrations tables are created in the database at the given connection string. It can be
+    /// of another kind than the migrated database, but holds the history of that database only.
+    SeparateDatabase(String),
+}
+
+impl Default for MigrationsHistoryStorage {
+    fn default() -> Self {
+        MigrationsHistoryStorage::MigratedDatabase
+    }
+}
+
+/// The database holding the migrations tables. The migration persistence implementations read and
+/// write the history through it.
+pub(crate) struct MigrationsHistory {
+    connection: Connection,
+    flavour: Box<dyn SqlFlavour + Send + Sync + 'static>,
+    storage: MigrationsHistoryStorage,
+}
+
+impl MigrationsHistory {
+    /// The history stored in the migrated database, at `connection`.
+    pub(crate) fn in_migrated_database(connection: &Connection) -> Self {
+        MigrationsHistory {
+            connection: connection.clone(),
+            flavour: crate::flavour::from_connection_info(connection.connection_info()),
+            storage: MigrationsHistoryStorage::MigratedDatabase,
+        }
+    }
+
+    /// Connect to the database where the history is stored.
+    pub(crate) async fn new(
+        storage: MigrationsHistoryStorage,
+        migrated_database: &Connection,
+    ) -> ConnectorResult<Self> {
+        let migrations_history_str = match &storage {
+            MigrationsHistoryStorage::MigratedDatabase => return Ok(Self::in_migrated_database(migrated_database)),
+            MigrationsHistoryStorage::SeparateDatabase(migrations_history_str) => migrations_history_str,
+        };
+
+        let connection = connect(migrations_history_str).await?;
+        let flavour = crate::flavour::from_connection_info(connection.connection_info());
+
+        flavour.ensure_connection_validity(&connection).await?;
+
+        Ok(MigrationsHistory {
+            connection,
+            flavour,
+            storage,
+        })
+    }
+
+    pub(crate) fn conn(&self) -> &Connection {
+        &self.connection
+    }
+
+    pub(crate) fn flavour(&self) -> &(dyn SqlFlavour + Send + Sync + 'static) {
+        self.flavour.as_ref()
+    }
+
+    pub(crate) fn schema_name(&self) -> &str {
+        self.connection.connection_info().schema_name()
+    }
+
+    /// Whether the history is stored outside of the migrated database. Resetting the migrated
+    /// database then leaves it untouched.
+    pub(crate) fn is_separate(&self) -> bool {
+        matches!(self.storage, MigrationsHistoryStorage::SeparateDatabase(_))
+    }
+}
@@ -0,0 +1,76 @@
+//! The shadow database is where migrations histories are replayed, to find the schema they result
+//! in. By default, the flavours create a temporary database on the server of the migrated
+//! database. When the connecting user can't create databases, a shadow database can be configured
+//! instead: it is reset and reused every time a history is replayed.
+
+use crate::{connect, connection_wrapper::Connection, flavour::SqlFlavour};
+use migration_connector::{ConnectorError, ConnectorResult, ErrorKind, MigrationDirectory};
+use quaint::prelude::ConnectionInfo;
+use sql_schema_describer::SqlSchema;
+
+/// A database dedicated to replaying migrations histories.
+pub(crate) struct ShadowDatabase {
+    connection: Connection,
+    flavour: Box<dyn SqlFlavour + Send + Sync + 'static>,
+}
+
+impl ShadowDatabase {
+    /// Connect to the shadow database at `shadow_database_str`. It has to be of the same kind as
+    /// the migrated database, and must not be the migrated database, since it gets reset.
+    pub(crate) async fn new(shadow_database_str: &str, migrated_database: &ConnectionInfo) -> ConnectorResult<Self> {
+        let connection = connect(shadow_database_str).await?;
+        let connection_info = connection.connection_info();
+
+        if connection_info.sql_family() != migrated_database.sql_family() {
+            return Err(ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl(format!(
+                "The shadow database at `{}` is not of the same kind as the database of the datasource.",
+                connection_info.host()
+            ))));
+        }
+
+        if is_same_database(connection_info, migrated_database) {
+            return Err(ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl(
+                "The shadow database must not be the database of the datasource, it is reset every time it is used."
+                    .to_owned(),
+            )));
+        }
+
+        let flavour = crate::flavour::from_connection_info(connection_info);
+
+        flavour.ensure_connection_validity(&connection).await?;
+
+        Ok(ShadowDatabase { connection, flavour })
+    }
+
+    /// Reset the shadow database, apply the given migration history to it, and return the final
+    /// introspected SQL schema.
+    #[tracing::instrument(skip(self, migrations))]
+    pub(crate) async fn sql_schema_from_migration_history(
+        &self,
+        migrations: &[MigrationDirectory],
+    ) -> ConnectorResult<SqlSchema> {
+        self.flavour.reset(&self.connection).await?;
+
+        for migration in migrations {
+            let script = migration.read_migration_script()?;
+
+            tracing::debug!(
+                "Applying migration `{}` to the shadow database.",
+                migration.migration_name()
+            );
+
+            self.connection.raw_script(&script).await.map_err(|connector_error| {
+                connector_error.into_migration_failed(migration.migration_name().to_owned())
+            })?;
+        }
+
+        self.flavour.describe_schema(&self.connection).await
+    }
+}
+
+fn is_same_database(left: &ConnectionInfo, right: &ConnectionInfo) -> bool {
+    left.host() == right.host()
+        && left.dbname() == right.dbname()
+        && left.schema_name() == right.schema_name()
+        && left.file_path() == right.file_path()
+}
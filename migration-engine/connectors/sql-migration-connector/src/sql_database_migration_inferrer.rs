@@ -1,9 +1,12 @@
 use crate::*;
-use crate::{sql_schema_calculator::SqlSchemaCalculator, sql_schema_differ::SqlSchemaDiffer};
+use crate::{
+    sql_schema_calculator::{database_enum_value_renames, SqlSchemaCalculator},
+    sql_schema_differ::SqlSchemaDiffer,
+};
 use datamodel::*;
 use migration_connector::steps::MigrationStep;
 use migration_connector::*;
-use sql_migration::SqlMigrationStep;
+use sql_migration::{SetAutoIncrementValue, SqlMigrationStep};
 use sql_schema_describer::*;
 
 pub struct SqlDatabaseMigrationInferrer<'a> {
@@ -25,25 +28,29 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
         _steps: &[MigrationStep],
     ) -> ConnectorResult<SqlMigration> {
         let current_database_schema: SqlSchema = self.describe().await?;
-        let expected_database_schema = SqlSchemaCalculator::calculate(next, self.database_info(), self.flavour());
+        let expected_database_schema =
+            SqlSchemaCalculator::calculate(next, self.database_info(), self.flavour(), self.relation_mode());
         Ok(infer(
             current_database_schema,
             expected_database_schema,
             self.database_info(),
             self.flavour(),
+            &EnumValueRenames::new(),
         ))
     }
 
     /// Infer the database migration steps, skipping the schema describer and assuming an empty database.
     fn infer_from_empty(&self, next: &Datamodel) -> ConnectorResult<SqlMigration> {
         let current_database_schema = SqlSchema::empty();
-        let expected_database_schema = SqlSchemaCalculator::calculate(next, self.database_info(), self.flavour());
+        let expected_database_schema =
+            SqlSchemaCalculator::calculate(next, self.database_info(), self.flavour(), self.relation_mode());
 
         Ok(infer(
             current_database_schema,
             expected_database_schema,
             self.database_info(),
             self.flavour(),
+            &EnumValueRenames::new(),
         ))
     }
 
@@ -54,14 +61,16 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
         _steps: &[MigrationStep],
     ) -> ConnectorResult<SqlMigration> {
         let current_database_schema: SqlSchema =
-            SqlSchemaCalculator::calculate(previous, self.database_info(), self.flavour());
-        let expected_database_schema = SqlSchemaCalculator::calculate(next, self.database_info(), self.flavour());
+            SqlSchemaCalculator::calculate(previous, self.database_info(), self.flavour(), self.relation_mode());
+        let expected_database_schema =
+            SqlSchemaCalculator::calculate(next, self.database_info(), self.flavour(), self.relation_mode());
 
         Ok(infer(
             current_database_schema,
             expected_database_schema,
             self.database_info(),
             self.flavour(),
+            &EnumValueRenames::new(),
         ))
     }
 
@@ -70,35 +79,116 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
         &self,
         previous_migrations: &[MigrationDirectory],
         target_schema: &Datamodel,
+        enum_value_renames: &EnumValueRenames,
     ) -> ConnectorResult<SqlMigration> {
-        let current_database_schema = self
-            .flavour()
-            .sql_schema_from_migration_history(previous_migrations, self.conn())
-            .await?;
-        let expected_database_schema =
-            SqlSchemaCalculator::calculate(target_schema, self.database_info(), self.flavour());
+        let current_database_schema = self.sql_schema_from_migration_history(previous_migrations).await?;
+        let expected_database_schema = SqlSchemaCalculator::calculate(
+            target_schema,
+            self.database_info(),
+            self.flavour(),
+            self.relation_mode(),
+        );
+        let enum_value_renames = database_enum_value_renames(target_schema, self.database_info(), enum_value_renames);
 
         Ok(infer(
             current_database_schema,
             expected_database_schema,
             self.database_info(),
             self.flavour(),
+            &enum_value_renames,
         ))
     }
 
     async fn detect_drift(&self, applied_migrations: &[MigrationDirectory]) -> ConnectorResult<bool> {
-        let expected_schema = self
-            .flavour()
-            .sql_schema_from_migration_history(applied_migrations, self.conn())
-            .await?;
+        let expected_schema = self.sql_schema_from_migration_history(applied_migrations).await?;
 
         let actual_schema = self.describe().await?;
 
-        let diff =
-            SqlSchemaDiffer::diff(&actual_schema, &expected_schema, self.flavour(), self.database_info()).into_steps();
+        let diff = SqlSchemaDiffer::diff(
+            &actual_schema,
+            &expected_schema,
+            self.flavour(),
+            self.database_info(),
+            &EnumValueRenames::new(),
+        )
+        .into_steps();
 
         Ok(!diff.is_empty())
     }
+
+    async fn diff(&self, from: DiffTarget<'_>, to: DiffTarget<'_>) -> ConnectorResult<SqlMigration> {
+        let current_database_schema = self.schema_of(from).await?;
+        let expected_database_schema = self.schema_of(to).await?;
+
+        Ok(infer(
+            current_database_schema,
+            expected_database_schema,
+            self.database_info(),
+            self.flavour(),
+            &EnumValueRenames::new(),
+        ))
+    }
+
+    async fn diff_with_auto_increment_values(
+        &self,
+        from: DiffTarget<'_>,
+        to: DiffTarget<'_>,
+    ) -> ConnectorResult<SqlMigration> {
+        let current_database_schema = self.schema_of(from).await?;
+        let expected_database_schema = self.schema_of(to).await?;
+
+        let set_auto_increment_values: Vec<_> = expected_database_schema
+            .auto_increment_values
+            .iter()
+            .map(|auto_increment_value| {
+                SqlMigrationStep::SetAutoIncrementValue(SetAutoIncrementValue {
+                    table: auto_increment_value.table.clone(),
+                    column: auto_increment_value.column.clone(),
+                    next_value: auto_increment_value.next_value,
+                })
+            })
+            .collect();
+
+        let mut migration = infer(
+            current_database_schema,
+            expected_database_schema,
+            self.database_info(),
+            self.flavour(),
+            &EnumValueRenames::new(),
+        );
+
+        migration.steps.extend(set_auto_increment_values);
+
+        Ok(migration)
+    }
+}
+
+impl SqlDatabaseMigrationInferrer<'_> {
+    async fn schema_of(&self, target: DiffTarget<'_>) -> ConnectorResult<SqlSchema> {
+        match target {
+            DiffTarget::Datamodel(datamodel) => Ok(SqlSchemaCalculator::calculate(
+                datamodel,
+                self.database_info(),
+                self.flavour(),
+                self.relation_mode(),
+            )),
+            DiffTarget::Database(database_str) => {
+                let connection = crate::connect(database_str).await?;
+
+                // The schema is described and rendered with the flavour of the connector.
+                if connection.connection_info().sql_family() != self.database_info().sql_family() {
+                    return Err(ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl(format!(
+                        "The database at `{}` is not of the same kind as the database of the datasource.",
+                        connection.connection_info().host()
+                    ))));
+                }
+
+                self.flavour().describe_schema(&connection).await
+            }
+            DiffTarget::Migrations(migrations) => self.sql_schema_from_migration_history(migrations).await,
+            DiffTarget::Empty => Ok(SqlSchema::empty()),
+        }
+    }
 }
 
 fn infer(
@@ -106,12 +196,14 @@ fn infer(
     expected_database_schema: SqlSchema,
     database_info: &DatabaseInfo,
     flavour: &dyn SqlFlavour,
+    enum_value_renames: &EnumValueRenames,
 ) -> SqlMigration {
     let steps = SqlSchemaDiffer::diff(
         &current_database_schema,
         &expected_database_schema,
         flavour,
         &database_info,
+        enum_value_renames,
     )
     .into_steps();
 
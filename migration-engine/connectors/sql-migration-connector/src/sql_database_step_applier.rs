@@ -1,12 +1,13 @@
 use crate::{
     database_info::DatabaseInfo,
+    error::MissingPrivileges,
     sql_migration::{CreateTable, DropTable, SqlMigration, SqlMigrationStep},
     sql_schema_differ::SqlSchemaDiffer,
     Component, SqlFlavour,
 };
 use migration_connector::{
     ConnectorError, ConnectorResult, DatabaseMigrationMarker, DatabaseMigrationStepApplier,
-    DestructiveChangeDiagnostics, PrettyDatabaseMigrationStep,
+    DestructiveChangeDiagnostics, EnumValueRenames, ErrorKind, MigrationTimeouts, PrettyDatabaseMigrationStep,
 };
 use sql_schema_describer::{walkers::SqlSchemaExt, SqlSchema};
 
@@ -48,6 +49,49 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier<'_> {
     }
 
     fn render_script(&self, database_migration: &SqlMigration, diagnostics: &DestructiveChangeDiagnostics) -> String {
+        self.render_script_with_guards(database_migration, diagnostics, false)
+    }
+
+    fn render_idempotent_script(
+        &self,
+        database_migration: &SqlMigration,
+        diagnostics: &DestructiveChangeDiagnostics,
+    ) -> String {
+        self.render_script_with_guards(database_migration, diagnostics, true)
+    }
+
+    async fn apply_script(&self, script: &str, timeouts: &MigrationTimeouts) -> ConnectorResult<()> {
+        self.check_migration_privileges().await?;
+
+        for statement in self.flavour().set_migration_timeouts(timeouts) {
+            self.conn().raw_cmd(&statement).await?;
+        }
+
+        let result = self
+            .conn()
+            .raw_script(script)
+            .await
+            .map_err(|err| self.classify_migration_timeout(err));
+
+        // The connection outlives the migration, the limits must not apply to what comes next.
+        for statement in self.flavour().reset_migration_timeouts(timeouts) {
+            if let Err(err) = self.conn().raw_cmd(&statement).await {
+                // The error of the script comes first.
+                return result.and(Err(err));
+            }
+        }
+
+        result
+    }
+}
+
+impl SqlDatabaseStepApplier<'_> {
+    fn render_script_with_guards(
+        &self,
+        database_migration: &SqlMigration,
+        diagnostics: &DestructiveChangeDiagnostics,
+        idempotent: bool,
+    ) -> String {
         if database_migration.is_empty() {
             return "-- This is an empty migration.".to_string();
         }
@@ -81,6 +125,7 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier<'_> {
                 self.database_info(),
                 &database_migration.before,
                 &database_migration.after,
+                idempotent,
             )
             .unwrap();
 
@@ -97,12 +142,33 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier<'_> {
         script
     }
 
-    async fn apply_script(&self, script: &str) -> ConnectorResult<()> {
-        self.conn().raw_cmd(script).await
+    /// Fails before any DDL runs when the connecting user is missing privileges.
+    async fn check_migration_privileges(&self) -> ConnectorResult<()> {
+        let missing_privileges = self.flavour().missing_migration_privileges(self.conn()).await?;
+
+        if !missing_privileges.is_empty() {
+            return Err(MissingPrivileges(missing_privileges).into());
+        }
+
+        Ok(())
+    }
+
+    /// Makes the errors of statements canceled by a migration timeout retryable.
+    fn classify_migration_timeout(&self, err: ConnectorError) -> ConnectorError {
+        let setting = match &err.kind {
+            ErrorKind::QueryError(cause) => cause
+                .downcast_ref::<quaint::error::Error>()
+                .and_then(|cause| cause.original_code())
+                .and_then(|code| self.flavour().migration_timeout_setting(code)),
+            _ => None,
+        };
+
+        match setting {
+            Some(setting) => err.into_migration_timed_out(setting),
+            None => err,
+        }
     }
-}
 
-impl SqlDatabaseStepApplier<'_> {
     async fn apply_next_step(
         &self,
         steps: &[SqlMigrationStep],
@@ -117,11 +183,22 @@ impl SqlDatabaseStepApplier<'_> {
             return Ok(false);
         }
 
+        if index == 0 {
+            self.check_migration_privileges().await?;
+        }
+
         let step = &steps[index];
         tracing::debug!(?step);
 
-        for sql_string in render_raw_sql(&step, renderer, self.database_info(), current_schema, next_schema)
-            .map_err(|err| ConnectorError::generic(err))?
+        for sql_string in render_raw_sql(
+            &step,
+            renderer,
+            self.database_info(),
+            current_schema,
+            next_schema,
+            false,
+        )
+        .map_err(|err| ConnectorError::generic(err))?
         {
             tracing::debug!(index, %sql_string);
 
@@ -142,7 +219,7 @@ fn render_steps_pretty(
     let mut steps = Vec::with_capacity(database_migration.steps.len());
 
     for step in &database_migration.steps {
-        let sql = render_raw_sql(&step, renderer, database_info, current_schema, next_schema)
+        let sql = render_raw_sql(&step, renderer, database_info, current_schema, next_schema, false)
             .map_err(|err: anyhow::Error| ConnectorError::from_kind(migration_connector::ErrorKind::Generic(err)))?
             .join(";\n");
 
@@ -163,37 +240,66 @@ fn render_raw_sql(
     database_info: &DatabaseInfo,
     current_schema: &SqlSchema,
     next_schema: &SqlSchema,
+    guarded: bool,
 ) -> Result<Vec<String>, anyhow::Error> {
+    // The renamed enum values are already part of the steps.
+    let enum_value_renames = EnumValueRenames::new();
     let differ = SqlSchemaDiffer {
         previous: current_schema,
         next: next_schema,
         database_info,
         flavour: renderer,
+        enum_value_renames: &enum_value_renames,
     };
 
     match step {
         SqlMigrationStep::RedefineTables { names } => Ok(renderer.render_redefine_tables(names, differ)),
-        SqlMigrationStep::CreateEnum(create_enum) => Ok(renderer.render_create_enum(create_enum)),
-        SqlMigrationStep::DropEnum(drop_enum) => Ok(renderer.render_drop_enum(drop_enum)),
+        SqlMigrationStep::CreateEnum(create_enum) => Ok(renderer.render_create_enum(create_enum, guarded)),
+        SqlMigrationStep::DropEnum(drop_enum) => Ok(renderer.render_drop_enum(drop_enum, guarded)),
         SqlMigrationStep::AlterEnum(alter_enum) => renderer.render_alter_enum(alter_enum, &differ),
+        SqlMigrationStep::RenameEnum(rename_enum) => Ok(renderer.render_rename_enum(rename_enum)),
         SqlMigrationStep::CreateTable(CreateTable { table }) => {
             let table = next_schema
                 .table_walker(&table.name)
                 .expect("CreateTable referring to an unknown table.");
 
-            Ok(vec![renderer.render_create_table(&table)?])
+            Ok(vec![renderer.render_create_table(&table, guarded)?])
         }
-        SqlMigrationStep::DropTable(DropTable { name }) => Ok(renderer.render_drop_table(name)),
+        SqlMigrationStep::DropTable(DropTable { name }) => Ok(renderer.render_drop_table(name, guarded)),
         SqlMigrationStep::RenameTable { name, new_name } => Ok(vec![renderer.render_rename_table(name, new_name)]),
-        SqlMigrationStep::AddForeignKey(add_foreign_key) => Ok(vec![renderer.render_add_foreign_key(add_foreign_key)]),
+        SqlMigrationStep::AddForeignKey(add_foreign_key) => {
+            Ok(vec![renderer.render_add_foreign_key(add_foreign_key, guarded)])
+        }
         SqlMigrationStep::DropForeignKey(drop_foreign_key) => {
-            Ok(vec![renderer.render_drop_foreign_key(drop_foreign_key)])
+            Ok(vec![renderer.render_drop_foreign_key(drop_foreign_key, guarded)])
         }
-        SqlMigrationStep::AlterTable(alter_table) => Ok(renderer.render_alter_table(alter_table, &differ)),
-        SqlMigrationStep::CreateIndex(create_index) => Ok(vec![renderer.render_create_index(create_index)]),
-        SqlMigrationStep::DropIndex(drop_index) => Ok(vec![renderer.render_drop_index(drop_index)]),
+        SqlMigrationStep::AlterTable(alter_table) => Ok(renderer.render_alter_table(alter_table, &differ, guarded)),
+        SqlMigrationStep::CreateIndex(create_index) => Ok(vec![renderer.render_create_index(create_index, guarded)]),
+        SqlMigrationStep::DropIndex(drop_index) => Ok(vec![renderer.render_drop_index(drop_index, guarded)]),
         SqlMigrationStep::AlterIndex(alter_index) => {
             renderer.render_alter_index(alter_index, database_info, current_schema)
         }
+        SqlMigrationStep::DistributeTable(distribute_table) => Ok(renderer.render_distribute_table(distribute_table)),
+        SqlMigrationStep::UndistributeTable(undistribute_table) => {
+            Ok(renderer.render_undistribute_table(undistribute_table))
+        }
+        SqlMigrationStep::AddSystemVersioning(add_system_versioning) => {
+            Ok(renderer.render_add_system_versioning(add_system_versioning))
+        }
+        SqlMigrationStep::DropSystemVersioning(drop_system_versioning) => {
+            Ok(renderer.render_drop_system_versioning(drop_system_versioning))
+        }
+        SqlMigrationStep::CreateSequence(create_sequence) => {
+            Ok(renderer.render_create_sequence(create_sequence, guarded))
+        }
+        SqlMigrationStep::AddCheckConstraint(add_check_constraint) => {
+            Ok(renderer.render_add_check_constraint(add_check_constraint, guarded))
+        }
+        SqlMigrationStep::DropCheckConstraint(drop_check_constraint) => {
+            Ok(renderer.render_drop_check_constraint(drop_check_constraint, database_info, guarded))
+        }
+        SqlMigrationStep::SetAutoIncrementValue(set_auto_increment_value) => {
+            Ok(renderer.render_set_auto_increment_value(set_auto_increment_value))
+        }
     }
 }
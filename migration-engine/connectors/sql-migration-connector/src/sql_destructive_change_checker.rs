@@ -177,6 +177,7 @@ impl SqlDestructiveChangeChecker<'_> {
                     index,
                     caused_by_create_table: false,
                     contains_nullable_columns: _,
+                    memory_optimized: _,
                 }) if index.is_unique() => plan.push_warning(
                     SqlMigrationWarningCheck::UniqueConstraintAddition {
                         table: table.clone(),
@@ -188,6 +189,7 @@ impl SqlDestructiveChangeChecker<'_> {
                     name,
                     created_variants: _,
                     dropped_variants,
+                    renamed_variants: _,
                 }) if !dropped_variants.is_empty() => plan.push_warning(
                     SqlMigrationWarningCheck::EnumValueRemoval {
                         enm: name.clone(),
@@ -14,6 +14,12 @@ pub(super) trait Check {
         None
     }
 
+    /// Indicates that the number of rows holding one of the returned values should be inspected for
+    /// the returned table and column.
+    fn needed_enum_value_count(&self) -> Option<(&str, &str, &[String])> {
+        None
+    }
+
     /// This function will always be called for every check in a migration. Each change must check
     /// for the data it needs in the database inspection results. If there is no data, it should
     /// assume the current state of the database could not be inspected and warn with a best effort
@@ -7,6 +7,8 @@ pub(super) struct DatabaseInspectionResults {
     row_counts: HashMap<String, i64>,
     /// HashMap from (table name, column name) to non-null values count.
     value_counts: HashMap<(Cow<'static, str>, Cow<'static, str>), i64>,
+    /// HashMap from (table name, column name) to the count of rows holding the removed enum values.
+    enum_value_counts: HashMap<(Cow<'static, str>, Cow<'static, str>), i64>,
 }
 
 impl DatabaseInspectionResults {
@@ -30,4 +32,14 @@ impl DatabaseInspectionResults {
     pub(super) fn set_value_count(&mut self, table: Cow<'static, str>, column: Cow<'static, str>, count: i64) {
         self.value_counts.insert((table, column), count);
     }
+
+    pub(super) fn get_enum_value_count(&self, table: &str, column: &str) -> Option<i64> {
+        self.enum_value_counts
+            .get(&(Cow::Borrowed(table), Cow::Borrowed(column)))
+            .copied()
+    }
+
+    pub(super) fn set_enum_value_count(&mut self, table: Cow<'static, str>, column: Cow<'static, str>, count: i64) {
+        self.enum_value_counts.insert((table, column), count);
+    }
 }
@@ -69,7 +69,9 @@ fn diagnose_enum_change(columns: &ColumnDiffer<'_>, plan: &mut DestructiveCheckP
 
         if !removed_values.is_empty() {
             plan.push_warning(
-                SqlMigrationWarningCheck::EnumValueRemoval {
+                SqlMigrationWarningCheck::EnumColumnValueRemoval {
+                    table: columns.previous.table().name().to_owned(),
+                    column: columns.previous.name().to_owned(),
                     enm: next_enum.name.clone(),
                     values: removed_values,
                 },
@@ -48,6 +48,8 @@ impl DestructiveChangeCheckerFlavour for PostgresFlavour {
                     }
                     PostgresAlterColumn::SetDefault(_)
                     | PostgresAlterColumn::AddSequence
+                    | PostgresAlterColumn::AddIdentity(_)
+                    | PostgresAlterColumn::DropIdentity
                     | PostgresAlterColumn::DropDefault
                     | PostgresAlterColumn::DropNotNull => (),
                 }
@@ -84,8 +86,12 @@ fn default_can_be_rendered(default: Option<&DefaultValue>) -> bool {
     match default {
         None => false,
         Some(DefaultValue::VALUE(_)) => true,
+        Some(DefaultValue::NULL) => false,
         Some(DefaultValue::DBGENERATED(expr)) => !expr.is_empty(),
         Some(DefaultValue::NOW) => true,
         Some(DefaultValue::SEQUENCE(_)) => false,
+        Some(DefaultValue::NEXTVAL(_)) => true,
+        // Identity columns are filled for the existing rows too.
+        Some(DefaultValue::IDENTITY(_)) => true,
     }
 }
@@ -106,6 +106,13 @@ impl DestructiveCheckPlan {
             }
         }
 
+        if let Some((table, column, values)) = check.needed_enum_value_count() {
+            if results.get_enum_value_count(table, column).is_none() {
+                let count = count_enum_values_in_column(column, table, values, conn).await?;
+                results.set_enum_value_count(table.to_owned().into(), column.to_owned().into(), count);
+            }
+        }
+
         Ok(())
     }
 
@@ -187,3 +194,32 @@ async fn count_values_in_column(column_name: &str, table: &str, conn: &Connectio
 
     Ok(values_count)
 }
+
+async fn count_enum_values_in_column(
+    column_name: &str,
+    table: &str,
+    values: &[String],
+    conn: &Connection,
+) -> ConnectorResult<i64> {
+    use quaint::ast::*;
+
+    let values: Vec<Value<'_>> = values.iter().map(|value| Value::text(value.as_str())).collect();
+    let query = Select::from_table((conn.connection_info().schema_name(), table))
+        .value(count(asterisk()))
+        .so_that(column_name.in_selection(values));
+
+    let values_count: i64 = conn.query(query).await.and_then(|result_set| {
+        result_set
+            .first()
+            .as_ref()
+            .and_then(|row| row.at(0))
+            .and_then(|count| count.as_i64())
+            .ok_or_else(|| {
+                ConnectorError::generic(anyhow::anyhow!(
+                    "Unexpected result set shape when checking removed enum values."
+                ))
+            })
+    })?;
+
+    Ok(values_count)
+}
@@ -2,12 +2,34 @@ use super::{check::Check, database_inspection_results::DatabaseInspectionResults
 
 #[derive(Debug)]
 pub(super) enum SqlMigrationWarningCheck {
-    NonEmptyColumnDrop { table: String, column: String },
-    NonEmptyTableDrop { table: String },
-    AlterColumn { table: String, column: String },
-    PrimaryKeyChange { table: String },
-    UniqueConstraintAddition { table: String, columns: Vec<String> },
-    EnumValueRemoval { enm: String, values: Vec<String> },
+    NonEmptyColumnDrop {
+        table: String,
+        column: String,
+    },
+    NonEmptyTableDrop {
+        table: String,
+    },
+    AlterColumn {
+        table: String,
+        column: String,
+    },
+    PrimaryKeyChange {
+        table: String,
+    },
+    UniqueConstraintAddition {
+        table: String,
+        columns: Vec<String>,
+    },
+    EnumValueRemoval {
+        enm: String,
+        values: Vec<String>,
+    },
+    EnumColumnValueRemoval {
+        table: String,
+        column: String,
+        enm: String,
+        values: Vec<String>,
+    },
 }
 
 impl Check for SqlMigrationWarningCheck {
@@ -32,6 +54,15 @@ impl Check for SqlMigrationWarningCheck {
         }
     }
 
+    fn needed_enum_value_count(&self) -> Option<(&str, &str, &[String])> {
+        match self {
+            SqlMigrationWarningCheck::EnumColumnValueRemoval {
+                table, column, values, ..
+            } => Some((table, column, values)),
+            _ => None,
+        }
+    }
+
     fn evaluate(&self, database_check_results: &DatabaseInspectionResults) -> Option<String> {
         match self {
             SqlMigrationWarningCheck::NonEmptyTableDrop { table } => match database_check_results.get_row_count(table) {
@@ -58,6 +89,11 @@ impl Check for SqlMigrationWarningCheck {
             },
             SqlMigrationWarningCheck::UniqueConstraintAddition { table, columns } =>  Some(format!("The migration will add a unique constraint covering the columns `{columns}` on the table `{table}`. If there are existing duplicate values, the migration will fail.", table = table, columns = format!("[{}]",columns.join(",")))),
             SqlMigrationWarningCheck::EnumValueRemoval { enm, values } =>  Some(format!("The migration will remove the values {values} on the enum `{enm}`. If these variants are still used in the database, the migration will fail.", enm = enm, values = format!("[{}]",values.join(",")))),
+            SqlMigrationWarningCheck::EnumColumnValueRemoval { table, column, enm, values } => match database_check_results.get_enum_value_count(table, column) {
+                Some(0) => None, // it's safe to remove values no row uses
+                Some(rows_count) => Some(format!("The migration will remove the values {values} on the enum `{enm}`, which are still used in {rows_count} rows of the `{column}` column on the `{table}` table. The migration will fail.", enm = enm, values = format!("[{}]", values.join(",")), rows_count = rows_count, column = column, table = table)),
+                None => Some(format!("The migration will remove the values {values} on the enum `{enm}`. If these variants are still used in the database, the migration will fail.", enm = enm, values = format!("[{}]",values.join(",")))),
+            },
 
         }
     }
@@ -1,4 +1,4 @@
-use crate::{component::Component, error::quaint_error_to_connector_error, SqlMigrationConnector};
+use crate::{error::quaint_error_to_connector_error, migrations_history::MigrationsHistory};
 use migration_connector::{ConnectorResult, FormatChecksum, ImperativeMigrationsPersistence, MigrationRecord};
 use quaint::ast::*;
 use sha2::{Digest, Sha256};
@@ -6,18 +6,30 @@ use uuid::Uuid;
 
 const IMPERATIVE_MIGRATIONS_TABLE_NAME: &str = "_prisma_migrations";
 
+impl MigrationsHistory {
+    /// Delete the imperative migrations history. The table may not exist yet.
+    pub(crate) async fn reset_imperative_migrations(&self) -> ConnectorResult<()> {
+        let conn = self.conn();
+
+        conn.query(Delete::from_table((
+            self.schema_name(),
+            IMPERATIVE_MIGRATIONS_TABLE_NAME,
+        )))
+        .await
+        .ok();
+
+        Ok(())
+    }
+}
+
 #[async_trait::async_trait]
-impl ImperativeMigrationsPersistence for SqlMigrationConnector {
+impl ImperativeMigrationsPersistence for MigrationsHistory {
     async fn record_migration_started(&self, migration_name: &str, script: &str) -> ConnectorResult<String> {
         let conn = self.conn();
-        self.flavour.ensure_imperative_migrations_table(conn).await?;
+        self.flavour().ensure_imperative_migrations_table(conn).await?;
 
         let id = Uuid::new_v4().to_string();
-
-        let mut hasher = Sha256::new();
-        hasher.update(script.as_bytes());
-        let checksum: [u8; 32] = hasher.finalize().into();
-        let checksum_string = checksum.format_checksum();
+        let checksum_string = script_checksum(script);
 
         let insert = Insert::single_into((self.schema_name(), IMPERATIVE_MIGRATIONS_TABLE_NAME))
             .value("id", id.as_str())
@@ -68,8 +80,52 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
         Ok(())
     }
 
+    async fn record_migration_rolled_back(&self, id: &str) -> ConnectorResult<()> {
+        let update = Update::table((self.schema_name(), IMPERATIVE_MIGRATIONS_TABLE_NAME))
+            .so_that(Column::from("id").equals(id))
+            .set("rolled_back_at", chrono::Utc::now());
+
+        self.conn().execute(update).await?;
+
+        Ok(())
+    }
+
+    async fn record_squashed_migrations(
+        &self,
+        squashed_migration_names: &[String],
+        migration_name: &str,
+        script: &str,
+    ) -> ConnectorResult<()> {
+        let conn = self.conn();
+        let table = (self.schema_name(), IMPERATIVE_MIGRATIONS_TABLE_NAME);
+
+        let forgotten_migration_names: Vec<&str> = squashed_migration_names
+            .iter()
+            .map(String::as_str)
+            .filter(|name| *name != migration_name)
+            .collect();
+
+        if !forgotten_migration_names.is_empty() {
+            let delete = Delete::from_table(table)
+                .so_that(Column::from("migration_name").in_selection(forgotten_migration_names));
+
+            conn.execute(delete).await?;
+        }
+
+        // The record keeps its `started_at`, so the squashed migration stays in place in the history.
+        let update = Update::table(table)
+            .so_that(Column::from("migration_name").equals(migration_name))
+            .set("checksum", script_checksum(script))
+            .set("script", script);
+
+        conn.execute(update).await?;
+
+        Ok(())
+    }
+
     async fn list_migrations(&self) -> ConnectorResult<Vec<MigrationRecord>> {
-        self.flavour.ensure_imperative_migrations_table(self.conn()).await?;
+        let conn = self.conn();
+        self.flavour().ensure_imperative_migrations_table(conn).await?;
 
         let select = Select::from_table((self.schema_name(), IMPERATIVE_MIGRATIONS_TABLE_NAME))
             .column("id")
@@ -83,11 +139,20 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
             .column("script")
             .order_by("started_at".ascend());
 
-        let result = self.conn().query(select).await?;
+        let result = conn.query(select).await?;
 
         let rows = quaint::serde::from_rows(result)
-            .map_err(|err| quaint_error_to_connector_error(err, self.connection_info()))?;
+            .map_err(|err| quaint_error_to_connector_error(err, conn.connection_info()))?;
 
         Ok(rows)
     }
 }
+
+/// The SHA-256 checksum of a migration script, as stored in the migrations table.
+fn script_checksum(script: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(script.as_bytes());
+    let checksum: [u8; 32] = hasher.finalize().into();
+
+    checksum.format_checksum()
+}
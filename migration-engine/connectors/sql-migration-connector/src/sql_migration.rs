@@ -2,7 +2,7 @@ pub(crate) mod expanded_alter_column;
 
 use migration_connector::DatabaseMigrationMarker;
 use serde::{Deserialize, Serialize};
-use sql_schema_describer::{Column, ForeignKey, Index, SqlSchema, Table};
+use sql_schema_describer::{CheckConstraint, Column, ForeignKey, Index, SqlSchema, Table};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SqlMigration {
@@ -48,6 +48,15 @@ pub enum SqlMigrationStep {
     CreateEnum(CreateEnum),
     DropEnum(DropEnum),
     AlterEnum(AlterEnum),
+    RenameEnum(RenameEnum),
+    DistributeTable(DistributeTable),
+    UndistributeTable(UndistributeTable),
+    AddSystemVersioning(AddSystemVersioning),
+    DropSystemVersioning(DropSystemVersioning),
+    CreateSequence(CreateSequence),
+    AddCheckConstraint(AddCheckConstraint),
+    DropCheckConstraint(DropCheckConstraint),
+    SetAutoIncrementValue(SetAutoIncrementValue),
 }
 
 impl SqlMigrationStep {
@@ -66,6 +75,15 @@ impl SqlMigrationStep {
             SqlMigrationStep::CreateEnum(_) => "CreateEnum",
             SqlMigrationStep::DropEnum(_) => "DropEnum",
             SqlMigrationStep::AlterEnum(_) => "AlterEnum",
+            SqlMigrationStep::RenameEnum(_) => "RenameEnum",
+            SqlMigrationStep::DistributeTable(_) => "DistributeTable",
+            SqlMigrationStep::UndistributeTable(_) => "UndistributeTable",
+            SqlMigrationStep::AddSystemVersioning(_) => "AddSystemVersioning",
+            SqlMigrationStep::DropSystemVersioning(_) => "DropSystemVersioning",
+            SqlMigrationStep::CreateSequence(_) => "CreateSequence",
+            SqlMigrationStep::AddCheckConstraint(_) => "AddCheckConstraint",
+            SqlMigrationStep::DropCheckConstraint(_) => "DropCheckConstraint",
+            SqlMigrationStep::SetAutoIncrementValue(_) => "SetAutoIncrementValue",
         }
     }
 }
@@ -91,8 +109,13 @@ pub enum TableChange {
     AddColumn(AddColumn),
     AlterColumn(AlterColumn),
     DropColumn(DropColumn),
-    DropPrimaryKey { constraint_name: Option<String> },
-    AddPrimaryKey { columns: Vec<String> },
+    DropPrimaryKey {
+        constraint_name: Option<String>,
+    },
+    AddPrimaryKey {
+        columns: Vec<String>,
+        clustered: Option<bool>,
+    },
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -129,12 +152,16 @@ pub struct CreateIndex {
     pub index: Index,
     pub caused_by_create_table: bool,
     pub contains_nullable_columns: bool,
+    /// Memory-optimized tables on SQL Server only take indexes through ALTER TABLE.
+    pub memory_optimized: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DropIndex {
     pub table: String,
     pub name: String,
+    /// Memory-optimized tables on SQL Server only drop indexes through ALTER TABLE.
+    pub memory_optimized: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -160,14 +187,84 @@ pub struct AlterEnum {
     pub name: String,
     pub created_variants: Vec<String>,
     pub dropped_variants: Vec<String>,
+    /// `(previous, next)` names of the variants that are renamed, keeping the rows that use them.
+    #[serde(default)]
+    pub renamed_variants: Vec<(String, String)>,
 }
 
 impl AlterEnum {
     pub(crate) fn is_empty(&self) -> bool {
-        self.created_variants.is_empty() && self.dropped_variants.is_empty()
+        self.created_variants.is_empty() && self.dropped_variants.is_empty() && self.renamed_variants.is_empty()
     }
 }
 
+/// Rename an enum whose variants did not change. Only generated on databases with named enum types.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RenameEnum {
+    pub name: String,
+    pub new_name: String,
+}
+
+/// Distribute a table over the worker nodes of a Citus cluster.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DistributeTable {
+    pub table: String,
+    pub distribution_column: String,
+}
+
+/// Turn a distributed Citus table back into a regular table on the coordinator.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UndistributeTable {
+    pub table: String,
+}
+
+/// Turn an existing table into a temporal table on SQL Server: add the period columns, and keep the
+/// previous versions of its rows in the history table.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AddSystemVersioning {
+    pub table: String,
+    pub history_table: String,
+    pub period_start_column: String,
+    pub period_end_column: String,
+}
+
+/// Turn a temporal table back into a regular table, dropping its period columns and history table.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DropSystemVersioning {
+    pub table: String,
+    pub history_table: String,
+    pub period_start_column: String,
+    pub period_end_column: String,
+}
+
+/// Create a sequence used by `sequence()` defaults.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateSequence {
+    pub name: String,
+}
+
+/// Add a CHECK constraint to an existing table.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AddCheckConstraint {
+    pub table: String,
+    pub check_constraint: CheckConstraint,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DropCheckConstraint {
+    pub table: String,
+    pub name: String,
+}
+
+/// Set the value the auto-incrementing column of a table takes next. Only rendered in
+/// migrations that restore the counters of a live database.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SetAutoIncrementValue {
+    pub table: String,
+    pub column: String,
+    pub next_value: u64,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct RedefineTable {
     pub name: String,
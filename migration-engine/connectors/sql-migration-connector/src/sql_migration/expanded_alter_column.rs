@@ -1,5 +1,5 @@
 use crate::sql_schema_differ::{ColumnChange, ColumnChanges, ColumnDiffer};
-use sql_schema_describer::{ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue};
+use sql_schema_describer::{ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue, IdentityGeneration};
 
 pub(crate) fn expand_mysql_alter_column(columns: &ColumnDiffer<'_>) -> MysqlAlterColumn {
     let column_changes = columns.all_changes();
@@ -35,6 +35,18 @@ pub(crate) fn expand_postgres_alter_column(columns: &ColumnDiffer<'_>) -> Option
     for change in columns.all_changes().iter() {
         match change {
             ColumnChange::Default => match (&columns.previous.default(), &columns.next.default()) {
+                // A column can not have both a default and an identity, the previous one goes first.
+                (Some(DefaultValue::IDENTITY(_)), Some(next_default)) => {
+                    changes.push(PostgresAlterColumn::DropIdentity);
+                    changes.push(PostgresAlterColumn::SetDefault((**next_default).clone()))
+                }
+                (Some(_), Some(DefaultValue::IDENTITY(generation))) => {
+                    changes.push(PostgresAlterColumn::DropDefault);
+                    changes.push(PostgresAlterColumn::AddIdentity(*generation))
+                }
+                (None, Some(DefaultValue::IDENTITY(generation))) => {
+                    changes.push(PostgresAlterColumn::AddIdentity(*generation))
+                }
                 (_, Some(next_default)) => changes.push(PostgresAlterColumn::SetDefault((**next_default).clone())),
                 (_, None) => changes.push(PostgresAlterColumn::DropDefault),
             },
@@ -60,18 +72,24 @@ pub(crate) fn expand_postgres_alter_column(columns: &ColumnDiffer<'_>) -> Option
                 &columns.previous.column_type_family(),
                 &columns.next.column_type_family(),
             ) {
-                // Ints can be cast to text.
-                (ColumnTypeFamily::Int, ColumnTypeFamily::String) => {
+                // Ints can be cast to text, and widened to bigints.
+                (ColumnTypeFamily::Int, ColumnTypeFamily::String)
+                | (ColumnTypeFamily::BigInt, ColumnTypeFamily::String)
+                | (ColumnTypeFamily::Int, ColumnTypeFamily::BigInt) => {
                     changes.push(PostgresAlterColumn::SetType(columns.next.column_type().clone()))
                 }
                 _ => return None,
             },
             ColumnChange::Sequence => {
                 if columns.previous.is_autoincrement() {
-                    // The sequence should be dropped.
-                    changes.push(PostgresAlterColumn::DropDefault)
-                } else {
-                    // The sequence should be created.
+                    // The sequence should be dropped, unless the default changes to another sequence.
+                    match (columns.previous.default(), columns.next.default()) {
+                        (Some(DefaultValue::IDENTITY(_)), None) => changes.push(PostgresAlterColumn::DropIdentity),
+                        (_, None) => changes.push(PostgresAlterColumn::DropDefault),
+                        (_, Some(_)) => (),
+                    }
+                } else if !matches!(columns.next.default(), Some(DefaultValue::IDENTITY(_))) {
+                    // The sequence should be created. Identities are added with the default change.
                     changes.push(PostgresAlterColumn::AddSequence)
                 }
             }
@@ -92,6 +110,10 @@ pub(crate) enum PostgresAlterColumn {
     SetNotNull,
     /// Add an auto-incrementing sequence as a default on the column.
     AddSequence,
+    /// Make the column an identity column.
+    AddIdentity(IdentityGeneration),
+    /// Make the column a regular column again, dropping the sequence of the identity.
+    DropIdentity,
 }
 
 /// https://dev.mysql.com/doc/refman/8.0/en/alter-table.html
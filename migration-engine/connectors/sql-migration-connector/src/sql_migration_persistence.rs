@@ -3,17 +3,30 @@ use barrel::types;
 use chrono::*;
 use migration_connector::*;
 use quaint::ast::*;
-use quaint::{connector::ResultSet, prelude::SqlFamily};
+use quaint::{
+    connector::ResultSet,
+    prelude::{ConnectionInfo, SqlFamily},
+};
 use std::convert::TryFrom;
 
 pub struct SqlMigrationPersistence<'a> {
     pub connector: &'a crate::SqlMigrationConnector,
 }
 
+/// The migrations table lives in the migrations history database, which is the migrated database
+/// unless configured otherwise.
 impl Component for SqlMigrationPersistence<'_> {
     fn connector(&self) -> &crate::SqlMigrationConnector {
         self.connector
     }
+
+    fn connection_info(&self) -> &ConnectionInfo {
+        self.conn().connection_info()
+    }
+
+    fn conn(&self) -> &Connection {
+        self.connector.migrations_history.conn()
+    }
 }
 
 #[async_trait::async_trait]
@@ -4,12 +4,14 @@ mod mysql_renderer;
 mod postgres_renderer;
 mod sqlite_renderer;
 
-pub(crate) use common::{IteratorJoin, Quoted, QuotedWithSchema};
+pub(crate) use common::{if_exists, if_not_exists, IteratorJoin, Quoted, QuotedWithSchema};
 
 use crate::{
     database_info::DatabaseInfo,
     sql_migration::{
-        AddForeignKey, AlterEnum, AlterIndex, AlterTable, CreateEnum, CreateIndex, DropEnum, DropForeignKey, DropIndex,
+        AddCheckConstraint, AddForeignKey, AddSystemVersioning, AlterEnum, AlterIndex, AlterTable, CreateEnum,
+        CreateIndex, CreateSequence, DistributeTable, DropCheckConstraint, DropEnum, DropForeignKey, DropIndex,
+        DropSystemVersioning, RenameEnum, SetAutoIncrementValue, UndistributeTable,
     },
     sql_schema_differ::SqlSchemaDiffer,
 };
@@ -17,36 +19,26 @@ use sql_schema_describer::walkers::{ColumnWalker, TableWalker};
 use sql_schema_describer::*;
 use std::{borrow::Cow, fmt::Write as _};
 
+/// The `guarded` flag of the `render_*` functions asks for statements guarded with existence checks (`IF NOT EXISTS`,
+/// `IF EXISTS`...), for idempotent scripts that can run again after they were partially applied. Statements the
+/// database has no guard for are rendered as is.
 pub(crate) trait SqlRenderer {
     fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str>;
 
     fn quote_with_schema<'a, 'b>(&'a self, name: &'b str) -> QuotedWithSchema<'a, &'b str>;
 
-    fn render_add_foreign_key(&self, add_foreign_key: &AddForeignKey) -> String {
-        let AddForeignKey { foreign_key, table } = add_foreign_key;
-        let mut add_constraint = String::with_capacity(120);
-
-        write!(
-            add_constraint,
-            "ALTER TABLE {table} ADD ",
-            table = self.quote_with_schema(table)
-        )
-        .unwrap();
-
-        if let Some(constraint_name) = foreign_key.constraint_name.as_ref() {
-            write!(add_constraint, "CONSTRAINT {} ", self.quote(constraint_name)).unwrap();
-        }
-
-        write!(
-            add_constraint,
-            "FOREIGN KEY ({})",
-            foreign_key.columns.iter().map(|col| self.quote(col)).join(", ")
-        )
-        .unwrap();
+    fn render_add_foreign_key(&self, add_foreign_key: &AddForeignKey, _guarded: bool) -> String {
+        add_foreign_key_statement(self, add_foreign_key)
+    }
 
-        add_constraint.push_str(&self.render_references(&table, &foreign_key));
+    /// Render an `AddCheckConstraint` step.
+    fn render_add_check_constraint(&self, add_check_constraint: &AddCheckConstraint, _guarded: bool) -> Vec<String> {
+        vec![add_check_constraint_statement(self, add_check_constraint)]
+    }
 
-        add_constraint
+    /// Render an `AddSystemVersioning` step. Only SQL Server has temporal tables, so the other flavours never get these.
+    fn render_add_system_versioning(&self, _add_system_versioning: &AddSystemVersioning) -> Vec<String> {
+        Vec::new()
     }
 
     fn render_alter_enum(&self, alter_enum: &AlterEnum, differ: &SqlSchemaDiffer<'_>) -> anyhow::Result<Vec<String>>;
@@ -65,33 +57,132 @@ pub(crate) trait SqlRenderer {
         current_schema: &SqlSchema,
     ) -> anyhow::Result<Vec<String>>;
 
-    fn render_alter_table(&self, alter_table: &AlterTable, differ: &SqlSchemaDiffer<'_>) -> Vec<String>;
+    fn render_alter_table(&self, alter_table: &AlterTable, differ: &SqlSchemaDiffer<'_>, guarded: bool) -> Vec<String>;
 
     /// Render a `CreateEnum` step.
-    fn render_create_enum(&self, create_enum: &CreateEnum) -> Vec<String>;
+    fn render_create_enum(&self, create_enum: &CreateEnum, guarded: bool) -> Vec<String>;
 
     /// Render a `CreateIndex` step.
-    fn render_create_index(&self, create_index: &CreateIndex) -> String;
+    fn render_create_index(&self, create_index: &CreateIndex, guarded: bool) -> String;
+
+    /// Render a `CreateSequence` step. Only Postgres has `sequence()` defaults, so the other flavours never get these.
+    fn render_create_sequence(&self, _create_sequence: &CreateSequence, _guarded: bool) -> Vec<String> {
+        Vec::new()
+    }
 
     /// Render a `CreateTable` step.
-    fn render_create_table(&self, table: &TableWalker<'_>) -> anyhow::Result<String>;
+    fn render_create_table(&self, table: &TableWalker<'_>, guarded: bool) -> anyhow::Result<String>;
+
+    /// Render a `DistributeTable` step. Only Citus has distributed tables, so the other flavours never get these.
+    fn render_distribute_table(&self, _distribute_table: &DistributeTable) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Render a `DropCheckConstraint` step.
+    fn render_drop_check_constraint(
+        &self,
+        drop_check_constraint: &DropCheckConstraint,
+        _database_info: &DatabaseInfo,
+        guarded: bool,
+    ) -> Vec<String> {
+        vec![format!(
+            "ALTER TABLE {table} DROP CONSTRAINT {if_exists}{name}",
+            table = self.quote_with_schema(&drop_check_constraint.table),
+            if_exists = if_exists(guarded),
+            name = self.quote(&drop_check_constraint.name),
+        )]
+    }
 
     /// Render a `DropEnum` step.
-    fn render_drop_enum(&self, drop_enum: &DropEnum) -> Vec<String>;
+    fn render_drop_enum(&self, drop_enum: &DropEnum, guarded: bool) -> Vec<String>;
 
     /// Render a `DropForeignKey` step.
-    fn render_drop_foreign_key(&self, drop_foreign_key: &DropForeignKey) -> String;
+    fn render_drop_foreign_key(&self, drop_foreign_key: &DropForeignKey, guarded: bool) -> String;
 
     /// Render a `DropIndex` step.
-    fn render_drop_index(&self, drop_index: &DropIndex) -> String;
+    fn render_drop_index(&self, drop_index: &DropIndex, guarded: bool) -> String;
+
+    /// Render a `DropSystemVersioning` step. Only SQL Server has temporal tables, so the other flavours never get these.
+    fn render_drop_system_versioning(&self, _drop_system_versioning: &DropSystemVersioning) -> Vec<String> {
+        Vec::new()
+    }
 
     /// Render a `DropTable` step.
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
-        vec![format!("DROP TABLE {}", self.quote_with_schema(&table_name))]
+    fn render_drop_table(&self, table_name: &str, guarded: bool) -> Vec<String> {
+        vec![format!(
+            "DROP TABLE {}{}",
+            if_exists(guarded),
+            self.quote_with_schema(&table_name)
+        )]
     }
 
     /// Render a `RedefineTables` step.
     fn render_redefine_tables(&self, tables: &[String], differ: SqlSchemaDiffer<'_>) -> Vec<String>;
 
+    /// Render a `RenameEnum` step. Only databases with named enum types get these.
+    fn render_rename_enum(&self, _rename_enum: &RenameEnum) -> Vec<String> {
+        Vec::new()
+    }
+
     fn render_rename_table(&self, name: &str, new_name: &str) -> String;
+
+    /// Render a `SetAutoIncrementValue` step. Only MySQL and Postgres counters are described, so the other
+    /// flavours never get these.
+    fn render_set_auto_increment_value(&self, _set_auto_increment_value: &SetAutoIncrementValue) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Render an `UndistributeTable` step.
+    fn render_undistribute_table(&self, _undistribute_table: &UndistributeTable) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// The `ALTER TABLE ... ADD ... FOREIGN KEY` statement of an `AddForeignKey` step, without guard.
+pub(crate) fn add_foreign_key_statement<R: SqlRenderer + ?Sized>(
+    renderer: &R,
+    add_foreign_key: &AddForeignKey,
+) -> String {
+    let AddForeignKey { foreign_key, table } = add_foreign_key;
+    let mut add_constraint = String::with_capacity(120);
+
+    write!(
+        add_constraint,
+        "ALTER TABLE {table} ADD ",
+        table = renderer.quote_with_schema(table)
+    )
+    .unwrap();
+
+    if let Some(constraint_name) = foreign_key.constraint_name.as_ref() {
+        write!(add_constraint, "CONSTRAINT {} ", renderer.quote(constraint_name)).unwrap();
+    }
+
+    write!(
+        add_constraint,
+        "FOREIGN KEY ({})",
+        foreign_key.columns.iter().map(|col| renderer.quote(col)).join(", ")
+    )
+    .unwrap();
+
+    add_constraint.push_str(&renderer.render_references(&table, &foreign_key));
+
+    add_constraint
+}
+
+/// The `ALTER TABLE ... ADD CONSTRAINT ... CHECK` statement of an `AddCheckConstraint` step, without guard.
+pub(crate) fn add_check_constraint_statement<R: SqlRenderer + ?Sized>(
+    renderer: &R,
+    add_check_constraint: &AddCheckConstraint,
+) -> String {
+    let AddCheckConstraint {
+        table,
+        check_constraint,
+    } = add_check_constraint;
+
+    format!(
+        "ALTER TABLE {table} ADD CONSTRAINT {name} CHECK ({definition})",
+        table = renderer.quote_with_schema(table),
+        name = renderer.quote(&check_constraint.name),
+        definition = check_constraint.definition,
+    )
 }
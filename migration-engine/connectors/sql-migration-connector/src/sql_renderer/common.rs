@@ -106,6 +106,24 @@ pub(crate) fn render_on_update(on_update: &ForeignKeyAction) -> &'static str {
     }
 }
 
+/// The `IF EXISTS` of a guarded `DROP`.
+pub(crate) fn if_exists(guarded: bool) -> &'static str {
+    if guarded {
+        "IF EXISTS "
+    } else {
+        ""
+    }
+}
+
+/// The `IF NOT EXISTS` of a guarded `CREATE` or `ADD`.
+pub(crate) fn if_not_exists(guarded: bool) -> &'static str {
+    if guarded {
+        "IF NOT EXISTS "
+    } else {
+        ""
+    }
+}
+
 pub(crate) trait IteratorJoin {
     fn join(self, sep: &str) -> String;
 }
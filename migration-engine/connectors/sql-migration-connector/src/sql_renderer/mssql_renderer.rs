@@ -1,10 +1,14 @@
-use super::{common, IteratorJoin, Quoted, QuotedWithSchema, SqlRenderer};
+use super::{
+    add_check_constraint_statement, add_foreign_key_statement, common, if_exists, IteratorJoin, Quoted,
+    QuotedWithSchema, SqlRenderer,
+};
 use crate::{
     database_info::DatabaseInfo,
     flavour::MssqlFlavour,
     sql_migration::{
-        AddColumn, AddForeignKey, AlterColumn, AlterEnum, AlterIndex, AlterTable, CreateEnum, CreateIndex, DropColumn,
-        DropEnum, DropForeignKey, DropIndex, TableChange,
+        AddCheckConstraint, AddColumn, AddForeignKey, AddSystemVersioning, AlterColumn, AlterEnum, AlterIndex,
+        AlterTable, CreateEnum, CreateIndex, DropColumn, DropEnum, DropForeignKey, DropIndex, DropSystemVersioning,
+        TableChange,
     },
     sql_schema_differ::SqlSchemaDiffer,
 };
@@ -13,7 +17,7 @@ use sql_schema_describer::{
     walkers::{ColumnWalker, TableWalker},
     ColumnTypeFamily, DefaultValue, ForeignKey, IndexType, SqlSchema,
 };
-use std::{borrow::Cow, fmt::Write};
+use std::borrow::Cow;
 
 impl SqlRenderer for MssqlFlavour {
     fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str> {
@@ -27,7 +31,7 @@ impl SqlRenderer for MssqlFlavour {
         }
     }
 
-    fn render_alter_table(&self, alter_table: &AlterTable, differ: &SqlSchemaDiffer<'_>) -> Vec<String> {
+    fn render_alter_table(&self, alter_table: &AlterTable, differ: &SqlSchemaDiffer<'_>, guarded: bool) -> Vec<String> {
         let AlterTable { table, changes } = alter_table;
 
         let mut lines = Vec::new();
@@ -38,9 +42,13 @@ impl SqlRenderer for MssqlFlavour {
                     let constraint = constraint_name.as_ref().unwrap();
                     lines.push(format!("DROP CONSTRAINT {}", self.quote(constraint)));
                 }
-                TableChange::AddPrimaryKey { columns } => {
+                TableChange::AddPrimaryKey { columns, clustered } => {
                     let columns = columns.iter().map(|colname| self.quote(colname)).join(", ");
-                    lines.push(format!("ADD PRIMARY KEY ({})", columns));
+                    lines.push(format!(
+                        "ADD PRIMARY KEY {}({})",
+                        render_clustering(*clustered),
+                        columns
+                    ));
                 }
                 TableChange::AddColumn(AddColumn { column }) => {
                     let column = ColumnWalker {
@@ -53,7 +61,7 @@ impl SqlRenderer for MssqlFlavour {
                 }
                 TableChange::DropColumn(DropColumn { name }) => {
                     let name = self.quote(&name);
-                    lines.push(format!("DROP COLUMN {}", name));
+                    lines.push(format!("DROP COLUMN {}{}", if_exists(guarded), name));
                 }
                 TableChange::AlterColumn(AlterColumn { .. }) => todo!("We must handle altering columns in MSSQL"),
             };
@@ -82,6 +90,8 @@ impl SqlRenderer for MssqlFlavour {
             ColumnTypeFamily::DateTime => "datetime2",
             ColumnTypeFamily::Float => "decimal(32,16)",
             ColumnTypeFamily::Int => "int",
+            ColumnTypeFamily::BigInt => "bigint",
+            ColumnTypeFamily::Binary => "varbinary(max)",
             ColumnTypeFamily::String | ColumnTypeFamily::Json => "nvarchar(1000)",
             x => unimplemented!("{:?} not handled yet", x),
         };
@@ -95,7 +105,7 @@ impl SqlRenderer for MssqlFlavour {
             .unwrap_or_else(String::new);
 
         if column.is_autoincrement() {
-            format!("{} int IDENTITY(1,1)", column_name)
+            format!("{} {} IDENTITY(1,1)", column_name, r#type)
         } else {
             format!("{} {} {} {}", column_name, r#type, nullability, default)
         }
@@ -138,7 +148,10 @@ impl SqlRenderer for MssqlFlavour {
                 Cow::from(if *val { "1" } else { "0" })
             }
             (DefaultValue::VALUE(val), _) => val.to_string().into(),
+            (DefaultValue::NULL, _) => "NULL".into(),
             (DefaultValue::SEQUENCE(_), _) => "".into(),
+            (DefaultValue::NEXTVAL(_), _) => unreachable!("NEXTVAL default on a database without sequences"),
+            (DefaultValue::IDENTITY(_), _) => unreachable!("IDENTITY default outside of PostgreSQL"),
         }
     }
 
@@ -163,16 +176,18 @@ impl SqlRenderer for MssqlFlavour {
         )])
     }
 
-    fn render_create_enum(&self, _: &CreateEnum) -> Vec<String> {
+    fn render_create_enum(&self, _: &CreateEnum, _guarded: bool) -> Vec<String> {
         unreachable!("render_create_enum on Microsoft SQL Server")
     }
 
-    fn render_create_index(&self, create_index: &CreateIndex) -> String {
+    /// Guarded, the index is created behind an `IF` checking the catalog.
+    fn render_create_index(&self, create_index: &CreateIndex, guarded: bool) -> String {
         let CreateIndex {
             table,
             index,
             caused_by_create_table: _,
             contains_nullable_columns,
+            memory_optimized,
         } = create_index;
 
         let index_type = match index.tpe {
@@ -184,8 +199,10 @@ impl SqlRenderer for MssqlFlavour {
         let index_name = self.quote(&index_name);
         let table_reference = self.quote_with_schema(&table).to_string();
 
-        let condition = match index.tpe {
-            IndexType::Unique if *contains_nullable_columns => {
+        let condition = match (index.partial_predicate(), &index.tpe) {
+            (Some(predicate), _) => Cow::from(format!(" WHERE {}", predicate)),
+            // Without a filter, SQL Server already considers the NULLs of a unique index equal.
+            (None, IndexType::Unique) if *contains_nullable_columns && !index.nulls_not_distinct => {
                 let columns = index
                     .columns
                     .iter()
@@ -199,9 +216,40 @@ impl SqlRenderer for MssqlFlavour {
 
         let columns = index.columns.iter().map(|c| self.quote(c));
 
+        let guard = if guarded {
+            format!(
+                "IF NOT EXISTS (SELECT * FROM sys.indexes WHERE name = N'{}' AND object_id = OBJECT_ID(N'{}')) ",
+                escape_string_literal(&index.name.replace('.', "_")),
+                escape_string_literal(&table_reference),
+            )
+        } else {
+            String::new()
+        };
+
+        if *memory_optimized {
+            return match index.tpe {
+                IndexType::Unique => format!(
+                    "{guard}ALTER TABLE {table_reference} ADD CONSTRAINT {index_name} UNIQUE NONCLUSTERED ({columns})",
+                    guard = guard,
+                    table_reference = table_reference,
+                    index_name = index_name,
+                    columns = columns.join(", "),
+                ),
+                IndexType::Normal => format!(
+                    "{guard}ALTER TABLE {table_reference} ADD INDEX {index_name} NONCLUSTERED ({columns})",
+                    guard = guard,
+                    table_reference = table_reference,
+                    index_name = index_name,
+                    columns = columns.join(", "),
+                ),
+            };
+        }
+
         format!(
-            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns}){condition}",
+            "{guard}CREATE {index_type}{clustering}INDEX {index_name} ON {table_reference}({columns}){condition}",
+            guard = guard,
             index_type = index_type,
+            clustering = render_clustering(index.clustered),
             index_name = index_name,
             table_reference = table_reference,
             columns = columns.join(", "),
@@ -209,7 +257,8 @@ impl SqlRenderer for MssqlFlavour {
         )
     }
 
-    fn render_create_table(&self, table: &TableWalker<'_>) -> anyhow::Result<String> {
+    /// Guarded, the table is created behind an `IF` checking the catalog.
+    fn render_create_table(&self, table: &TableWalker<'_>, guarded: bool) -> anyhow::Result<String> {
         let columns: String = table.columns().map(|column| self.render_column(column)).join(",\n");
 
         let primary_columns = table.table.primary_key_columns();
@@ -218,7 +267,12 @@ impl SqlRenderer for MssqlFlavour {
             let index_name = format!("PK_{}_{}", table.table.name, primary_columns.iter().join("_"));
             let column_names = primary_columns.iter().map(|col| self.quote(&col)).join(",");
 
-            format!(",\nCONSTRAINT {} PRIMARY KEY ({})", index_name, column_names)
+            format!(
+                ",\nCONSTRAINT {} PRIMARY KEY {}({})",
+                index_name,
+                render_clustering(table.table.primary_key.as_ref().and_then(|pk| pk.clustered)),
+                column_names
+            )
         } else {
             String::new()
         };
@@ -227,7 +281,7 @@ impl SqlRenderer for MssqlFlavour {
         // null.
         let constraints = table
             .indexes()
-            .filter(|index| index.index.is_unique() && !index.has_nullable_columns())
+            .filter(|index| index.index.is_unique() && !index.index.is_partial() && !index.has_nullable_columns())
             .collect::<Vec<_>>();
 
         let constraints = if !constraints.is_empty() {
@@ -237,7 +291,12 @@ impl SqlRenderer for MssqlFlavour {
                     let name = index.index.name.replace('.', "_");
                     let columns = index.index.columns.iter().map(|col| self.quote(&col));
 
-                    format!("CONSTRAINT {} UNIQUE ({})", name, columns.join(","))
+                    format!(
+                        "CONSTRAINT {} UNIQUE {}({})",
+                        name,
+                        render_clustering(index.index.clustered),
+                        columns.join(",")
+                    )
                 })
                 .join(",\n");
 
@@ -246,30 +305,50 @@ impl SqlRenderer for MssqlFlavour {
             String::new()
         };
 
+        let table_name = self.quote_with_schema(table.name()).to_string();
+
+        let guard = if guarded {
+            render_object_guard(&table_name, "U")
+        } else {
+            String::new()
+        };
+
         Ok(format!(
-            "CREATE TABLE {} ({columns}{primary_key}{constraints})",
-            table_name = self.quote_with_schema(table.name()),
+            "{guard}CREATE TABLE {table_name} ({columns}{primary_key}{constraints})",
+            guard = guard,
+            table_name = table_name,
             columns = columns,
             primary_key = primary_key,
             constraints = constraints,
         ))
     }
 
-    fn render_drop_enum(&self, _drop_enum: &DropEnum) -> Vec<String> {
+    fn render_drop_enum(&self, _drop_enum: &DropEnum, _guarded: bool) -> Vec<String> {
         unreachable!("render_drop_enum on MSSQL")
     }
 
-    fn render_drop_foreign_key(&self, drop_foreign_key: &DropForeignKey) -> String {
+    fn render_drop_foreign_key(&self, drop_foreign_key: &DropForeignKey, guarded: bool) -> String {
         format!(
-            "ALTER TABLE {table} DROP CONSTRAINT {constraint_name}",
+            "ALTER TABLE {table} DROP CONSTRAINT {if_exists}{constraint_name}",
             table = self.quote_with_schema(&drop_foreign_key.table),
+            if_exists = if_exists(guarded),
             constraint_name = Quoted::mssql_ident(&drop_foreign_key.constraint_name),
         )
     }
 
-    fn render_drop_index(&self, drop_index: &DropIndex) -> String {
+    /// There is no `IF EXISTS` for the indexes of memory-optimized tables, they are dropped without guard.
+    fn render_drop_index(&self, drop_index: &DropIndex, guarded: bool) -> String {
+        if drop_index.memory_optimized {
+            return format!(
+                "ALTER TABLE {} DROP INDEX {}",
+                self.quote_with_schema(&drop_index.table),
+                self.quote(&drop_index.name)
+            );
+        }
+
         format!(
-            "DROP INDEX {} ON {}",
+            "DROP INDEX {}{} ON {}",
+            if_exists(guarded),
             self.quote_with_schema(&drop_index.name),
             self.quote_with_schema(&drop_index.table)
         )
@@ -289,38 +368,127 @@ impl SqlRenderer for MssqlFlavour {
         )
     }
 
-    fn render_add_foreign_key(&self, add_foreign_key: &AddForeignKey) -> String {
-        let AddForeignKey { foreign_key, table } = add_foreign_key;
-        let mut add_constraint = String::with_capacity(120);
+    /// Guarded, named foreign keys are added behind an `IF` checking the catalog.
+    fn render_add_foreign_key(&self, add_foreign_key: &AddForeignKey, guarded: bool) -> String {
+        let statement = add_foreign_key_statement(self, add_foreign_key);
 
-        write!(
-            add_constraint,
-            "ALTER TABLE {table} ADD ",
-            table = self.quote_with_schema(table)
-        )
-        .unwrap();
+        match add_foreign_key.foreign_key.constraint_name.as_ref() {
+            Some(constraint_name) if guarded => {
+                let constraint_name = self.quote_with_schema(constraint_name).to_string();
 
-        if let Some(constraint_name) = foreign_key.constraint_name.as_ref() {
-            write!(add_constraint, "CONSTRAINT {} ", self.quote(constraint_name)).unwrap();
+                format!("{}{}", render_object_guard(&constraint_name, "F"), statement)
+            }
+            _ => statement,
         }
+    }
 
-        write!(
-            add_constraint,
-            "FOREIGN KEY ({})",
-            foreign_key.columns.iter().map(|col| self.quote(col)).join(", ")
-        )
-        .unwrap();
+    /// Guarded, the constraint is added behind an `IF` checking the catalog.
+    fn render_add_check_constraint(&self, add_check_constraint: &AddCheckConstraint, guarded: bool) -> Vec<String> {
+        let statement = add_check_constraint_statement(self, add_check_constraint);
+
+        if guarded {
+            let constraint_name = self
+                .quote_with_schema(&add_check_constraint.check_constraint.name)
+                .to_string();
 
-        add_constraint.push_str(&self.render_references(&table, &foreign_key));
+            vec![format!("{}{}", render_object_guard(&constraint_name, "C"), statement)]
+        } else {
+            vec![statement]
+        }
+    }
+
+    fn render_drop_table(&self, table_name: &str, guarded: bool) -> Vec<String> {
+        vec![format!(
+            "DROP TABLE {}{}",
+            if_exists(guarded),
+            self.quote_with_schema(&table_name)
+        )]
+    }
 
-        add_constraint
+    fn render_add_system_versioning(&self, add_system_versioning: &AddSystemVersioning) -> Vec<String> {
+        let AddSystemVersioning {
+            table,
+            history_table,
+            period_start_column,
+            period_end_column,
+        } = add_system_versioning;
+
+        let table_reference = self.quote_with_schema(table);
+        let period_start = self.quote(period_start_column);
+        let period_end = self.quote(period_end_column);
+
+        // The defaults fill in the period of the rows already in the table.
+        vec![
+            format!(
+                "ALTER TABLE {table} ADD \
+                 {period_start} DATETIME2 GENERATED ALWAYS AS ROW START HIDDEN NOT NULL CONSTRAINT {period_start_default} DEFAULT SYSUTCDATETIME(), \
+                 {period_end} DATETIME2 GENERATED ALWAYS AS ROW END HIDDEN NOT NULL CONSTRAINT {period_end_default} DEFAULT CONVERT(DATETIME2, '9999-12-31 23:59:59.9999999'), \
+                 PERIOD FOR SYSTEM_TIME ({period_start}, {period_end})",
+                table = table_reference,
+                period_start = period_start,
+                period_start_default = self.quote(&period_default_name(table, period_start_column)),
+                period_end = period_end,
+                period_end_default = self.quote(&period_default_name(table, period_end_column)),
+            ),
+            format!(
+                "ALTER TABLE {} SET (SYSTEM_VERSIONING = ON (HISTORY_TABLE = {}))",
+                table_reference,
+                self.quote_with_schema(history_table),
+            ),
+        ]
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
-        vec![format!("DROP TABLE {}", self.quote_with_schema(&table_name))]
+    fn render_drop_system_versioning(&self, drop_system_versioning: &DropSystemVersioning) -> Vec<String> {
+        let DropSystemVersioning {
+            table,
+            history_table,
+            period_start_column,
+            period_end_column,
+        } = drop_system_versioning;
+
+        let table_reference = self.quote_with_schema(table);
+
+        vec![
+            format!("ALTER TABLE {} SET (SYSTEM_VERSIONING = OFF)", table_reference),
+            format!("ALTER TABLE {} DROP PERIOD FOR SYSTEM_TIME", table_reference),
+            format!(
+                "ALTER TABLE {table} DROP CONSTRAINT {period_start_default}, {period_end_default}, COLUMN {period_start}, {period_end}",
+                table = table_reference,
+                period_start_default = self.quote(&period_default_name(table, period_start_column)),
+                period_end_default = self.quote(&period_default_name(table, period_end_column)),
+                period_start = self.quote(period_start_column),
+                period_end = self.quote(period_end_column),
+            ),
+            format!("DROP TABLE {}", self.quote_with_schema(history_table)),
+        ]
     }
 }
 
+/// The name of the default constraint on a period column, so it can be dropped with the column.
+fn period_default_name(table: &str, column: &str) -> String {
+    format!("DF__{}__{}", table, column)
+}
+
 fn escape_string_literal(s: &str) -> String {
     s.replace('\'', "''")
 }
+
+/// The `IF` guarding the creation of an object of the given type (`U` for tables, `F` for foreign keys, `C` for check
+/// constraints), followed by a space.
+fn render_object_guard(object_name: &str, object_type: &str) -> String {
+    format!(
+        "IF OBJECT_ID(N'{}', N'{}') IS NULL ",
+        escape_string_literal(object_name),
+        object_type
+    )
+}
+
+/// The clustering keyword of an index or primary key, followed by a space. SQL Server decides when
+/// it is not set.
+fn render_clustering(clustered: Option<bool>) -> &'static str {
+    match clustered {
+        Some(true) => "CLUSTERED ",
+        Some(false) => "NONCLUSTERED ",
+        None => "",
+    }
+}
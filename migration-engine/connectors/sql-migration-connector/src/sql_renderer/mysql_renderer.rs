@@ -11,14 +11,18 @@ use crate::{
     sql_migration::TableChange,
     sql_migration::{
         expanded_alter_column::{expand_mysql_alter_column, MysqlAlterColumn},
-        AlterEnum, AlterIndex, CreateEnum, CreateIndex, DropEnum, DropForeignKey, DropIndex,
+        AlterEnum, AlterIndex, CreateEnum, CreateIndex, DropCheckConstraint, DropEnum, DropForeignKey, DropIndex,
+        SetAutoIncrementValue,
     },
     sql_schema_differ::{ColumnChanges, SqlSchemaDiffer},
 };
 use once_cell::sync::Lazy;
 use prisma_value::PrismaValue;
 use regex::Regex;
-use sql_schema_describer::{walkers::ColumnWalker, *};
+use sql_schema_describer::{
+    walkers::{walk_columns, ColumnWalker},
+    *,
+};
 use std::borrow::Cow;
 use walkers::TableWalker;
 
@@ -33,7 +37,7 @@ impl SqlRenderer for MysqlFlavour {
         unreachable!("quote_with_schema on MySQL")
     }
 
-    fn render_add_foreign_key(&self, add_foreign_key: &AddForeignKey) -> String {
+    fn render_add_foreign_key(&self, add_foreign_key: &AddForeignKey, _guarded: bool) -> String {
         use std::fmt::Write;
 
         let AddForeignKey { foreign_key, table } = add_foreign_key;
@@ -57,8 +61,49 @@ impl SqlRenderer for MysqlFlavour {
         add_constraint
     }
 
-    fn render_alter_enum(&self, _alter_enum: &AlterEnum, _differ: &SqlSchemaDiffer<'_>) -> anyhow::Result<Vec<String>> {
-        unreachable!("render_alter_enum on MySQL")
+    /// Enums are defined on the column using them on MySQL, and only the renamed variants need an `AlterEnum`.
+    /// The column is widened to accept both the previous and the next variants, the rows are moved to the next
+    /// variants, and the `AlterColumn` step of the same migration narrows the column down to the next enum.
+    fn render_alter_enum(&self, alter_enum: &AlterEnum, differ: &SqlSchemaDiffer<'_>) -> anyhow::Result<Vec<String>> {
+        let column = walk_columns(differ.previous)
+            .find(|column| column.column_type_family().as_enum() == Some(alter_enum.name.as_str()))
+            .ok_or_else(|| anyhow::anyhow!("No column uses the enum `{}` in the current schema.", alter_enum.name))?;
+        let previous_enum = column.column_type_family_as_enum().unwrap();
+
+        let variants = previous_enum
+            .values
+            .iter()
+            .map(String::as_str)
+            .chain(alter_enum.renamed_variants.iter().map(|(_, next)| next.as_str()))
+            .map(render_enum_value)
+            .join(", ");
+
+        let default_str = column
+            .default()
+            .filter(|default| matches!(default, DefaultValue::VALUE(_)))
+            .map(|default| format!(" DEFAULT {}", self.render_default(default, column.column_type_family())))
+            .unwrap_or_else(String::new);
+
+        let mut stmts = vec![format!(
+            "ALTER TABLE {table_name} MODIFY {column_name} ENUM({variants}){nullability}{default}",
+            table_name = self.quote(column.table().name()),
+            column_name = self.quote(column.name()),
+            variants = variants,
+            nullability = render_nullability(&column),
+            default = default_str,
+        )];
+
+        stmts.extend(alter_enum.renamed_variants.iter().map(|(previous, next)| {
+            format!(
+                "UPDATE {table_name} SET {column_name} = {next} WHERE {column_name} = {previous}",
+                table_name = self.quote(column.table().name()),
+                column_name = self.quote(column.name()),
+                next = render_enum_value(next),
+                previous = render_enum_value(previous),
+            )
+        }));
+
+        Ok(stmts)
     }
 
     fn render_alter_index(
@@ -97,12 +142,16 @@ impl SqlRenderer for MysqlFlavour {
 
             // Order matters: dropping the old index first wouldn't work when foreign key constraints are still relying on it.
             Ok(vec![
-                self.render_create_index(&CreateIndex {
-                    table: table.clone(),
-                    index: new_index,
-                    caused_by_create_table: false,
-                    contains_nullable_columns: false,
-                }),
+                self.render_create_index(
+                    &CreateIndex {
+                        table: table.clone(),
+                        index: new_index,
+                        caused_by_create_table: false,
+                        contains_nullable_columns: false,
+                        memory_optimized: false,
+                    },
+                    false,
+                ),
                 mysql_drop_index(self, table, index_name),
             ])
         } else {
@@ -115,7 +164,12 @@ impl SqlRenderer for MysqlFlavour {
         }
     }
 
-    fn render_alter_table(&self, alter_table: &AlterTable, differ: &SqlSchemaDiffer<'_>) -> Vec<String> {
+    fn render_alter_table(
+        &self,
+        alter_table: &AlterTable,
+        differ: &SqlSchemaDiffer<'_>,
+        _guarded: bool,
+    ) -> Vec<String> {
         let AlterTable { table, changes } = alter_table;
 
         let mut lines = Vec::new();
@@ -123,7 +177,7 @@ impl SqlRenderer for MysqlFlavour {
         for change in changes {
             match change {
                 TableChange::DropPrimaryKey { constraint_name: _ } => lines.push("DROP PRIMARY KEY".to_owned()),
-                TableChange::AddPrimaryKey { columns } => lines.push(format!(
+                TableChange::AddPrimaryKey { columns, .. } => lines.push(format!(
                     "ADD PRIMARY KEY ({})",
                     columns.iter().map(|colname| self.quote(colname)).join(", ")
                 )),
@@ -134,7 +188,20 @@ impl SqlRenderer for MysqlFlavour {
                         column,
                     };
                     let col_sql = self.render_column(column);
-                    lines.push(format!("ADD COLUMN {}", col_sql));
+
+                    // MySQL appends new columns to the table by default. Columns added in the
+                    // middle of a model are positioned explicitly, to keep the column order equal
+                    // to the field order.
+                    let position = if column.is_last_column() {
+                        String::new()
+                    } else {
+                        match column.previous_column() {
+                            Some(previous) => format!(" AFTER {}", self.quote(previous.name())),
+                            None => " FIRST".to_owned(),
+                        }
+                    };
+
+                    lines.push(format!("ADD COLUMN {}{}", col_sql, position));
                 }
                 TableChange::DropColumn(DropColumn { name }) => {
                     let name = self.quote(&name);
@@ -209,10 +276,14 @@ impl SqlRenderer for MysqlFlavour {
             .map(|col| self.quote(col))
             .join(",");
 
+        // Tables of other databases on the server are referenced by their qualified name.
+        let (schema_name, table_name) = split_qualified_name(&foreign_key.referenced_table)
+            .unwrap_or((self.schema_name(), foreign_key.referenced_table.as_str()));
+
         format!(
             " REFERENCES `{}`.`{}`({}) {} ON UPDATE CASCADE",
-            self.schema_name(),
-            foreign_key.referenced_table,
+            schema_name,
+            table_name,
             referenced_columns,
             render_on_delete(&foreign_key.on_delete_action)
         )
@@ -221,24 +292,27 @@ impl SqlRenderer for MysqlFlavour {
     fn render_default<'a>(&self, default: &'a DefaultValue, family: &ColumnTypeFamily) -> Cow<'a, str> {
         match (default, family) {
             (DefaultValue::DBGENERATED(val), _) => val.as_str().into(),
-            (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::String)
-            | (DefaultValue::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_)) => {
+            (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::String) => {
                 format!("'{}'", escape_string_literal(&val)).into()
             }
+            (DefaultValue::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_)) => render_enum_value(val).into(),
             (DefaultValue::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP(3)".into(),
             (DefaultValue::NOW, _) => unreachable!("NOW default on non-datetime column"),
             (DefaultValue::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
             (DefaultValue::VALUE(val), _) => format!("{}", val).into(),
+            (DefaultValue::NULL, _) => "NULL".into(),
             (DefaultValue::SEQUENCE(_), _) => "".into(),
+            (DefaultValue::NEXTVAL(_), _) => unreachable!("NEXTVAL default on a database without sequences"),
+            (DefaultValue::IDENTITY(_), _) => unreachable!("IDENTITY default outside of PostgreSQL"),
         }
     }
 
-    fn render_create_enum(&self, _create_enum: &CreateEnum) -> Vec<String> {
+    fn render_create_enum(&self, _create_enum: &CreateEnum, _guarded: bool) -> Vec<String> {
         Vec::new() // enums are defined on each column that uses them on MySQL
     }
 
-    fn render_create_index(&self, create_index: &CreateIndex) -> String {
-        let Index { name, columns, tpe } = &create_index.index;
+    fn render_create_index(&self, create_index: &CreateIndex, _guarded: bool) -> String {
+        let Index { name, columns, tpe, .. } = &create_index.index;
         let name = if name.len() > MYSQL_IDENTIFIER_SIZE_LIMIT {
             &name[0..MYSQL_IDENTIFIER_SIZE_LIMIT]
         } else {
@@ -262,7 +336,7 @@ impl SqlRenderer for MysqlFlavour {
         )
     }
 
-    fn render_create_table(&self, table: &TableWalker<'_>) -> anyhow::Result<String> {
+    fn render_create_table(&self, table: &TableWalker<'_>, guarded: bool) -> anyhow::Result<String> {
         let columns: String = table.columns().map(|column| self.render_column(column)).join(",\n");
 
         let primary_columns = table.table.primary_key_columns();
@@ -302,7 +376,8 @@ impl SqlRenderer for MysqlFlavour {
         };
 
         Ok(format!(
-            "CREATE TABLE {} (\n{columns}{indexes}{primary_key}\n) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci",
+            "CREATE TABLE {if_not_exists}{table_name} (\n{columns}{indexes}{primary_key}\n) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci",
+            if_not_exists = if_not_exists(guarded),
             table_name = self.quote(table.name()),
             columns = columns,
             indexes = indexes,
@@ -310,11 +385,32 @@ impl SqlRenderer for MysqlFlavour {
         ))
     }
 
-    fn render_drop_enum(&self, _drop_enum: &DropEnum) -> Vec<String> {
+    fn render_drop_enum(&self, _drop_enum: &DropEnum, _guarded: bool) -> Vec<String> {
         Vec::new()
     }
 
-    fn render_drop_foreign_key(&self, drop_foreign_key: &DropForeignKey) -> String {
+    fn render_drop_check_constraint(
+        &self,
+        drop_check_constraint: &DropCheckConstraint,
+        database_info: &DatabaseInfo,
+        _guarded: bool,
+    ) -> Vec<String> {
+        // MySQL only knows `DROP CHECK`, MariaDB only `DROP CONSTRAINT`.
+        let keyword = if database_info.is_mariadb() {
+            "CONSTRAINT"
+        } else {
+            "CHECK"
+        };
+
+        vec![format!(
+            "ALTER TABLE {table} DROP {keyword} {name}",
+            table = self.quote(&drop_check_constraint.table),
+            keyword = keyword,
+            name = Quoted::mysql_ident(&drop_check_constraint.name),
+        )]
+    }
+
+    fn render_drop_foreign_key(&self, drop_foreign_key: &DropForeignKey, _guarded: bool) -> String {
         format!(
             "ALTER TABLE {table} DROP FOREIGN KEY {constraint_name}",
             table = self.quote(&drop_foreign_key.table),
@@ -322,12 +418,14 @@ impl SqlRenderer for MysqlFlavour {
         )
     }
 
-    fn render_drop_index(&self, drop_index: &DropIndex) -> String {
+    fn render_drop_index(&self, drop_index: &DropIndex, _guarded: bool) -> String {
         mysql_drop_index(self, &drop_index.table, &drop_index.name)
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
-        vec![format!("DROP TABLE {}", self.quote(&table_name))]
+    /// MySQL only has guards for tables: columns, indexes and foreign keys can't be checked for outside of stored
+    /// procedures.
+    fn render_drop_table(&self, table_name: &str, guarded: bool) -> Vec<String> {
+        vec![format!("DROP TABLE {}{}", if_exists(guarded), self.quote(&table_name))]
     }
 
     fn render_redefine_tables(&self, _names: &[String], _differ: SqlSchemaDiffer<'_>) -> Vec<String> {
@@ -341,6 +439,14 @@ impl SqlRenderer for MysqlFlavour {
             new_name = self.quote(&new_name),
         )
     }
+
+    fn render_set_auto_increment_value(&self, set_auto_increment_value: &SetAutoIncrementValue) -> Vec<String> {
+        vec![format!(
+            "ALTER TABLE {} AUTO_INCREMENT = {}",
+            self.quote(&set_auto_increment_value.table),
+            set_auto_increment_value.next_value,
+        )]
+    }
 }
 
 fn render_mysql_modify(
@@ -394,6 +500,7 @@ pub(crate) fn render_column_type(column: &ColumnWalker<'_>) -> Cow<'static, str>
         ColumnTypeFamily::DateTime => "datetime(3)".into(),
         ColumnTypeFamily::Float => "decimal(65,30)".into(),
         ColumnTypeFamily::Int => "int".into(),
+        ColumnTypeFamily::BigInt => "bigint".into(),
         // we use varchar right now as mediumtext doesn't allow default values
         // a bigger length would not allow to use such a column as primary key
         ColumnTypeFamily::String => format!("varchar{}", VARCHAR_LENGTH_PREFIX).into(),
@@ -403,15 +510,21 @@ pub(crate) fn render_column_type(column: &ColumnWalker<'_>) -> Cow<'static, str>
                 .get_enum(&enum_name)
                 .unwrap_or_else(|| panic!("Could not render the variants of enum `{}`", enum_name));
 
-            let variants: String = r#enum.values.iter().map(Quoted::mysql_string).join(", ");
+            let variants: String = r#enum.values.iter().map(|value| render_enum_value(value)).join(", ");
 
             format!("ENUM({})", variants).into()
         }
         ColumnTypeFamily::Json => "json".into(),
+        ColumnTypeFamily::Binary => "longblob".into(),
         x => unimplemented!("{:?} not handled yet", x),
     }
 }
 
+/// Enum values are string literals in the `ENUM` type of the column, and in its default.
+fn render_enum_value(value: &str) -> String {
+    format!("'{}'", escape_string_literal(value))
+}
+
 fn escape_string_literal(s: &str) -> Cow<'_, str> {
     static STRING_LITERAL_CHARACTER_TO_ESCAPE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"'"#).unwrap());
 
@@ -1,11 +1,12 @@
-use super::{common::*, SqlRenderer};
+use super::{add_check_constraint_statement, add_foreign_key_statement, common::*, SqlRenderer};
 use crate::{
     database_info::DatabaseInfo,
     flavour::PostgresFlavour,
     sql_migration::{
         expanded_alter_column::{expand_postgres_alter_column, PostgresAlterColumn},
-        AddColumn, AlterColumn, AlterEnum, AlterIndex, AlterTable, CreateEnum, CreateIndex, DropColumn, DropEnum,
-        DropForeignKey, DropIndex, TableChange,
+        AddCheckConstraint, AddColumn, AddForeignKey, AlterColumn, AlterEnum, AlterIndex, AlterTable, CreateEnum,
+        CreateIndex, CreateSequence, DistributeTable, DropColumn, DropEnum, DropForeignKey, DropIndex, RenameEnum,
+        SetAutoIncrementValue, TableChange, UndistributeTable,
     },
     sql_schema_differ::{ColumnDiffer, SqlSchemaDiffer},
 };
@@ -28,19 +29,49 @@ impl SqlRenderer for PostgresFlavour {
         }
     }
 
+    fn render_add_foreign_key(&self, add_foreign_key: &AddForeignKey, guarded: bool) -> String {
+        let statement = add_foreign_key_statement(self, add_foreign_key);
+
+        if guarded {
+            ignore_duplicate_object(&statement)
+        } else {
+            statement
+        }
+    }
+
+    fn render_add_check_constraint(&self, add_check_constraint: &AddCheckConstraint, guarded: bool) -> Vec<String> {
+        let statement = add_check_constraint_statement(self, add_check_constraint);
+
+        if guarded {
+            vec![ignore_duplicate_object(&statement)]
+        } else {
+            vec![statement]
+        }
+    }
+
     fn render_alter_enum(&self, alter_enum: &AlterEnum, differ: &SqlSchemaDiffer<'_>) -> anyhow::Result<Vec<String>> {
+        // Renamed values keep the rows using them, and the recreated enum below can be cast to from their new name.
+        let mut stmts: Vec<String> = alter_enum
+            .renamed_variants
+            .iter()
+            .map(|(previous_value, next_value)| {
+                format!(
+                    "ALTER TYPE {enum_name} RENAME VALUE {previous_value} TO {next_value}",
+                    enum_name = self.quote_with_schema(&alter_enum.name),
+                    previous_value = Quoted::postgres_string(previous_value),
+                    next_value = Quoted::postgres_string(next_value),
+                )
+            })
+            .collect();
+
         if alter_enum.dropped_variants.is_empty() {
-            let stmts: Vec<String> = alter_enum
-                .created_variants
-                .iter()
-                .map(|created_value| {
-                    format!(
-                        "ALTER TYPE {enum_name} ADD VALUE {value}",
-                        enum_name = Quoted::postgres_ident(&alter_enum.name),
-                        value = Quoted::postgres_string(created_value)
-                    )
-                })
-                .collect();
+            stmts.extend(alter_enum.created_variants.iter().map(|created_value| {
+                format!(
+                    "ALTER TYPE {enum_name} ADD VALUE {value}",
+                    enum_name = self.quote_with_schema(&alter_enum.name),
+                    value = Quoted::postgres_string(created_value)
+                )
+            }));
 
             return Ok(stmts);
         }
@@ -50,8 +81,6 @@ impl SqlRenderer for PostgresFlavour {
             .get_enum(&alter_enum.name)
             .ok_or_else(|| anyhow::anyhow!("Enum `{}` not found in target schema.", alter_enum.name))?;
 
-        let mut stmts = Vec::with_capacity(10);
-
         let tmp_name = format!("{}_new", &new_enum.name);
         let tmp_old_name = format!("{}_old", &alter_enum.name);
 
@@ -61,24 +90,41 @@ impl SqlRenderer for PostgresFlavour {
         {
             let create_new_enum = format!(
                 "CREATE TYPE {enum_name} AS ENUM ({variants})",
-                enum_name = QuotedWithSchema {
-                    schema_name: self.schema_name(),
-                    name: Quoted::postgres_ident(&tmp_name),
-                },
+                enum_name = self.quote_with_schema(&tmp_name),
                 variants = new_enum.values.iter().map(Quoted::postgres_string).join(", ")
             );
 
             stmts.push(create_new_enum);
         }
 
-        // alter type of the current columns to new, with a cast
-        {
-            let affected_columns = walk_columns(differ.next).filter(|column| match &column.column_type().family {
+        let affected_columns: Vec<_> = walk_columns(differ.next)
+            .filter(|column| match &column.column_type().family {
                 ColumnTypeFamily::Enum(name) if name.as_str() == alter_enum.name.as_str() => true,
                 _ => false,
-            });
+            })
+            .collect();
+
+        // defaults can't be cast to the new enum, they have to be dropped and set again
+        let columns_with_previous_default = affected_columns.iter().filter(|column| {
+            find_column(differ.previous, column.table().name(), column.name())
+                .and_then(|previous_column| previous_column.default())
+                .is_some()
+        });
+
+        for column in columns_with_previous_default {
+            let sql = format!(
+                "ALTER TABLE {schema_name}.{table_name} ALTER COLUMN {column_name} DROP DEFAULT",
+                schema_name = Quoted::postgres_ident(self.schema_name()),
+                table_name = Quoted::postgres_ident(column.table().name()),
+                column_name = Quoted::postgres_ident(column.name()),
+            );
 
-            for column in affected_columns {
+            stmts.push(sql);
+        }
+
+        // alter type of the current columns to new, with a cast
+        {
+            for column in &affected_columns {
                 let sql = format!(
                     "ALTER TABLE {schema_name}.{table_name} \
                             ALTER COLUMN {column_name} TYPE {tmp_name} \
@@ -86,7 +132,7 @@ impl SqlRenderer for PostgresFlavour {
                     schema_name = Quoted::postgres_ident(self.schema_name()),
                     table_name = Quoted::postgres_ident(column.table().name()),
                     column_name = Quoted::postgres_ident(column.name()),
-                    tmp_name = Quoted::postgres_ident(&tmp_name),
+                    tmp_name = self.quote_with_schema(&tmp_name),
                 );
 
                 stmts.push(sql);
@@ -97,7 +143,7 @@ impl SqlRenderer for PostgresFlavour {
         {
             let sql = format!(
                 "ALTER TYPE {enum_name} RENAME TO {tmp_old_name}",
-                enum_name = Quoted::postgres_ident(&alter_enum.name),
+                enum_name = self.quote_with_schema(&alter_enum.name),
                 tmp_old_name = Quoted::postgres_ident(&tmp_old_name)
             );
 
@@ -108,7 +154,7 @@ impl SqlRenderer for PostgresFlavour {
         {
             let sql = format!(
                 "ALTER TYPE {tmp_name} RENAME TO {enum_name}",
-                tmp_name = Quoted::postgres_ident(&tmp_name),
+                tmp_name = self.quote_with_schema(&tmp_name),
                 enum_name = Quoted::postgres_ident(&new_enum.name)
             );
 
@@ -119,12 +165,26 @@ impl SqlRenderer for PostgresFlavour {
         {
             let sql = format!(
                 "DROP TYPE {tmp_old_name}",
-                tmp_old_name = Quoted::postgres_ident(&tmp_old_name),
+                tmp_old_name = self.quote_with_schema(&tmp_old_name),
             );
 
             stmts.push(sql)
         }
 
+        for column in &affected_columns {
+            if let Some(default) = column.default() {
+                let sql = format!(
+                    "ALTER TABLE {schema_name}.{table_name} ALTER COLUMN {column_name} SET DEFAULT {default}",
+                    schema_name = Quoted::postgres_ident(self.schema_name()),
+                    table_name = Quoted::postgres_ident(column.table().name()),
+                    column_name = Quoted::postgres_ident(column.name()),
+                    default = self.render_default(default, column.column_type_family()),
+                );
+
+                stmts.push(sql);
+            }
+        }
+
         stmts.push("Commit".to_string());
 
         Ok(stmts)
@@ -143,7 +203,7 @@ impl SqlRenderer for PostgresFlavour {
         )])
     }
 
-    fn render_alter_table(&self, alter_table: &AlterTable, differ: &SqlSchemaDiffer<'_>) -> Vec<String> {
+    fn render_alter_table(&self, alter_table: &AlterTable, differ: &SqlSchemaDiffer<'_>, guarded: bool) -> Vec<String> {
         let AlterTable { table, changes } = alter_table;
 
         let mut lines = Vec::new();
@@ -153,14 +213,15 @@ impl SqlRenderer for PostgresFlavour {
         for change in changes {
             match change {
                 TableChange::DropPrimaryKey { constraint_name } => lines.push(format!(
-                    "DROP CONSTRAINT {}",
+                    "DROP CONSTRAINT {}{}",
+                    if_exists(guarded),
                     Quoted::postgres_ident(
                         constraint_name
                             .as_ref()
                             .expect("Missing constraint name for DROP CONSTRAINT on Postgres.")
                     )
                 )),
-                TableChange::AddPrimaryKey { columns } => lines.push(format!(
+                TableChange::AddPrimaryKey { columns, .. } => lines.push(format!(
                     "ADD PRIMARY KEY ({})",
                     columns.iter().map(|colname| self.quote(colname)).join(", ")
                 )),
@@ -171,11 +232,11 @@ impl SqlRenderer for PostgresFlavour {
                         column,
                     };
                     let col_sql = self.render_column(column);
-                    lines.push(format!("ADD COLUMN {}", col_sql));
+                    lines.push(format!("ADD COLUMN {}{}", if_not_exists(guarded), col_sql));
                 }
                 TableChange::DropColumn(DropColumn { name }) => {
                     let name = self.quote(&name);
-                    lines.push(format!("DROP COLUMN {}", name));
+                    lines.push(format!("DROP COLUMN {}{}", if_exists(guarded), name));
                 }
                 TableChange::AlterColumn(AlterColumn { name, column: _ }) => {
                     let column = differ
@@ -187,10 +248,10 @@ impl SqlRenderer for PostgresFlavour {
                         .is_none()
                     {
                         let name = self.quote(&name);
-                        lines.push(format!("DROP COLUMN {}", name));
+                        lines.push(format!("DROP COLUMN {}{}", if_exists(guarded), name));
 
                         let col_sql = self.render_column(column.next);
-                        lines.push(format!("ADD COLUMN {}", col_sql));
+                        lines.push(format!("ADD COLUMN {}{}", if_not_exists(guarded), col_sql));
                     }
                 }
             };
@@ -217,16 +278,21 @@ impl SqlRenderer for PostgresFlavour {
 
     fn render_column(&self, column: ColumnWalker<'_>) -> String {
         let column_name = self.quote(column.name());
-        let tpe_str = render_column_type(column.column_type());
+        let tpe_str = render_column_type(column.column_type(), self.schema_name());
         let nullability_str = render_nullability(&column);
         let default_str = column
             .default()
-            .filter(|default| !matches!(default, DefaultValue::DBGENERATED(_)))
+            // `@default(dbgenerated())` has no expression to render, unlike `@default(uuid())`.
+            .filter(|default| !matches!(default, DefaultValue::DBGENERATED(expression) if expression.is_empty()))
             .map(|default| format!("DEFAULT {}", self.render_default(default, column.column_type_family())))
             .unwrap_or_else(String::new);
         let is_serial = column.is_autoincrement();
 
-        if is_serial {
+        if let Some(DefaultValue::IDENTITY(generation)) = column.default() {
+            format!("{} {} {} {}", column_name, tpe_str, nullability_str, generation)
+        } else if is_serial && column.column_type_family() == &ColumnTypeFamily::BigInt {
+            format!("{} BIGSERIAL", column_name)
+        } else if is_serial {
             format!("{} SERIAL", column_name)
         } else {
             format!("{} {} {} {}", column_name, tpe_str, nullability_str, default_str)
@@ -252,33 +318,48 @@ impl SqlRenderer for PostgresFlavour {
         match (default, family) {
             (DefaultValue::DBGENERATED(val), _) => val.as_str().into(),
             (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::String)
-            | (DefaultValue::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_)) => {
+            | (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::Xml)
+            | (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::TextSearch) => {
                 format!("E'{}'", escape_string_literal(&val)).into()
             }
+            (DefaultValue::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(enum_name)) => format!(
+                "E'{}'::{}",
+                escape_string_literal(&val),
+                self.quote_with_schema(enum_name)
+            )
+            .into(),
             (DefaultValue::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
             (DefaultValue::NOW, _) => unreachable!("NOW default on non-datetime column"),
             (DefaultValue::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
             (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::Json) => format!("'{}'", val).into(),
             (DefaultValue::VALUE(val), _) => val.to_string().into(),
+            (DefaultValue::NULL, _) => "NULL".into(),
             (DefaultValue::SEQUENCE(_), _) => "".into(),
+            (DefaultValue::IDENTITY(_), _) => "".into(),
+            (DefaultValue::NEXTVAL(sequence_name), _) => format!(
+                "nextval({})",
+                Quoted::postgres_string(self.quote_with_schema(sequence_name))
+            )
+            .into(),
         }
     }
 
-    fn render_create_enum(&self, create_enum: &CreateEnum) -> Vec<String> {
+    fn render_create_enum(&self, create_enum: &CreateEnum, guarded: bool) -> Vec<String> {
         let sql = format!(
             r#"CREATE TYPE {enum_name} AS ENUM ({variants})"#,
-            enum_name = QuotedWithSchema {
-                schema_name: &self.0.schema(),
-                name: Quoted::postgres_ident(&create_enum.name)
-            },
+            enum_name = self.quote_with_schema(&create_enum.name),
             variants = create_enum.variants.iter().map(Quoted::postgres_string).join(", "),
         );
 
-        vec![sql]
+        if guarded {
+            vec![ignore_duplicate_object(&sql)]
+        } else {
+            vec![sql]
+        }
     }
 
-    fn render_create_index(&self, create_index: &CreateIndex) -> String {
-        let Index { name, columns, tpe } = &create_index.index;
+    fn render_create_index(&self, create_index: &CreateIndex, guarded: bool) -> String {
+        let Index { name, columns, tpe, .. } = &create_index.index;
         let index_type = match tpe {
             IndexType::Unique => "UNIQUE ",
             IndexType::Normal => "",
@@ -286,17 +367,29 @@ impl SqlRenderer for PostgresFlavour {
         let index_name = self.quote(&name).to_string();
         let table_reference = self.quote_with_schema(&create_index.table).to_string();
         let columns = columns.iter().map(|c| self.quote(c));
+        let nulls_not_distinct = if create_index.index.nulls_not_distinct {
+            " NULLS NOT DISTINCT"
+        } else {
+            ""
+        };
+        let predicate = match create_index.index.partial_predicate() {
+            Some(predicate) => format!(" WHERE {}", predicate),
+            None => String::new(),
+        };
 
         format!(
-            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns})",
+            "CREATE {index_type}INDEX {if_not_exists}{index_name} ON {table_reference}({columns}){nulls_not_distinct}{predicate}",
             index_type = index_type,
+            if_not_exists = if_not_exists(guarded),
             index_name = index_name,
             table_reference = table_reference,
-            columns = columns.join(", ")
+            columns = columns.join(", "),
+            nulls_not_distinct = nulls_not_distinct,
+            predicate = predicate,
         )
     }
 
-    fn render_create_table(&self, table: &TableWalker<'_>) -> anyhow::Result<String> {
+    fn render_create_table(&self, table: &TableWalker<'_>, guarded: bool) -> anyhow::Result<String> {
         let columns: String = table.columns().map(|column| self.render_column(column)).join(",\n");
 
         let primary_columns = table.table.primary_key_columns();
@@ -308,38 +401,55 @@ impl SqlRenderer for PostgresFlavour {
         };
 
         Ok(format!(
-            "CREATE TABLE {table_name} (\n{columns}{primary_key}\n)",
+            "CREATE TABLE {if_not_exists}{table_name} (\n{columns}{primary_key}\n)",
+            if_not_exists = if_not_exists(guarded),
             table_name = self.quote_with_schema(table.name()),
             columns = columns,
             primary_key = pk,
         ))
     }
 
-    fn render_drop_enum(&self, drop_enum: &DropEnum) -> Vec<String> {
+    fn render_drop_enum(&self, drop_enum: &DropEnum, guarded: bool) -> Vec<String> {
         let sql = format!(
-            "DROP TYPE {enum_name}",
-            enum_name = Quoted::postgres_ident(&drop_enum.name),
+            "DROP TYPE {if_exists}{enum_name}",
+            if_exists = if_exists(guarded),
+            enum_name = self.quote_with_schema(&drop_enum.name),
         );
 
         vec![sql]
     }
 
-    fn render_drop_foreign_key(&self, drop_foreign_key: &DropForeignKey) -> String {
+    fn render_drop_foreign_key(&self, drop_foreign_key: &DropForeignKey, guarded: bool) -> String {
         format!(
-            "ALTER TABLE {table} DROP CONSTRAINT {constraint_name}",
+            "ALTER TABLE {table} DROP CONSTRAINT {if_exists}{constraint_name}",
             table = self.quote_with_schema(&drop_foreign_key.table),
+            if_exists = if_exists(guarded),
             constraint_name = Quoted::postgres_ident(&drop_foreign_key.constraint_name),
         )
     }
 
-    fn render_drop_index(&self, drop_index: &DropIndex) -> String {
-        format!("DROP INDEX {}", self.quote_with_schema(&drop_index.name))
+    fn render_drop_index(&self, drop_index: &DropIndex, guarded: bool) -> String {
+        format!(
+            "DROP INDEX {}{}",
+            if_exists(guarded),
+            self.quote_with_schema(&drop_index.name)
+        )
     }
 
     fn render_redefine_tables(&self, _names: &[String], _differ: SqlSchemaDiffer<'_>) -> Vec<String> {
         unreachable!("render_redefine_table on Postgres")
     }
 
+    fn render_rename_enum(&self, rename_enum: &RenameEnum) -> Vec<String> {
+        let sql = format!(
+            "ALTER TYPE {enum_name} RENAME TO {new_name}",
+            enum_name = self.quote_with_schema(&rename_enum.name),
+            new_name = Quoted::postgres_ident(&rename_enum.new_name),
+        );
+
+        vec![sql]
+    }
+
     fn render_rename_table(&self, name: &str, new_name: &str) -> String {
         format!(
             "ALTER TABLE {} RENAME TO {}",
@@ -347,14 +457,66 @@ impl SqlRenderer for PostgresFlavour {
             new_name = self.quote_with_schema(&new_name).to_string(),
         )
     }
+
+    fn render_create_sequence(&self, create_sequence: &CreateSequence, guarded: bool) -> Vec<String> {
+        vec![format!(
+            "CREATE SEQUENCE {}{}",
+            if_not_exists(guarded),
+            self.quote_with_schema(&create_sequence.name)
+        )]
+    }
+
+    fn render_distribute_table(&self, distribute_table: &DistributeTable) -> Vec<String> {
+        vec![format!(
+            "SELECT create_distributed_table(E'{}', E'{}')",
+            escape_string_literal(&self.quote_with_schema(&distribute_table.table).to_string()),
+            escape_string_literal(&distribute_table.distribution_column),
+        )]
+    }
+
+    fn render_set_auto_increment_value(&self, set_auto_increment_value: &SetAutoIncrementValue) -> Vec<String> {
+        // The sequence is not called yet, the next call returns the value.
+        vec![format!(
+            "SELECT setval(pg_get_serial_sequence(E'{}', E'{}'), {}, false)",
+            escape_string_literal(&self.quote_with_schema(&set_auto_increment_value.table).to_string()),
+            escape_string_literal(&set_auto_increment_value.column),
+            set_auto_increment_value.next_value,
+        )]
+    }
+
+    fn render_undistribute_table(&self, undistribute_table: &UndistributeTable) -> Vec<String> {
+        vec![format!(
+            "SELECT undistribute_table(E'{}')",
+            escape_string_literal(&self.quote_with_schema(&undistribute_table.table).to_string()),
+        )]
+    }
 }
 
-pub(crate) fn render_column_type(t: &ColumnType) -> String {
+/// There is no `IF NOT EXISTS` for types and constraints: the statement runs in a block ignoring the error of an object
+/// that exists already.
+fn ignore_duplicate_object(statement: &str) -> String {
+    format!(
+        "DO $$ BEGIN\n{};\nEXCEPTION WHEN duplicate_object THEN null;\nEND $$",
+        statement
+    )
+}
+
+/// Enums are user-defined types, they are qualified with the schema they are defined in.
+pub(crate) fn render_column_type(t: &ColumnType, schema_name: &str) -> String {
     let array = match t.arity {
         ColumnArity::List => "[]",
         _ => "",
     };
 
+    if let ColumnTypeFamily::Enum(name) = &t.family {
+        let enum_name = QuotedWithSchema {
+            schema_name,
+            name: Quoted::postgres_ident(name),
+        };
+
+        return format!("{}{}", enum_name, array);
+    }
+
     if !t.full_data_type.is_empty() {
         return format!("{}{}", t.full_data_type, array);
     }
@@ -364,9 +526,11 @@ pub(crate) fn render_column_type(t: &ColumnType) -> String {
         ColumnTypeFamily::DateTime => format!("timestamp(3) {}", array),
         ColumnTypeFamily::Float => format!("Decimal(65,30) {}", array),
         ColumnTypeFamily::Int => format!("integer {}", array),
+        ColumnTypeFamily::BigInt => format!("bigint {}", array),
         ColumnTypeFamily::String => format!("text {}", array),
-        ColumnTypeFamily::Enum(name) => format!("{}{}", Quoted::postgres_ident(name), array),
         ColumnTypeFamily::Json => format!("jsonb {}", array),
+        ColumnTypeFamily::Xml => format!("xml {}", array),
+        ColumnTypeFamily::Binary => format!("bytea {}", array),
         x => unimplemented!("{:?} not handled yet", x),
     }
 }
@@ -384,9 +548,10 @@ fn render_alter_column(
     clauses: &mut Vec<String>,
     after_statements: &mut Vec<String>,
 ) -> Option<()> {
-    // Matches the sequence name from inside an autoincrement default expression.
+    // Matches the sequence name from inside an autoincrement default expression. The sequence is only
+    // qualified with its schema when the schema is not on the search path.
     static SEQUENCE_DEFAULT_RE: Lazy<Regex> =
-        Lazy::new(|| Regex::new(r#"nextval\('"?([^"]+)"?'::regclass\)"#).unwrap());
+        Lazy::new(|| Regex::new(r#"nextval\('(?:(?:"[^"]+"|[^."]+)\.)?"?([^"]+)"?'::regclass\)"#).unwrap());
 
     let steps = expand_postgres_alter_column(differ)?;
     let table_name = Quoted::postgres_ident(differ.previous.table().name());
@@ -410,7 +575,7 @@ fn render_alter_column(
                     let sequence_is_still_used = walk_columns(differ.next.schema()).any(|column| matches!(column.default(), Some(DefaultValue::SEQUENCE(other_sequence)) if other_sequence == sequence_expression) && !column.is_same_column(&differ.next));
 
                     if !sequence_is_still_used {
-                        after_statements.push(format!("DROP SEQUENCE {}", Quoted::postgres_ident(sequence_name)));
+                        after_statements.push(format!("DROP SEQUENCE {}", renderer.quote_with_schema(sequence_name)));
                     }
                 }
             }
@@ -424,7 +589,7 @@ fn render_alter_column(
             PostgresAlterColumn::SetType(ty) => clauses.push(format!(
                 "{} SET DATA TYPE {}",
                 &alter_column_prefix,
-                render_column_type(&ty)
+                render_column_type(&ty, renderer.schema_name())
             )),
             PostgresAlterColumn::AddSequence => {
                 // We imitate the sequence that would be automatically created on a `SERIAL` column.
@@ -438,7 +603,9 @@ fn render_alter_column(
                 )
                 .to_lowercase();
 
-                before_statements.push(format!("CREATE SEQUENCE {}", Quoted::postgres_ident(&sequence_name)));
+                let sequence_name = renderer.quote_with_schema(&sequence_name).to_string();
+
+                before_statements.push(format!("CREATE SEQUENCE {}", sequence_name));
 
                 clauses.push(format!(
                     "{prefix} SET DEFAULT {default}",
@@ -448,12 +615,28 @@ fn render_alter_column(
 
                 after_statements.push(format!(
                     "ALTER SEQUENCE {sequence_name} OWNED BY {schema_name}.{table_name}.{column_name}",
-                    sequence_name = Quoted::postgres_ident(sequence_name),
+                    sequence_name = sequence_name,
                     schema_name = Quoted::postgres_ident(renderer.0.schema()),
                     table_name = table_name,
                     column_name = column_name,
                 ));
             }
+            PostgresAlterColumn::AddIdentity(generation) => {
+                clauses.push(format!("{} ADD {}", &alter_column_prefix, generation));
+
+                // The identity starts after the values already in the column, e.g. the ones of a `SERIAL`
+                // column converted to an identity.
+                let table_reference = renderer.quote_with_schema(differ.next.table().name()).to_string();
+
+                after_statements.push(format!(
+                    "SELECT setval(pg_get_serial_sequence({table}, {column}), COALESCE(MAX({column_name}), 0) + 1, false) FROM {table_reference}",
+                    table = Quoted::postgres_string(&table_reference),
+                    column = Quoted::postgres_string(differ.next.name()),
+                    column_name = column_name,
+                    table_reference = table_reference,
+                ));
+            }
+            PostgresAlterColumn::DropIdentity => clauses.push(format!("{} DROP IDENTITY", &alter_column_prefix)),
         }
     }
 
@@ -3,8 +3,8 @@ use crate::{
     database_info::DatabaseInfo,
     flavour::SqliteFlavour,
     sql_migration::{
-        AddColumn, AddForeignKey, AlterEnum, AlterIndex, AlterTable, CreateEnum, CreateIndex, DropEnum, DropForeignKey,
-        DropIndex, TableChange,
+        AddCheckConstraint, AddColumn, AddForeignKey, AlterEnum, AlterIndex, AlterTable, CreateEnum, CreateIndex,
+        DropCheckConstraint, DropEnum, DropForeignKey, DropIndex, TableChange,
     },
     sql_schema_differ::{ColumnDiffer, SqlSchemaDiffer, TableDiffer},
 };
@@ -39,8 +39,12 @@ impl SqlRenderer for SqliteFlavour {
         unreachable!("render_alter_index on sqlite")
     }
 
-    fn render_create_index(&self, create_index: &CreateIndex) -> String {
-        let Index { name, columns, tpe } = &create_index.index;
+    fn render_add_check_constraint(&self, _add_check_constraint: &AddCheckConstraint, _guarded: bool) -> Vec<String> {
+        unreachable!("render_add_check_constraint on SQLite")
+    }
+
+    fn render_create_index(&self, create_index: &CreateIndex, guarded: bool) -> String {
+        let Index { name, columns, tpe, .. } = &create_index.index;
         let index_type = match tpe {
             IndexType::Unique => "UNIQUE ",
             IndexType::Normal => "",
@@ -48,13 +52,19 @@ impl SqlRenderer for SqliteFlavour {
         let index_name = self.quote_with_schema(&name).to_string();
         let table_reference = self.quote(&create_index.table).to_string();
         let columns = columns.iter().map(|c| self.quote(c));
+        let predicate = match create_index.index.partial_predicate() {
+            Some(predicate) => format!(" WHERE {}", predicate),
+            None => String::new(),
+        };
 
         format!(
-            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns})",
+            "CREATE {index_type}INDEX {if_not_exists}{index_name} ON {table_reference}({columns}){predicate}",
             index_type = index_type,
+            if_not_exists = if_not_exists(guarded),
             index_name = index_name,
             table_reference = table_reference,
-            columns = columns.join(", ")
+            columns = columns.join(", "),
+            predicate = predicate,
         )
     }
 
@@ -110,15 +120,23 @@ impl SqlRenderer for SqliteFlavour {
             (DefaultValue::NOW, _) => unreachable!("NOW default on non-datetime column"),
             (DefaultValue::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
             (DefaultValue::VALUE(val), _) => format!("{}", val).into(),
+            (DefaultValue::NULL, _) => "NULL".into(),
             (DefaultValue::SEQUENCE(_), _) => "".into(),
+            (DefaultValue::NEXTVAL(_), _) => unreachable!("NEXTVAL default on a database without sequences"),
+            (DefaultValue::IDENTITY(_), _) => unreachable!("IDENTITY default outside of PostgreSQL"),
         }
     }
 
-    fn render_add_foreign_key(&self, _add_foreign_key: &AddForeignKey) -> String {
+    fn render_add_foreign_key(&self, _add_foreign_key: &AddForeignKey, _guarded: bool) -> String {
         unreachable!("AddForeignKey on SQLite")
     }
 
-    fn render_alter_table(&self, alter_table: &AlterTable, differ: &SqlSchemaDiffer<'_>) -> Vec<String> {
+    fn render_alter_table(
+        &self,
+        alter_table: &AlterTable,
+        differ: &SqlSchemaDiffer<'_>,
+        _guarded: bool,
+    ) -> Vec<String> {
         let AlterTable { table, changes } = alter_table;
 
         let mut statements = Vec::new();
@@ -153,11 +171,11 @@ impl SqlRenderer for SqliteFlavour {
         statements
     }
 
-    fn render_create_enum(&self, _create_enum: &CreateEnum) -> Vec<String> {
+    fn render_create_enum(&self, _create_enum: &CreateEnum, _guarded: bool) -> Vec<String> {
         Vec::new()
     }
 
-    fn render_create_table(&self, table: &TableWalker<'_>) -> anyhow::Result<String> {
+    fn render_create_table(&self, table: &TableWalker<'_>, guarded: bool) -> anyhow::Result<String> {
         use std::fmt::Write;
 
         let columns: String = table.columns().map(|column| self.render_column(column)).join(",\n");
@@ -193,7 +211,8 @@ impl SqlRenderer for SqliteFlavour {
         };
 
         Ok(format!(
-            "CREATE TABLE {table_name} (\n{columns}{foreign_keys}{primary_key}\n)",
+            "CREATE TABLE {if_not_exists}{table_name} (\n{columns}{foreign_keys}{primary_key}\n)",
+            if_not_exists = if_not_exists(guarded),
             table_name = self.quote_with_schema(table.name()),
             columns = columns,
             foreign_keys = foreign_keys,
@@ -201,26 +220,43 @@ impl SqlRenderer for SqliteFlavour {
         ))
     }
 
-    fn render_drop_enum(&self, _drop_enum: &DropEnum) -> Vec<String> {
+    fn render_drop_enum(&self, _drop_enum: &DropEnum, _guarded: bool) -> Vec<String> {
         Vec::new()
     }
 
-    fn render_drop_foreign_key(&self, _drop_foreign_key: &DropForeignKey) -> String {
+    fn render_drop_check_constraint(
+        &self,
+        _drop_check_constraint: &DropCheckConstraint,
+        _database_info: &DatabaseInfo,
+        _guarded: bool,
+    ) -> Vec<String> {
+        unreachable!("render_drop_check_constraint on SQLite")
+    }
+
+    fn render_drop_foreign_key(&self, _drop_foreign_key: &DropForeignKey, _guarded: bool) -> String {
         unreachable!("render_drop_foreign_key on SQLite")
     }
 
-    fn render_drop_index(&self, drop_index: &DropIndex) -> String {
-        format!("DROP INDEX {}", self.quote_with_schema(&drop_index.name))
+    fn render_drop_index(&self, drop_index: &DropIndex, guarded: bool) -> String {
+        format!(
+            "DROP INDEX {}{}",
+            if_exists(guarded),
+            self.quote_with_schema(&drop_index.name)
+        )
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
+    fn render_drop_table(&self, table_name: &str, guarded: bool) -> Vec<String> {
         // Turning off the pragma is safe, because schema validation would forbid foreign keys
         // to a non-existent model. There appears to be no other way to deal with cyclic
         // dependencies in the dropping order of tables in the presence of foreign key
         // constraints on SQLite.
         vec![
             "PRAGMA foreign_keys=off".to_string(),
-            format!("DROP TABLE {}", self.quote_with_schema(&table_name)),
+            format!(
+                "DROP TABLE {}{}",
+                if_exists(guarded),
+                self.quote_with_schema(&table_name)
+            ),
             "PRAGMA foreign_keys=on".to_string(),
         ]
     }
@@ -247,7 +283,10 @@ impl SqlRenderer for SqliteFlavour {
             };
 
             // TODO start transaction now. Unclear if we really want to do that.
-            result.push(self.render_create_table(&temporary_table).expect("render_create_table"));
+            result.push(
+                self.render_create_table(&temporary_table, false)
+                    .expect("render_create_table"),
+            );
 
             copy_current_table_into_new_table(&mut result, &differ, temporary_table.name(), self).unwrap();
 
@@ -261,12 +300,16 @@ impl SqlRenderer for SqliteFlavour {
 
             // Recreate the indices
             result.extend(differ.next.table.indices.iter().map(|index| {
-                self.render_create_index(&CreateIndex {
-                    table: differ.next.name().to_owned(),
-                    index: index.clone(),
-                    caused_by_create_table: false,
-                    contains_nullable_columns: false,
-                })
+                self.render_create_index(
+                    &CreateIndex {
+                        table: differ.next.name().to_owned(),
+                        index: index.clone(),
+                        caused_by_create_table: false,
+                        contains_nullable_columns: false,
+                        memory_optimized: false,
+                    },
+                    false,
+                )
             }));
         }
 
@@ -295,7 +338,9 @@ fn render_column_type(t: &ColumnType) -> &'static str {
         ColumnTypeFamily::DateTime => "DATETIME",
         ColumnTypeFamily::Float => "REAL",
         ColumnTypeFamily::Int => "INTEGER",
+        ColumnTypeFamily::BigInt => "BIGINT",
         ColumnTypeFamily::String => "TEXT",
+        ColumnTypeFamily::Binary => "BLOB",
         x => unimplemented!("{:?} not handled yet", x),
     }
 }
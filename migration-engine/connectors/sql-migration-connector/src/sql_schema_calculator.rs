@@ -5,8 +5,10 @@ pub(super) use sql_schema_calculator_flavour::SqlSchemaCalculatorFlavour;
 use crate::{flavour::SqlFlavour, sql_renderer::IteratorJoin, DatabaseInfo};
 use datamodel::{
     walkers::{walk_models, walk_relations, ModelWalker, ScalarFieldWalker, TypeWalker},
-    Datamodel, DefaultValue, FieldArity, IndexDefinition, IndexType, ScalarType, ValueGenerator, ValueGeneratorFn,
+    Datamodel, DefaultValue, FieldArity, IndexDefinition, IndexType, OnDeleteStrategy, RelationMode, ScalarType,
+    ValueGenerator, ValueGeneratorFn,
 };
+use migration_connector::EnumValueRenames;
 use prisma_value::PrismaValue;
 use quaint::prelude::SqlFamily;
 use sql_schema_describer::{self as sql, ColumnArity};
@@ -15,6 +17,7 @@ pub struct SqlSchemaCalculator<'a> {
     data_model: &'a Datamodel,
     database_info: &'a DatabaseInfo,
     flavour: &'a dyn SqlFlavour,
+    relation_mode: RelationMode,
 }
 
 impl<'a> SqlSchemaCalculator<'a> {
@@ -22,20 +25,30 @@ impl<'a> SqlSchemaCalculator<'a> {
         data_model: &Datamodel,
         database_info: &DatabaseInfo,
         flavour: &dyn SqlFlavour,
+        relation_mode: RelationMode,
     ) -> sql::SqlSchema {
         let calculator = SqlSchemaCalculator {
             data_model,
             database_info,
             flavour,
+            relation_mode,
         };
         calculator.calculate_internal()
     }
 
     fn calculate_internal(&self) -> sql::SqlSchema {
         let mut tables = Vec::with_capacity(self.data_model.models().len());
+        let mut external_tables = Vec::new();
         let model_tables_without_inline_relations = self.calculate_model_tables();
 
         for (model, mut table) in model_tables_without_inline_relations {
+            // Models mapped to a qualified `database.table` name live in another database of the MySQL
+            // server. Migrations only reference them.
+            if self.database_info.sql_family().is_mysql() && sql::split_qualified_name(&table.name).is_some() {
+                external_tables.push(table);
+                continue;
+            }
+
             self.add_inline_relations_to_model_tables(model, &mut table);
             tables.push(table);
         }
@@ -43,13 +56,81 @@ impl<'a> SqlSchemaCalculator<'a> {
         tables.extend(self.calculate_relation_tables());
 
         let enums = self.flavour.calculate_enums(self);
-        let sequences = Vec::new();
+        let sequences = self.calculate_sequences();
+        let distributed_tables = self.calculate_distributed_tables();
+        let temporal_tables = self.calculate_temporal_tables();
 
         sql::SqlSchema {
             tables,
             enums,
             sequences,
+            distributed_tables,
+            missing_privileges: Vec::new(),
+            on_update_timestamps: Vec::new(),
+            views: Vec::new(),
+            generated_columns: Vec::new(),
+            check_constraints: Vec::new(),
+            auto_increment_values: Vec::new(),
+            temporal_tables,
+            memory_optimized_tables: Vec::new(),
+            external_tables,
+        }
+    }
+
+    /// The sequences used by `sequence()` defaults.
+    fn calculate_sequences(&self) -> Vec<sql::Sequence> {
+        let mut sequences: Vec<sql::Sequence> = Vec::new();
+
+        for model in walk_models(self.data_model) {
+            for field in model.scalar_fields() {
+                let sequence_name = match field.default_value() {
+                    Some(DefaultValue::Expression(generator)) => generator.sequence_name(),
+                    _ => None,
+                };
+
+                match sequence_name {
+                    Some(name) if !sequences.iter().any(|sequence| sequence.name == name) => {
+                        sequences.push(sql::Sequence {
+                            name: name.to_owned(),
+                            initial_value: 1,
+                            allocation_size: 1,
+                        })
+                    }
+                    _ => (),
+                }
+            }
         }
+
+        sequences
+    }
+
+    fn calculate_distributed_tables(&self) -> Vec<sql::DistributedTable> {
+        walk_models(self.data_model)
+            .filter_map(|model| {
+                let distribution_field = model.distribution_field()?;
+
+                Some(sql::DistributedTable {
+                    table: model.database_name().to_owned(),
+                    distribution_column: distribution_field.db_name().to_owned(),
+                })
+            })
+            .collect()
+    }
+
+    /// Temporal tables get the period columns SQL Server itself names when versioning an existing table.
+    fn calculate_temporal_tables(&self) -> Vec<sql::TemporalTable> {
+        walk_models(self.data_model)
+            .filter_map(|model| {
+                let history_table = model.history_table_name()?;
+
+                Some(sql::TemporalTable {
+                    table: model.database_name().to_owned(),
+                    history_table,
+                    period_start_column: "ValidFrom".to_owned(),
+                    period_end_column: "ValidTo".to_owned(),
+                })
+            })
+            .collect()
     }
 
     fn calculate_model_tables<'iter>(&'iter self) -> impl Iterator<Item = (ModelWalker<'a>, sql::Table)> + 'iter {
@@ -66,7 +147,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                         Some(sql::Column {
                             name: f.db_name().to_owned(),
                             tpe: column_type(&f),
-                            default: migration_value_new(&f),
+                            default: migration_value_new(&f, self),
                             auto_increment: has_auto_increment_default || is_sqlite_integer_primary_key,
                         })
                     },
@@ -75,7 +156,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                         Some(sql::Column {
                             name: f.db_name().to_owned(),
                             tpe: enum_column_type(&f, &self.database_info, enum_db_name),
-                            default: migration_value_new(&f),
+                            default: migration_value_new(&f, self),
                             auto_increment: false,
                         })
                     }
@@ -88,7 +169,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                         Some(sql::Column {
                             name: f.db_name().to_owned(),
                             tpe: self.flavour.column_type_for_native_type(&f, scalar_type, native_type_instance),
-                            default: migration_value_new(&f),
+                            default: migration_value_new(&f, self),
                             auto_increment: has_auto_increment_default || is_sqlite_integer_primary_key
                         })
                     } ,
@@ -96,6 +177,10 @@ impl<'a> SqlSchemaCalculator<'a> {
                 })
                 .collect();
 
+            // SQL Server clusters the primary key by default, unless another index is clustered.
+            let is_mssql = self.database_info.sql_family().is_mssql();
+            let has_clustered_index = model.indexes().any(|index| index.clustered == Some(true));
+
             let primary_key = Some(sql::PrimaryKey {
                 columns: model
                     .id_fields()
@@ -103,6 +188,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                     .collect(),
                 sequence: None,
                 constraint_name: None,
+                clustered: Some(model.id_clustered().unwrap_or(!has_clustered_index)).filter(|_| is_mssql),
             }).filter(|pk| !pk.columns.is_empty());
 
             let single_field_indexes = model.scalar_fields().filter(|f| f.is_unique()).map(|f| {
@@ -110,6 +196,9 @@ impl<'a> SqlSchemaCalculator<'a> {
                     name: format!("{}.{}_unique", &model.db_name(), &f.db_name()),
                     columns: vec![f.db_name().to_owned()],
                     tpe: sql::IndexType::Unique,
+                    clustered: Some(false).filter(|_| is_mssql),
+                    predicate: None,
+                    nulls_not_distinct: false,
                 }
             });
 
@@ -143,6 +232,11 @@ impl<'a> SqlSchemaCalculator<'a> {
                         .map(|field| field.db_name().to_owned())
                         .collect(),
                     tpe: index_type,
+                    clustered: Some(index_definition.clustered.unwrap_or(false)).filter(|_| is_mssql),
+                    predicate: index_definition.predicate.clone(),
+                    // Like the describer, only keep the flag where there are NULLs to compare.
+                    nulls_not_distinct: index_definition.nulls_not_distinct
+                        && referenced_fields.iter().any(|field| !field.is_required()),
                 }
             });
 
@@ -171,6 +265,12 @@ impl<'a> SqlSchemaCalculator<'a> {
                 add_one_to_one_relation_unique_index(table, &fk_columns);
             }
 
+            // Without foreign keys, the relation columns still need an index for the queries emulating them.
+            if !self.relation_mode.uses_foreign_keys() {
+                add_relation_index(table, &fk_columns);
+                continue;
+            }
+
             // Foreign key
             {
                 let fk = sql::ForeignKey {
@@ -179,9 +279,14 @@ impl<'a> SqlSchemaCalculator<'a> {
                     referenced_table: relation_field.referenced_table_name().to_owned(),
                     referenced_columns: relation_field.referenced_columns().map(String::from).collect(),
                     on_update_action: sql::ForeignKeyAction::Cascade,
-                    on_delete_action: match column_arity(relation_field.arity()) {
-                        ColumnArity::Required => sql::ForeignKeyAction::Cascade,
-                        _ => sql::ForeignKeyAction::SetNull,
+                    on_delete_action: match (relation_field.on_delete(), column_arity(relation_field.arity())) {
+                        (OnDeleteStrategy::Restrict, _) => sql::ForeignKeyAction::Restrict,
+                        (OnDeleteStrategy::NoAction, _) => sql::ForeignKeyAction::NoAction,
+                        (OnDeleteStrategy::Cascade, _) => sql::ForeignKeyAction::Cascade,
+                        (OnDeleteStrategy::SetNull, _) => sql::ForeignKeyAction::SetNull,
+                        (OnDeleteStrategy::SetDefault, _) => sql::ForeignKeyAction::SetDefault,
+                        (OnDeleteStrategy::None, ColumnArity::Required) => sql::ForeignKeyAction::Cascade,
+                        (OnDeleteStrategy::None, _) => sql::ForeignKeyAction::SetNull,
                     },
                 };
 
@@ -204,6 +309,7 @@ impl<'a> SqlSchemaCalculator<'a> {
 
     fn calculate_relation_tables<'b>(&'b self) -> impl Iterator<Item = sql::Table> + 'b {
         let family = self.flavour.sql_family();
+        let relation_mode = self.relation_mode;
 
         walk_relations(self.data_model)
             .filter_map(|relation| relation.as_m2m())
@@ -214,35 +320,45 @@ impl<'a> SqlSchemaCalculator<'a> {
                 let model_a = model_a_id.model();
                 let model_b = model_b_id.model();
 
-                let foreign_keys = vec![
-                    sql::ForeignKey {
-                        constraint_name: None,
-                        columns: vec![m2m.model_a_column().into()],
-                        referenced_table: model_a.db_name().into(),
-                        referenced_columns: vec![model_a_id.db_name().into()],
-                        on_update_action: Self::m2m_foreign_key_action(family, &model_a, &model_b),
-                        on_delete_action: Self::m2m_foreign_key_action(family, &model_a, &model_b),
-                    },
-                    sql::ForeignKey {
-                        constraint_name: None,
-                        columns: vec![m2m.model_b_column().into()],
-                        referenced_table: model_b.db_name().into(),
-                        referenced_columns: vec![model_b_id.db_name().into()],
-                        on_update_action: Self::m2m_foreign_key_action(family, &model_a, &model_b),
-                        on_delete_action: Self::m2m_foreign_key_action(family, &model_a, &model_b),
-                    },
-                ];
+                let foreign_keys = if relation_mode.uses_foreign_keys() {
+                    vec![
+                        sql::ForeignKey {
+                            constraint_name: None,
+                            columns: vec![m2m.model_a_column().into()],
+                            referenced_table: model_a.db_name().into(),
+                            referenced_columns: vec![model_a_id.db_name().into()],
+                            on_update_action: Self::m2m_foreign_key_action(family, &model_a, &model_b),
+                            on_delete_action: Self::m2m_foreign_key_action(family, &model_a, &model_b),
+                        },
+                        sql::ForeignKey {
+                            constraint_name: None,
+                            columns: vec![m2m.model_b_column().into()],
+                            referenced_table: model_b.db_name().into(),
+                            referenced_columns: vec![model_b_id.db_name().into()],
+                            on_update_action: Self::m2m_foreign_key_action(family, &model_a, &model_b),
+                            on_delete_action: Self::m2m_foreign_key_action(family, &model_a, &model_b),
+                        },
+                    ]
+                } else {
+                    Vec::new()
+                };
 
                 let indexes = vec![
                     sql::Index {
                         name: format!("{}_AB_unique", &table_name),
                         columns: vec![m2m.model_a_column().into(), m2m.model_b_column().into()],
                         tpe: sql::IndexType::Unique,
+                        clustered: None,
+                        predicate: None,
+                        nulls_not_distinct: false,
                     },
                     sql::Index {
                         name: format!("{}_B_index", &table_name),
                         columns: vec![m2m.model_b_column().into()],
                         tpe: sql::IndexType::Normal,
+                        clustered: None,
+                        predicate: None,
+                        nulls_not_distinct: false,
                     },
                 ];
 
@@ -272,7 +388,10 @@ impl<'a> SqlSchemaCalculator<'a> {
     }
 }
 
-fn migration_value_new(field: &ScalarFieldWalker<'_>) -> Option<sql_schema_describer::DefaultValue> {
+fn migration_value_new(
+    field: &ScalarFieldWalker<'_>,
+    calculator: &SqlSchemaCalculator<'_>,
+) -> Option<sql_schema_describer::DefaultValue> {
     let value = match &field.default_value()? {
         datamodel::DefaultValue::Single(s) => match field.field_type() {
             TypeWalker::Enum(inum) => {
@@ -297,7 +416,15 @@ fn migration_value_new(field: &ScalarFieldWalker<'_>) -> Option<sql_schema_descr
         datamodel::DefaultValue::Expression(expression)
             if expression.name == "autoincrement" && expression.args.is_empty() =>
         {
-            return Some(sql_schema_describer::DefaultValue::SEQUENCE(String::new()))
+            return Some(calculator.flavour.autoincrement_default(calculator.database_info))
+        }
+        datamodel::DefaultValue::Expression(expression) if expression.name == "uuid" && expression.args.is_empty() => {
+            return calculator.flavour.uuid_default(calculator.database_info)
+        }
+        datamodel::DefaultValue::Expression(expression) if expression.sequence_name().is_some() => {
+            return expression
+                .sequence_name()
+                .map(|name| sql_schema_describer::DefaultValue::NEXTVAL(name.to_owned()))
         }
         datamodel::DefaultValue::Expression(_) => return None,
     };
@@ -317,6 +444,53 @@ fn enum_column_type(field: &ScalarFieldWalker<'_>, database_info: &DatabaseInfo,
     }
 }
 
+/// Translates the enum value renames from the names in the Prisma schema to the names of the enums and
+/// values in the database. The previous values are not in the target schema anymore, so their names are
+/// taken as they are. On MySQL, each column using the enum has its own enum.
+pub(crate) fn database_enum_value_renames(
+    data_model: &Datamodel,
+    database_info: &DatabaseInfo,
+    renames: &EnumValueRenames,
+) -> EnumValueRenames {
+    let mut database_renames = EnumValueRenames::new();
+
+    if renames.is_empty() {
+        return database_renames;
+    }
+
+    for model in walk_models(data_model) {
+        for field in model.scalar_fields() {
+            let r#enum = match field.field_type() {
+                TypeWalker::Enum(r#enum) => r#enum,
+                _ => continue,
+            };
+
+            let enum_db_name = match enum_column_type(&field, database_info, r#enum.db_name()).family {
+                sql::ColumnTypeFamily::Enum(name) => name,
+                _ => continue,
+            };
+
+            for (enum_name, renamed_values) in renames.iter() {
+                if enum_name != r#enum.r#enum.name {
+                    continue;
+                }
+
+                for (previous_value, next_value) in renamed_values {
+                    let next_value = r#enum
+                        .r#enum
+                        .find_value(next_value)
+                        .map(|value| value.final_database_name())
+                        .unwrap_or(next_value);
+
+                    database_renames.insert(enum_db_name.as_str(), previous_value, next_value);
+                }
+            }
+        }
+    }
+
+    database_renames
+}
+
 fn column_type(field: &ScalarFieldWalker<'_>) -> sql::ColumnType {
     column_type_for_scalar_type(&scalar_type_for_field(field), column_arity(field.arity()))
 }
@@ -345,18 +519,48 @@ fn column_arity(arity: FieldArity) -> sql::ColumnArity {
 fn column_type_for_scalar_type(scalar_type: &ScalarType, column_arity: ColumnArity) -> sql::ColumnType {
     match scalar_type {
         ScalarType::Int => sql::ColumnType::pure(sql::ColumnTypeFamily::Int, column_arity),
-        ScalarType::Float => sql::ColumnType::pure(sql::ColumnTypeFamily::Float, column_arity),
+        ScalarType::BigInt => sql::ColumnType::pure(sql::ColumnTypeFamily::BigInt, column_arity),
+        // Float columns are already rendered as DECIMAL / NUMERIC everywhere but on SQLite, which
+        // rejects Decimal fields.
+        ScalarType::Float | ScalarType::Decimal => sql::ColumnType::pure(sql::ColumnTypeFamily::Float, column_arity),
         ScalarType::Boolean => sql::ColumnType::pure(sql::ColumnTypeFamily::Boolean, column_arity),
         ScalarType::String => sql::ColumnType::pure(sql::ColumnTypeFamily::String, column_arity),
         ScalarType::DateTime => sql::ColumnType::pure(sql::ColumnTypeFamily::DateTime, column_arity),
         ScalarType::Json => sql::ColumnType::pure(sql::ColumnTypeFamily::Json, column_arity),
         ScalarType::Bytes => sql::ColumnType::pure(sql::ColumnTypeFamily::Binary, column_arity),
         ScalarType::XML => unreachable!("XML type rendering"),
-        ScalarType::Decimal => unreachable!("Decimal type rendering"),
         ScalarType::Duration => unreachable!("Duration type rendering"),
     }
 }
 
+/// A plain index on the columns of a relation, unless an existing index already starts with them.
+fn add_relation_index(table: &mut sql::Table, column_names: &[String]) {
+    let is_indexed = table
+        .indices
+        .iter()
+        .any(|index| index.columns.starts_with(column_names))
+        || table
+            .primary_key
+            .as_ref()
+            .map(|pk| pk.columns.starts_with(column_names))
+            .unwrap_or(false);
+
+    if is_indexed {
+        return;
+    }
+
+    let index = sql::Index {
+        name: format!("{}.{}_index", table.name, column_names.join("_")),
+        columns: column_names.to_owned(),
+        tpe: sql::IndexType::Normal,
+        clustered: None,
+        predicate: None,
+        nulls_not_distinct: false,
+    };
+
+    table.indices.push(index);
+}
+
 fn add_one_to_one_relation_unique_index(table: &mut sql::Table, column_names: &[String]) {
     // Don't add a duplicate index.
     if table
@@ -372,6 +576,9 @@ fn add_one_to_one_relation_unique_index(table: &mut sql::Table, column_names: &[
         name: format!("{}_{}_unique", table.name, columns_suffix),
         columns: column_names.to_owned(),
         tpe: sql::IndexType::Unique,
+        clustered: None,
+        predicate: None,
+        nulls_not_distinct: false,
     };
 
     table.indices.push(index);
@@ -4,6 +4,7 @@ mod postgres;
 mod sqlite;
 
 use super::SqlSchemaCalculator;
+use crate::DatabaseInfo;
 use datamodel::{walkers::ScalarFieldWalker, ScalarType};
 use datamodel_connector::NativeTypeInstance;
 use sql_schema_describer as sql;
@@ -19,4 +20,15 @@ pub(crate) trait SqlSchemaCalculatorFlavour {
         _scalar_type: ScalarType,
         _native_type_instance: &NativeTypeInstance,
     ) -> sql::ColumnType;
+
+    /// The database default for `@default(uuid())`. By default there is none, and the ids are
+    /// generated by the clients.
+    fn uuid_default(&self, _database_info: &DatabaseInfo) -> Option<sql::DefaultValue> {
+        None
+    }
+
+    /// The database default for `@default(autoincrement())`.
+    fn autoincrement_default(&self, _database_info: &DatabaseInfo) -> sql::DefaultValue {
+        sql::DefaultValue::SEQUENCE(String::new())
+    }
 }
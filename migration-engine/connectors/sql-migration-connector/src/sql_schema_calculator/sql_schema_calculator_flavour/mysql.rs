@@ -73,6 +73,14 @@ impl SqlSchemaCalculatorFlavour for MysqlFlavour {
             MySqlType::Timestamp(None) => "TIMESTAMP".into(),
             MySqlType::Year => "YEAR".into(),
             MySqlType::JSON => "JSON".into(),
+            MySqlType::Set(values) => format!(
+                "SET({})",
+                values
+                    .iter()
+                    .map(|value| format!("'{}'", value.replace('\'', "''")))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
             _ => todo!(),
         };
 
@@ -1,5 +1,5 @@
 use super::SqlSchemaCalculatorFlavour;
-use crate::{flavour::PostgresFlavour, sql_schema_calculator::SqlSchemaCalculator};
+use crate::{flavour::PostgresFlavour, sql_schema_calculator::SqlSchemaCalculator, DatabaseInfo};
 use datamodel::{walkers::ScalarFieldWalker, ScalarType, WithDatabaseName};
 use datamodel_connector::NativeTypeInstance;
 use native_types::PostgresType;
@@ -24,6 +24,12 @@ impl SqlSchemaCalculatorFlavour for PostgresFlavour {
         native_type_instance: &NativeTypeInstance,
     ) -> sql::ColumnType {
         let postgres_type: PostgresType = native_type_instance.deserialize_native_type();
+        let family = match postgres_type {
+            PostgresType::XML => sql::ColumnTypeFamily::Xml,
+            PostgresType::TsVector | PostgresType::TsQuery => sql::ColumnTypeFamily::TextSearch,
+            _ => sql::ColumnTypeFamily::String,
+        };
+
         let data_type = match postgres_type {
             PostgresType::SmallInt => "SMALLINT".to_owned(),
             PostgresType::Integer => "INTEGER".to_owned(),
@@ -47,11 +53,19 @@ impl SqlSchemaCalculatorFlavour for PostgresFlavour {
             PostgresType::Time(precision) => format!("TIME({precision})", precision = precision),
             PostgresType::TimeWithTimeZone(precision) => format!("TIMETZ({precision})", precision = precision),
             PostgresType::Interval(precision) => format!("INTERVAL({precision})", precision = precision),
+            PostgresType::Int4Range => "INT4RANGE".to_owned(),
+            PostgresType::Int8Range => "INT8RANGE".to_owned(),
+            PostgresType::NumRange => "NUMRANGE".to_owned(),
+            PostgresType::TsRange => "TSRANGE".to_owned(),
+            PostgresType::TsTzRange => "TSTZRANGE".to_owned(),
+            PostgresType::DateRange => "DATERANGE".to_owned(),
             PostgresType::Boolean => "BOOLEAN".to_owned(),
             PostgresType::Bit(size) => format!("BIT({})", size),
             PostgresType::VarBit(size) => format!("VARBIT({})", size),
             PostgresType::UUID => "UUID".to_owned(),
             PostgresType::XML => "XML".to_owned(),
+            PostgresType::TsVector => "TSVECTOR".to_owned(),
+            PostgresType::TsQuery => "TSQUERY".to_owned(),
             PostgresType::JSON => "JSON".to_owned(),
             PostgresType::JSONB => "JSONB".to_owned(),
         };
@@ -60,7 +74,7 @@ impl SqlSchemaCalculatorFlavour for PostgresFlavour {
             data_type: data_type.clone(),
             full_data_type: data_type,
             character_maximum_length: None,
-            family: sql::ColumnTypeFamily::String,
+            family,
             arity: match field.arity() {
                 datamodel::FieldArity::Required => sql::ColumnArity::Required,
                 datamodel::FieldArity::Optional => sql::ColumnArity::Nullable,
@@ -68,4 +82,25 @@ impl SqlSchemaCalculatorFlavour for PostgresFlavour {
             },
         }
     }
+
+    fn uuid_default(&self, database_info: &DatabaseInfo) -> Option<sql::DefaultValue> {
+        // gen_random_uuid() is built in from PostgreSQL 13 on. On older versions, no default is
+        // created, but the ones already in the database, e.g. uuid_generate_v4(), are kept.
+        let function = match database_info.postgres_major_version() {
+            Some(major) if major >= 13 => "gen_random_uuid()",
+            _ => "",
+        };
+
+        Some(sql::DefaultValue::DBGENERATED(function.to_owned()))
+    }
+
+    fn autoincrement_default(&self, database_info: &DatabaseInfo) -> sql::DefaultValue {
+        // Identity columns exist from PostgreSQL 10 on. They are opt-in, `SERIAL` stays the default.
+        match database_info.postgres_major_version() {
+            Some(major) if major >= 10 && self.identity_columns() => {
+                sql::DefaultValue::IDENTITY(sql::IdentityGeneration::ByDefault)
+            }
+            _ => sql::DefaultValue::SEQUENCE(String::new()),
+        }
+    }
 }
@@ -9,13 +9,18 @@ pub(crate) use sql_schema_differ_flavour::SqlSchemaDifferFlavour;
 pub(crate) use table::TableDiffer;
 
 use crate::*;
+pub(crate) use enums::renamed_enums;
+
 use enums::EnumDiffer;
+use migration_connector::EnumValueRenames;
 use sql_migration::{
-    AddColumn, AddForeignKey, AlterColumn, AlterEnum, AlterIndex, AlterTable, CreateEnum, CreateIndex, CreateTable,
-    DropColumn, DropEnum, DropForeignKey, DropIndex, DropTable, SqlMigrationStep, TableChange,
+    AddCheckConstraint, AddColumn, AddForeignKey, AddSystemVersioning, AlterColumn, AlterEnum, AlterIndex, AlterTable,
+    CreateEnum, CreateIndex, CreateSequence, CreateTable, DistributeTable, DropCheckConstraint, DropColumn, DropEnum,
+    DropForeignKey, DropIndex, DropSystemVersioning, DropTable, RenameEnum, SqlMigrationStep, TableChange,
+    UndistributeTable,
 };
 use sql_schema_describer::{
-    walkers::{ForeignKeyWalker, TableWalker},
+    walkers::{walk_columns, ForeignKeyWalker, TableWalker},
     *,
 };
 use std::collections::HashSet;
@@ -27,6 +32,8 @@ pub(crate) struct SqlSchemaDiffer<'a> {
     pub(crate) next: &'a SqlSchema,
     pub(crate) database_info: &'a DatabaseInfo,
     pub(crate) flavour: &'a dyn SqlFlavour,
+    /// The enum value renames, with the database names of the enums and values.
+    pub(crate) enum_value_renames: &'a EnumValueRenames,
 }
 
 #[derive(Debug, Clone)]
@@ -42,6 +49,14 @@ pub struct SqlSchemaDiff {
     pub create_enums: Vec<CreateEnum>,
     pub drop_enums: Vec<DropEnum>,
     pub alter_enums: Vec<AlterEnum>,
+    pub rename_enums: Vec<RenameEnum>,
+    pub distribute_tables: Vec<DistributeTable>,
+    pub undistribute_tables: Vec<UndistributeTable>,
+    pub add_system_versionings: Vec<AddSystemVersioning>,
+    pub drop_system_versionings: Vec<DropSystemVersioning>,
+    pub create_sequences: Vec<CreateSequence>,
+    pub add_check_constraints: Vec<AddCheckConstraint>,
+    pub drop_check_constraints: Vec<DropCheckConstraint>,
     pub tables_to_redefine: HashSet<String>,
 }
 
@@ -57,8 +72,29 @@ impl SqlSchemaDiff {
                 SqlMigrationStep::RedefineTables { names }
             });
 
-        wrap_as_step(self.create_enums, SqlMigrationStep::CreateEnum)
+        // Order matters: a created enum can take the previous name of a renamed enum.
+        wrap_as_step(self.rename_enums, SqlMigrationStep::RenameEnum)
+            .chain(wrap_as_step(self.create_enums, SqlMigrationStep::CreateEnum))
             .chain(wrap_as_step(self.alter_enums, SqlMigrationStep::AlterEnum))
+            // Order matters: sequences must exist before the columns using them in their defaults.
+            .chain(wrap_as_step(self.create_sequences, SqlMigrationStep::CreateSequence))
+            // Order matters: Citus forbids changing the distribution column of a distributed table, so
+            // the table has to be undistributed before it is altered.
+            .chain(wrap_as_step(
+                self.undistribute_tables,
+                SqlMigrationStep::UndistributeTable,
+            ))
+            // Order matters: SQL Server refuses to drop a temporal table, or its history table, while
+            // system versioning is on.
+            .chain(wrap_as_step(
+                self.drop_system_versionings,
+                SqlMigrationStep::DropSystemVersioning,
+            ))
+            // Order matters: the checks on altered or dropped columns are dropped before the ALTER TABLEs.
+            .chain(wrap_as_step(
+                self.drop_check_constraints,
+                SqlMigrationStep::DropCheckConstraint,
+            ))
             .chain(wrap_as_step(self.drop_indexes, SqlMigrationStep::DropIndex))
             .chain(wrap_as_step(self.drop_foreign_keys, SqlMigrationStep::DropForeignKey))
             .chain(wrap_as_step(self.alter_tables, SqlMigrationStep::AlterTable))
@@ -68,9 +104,22 @@ impl SqlSchemaDiff {
             .chain(wrap_as_step(self.drop_enums, SqlMigrationStep::DropEnum))
             .chain(wrap_as_step(self.create_tables, SqlMigrationStep::CreateTable))
             .chain(redefine_tables.into_iter())
+            // Order matters: foreign keys between distributed tables can only be created once both
+            // tables are distributed.
+            .chain(wrap_as_step(self.distribute_tables, SqlMigrationStep::DistributeTable))
+            // Order matters: the period columns are added to created tables.
+            .chain(wrap_as_step(
+                self.add_system_versionings,
+                SqlMigrationStep::AddSystemVersioning,
+            ))
             // Order matters: we must create indexes after ALTER TABLEs because the indexes can be
             // on fields that are dropped/created there.
             .chain(wrap_as_step(self.create_indexes, SqlMigrationStep::CreateIndex))
+            // Order matters: checks can only be added once the columns they check have their new type.
+            .chain(wrap_as_step(
+                self.add_check_constraints,
+                SqlMigrationStep::AddCheckConstraint,
+            ))
             // Order matters: this needs to come after create_indexes, because the foreign keys can depend on unique
             // indexes created there.
             .chain(wrap_as_step(self.add_foreign_keys, SqlMigrationStep::AddForeignKey))
@@ -86,12 +135,14 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         next: &SqlSchema,
         flavour: &dyn SqlFlavour,
         database_info: &DatabaseInfo,
+        enum_value_renames: &EnumValueRenames,
     ) -> SqlSchemaDiff {
         let differ = SqlSchemaDiffer {
             previous,
             next,
             flavour,
             database_info,
+            enum_value_renames,
         };
         differ.diff_internal()
     }
@@ -110,6 +161,9 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         let alter_indexes: Vec<_> = self.alter_indexes(&tables_to_redefine);
         let (drop_tables, mut drop_foreign_keys) = self.drop_tables();
         self.drop_foreign_keys(&mut drop_foreign_keys, &tables_to_redefine);
+        let (distribute_tables, undistribute_tables) = self.distribution_changes();
+        let (add_system_versionings, drop_system_versionings) = self.system_versioning_changes();
+        let (add_check_constraints, drop_check_constraints) = self.check_constraint_changes(&tables_to_redefine);
 
         SqlSchemaDiff {
             add_foreign_keys: self.add_foreign_keys(&tables_to_redefine),
@@ -123,10 +177,213 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             create_enums: self.create_enums(),
             drop_enums: self.drop_enums(),
             alter_enums: self.alter_enums(),
+            rename_enums: self.rename_enums(),
+            distribute_tables,
+            undistribute_tables,
+            add_system_versionings,
+            drop_system_versionings,
+            create_sequences: self.create_sequences(),
+            add_check_constraints,
+            drop_check_constraints,
             tables_to_redefine,
         }
     }
 
+    /// The sequences used in `sequence()` defaults that do not exist yet. Sequences are never
+    /// dropped: other database objects, outside of the Prisma schema, can use them too.
+    fn create_sequences(&self) -> Vec<CreateSequence> {
+        let mut create_sequences: Vec<CreateSequence> = Vec::new();
+
+        for column in walk_columns(self.next) {
+            let sequence_name = match column.default() {
+                Some(DefaultValue::NEXTVAL(sequence_name)) => sequence_name,
+                _ => continue,
+            };
+
+            let already_created = self.previous.get_sequence(sequence_name).is_some()
+                || create_sequences.iter().any(|created| &created.name == sequence_name);
+
+            if !already_created {
+                create_sequences.push(CreateSequence {
+                    name: sequence_name.clone(),
+                });
+            }
+        }
+
+        create_sequences
+    }
+
+    /// Distributed tables whose distribution column changes, or whose distribution column is altered
+    /// or dropped, are undistributed before the migration and distributed again afterwards.
+    fn distribution_changes(&self) -> (Vec<DistributeTable>, Vec<UndistributeTable>) {
+        let mut distribute_tables = Vec::new();
+        let mut undistribute_tables = Vec::new();
+
+        if !self.flavour.supports_distributed_tables() {
+            return (distribute_tables, undistribute_tables);
+        }
+
+        for created_table in self.created_tables() {
+            if let Some(distributed_table) = self.next.get_distributed_table(&created_table.name) {
+                distribute_tables.push(DistributeTable {
+                    table: distributed_table.table.clone(),
+                    distribution_column: distributed_table.distribution_column.clone(),
+                });
+            }
+        }
+
+        for tables in self.table_pairs() {
+            let previous = self.previous.get_distributed_table(tables.previous.name());
+            let next = self.next.get_distributed_table(tables.next.name());
+
+            let distribution_column_changed = previous.map(|previous| {
+                next.map(|next| next.distribution_column != previous.distribution_column)
+                    .unwrap_or(true)
+                    || tables
+                        .dropped_columns()
+                        .any(|column| column.name() == previous.distribution_column)
+                    || tables.column_pairs().any(|columns| {
+                        columns.previous.name() == previous.distribution_column && columns.differs_in_something()
+                    })
+            });
+
+            match (distribution_column_changed, next) {
+                (Some(false), _) => (),
+                (Some(true), next) => {
+                    undistribute_tables.push(UndistributeTable {
+                        table: tables.previous.name().to_owned(),
+                    });
+
+                    distribute_tables.extend(next.map(|next| DistributeTable {
+                        table: next.table.clone(),
+                        distribution_column: next.distribution_column.clone(),
+                    }));
+                }
+                (None, Some(next)) => distribute_tables.push(DistributeTable {
+                    table: next.table.clone(),
+                    distribution_column: next.distribution_column.clone(),
+                }),
+                (None, None) => (),
+            }
+        }
+
+        (distribute_tables, undistribute_tables)
+    }
+
+    /// Temporal tables whose history table changes are turned back into regular tables before the
+    /// migration and versioned again afterwards. Dropped temporal tables lose their versioning first.
+    fn system_versioning_changes(&self) -> (Vec<AddSystemVersioning>, Vec<DropSystemVersioning>) {
+        let mut add_system_versionings = Vec::new();
+        let mut drop_system_versionings = Vec::new();
+
+        if !self.flavour.supports_temporal_tables() {
+            return (add_system_versionings, drop_system_versionings);
+        }
+
+        for created_table in self.created_tables() {
+            if let Some(temporal_table) = self.next.get_temporal_table(&created_table.name) {
+                add_system_versionings.push(add_system_versioning(temporal_table));
+            }
+        }
+
+        for dropped_table in self.dropped_tables() {
+            if let Some(temporal_table) = self.previous.get_temporal_table(&dropped_table.name) {
+                drop_system_versionings.push(drop_system_versioning(temporal_table));
+            }
+        }
+
+        for tables in self.table_pairs() {
+            let previous = self.previous.get_temporal_table(tables.previous.name());
+            let next = self.next.get_temporal_table(tables.next.name());
+
+            match (previous, next) {
+                (Some(previous), Some(next)) if previous.history_table == next.history_table => (),
+                (previous, next) => {
+                    drop_system_versionings.extend(previous.map(drop_system_versioning));
+                    add_system_versionings.extend(next.map(add_system_versioning));
+                }
+            }
+        }
+
+        (add_system_versionings, drop_system_versionings)
+    }
+
+    /// The Prisma schema cannot express CHECK constraints, so the ones in the database are kept. Only
+    /// the checks on altered or dropped columns are dropped before the migration, and added again
+    /// afterwards unless a column they check is gone.
+    fn check_constraint_changes(
+        &self,
+        tables_to_redefine: &HashSet<String>,
+    ) -> (Vec<AddCheckConstraint>, Vec<DropCheckConstraint>) {
+        let mut add_check_constraints = Vec::new();
+        let mut drop_check_constraints = Vec::new();
+
+        for created_table in self.created_tables() {
+            add_check_constraints.extend(self.next.check_constraints_on(&created_table.name).map(|check| {
+                AddCheckConstraint {
+                    table: created_table.name.clone(),
+                    check_constraint: check.clone(),
+                }
+            }));
+        }
+
+        for tables in self
+            .table_pairs()
+            .filter(|tables| !tables_to_redefine.contains(tables.next.name()))
+        {
+            let previous_checks = self.previous.check_constraints_on(tables.previous.name());
+
+            for previous in previous_checks {
+                let next = self
+                    .next
+                    .check_constraints_on(tables.next.name())
+                    .find(|next| next.name == previous.name);
+
+                let column_dropped = tables
+                    .dropped_columns()
+                    .any(|column| previous.references_column(column.name()));
+                let column_altered = tables.column_pairs().any(|columns| {
+                    columns.differs_in_something() && previous.references_column(columns.previous.name())
+                });
+
+                let add = match next {
+                    Some(next) if next.definition != previous.definition || column_dropped || column_altered => {
+                        Some(next)
+                    }
+                    None if column_dropped => None,
+                    None if column_altered => Some(previous),
+                    _ => continue,
+                };
+
+                drop_check_constraints.push(DropCheckConstraint {
+                    table: tables.previous.name().to_owned(),
+                    name: previous.name.clone(),
+                });
+
+                add_check_constraints.extend(add.map(|check| AddCheckConstraint {
+                    table: tables.next.name().to_owned(),
+                    check_constraint: check.clone(),
+                }));
+            }
+
+            for next in self.next.check_constraints_on(tables.next.name()) {
+                let created = !self
+                    .previous
+                    .check_constraints_on(tables.previous.name())
+                    .any(|previous| previous.name == next.name);
+
+                if created {
+                    add_check_constraints.push(AddCheckConstraint {
+                        table: tables.next.name().to_owned(),
+                        check_constraint: next.clone(),
+                    });
+                }
+            }
+        }
+
+        (add_check_constraints, drop_check_constraints)
+    }
+
     fn create_tables(&self) -> Vec<CreateTable> {
         self.created_tables()
             .map(|created_table| CreateTable {
@@ -269,6 +526,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             .filter(|pk| !pk.columns.is_empty())
             .map(|pk| TableChange::AddPrimaryKey {
                 columns: pk.columns.clone(),
+                clustered: pk.clustered,
             })
     }
 
@@ -290,7 +548,11 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                 for walker in walker.indexes() {
                     let contains_nullable_columns = walker.has_nullable_columns();
 
-                    if family.is_mssql() && walker.index.is_unique() && !contains_nullable_columns {
+                    if family.is_mssql()
+                        && walker.index.is_unique()
+                        && !walker.index.is_partial()
+                        && !contains_nullable_columns
+                    {
                         continue;
                     }
 
@@ -299,6 +561,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                         index: walker.index.clone(),
                         caused_by_create_table: true,
                         contains_nullable_columns,
+                        memory_optimized: false,
                     });
                 }
             }
@@ -314,6 +577,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                     index: index.index.clone(),
                     caused_by_create_table: false,
                     contains_nullable_columns: index.has_nullable_columns(),
+                    memory_optimized: self.previous.is_memory_optimized(tables.previous.name()),
                 })
             }
         }
@@ -334,6 +598,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                 drop_indexes.push(DropIndex {
                     table: tables.previous.name().to_owned(),
                     name: index.index.name.clone(),
+                    memory_optimized: self.previous.is_memory_optimized(tables.previous.name()),
                 })
             }
         }
@@ -362,6 +627,16 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         self.flavour.alter_enums(self)
     }
 
+    fn rename_enums(&self) -> Vec<RenameEnum> {
+        self.renamed_enums()
+            .into_iter()
+            .map(|(previous, next)| RenameEnum {
+                name: previous.name.clone(),
+                new_name: next.name.clone(),
+            })
+            .collect()
+    }
+
     /// An iterator over the tables that are present in both schemas.
     fn table_pairs<'a>(&'a self) -> impl Iterator<Item = TableDiffer<'schema>> + 'a
     where
@@ -442,18 +717,39 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         self.previous_enums().filter_map(move |previous| {
             self.next_enums()
                 .find(|next| enums_match(previous, next))
-                .map(|next| EnumDiffer { previous, next })
+                .map(|next| EnumDiffer {
+                    previous,
+                    next,
+                    value_renames: self.enum_value_renames,
+                })
         })
     }
 
     fn created_enums(&self) -> impl Iterator<Item = &Enum> {
-        self.next_enums()
-            .filter(move |next| !self.previous_enums().any(|previous| enums_match(previous, next)))
+        let renamed_enums = self.renamed_enums();
+
+        self.next_enums().filter(move |next| {
+            !self.previous_enums().any(|previous| enums_match(previous, next))
+                && !renamed_enums.iter().any(|(_, renamed)| enums_match(renamed, next))
+        })
     }
 
     fn dropped_enums(&self) -> impl Iterator<Item = &Enum> {
-        self.previous_enums()
-            .filter(move |previous| !self.next_enums().any(|next| enums_match(previous, next)))
+        let renamed_enums = self.renamed_enums();
+
+        self.previous_enums().filter(move |previous| {
+            !self.next_enums().any(|next| enums_match(previous, next))
+                && !renamed_enums.iter().any(|(renamed, _)| enums_match(previous, renamed))
+        })
+    }
+
+    /// The `(previous, next)` enums that are renamed, on databases with named enum types.
+    fn renamed_enums(&self) -> Vec<(&Enum, &Enum)> {
+        if !self.flavour.supports_enum_renaming() {
+            return Vec::new();
+        }
+
+        renamed_enums(self.previous, self.next)
     }
 
     fn previous_enums(&self) -> impl Iterator<Item = &Enum> {
@@ -519,6 +815,24 @@ fn foreign_keys_match(previous: &ForeignKeyWalker<'_>, next: &ForeignKeyWalker<'
     true
 }
 
+fn add_system_versioning(temporal_table: &TemporalTable) -> AddSystemVersioning {
+    AddSystemVersioning {
+        table: temporal_table.table.clone(),
+        history_table: temporal_table.history_table.clone(),
+        period_start_column: temporal_table.period_start_column.clone(),
+        period_end_column: temporal_table.period_end_column.clone(),
+    }
+}
+
+fn drop_system_versioning(temporal_table: &TemporalTable) -> DropSystemVersioning {
+    DropSystemVersioning {
+        table: temporal_table.table.clone(),
+        history_table: temporal_table.history_table.clone(),
+        period_start_column: temporal_table.period_start_column.clone(),
+        period_end_column: temporal_table.period_end_column.clone(),
+    }
+}
+
 fn tables_match(previous: &Table, next: &Table) -> bool {
     previous.name == next.name
 }
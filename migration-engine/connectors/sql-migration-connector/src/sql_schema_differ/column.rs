@@ -25,6 +25,11 @@ impl<'a> ColumnDiffer<'a> {
     pub(crate) fn all_changes(&self) -> ColumnChanges {
         let mut changes = BitFlags::empty();
 
+        // The data model can not describe how generated columns are computed, they are left alone.
+        if self.previous.is_generated() {
+            return ColumnChanges { changes };
+        }
+
         if self.previous.name() != self.next.name() {
             changes |= ColumnChange::Renaming;
         };
@@ -94,7 +99,22 @@ impl<'a> ColumnDiffer<'a> {
             (Some(DefaultValue::SEQUENCE(_)), Some(DefaultValue::VALUE(_))) => false,
             (Some(DefaultValue::SEQUENCE(_)), Some(DefaultValue::NOW)) => false,
 
+            (Some(DefaultValue::NEXTVAL(prev)), Some(DefaultValue::NEXTVAL(next))) => prev == next,
+            (Some(DefaultValue::NEXTVAL(_)), Some(DefaultValue::VALUE(_))) => false,
+            (Some(DefaultValue::NEXTVAL(_)), Some(DefaultValue::NOW)) => false,
+            (Some(DefaultValue::NEXTVAL(_)), None) => false,
+
+            // Identity columns are left alone when `autoincrement()` is rendered as `SERIAL`, and the
+            // schema can not tell `GENERATED ALWAYS` from `GENERATED BY DEFAULT`.
+            (Some(DefaultValue::IDENTITY(_)), Some(DefaultValue::IDENTITY(_))) => true,
+            (Some(DefaultValue::IDENTITY(_)), None) => true, // identities are dropped with the sequence
+            (Some(DefaultValue::IDENTITY(_)), Some(DefaultValue::VALUE(_))) => false,
+            (Some(DefaultValue::IDENTITY(_)), Some(DefaultValue::NOW)) => false,
+
             (None, None) => true,
+            // An explicit `DEFAULT NULL` is the same as no default.
+            (Some(DefaultValue::NULL), next) => next.map(|next| next.is_null()).unwrap_or(true),
+            (previous, Some(DefaultValue::NULL)) => previous.is_none(),
             (None, Some(DefaultValue::VALUE(_))) => false,
             (None, Some(DefaultValue::NOW)) => false,
 
@@ -102,6 +122,8 @@ impl<'a> ColumnDiffer<'a> {
             (_, Some(DefaultValue::DBGENERATED(_))) => true,
             // Sequence migrations are handled separately.
             (_, Some(DefaultValue::SEQUENCE(_))) => true,
+            (_, Some(DefaultValue::NEXTVAL(_))) => false,
+            (_, Some(DefaultValue::IDENTITY(_))) => false,
         }
     }
 }
@@ -1,8 +1,10 @@
-use sql_schema_describer::Enum;
+use migration_connector::EnumValueRenames;
+use sql_schema_describer::{Enum, SqlSchema};
 
 pub(crate) struct EnumDiffer<'a> {
     pub(crate) previous: &'a Enum,
     pub(crate) next: &'a Enum,
+    pub(crate) value_renames: &'a EnumValueRenames,
 }
 
 impl<'a> EnumDiffer<'a> {
@@ -17,6 +19,7 @@ impl<'a> EnumDiffer<'a> {
                     .iter()
                     .any(|previous_value| values_match(previous_value, next_value))
             })
+            .filter(move |next_value| !self.renamed_values().any(|(_, renamed)| renamed == next_value.as_str()))
             .map(String::as_str)
     }
 
@@ -31,10 +34,72 @@ impl<'a> EnumDiffer<'a> {
                     .iter()
                     .any(|next_value| values_match(previous_value, next_value))
             })
+            .filter(move |previous_value| {
+                !self
+                    .renamed_values()
+                    .any(|(renamed, _)| renamed == previous_value.as_str())
+            })
             .map(String::as_str)
     }
+
+    /// The `(previous, next)` values renamed by the user. Renames are only taken into account from a
+    /// value that disappears to a value that appears.
+    pub(crate) fn renamed_values<'b>(&'b self) -> impl Iterator<Item = (&'a str, &'a str)> + 'b {
+        self.previous.values.iter().filter_map(move |previous_value| {
+            let renamed = self.value_renames.renamed_value(&self.next.name, previous_value)?;
+            let next_value = self
+                .next
+                .values
+                .iter()
+                .find(|next_value| next_value.as_str() == renamed)?;
+
+            if self.next.values.contains(previous_value) || self.previous.values.contains(next_value) {
+                return None;
+            }
+
+            Some((previous_value.as_str(), next_value.as_str()))
+        })
+    }
 }
 
 fn values_match(previous: &str, next: &str) -> bool {
     previous == next
 }
+
+/// An enum that disappears while another enum with exactly the same values appears is considered
+/// renamed, as long as no other appearing or disappearing enum has the same values.
+pub(crate) fn renamed_enums<'a>(previous: &'a SqlSchema, next: &'a SqlSchema) -> Vec<(&'a Enum, &'a Enum)> {
+    let dropped_enums: Vec<&Enum> = previous
+        .enums
+        .iter()
+        .filter(|previous_enum| next.get_enum(&previous_enum.name).is_none())
+        .collect();
+    let created_enums: Vec<&Enum> = next
+        .enums
+        .iter()
+        .filter(|next_enum| previous.get_enum(&next_enum.name).is_none())
+        .collect();
+
+    dropped_enums
+        .iter()
+        .filter_map(|previous_enum| {
+            let mut candidates = created_enums
+                .iter()
+                .filter(|next_enum| next_enum.values == previous_enum.values);
+            let next_enum = candidates.next()?;
+
+            let ambiguous = candidates.next().is_some()
+                || dropped_enums
+                    .iter()
+                    .filter(|dropped_enum| dropped_enum.values == next_enum.values)
+                    .count()
+                    > 1;
+
+            if ambiguous {
+                None
+            } else {
+                Some((*previous_enum, *next_enum))
+            }
+        })
+        .collect()
+}
@@ -31,6 +31,21 @@ pub(crate) trait SqlSchemaDifferFlavour {
         true
     }
 
+    /// Whether the differ should generate steps to distribute and undistribute Citus tables.
+    fn supports_distributed_tables(&self) -> bool {
+        false
+    }
+
+    /// Whether the differ should generate steps to add and drop system versioning on temporal tables.
+    fn supports_temporal_tables(&self) -> bool {
+        false
+    }
+
+    /// Whether enums are named types that can be renamed, instead of being dropped and created.
+    fn supports_enum_renaming(&self) -> bool {
+        false
+    }
+
     /// Return the tables that cannot be migrated without being redefined. This is currently useful only on SQLite.
     fn tables_to_redefine(&self, _differ: &SqlSchemaDiffer<'_>) -> HashSet<String> {
         HashSet::new()
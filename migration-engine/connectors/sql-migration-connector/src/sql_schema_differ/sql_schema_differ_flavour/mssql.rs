@@ -1,4 +1,8 @@
 use super::SqlSchemaDifferFlavour;
 use crate::flavour::MssqlFlavour;
 
-impl SqlSchemaDifferFlavour for MssqlFlavour {}
+impl SqlSchemaDifferFlavour for MssqlFlavour {
+    fn supports_temporal_tables(&self) -> bool {
+        true
+    }
+}
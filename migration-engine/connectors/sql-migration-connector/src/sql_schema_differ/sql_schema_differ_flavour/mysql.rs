@@ -1,11 +1,41 @@
 use super::SqlSchemaDifferFlavour;
-use crate::{flavour::MysqlFlavour, flavour::MYSQL_IDENTIFIER_SIZE_LIMIT, sql_schema_differ::ColumnDiffer};
-use sql_schema_describer::{ColumnTypeFamily, Index};
+use crate::{
+    flavour::MysqlFlavour,
+    flavour::MYSQL_IDENTIFIER_SIZE_LIMIT,
+    sql_migration::AlterEnum,
+    sql_schema_differ::{ColumnDiffer, SqlSchemaDiffer},
+};
+use sql_schema_describer::{mysql::extract_set_values, ColumnTypeFamily, Index};
 
 /// On MariaDB, JSON is an alias for LONGTEXT. https://mariadb.com/kb/en/json-data-type/
 const MARIADB_ALIASES: &[ColumnTypeFamily] = &[ColumnTypeFamily::String, ColumnTypeFamily::Json];
 
 impl SqlSchemaDifferFlavour for MysqlFlavour {
+    /// Created and dropped values are migrated by altering the columns using the enum, only the renamed values
+    /// need their own step.
+    fn alter_enums(&self, differ: &SqlSchemaDiffer<'_>) -> Vec<AlterEnum> {
+        differ
+            .enum_pairs()
+            .filter_map(|differ| {
+                let renamed_variants: Vec<(String, String)> = differ
+                    .renamed_values()
+                    .map(|(previous, next)| (previous.to_owned(), next.to_owned()))
+                    .collect();
+
+                if renamed_variants.is_empty() {
+                    return None;
+                }
+
+                Some(AlterEnum {
+                    name: differ.previous.name.clone(),
+                    created_variants: Vec::new(),
+                    dropped_variants: Vec::new(),
+                    renamed_variants,
+                })
+            })
+            .collect()
+    }
+
     fn column_type_changed(&self, differ: &ColumnDiffer<'_>) -> bool {
         if differ.database_info.is_mariadb()
             && MARIADB_ALIASES.contains(&differ.previous.column_type_family())
@@ -25,6 +55,15 @@ impl SqlSchemaDifferFlavour for MysqlFlavour {
             return previous_enum.values != next_enum.values;
         }
 
+        match (
+            extract_set_values(&differ.previous.column_type().full_data_type),
+            extract_set_values(&differ.next.column_type().full_data_type),
+        ) {
+            (Some(previous_values), Some(next_values)) => return previous_values != next_values,
+            (None, None) => (),
+            _ => return true,
+        }
+
         false
     }
 
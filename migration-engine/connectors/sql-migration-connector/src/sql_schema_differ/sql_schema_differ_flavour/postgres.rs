@@ -1,8 +1,12 @@
 use super::SqlSchemaDifferFlavour;
-use crate::{flavour::PostgresFlavour, sql_migration::AlterEnum, sql_schema_differ::SqlSchemaDiffer};
+use crate::{
+    flavour::PostgresFlavour,
+    sql_migration::AlterEnum,
+    sql_schema_differ::{renamed_enums, ColumnDiffer, SqlSchemaDiffer},
+};
 use once_cell::sync::Lazy;
 use regex::RegexSet;
-use sql_schema_describer::Index;
+use sql_schema_describer::{postgres::is_range_type, ColumnTypeFamily, Index};
 
 /// The maximum length of postgres identifiers, in bytes.
 ///
@@ -17,6 +21,10 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
                 let step = AlterEnum {
                     created_variants: differ.created_values().map(String::from).collect(),
                     dropped_variants: differ.dropped_values().map(String::from).collect(),
+                    renamed_variants: differ
+                        .renamed_values()
+                        .map(|(previous, next)| (previous.to_owned(), next.to_owned()))
+                        .collect(),
                     name: differ.previous.name.clone(),
                 };
 
@@ -29,6 +37,29 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
             .collect()
     }
 
+    fn column_type_changed(&self, differ: &ColumnDiffer<'_>) -> bool {
+        match (differ.previous.column_type_family(), differ.next.column_type_family()) {
+            // Columns follow their enum when it is renamed.
+            (ColumnTypeFamily::Enum(previous_enum), ColumnTypeFamily::Enum(next_enum))
+                if previous_enum != next_enum =>
+            {
+                !renamed_enums(differ.previous.schema, differ.next.schema)
+                    .iter()
+                    .any(|(previous, next)| &previous.name == previous_enum && &next.name == next_enum)
+            }
+            // Ranges are strings for Prisma, but changing the range type changes the column type.
+            (ColumnTypeFamily::String, ColumnTypeFamily::String) => {
+                // The full data type of array columns is the element type prefixed with an underscore.
+                let previous_type = differ.previous.column_type().full_data_type.trim_start_matches('_');
+                let next_type = differ.next.column_type().full_data_type.trim_start_matches('_');
+
+                (is_range_type(previous_type) || is_range_type(next_type))
+                    && !previous_type.eq_ignore_ascii_case(next_type)
+            }
+            (previous, next) => previous != next,
+        }
+    }
+
     fn index_should_be_renamed(&self, previous: &Index, next: &Index) -> bool {
         // Implements correct comparison for truncated index names.
         if previous.name.len() == POSTGRES_IDENTIFIER_SIZE_LIMIT && next.name.len() > POSTGRES_IDENTIFIER_SIZE_LIMIT {
@@ -38,6 +69,14 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
         }
     }
 
+    fn supports_distributed_tables(&self) -> bool {
+        true
+    }
+
+    fn supports_enum_renaming(&self) -> bool {
+        true
+    }
+
     fn table_should_be_ignored(&self, table_name: &str) -> bool {
         static POSTGRES_IGNORED_TABLES: Lazy<RegexSet> = Lazy::new(|| {
             RegexSet::new(&[
@@ -97,7 +97,7 @@ impl<'schema> TableDiffer<'schema> {
     pub(crate) fn created_primary_key(&self) -> Option<&'schema PrimaryKey> {
         match (self.previous.primary_key(), self.next.primary_key()) {
             (None, Some(pk)) => Some(pk),
-            (Some(previous_pk), Some(next_pk)) if previous_pk.columns != next_pk.columns => Some(next_pk),
+            (Some(previous_pk), Some(next_pk)) if primary_keys_differ(previous_pk, next_pk) => Some(next_pk),
             (Some(previous_pk), Some(next_pk)) => {
                 if self.primary_key_column_changed(previous_pk) {
                     Some(next_pk)
@@ -113,7 +113,7 @@ impl<'schema> TableDiffer<'schema> {
     pub(crate) fn dropped_primary_key(&self) -> Option<&'schema PrimaryKey> {
         match (self.previous.primary_key(), self.next.primary_key()) {
             (Some(pk), None) => Some(pk),
-            (Some(previous_pk), Some(next_pk)) if previous_pk.columns != next_pk.columns => Some(previous_pk),
+            (Some(previous_pk), Some(next_pk)) if primary_keys_differ(previous_pk, next_pk) => Some(previous_pk),
             (Some(previous_pk), Some(_next_pk)) => {
                 if self.primary_key_column_changed(previous_pk) {
                     Some(previous_pk)
@@ -168,5 +168,42 @@ pub(crate) fn columns_match(a: &ColumnWalker<'_>, b: &ColumnWalker<'_>) -> bool
 
 /// Compare two SQL indexes and return whether they only differ by name.
 fn indexes_match(first: &Index, second: &Index) -> bool {
-    first.columns == second.columns && first.tpe == second.tpe
+    first.columns == second.columns
+        && first.tpe == second.tpe
+        && first.clustered.unwrap_or(false) == second.clustered.unwrap_or(false)
+        && first.partial_predicate().map(normalize_predicate) == second.partial_predicate().map(normalize_predicate)
+        && first.nulls_not_distinct == second.nulls_not_distinct
+}
+
+/// The databases render the predicates of partial indexes their own way: Postgres wraps them in
+/// parentheses, SQL Server also quotes the columns in brackets. Compare them without the
+/// parentheses, quotes and whitespace, and case-insensitively outside of string literals.
+fn normalize_predicate(predicate: &str) -> String {
+    let mut normalized = String::with_capacity(predicate.len());
+    let mut in_string_literal = false;
+
+    for c in predicate.chars() {
+        match c {
+            '\'' => {
+                in_string_literal = !in_string_literal;
+                normalized.push(c);
+            }
+            _ if in_string_literal => normalized.push(c),
+            '(' | ')' | '[' | ']' | '"' | '`' => (),
+            c if c.is_whitespace() => {
+                if !normalized.ends_with(' ') && !normalized.is_empty() {
+                    normalized.push(' ');
+                }
+            }
+            c => normalized.extend(c.to_lowercase()),
+        }
+    }
+
+    normalized.trim_end().to_owned()
+}
+
+/// Primary keys are recreated when their columns or their clustering change. The clustering is only
+/// known on SQL Server, where primary keys are clustered by default.
+fn primary_keys_differ(previous: &PrimaryKey, next: &PrimaryKey) -> bool {
+    previous.columns != next.columns || previous.clustered.unwrap_or(true) != next.clustered.unwrap_or(true)
 }
@@ -0,0 +1,419 @@
+//! Splitting of migration scripts into the statements they are made of.
+//!
+//! Migration scripts can be edited by hand, so they may contain anything the database accepts:
+//! functions with dollar-quoted bodies, triggers and procedures with `BEGIN ... END` blocks,
+//! `DELIMITER` directives (MySQL) or `GO` batch separators (SQL Server). Splitting them on every
+//! semicolon breaks these, so the scripts are scanned with the quoting and comment rules of the
+//! dialect instead.
+
+use quaint::prelude::SqlFamily;
+
+/// Splits a script into the statements (batches on SQL Server) to send to the database one by one.
+///
+/// The statement delimiters are not part of the returned statements. Statements containing only
+/// whitespace and comments are skipped.
+pub fn split_sql_statements(sql_family: SqlFamily, script: &str) -> Vec<&str> {
+    let mut splitter = Splitter::new(sql_family, script);
+    splitter.run();
+    splitter.statements
+}
+
+/// Words that make a `CREATE` statement contain a body with `BEGIN ... END` blocks.
+const COMPOUND_STATEMENT_KINDS: &[&str] = &["trigger", "procedure", "function", "event"];
+
+/// Words that can come between `CREATE` and the kind of object created.
+const CREATE_MODIFIERS: &[&str] = &["or", "replace", "temp", "temporary", "aggregate", "constraint"];
+
+/// Words that follow `END` when it closes a control flow statement rather than a block.
+const END_QUALIFIERS: &[&str] = &["if", "loop", "while", "repeat"];
+
+/// What is known about the current statement from its leading words.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum StatementKind {
+    Start,
+    /// A `CREATE` statement whose object kind has not been seen yet. Counts the words of the
+    /// MySQL `DEFINER = user@host` clause, once it was seen.
+    Create {
+        definer_words: Option<usize>,
+    },
+    /// A statement that can contain `BEGIN ... END` blocks.
+    Compound,
+    Other,
+}
+
+struct Splitter<'a> {
+    sql_family: SqlFamily,
+    script: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+    statements: Vec<&'a str>,
+    /// Only settable on MySQL, with the `DELIMITER` directive.
+    delimiter: String,
+    statement_start: usize,
+    /// Whether the current statement contains anything besides whitespace and comments.
+    has_content: bool,
+    kind: StatementKind,
+    block_depth: usize,
+    /// An `END` whose meaning depends on the word following it.
+    pending_end: bool,
+}
+
+impl<'a> Splitter<'a> {
+    fn new(sql_family: SqlFamily, script: &'a str) -> Self {
+        Splitter {
+            sql_family,
+            script,
+            bytes: script.as_bytes(),
+            pos: 0,
+            statements: Vec::new(),
+            delimiter: ";".to_owned(),
+            statement_start: 0,
+            has_content: false,
+            kind: StatementKind::Start,
+            block_depth: 0,
+            pending_end: false,
+        }
+    }
+
+    fn run(&mut self) {
+        while self.pos < self.bytes.len() {
+            if self.at_line_start() && self.try_directive() {
+                continue;
+            }
+
+            if self.at_delimiter() {
+                let end = self.pos;
+                self.pos += self.delimiter.len();
+                self.finish_statement(end, self.pos);
+                continue;
+            }
+
+            let byte = self.bytes[self.pos];
+
+            match byte {
+                b'-' if self.peek(1) == Some(b'-') => self.skip_line(),
+                b'#' if self.sql_family == SqlFamily::Mysql => self.skip_line(),
+                // MySQL runs the contents of `/*! ... */` comments.
+                b'/' if self.peek(1) == Some(b'*') && self.peek(2) == Some(b'!') && self.is_mysql() => {
+                    self.mark_content();
+                    self.skip_block_comment();
+                }
+                b'/' if self.peek(1) == Some(b'*') => self.skip_block_comment(),
+                b'\'' => {
+                    self.mark_content();
+                    let backslash_escapes = self.is_mysql() || self.is_postgres_escape_string();
+                    self.skip_quoted(b'\'', backslash_escapes);
+                }
+                b'"' => {
+                    self.mark_content();
+                    self.skip_quoted(b'"', self.is_mysql());
+                }
+                b'`' if self.is_mysql() || self.sql_family == SqlFamily::Sqlite => {
+                    self.mark_content();
+                    self.skip_quoted(b'`', false);
+                }
+                b'[' if self.sql_family == SqlFamily::Mssql || self.sql_family == SqlFamily::Sqlite => {
+                    self.mark_content();
+                    self.skip_quoted(b']', false);
+                }
+                b'$' if self.sql_family == SqlFamily::Postgres && self.try_skip_dollar_quoted() => (),
+                byte if is_word_byte(byte) => self.read_word(),
+                byte if byte.is_ascii_whitespace() => self.pos += 1,
+                _ => {
+                    self.mark_content();
+                    self.resolve_pending_end();
+                    self.pos += 1;
+                }
+            }
+        }
+
+        self.finish_statement(self.bytes.len(), self.bytes.len());
+    }
+
+    fn is_mysql(&self) -> bool {
+        self.sql_family == SqlFamily::Mysql
+    }
+
+    fn peek(&self, offset: usize) -> Option<u8> {
+        self.bytes.get(self.pos + offset).copied()
+    }
+
+    fn at_line_start(&self) -> bool {
+        self.pos == 0 || self.bytes[self.pos - 1] == b'\n'
+    }
+
+    fn at_delimiter(&self) -> bool {
+        if self.sql_family == SqlFamily::Mssql || !self.bytes[self.pos..].starts_with(self.delimiter.as_bytes()) {
+            return false;
+        }
+
+        // A custom delimiter is there precisely to end statements containing semicolons.
+        self.delimiter != ";" || self.open_blocks() == 0
+    }
+
+    fn open_blocks(&self) -> usize {
+        if self.pending_end {
+            self.block_depth.saturating_sub(1)
+        } else {
+            self.block_depth
+        }
+    }
+
+    fn mark_content(&mut self) {
+        self.has_content = true;
+    }
+
+    /// Handles the client-side `GO` (SQL Server) and `DELIMITER` (MySQL) directives.
+    fn try_directive(&mut self) -> bool {
+        let line_end = self.bytes[self.pos..]
+            .iter()
+            .position(|b| *b == b'\n')
+            .map(|idx| self.pos + idx + 1)
+            .unwrap_or_else(|| self.bytes.len());
+        let words: Vec<&str> = self.script[self.pos..line_end].split_whitespace().collect();
+        let first_word = words.first().copied().unwrap_or("");
+
+        match self.sql_family {
+            // `GO` can be followed by a repetition count, which is ignored.
+            SqlFamily::Mssql
+                if first_word.eq_ignore_ascii_case("go")
+                    && words[1..].iter().all(|count| count.bytes().all(|b| b.is_ascii_digit())) =>
+            {
+                let end = self.pos;
+                self.pos = line_end;
+                self.finish_statement(end, line_end);
+                true
+            }
+            SqlFamily::Mysql if !self.has_content && first_word.eq_ignore_ascii_case("delimiter") => {
+                if let Some(delimiter) = words.get(1) {
+                    self.delimiter = (*delimiter).to_owned();
+                }
+
+                self.pos = line_end;
+                self.statement_start = line_end;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn skip_line(&mut self) {
+        while self.pos < self.bytes.len() && self.bytes[self.pos] != b'\n' {
+            self.pos += 1;
+        }
+    }
+
+    /// Block comments nest on Postgres.
+    fn skip_block_comment(&mut self) {
+        let nests = self.sql_family == SqlFamily::Postgres;
+        let mut depth = 0;
+
+        while self.pos < self.bytes.len() {
+            if self.bytes[self.pos..].starts_with(b"/*") && (nests || depth == 0) {
+                depth += 1;
+                self.pos += 2;
+            } else if self.bytes[self.pos..].starts_with(b"*/") {
+                depth -= 1;
+                self.pos += 2;
+
+                if depth == 0 {
+                    return;
+                }
+            } else {
+                self.pos += 1;
+            }
+        }
+    }
+
+    /// Skips a quoted string or identifier. A doubled closing quote is an escaped quote.
+    fn skip_quoted(&mut self, closing_quote: u8, backslash_escapes: bool) {
+        self.pos += 1;
+
+        while self.pos < self.bytes.len() {
+            let byte = self.bytes[self.pos];
+
+            let is_escape =
+                (backslash_escapes && byte == b'\\') || (byte == closing_quote && self.peek(1) == Some(closing_quote));
+
+            if is_escape {
+                self.pos += 2;
+            } else if byte == closing_quote {
+                self.pos += 1;
+                return;
+            } else {
+                self.pos += 1;
+            }
+        }
+
+        self.pos = self.bytes.len();
+    }
+
+    /// Postgres only interprets backslashes in `E'...'` strings.
+    fn is_postgres_escape_string(&self) -> bool {
+        if self.sql_family != SqlFamily::Postgres || self.pos == 0 {
+            return false;
+        }
+
+        let prefix = self.bytes[self.pos - 1];
+        let before_prefix = if self.pos >= 2 {
+            Some(self.bytes[self.pos - 2])
+        } else {
+            None
+        };
+
+        (prefix == b'e' || prefix == b'E') && !before_prefix.map(is_word_byte).unwrap_or(false)
+    }
+
+    /// Skips a `$tag$ ... $tag$` string. Returns false if the `$` does not start one, e.g. in `$1`.
+    fn try_skip_dollar_quoted(&mut self) -> bool {
+        if self.pos > 0 && is_word_byte(self.bytes[self.pos - 1]) {
+            return false;
+        }
+
+        let tag_len = self.bytes[self.pos + 1..]
+            .iter()
+            .take_while(|b| is_word_byte(**b) && **b != b'$')
+            .count();
+        let tag_end = self.pos + 1 + tag_len;
+
+        if self.bytes.get(tag_end) != Some(&b'$') || self.peek(1).map(|b| b.is_ascii_digit()).unwrap_or(false) {
+            return false;
+        }
+
+        let tag = &self.script[self.pos..=tag_end];
+        let body_start = tag_end + 1;
+
+        self.mark_content();
+        self.pos = match self.script[body_start..].find(tag) {
+            Some(idx) => body_start + idx + tag.len(),
+            None => self.bytes.len(),
+        };
+
+        true
+    }
+
+    fn read_word(&mut self) {
+        let start = self.pos;
+
+        while self.pos < self.bytes.len() && is_word_byte(self.bytes[self.pos]) {
+            self.pos += 1;
+        }
+
+        let word = self.script[start..self.pos].to_ascii_lowercase();
+
+        self.mark_content();
+        self.track_statement_kind(&word);
+
+        if self.kind == StatementKind::Compound {
+            self.track_blocks(&word);
+        }
+    }
+
+    fn track_statement_kind(&mut self, word: &str) {
+        self.kind = match self.kind {
+            StatementKind::Start if word == "create" => StatementKind::Create { definer_words: None },
+            StatementKind::Start => StatementKind::Other,
+            StatementKind::Create { .. } if COMPOUND_STATEMENT_KINDS.contains(&word) => StatementKind::Compound,
+            StatementKind::Create { .. } if word == "definer" => StatementKind::Create { definer_words: Some(0) },
+            StatementKind::Create { .. } if CREATE_MODIFIERS.contains(&word) => self.kind,
+            StatementKind::Create {
+                definer_words: Some(count),
+            } if count < 2 => StatementKind::Create {
+                definer_words: Some(count + 1),
+            },
+            StatementKind::Create { .. } => StatementKind::Other,
+            kind => kind,
+        };
+    }
+
+    fn track_blocks(&mut self, word: &str) {
+        if self.pending_end {
+            self.pending_end = false;
+
+            if END_QUALIFIERS.contains(&word) {
+                return;
+            }
+
+            self.block_depth = self.block_depth.saturating_sub(1);
+
+            // `END CASE` closes a CASE statement.
+            if word == "case" {
+                return;
+            }
+        }
+
+        match word {
+            "begin" | "case" => self.block_depth += 1,
+            "end" if self.block_depth > 0 => self.pending_end = true,
+            _ => (),
+        }
+    }
+
+    fn resolve_pending_end(&mut self) {
+        if self.pending_end {
+            self.pending_end = false;
+            self.block_depth = self.block_depth.saturating_sub(1);
+        }
+    }
+
+    /// Ends the current statement at `end`, the next one starts at `next_start`.
+    fn finish_statement(&mut self, end: usize, next_start: usize) {
+        if self.has_content {
+            self.statements.push(self.script[self.statement_start..end].trim());
+        }
+
+        self.statement_start = next_start;
+        self.has_content = false;
+        self.kind = StatementKind::Start;
+        self.block_depth = 0;
+        self.pending_end = false;
+    }
+}
+
+/// Bytes that can be part of unquoted identifiers and keywords. Non-ASCII bytes are always part of
+/// an identifier, so the scanner never stops in the middle of a multi-byte character.
+fn is_word_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'$' || byte >= 0x80
+}
+
+/// Whether a statement of a split script can run in a transaction on Postgres. Statements that control the
+/// transaction themselves, like `BEGIN` or `COMMIT`, and statements Postgres refuses to run in a transaction
+/// block, like `CREATE INDEX CONCURRENTLY` or `VACUUM`, can not.
+pub fn can_run_in_postgres_transaction(statement: &str) -> bool {
+    let words = leading_words(statement, 6);
+    let words: Vec<&str> = words.iter().map(String::as_str).collect();
+
+    match words.as_slice() {
+        ["begin", ..] | ["start", "transaction", ..] | ["commit", ..] | ["end", ..] | ["rollback", ..] => false,
+        ["abort", ..] | ["prepare", "transaction", ..] => false,
+        ["vacuum", ..] | ["alter", "system", ..] => false,
+        ["create", "database", ..] | ["drop", "database", ..] => false,
+        ["create", "tablespace", ..] | ["drop", "tablespace", ..] => false,
+        ["reindex", rest @ ..] => !rest
+            .iter()
+            .any(|word| ["concurrently", "database", "system"].contains(word)),
+        ["create", rest @ ..] | ["drop", rest @ ..] if rest.contains(&"index") => !rest.contains(&"concurrently"),
+        _ => true,
+    }
+}
+
+/// The first `count` words of a statement, lowercased, skipping the comments in front of it.
+fn leading_words(statement: &str, count: usize) -> Vec<String> {
+    let mut rest = statement.trim_start();
+
+    loop {
+        if rest.starts_with("--") {
+            rest = rest.find('\n').map(|end| &rest[end..]).unwrap_or("").trim_start();
+        } else if rest.starts_with("/*") {
+            rest = rest.find("*/").map(|end| &rest[end + 2..]).unwrap_or("").trim_start();
+        } else {
+            break;
+        }
+    }
+
+    rest.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .filter(|word| !word.is_empty())
+        .take(count)
+        .map(|word| word.to_ascii_lowercase())
+        .collect()
+}
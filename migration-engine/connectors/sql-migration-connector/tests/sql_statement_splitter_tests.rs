@@ -0,0 +1,222 @@
+use quaint::prelude::SqlFamily;
+use sql_migration_connector::sql_statement_splitter::{can_run_in_postgres_transaction, split_sql_statements};
+
+#[test]
+fn simple_statements_are_split_on_semicolons() {
+    let script = r#"
+        -- CreateTable
+        CREATE TABLE "Cat" ("id" INTEGER NOT NULL, PRIMARY KEY ("id"));
+
+        -- CreateTable
+        CREATE TABLE "Dog" ("id" INTEGER NOT NULL, PRIMARY KEY ("id"));
+    "#;
+
+    for sql_family in &[SqlFamily::Postgres, SqlFamily::Mysql, SqlFamily::Sqlite] {
+        assert_eq!(
+            split_sql_statements(*sql_family, script),
+            &[
+                "-- CreateTable\n        CREATE TABLE \"Cat\" (\"id\" INTEGER NOT NULL, PRIMARY KEY (\"id\"))",
+                "-- CreateTable\n        CREATE TABLE \"Dog\" (\"id\" INTEGER NOT NULL, PRIMARY KEY (\"id\"))",
+            ]
+        );
+    }
+}
+
+#[test]
+fn comment_only_statements_are_skipped() {
+    let script = r#"
+        -- This is an empty migration.
+        /* Nothing; to see; here */
+        ;
+    "#;
+
+    for sql_family in &[SqlFamily::Postgres, SqlFamily::Mysql, SqlFamily::Sqlite] {
+        assert!(split_sql_statements(*sql_family, script).is_empty());
+    }
+}
+
+#[test]
+fn semicolons_in_strings_identifiers_and_comments_do_not_end_statements() {
+    let script = r#"
+        INSERT INTO "a;b" ("c") VALUES ('it''s; fine'); -- trailing; comment
+        /* block; comment */ SELECT 'x';
+    "#;
+
+    assert_eq!(
+        split_sql_statements(SqlFamily::Postgres, script),
+        &[
+            r#"INSERT INTO "a;b" ("c") VALUES ('it''s; fine')"#,
+            "-- trailing; comment\n        /* block; comment */ SELECT 'x'",
+        ]
+    );
+}
+
+#[test]
+fn postgres_dollar_quoted_function_bodies_are_not_split() {
+    let script = r#"
+        CREATE FUNCTION "set_updated_at"() RETURNS TRIGGER AS $$
+        BEGIN
+            NEW."updatedAt" = now();
+            RETURN NEW;
+        END;
+        $$ LANGUAGE plpgsql;
+
+        CREATE TRIGGER "cat_updated_at" BEFORE UPDATE ON "Cat"
+            FOR EACH ROW EXECUTE PROCEDURE "set_updated_at"();
+
+        DO $body$ BEGIN RAISE NOTICE 'a $$ b; c'; END $body$;
+    "#;
+
+    let statements = split_sql_statements(SqlFamily::Postgres, script);
+
+    assert_eq!(statements.len(), 3);
+    assert!(statements[0].starts_with("CREATE FUNCTION"));
+    assert!(statements[0].ends_with("$$ LANGUAGE plpgsql"));
+    assert!(statements[1].starts_with("CREATE TRIGGER"));
+    assert_eq!(statements[2], "DO $body$ BEGIN RAISE NOTICE 'a $$ b; c'; END $body$");
+}
+
+#[test]
+fn postgres_escape_strings_and_nested_comments_are_handled() {
+    let script = r#"
+        SELECT E'it\'s; escaped', $1;
+        /* outer /* inner; */ still a comment; */
+        SELECT 'backslash\';
+    "#;
+
+    assert_eq!(
+        split_sql_statements(SqlFamily::Postgres, script),
+        &[
+            r#"SELECT E'it\'s; escaped', $1"#,
+            "/* outer /* inner; */ still a comment; */\n        SELECT 'backslash\\'",
+        ]
+    );
+}
+
+#[test]
+fn sqlite_trigger_bodies_are_not_split() {
+    let script = r#"
+        CREATE TRIGGER "cat_updated_at" AFTER UPDATE ON "Cat"
+        BEGIN
+            UPDATE "Cat" SET "updatedAt" = CURRENT_TIMESTAMP WHERE "id" = NEW."id";
+            SELECT CASE WHEN NEW."id" < 0 THEN RAISE(ABORT, 'negative; id') END;
+        END;
+
+        BEGIN;
+        DELETE FROM "Cat";
+        COMMIT;
+    "#;
+
+    let statements = split_sql_statements(SqlFamily::Sqlite, script);
+
+    assert_eq!(statements.len(), 4);
+    assert!(statements[0].starts_with("CREATE TRIGGER"));
+    assert!(statements[0].ends_with("END"));
+    assert_eq!(&statements[1..], &["BEGIN", "DELETE FROM \"Cat\"", "COMMIT"]);
+}
+
+#[test]
+fn mysql_procedures_are_not_split() {
+    let script = r#"
+        CREATE DEFINER=`root`@`localhost` PROCEDURE `adopt`(IN cat_id INT)
+        BEGIN
+            IF cat_id > 0 THEN
+                UPDATE `Cat` SET `adopted` = TRUE WHERE `id` = cat_id;
+            END IF;
+            my_loop: LOOP
+                LEAVE my_loop;
+            END LOOP my_loop;
+            CASE cat_id WHEN 1 THEN SELECT 'one;'; ELSE SELECT "other\";"; END CASE;
+        END;
+
+        # A hash comment; with a semicolon
+        SELECT 1;
+    "#;
+
+    let statements = split_sql_statements(SqlFamily::Mysql, script);
+
+    assert_eq!(statements.len(), 2);
+    assert!(statements[0].starts_with("CREATE DEFINER"));
+    assert!(statements[0].ends_with("END"));
+    assert_eq!(statements[1], "# A hash comment; with a semicolon\n        SELECT 1");
+}
+
+#[test]
+fn mysql_delimiter_directives_are_respected() {
+    let script = r#"
+DELIMITER //
+CREATE TRIGGER `cat_insert` BEFORE INSERT ON `Cat` FOR EACH ROW
+BEGIN
+    SET NEW.`name` = TRIM(NEW.`name`);
+END//
+DELIMITER ;
+/*!40101 SET NAMES utf8mb4 */;
+SELECT 1;
+    "#;
+
+    let statements = split_sql_statements(SqlFamily::Mysql, script);
+
+    assert_eq!(statements.len(), 3);
+    assert!(statements[0].starts_with("CREATE TRIGGER"));
+    assert!(statements[0].ends_with("END"));
+    assert_eq!(&statements[1..], &["/*!40101 SET NAMES utf8mb4 */", "SELECT 1"]);
+}
+
+#[test]
+fn mssql_scripts_are_split_on_go_batch_separators() {
+    let script = r#"
+CREATE TABLE [dbo].[Cat] ([id] INT NOT NULL, CONSTRAINT [Cat_pkey] PRIMARY KEY ([id]));
+GO
+CREATE PROCEDURE [dbo].[adopt] @id INT AS
+BEGIN
+    -- GO
+    UPDATE [dbo].[Cat] SET [name] = 'GO
+' WHERE [id] = @id;
+END
+  go 2
+SELECT 1;
+    "#;
+
+    let statements = split_sql_statements(SqlFamily::Mssql, script);
+
+    assert_eq!(statements.len(), 3);
+    assert!(statements[0].starts_with("CREATE TABLE"));
+    assert!(statements[1].starts_with("CREATE PROCEDURE"));
+    assert!(statements[1].ends_with("END"));
+    assert_eq!(statements[2], "SELECT 1;");
+}
+
+#[test]
+fn postgres_transaction_control_and_concurrent_index_creation_can_not_run_in_a_transaction() {
+    let statements = &[
+        "BEGIN",
+        "-- Start the work\nstart transaction isolation level serializable",
+        "COMMIT;",
+        "/* done */ END",
+        "CREATE INDEX CONCURRENTLY \"Cat_name_idx\" ON \"Cat\"(\"name\")",
+        "create unique index concurrently if not exists \"Cat_name_key\" on \"Cat\"(\"name\")",
+        "DROP INDEX CONCURRENTLY \"Cat_name_idx\"",
+        "REINDEX TABLE CONCURRENTLY \"Cat\"",
+        "VACUUM ANALYZE \"Cat\"",
+        "CREATE DATABASE \"cats\"",
+    ];
+
+    for statement in statements {
+        assert!(!can_run_in_postgres_transaction(statement), "{}", statement);
+    }
+}
+
+#[test]
+fn regular_postgres_statements_can_run_in_a_transaction() {
+    let statements = &[
+        "-- CreateTable\nCREATE TABLE \"Cat\" (\"id\" INTEGER NOT NULL, PRIMARY KEY (\"id\"))",
+        "CREATE INDEX \"Cat_name_idx\" ON \"Cat\"(\"name\")",
+        "REINDEX TABLE \"Cat\"",
+        "CREATE FUNCTION meow() RETURNS trigger AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql",
+        "INSERT INTO \"Cat\" (\"id\") VALUES (1)",
+    ];
+
+    for statement in statements {
+        assert!(can_run_in_postgres_transaction(statement), "{}", statement);
+    }
+}
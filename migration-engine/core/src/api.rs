@@ -57,14 +57,21 @@ pub trait GenericApi: Send + Sync + 'static {
         &self,
         input: &DiagnoseMigrationHistoryInput,
     ) -> CoreResult<DiagnoseMigrationHistoryOutput>;
+    async fn diff(&self, input: &DiffInput) -> CoreResult<DiffOutput>;
     async fn infer_migration_steps(&self, input: &InferMigrationStepsInput) -> CoreResult<MigrationStepsResultOutput>;
     async fn initialize(&self, input: &InitializeInput) -> CoreResult<InitializeOutput>;
     async fn list_migrations(&self, input: &serde_json::Value) -> CoreResult<Vec<ListMigrationsOutput>>;
     async fn migration_progress(&self, input: &MigrationProgressInput) -> CoreResult<MigrationProgressOutput>;
     async fn plan_migration(&self, input: &PlanMigrationInput) -> CoreResult<PlanMigrationOutput>;
     async fn reset(&self, input: &()) -> CoreResult<()>;
+    async fn rollback_migration(&self, input: &RollbackMigrationInput) -> CoreResult<RollbackMigrationOutput>;
     async fn schema_push(&self, input: &SchemaPushInput) -> CoreResult<SchemaPushOutput>;
+    async fn squash_migrations(&self, input: &SquashMigrationsInput) -> CoreResult<SquashMigrationsOutput>;
     async fn unapply_migration(&self, input: &UnapplyMigrationInput) -> CoreResult<UnapplyMigrationOutput>;
+    async fn verify_migration_checksums(
+        &self,
+        input: &VerifyMigrationChecksumsInput,
+    ) -> CoreResult<VerifyMigrationChecksumsOutput>;
     fn migration_persistence<'a>(&'a self) -> Box<dyn MigrationPersistence + 'a>;
     fn connector_type(&self) -> &'static str;
 
@@ -143,6 +150,12 @@ where
             .await
     }
 
+    async fn diff(&self, input: &DiffInput) -> CoreResult<DiffOutput> {
+        self.handle_command::<DiffCommand>(input)
+            .instrument(tracing::info_span!("Diff"))
+            .await
+    }
+
     async fn infer_migration_steps(&self, input: &InferMigrationStepsInput) -> CoreResult<MigrationStepsResultOutput> {
         self.handle_command::<InferMigrationStepsCommand<'_>>(input)
             .instrument(tracing::info_span!(
@@ -188,18 +201,43 @@ where
             .await
     }
 
+    async fn rollback_migration(&self, input: &RollbackMigrationInput) -> CoreResult<RollbackMigrationOutput> {
+        self.handle_command::<RollbackMigrationCommand>(input)
+            .instrument(tracing::info_span!("RollbackMigration"))
+            .await
+    }
+
     async fn schema_push(&self, input: &SchemaPushInput) -> CoreResult<SchemaPushOutput> {
         self.handle_command::<SchemaPushCommand>(input)
             .instrument(tracing::info_span!("SchemaPush"))
             .await
     }
 
+    async fn squash_migrations(&self, input: &SquashMigrationsInput) -> CoreResult<SquashMigrationsOutput> {
+        self.handle_command::<SquashMigrationsCommand>(input)
+            .instrument(tracing::info_span!(
+                "SquashMigrations",
+                from_migration_name = input.from_migration_name.as_str(),
+                to_migration_name = input.to_migration_name.as_str()
+            ))
+            .await
+    }
+
     async fn unapply_migration(&self, input: &UnapplyMigrationInput) -> CoreResult<UnapplyMigrationOutput> {
         self.handle_command::<UnapplyMigrationCommand<'_>>(input)
             .instrument(tracing::info_span!("UnapplyMigration"))
             .await
     }
 
+    async fn verify_migration_checksums(
+        &self,
+        input: &VerifyMigrationChecksumsInput,
+    ) -> CoreResult<VerifyMigrationChecksumsOutput> {
+        self.handle_command::<VerifyMigrationChecksumsCommand>(input)
+            .instrument(tracing::info_span!("VerifyMigrationChecksums"))
+            .await
+    }
+
     fn migration_persistence<'a>(&'a self) -> Box<dyn MigrationPersistence + 'a> {
         self.engine.connector().migration_persistence()
     }
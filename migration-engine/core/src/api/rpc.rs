@@ -18,6 +18,7 @@ enum RpcCommand {
     CreateMigration,
     DebugPanic,
     DiagnoseMigrationHistory,
+    Diff,
     InferMigrationSteps,
     Initialize,
     ListMigrations,
@@ -26,7 +27,10 @@ enum RpcCommand {
     ApplyMigration,
     UnapplyMigration,
     Reset,
+    RollbackMigration,
     SchemaPush,
+    SquashMigrations,
+    VerifyMigrationChecksums,
     CalculateDatamodel,
     CalculateDatabaseSteps,
 }
@@ -39,6 +43,7 @@ impl RpcCommand {
             RpcCommand::CreateMigration => "createMigration",
             RpcCommand::DebugPanic => "debugPanic",
             RpcCommand::DiagnoseMigrationHistory => "diagnoseMigrationHistory",
+            RpcCommand::Diff => "diff",
             RpcCommand::InferMigrationSteps => "inferMigrationSteps",
             RpcCommand::ListMigrations => "listMigrations",
             RpcCommand::MigrationProgress => "migrationProgress",
@@ -47,7 +52,10 @@ impl RpcCommand {
             RpcCommand::Initialize => "initialize",
             RpcCommand::PlanMigration => "planMigration",
             RpcCommand::Reset => "reset",
+            RpcCommand::RollbackMigration => "rollbackMigration",
             RpcCommand::SchemaPush => "schemaPush",
+            RpcCommand::SquashMigrations => "squashMigrations",
+            RpcCommand::VerifyMigrationChecksums => "verifyMigrationChecksums",
             RpcCommand::CalculateDatamodel => "calculateDatamodel",
             RpcCommand::CalculateDatabaseSteps => "calculateDatabaseSteps",
         }
@@ -61,6 +69,7 @@ const AVAILABLE_COMMANDS: &[RpcCommand] = &[
     RpcCommand::CreateMigration,
     RpcCommand::DiagnoseMigrationHistory,
     RpcCommand::DebugPanic,
+    RpcCommand::Diff,
     RpcCommand::InferMigrationSteps,
     RpcCommand::Initialize,
     RpcCommand::ListMigrations,
@@ -68,16 +77,24 @@ const AVAILABLE_COMMANDS: &[RpcCommand] = &[
     RpcCommand::PlanMigration,
     RpcCommand::UnapplyMigration,
     RpcCommand::Reset,
+    RpcCommand::RollbackMigration,
     RpcCommand::SchemaPush,
+    RpcCommand::SquashMigrations,
+    RpcCommand::VerifyMigrationChecksums,
     RpcCommand::CalculateDatamodel,
     RpcCommand::CalculateDatabaseSteps,
 ];
 
 impl RpcApi {
-    pub async fn new(datamodel: &str) -> CoreResult<Self> {
+    pub async fn new(
+        datamodel: &str,
+        migrations_history: crate::MigrationsHistoryStorage,
+        shadow_database_url: Option<&str>,
+    ) -> CoreResult<Self> {
         let mut rpc_api = Self {
             io_handler: IoHandler::default(),
-            executor: crate::migration_api(datamodel).await?,
+            executor: crate::migration_api_with_migrations_history(datamodel, migrations_history, shadow_database_url)
+                .await?,
         };
 
         for cmd in AVAILABLE_COMMANDS {
@@ -155,6 +172,10 @@ impl RpcApi {
                 let input: DiagnoseMigrationHistoryInput = params.clone().parse()?;
                 render(executor.diagnose_migration_history(&input).await?)
             }
+            RpcCommand::Diff => {
+                let input: DiffInput = params.clone().parse()?;
+                render(executor.diff(&input).await?)
+            }
             RpcCommand::InferMigrationSteps => {
                 let input: InferMigrationStepsInput = params.clone().parse()?;
                 render(executor.infer_migration_steps(&input).await?)
@@ -183,10 +204,22 @@ impl RpcApi {
                 render(executor.unapply_migration(&input).await?)
             }
             RpcCommand::Reset => render(executor.reset(&()).await?),
+            RpcCommand::RollbackMigration => {
+                let input: RollbackMigrationInput = params.clone().parse()?;
+                render(executor.rollback_migration(&input).await?)
+            }
             RpcCommand::SchemaPush => {
                 let input: SchemaPushInput = params.clone().parse()?;
                 render(executor.schema_push(&input).await?)
             }
+            RpcCommand::SquashMigrations => {
+                let input: SquashMigrationsInput = params.clone().parse()?;
+                render(executor.squash_migrations(&input).await?)
+            }
+            RpcCommand::VerifyMigrationChecksums => {
+                let input: VerifyMigrationChecksumsInput = params.clone().parse()?;
+                render(executor.verify_migration_checksums(&input).await?)
+            }
             RpcCommand::CalculateDatamodel => {
                 let input: CalculateDatamodelInput = params.clone().parse()?;
                 render(executor.calculate_datamodel(&input).await?)
@@ -14,6 +14,7 @@ mod command;
 mod create_migration;
 mod debug_panic;
 mod diagnose_migration_history;
+mod diff;
 mod get_database_version;
 #[allow(missing_docs)]
 mod infer_migration_steps;
@@ -24,9 +25,12 @@ mod list_migrations;
 mod migration_progress;
 mod plan_migration;
 mod reset;
+mod rollback_migration;
 mod schema_push;
+mod squash_migrations;
 #[allow(missing_docs)]
 mod unapply_migration;
+mod verify_migration_checksums;
 
 pub use apply_migration::*;
 pub use apply_migrations::{ApplyMigrationsCommand, ApplyMigrationsInput, ApplyMigrationsOutput};
@@ -38,6 +42,7 @@ pub use debug_panic::DebugPanicCommand;
 pub use diagnose_migration_history::{
     DiagnoseMigrationHistoryCommand, DiagnoseMigrationHistoryInput, DiagnoseMigrationHistoryOutput, HistoryDiagnostic,
 };
+pub use diff::{DiffCommand, DiffInput, DiffOutput, DiffSource};
 pub use get_database_version::*;
 pub use infer_migration_steps::*;
 pub use initialize::{InitializeCommand, InitializeInput, InitializeOutput};
@@ -45,8 +50,13 @@ pub use list_migrations::*;
 pub use migration_progress::*;
 pub use plan_migration::{PlanMigrationCommand, PlanMigrationInput, PlanMigrationOutput};
 pub use reset::ResetCommand;
+pub use rollback_migration::{RollbackMigrationCommand, RollbackMigrationInput, RollbackMigrationOutput};
 pub use schema_push::{SchemaPushCommand, SchemaPushInput, SchemaPushOutput};
+pub use squash_migrations::{SquashMigrationsCommand, SquashMigrationsInput, SquashMigrationsOutput};
 pub use unapply_migration::*;
+pub use verify_migration_checksums::{
+    EditedMigration, VerifyMigrationChecksumsCommand, VerifyMigrationChecksumsInput, VerifyMigrationChecksumsOutput,
+};
 
 use migration_connector::{
     MigrationError, MigrationStep, MigrationWarning, PrettyDatabaseMigrationStep, UnexecutableMigration,
@@ -2,8 +2,9 @@ use std::path::Path;
 
 use super::{CommandError, CommandResult, MigrationCommand};
 use crate::migration_engine::MigrationEngine;
-use migration_connector::{ConnectorError, MigrationDirectory, MigrationRecord};
+use migration_connector::{ConnectorError, MigrationDirectory, MigrationRecord, MigrationTimeouts};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// The input to the `ApplyMigrations` command.
 #[derive(Deserialize, Debug)]
@@ -11,6 +12,13 @@ use serde::{Deserialize, Serialize};
 pub struct ApplyMigrationsInput {
     /// The location of the migrations directory.
     pub migrations_directory_path: String,
+    /// How long, in milliseconds, each statement may wait for locks held by other queries. A
+    /// migration canceled by a timeout fails with a retryable error.
+    #[serde(default)]
+    pub lock_timeout: Option<u64>,
+    /// How long, in milliseconds, each statement may run. Not enforced on MySQL.
+    #[serde(default)]
+    pub statement_timeout: Option<u64>,
 }
 
 /// The output of the `ApplyMigrations` command.
@@ -43,7 +51,7 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
 
         let migrations_from_filesystem =
             migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
-        let migrations_from_database = migration_persistence.list_migrations().await?;
+        let migrations_from_database = migration_persistence.list_applied_migrations().await?;
 
         diagnose_migration_history(&migrations_from_database, &migrations_from_filesystem)?;
 
@@ -58,6 +66,10 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
             })
             .collect();
 
+        let timeouts = MigrationTimeouts {
+            lock_timeout: input.lock_timeout.map(Duration::from_millis),
+            statement_timeout: input.statement_timeout.map(Duration::from_millis),
+        };
         let mut applied_migration_names: Vec<String> = Vec::new();
 
         for unapplied_migration in unapplied_migrations {
@@ -75,7 +87,7 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
                 .record_migration_started(unapplied_migration.migration_name(), &script)
                 .await?;
 
-            match applier.apply_script(&script).await {
+            match applier.apply_script(&script, &timeouts).await {
                 Ok(()) => {
                     tracing::debug!("Successfully applied the script.");
                     migration_persistence
@@ -2,9 +2,11 @@ use std::path::Path;
 
 use super::{CommandError, MigrationCommand};
 use crate::{migration_engine::MigrationEngine, parse_datamodel};
+use migration_connector::{DiffTarget, EnumValueRenames};
 use serde::{Deserialize, Serialize};
 
-/// Create and potentially apply a new migration.
+/// Create and potentially apply a new migration. The migration directory also gets a down migration
+/// script, reverting the migration, for `rollbackMigration`.
 pub struct CreateMigrationCommand;
 
 /// The input to the `createMigration` command.
@@ -19,6 +21,14 @@ pub struct CreateMigrationInput {
     pub migration_name: String,
     /// If true, always generate a migration, but do not apply.
     pub draft: bool,
+    /// Enum values to rename instead of dropping and creating them, by enum name, from the previous to
+    /// the next value name.
+    #[serde(default)]
+    pub enum_value_renames: EnumValueRenames,
+    /// Whether the statements of the migration scripts should be guarded with existence checks, so a
+    /// partially applied migration can be run again.
+    #[serde(default)]
+    pub idempotent: bool,
 }
 
 /// The output of the `createMigration` command.
@@ -49,7 +59,7 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
         let target_schema = parse_datamodel(&input.prisma_schema)?;
 
         let migration = database_migration_inferrer
-            .infer_next_migration(&previous_migrations, &target_schema)
+            .infer_next_migration(&previous_migrations, &target_schema, &input.enum_value_renames)
             .await?;
 
         if migration.is_empty() && !input.draft {
@@ -60,7 +70,24 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
 
         let destructive_change_diagnostics = checker.pure_check(&migration);
 
-        let migration_script = applier.render_script(&migration, &destructive_change_diagnostics);
+        let render_script = |migration: &D, diagnostics: &migration_connector::DestructiveChangeDiagnostics| {
+            if input.idempotent {
+                applier.render_idempotent_script(migration, diagnostics)
+            } else {
+                applier.render_script(migration, diagnostics)
+            }
+        };
+
+        let migration_script = render_script(&migration, &destructive_change_diagnostics);
+
+        // The down migration takes the schema back from the target to the end of the history.
+        let down_migration = database_migration_inferrer
+            .diff(
+                DiffTarget::Datamodel(&target_schema),
+                DiffTarget::Migrations(&previous_migrations),
+            )
+            .await?;
+        let down_migration_script = render_script(&down_migration, &checker.pure_check(&down_migration));
 
         // Write the migration script to a file.
         let directory = migration_connector::create_migration_directory(
@@ -72,8 +99,17 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
             .write_migration_script(&migration_script, D::FILE_EXTENSION)
             .map_err(|err| {
                 CommandError::Generic(anyhow::anyhow!(
-                    "Failed to write the migration script to `{:?}`. {}",
-                    directory.path(),
+                    "Failed to write the migration script to `{}`. {}",
+                    directory.path().display(),
+                    err
+                ))
+            })?;
+        directory
+            .write_down_migration_script(&down_migration_script, D::FILE_EXTENSION)
+            .map_err(|err| {
+                CommandError::Generic(anyhow::anyhow!(
+                    "Failed to write the down migration script to `{}`. {}",
+                    directory.path().display(),
                     err
                 ))
             })?;
@@ -0,0 +1,143 @@
+use super::MigrationCommand;
+use crate::{migration_engine::MigrationEngine, parse_datamodel};
+use migration_connector::{DiffTarget, MigrationDirectory};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Render the migration script between two schemas, without applying anything. For reviewing
+/// changes, and detecting drift between a database and a Prisma schema or migrations history.
+pub struct DiffCommand;
+
+/// The input to the `diff` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffInput {
+    /// The schema the migration starts from.
+    pub from: DiffSource,
+    /// The schema the migration ends with.
+    pub to: DiffSource,
+    /// Whether the migration should also set the auto-incrementing counters to their current
+    /// values in `to`, when it is a live database. For baseline migrations, so restoring the schema
+    /// from migrations does not reset the counters under existing data.
+    #[serde(default)]
+    pub include_auto_increment_values: bool,
+    /// Whether the statements of the script should be guarded with existence checks, so the script can be
+    /// run again after it partially failed.
+    #[serde(default)]
+    pub idempotent: bool,
+}
+
+/// Where one side of a diff comes from.
+#[derive(Deserialize, Debug)]
+#[serde(tag = "tag", rename_all = "camelCase")]
+pub enum DiffSource {
+    /// The schema a Prisma schema translates to.
+    SchemaDatamodel {
+        /// The contents of the Prisma schema.
+        schema: String,
+    },
+    /// The current schema of a live database, of the same kind as the database of the datasource.
+    Url {
+        /// The connection string of the database.
+        url: String,
+    },
+    /// The schema at the end of a migrations history. The migrations are applied to the shadow
+    /// database.
+    Migrations {
+        /// The filesystem path of the migrations directory.
+        #[serde(rename = "migrationsDirectoryPath")]
+        migrations_directory_path: String,
+    },
+    /// An empty schema.
+    Empty,
+}
+
+/// The output of the `diff` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffOutput {
+    /// The migration script, empty if the schemas match.
+    pub script: String,
+    /// Whether the two schemas match.
+    pub is_empty: bool,
+}
+
+#[async_trait::async_trait]
+impl MigrationCommand for DiffCommand {
+    type Input = DiffInput;
+
+    type Output = DiffOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> super::CommandResult<Self::Output>
+    where
+        C: migration_connector::MigrationConnector<DatabaseMigration = D>,
+        D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let inferrer = engine.connector().database_migration_inferrer();
+        let applier = engine.connector().database_migration_step_applier();
+        let checker = engine.connector().destructive_change_checker();
+
+        let from = LoadedSource::load(&input.from)?;
+        let to = LoadedSource::load(&input.to)?;
+
+        let migration = if input.include_auto_increment_values {
+            inferrer
+                .diff_with_auto_increment_values(from.as_target(), to.as_target())
+                .await?
+        } else {
+            inferrer.diff(from.as_target(), to.as_target()).await?
+        };
+
+        if migration.is_empty() {
+            return Ok(DiffOutput {
+                script: String::new(),
+                is_empty: true,
+            });
+        }
+
+        let destructive_change_diagnostics = checker.pure_check(&migration);
+
+        let script = if input.idempotent {
+            applier.render_idempotent_script(&migration, &destructive_change_diagnostics)
+        } else {
+            applier.render_script(&migration, &destructive_change_diagnostics)
+        };
+
+        Ok(DiffOutput {
+            script,
+            is_empty: false,
+        })
+    }
+}
+
+/// A diff source, read and parsed.
+enum LoadedSource<'a> {
+    Datamodel(datamodel::Datamodel),
+    Database(&'a str),
+    Migrations(Vec<MigrationDirectory>),
+    Empty,
+}
+
+impl<'a> LoadedSource<'a> {
+    fn load(source: &'a DiffSource) -> super::CommandResult<Self> {
+        Ok(match source {
+            DiffSource::SchemaDatamodel { schema } => LoadedSource::Datamodel(parse_datamodel(schema)?),
+            DiffSource::Url { url } => LoadedSource::Database(url),
+            DiffSource::Migrations {
+                migrations_directory_path,
+            } => LoadedSource::Migrations(migration_connector::list_migrations(&Path::new(
+                migrations_directory_path,
+            ))?),
+            DiffSource::Empty => LoadedSource::Empty,
+        })
+    }
+
+    fn as_target(&self) -> DiffTarget<'_> {
+        match self {
+            LoadedSource::Datamodel(datamodel) => DiffTarget::Datamodel(datamodel),
+            LoadedSource::Database(url) => DiffTarget::Database(url),
+            LoadedSource::Migrations(migrations) => DiffTarget::Migrations(migrations),
+            LoadedSource::Empty => DiffTarget::Empty,
+        }
+    }
+}
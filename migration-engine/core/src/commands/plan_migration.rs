@@ -1,6 +1,6 @@
 use super::{CommandResult, MigrationCommand};
 use crate::{migration_engine::MigrationEngine, parse_datamodel};
-use migration_connector::list_migrations;
+use migration_connector::{list_migrations, EnumValueRenames};
 use serde::{Deserialize, Serialize};
 
 /// The input to the `planMigration` command.
@@ -11,6 +11,10 @@ pub struct PlanMigrationInput {
     pub migrations_directory_path: String,
     /// The prisma schema to migrate to.
     pub prisma_schema: String,
+    /// Enum values to rename instead of dropping and creating them, by enum name, from the previous to
+    /// the next value name.
+    #[serde(default)]
+    pub enum_value_renames: EnumValueRenames,
 }
 
 /// The output of the `planMigration` command.
@@ -79,7 +83,7 @@ impl<'a> MigrationCommand for PlanMigrationCommand {
         let target_schema = parse_datamodel(&input.prisma_schema)?;
 
         let migration = inferrer
-            .infer_next_migration(&migrations_from_directory, &target_schema)
+            .infer_next_migration(&migrations_from_directory, &target_schema, &input.enum_value_renames)
             .await?;
 
         let rendered_migration_steps = applier
@@ -0,0 +1,98 @@
+use super::{CommandError, CommandResult, MigrationCommand};
+use crate::migration_engine::MigrationEngine;
+use migration_connector::{ConnectorError, MigrationTimeouts};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The input to the `rollbackMigration` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RollbackMigrationInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+}
+
+/// The output of the `rollbackMigration` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RollbackMigrationOutput {
+    /// The name of the migration that was rolled back, if any migration was applied.
+    pub rolled_back_migration_name: Option<String>,
+}
+
+/// Roll back the last applied migration by applying its down migration script. The migration record
+/// is marked as rolled back, so the migration counts as unapplied again.
+pub struct RollbackMigrationCommand;
+
+#[async_trait::async_trait]
+impl MigrationCommand for RollbackMigrationCommand {
+    type Input = RollbackMigrationInput;
+
+    type Output = RollbackMigrationOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: migration_connector::MigrationConnector<DatabaseMigration = D>,
+        D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let applier = connector.database_migration_step_applier();
+        let migration_persistence = connector.new_migration_persistence();
+
+        let migrations_from_filesystem =
+            migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
+        let migrations_from_database = migration_persistence.list_applied_migrations().await?;
+
+        let last_migration = match migrations_from_database.last() {
+            Some(migration) => migration,
+            None => {
+                return Ok(RollbackMigrationOutput {
+                    rolled_back_migration_name: None,
+                })
+            }
+        };
+
+        // The down migration script assumes the whole migration was applied.
+        if last_migration.is_failed() {
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "The last migration, `{}`, failed to apply. It has to be resolved before it can be rolled back.",
+                last_migration.migration_name
+            )));
+        }
+
+        let fs_migration = migrations_from_filesystem
+            .iter()
+            .find(|fs_migration| fs_migration.migration_name() == last_migration.migration_name)
+            .ok_or_else(|| {
+                CommandError::Input(anyhow::anyhow!(
+                    "The last applied migration, `{}`, is not in the migrations directory.",
+                    last_migration.migration_name
+                ))
+            })?;
+
+        let script = fs_migration
+            .read_down_migration_script()
+            .map_err(ConnectorError::from)?
+            .ok_or_else(|| {
+                CommandError::Input(anyhow::anyhow!(
+                    "The last applied migration, `{}`, has no down migration script.",
+                    last_migration.migration_name
+                ))
+            })?;
+
+        tracing::info!(
+            script = script.as_str(),
+            "Rolling back `{}`",
+            last_migration.migration_name
+        );
+
+        applier.apply_script(&script, &MigrationTimeouts::default()).await?;
+        migration_persistence
+            .record_migration_rolled_back(&last_migration.id)
+            .await?;
+
+        Ok(RollbackMigrationOutput {
+            rolled_back_migration_name: Some(last_migration.migration_name.clone()),
+        })
+    }
+}
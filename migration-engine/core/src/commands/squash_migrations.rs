@@ -0,0 +1,185 @@
+use super::{CommandError, CommandResult, MigrationCommand};
+use crate::migration_engine::MigrationEngine;
+use migration_connector::{DiffTarget, MigrationDirectory, MigrationRecord};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The input to the `squashMigrations` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+    /// The name of the first migration to squash.
+    pub from_migration_name: String,
+    /// The name of the last migration to squash. The squashed migration takes its place.
+    pub to_migration_name: String,
+}
+
+/// The output of the `squashMigrations` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsOutput {
+    /// The name of the migration holding the squashed script.
+    pub squashed_migration_name: String,
+    /// The names of the migrations that were removed from the migrations directory.
+    pub removed_migration_names: Vec<String>,
+}
+
+/// Replace a range of migrations with a single migration, taking the database from the schema before
+/// the first of them to the schema after the last of them. The squashed script and its down migration
+/// script replace the scripts of the last migration, the other directories are removed. If the
+/// migrations were applied to the database, their records in the migrations table are squashed the
+/// same way, so the history stays consistent.
+pub struct SquashMigrationsCommand;
+
+#[async_trait::async_trait]
+impl MigrationCommand for SquashMigrationsCommand {
+    type Input = SquashMigrationsInput;
+
+    type Output = SquashMigrationsOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> super::CommandResult<Self::Output>
+    where
+        C: migration_connector::MigrationConnector<DatabaseMigration = D>,
+        D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let inferrer = connector.database_migration_inferrer();
+        let applier = connector.database_migration_step_applier();
+        let checker = connector.destructive_change_checker();
+        let migration_persistence = connector.new_migration_persistence();
+
+        let mut migrations_from_filesystem =
+            migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
+        let (start, end) = find_range(&migrations_from_filesystem, input)?;
+
+        // Replay the history on the shadow database, up to the range and through it.
+        let migration = inferrer
+            .diff(
+                DiffTarget::Migrations(&migrations_from_filesystem[..start]),
+                DiffTarget::Migrations(&migrations_from_filesystem[..=end]),
+            )
+            .await?;
+        let script = applier.render_script(&migration, &checker.pure_check(&migration));
+        let down_migration = inferrer
+            .diff(
+                DiffTarget::Migrations(&migrations_from_filesystem[..=end]),
+                DiffTarget::Migrations(&migrations_from_filesystem[..start]),
+            )
+            .await?;
+        let down_script = applier.render_script(&down_migration, &checker.pure_check(&down_migration));
+
+        let squashed: Vec<MigrationDirectory> = migrations_from_filesystem.drain(start..=end).collect();
+        let squashed_names: Vec<String> = squashed
+            .iter()
+            .map(|migration| migration.migration_name().to_owned())
+            .collect();
+
+        let migrations_from_database = migration_persistence.list_applied_migrations().await?;
+
+        if range_is_applied(&migrations_from_database, &squashed_names)? {
+            migration_persistence
+                .record_squashed_migrations(&squashed_names, &input.to_migration_name, &script)
+                .await?;
+        }
+
+        let mut removed_migration_names = Vec::with_capacity(squashed.len() - 1);
+
+        for directory in squashed {
+            if directory.migration_name() == input.to_migration_name {
+                directory
+                    .write_migration_script(&script, D::FILE_EXTENSION)
+                    .map_err(|err| {
+                        CommandError::Generic(anyhow::anyhow!(
+                            "Failed to write the migration script to `{}`. {}",
+                            directory.path().display(),
+                            err
+                        ))
+                    })?;
+                directory
+                    .write_down_migration_script(&down_script, D::FILE_EXTENSION)
+                    .map_err(|err| {
+                        CommandError::Generic(anyhow::anyhow!(
+                            "Failed to write the down migration script to `{}`. {}",
+                            directory.path().display(),
+                            err
+                        ))
+                    })?;
+            } else {
+                let name = directory.migration_name().to_owned();
+                let path = directory.path().to_owned();
+
+                directory.remove().map_err(|err| {
+                    CommandError::Generic(anyhow::anyhow!(
+                        "Failed to remove the migration directory at `{}`. {}",
+                        path.display(),
+                        err
+                    ))
+                })?;
+
+                removed_migration_names.push(name);
+            }
+        }
+
+        Ok(SquashMigrationsOutput {
+            squashed_migration_name: input.to_migration_name.clone(),
+            removed_migration_names,
+        })
+    }
+}
+
+/// The indexes of the first and last migrations to squash.
+fn find_range(migrations: &[MigrationDirectory], input: &SquashMigrationsInput) -> CommandResult<(usize, usize)> {
+    let position = |name: &str| {
+        migrations
+            .iter()
+            .position(|migration| migration.migration_name() == name)
+            .ok_or_else(|| {
+                CommandError::Input(anyhow::anyhow!(
+                    "The migration `{}` is not in the migrations directory.",
+                    name
+                ))
+            })
+    };
+
+    let start = position(&input.from_migration_name)?;
+    let end = position(&input.to_migration_name)?;
+
+    if start >= end {
+        return Err(CommandError::Input(anyhow::anyhow!(
+            "The migration `{}` has to come before `{}` to squash them.",
+            input.from_migration_name,
+            input.to_migration_name
+        )));
+    }
+
+    Ok((start, end))
+}
+
+/// Whether all the squashed migrations were applied to the database. Ranges that were only partially
+/// applied, or failed to apply, can't be squashed.
+fn range_is_applied(migrations_from_database: &[MigrationRecord], squashed_names: &[String]) -> CommandResult<bool> {
+    let records: Vec<&MigrationRecord> = migrations_from_database
+        .iter()
+        .filter(|record| squashed_names.contains(&record.migration_name))
+        .collect();
+
+    if records.is_empty() {
+        return Ok(false);
+    }
+
+    let all_applied = squashed_names.iter().all(|name| {
+        records
+            .iter()
+            .any(|record| &record.migration_name == name && !record.is_failed())
+    });
+
+    if all_applied {
+        Ok(true)
+    } else {
+        Err(CommandError::Input(anyhow::anyhow!(
+            "Only some of the migrations to squash were applied to the database, or some of them failed to apply. Apply or resolve them first."
+        )))
+    }
+}
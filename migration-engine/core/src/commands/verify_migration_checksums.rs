@@ -0,0 +1,87 @@
+use super::{CommandResult, MigrationCommand};
+use crate::migration_engine::MigrationEngine;
+use migration_connector::ConnectorError;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The input to the `verifyMigrationChecksums` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyMigrationChecksumsInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+}
+
+/// The output of the `verifyMigrationChecksums` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyMigrationChecksumsOutput {
+    /// The applied migrations whose script was edited after they were applied.
+    pub edited_migrations: Vec<EditedMigration>,
+}
+
+/// An applied migration whose script in the migrations directory does not match the applied script.
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EditedMigration {
+    /// The name of the migration directory.
+    pub migration_name: String,
+    /// The checksum of the script, as recorded in the migrations table when the migration was applied.
+    pub applied_checksum: String,
+    /// The checksum of the script in the migrations directory.
+    pub filesystem_checksum: String,
+}
+
+/// Compare the checksums of the migration scripts in the migrations directory with the checksums
+/// recorded in the migrations table, and report the migrations that were edited after they were
+/// applied. Unlike `diagnoseMigrationHistory`, this only reads the migrations directory and the
+/// migrations table, it does not replay the history on a shadow database.
+pub struct VerifyMigrationChecksumsCommand;
+
+#[async_trait::async_trait]
+impl MigrationCommand for VerifyMigrationChecksumsCommand {
+    type Input = VerifyMigrationChecksumsInput;
+
+    type Output = VerifyMigrationChecksumsOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: migration_connector::MigrationConnector<DatabaseMigration = D>,
+        D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let migration_persistence = engine.connector().new_migration_persistence();
+
+        let migrations_from_filesystem =
+            migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
+        let migrations_from_database = migration_persistence.list_applied_migrations().await?;
+
+        let mut edited_migrations = Vec::new();
+
+        for db_migration in &migrations_from_database {
+            let fs_migration = match migrations_from_filesystem
+                .iter()
+                .find(|fs_migration| fs_migration.migration_name() == db_migration.migration_name)
+            {
+                Some(fs_migration) => fs_migration,
+                None => continue,
+            };
+
+            let filesystem_checksum = fs_migration.script_checksum().map_err(ConnectorError::from)?;
+
+            if filesystem_checksum != db_migration.checksum {
+                tracing::warn!(
+                    "The `{}` migration was edited after it was applied.",
+                    db_migration.migration_name
+                );
+
+                edited_migrations.push(EditedMigration {
+                    migration_name: db_migration.migration_name.clone(),
+                    applied_checksum: db_migration.checksum.clone(),
+                    filesystem_checksum,
+                });
+            }
+        }
+
+        Ok(VerifyMigrationChecksumsOutput { edited_migrations })
+    }
+}
@@ -10,6 +10,7 @@ pub mod migration_engine;
 pub use api::GenericApi;
 pub use commands::{ApplyMigrationInput, InferMigrationStepsInput, MigrationStepsResultOutput};
 pub use error::CoreResult;
+pub use sql_migration_connector::MigrationsHistoryStorage;
 
 use commands::{CommandError, CommandResult};
 use datamodel::{
@@ -23,6 +24,18 @@ use std::sync::Arc;
 
 /// Top-level constructor for the migration engine API.
 pub async fn migration_api(datamodel: &str) -> CoreResult<Arc<dyn api::GenericApi>> {
+    migration_api_with_migrations_history(datamodel, MigrationsHistoryStorage::default(), None).await
+}
+
+/// Like `migration_api`, but the migrations history is stored as configured in
+/// `migrations_history` instead of the datasource database. The migrations
+/// histories are replayed on the database at `shadow_database_url` when it is set, instead of a
+/// temporary database.
+pub async fn migration_api_with_migrations_history(
+    datamodel: &str,
+    migrations_history: MigrationsHistoryStorage,
+    shadow_database_url: Option<&str>,
+) -> CoreResult<Arc<dyn api::GenericApi>> {
     let config = datamodel::parse_configuration(datamodel)?;
 
     let source = config
@@ -33,8 +46,8 @@ pub async fn migration_api(datamodel: &str) -> CoreResult<Arc<dyn api::GenericAp
     let connector = match &source.active_provider {
         #[cfg(feature = "sql")]
         provider if POSTGRES_SOURCE_NAME == provider => {
-            let mut u = url::Url::parse(&source.url().value).map_err(|url_error| {
-                Error::ConnectorError(ConnectorError::url_parse_error(url_error, &source.url().value))
+            let mut u = url::Url::parse(source.connection_url()).map_err(|url_error| {
+                Error::ConnectorError(ConnectorError::url_parse_error(url_error, source.connection_url()))
             })?;
 
             let params: Vec<(String, String)> = u.query_pairs().map(|(k, v)| (k.to_string(), v.to_string())).collect();
@@ -57,11 +70,20 @@ pub async fn migration_api(datamodel: &str) -> CoreResult<Arc<dyn api::GenericAp
         }
         #[cfg(feature = "sql")]
         provider if [MYSQL_SOURCE_NAME, SQLITE_SOURCE_NAME, MSSQL_SOURCE_NAME].contains(&provider.as_str()) => {
-            SqlMigrationConnector::new(&source.url().value).await?
+            SqlMigrationConnector::new(source.connection_url()).await?
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     };
 
+    let connector = connector.with_migrations_history(migrations_history).await?;
+
+    let connector = match shadow_database_url {
+        Some(shadow_database_url) => connector.with_shadow_database(shadow_database_url).await?,
+        None => connector,
+    };
+
+    let connector = connector.with_relation_mode(source.relation_mode);
+
     let api = api::MigrationApi::new(connector).await?;
 
     Ok(Arc::new(api))
@@ -86,7 +108,7 @@ pub async fn create_database(schema: &str) -> CoreResult<String> {
             ]
             .contains(&provider.as_str()) =>
         {
-            Ok(SqlMigrationConnector::create_database(&source.url().value).await?)
+            Ok(SqlMigrationConnector::create_database(source.connection_url()).await?)
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     }
@@ -111,7 +133,7 @@ pub async fn qe_setup(prisma_schema: &str) -> CoreResult<()> {
             ]
             .contains(&provider.as_str()) =>
         {
-            SqlMigrationConnector::qe_setup(&source.url().value).await?;
+            SqlMigrationConnector::qe_setup(source.connection_url()).await?;
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     }
@@ -207,6 +207,19 @@ impl<'a> TableAssertion<'a> {
         Ok(this)
     }
 
+    pub fn assert_column_order(self, column_names: &[&str]) -> AssertionResult<Self> {
+        let actual_names: Vec<&str> = self.0.columns.iter().map(|column| column.name.as_str()).collect();
+
+        anyhow::ensure!(
+            actual_names == column_names,
+            "Assertion failed: expected the columns {:?}, found {:?}",
+            column_names,
+            actual_names,
+        );
+
+        Ok(self)
+    }
+
     pub fn assert_columns_count(self, count: usize) -> AssertionResult<Self> {
         let actual_count = self.0.columns.len();
 
@@ -492,6 +505,17 @@ impl<'a> ForeignKeyAssertion<'a> {
 
         Ok(self)
     }
+
+    pub fn assert_on_delete(self, action: ForeignKeyAction) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.on_delete_action == action,
+            "Assertion failed: expected foreign key to {:?} on delete, found {:?}.",
+            action,
+            self.0.on_delete_action,
+        );
+
+        Ok(self)
+    }
 }
 
 pub struct IndexAssertion<'a>(&'a Index);
@@ -3,24 +3,32 @@ mod apply_migrations;
 mod calculate_database_steps;
 mod create_migration;
 mod diagnose_migration_history;
+mod diff;
 mod infer;
 mod infer_apply;
 mod plan_migration;
 mod reset;
+mod rollback_migration;
 mod schema_push;
+mod squash_migrations;
 mod unapply_migration;
+mod verify_migration_checksums;
 
 pub use apply::Apply;
 pub use apply_migrations::ApplyMigrations;
 pub use calculate_database_steps::CalculateDatabaseSteps;
 pub use create_migration::CreateMigration;
 pub use diagnose_migration_history::DiagnoseMigrationHistory;
+pub use diff::Diff;
 pub use infer::Infer;
 pub use infer_apply::InferApply;
 pub use plan_migration::PlanMigration;
 pub use reset::Reset;
+pub use rollback_migration::RollbackMigration;
 pub use schema_push::SchemaPush;
+pub use squash_migrations::SquashMigrations;
 pub use unapply_migration::UnapplyMigration;
+pub use verify_migration_checksums::VerifyMigrationChecksums;
 
 use crate::AssertionResult;
 
@@ -33,7 +41,7 @@ use super::{
 use migration_connector::{ImperativeMigrationsPersistence, MigrationPersistence, MigrationRecord, MigrationStep};
 use migration_core::{
     api::{GenericApi, MigrationApi},
-    commands::ApplyMigrationInput,
+    commands::{ApplyMigrationInput, DiffSource},
 };
 use quaint::{
     prelude::{ConnectionInfo, Queryable, SqlFamily},
@@ -42,7 +50,10 @@ use quaint::{
 use sql_migration_connector::{sql_migration::SqlMigration, SqlMigrationConnector, MIGRATION_TABLE_NAME};
 use sql_schema_describer::*;
 use tempfile::TempDir;
-use test_setup::*;
+use test_setup::{
+    connectors::{ExternalConnector, ExternalConnectorFamily, TestApiFactory, TestApiFuture},
+    *,
+};
 
 /// A handle to all the context needed for end-to-end testing of the migration engine across
 /// connectors.
@@ -99,6 +110,50 @@ impl TestApi {
         self.connection_info().sql_family()
     }
 
+    /// A test api on the same database, connecting with additional connection string parameters,
+    /// e.g. `identity_columns=true` on PostgreSQL.
+    pub async fn with_connection_string_params(&self, params: &[(&str, &str)]) -> TestApi {
+        let mut url = match &self.connection_info {
+            ConnectionInfo::Postgres(url) => url.url().clone(),
+            other => panic!(
+                "Connection string parameters on {:?} are not supported",
+                other.sql_family()
+            ),
+        };
+
+        url.query_pairs_mut().extend_pairs(params);
+
+        let connector = SqlMigrationConnector::new(url.as_str()).await.unwrap();
+
+        TestApi {
+            connector_name: self.connector_name,
+            connection_info: ConnectionInfo::from_url(url.as_str()).unwrap(),
+            database: connector.quaint().clone(),
+            api: test_api(connector).await,
+        }
+    }
+
+    /// A TestApi on the same database, whose connector maintains the integrity of relations in the given mode.
+    pub async fn with_relation_mode(&self, relation_mode: datamodel::RelationMode) -> TestApi {
+        let url = match &self.connection_info {
+            ConnectionInfo::Postgres(url) => url.url().clone(),
+            ConnectionInfo::Mysql(url) => url.url().clone(),
+            other => panic!("Relation modes on {:?} are not supported", other.sql_family()),
+        };
+
+        let connector = SqlMigrationConnector::new(url.as_str())
+            .await
+            .unwrap()
+            .with_relation_mode(relation_mode);
+
+        TestApi {
+            connector_name: self.connector_name,
+            connection_info: self.connection_info.clone(),
+            database: connector.quaint().clone(),
+            api: test_api(connector).await,
+        }
+    }
+
     pub fn datasource(&self) -> String {
         match self.sql_family() {
             SqlFamily::Mysql => mysql_test_config("unreachable"),
@@ -160,6 +215,11 @@ impl TestApi {
         DiagnoseMigrationHistory::new(&self.api, migrations_directory)
     }
 
+    /// Builder and assertions to call the Diff command.
+    pub fn diff(&self, from: DiffSource, to: DiffSource) -> Diff<'_> {
+        Diff::new(&self.api, from, to)
+    }
+
     pub fn infer_apply<'a>(&'a self, schema: &'a str) -> InferApply<'a> {
         InferApply::new(&self.api, schema)
     }
@@ -215,10 +275,35 @@ impl TestApi {
         Reset::new(&self.api)
     }
 
+    /// Builder to call the RollbackMigration command.
+    pub fn rollback_migration<'a>(&'a self, migrations_directory: &'a TempDir) -> RollbackMigration<'a> {
+        RollbackMigration::new(&self.api, migrations_directory)
+    }
+
     pub fn schema_push<'a>(&'a self, dm: impl Into<String>) -> SchemaPush<'a> {
         SchemaPush::new(&self.api, dm.into())
     }
 
+    /// Builder to call the SquashMigrations command.
+    pub fn squash_migrations<'a>(
+        &'a self,
+        migrations_directory: &'a TempDir,
+        from_migration_name: impl Into<String>,
+        to_migration_name: impl Into<String>,
+    ) -> SquashMigrations<'a> {
+        SquashMigrations::new(
+            &self.api,
+            migrations_directory,
+            from_migration_name.into(),
+            to_migration_name.into(),
+        )
+    }
+
+    /// Builder to call the VerifyMigrationChecksums command.
+    pub fn verify_migration_checksums<'a>(&'a self, migrations_directory: &'a TempDir) -> VerifyMigrationChecksums<'a> {
+        VerifyMigrationChecksums::new(&self.api, migrations_directory)
+    }
+
     pub fn barrel(&self) -> BarrelMigrationExecutor<'_> {
         BarrelMigrationExecutor {
             api: self,
@@ -464,6 +549,30 @@ pub async fn sqlite_test_api(db_name: &str) -> TestApi {
     }
 }
 
+impl TestApiFactory for TestApi {
+    fn external_test_api(connector: &'static ExternalConnector, test_name: &'static str) -> TestApiFuture<Self> {
+        Box::pin(async move {
+            let url = connector.url(test_name);
+            let connection_info = ConnectionInfo::from_url(&url).unwrap();
+
+            let migration_connector = match connector.family() {
+                ExternalConnectorFamily::Postgres => postgres_migration_connector(&url).await,
+                ExternalConnectorFamily::Mysql => mysql_migration_connector(&url).await,
+                ExternalConnectorFamily::Sqlite => SqlMigrationConnector::new(&url).await.unwrap(),
+                // The migration tests don't run on SQL Server yet.
+                ExternalConnectorFamily::Mssql => return Err(connector.unsupported_by("migration engine")),
+            };
+
+            Ok(TestApi {
+                connector_name: connector.name(),
+                connection_info,
+                database: migration_connector.quaint().clone(),
+                api: test_api(migration_connector).await,
+            })
+        })
+    }
+}
+
 pub trait MigrationsAssertions: Sized {
     fn assert_checksum(self, expected: &str) -> AssertionResult<Self>;
     fn assert_migration_name(self, expected: &str) -> AssertionResult<Self>;
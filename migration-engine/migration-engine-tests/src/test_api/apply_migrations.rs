@@ -7,6 +7,8 @@ use crate::AssertionResult;
 pub struct ApplyMigrations<'a> {
     api: &'a dyn GenericApi,
     migrations_directory: &'a TempDir,
+    lock_timeout: Option<u64>,
+    statement_timeout: Option<u64>,
 }
 
 impl<'a> ApplyMigrations<'a> {
@@ -14,14 +16,37 @@ impl<'a> ApplyMigrations<'a> {
         ApplyMigrations {
             api,
             migrations_directory,
+            lock_timeout: None,
+            statement_timeout: None,
         }
     }
 
+    pub fn lock_timeout(mut self, milliseconds: u64) -> Self {
+        self.lock_timeout = Some(milliseconds);
+        self
+    }
+
+    pub fn statement_timeout(mut self, milliseconds: u64) -> Self {
+        self.statement_timeout = Some(milliseconds);
+        self
+    }
+
     pub async fn send(self) -> anyhow::Result<ApplyMigrationsAssertion<'a>> {
+        Ok(self.send_inner().await?)
+    }
+
+    pub async fn send_user_facing(self) -> Result<ApplyMigrationsAssertion<'a>, user_facing_errors::Error> {
+        let api = self.api;
+        self.send_inner().await.map_err(|err| api.render_error(err))
+    }
+
+    async fn send_inner(self) -> Result<ApplyMigrationsAssertion<'a>, migration_core::error::Error> {
         let output = self
             .api
             .apply_migrations(&ApplyMigrationsInput {
                 migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                lock_timeout: self.lock_timeout,
+                statement_timeout: self.statement_timeout,
             })
             .await?;
 
@@ -1,4 +1,5 @@
 use anyhow::Context;
+use migration_connector::EnumValueRenames;
 use migration_core::{commands::CreateMigrationInput, commands::CreateMigrationOutput, GenericApi};
 use pretty_assertions::assert_eq;
 use std::path::Path;
@@ -12,6 +13,8 @@ pub struct CreateMigration<'a> {
     migrations_directory: &'a TempDir,
     draft: bool,
     name: &'a str,
+    enum_value_renames: EnumValueRenames,
+    idempotent: bool,
 }
 
 impl<'a> CreateMigration<'a> {
@@ -22,6 +25,8 @@ impl<'a> CreateMigration<'a> {
             migrations_directory,
             draft: false,
             name,
+            enum_value_renames: EnumValueRenames::new(),
+            idempotent: false,
         }
     }
 
@@ -31,6 +36,18 @@ impl<'a> CreateMigration<'a> {
         self
     }
 
+    pub fn rename_enum_value(mut self, enum_name: &str, previous_value: &str, next_value: &str) -> Self {
+        self.enum_value_renames.insert(enum_name, previous_value, next_value);
+
+        self
+    }
+
+    pub fn idempotent(mut self, idempotent: bool) -> Self {
+        self.idempotent = idempotent;
+
+        self
+    }
+
     pub async fn send(self) -> anyhow::Result<CreateMigrationAssertion<'a>> {
         let output = self
             .api
@@ -39,6 +56,8 @@ impl<'a> CreateMigration<'a> {
                 prisma_schema: self.schema.to_owned(),
                 draft: self.draft,
                 migration_name: self.name.to_owned(),
+                enum_value_renames: self.enum_value_renames,
+                idempotent: self.idempotent,
             })
             .await?;
 
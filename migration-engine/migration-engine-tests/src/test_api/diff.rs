@@ -0,0 +1,81 @@
+use migration_core::{
+    commands::{DiffInput, DiffOutput, DiffSource},
+    GenericApi,
+};
+
+#[must_use = "This struct does nothing on its own. See Diff::send()"]
+pub struct Diff<'a> {
+    api: &'a dyn GenericApi,
+    from: DiffSource,
+    to: DiffSource,
+    include_auto_increment_values: bool,
+    idempotent: bool,
+}
+
+impl<'a> Diff<'a> {
+    pub fn new(api: &'a dyn GenericApi, from: DiffSource, to: DiffSource) -> Self {
+        Diff {
+            api,
+            from,
+            to,
+            include_auto_increment_values: false,
+            idempotent: false,
+        }
+    }
+
+    pub fn include_auto_increment_values(mut self, include_auto_increment_values: bool) -> Self {
+        self.include_auto_increment_values = include_auto_increment_values;
+        self
+    }
+
+    pub fn idempotent(mut self, idempotent: bool) -> Self {
+        self.idempotent = idempotent;
+        self
+    }
+
+    pub async fn send(self) -> anyhow::Result<DiffAssertion> {
+        let output = self
+            .api
+            .diff(&DiffInput {
+                from: self.from,
+                to: self.to,
+                include_auto_increment_values: self.include_auto_increment_values,
+                idempotent: self.idempotent,
+            })
+            .await?;
+
+        Ok(DiffAssertion { output })
+    }
+}
+
+#[derive(Debug)]
+pub struct DiffAssertion {
+    output: DiffOutput,
+}
+
+impl DiffAssertion {
+    pub fn assert_empty(self) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            self.output.is_empty && self.output.script.is_empty(),
+            "Expected an empty diff, got:\n{}",
+            self.output.script
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_script_contains(self, expected: &str) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            !self.output.is_empty && self.output.script.contains(expected),
+            "Expected the script to contain `{}`, got:\n{}",
+            expected,
+            self.output.script
+        );
+
+        Ok(self)
+    }
+
+    pub fn into_output(self) -> DiffOutput {
+        self.output
+    }
+}
@@ -27,6 +27,7 @@ impl<'a> PlanMigration<'a> {
             .plan_migration(&PlanMigrationInput {
                 migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
                 prisma_schema: self.prisma_schema,
+                enum_value_renames: Default::default(),
             })
             .await?;
 
@@ -0,0 +1,31 @@
+use migration_core::{
+    commands::{RollbackMigrationInput, RollbackMigrationOutput},
+    GenericApi,
+};
+use tempfile::TempDir;
+
+#[must_use = "This struct does nothing on its own. See RollbackMigration::send()"]
+pub struct RollbackMigration<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a TempDir,
+}
+
+impl<'a> RollbackMigration<'a> {
+    pub fn new(api: &'a dyn GenericApi, migrations_directory: &'a TempDir) -> Self {
+        RollbackMigration {
+            api,
+            migrations_directory,
+        }
+    }
+
+    pub async fn send(self) -> anyhow::Result<RollbackMigrationOutput> {
+        let output = self
+            .api
+            .rollback_migration(&RollbackMigrationInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+            })
+            .await?;
+
+        Ok(output)
+    }
+}
@@ -0,0 +1,42 @@
+use migration_core::{
+    commands::{SquashMigrationsInput, SquashMigrationsOutput},
+    GenericApi,
+};
+use tempfile::TempDir;
+
+#[must_use = "This struct does nothing on its own. See SquashMigrations::send()"]
+pub struct SquashMigrations<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a TempDir,
+    from_migration_name: String,
+    to_migration_name: String,
+}
+
+impl<'a> SquashMigrations<'a> {
+    pub fn new(
+        api: &'a dyn GenericApi,
+        migrations_directory: &'a TempDir,
+        from_migration_name: String,
+        to_migration_name: String,
+    ) -> Self {
+        SquashMigrations {
+            api,
+            migrations_directory,
+            from_migration_name,
+            to_migration_name,
+        }
+    }
+
+    pub async fn send(self) -> anyhow::Result<SquashMigrationsOutput> {
+        let output = self
+            .api
+            .squash_migrations(&SquashMigrationsInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                from_migration_name: self.from_migration_name,
+                to_migration_name: self.to_migration_name,
+            })
+            .await?;
+
+        Ok(output)
+    }
+}
@@ -0,0 +1,31 @@
+use migration_core::{
+    commands::{VerifyMigrationChecksumsInput, VerifyMigrationChecksumsOutput},
+    GenericApi,
+};
+use tempfile::TempDir;
+
+#[must_use = "This struct does nothing on its own. See VerifyMigrationChecksums::send()"]
+pub struct VerifyMigrationChecksums<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a TempDir,
+}
+
+impl<'a> VerifyMigrationChecksums<'a> {
+    pub fn new(api: &'a dyn GenericApi, migrations_directory: &'a TempDir) -> Self {
+        VerifyMigrationChecksums {
+            api,
+            migrations_directory,
+        }
+    }
+
+    pub async fn send(self) -> anyhow::Result<VerifyMigrationChecksumsOutput> {
+        let output = self
+            .api
+            .verify_migration_checksums(&VerifyMigrationChecksumsInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+            })
+            .await?;
+
+        Ok(output)
+    }
+}
@@ -185,3 +185,235 @@ async fn migrations_should_fail_to_apply_if_modified(api: &TestApi) -> TestResul
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn migrations_with_functions_and_triggers_can_be_applied_on_postgres(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id          Int @id
+            updatedAt   DateTime
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|contents| {
+            contents.push_str(
+                r#"
+                -- The function body contains semicolons.
+                CREATE FUNCTION "set_updated_at"() RETURNS TRIGGER AS $$
+                BEGIN
+                    NEW."updatedAt" = now();
+                    RETURN NEW;
+                END;
+                $$ LANGUAGE plpgsql;
+
+                CREATE TRIGGER "cat_updated_at" BEFORE UPDATE ON "Cat"
+                    FOR EACH ROW EXECUTE PROCEDURE "set_updated_at"();
+                "#,
+            )
+        })?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn migrations_with_procedures_can_be_applied_on_mysql(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|contents| {
+            contents.push_str(
+                r#"
+DELIMITER //
+CREATE PROCEDURE `rename_cat`(IN cat_id INT, IN new_name VARCHAR(191))
+BEGIN
+    IF new_name <> '' THEN
+        UPDATE `Cat` SET `name` = new_name WHERE `id` = cat_id;
+    END IF;
+END//
+DELIMITER ;
+                "#,
+            )
+        })?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn migrations_with_triggers_can_be_applied_on_sqlite(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id          Int @id
+            updatedAt   DateTime
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|contents| {
+            contents.push_str(
+                r#"
+                CREATE TRIGGER "cat_updated_at" AFTER UPDATE ON "Cat"
+                BEGIN
+                    UPDATE "Cat" SET "updatedAt" = CURRENT_TIMESTAMP WHERE "id" = NEW."id";
+                END;
+                "#,
+            )
+        })?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn migration_timeouts_only_apply_to_the_migration(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .lock_timeout(1000)
+        .statement_timeout(5000)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    for setting in &["lock_timeout", "statement_timeout"] {
+        let value = api
+            .database()
+            .query_raw(&format!("SHOW {}", setting), &[])
+            .await?
+            .into_single()?
+            .get(*setting)
+            .and_then(|value| value.as_str().map(String::from));
+
+        assert_eq!(value.as_deref(), Some("0"));
+    }
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn migrations_canceled_by_a_statement_timeout_return_a_retryable_error(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|contents| contents.push_str("\nSELECT pg_sleep(2);\n"))?;
+
+    let error = api
+        .apply_migrations(&migrations_directory)
+        .statement_timeout(100)
+        .send_user_facing()
+        .await
+        .unwrap_err();
+
+    let json_error = serde_json::to_value(&error).unwrap();
+
+    assert_eq!(json_error["error_code"], "P3006");
+    assert_eq!(json_error["meta"]["setting"], "statement_timeout");
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn failed_migrations_are_rolled_back_on_postgres(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|contents| contents.push_str("\nSELECT (^.^)_n;\n"))?;
+
+    let result = api.apply_migrations(&migrations_directory).send().await;
+
+    assert!(result.is_err());
+
+    api.assert_schema()
+        .await?
+        .assert_tables_count(1)?
+        .assert_has_table("_prisma_migrations")?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn migrations_with_concurrent_index_creation_can_be_applied_on_postgres(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|contents| {
+            contents.push_str("\nCREATE INDEX CONCURRENTLY \"Cat_name_idx\" ON \"Cat\"(\"name\");\n")
+        })?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_index_on_columns(&["name"], |idx| idx.assert_name("Cat_name_idx"))
+    })?;
+
+    Ok(())
+}
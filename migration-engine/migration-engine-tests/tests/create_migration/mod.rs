@@ -227,3 +227,42 @@ async fn empty_migrations_should_be_created_with_the_draft_option(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn enum_types_are_qualified_with_the_schema(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            color   Color
+        }
+
+        enum Color {
+            BLACK
+            WHITE
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    api.create_migration("create-cats", dm, &dir)
+        .send()
+        .await?
+        .assert_migration("create-cats", |migration| {
+            let expected_script = indoc! {
+                r#"
+                -- CreateEnum
+                CREATE TYPE "prisma-tests"."Color" AS ENUM ('BLACK', 'WHITE');
+                -- CreateTable
+                CREATE TABLE "prisma-tests"."Cat" (
+                "id" integer   NOT NULL ,
+                "color" "prisma-tests"."Color"  NOT NULL ,
+                PRIMARY KEY ("id")
+                );
+                "#
+            };
+
+            migration.assert_contents(expected_script)
+        })?;
+
+    Ok(())
+}
@@ -0,0 +1,219 @@
+use crate::*;
+use migration_core::commands::DiffSource;
+
+fn schema(dm: &str) -> DiffSource {
+    DiffSource::SchemaDatamodel { schema: dm.to_owned() }
+}
+
+fn migrations(directory: &tempfile::TempDir) -> DiffSource {
+    DiffSource::Migrations {
+        migrations_directory_path: directory.path().to_str().unwrap().to_owned(),
+    }
+}
+
+#[test_each_connector]
+async fn diffing_an_empty_schema_with_a_datamodel_creates_its_tables(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.diff(DiffSource::Empty, schema(dm))
+        .send()
+        .await?
+        .assert_script_contains("CREATE TABLE")?
+        .assert_script_contains("Cat")?;
+
+    api.diff(schema(dm), schema(dm)).send().await?.assert_empty()?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn diffing_a_migrations_history_with_a_datamodel_works(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.create_migration("initial", dm1, &directory).send().await?;
+
+    api.diff(migrations(&directory), schema(dm1))
+        .send()
+        .await?
+        .assert_empty()?;
+
+    let dm2 = r#"
+        model Cat {
+            id         Int    @id
+            name       String
+            fluffiness Float
+        }
+    "#;
+
+    api.diff(migrations(&directory), schema(dm2))
+        .send()
+        .await?
+        .assert_script_contains("fluffiness")?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn diffing_does_not_apply_anything(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.diff(DiffSource::Empty, schema(dm))
+        .send()
+        .await?
+        .assert_script_contains("Cat")?;
+
+    api.assert_schema().await?.assert_tables_count(0)?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite"))]
+async fn idempotent_diffs_guard_their_statements(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id    Int    @id
+            email String @unique
+        }
+    "#;
+
+    api.diff(DiffSource::Empty, schema(dm))
+        .idempotent(true)
+        .send()
+        .await?
+        .assert_script_contains("CREATE TABLE IF NOT EXISTS")?
+        .assert_script_contains("CREATE UNIQUE INDEX IF NOT EXISTS")?;
+
+    api.diff(schema(dm), DiffSource::Empty)
+        .idempotent(true)
+        .send()
+        .await?
+        .assert_script_contains("DROP TABLE IF EXISTS")?;
+
+    let script = api
+        .diff(DiffSource::Empty, schema(dm))
+        .send()
+        .await?
+        .into_output()
+        .script;
+
+    assert!(!script.contains("IF NOT EXISTS"), "{}", script);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn idempotent_diffs_guard_the_columns_but_not_their_defaults(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            note String @default("DROP COLUMN ADD COLUMN ")
+        }
+    "#;
+
+    api.diff(schema(dm1), schema(dm2))
+        .idempotent(true)
+        .send()
+        .await?
+        .assert_script_contains(r#"ADD COLUMN IF NOT EXISTS "note""#)?
+        .assert_script_contains("DEFAULT E'DROP COLUMN ADD COLUMN '")?;
+
+    Ok(())
+}
+
+const AUTO_INCREMENT_DM: &str = r#"
+    model Cat {
+        id   Int    @id @default(autoincrement())
+        name String
+    }
+"#;
+
+async fn insert_three_cats(api: &TestApi) -> TestResult {
+    api.schema_push(AUTO_INCREMENT_DM).send().await?.assert_green()?;
+
+    for name in &["Felix", "Garfield", "Tom"] {
+        api.insert("Cat").value("name", *name).result_raw().await?;
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn diffing_with_auto_increment_values_restores_the_mysql_counters() -> TestResult {
+    let db_name = "diffing_with_auto_increment_values_mysql";
+    let api = mysql_test_api(db_name).await;
+
+    insert_three_cats(&api).await?;
+
+    let database = DiffSource::Url {
+        url: mysql_url(db_name),
+    };
+
+    api.diff(DiffSource::Empty, database)
+        .include_auto_increment_values(true)
+        .send()
+        .await?
+        .assert_script_contains("CREATE TABLE")?
+        .assert_script_contains("ALTER TABLE `Cat` AUTO_INCREMENT = 4")?;
+
+    let database = DiffSource::Url {
+        url: mysql_url(db_name),
+    };
+
+    let script = api.diff(DiffSource::Empty, database).send().await?.into_output().script;
+
+    assert!(!script.contains("AUTO_INCREMENT = 4"), "{}", script);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn diffing_with_auto_increment_values_restores_the_postgres_sequences() -> TestResult {
+    let db_name = "diffing_with_auto_increment_values_postgres";
+    let api = postgres_test_api(db_name).await;
+
+    insert_three_cats(&api).await?;
+
+    let database = DiffSource::Url {
+        url: postgres_10_url(db_name),
+    };
+
+    api.diff(DiffSource::Empty, database)
+        .include_auto_increment_values(true)
+        .send()
+        .await?
+        .assert_script_contains("CREATE TABLE")?
+        .assert_script_contains("SELECT setval(pg_get_serial_sequence(")?
+        .assert_script_contains(", 4, false)")?;
+
+    let database = DiffSource::Url {
+        url: postgres_10_url(db_name),
+    };
+
+    let script = api.diff(DiffSource::Empty, database).send().await?.into_output().script;
+
+    assert!(!script.contains("setval"), "{}", script);
+
+    Ok(())
+}
@@ -22,7 +22,10 @@ async fn authentication_failure_must_return_a_known_error_on_postgres() {
         url
     );
 
-    let error = RpcApi::new(&dm).await.map(|_| ()).unwrap_err();
+    let error = RpcApi::new(&dm, Default::default(), None)
+        .await
+        .map(|_| ())
+        .unwrap_err();
 
     let user = url.username();
     let host = url.host().unwrap().to_string();
@@ -59,7 +62,10 @@ async fn authentication_failure_must_return_a_known_error_on_mysql() {
         url
     );
 
-    let error = RpcApi::new(&dm).await.map(|_| ()).unwrap_err();
+    let error = RpcApi::new(&dm, Default::default(), None)
+        .await
+        .map(|_| ())
+        .unwrap_err();
 
     let user = url.username();
     let host = url.host().unwrap().to_string();
@@ -96,7 +102,10 @@ async fn unreachable_database_must_return_a_proper_error_on_mysql() {
         url
     );
 
-    let error = RpcApi::new(&dm).await.map(|_| ()).unwrap_err();
+    let error = RpcApi::new(&dm, Default::default(), None)
+        .await
+        .map(|_| ())
+        .unwrap_err();
 
     let port = url.port().unwrap();
     let host = url.host().unwrap().to_string();
@@ -133,7 +142,10 @@ async fn unreachable_database_must_return_a_proper_error_on_postgres() {
         url
     );
 
-    let error = RpcApi::new(&dm).await.map(|_| ()).unwrap_err();
+    let error = RpcApi::new(&dm, Default::default(), None)
+        .await
+        .map(|_| ())
+        .unwrap_err();
 
     let host = url.host().unwrap().to_string();
     let port = url.port().unwrap();
@@ -171,7 +183,10 @@ async fn database_does_not_exist_must_return_a_proper_error() {
         url
     );
 
-    let error = RpcApi::new(&dm).await.map(|_| ()).unwrap_err();
+    let error = RpcApi::new(&dm, Default::default(), None)
+        .await
+        .map(|_| ())
+        .unwrap_err();
 
     let json_error = serde_json::to_value(&render_error(error)).unwrap();
     let expected = json!({
@@ -214,7 +229,10 @@ async fn database_access_denied_must_return_a_proper_error_in_rpc() {
         url,
     );
 
-    let error = RpcApi::new(&dm).await.map(|_| ()).unwrap_err();
+    let error = RpcApi::new(&dm, Default::default(), None)
+        .await
+        .map(|_| ())
+        .unwrap_err();
     let json_error = serde_json::to_value(&render_error(error)).unwrap();
 
     let expected = json!({
@@ -243,7 +261,7 @@ async fn bad_datasource_url_and_provider_combinations_must_return_a_proper_error
         postgres_10_url(db_name),
     );
 
-    let error = RpcApi::new(&dm).await.map(drop).unwrap_err();
+    let error = RpcApi::new(&dm, Default::default(), None).await.map(drop).unwrap_err();
 
     let json_error = serde_json::to_value(&render_error(error)).unwrap();
 
@@ -273,7 +291,7 @@ async fn connections_to_system_databases_must_be_rejected(_api: &TestApi) -> Tes
         // "mysql" is the default in Quaint.
         let name = if name == &"" { "mysql" } else { name };
 
-        let error = RpcApi::new(&dm).await.map(drop).unwrap_err();
+        let error = RpcApi::new(&dm, Default::default(), None).await.map(drop).unwrap_err();
 
         let json_error = serde_json::to_value(&render_error(error)).unwrap();
 
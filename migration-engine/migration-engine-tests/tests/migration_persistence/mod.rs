@@ -1,4 +1,5 @@
 mod imperative_migration_persistence_tests;
+mod separate_migrations_history_tests;
 
 use migration_connector::{steps::CreateEnum, *};
 use migration_engine_tests::*;
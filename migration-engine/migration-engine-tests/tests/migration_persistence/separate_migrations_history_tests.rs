@@ -0,0 +1,112 @@
+use migration_connector::*;
+use migration_engine_tests::*;
+use pretty_assertions::assert_eq;
+use quaint::single::Quaint;
+use sql_migration_connector::{MigrationsHistoryStorage, SqlMigrationConnector};
+use sql_schema_describer::{sqlite::SqlSchemaDescriber, SqlSchemaDescriberBackend};
+
+async fn table_names(url: &str) -> Vec<String> {
+    let conn = Quaint::new(url).await.unwrap();
+    let schema_name = conn.connection_info().schema_name().to_owned();
+    let schema = SqlSchemaDescriber::new(conn).describe(&schema_name).await.unwrap();
+
+    schema.tables.into_iter().map(|table| table.name).collect()
+}
+
+async fn connector(url: &str, migrations_history: MigrationsHistoryStorage) -> ConnectorResult<SqlMigrationConnector> {
+    SqlMigrationConnector::new(url)
+        .await?
+        .with_migrations_history(migrations_history)
+        .await
+}
+
+#[tokio::test]
+async fn imperative_migrations_can_be_stored_in_a_separate_database() -> TestResult {
+    let url = sqlite_test_url("separate_history_imperative_target");
+    let history_url = sqlite_test_url("separate_history_imperative_history");
+    let connector = connector(&url, MigrationsHistoryStorage::SeparateDatabase(history_url.clone())).await?;
+    let persistence = connector.new_migration_persistence();
+
+    let id = persistence
+        .record_migration_started("initial_migration", "CREATE TABLE cats ( id INTEGER PRIMARY KEY )")
+        .await?;
+    persistence.record_migration_finished(&id).await?;
+
+    let migrations = persistence.list_migrations().await?;
+
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(migrations[0].id, id);
+    assert!(migrations[0].finished_at.is_some());
+
+    assert_eq!(table_names(&history_url).await, &["_prisma_migrations"]);
+    assert!(table_names(&url).await.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn imperative_migrations_are_stored_in_the_migrated_database_by_default() -> TestResult {
+    let url = sqlite_test_url("separate_history_default_target");
+    let connector = connector(&url, MigrationsHistoryStorage::default()).await?;
+    let persistence = connector.new_migration_persistence();
+
+    persistence
+        .record_migration_started("initial_migration", "CREATE TABLE cats ( id INTEGER PRIMARY KEY )")
+        .await?;
+
+    assert_eq!(persistence.list_migrations().await?.len(), 1);
+    assert_eq!(table_names(&url).await, &["_prisma_migrations"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn migrations_can_be_stored_in_a_separate_database() -> TestResult {
+    let url = sqlite_test_url("separate_history_target");
+    let history_url = sqlite_test_url("separate_history_history");
+    let connector = connector(&url, MigrationsHistoryStorage::SeparateDatabase(history_url.clone())).await?;
+    let api = test_api(connector).await;
+
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    InferApply::new(&api, dm)
+        .migration_id(Some("first_migration"))
+        .send()
+        .await?
+        .assert_green()?;
+
+    let migrations = api.connector().migration_persistence().load_all().await?;
+
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(migrations[0].name, "first_migration");
+
+    assert_eq!(table_names(&history_url).await, &["_Migration"]);
+    assert_eq!(table_names(&url).await, &["Cat"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn resetting_clears_the_separate_migrations_history() -> TestResult {
+    let url = sqlite_test_url("separate_history_reset_target");
+    let history_url = sqlite_test_url("separate_history_reset_history");
+    let connector = connector(&url, MigrationsHistoryStorage::SeparateDatabase(history_url)).await?;
+
+    connector.initialize().await?;
+
+    let persistence = connector.new_migration_persistence();
+    persistence
+        .record_migration_started("initial_migration", "CREATE TABLE cats ( id INTEGER PRIMARY KEY )")
+        .await?;
+
+    connector.reset().await?;
+
+    assert!(persistence.list_migrations().await?.is_empty());
+    assert!(connector.migration_persistence().load_all().await?.is_empty());
+
+    Ok(())
+}
@@ -5,6 +5,7 @@ mod create_migration;
 mod datamodel_calculator;
 mod datamodel_steps_inferrer;
 mod diagnose_migration_history;
+mod diff;
 mod errors;
 mod existing_data;
 mod existing_databases;
@@ -15,8 +16,12 @@ mod migrations;
 mod multi_user;
 mod plan_migration;
 mod reset;
+mod rollback_migration;
 mod schema_push;
+mod shadow_database;
+mod squash_migrations;
 mod unapply_migration;
+mod verify_migration_checksums;
 
 use migration_engine_tests::sql::*;
 use pretty_assertions::assert_eq;
@@ -62,6 +67,53 @@ async fn adding_a_scalar_field_must_work(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+// SQLite only auto-increments `INTEGER PRIMARY KEY` columns.
+#[test_each_connector(ignore("sqlite"))]
+async fn bigint_fields_get_bigint_columns(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Test {
+            id      BigInt @id @default(autoincrement())
+            counter BigInt
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Test", |table| {
+        table
+            .assert_column("id", |c| c.assert_type_family(ColumnTypeFamily::BigInt))?
+            .assert_column("counter", |c| {
+                c.assert_is_required()?.assert_type_family(ColumnTypeFamily::BigInt)
+            })
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn bytes_fields_get_binary_columns(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Test {
+            id      Int    @id
+            payload Bytes
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Test", |table| {
+        table.assert_column("payload", |c| {
+            c.assert_is_required()?.assert_type_family(ColumnTypeFamily::Binary)
+        })
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
 #[test_each_connector(capabilities("enums"))]
 async fn adding_an_enum_field_must_work(api: &TestApi) -> TestResult {
     let dm = r#"
@@ -1334,6 +1386,9 @@ async fn foreign_keys_of_inline_one_to_one_relations_have_a_unique_constraint(ap
         name: "Box_cat_id_unique".into(),
         columns: vec!["cat_id".into()],
         tpe: IndexType::Unique,
+        clustered: None,
+        predicate: None,
+        nulls_not_distinct: false,
     }];
 
     assert_eq!(box_table.indices, expected_indexes);
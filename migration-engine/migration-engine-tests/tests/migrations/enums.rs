@@ -1,4 +1,5 @@
 use migration_engine_tests::sql::*;
+use prisma_value::PrismaValue;
 
 #[test_each_connector(capabilities("enums"))]
 async fn an_enum_can_be_turned_into_a_model(api: &TestApi) -> TestResult {
@@ -134,16 +135,326 @@ async fn variants_can_be_removed_from_an_existing_enum(api: &TestApi) -> TestRes
         }
     "#;
 
+    let res = api.schema_push(dm2).force(true).send().await?;
+
+    if api.sql_family().is_mysql() {
+        // The enum is defined on the column, and no row uses the removed value.
+        res.assert_no_warning()?.assert_executable()?;
+    } else {
+        res.assert_warnings(&[format!("The migration will remove the values [HAPPY] on the enum `{}`. If these variants are still used in the database, the migration will fail.", enum_name).into()])?
+            .assert_executable()?;
+    }
+
+    api.assert_schema()
+        .await?
+        .assert_enum(enum_name, |enm| enm.assert_values(&["HUNGRY"]))?;
+
+    Ok(())
+}
+
+#[test_each_connector(capabilities("enums"))]
+async fn variants_can_be_removed_from_an_existing_enum_with_a_default(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            mood CatMood @default(HUNGRY)
+        }
+
+        enum CatMood {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+    api.schema_push(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let enum_name = if api.sql_family().is_mysql() {
+        "Cat_mood"
+    } else {
+        "CatMood"
+    };
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            mood CatMood @default(HUNGRY)
+        }
+
+        enum CatMood {
+            HUNGRY
+        }
+    "#;
+
+    let res = api.schema_push(dm2).force(true).send().await?;
+
+    if api.sql_family().is_mysql() {
+        // The enum is defined on the column, and no row uses the removed value.
+        res.assert_no_warning()?.assert_executable()?;
+    } else {
+        res.assert_warnings(&[format!("The migration will remove the values [HAPPY] on the enum `{}`. If these variants are still used in the database, the migration will fail.", enum_name).into()])?
+            .assert_executable()?;
+    }
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    api.assert_schema()
+        .await?
+        .assert_enum(enum_name, |enm| enm.assert_values(&["HUNGRY"]))?
+        .assert_table("Cat", |table| {
+            table.assert_column("mood", |col| {
+                col.assert_default_value(&PrismaValue::Enum("HUNGRY".to_owned()))
+            })
+        })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn enums_with_the_same_variants_are_renamed_in_place(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("mood", "HAPPY")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    api.assert_schema()
+        .await?
+        .assert_enum("Mood", |enm| enm.assert_values(&["HAPPY", "HUNGRY"]))?
+        .assert_has_no_enum("CatMood")?;
+
+    let rows = api.select("Cat").column("mood").send_debug().await?;
+    assert_eq!(rows, &[[r#"Enum(Some("HAPPY"))"#]]);
+
+    Ok(())
+}
+
+#[test_each_connector(capabilities("enums"))]
+async fn renamed_enum_variants_keep_their_rows(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+
+    let directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &directory).send().await?;
+    api.apply_migrations(&directory).send().await?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("mood", "HUNGRY")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HAPPY
+            STARVING
+        }
+    "#;
+
+    api.create_migration("rename-hungry", dm2, &directory)
+        .rename_enum_value("CatMood", "HUNGRY", "STARVING")
+        .send()
+        .await?;
+
+    api.apply_migrations(&directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["rename-hungry"])?;
+
+    let enum_name = if api.sql_family().is_mysql() {
+        "Cat_mood"
+    } else {
+        "CatMood"
+    };
+
+    api.assert_schema()
+        .await?
+        .assert_enum(enum_name, |enm| enm.assert_values(&["HAPPY", "STARVING"]))?;
+
+    let rows = api.select("Cat").column("mood").send_debug().await?;
+
+    if api.sql_family().is_mysql() {
+        assert_eq!(rows, &[[r#"Text(Some("STARVING"))"#]]);
+    } else {
+        assert_eq!(rows, &[[r#"Enum(Some("STARVING"))"#]]);
+    }
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn reordering_enum_variants_keeps_the_rows_on_mysql(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HAPPY
+            HUNGRY
+            SLEEPY
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("mood", "HUNGRY")
+        .result_raw()
+        .await?;
+
+    api.insert("Cat")
+        .value("id", 2)
+        .value("mood", "SLEEPY")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            SLEEPY
+            HUNGRY
+            HAPPY
+        }
+    "#;
+
     api.schema_push(dm2)
-        .force(true)
         .send()
         .await?
-        .assert_warnings(&[format!("The migration will remove the values [HAPPY] on the enum `{}`. If these variants are still used in the database, the migration will fail.", enum_name).into()])?
-        .assert_executable()?;
+        .assert_green()?
+        .assert_has_executed_steps()?;
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
 
     api.assert_schema()
         .await?
-        .assert_enum(enum_name, |enm| enm.assert_values(&["HUNGRY"]))?;
+        .assert_enum("Cat_mood", |enm| enm.assert_values(&["SLEEPY", "HUNGRY", "HAPPY"]))?;
+
+    let rows = api.select("Cat").column("mood").send_debug().await?;
+    assert_eq!(rows, &[[r#"Text(Some("HUNGRY"))"#], [r#"Text(Some("SLEEPY"))"#]]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn removing_enum_variants_used_by_rows_warns_with_the_row_count_on_mysql(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("mood", "HAPPY")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HUNGRY
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_warnings(&["The migration will remove the values [HAPPY] on the enum `Cat_mood`, which are still used in 1 rows of the `mood` column on the `Cat` table. The migration will fail.".into()])?;
+
+    api.assert_schema()
+        .await?
+        .assert_enum("Cat_mood", |enm| enm.assert_values(&["HAPPY", "HUNGRY"]))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn enum_values_with_quotes_and_commas_roundtrip_as_defaults_on_mysql(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+            mood CatMood @default(HUNGRY)
+        }
+
+        enum CatMood {
+            HAPPY  @map("it's, happy")
+            HUNGRY @map("hungry, 'very'")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    api.assert_schema()
+        .await?
+        .assert_enum("Cat_mood", |enm| enm.assert_values(&["it's, happy", "hungry, 'very'"]))?
+        .assert_table("Cat", |table| {
+            table.assert_column("mood", |col| {
+                col.assert_default_value(&PrismaValue::Enum("hungry, 'very'".to_owned()))
+            })
+        })?;
 
     Ok(())
 }
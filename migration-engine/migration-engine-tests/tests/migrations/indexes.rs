@@ -436,6 +436,7 @@ async fn index_updates_with_rename_must_work(api: &TestApi) {
             SqlMigrationStep::DropIndex(DropIndex {
                 table: "A".into(),
                 name: "customName".into(),
+                memory_optimized: false,
             }),
             SqlMigrationStep::CreateIndex(CreateIndex {
                 table: "A".into(),
@@ -443,9 +444,13 @@ async fn index_updates_with_rename_must_work(api: &TestApi) {
                     name: "customNameA".into(),
                     columns: vec!["field".into(), "id".into()],
                     tpe: IndexType::Unique,
+                    clustered: None,
+                    predicate: None,
+                    nulls_not_distinct: false,
                 },
                 caused_by_create_table: false,
                 contains_nullable_columns: false,
+                memory_optimized: false,
             }),
         ];
         let actual_steps = result.sql_migration();
@@ -69,3 +69,21 @@ async fn foreign_keys_to_indexes_being_renamed_must_work(api: &TestApi) -> TestR
 
     Ok(())
 }
+
+#[test_each_connector(tags("mariadb"))]
+async fn explicit_null_defaults_do_not_cause_alters(api: &TestApi) -> TestResult {
+    api.database()
+        .raw_cmd("CREATE TABLE `Cat` (`id` INTEGER NOT NULL PRIMARY KEY, `name` VARCHAR(191) NULL DEFAULT NULL)")
+        .await?;
+
+    let dm = r#"
+        model Cat {
+            id   Int     @id
+            name String?
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
@@ -282,3 +282,103 @@ async fn native_type_columns_can_be_created(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql"), ignore("mysql_5_6"))]
+async fn generated_columns_are_left_alone(api: &TestApi) -> TestResult {
+    let sql = r#"
+        CREATE TABLE Person (
+            id INTEGER PRIMARY KEY,
+            firstName VARCHAR(100) NOT NULL,
+            lastName VARCHAR(100) NOT NULL,
+            fullName VARCHAR(201) AS (CONCAT(firstName, ' ', lastName)) VIRTUAL NOT NULL
+        );
+    "#;
+
+    api.database().raw_cmd(sql).await?;
+
+    let dm = r#"
+        model Person {
+            id        Int    @id
+            firstName String
+            lastName  String
+            fullName  String @default(dbgenerated()) @readOnly
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn fields_added_in_the_middle_of_a_model_are_positioned_accordingly(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id    Int    @id
+            title String
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Test {
+            first    String
+            id       Int    @id
+            subtitle String
+            title    String
+            last     String
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Test", |table| {
+        table.assert_column_order(&["first", "id", "subtitle", "title", "last"])
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn relations_get_indexes_instead_of_foreign_keys_in_prisma_relation_mode(api: &TestApi) -> TestResult {
+    let api = api.with_relation_mode(datamodel::RelationMode::Prisma).await;
+
+    let dm = r#"
+        model Human {
+            id      Int    @id
+            catname String
+            cat     Cat    @relation(fields: [catname], references: [name])
+            dogs    Dog[]
+
+            @@index([catname])
+        }
+
+        model Cat {
+            id     Int     @id
+            name   String  @unique
+            humans Human[]
+        }
+
+        model Dog {
+            id     Int     @id
+            humans Human[]
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Human", |table| {
+            table
+                .assert_foreign_keys_count(0)?
+                .assert_indexes_count(1)?
+                .assert_index_on_columns(&["catname"], |idx| idx.assert_is_not_unique())
+        })?
+        .assert_table("_DogToHuman", |table| table.assert_foreign_keys_count(0))?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
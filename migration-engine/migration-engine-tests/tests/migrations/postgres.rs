@@ -1,6 +1,6 @@
 use migration_engine_tests::*;
 use quaint::prelude::Queryable;
-use sql_schema_describer::{ColumnArity, ColumnTypeFamily};
+use sql_schema_describer::{ColumnArity, ColumnTypeFamily, DefaultValue, ForeignKeyAction, IdentityGeneration};
 use std::fmt::Write;
 
 #[test_each_connector(tags("postgres"))]
@@ -122,11 +122,19 @@ async fn native_type_columns_can_be_created(api: &TestApi) -> TestResult {
         ("time", "DateTime", "Time(2)", "time"),
         ("timetz", "DateTime", "TimeWithTimeZone(2)", "timetz"),
         ("interval", "Duration", "Interval(2)", "interval"),
+        ("int4range", "String", "Int4Range", "int4range"),
+        ("int8range", "String", "Int8Range", "int8range"),
+        ("numrange", "String", "NumRange", "numrange"),
+        ("tsrange", "String", "TsRange", "tsrange"),
+        ("tstzrange", "String", "TsTzRange", "tstzrange"),
+        ("daterange", "String", "DateRange", "daterange"),
         ("bool", "Boolean", "Boolean", "bool"),
         ("bit", "String", "Bit(1)", "bit"),
         ("varbit", "String", "VarBit(1)", "varbit"),
         ("uuid", "String", "Uuid", "uuid"),
-        ("xml", "XML", "Xml", "xml"),
+        ("xml", "String", "Xml", "xml"),
+        ("tsvector", "String", "TsVector", "tsvector"),
+        ("tsquery", "String", "TsQuery", "tsquery"),
         ("json", "Json", "Json", "json"),
         ("jsonb", "Json", "JsonB", "jsonb"),
     ];
@@ -162,3 +170,353 @@ async fn native_type_columns_can_be_created(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn shared_sequences_can_be_created_and_used_in_defaults(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id @default(sequence("pet_ids"))
+        }
+
+        model Dog {
+            id     Int @id @default(sequence("pet_ids"))
+            number Int @default(sequence("dog_numbers"))
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    let schema = api.assert_schema().await?.into_schema();
+
+    assert!(schema.get_sequence("pet_ids").is_some());
+    assert!(schema.get_sequence("dog_numbers").is_some());
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| {
+            table.assert_column("id", |column| {
+                column.assert_default(Some(DefaultValue::NEXTVAL("pet_ids".into())))
+            })
+        })?
+        .assert_table("Dog", |table| {
+            table
+                .assert_column("id", |column| {
+                    column.assert_default(Some(DefaultValue::NEXTVAL("pet_ids".into())))
+                })?
+                .assert_column("number", |column| {
+                    column.assert_default(Some(DefaultValue::NEXTVAL("dog_numbers".into())))
+                })
+        })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    api.database()
+        .raw_cmd(&format!(
+            r#"INSERT INTO "{0}"."Cat" DEFAULT VALUES; INSERT INTO "{0}"."Dog" ("id") VALUES (DEFAULT);"#,
+            api.schema_name()
+        ))
+        .await?;
+
+    let dog_id = api
+        .database()
+        .query_raw(&format!(r#"SELECT "id" FROM "{}"."Dog""#, api.schema_name()), &[])
+        .await?
+        .into_single()?
+        .get("id")
+        .and_then(|id| id.as_i64());
+
+    assert_eq!(dog_id, Some(2));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn uuid_defaults_are_rendered_as_gen_random_uuid_from_postgres_13_on(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id String @id @default(uuid())
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    // The uuid returned by the function is cast to the type of the text column.
+    let expected_default = if api.connector_name() == "postgres13" {
+        Some(DefaultValue::DBGENERATED("(gen_random_uuid())::text".into()))
+    } else {
+        None
+    };
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("id", |column| column.assert_default(expected_default))
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn existing_database_defaults_are_kept_for_uuid_defaults(api: &TestApi) -> TestResult {
+    api.database()
+        .raw_cmd(&format!(
+            r#"CREATE TABLE "{}"."Cat" ("id" TEXT PRIMARY KEY DEFAULT md5(random()::text))"#,
+            api.schema_name()
+        ))
+        .await?;
+
+    let dm = r#"
+        model Cat {
+            id String @id @default(uuid())
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("id", |column| {
+            column.assert_default(Some(DefaultValue::DBGENERATED("md5((random())::text)".into())))
+        })
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn existing_partial_indexes_match_their_datamodel_counterpart(api: &TestApi) -> TestResult {
+    api.database()
+        .raw_cmd(&format!(
+            r#"
+                CREATE TABLE "{0}"."Post" ("id" INTEGER PRIMARY KEY, "slug" TEXT NOT NULL, "deletedAt" TIMESTAMP(3));
+                CREATE UNIQUE INDEX "live_slug_unique" ON "{0}"."Post" ("slug") WHERE "deletedAt" IS NULL;
+            "#,
+            api.schema_name()
+        ))
+        .await?;
+
+    let dm = r#"
+        model Post {
+            id        Int       @id
+            slug      String
+            deletedAt DateTime?
+
+            @@unique([slug], name: "live_slug_unique", where: "\"deletedAt\" IS NULL")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_index_on_columns(&["slug"], |index| {
+            index.assert_name("live_slug_unique")?.assert_is_unique()
+        })
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn partial_indexes_can_be_created_from_the_datamodel(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Post {
+            id        Int       @id
+            slug      String
+            deletedAt DateTime?
+
+            @@unique([slug], where: "\"deletedAt\" IS NULL")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_index_on_columns(&["slug"], |index| index.assert_is_unique())
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn check_constraints_are_left_alone(api: &TestApi) -> TestResult {
+    api.database()
+        .raw_cmd(&format!(
+            r#"CREATE TABLE "{}"."Product" ("id" INTEGER PRIMARY KEY, "price" INTEGER NOT NULL CONSTRAINT "positive_price" CHECK ("price" > 0))"#,
+            api.schema_name()
+        ))
+        .await?;
+
+    let dm = r#"
+        model Product {
+            id    Int @id
+            price Int
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    let schema = api.describe_database().await?;
+    let checks: Vec<_> = schema
+        .check_constraints_on("Product")
+        .map(|c| c.name.as_str())
+        .collect();
+    assert_eq!(checks, &["positive_price"]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn check_constraints_on_altered_columns_are_added_again(api: &TestApi) -> TestResult {
+    api.database()
+        .raw_cmd(&format!(
+            r#"
+                CREATE TABLE "{0}"."Product" ("id" INTEGER PRIMARY KEY, "price" INTEGER NOT NULL, "stock" INTEGER NOT NULL);
+                ALTER TABLE "{0}"."Product" ADD CONSTRAINT "positive_price" CHECK ("price" > 0);
+                ALTER TABLE "{0}"."Product" ADD CONSTRAINT "positive_stock" CHECK ("stock" >= 0);
+            "#,
+            api.schema_name()
+        ))
+        .await?;
+
+    let dm = r#"
+        model Product {
+            id    Int   @id
+            price Float
+        }
+    "#;
+
+    api.schema_push(dm).force(true).send().await?.assert_executable()?;
+
+    let schema = api.describe_database().await?;
+    let checks: Vec<_> = schema.check_constraints_on("Product").collect();
+
+    // The check on the dropped column is gone with it.
+    assert_eq!(checks.len(), 1);
+    assert_eq!(checks[0].name, "positive_price");
+    assert!(checks[0].references_column("price"));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn referential_actions_are_rendered_on_foreign_keys(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model User {
+            id       Int       @id
+            posts    Post[]
+            comments Comment[]
+            likes    Like[]
+        }
+
+        model Post {
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id], onDelete: RESTRICT)
+        }
+
+        model Comment {
+            id       Int  @id
+            authorId Int  @default(0)
+            author   User @relation(fields: [authorId], references: [id], onDelete: SET_DEFAULT)
+        }
+
+        model Like {
+            id     Int   @id
+            userId Int?
+            user   User? @relation(fields: [userId], references: [id], onDelete: CASCADE)
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Post", |table| {
+            table.assert_fk_on_columns(&["authorId"], |fk| fk.assert_on_delete(ForeignKeyAction::Restrict))
+        })?
+        .assert_table("Comment", |table| {
+            table.assert_fk_on_columns(&["authorId"], |fk| fk.assert_on_delete(ForeignKeyAction::SetDefault))
+        })?
+        .assert_table("Like", |table| {
+            table.assert_fk_on_columns(&["userId"], |fk| fk.assert_on_delete(ForeignKeyAction::Cascade))
+        })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"), ignore("postgres9"))]
+async fn autoincrement_columns_can_be_created_as_identity_columns(api: &TestApi) -> TestResult {
+    let api = api.with_connection_string_params(&[("identity_columns", "true")]).await;
+
+    let dm = r#"
+        model Cat {
+            id Int @id @default(autoincrement())
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("id", |column| {
+            column
+                .assert_auto_increments()?
+                .assert_default(Some(DefaultValue::IDENTITY(IdentityGeneration::ByDefault)))
+        })
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"), ignore("postgres9"))]
+async fn serial_columns_are_converted_to_identity_columns(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id @default(autoincrement())
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.database()
+        .raw_cmd(&format!(
+            r#"INSERT INTO "{0}"."Cat" DEFAULT VALUES; INSERT INTO "{0}"."Cat" DEFAULT VALUES;"#,
+            api.schema_name()
+        ))
+        .await?;
+
+    let identity_api = api.with_connection_string_params(&[("identity_columns", "true")]).await;
+
+    identity_api.schema_push(dm).send().await?.assert_green()?;
+
+    identity_api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("id", |column| {
+            column.assert_default(Some(DefaultValue::IDENTITY(IdentityGeneration::ByDefault)))
+        })
+    })?;
+
+    // The identity continues after the existing rows.
+    api.database()
+        .raw_cmd(&format!(r#"INSERT INTO "{}"."Cat" DEFAULT VALUES"#, api.schema_name()))
+        .await?;
+
+    let max_id = api
+        .database()
+        .query_raw(
+            &format!(r#"SELECT MAX("id") AS "id" FROM "{}"."Cat""#, api.schema_name()),
+            &[],
+        )
+        .await?
+        .into_single()?
+        .get("id")
+        .and_then(|id| id.as_i64());
+
+    assert_eq!(max_id, Some(3));
+
+    // Identity columns are left alone when the engine renders `SERIAL` columns again.
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use crate::*;
+use pretty_assertions::assert_eq;
+
+#[test_each_connector]
+async fn rollback_migration_on_an_empty_history_does_nothing(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let output = api.rollback_migration(&directory).send().await?;
+
+    assert_eq!(output.rolled_back_migration_name, None);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn create_migration_writes_a_down_migration_script(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migration_name = api
+        .create_migration("initial", dm, &directory)
+        .send()
+        .await?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    let down_script = std::fs::read_to_string(directory.path().join(&migration_name).join("down.sql"))?;
+
+    assert!(down_script.contains("DROP TABLE"), "{}", down_script);
+    assert!(down_script.contains("Cat"), "{}", down_script);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn rollback_migration_reverts_the_last_migration(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    api.create_migration("initial", dm1, &directory).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+
+        model Dog {
+            id      Int @id
+        }
+    "#;
+
+    let second_migration_name = api
+        .create_migration("second-migration", dm2, &directory)
+        .send()
+        .await?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    api.apply_migrations(&directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "second-migration"])?;
+
+    let output = api.rollback_migration(&directory).send().await?;
+
+    assert_eq!(output.rolled_back_migration_name, Some(second_migration_name));
+
+    let schema = api.describe_database().await?;
+
+    assert!(schema.has_table("Cat"));
+    assert!(!schema.has_table("Dog"));
+
+    // The rolled back migration counts as unapplied again.
+    api.apply_migrations(&directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["second-migration"])?;
+
+    api.assert_schema().await?.assert_has_table("Dog")?;
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use migration_core::commands::HistoryDiagnostic;
+use migration_engine_tests::*;
+use pretty_assertions::assert_eq;
+use sql_migration_connector::SqlMigrationConnector;
+
+async fn connector_with_shadow_database(name: &str) -> anyhow::Result<SqlMigrationConnector> {
+    let url = sqlite_test_url(name);
+    let shadow_database_url = sqlite_test_url(&format!("{}_shadow", name));
+
+    std::fs::remove_file(sqlite_test_file(name)).ok();
+
+    let connector = SqlMigrationConnector::new(&url)
+        .await?
+        .with_shadow_database(&shadow_database_url)
+        .await?;
+
+    Ok(connector)
+}
+
+#[tokio::test]
+async fn drift_is_detected_with_a_configured_shadow_database() -> TestResult {
+    let connector = connector_with_shadow_database("drift_with_configured_shadow_database").await?;
+    let api = test_api(connector).await;
+    let directory = tempfile::tempdir()?;
+
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    CreateMigration::new(&api, "initial", dm1, &directory).send().await?;
+    ApplyMigrations::new(&api, &directory).send().await?;
+
+    let result = DiagnoseMigrationHistory::new(&api, &directory)
+        .send()
+        .await?
+        .into_output();
+
+    assert_eq!(result.history_problems, &[]);
+
+    let dm2 = r#"
+        model Cat {
+            id          Int    @id
+            name        String
+            fluffiness  Float?
+        }
+    "#;
+
+    SchemaPush::new(&api, dm2.to_owned()).send().await?;
+
+    let result = DiagnoseMigrationHistory::new(&api, &directory)
+        .send()
+        .await?
+        .into_output();
+
+    assert_eq!(result.history_problems, &[HistoryDiagnostic::DriftDetected]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn the_shadow_database_cannot_be_the_migrated_database() -> TestResult {
+    let url = sqlite_test_url("shadow_database_is_the_migrated_database");
+    let result = SqlMigrationConnector::new(&url).await?.with_shadow_database(&url).await;
+
+    assert!(result.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use crate::*;
+use pretty_assertions::assert_eq;
+
+const DM1: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+"#;
+
+const DM2: &str = r#"
+    model Cat {
+        id         Int    @id
+        name       String
+        fluffiness Float
+    }
+"#;
+
+const DM3: &str = r#"
+    model Cat {
+        id         Int    @id
+        name       String
+        fluffiness Float
+    }
+
+    model Dog {
+        id Int @id
+    }
+"#;
+
+async fn create_three_migrations(api: &TestApi, directory: &tempfile::TempDir) -> anyhow::Result<Vec<String>> {
+    let mut names = Vec::new();
+
+    for (name, dm) in &[("initial", DM1), ("second", DM2), ("third", DM3)] {
+        let output = api.create_migration(name, dm, directory).send().await?.into_output();
+
+        names.push(output.generated_migration_name.unwrap());
+    }
+
+    Ok(names)
+}
+
+#[test_each_connector]
+async fn squashed_migrations_apply_to_the_same_schema(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+    let names = create_three_migrations(api, &directory).await?;
+
+    let output = api
+        .squash_migrations(&directory, names[1].as_str(), names[2].as_str())
+        .send()
+        .await?;
+
+    assert_eq!(output.squashed_migration_name, names[2]);
+    assert_eq!(output.removed_migration_names, &[names[1].clone()]);
+
+    api.apply_migrations(&directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "third"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("fluffiness"))?
+        .assert_has_table("Dog")?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn squashing_applied_migrations_keeps_the_history_consistent(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+    let names = create_three_migrations(api, &directory).await?;
+
+    api.apply_migrations(&directory).send().await?;
+
+    api.squash_migrations(&directory, names[0].as_str(), names[1].as_str())
+        .send()
+        .await?;
+
+    let recorded_names: Vec<String> = api
+        .imperative_migration_persistence()
+        .list_migrations()
+        .await?
+        .into_iter()
+        .map(|migration| migration.migration_name)
+        .collect();
+
+    assert_eq!(recorded_names, &[names[1].clone(), names[2].clone()]);
+
+    let result = api.diagnose_migration_history(&directory).send().await?.into_output();
+
+    assert_eq!(result.history_problems, &[]);
+
+    api.apply_migrations(&directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn squashing_migrations_in_the_wrong_order_fails(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+    let names = create_three_migrations(api, &directory).await?;
+
+    let result = api
+        .squash_migrations(&directory, names[2].as_str(), names[0].as_str())
+        .send()
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(migration_connector::list_migrations(directory.path())?.len(), 3);
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use crate::*;
+use migration_connector::ImperativeMigrationsPersistence;
+use pretty_assertions::assert_eq;
+
+#[test_each_connector]
+async fn verify_migration_checksums_ignores_unedited_and_unapplied_migrations(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    api.create_migration("initial", dm1, &directory).send().await?;
+    api.apply_migrations(&directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    let dm2 = r#"
+        model Cat {
+            id          Int @id
+            name        String
+            fluffiness  Float
+        }
+    "#;
+
+    api.create_migration("second-migration", dm2, &directory).send().await?;
+
+    let output = api.verify_migration_checksums(&directory).send().await?;
+
+    assert!(output.edited_migrations.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn verify_migration_checksums_reports_migrations_edited_after_they_were_applied(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let initial_assertions = api.create_migration("initial", dm1, &directory).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id          Int @id
+            name        String
+            fluffiness  Float
+        }
+    "#;
+
+    api.create_migration("second-migration", dm2, &directory).send().await?;
+
+    api.apply_migrations(&directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "second-migration"])?;
+
+    let initial_migration_name = initial_assertions
+        .modify_migration(|script| {
+            std::mem::swap(script, &mut format!("/* test */\n{}", script));
+        })?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    let applied_checksum = api
+        .imperative_migration_persistence()
+        .list_migrations()
+        .await?
+        .into_iter()
+        .find(|migration| migration.migration_name == initial_migration_name)
+        .unwrap()
+        .checksum;
+
+    let output = api.verify_migration_checksums(&directory).send().await?;
+
+    assert_eq!(output.edited_migrations.len(), 1);
+
+    let edited_migration = &output.edited_migrations[0];
+
+    assert_eq!(edited_migration.migration_name, initial_migration_name);
+    assert_eq!(edited_migration.applied_checksum, applied_checksum);
+    assert_ne!(edited_migration.filesystem_checksum, applied_checksum);
+
+    Ok(())
+}
@@ -34,3 +34,12 @@ pub fn run(opts: FormatOpts) {
         }
     }
 }
+
+/// Formats the given schema in memory.
+pub fn format(schema: &str, tab_width: usize) -> String {
+    let mut buf = Vec::new();
+
+    Reformatter::new(schema).reformat_to(&mut buf, tab_width);
+
+    String::from_utf8(buf).expect("The formatted schema is not valid UTF-8.")
+}
@@ -10,28 +10,30 @@ pub fn run(opts: LintOpts) {
         .read_to_string(&mut datamodel_string)
         .expect("Unable to read from stdin.");
 
-    let datamodel_result = if opts.no_env_errors {
-        datamodel::parse_datamodel_and_ignore_datasource_urls(&datamodel_string)
+    let mini_errors = lint(&datamodel_string, opts.no_env_errors);
+    let json = serde_json::to_string(&mini_errors).expect("Failed to render JSON");
+
+    print!("{}", json)
+}
+
+/// Validates the given schema, returning the errors found, if any.
+pub fn lint(schema: &str, no_env_errors: bool) -> Vec<MiniError> {
+    let datamodel_result = if no_env_errors {
+        datamodel::parse_datamodel_and_ignore_datasource_urls(schema)
     } else {
-        datamodel::parse_datamodel(&datamodel_string)
+        datamodel::parse_datamodel(schema)
     };
 
     match datamodel_result {
-        Err(err) => {
-            let mini_errors: Vec<MiniError> = err
-                .errors
-                .iter()
-                .map(|err: &DatamodelError| MiniError {
-                    start: err.span().start,
-                    end: err.span().end,
-                    text: format!("{}", err),
-                })
-                .collect();
-
-            let json = serde_json::to_string(&mini_errors).expect("Failed to render JSON");
-
-            print!("{}", json)
-        }
-        _ => print!("[]"),
+        Err(err) => err
+            .errors
+            .iter()
+            .map(|err: &DatamodelError| MiniError {
+                start: err.span().start,
+                end: err.span().end,
+                text: format!("{}", err),
+            })
+            .collect(),
+        _ => Vec::new(),
     }
 }
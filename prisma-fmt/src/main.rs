@@ -1,6 +1,7 @@
 mod format;
 mod lint;
 mod native;
+mod rpc;
 
 use std::path::PathBuf;
 
@@ -39,9 +40,11 @@ pub enum FmtOpts {
     Format(FormatOpts),
     /// Specifies Native Types mode
     NativeTypes,
+    /// Starts a JSON-RPC server over stdio, serving the other modes as methods
+    Rpc,
 }
 
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, Debug)]
 pub struct MiniError {
     pub start: usize,
     pub end: usize,
@@ -53,5 +56,6 @@ fn main() {
         FmtOpts::Lint(opts) => lint::run(opts),
         FmtOpts::Format(opts) => format::run(opts),
         FmtOpts::NativeTypes => native::run(),
+        FmtOpts::Rpc => rpc::run(),
     }
 }
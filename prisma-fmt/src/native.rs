@@ -8,26 +8,23 @@ pub fn run() {
         .read_to_string(&mut datamodel_string)
         .expect("Unable to read from stdin.");
 
-    let datamodel_result = datamodel::parse_configuration_and_ignore_datasource_urls(&datamodel_string);
+    let json = serde_json::to_string(&native_types(&datamodel_string)).expect("Failed to render JSON");
 
-    match datamodel_result {
-        Ok(configuration) => {
-            if configuration.datasources.len() != 1 {
-                print!("[]")
-            } else {
-                if let Some(datasource) = configuration.datasources.first() {
-                    let available_native_type_constructors =
-                        datasource.active_connector.available_native_type_constructors();
+    print!("{}", json)
+}
 
-                    let json =
-                        serde_json::to_string(available_native_type_constructors).expect("Failed to render JSON");
+/// The native type constructors of the connector of the only datasource of the given schema. Empty
+/// if the schema is invalid or doesn't have exactly one datasource.
+pub fn native_types(schema: &str) -> serde_json::Value {
+    let datamodel_result = datamodel::parse_configuration_and_ignore_datasource_urls(schema);
+
+    match datamodel_result {
+        Ok(configuration) if configuration.datasources.len() == 1 => {
+            let datasource = configuration.datasources.first().unwrap();
+            let available_native_type_constructors = datasource.active_connector.available_native_type_constructors();
 
-                    print!("{}", json)
-                } else {
-                    print!("[]")
-                }
-            }
+            serde_json::to_value(available_native_type_constructors).expect("Failed to render JSON")
         }
-        _ => print!("[]"),
+        _ => serde_json::Value::Array(Vec::new()),
     }
 }
@@ -0,0 +1,74 @@
+use crate::{format, lint, native, MiniError};
+use jsonrpc_core::IoHandler;
+use jsonrpc_derive::rpc;
+use serde::Deserialize;
+
+type RpcResult<T> = jsonrpc_core::Result<T>;
+
+/// The modes of the CLI, as JSON-RPC methods taking the schema as a parameter instead of reading
+/// it from stdin.
+#[rpc]
+pub trait Rpc {
+    #[rpc(name = "format")]
+    fn format(&self, input: FormatInput) -> RpcResult<String>;
+
+    #[rpc(name = "lint")]
+    fn lint(&self, input: LintInput) -> RpcResult<Vec<MiniError>>;
+
+    #[rpc(name = "nativeTypes")]
+    fn native_types(&self, input: NativeTypesInput) -> RpcResult<serde_json::Value>;
+}
+
+pub struct RpcImpl;
+
+impl Rpc for RpcImpl {
+    fn format(&self, input: FormatInput) -> RpcResult<String> {
+        Ok(format::format(&input.schema, input.tab_width))
+    }
+
+    fn lint(&self, input: LintInput) -> RpcResult<Vec<MiniError>> {
+        Ok(lint::lint(&input.schema, input.no_env_errors))
+    }
+
+    fn native_types(&self, input: NativeTypesInput) -> RpcResult<serde_json::Value> {
+        Ok(native::native_types(&input.schema))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FormatInput {
+    schema: String,
+    #[serde(default = "default_tab_width")]
+    tab_width: usize,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintInput {
+    schema: String,
+    #[serde(default)]
+    no_env_errors: bool,
+}
+
+#[derive(Deserialize)]
+pub struct NativeTypesInput {
+    schema: String,
+}
+
+fn default_tab_width() -> usize {
+    2
+}
+
+pub fn run() {
+    let mut io_handler = IoHandler::new();
+    io_handler.extend_with(RpcImpl.to_delegate());
+
+    let mut runtime = tokio::runtime::Builder::new()
+        .basic_scheduler()
+        .enable_all()
+        .build()
+        .expect("Failed to start the tokio runtime.");
+
+    runtime.block_on(json_rpc_stdio::run(&io_handler)).unwrap();
+}
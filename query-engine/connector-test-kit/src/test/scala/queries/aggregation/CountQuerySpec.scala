@@ -0,0 +1,67 @@
+package queries.aggregation
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class CountQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """model Item {
+      |  id   String @id @default(cuid())
+      |  name String
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  def createItem(name: String) = {
+    server.query(
+      s"""mutation {
+         |  createItem(data: { name: "$name" }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+
+  "Counting with no records in the database" should "return 0" in {
+    val result = server.query("""{ countItem }""", project)
+
+    result should equal("""{"data":{"countItem":0}}""".parseJson)
+  }
+
+  "Counting with query arguments" should "only count the matching records" in {
+    createItem("1")
+    val i2 = createItem("2")
+    createItem("3")
+    createItem("4")
+
+    server.query("""{ countItem }""", project) should equal("""{"data":{"countItem":4}}""".parseJson)
+    server.query("""{ countItem(take: 2) }""", project) should equal("""{"data":{"countItem":2}}""".parseJson)
+    server.query("""{ countItem(skip: 3) }""", project) should equal("""{"data":{"countItem":1}}""".parseJson)
+    server.query("""{ countItem(where: { name: { gt: "2" }}) }""", project) should equal(
+      """{"data":{"countItem":2}}""".parseJson)
+    server.query("""{ countItem(where: { name: { gt: "1" }}, take: 2) }""", project) should equal(
+      """{"data":{"countItem":2}}""".parseJson)
+
+    val result = server.query(
+      s"""{ countItem(cursor: { id: "${i2.pathAsString("data.createItem.id")}" }) }""",
+      project
+    )
+
+    result should equal("""{"data":{"countItem":3}}""".parseJson)
+  }
+
+  "Counting with an estimate on a small table" should "return the exact count" in {
+    createItem("1")
+    createItem("2")
+
+    val result = server.query("""{ countItem(where: { name: { not: "1" }}, estimate: true) }""", project)
+
+    result should equal("""{"data":{"countItem":1}}""".parseJson)
+  }
+}
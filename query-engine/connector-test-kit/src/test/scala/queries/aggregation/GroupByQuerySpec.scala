@@ -0,0 +1,71 @@
+package queries.aggregation
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class GroupByQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """model Item {
+      |  id       String @id @default(cuid())
+      |  category String
+      |  int      Int
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  def createItem(category: String, int: Int) = {
+    server.query(
+      s"""mutation {
+         |  createItem(data: { category: "$category", int: $int }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+
+  "Grouping with no records in the database" should "return no groups" in {
+    val result = server.query("""{ groupByItem(by: [category]) { category count } }""", project)
+
+    result should equal("""{"data":{"groupByItem":[]}}""".parseJson)
+  }
+
+  "Grouping by a field" should "aggregate every group" in {
+    createItem("a", 5)
+    createItem("b", 2)
+    createItem("a", 10)
+
+    val result = server.query(
+      """{ groupByItem(by: [category], where: { int: { gt: 1 }}) { category count sum { int } } }""",
+      project
+    )
+
+    result should equal(
+      """{"data":{"groupByItem":[{"category":"a","count":2,"sum":{"int":15}},{"category":"b","count":1,"sum":{"int":2}}]}}""".parseJson)
+  }
+
+  "Selecting a field that is not grouped by" should "fail" in {
+    createItem("a", 5)
+
+    server.queryThatMustFail(
+      """{ groupByItem(by: [category]) { category int } }""",
+      project,
+      errorCode = 2019,
+      errorContains = "The field `int` is selected, but not grouped by."
+    )
+  }
+
+  "Grouping by no field" should "fail" in {
+    server.queryThatMustFail(
+      """{ groupByItem(by: []) { count } }""",
+      project,
+      errorCode = 2019,
+      errorContains = "At least one field to group by is required."
+    )
+  }
+}
@@ -1,6 +1,7 @@
 package queries.batch
 
 import org.scalatest.{FlatSpec, Matchers}
+import play.api.libs.json.Json
 import util.{ApiSpecBase, ProjectDsl}
 
 class TransactionalBatchSpec extends FlatSpec with Matchers with ApiSpecBase {
@@ -63,6 +64,100 @@ class TransactionalBatchSpec extends FlatSpec with Matchers with ApiSpecBase {
     result.toString() should be("""{"data":{"findManyModelA":[]}}""")
   }
 
+  "A nested batch in a transactional batch" should "run in the outer transaction" in {
+    val request = Json.obj(
+      "batch" -> Seq(
+        server.createSingleQuery("""mutation { createOneModelA(data: { id: 1 }) { id }}"""),
+        Json.obj(
+          "batch" -> Seq(
+            server.createSingleQuery("""mutation { createOneModelA(data: { id: 2 }) { id }}"""),
+            server.createSingleQuery("""mutation { createOneModelA(data: { id: 1 }) { id }}""")
+          ),
+          "transaction" -> false
+        )
+      ),
+      "transaction" -> true
+    )
+
+    server.queryBinaryCLI(request, project, legacy = false)._1.toString should startWith(
+      """{"errors":[{"error":"Error occurred during query execution:\nConnectorError(ConnectorError { user_facing_error: Some(KnownError { message: \"Unique constraint failed"""
+    )
+
+    val result = server.query("""{ findManyModelA { id } }""", project, legacy = false)
+    result.toString() should be("""{"data":{"findManyModelA":[]}}""")
+  }
+
+  "The responses of a nested batch" should "be nested like the queries" in {
+    val request = Json.obj(
+      "batch" -> Seq(
+        server.createSingleQuery("""mutation { createOneModelA(data: { id: 1 }) { id }}"""),
+        Json.obj(
+          "batch" -> Seq(server.createSingleQuery("""mutation { createOneModelA(data: { id: 2 }) { id }}""")),
+          "transaction" -> true
+        )
+      ),
+      "transaction" -> true
+    )
+
+    server.queryBinaryCLI(request, project, legacy = false)._1.toString should be(
+      """[{"data":{"createOneModelA":{"id":1}}},[{"data":{"createOneModelA":{"id":2}}}]]"""
+    )
+  }
+
+  "A transactional batch in a non-transactional batch" should "be rejected" in {
+    val request = Json.obj(
+      "batch" -> Seq(
+        server.createSingleQuery("""mutation { createOneModelA(data: { id: 1 }) { id }}"""),
+        Json.obj(
+          "batch" -> Seq(server.createSingleQuery("""mutation { createOneModelA(data: { id: 2 }) { id }}""")),
+          "transaction" -> true
+        )
+      ),
+      "transaction" -> false
+    )
+
+    server.queryBinaryCLI(request, project, legacy = false)._1.assertFailingResponse(2024, 1, "batch[1]", Array.empty)
+
+    val result = server.query("""{ findManyModelA { id } }""", project, legacy = false)
+    result.toString() should be("""{"data":{"findManyModelA":[]}}""")
+  }
+
+  "A transactional batch with a failing query" should "not run the queries after it" in {
+    val queries = Seq(
+      """mutation { createOneModelA(data: { id: 1 }) { id }}""",
+      """mutation { createOneModelA(data: { id: 1 }) { id }}""",
+      """mutation { createOneModelA(data: { id: 2 }) { id }}""",
+    )
+
+    server.batch(queries, transaction = true, project, legacy = false).toString should startWith(
+      """{"errors":[{"error":"Error occurred during query execution:\nConnectorError(ConnectorError { user_facing_error: Some(KnownError { message: \"Unique constraint failed"""
+    )
+
+    val result = server.query("""{ findManyModelA { id } }""", project, legacy = false)
+    result.toString() should be("""{"data":{"findManyModelA":[]}}""")
+  }
+
+  "A batch without the transaction flag" should "run its queries independently" in {
+    server.query("""mutation { createOneModelA(data: { id: 1 }) { id }}""", project, legacy = false)
+
+    val request = Json.obj(
+      "batch" -> Seq(
+        server.createSingleQuery("""mutation { createOneModelA(data: { id: 1 }) { id }}"""),
+        server.createSingleQuery("""mutation { createOneModelA(data: { id: 2 }) { id }}""")
+      )
+    )
+
+    val response = server.queryBinaryCLI(request, project, legacy = false)._1.toString
+
+    response should startWith(
+      """[{"errors":[{"error":"Error occurred during query execution:\nConnectorError(ConnectorError { user_facing_error: Some(KnownError { message: \"Unique constraint failed"""
+    )
+    response should endWith("""{"data":{"createOneModelA":{"id":2}}}]""")
+
+    val result = server.query("""{ findManyModelA(orderBy: { id: asc }) { id } }""", project, legacy = false)
+    result.toString() should be("""{"data":{"findManyModelA":[{"id":1},{"id":2}]}}""")
+  }
+
   "A single-query batch with a write query" should "be transactional in itself (roll back all changes)" in {
     // Existing ModelA in the DB will prevent the nested ModelA creation in the batch.
     server.query("""
@@ -1,4 +1,4 @@
-use crate::filter::Filter;
+use crate::filter::{Filter, JsonFilterPath, JsonOperation, ScalarCondition};
 use prisma_models::PrismaValue;
 
 /// Comparing methods for scalar fields.
@@ -58,6 +58,14 @@ pub trait ScalarCompare {
     fn greater_than_or_equals<T>(&self, val: T) -> Filter
     where
         T: Into<PrismaValue>;
+
+    fn search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>;
+
+    fn not_search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>;
 }
 
 /// Comparison methods for relational fields.
@@ -79,6 +87,13 @@ pub trait RelationCompare {
         T: Into<Filter>;
 
     fn one_relation_is_null(&self) -> Filter;
+
+    fn related_count(&self, condition: ScalarCondition) -> Filter;
+}
+
+/// Comparison methods for the values at a path of Json fields.
+pub trait JsonCompare {
+    fn json_compare(&self, path: JsonFilterPath, operation: JsonOperation) -> Filter;
 }
 
 /// Comparison methods for scalar list fields.
@@ -87,13 +102,13 @@ pub trait ScalarListCompare {
     where
         T: Into<PrismaValue>;
 
-    fn contains_every_element<T>(&self, filter: Vec<T>) -> Filter
+    fn contains_every_element<T>(&self, values: Vec<T>) -> Filter
     where
-        T: Into<Filter>;
+        T: Into<PrismaValue>;
 
-    fn contains_some_element<T>(&self, filter: Vec<T>) -> Filter
+    fn contains_some_element<T>(&self, values: Vec<T>) -> Filter
     where
-        T: Into<Filter>;
+        T: Into<PrismaValue>;
 
     fn contains_none(&self) -> Filter;
 }
@@ -1,5 +1,6 @@
 use crate::filter::Filter;
 use prisma_models::prelude::DomainError;
+use std::time::Duration;
 use thiserror::Error;
 use user_facing_errors::{query_engine::DatabaseConstraint, KnownError};
 
@@ -28,6 +29,16 @@ impl ConnectorError {
                 KnownError::new(user_facing_errors::query_engine::ColumnDoesNotExist { column: column.clone() })
                     .unwrap(),
             ),
+            ErrorKind::ConcurrencyLimitReached { limit, timeout } => Some(
+                KnownError::new(user_facing_errors::query_engine::ConcurrencyLimitReached {
+                    limit: *limit,
+                    timeout: render_duration(*timeout),
+                })
+                .unwrap(),
+            ),
+            ErrorKind::UnknownTenant { tenant } => Some(
+                KnownError::new(user_facing_errors::query_engine::UnknownTenant { tenant: tenant.clone() }).unwrap(),
+            ),
             _ => None,
         };
 
@@ -123,6 +134,32 @@ pub enum ErrorKind {
 
     #[error("Database error. error code: {}, error message: {}", code, message)]
     RawError { code: String, message: String },
+
+    #[error(
+        "Timed out after {:?} waiting for one of the {} concurrent queries allowed.",
+        timeout,
+        limit
+    )]
+    ConcurrencyLimitReached { limit: usize, timeout: Duration },
+
+    #[error("Transforming the value of the field `{}.{}` failed: {}", model, field, cause)]
+    FieldTransformationFailed {
+        model: String,
+        field: String,
+        cause: Box<dyn std::error::Error + Send + Sync>,
+    },
+
+    #[error("Unknown tenant `{}`.", tenant)]
+    UnknownTenant { tenant: String },
+}
+
+/// Durations are rendered in seconds, or in milliseconds below one second.
+fn render_duration(duration: Duration) -> String {
+    if duration.as_millis() < 1000 {
+        format!("{}ms", duration.as_millis())
+    } else {
+        format!("{}s", duration.as_secs())
+    }
 }
 
 impl From<DomainError> for ConnectorError {
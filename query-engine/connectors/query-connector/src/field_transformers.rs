@@ -0,0 +1,67 @@
+use prisma_models::PrismaValue;
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
+
+pub type FieldTransformationResult = Result<PrismaValue, Box<dyn std::error::Error + Send + Sync>>;
+
+/// Transforms the values of a field on their way to and from the database, e.g. to encrypt them
+/// at rest. Null values are never handed to the transformer, they are stored as they are.
+pub trait FieldTransformer: Debug + Send + Sync {
+    /// Transforms a value written to the database, e.g. encrypts it.
+    fn on_write(&self, value: PrismaValue) -> FieldTransformationResult;
+
+    /// Transforms a value read from the database back, e.g. decrypts it.
+    fn on_read(&self, value: PrismaValue) -> FieldTransformationResult;
+}
+
+/// The transformers configured for the fields of the models, by model and field name.
+///
+/// The database only ever sees the transformed values: filtering, ordering and aggregating on a
+/// transformed field operate on these, not on the values of the queries and results.
+#[derive(Debug, Clone, Default)]
+pub struct FieldTransformers {
+    transformers: HashMap<(String, String), Arc<dyn FieldTransformer>>,
+}
+
+impl FieldTransformers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the transformer of a field, replacing any previous one.
+    pub fn insert(
+        &mut self,
+        model: impl Into<String>,
+        field: impl Into<String>,
+        transformer: Arc<dyn FieldTransformer>,
+    ) {
+        self.transformers.insert((model.into(), field.into()), transformer);
+    }
+
+    pub fn with(
+        mut self,
+        model: impl Into<String>,
+        field: impl Into<String>,
+        transformer: Arc<dyn FieldTransformer>,
+    ) -> Self {
+        self.insert(model, field, transformer);
+        self
+    }
+
+    pub fn get(&self, model: &str, field: &str) -> Option<&Arc<dyn FieldTransformer>> {
+        self.transformers.get(&(model.to_owned(), field.to_owned()))
+    }
+
+    /// Whether any field of the model is transformed.
+    pub fn applies_to_model(&self, model: &str) -> bool {
+        self.transformers.keys().any(|(m, _)| m == model)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.transformers.is_empty()
+    }
+
+    /// Iterates over the transformed fields, as model and field name.
+    pub fn fields(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.transformers.keys().map(|(m, f)| (m.as_str(), f.as_str()))
+    }
+}
@@ -23,6 +23,7 @@ pub enum Filter {
     ScalarList(ScalarListFilter),
     OneRelationIsNull(OneRelationIsNullFilter),
     Relation(RelationFilter),
+    RelationCount(RelationCountFilter),
     NodeSubscription,
     BoolFilter(bool),
     Empty,
@@ -129,6 +130,7 @@ impl Filter {
             Filter::ScalarList(_) => {}
             Filter::OneRelationIsNull(_) => {}
             Filter::Relation(_) => {}
+            Filter::RelationCount(_) => {}
             Filter::NodeSubscription => {}
             Filter::BoolFilter(_) => {}
             Filter::Empty => {}
@@ -160,6 +162,12 @@ impl From<RelationFilter> for Filter {
     }
 }
 
+impl From<RelationCountFilter> for Filter {
+    fn from(sf: RelationCountFilter) -> Self {
+        Filter::RelationCount(sf)
+    }
+}
+
 impl From<bool> for Filter {
     fn from(b: bool) -> Self {
         Filter::BoolFilter(b)
@@ -1,5 +1,5 @@
 use crate::compare::RelationCompare;
-use crate::filter::Filter;
+use crate::filter::{Filter, ScalarCondition};
 use prisma_models::RelationField;
 use std::sync::Arc;
 
@@ -15,6 +15,17 @@ pub struct OneRelationIsNullFilter {
     pub field: Arc<RelationField>,
 }
 
+/// Compares the number of related records of a to-many relation with a value:
+///
+/// ```graphql
+/// findManyUser(where: { posts: { count: { gt: 5 } } })
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RelationCountFilter {
+    pub field: Arc<RelationField>,
+    pub condition: ScalarCondition,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum RelationCondition {
     EveryRelatedRecord,
@@ -87,4 +98,12 @@ impl RelationCompare for Arc<RelationField> {
             field: Arc::clone(self),
         })
     }
+
+    /// The number of related records matches the condition.
+    fn related_count(&self, condition: ScalarCondition) -> Filter {
+        Filter::from(RelationCountFilter {
+            field: Arc::clone(self),
+            condition,
+        })
+    }
 }
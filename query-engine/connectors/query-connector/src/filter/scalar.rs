@@ -1,5 +1,5 @@
 use super::Filter;
-use crate::compare::ScalarCompare;
+use crate::compare::{JsonCompare, ScalarCompare};
 use once_cell::sync::Lazy;
 use prisma_models::{ModelProjection, PrismaListValue, PrismaValue, ScalarFieldRef};
 use std::{collections::BTreeSet, env, sync::Arc};
@@ -126,6 +126,56 @@ pub enum ScalarCondition {
     GreaterThanOrEquals(PrismaValue),
     In(PrismaListValue),
     NotIn(PrismaListValue),
+    Search(PrismaValue),
+    NotSearch(PrismaValue),
+    JsonCompare(JsonCondition),
+}
+
+/// A condition on the value at a path of a Json field.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonCondition {
+    pub path: JsonFilterPath,
+    pub operation: JsonOperation,
+}
+
+/// The path of a value in a Json field, in the syntax of the connector.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonFilterPath {
+    /// Keys and array indexes, e.g. `["a", "0"]`.
+    Array(Vec<String>),
+    /// A JSON path string, e.g. `$.a[0]`.
+    String(String),
+}
+
+impl JsonFilterPath {
+    /// If `true`, the path addresses the whole value of the field.
+    pub fn is_root(&self) -> bool {
+        match self {
+            JsonFilterPath::Array(keys) => keys.is_empty(),
+            JsonFilterPath::String(path) => path == "$",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonOperation {
+    Equals(PrismaValue),
+    NotEquals(PrismaValue),
+    StringContains(PrismaValue),
+    StringStartsWith(PrismaValue),
+    StringEndsWith(PrismaValue),
+    ArrayContains(PrismaValue),
+}
+
+impl JsonCompare for ScalarFieldRef {
+    /// The value at the given path of the field satisfies the operation.
+    fn json_compare(&self, path: JsonFilterPath, operation: JsonOperation) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::JsonCompare(JsonCondition { path, operation }),
+            mode: QueryMode::Default,
+        })
+    }
 }
 
 impl ScalarCompare for ScalarFieldRef {
@@ -296,6 +346,30 @@ impl ScalarCompare for ScalarFieldRef {
             mode: QueryMode::Default,
         })
     }
+
+    /// Field matches the given full-text search query.
+    fn search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::Search(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field does not match the given full-text search query.
+    fn not_search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::NotSearch(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
 }
 
 impl ScalarCompare for ModelProjection {
@@ -466,4 +540,28 @@ impl ScalarCompare for ModelProjection {
             mode: QueryMode::Default,
         })
     }
+
+    /// Field matches the given full-text search query.
+    fn search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::Search(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field does not match the given full-text search query.
+    fn not_search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::NotSearch(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
 }
@@ -9,10 +9,17 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
         model: &ModelRef,
         filter: &Filter,
         selected_fields: &ModelProjection,
+        relation_counts: &[RelationFieldRef],
     ) -> crate::Result<Option<SingleRecord>> {
         match self {
-            Self::Connection(c) => c.get_single_record(model, filter, selected_fields).await,
-            Self::Transaction(tx) => tx.get_single_record(model, filter, selected_fields).await,
+            Self::Connection(c) => {
+                c.get_single_record(model, filter, selected_fields, relation_counts)
+                    .await
+            }
+            Self::Transaction(tx) => {
+                tx.get_single_record(model, filter, selected_fields, relation_counts)
+                    .await
+            }
         }
     }
 
@@ -21,10 +28,36 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
         model: &ModelRef,
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
+        relation_counts: &[RelationFieldRef],
     ) -> crate::Result<ManyRecords> {
         match self {
-            Self::Connection(c) => c.get_many_records(model, query_arguments, selected_fields).await,
-            Self::Transaction(tx) => tx.get_many_records(model, query_arguments, selected_fields).await,
+            Self::Connection(c) => {
+                c.get_many_records(model, query_arguments, selected_fields, relation_counts)
+                    .await
+            }
+            Self::Transaction(tx) => {
+                tx.get_many_records(model, query_arguments, selected_fields, relation_counts)
+                    .await
+            }
+        }
+    }
+
+    async fn get_many_records_per_partition(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        partition_by: &ModelProjection,
+        selected_fields: &ModelProjection,
+    ) -> crate::Result<Option<ManyRecords>> {
+        match self {
+            Self::Connection(c) => {
+                c.get_many_records_per_partition(model, query_arguments, partition_by, selected_fields)
+                    .await
+            }
+            Self::Transaction(tx) => {
+                tx.get_many_records_per_partition(model, query_arguments, partition_by, selected_fields)
+                    .await
+            }
         }
     }
 
@@ -50,6 +83,31 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
             Self::Transaction(tx) => tx.aggregate_records(model, aggregators, query_arguments).await,
         }
     }
+
+    async fn group_by_records(
+        &self,
+        model: &ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+    ) -> crate::Result<Vec<Vec<AggregationResult>>> {
+        match self {
+            Self::Connection(c) => c.group_by_records(model, group_by, aggregators, query_arguments).await,
+            Self::Transaction(tx) => tx.group_by_records(model, group_by, aggregators, query_arguments).await,
+        }
+    }
+
+    async fn count_records(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        estimate: bool,
+    ) -> crate::Result<usize> {
+        match self {
+            Self::Connection(c) => c.count_records(model, query_arguments, estimate).await,
+            Self::Transaction(tx) => tx.count_records(model, query_arguments, estimate).await,
+        }
+    }
 }
 
 #[async_trait]
@@ -73,13 +131,29 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
-    async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> crate::Result<usize> {
+    async fn delete_records(
+        &self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+    ) -> crate::Result<Vec<RecordProjection>> {
         match self {
             Self::Connection(c) => c.delete_records(model, record_filter).await,
             Self::Transaction(tx) => tx.delete_records(model, record_filter).await,
         }
     }
 
+    async fn upsert_records(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        conflict_target: &[ScalarFieldRef],
+    ) -> crate::Result<usize> {
+        match self {
+            Self::Connection(c) => c.upsert_records(model, args, conflict_target).await,
+            Self::Transaction(tx) => tx.upsert_records(model, args, conflict_target).await,
+        }
+    }
+
     async fn connect(
         &self,
         field: &RelationFieldRef,
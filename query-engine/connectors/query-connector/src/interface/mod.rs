@@ -1,7 +1,10 @@
 mod dispatch;
 pub use dispatch::*;
 
-use crate::{Filter, QueryArguments, WriteArgs};
+use crate::{
+    error::{ConnectorError, ErrorKind},
+    Filter, PoolStats, QueryArguments, QueryTags, WriteArgs,
+};
 use async_trait::async_trait;
 use dml::FieldArity;
 use prisma_models::*;
@@ -12,19 +15,60 @@ pub trait Connector {
     /// Returns a connection to a data source.
     async fn get_connection(&self) -> crate::Result<Box<dyn Connection>>;
 
+    /// Returns a connection for queries that only read, to a read replica of the data source if
+    /// the connector has some. Replicas may lag behind the primary.
+    async fn get_read_connection(&self) -> crate::Result<Box<dyn Connection>> {
+        self.get_connection().await
+    }
+
+    /// Returns a connection to the schema or database of `tenant`, for connectors serving one per tenant.
+    /// Tenants outside of the allow-list of the connector are rejected.
+    async fn get_tenant_connection(&self, tenant: &str) -> crate::Result<Box<dyn Connection>> {
+        Err(ConnectorError::from_kind(ErrorKind::UnknownTenant {
+            tenant: tenant.to_owned(),
+        }))
+    }
+
     /// Returns name of the connector.
     fn name(&self) -> String;
+
+    /// The state of the connection pool, for connectors with one.
+    async fn pool_stats(&self) -> Option<PoolStats> {
+        None
+    }
 }
 
 #[async_trait]
 pub trait Connection: ReadOperations + WriteOperations + Send + Sync {
     async fn start_transaction<'a>(&'a self) -> crate::Result<Box<dyn Transaction + 'a>>;
+
+    /// Sets the tags to attach to all queries run on this connection and the transactions started from it.
+    /// Connectors without a way to attach tags to their queries ignore them.
+    fn set_query_tags(&mut self, _tags: QueryTags) {}
 }
 
 #[async_trait]
 pub trait Transaction: ReadOperations + WriteOperations + Send + Sync {
     async fn commit(&self) -> crate::Result<()>;
     async fn rollback(&self) -> crate::Result<()>;
+
+    /// Sets the tags to attach to the queries run in this transaction from now on, e.g. for each operation of an
+    /// interactive transaction. Connectors without a way to attach tags to their queries ignore them.
+    fn set_query_tags(&self, _tags: QueryTags) {}
+
+    /// Returns true if the underlying data source is able to partially roll back
+    /// a transaction to a previously created savepoint.
+    fn supports_savepoints(&self) -> bool;
+
+    /// Creates a savepoint with the given name inside of the transaction.
+    async fn create_savepoint(&self, name: &str) -> crate::Result<()>;
+
+    /// Rolls back all changes made after the savepoint with the given name was created.
+    /// The transaction itself stays usable.
+    async fn rollback_to_savepoint(&self, name: &str) -> crate::Result<()>;
+
+    /// Releases the savepoint with the given name, keeping all changes made after it was created.
+    async fn release_savepoint(&self, name: &str) -> crate::Result<()>;
 }
 
 pub enum ConnectionLike<'conn, 'tx>
@@ -128,6 +172,15 @@ impl Aggregator {
     }
 }
 
+/// The prefix of the names of the fields holding relation counts, e.g. `_count.posts`.
+pub const RELATION_COUNT_PREFIX: &str = "_count.";
+
+/// The name of the field holding the number of records related through the given to-many relation field.
+/// Relation counts are returned after the selected fields of the records, in the order they were requested.
+pub fn relation_count_field_name(field: &RelationFieldRef) -> String {
+    format!("{}{}", RELATION_COUNT_PREFIX, field.name)
+}
+
 /// Result of an aggregation operation on a model or field.
 /// It is expected that the type of a `PrismaValue` matches the `TypeIdentifier`
 /// of the accompanying `ScalarFieldRef` for `Sum`, `Min` and `Max`.
@@ -139,6 +192,9 @@ pub enum AggregationResult {
     Sum(ScalarFieldRef, PrismaValue),
     Min(ScalarFieldRef, PrismaValue),
     Max(ScalarFieldRef, PrismaValue),
+
+    /// The value of a field the records are grouped by, for grouped aggregations.
+    Field(ScalarFieldRef, PrismaValue),
 }
 
 #[async_trait]
@@ -149,11 +205,14 @@ pub trait ReadOperations {
     /// - The `Filter` defines what item we want back and is guaranteed to be
     ///   defined to filter at most one item by the core.
     /// - The `SelectedFields` defines the values to be returned.
+    /// - The `relation_counts` are to-many relations of the model whose related records are counted
+    ///   instead of read, see `relation_count_field_name`.
     async fn get_single_record(
         &self,
         model: &ModelRef,
         filter: &Filter,
         selected_fields: &ModelProjection,
+        relation_counts: &[RelationFieldRef],
     ) -> crate::Result<Option<SingleRecord>>;
 
     /// Gets multiple records from the database.
@@ -162,13 +221,30 @@ pub trait ReadOperations {
     /// - The `QueryArguments` defines various constraints (see docs for detailed explanation).
     /// - The `SelectedFields` defines the fields (e.g. columns or document fields)
     ///   to be returned as a projection of fields of the model it queries.
+    /// - The `relation_counts` are to-many relations of the model whose related records are counted
+    ///   instead of read, see `relation_count_field_name`.
     async fn get_many_records(
         &self,
         model: &ModelRef,
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
+        relation_counts: &[RelationFieldRef],
     ) -> crate::Result<ManyRecords>;
 
+    /// Gets multiple records from the database, paginating every partition of the records on its own:
+    /// `skip` and `take` of the `QueryArguments` apply to every group of records sharing the same values for
+    /// `partition_by`, e.g. to the related records of every parent record. The fields of `partition_by` must be
+    /// part of the `selected_fields`. Cursors, distinct and negative `take`s are not supported.
+    ///
+    /// Returns `None` if the data source can't paginate partitions, the caller has to paginate in memory then.
+    async fn get_many_records_per_partition(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        partition_by: &ModelProjection,
+        selected_fields: &ModelProjection,
+    ) -> crate::Result<Option<ManyRecords>>;
+
     /// Retrieves pairs of IDs that belong together from a intermediate join
     /// table.
     ///
@@ -192,6 +268,27 @@ pub trait ReadOperations {
         aggregators: Vec<Aggregator>,
         query_arguments: QueryArguments,
     ) -> crate::Result<Vec<AggregationResult>>;
+
+    /// Groups the records of a model matching the query arguments by the values of the `group_by`
+    /// fields, and aggregates every group with the given aggregators. Each returned row starts with
+    /// the `AggregationResult::Field` values of the group, in the order of `group_by`.
+    async fn group_by_records(
+        &self,
+        model: &ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+    ) -> crate::Result<Vec<Vec<AggregationResult>>>;
+
+    /// Counts the records of a model matching the query arguments.
+    /// With `estimate`, the connector may return an approximate count when counting exactly would
+    /// be expensive, e.g. from planner statistics. Connectors without estimates count exactly.
+    async fn count_records(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        estimate: bool,
+    ) -> crate::Result<usize>;
 }
 
 #[async_trait]
@@ -209,7 +306,24 @@ pub trait WriteOperations {
     ) -> crate::Result<Vec<RecordProjection>>;
 
     /// Delete records in the `Model` with the given `Filter`.
-    async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> crate::Result<usize>;
+    ///
+    /// Returns the identifiers of the deleted records.
+    async fn delete_records(
+        &self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+    ) -> crate::Result<Vec<RecordProjection>>;
+
+    /// Insert the records into the database, updating the existing records that have the same
+    /// values for the `conflict_target` fields instead.
+    ///
+    /// Returns the number of records written.
+    async fn upsert_records(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        conflict_target: &[ScalarFieldRef],
+    ) -> crate::Result<usize>;
 
     // We plan to remove the methods below in the future. We want emulate them with the ones above. Those should suffice.
 
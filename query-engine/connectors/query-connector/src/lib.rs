@@ -4,14 +4,20 @@ pub mod error;
 pub mod filter;
 
 mod compare;
+mod field_transformers;
 mod interface;
+mod pool;
 mod query_arguments;
+mod query_tags;
 mod write_args;
 
 pub use compare::*;
+pub use field_transformers::*;
 pub use filter::*;
 pub use interface::*;
+pub use pool::*;
 pub use query_arguments::*;
+pub use query_tags::*;
 pub use write_args::*;
 
 pub type Result<T> = std::result::Result<T, error::ConnectorError>;
@@ -0,0 +1,87 @@
+use serde::Serialize;
+use std::time::Duration;
+
+/// The configuration of the connection pool of a connector. Unset values keep the defaults of the
+/// connector.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PoolConfig {
+    /// The maximum number of open connections.
+    pub connection_limit: Option<usize>,
+    /// How long opening a connection to the database may take.
+    pub connect_timeout: Option<Duration>,
+    /// How long a query waits for a connection of the pool when they are all in use.
+    pub pool_timeout: Option<Duration>,
+    /// How long a connection stays open while idle.
+    pub max_idle_lifetime: Option<Duration>,
+    /// How long a connection stays open at most, idle or not.
+    pub max_lifetime: Option<Duration>,
+}
+
+impl PoolConfig {
+    /// This configuration, with the values set in `overrides` replacing its own.
+    pub fn overridden_by(self, overrides: PoolConfig) -> PoolConfig {
+        PoolConfig {
+            connection_limit: overrides.connection_limit.or(self.connection_limit),
+            connect_timeout: overrides.connect_timeout.or(self.connect_timeout),
+            pool_timeout: overrides.pool_timeout.or(self.pool_timeout),
+            max_idle_lifetime: overrides.max_idle_lifetime.or(self.max_idle_lifetime),
+            max_lifetime: overrides.max_lifetime.or(self.max_lifetime),
+        }
+    }
+}
+
+/// The state of the connection pool of a connector, and the configuration it runs with.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolStats {
+    /// The maximum number of open connections, as configured or defaulted by the connector.
+    pub connection_limit: u32,
+    pub connect_timeout_seconds: Option<u64>,
+    pub pool_timeout_seconds: Option<u64>,
+    pub max_idle_lifetime_seconds: Option<u64>,
+    pub max_lifetime_seconds: Option<u64>,
+}
+
+impl PoolStats {
+    pub fn new(connection_limit: u32, config: &PoolConfig) -> Self {
+        let seconds = |duration: Option<Duration>| duration.map(|duration| duration.as_secs());
+
+        Self {
+            connection_limit,
+            connect_timeout_seconds: seconds(config.connect_timeout),
+            pool_timeout_seconds: seconds(config.pool_timeout),
+            max_idle_lifetime_seconds: seconds(config.max_idle_lifetime),
+            max_lifetime_seconds: seconds(config.max_lifetime),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_replace_only_the_values_they_set() {
+        let from_url = PoolConfig {
+            connection_limit: Some(5),
+            pool_timeout: Some(Duration::from_secs(10)),
+            ..Default::default()
+        };
+
+        let overrides = PoolConfig {
+            connection_limit: Some(2),
+            max_lifetime: Some(Duration::from_secs(600)),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            from_url.overridden_by(overrides),
+            PoolConfig {
+                connection_limit: Some(2),
+                pool_timeout: Some(Duration::from_secs(10)),
+                max_lifetime: Some(Duration::from_secs(600)),
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -61,33 +61,83 @@ impl QueryArguments {
         self.cursor.is_some() && !self.is_stable_ordering()
     }
 
-    /// A null cursor is a cursor that is used in conjunction with a nullable order by (i.e. a field is optional).
+    /// A null cursor is a cursor that is used in conjunction with a nullable order by (i.e. a field is optional, or
+    /// an optional relation leads to it).
     pub fn contains_null_cursor(&self) -> bool {
-        self.cursor.is_some() && self.order_by.iter().any(|o| !o.field.is_required)
+        self.cursor.is_some() && self.order_by.iter().any(|o| o.is_nullable())
     }
 
     /// Checks if the orderBy provided is guaranteeing a stable ordering of records for the model. Assumes that `model`
     /// is the same as the model used
-    /// `true` if at least one unique field is present, or contains a combination of fields that is marked as unique.
-    /// `false` otherwise.
+    /// `true` if at least one unique field is present, or contains a combination of fields that is marked as unique,
+    /// or all the fields of the primary identifier.
+    /// `false` otherwise. Orderings by related records never make the ordering stable.
     pub fn is_stable_ordering(&self) -> bool {
-        let order_fields: Vec<_> = self.order_by.iter().map(|o| &o.field).collect();
+        let order_fields: Vec<_> = self
+            .order_by
+            .iter()
+            .filter(|o| o.is_on_model())
+            .map(|o| &o.field)
+            .collect();
 
         !self.order_by.is_empty()
-            && (self.order_by.iter().any(|o| o.field.unique())
+            && (self.order_by.iter().any(|o| o.is_on_model() && o.field.unique())
                 || self
                     .model
                     .unique_indexes()
                     .into_iter()
-                    .any(|index| index.fields().into_iter().all(|f| order_fields.contains(&&f))))
+                    .any(|index| index.fields().into_iter().all(|f| order_fields.contains(&&f)))
+                || self
+                    .model
+                    .primary_identifier()
+                    .scalar_fields()
+                    .all(|f| order_fields.contains(&&f)))
+    }
+
+    /// Makes the ordering stable by ordering by the fields of the primary identifier last, ascending, so that
+    /// records with the same values in all the ordered fields are never skipped or repeated between pages.
+    pub fn stabilize_ordering(&mut self) {
+        if self.is_stable_ordering() {
+            return;
+        }
+
+        let tie_breakers: Vec<OrderBy> = self
+            .model
+            .primary_identifier()
+            .scalar_fields()
+            .filter(|field| !self.order_by.iter().any(|o| o.is_on_model() && &o.field == field))
+            .map(Into::into)
+            .collect();
+
+        self.order_by.extend(tie_breakers);
+    }
+
+    /// Checks if `distinct` can be processed by the data source instead of the core: keeping the first record of every
+    /// distinct group must be the same as keeping the record with the lowest (or highest) primary identifier.
+    /// `true` if the model has a single field primary identifier, no cursor is given and the records are either not
+    /// ordered or ordered by the primary identifier only.
+    /// `false` otherwise, or if no `distinct` is given.
+    pub fn can_distinct_in_datasource(&self) -> bool {
+        let id_fields: Vec<_> = self.model.primary_identifier().scalar_fields().collect();
+        let ordered_by_id = match self.order_by.as_slice() {
+            [] => true,
+            [order_by] => id_fields.len() == 1 && order_by.is_on_model() && order_by.field.name == id_fields[0].name,
+            _ => false,
+        };
+
+        self.distinct.is_some() && self.cursor.is_none() && id_fields.len() == 1 && ordered_by_id
     }
 
     pub fn take_abs(&self) -> Option<i64> {
         self.take.clone().map(|t| if t < 0 { t * -1 } else { t })
     }
 
+    /// Batched queries are ordered in memory, which is only possible with orderings by fields of the model itself.
     pub fn can_batch(&self) -> bool {
-        self.filter.as_ref().map(|filter| filter.can_batch()).unwrap_or(false) && self.cursor.is_none()
+        self.filter.as_ref().map(|filter| filter.can_batch()).unwrap_or(false)
+            && self.cursor.is_none()
+            && self.distinct.is_none()
+            && self.order_by.iter().all(|o| o.is_on_model())
     }
 
     pub fn batched(self) -> Vec<Self> {
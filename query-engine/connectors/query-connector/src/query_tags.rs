@@ -0,0 +1,40 @@
+use std::collections::BTreeMap;
+
+/// Key-value pairs describing where the queries of an operation come from, such as the application
+/// name, the trace id or the name of the operation. Connectors attach them to the queries they run,
+/// so the load on the database can be attributed to specific operations.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct QueryTags {
+    tags: BTreeMap<String, String>,
+}
+
+impl QueryTags {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the tag, replacing any previous value for the key.
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.tags.insert(key.into(), value.into());
+    }
+
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.insert(key, value);
+        self
+    }
+
+    /// Adds all tags of `other`, replacing the values of keys present in both.
+    pub fn merge(mut self, other: QueryTags) -> Self {
+        self.tags.extend(other.tags);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    /// Iterates over the tags, ordered by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.tags.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
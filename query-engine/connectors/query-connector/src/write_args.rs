@@ -1,6 +1,5 @@
 use crate::error::{ConnectorError, ErrorKind};
-use chrono::Utc;
-use prisma_models::{ModelProjection, ModelRef, PrismaValue, RecordProjection, ScalarFieldRef};
+use prisma_models::{dml, ModelProjection, ModelRef, PrismaValue, RecordProjection, ScalarFieldRef};
 use std::{
     borrow::Borrow,
     collections::{hash_map::Keys, HashMap},
@@ -61,6 +60,9 @@ pub enum WriteExpression {
 
     /// Divide field by value.
     Divide(PrismaValue),
+
+    /// Append the values of the list to the scalar list field.
+    Push(PrismaValue),
 }
 
 impl From<PrismaValue> for WriteExpression {
@@ -151,7 +153,7 @@ impl WriteArgs {
     }
 
     pub fn add_datetimes(&mut self, model: ModelRef) {
-        let now = PrismaValue::DateTime(Utc::now());
+        let now = PrismaValue::DateTime(dml::now());
         let created_at_field = model.fields().created_at();
         let updated_at_field = model.fields().updated_at();
 
@@ -172,7 +174,7 @@ impl WriteArgs {
         if !self.args.is_empty() {
             if let Some(field) = model.fields().updated_at() {
                 if let None = self.args.get(field.db_name()) {
-                    self.args.insert(field.into(), PrismaValue::DateTime(Utc::now()).into());
+                    self.args.insert(field.into(), PrismaValue::DateTime(dml::now()).into());
                 }
             }
         }
@@ -0,0 +1,183 @@
+use crate::SqlError;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::Semaphore;
+
+const LIMIT_PARAM: &str = "query_concurrency_limit";
+const TIMEOUT_PARAM: &str = "query_concurrency_timeout";
+
+/// How long a request waits for a permit when `query_concurrency_timeout` is not set.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Caps the number of requests using the database at the same time, independently of the size of
+/// the connection pool. This protects databases allowing very few connections, e.g. serverless
+/// ones shared between many engine instances.
+///
+/// Configured with connection string parameters:
+///
+/// - `query_concurrency_limit`: the maximum number of concurrent requests. No limit if not set.
+/// - `query_concurrency_timeout`: how long, in seconds, a request waits in the queue before
+///   failing with a `ConcurrencyLimitReached` error. Defaults to 10 seconds.
+///
+/// A permit is held for as long as a connection is used, so all the queries of a request,
+/// transactions included, run under the same permit.
+#[derive(Debug, Clone)]
+pub struct ConcurrencyLimiter {
+    semaphore: Arc<Semaphore>,
+    limit: usize,
+    timeout: Duration,
+}
+
+impl ConcurrencyLimiter {
+    pub fn new(limit: usize, timeout: Duration) -> Self {
+        Self {
+            semaphore: Arc::new(Semaphore::new(limit)),
+            limit,
+            timeout,
+        }
+    }
+
+    /// Reads the limiter configuration from the connection string parameters. Returns `None` if
+    /// no limit is set.
+    pub fn from_url(url: &str) -> anyhow::Result<Option<Self>> {
+        let mut limit = None;
+        let mut timeout = DEFAULT_TIMEOUT;
+
+        for (key, value) in url_params(url) {
+            match key {
+                LIMIT_PARAM => {
+                    let parsed: usize = value
+                        .parse()
+                        .map_err(|_| anyhow::anyhow!("Invalid value for `{}`: `{}`.", LIMIT_PARAM, value))?;
+
+                    if parsed == 0 {
+                        anyhow::bail!("`{}` must be at least 1.", LIMIT_PARAM);
+                    }
+
+                    limit = Some(parsed);
+                }
+                TIMEOUT_PARAM => {
+                    let seconds: u64 = value
+                        .parse()
+                        .map_err(|_| anyhow::anyhow!("Invalid value for `{}`: `{}`.", TIMEOUT_PARAM, value))?;
+
+                    timeout = Duration::from_secs(seconds);
+                }
+                _ => (),
+            }
+        }
+
+        Ok(limit.map(|limit| Self::new(limit, timeout)))
+    }
+
+    /// Waits for a free slot, for at most the configured timeout.
+    pub async fn acquire(&self) -> crate::Result<ConcurrencyPermit> {
+        match tokio::time::timeout(self.timeout, self.semaphore.acquire()).await {
+            Ok(permit) => {
+                // The permit borrows the semaphore, but connections must be `'static`. The slot is
+                // given back by `ConcurrencyPermit` instead.
+                permit.forget();
+
+                Ok(ConcurrencyPermit {
+                    semaphore: self.semaphore.clone(),
+                })
+            }
+            Err(_) => Err(SqlError::ConcurrencyLimitReached {
+                limit: self.limit,
+                timeout: self.timeout,
+            }),
+        }
+    }
+}
+
+/// A slot of a `ConcurrencyLimiter`, given back when dropped.
+#[derive(Debug)]
+pub struct ConcurrencyPermit {
+    semaphore: Arc<Semaphore>,
+}
+
+impl Drop for ConcurrencyPermit {
+    fn drop(&mut self) {
+        self.semaphore.add_permits(1);
+    }
+}
+
+/// The `key=value` parameters of a connection string. SQL Server connection strings separate them
+/// with semicolons, the others are URLs with a query string.
+pub(crate) fn url_params(url: &str) -> impl Iterator<Item = (&str, &str)> {
+    let (params, separator) = if url.starts_with("sqlserver:") {
+        (url.splitn(2, ';').nth(1), ';')
+    } else {
+        (url.splitn(2, '?').nth(1), '&')
+    };
+
+    params.unwrap_or("").split(separator).filter_map(|pair| {
+        let mut parts = pair.splitn(2, '=');
+        let key = parts.next()?.trim();
+        let value = parts.next()?.trim();
+
+        Some((key, value))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_limiter_is_configured_from_the_query_string() {
+        let limiter = ConcurrencyLimiter::from_url(
+            "postgresql://localhost:5432/db?schema=s&query_concurrency_limit=3&query_concurrency_timeout=2",
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(limiter.limit, 3);
+        assert_eq!(limiter.timeout, Duration::from_secs(2));
+        assert_eq!(limiter.semaphore.available_permits(), 3);
+    }
+
+    #[test]
+    fn the_limiter_is_configured_from_sql_server_connection_strings() {
+        let limiter =
+            ConcurrencyLimiter::from_url("sqlserver://localhost:1433;database=master;query_concurrency_limit=5")
+                .unwrap()
+                .unwrap();
+
+        assert_eq!(limiter.limit, 5);
+        assert_eq!(limiter.timeout, DEFAULT_TIMEOUT);
+    }
+
+    #[test]
+    fn there_is_no_limiter_without_a_limit() {
+        assert!(
+            ConcurrencyLimiter::from_url("mysql://localhost:3306/db?query_concurrency_timeout=2")
+                .unwrap()
+                .is_none()
+        );
+        assert!(ConcurrencyLimiter::from_url("file:dev.db").unwrap().is_none());
+    }
+
+    #[test]
+    fn invalid_limits_are_rejected() {
+        assert!(ConcurrencyLimiter::from_url("mysql://localhost/db?query_concurrency_limit=0").is_err());
+        assert!(ConcurrencyLimiter::from_url("mysql://localhost/db?query_concurrency_limit=many").is_err());
+        assert!(ConcurrencyLimiter::from_url(
+            "mysql://localhost/db?query_concurrency_limit=1&query_concurrency_timeout=-1"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn dropping_a_permit_frees_its_slot() {
+        let limiter = ConcurrencyLimiter::new(1, DEFAULT_TIMEOUT);
+        let permit = ConcurrencyPermit {
+            semaphore: limiter.semaphore.clone(),
+        };
+
+        limiter.semaphore.try_acquire().unwrap().forget();
+        assert_eq!(limiter.semaphore.available_permits(), 0);
+
+        drop(permit);
+        assert_eq!(limiter.semaphore.available_permits(), 1);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::query_arguments_ext::QueryArgumentsExt;
+use crate::{ordering::order_expression, query_arguments_ext::QueryArgumentsExt};
 use connector_interface::QueryArguments;
 use prisma_models::*;
 use quaint::ast::*;
@@ -43,7 +43,13 @@ static ORDER_TABLE_ALIAS: &'static str = "order_cmp";
 ///   `TestModel`.`fieldD` DESC;
 /// ```
 ///
-/// The above assumes that all field are non-nullable. If a field is nullable, #2 conditions slighty change:
+/// The above assumes that all field are non-nullable.
+///
+/// With a stable ordering and a `skip`, the cursor row is the only one that is equal in all the fields of the first block.
+/// The first skipped row is then the cursor row itself, and the `<=` becomes `<`, so that it is left out without an
+/// `OFFSET` (keyset pagination).
+///
+/// If a field is nullable, #2 conditions slighty change:
 /// ```sql
 ///   -- ... The first (4 - condition) block:
 ///   (
@@ -62,6 +68,9 @@ static ORDER_TABLE_ALIAS: &'static str = "order_cmp";
 ///   )
 ///   -- ...
 /// ```
+///
+/// Orderings by related records compare the subquery reading the related value (see `ordering::order_expression`)
+/// with the same subquery selected in #1 as `order_cmp_<index of the ordering>`.
 pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Table<'static>>, ConditionTree<'static>) {
     match query_arguments.cursor {
         None => (None, ConditionTree::NoCondition),
@@ -83,27 +92,38 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
             // Subquery to find the value of the order field(s) that we need for comparison. Builds part #1 of the query example in the docs.
             let order_subquery = order_definitions
                 .iter()
-                .fold(Select::from_table(model.as_table()), |select, (field, _)| {
-                    select.column(field.as_column())
+                .enumerate()
+                .fold(Select::from_table(model.as_table()), |select, (index, order_by)| {
+                    if order_by.is_on_model() {
+                        select.column(order_by.field.as_column())
+                    } else {
+                        select.value(order_expression(order_by).alias(cmp_column_name(order_by, index)))
+                    }
                 })
                 .so_that(cursor_condition);
 
             let subquery_table = Table::from(order_subquery).alias(ORDER_TABLE_ALIAS);
             let len = order_definitions.len();
             let reverse = query_arguments.needs_reversed_order();
+            let include_cursor_row = !query_arguments.skips_cursor_row();
 
             // Builds part #2 of the example query.
             // If we only have one ordering, we only want a single, slightly different, condition of (orderField [<= / >=] cmp_field).
             let condition_tree = if len == 1 {
-                let (field, order) = order_definitions.pop().unwrap();
-                ConditionTree::Single(Box::new(map_orderby_condition(&field, &order, reverse, true)))
+                let order_by = order_definitions.pop().unwrap();
+                ConditionTree::Single(Box::new(map_orderby_condition(
+                    &order_by,
+                    0,
+                    reverse,
+                    include_cursor_row,
+                )))
             } else {
                 let or_conditions = (0..len).fold(Vec::with_capacity(len), |mut conditions_acc, n| {
                     let (head, tail) = order_definitions.split_at(len - n - 1);
                     let mut and_conditions = Vec::with_capacity(head.len() + 1);
 
-                    for (field, _) in head {
-                        and_conditions.push(map_equality_condition(field));
+                    for (index, order_by) in head.iter().enumerate() {
+                        and_conditions.push(map_equality_condition(order_by, index));
                     }
 
                     if head.len() == len - 1 {
@@ -131,12 +151,12 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
                         //
                         // Said differently, we handle all the cases in which the prefixes are equal to len - 1 to account for possible identical comparators,
                         // but everything else must come strictly "after" the cursor.
-                        let (field, order) = tail.first().unwrap();
+                        let order_by = tail.first().unwrap();
 
-                        and_conditions.push(map_orderby_condition(field, order, reverse, true));
+                        and_conditions.push(map_orderby_condition(order_by, head.len(), reverse, include_cursor_row));
                     } else {
-                        let (field, order) = tail.first().unwrap();
-                        and_conditions.push(map_orderby_condition(field, order, reverse, false));
+                        let order_by = tail.first().unwrap();
+                        and_conditions.push(map_orderby_condition(order_by, head.len(), reverse, false));
                     }
 
                     conditions_acc.push(ConditionTree::And(and_conditions));
@@ -153,16 +173,11 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
 
 // A negative `take` value signifies that values should be taken before the cursor,
 // requiring the correct comarison operator to be used to fit the reversed order.
-fn map_orderby_condition(
-    field: &ScalarFieldRef,
-    order: &SortOrder,
-    reverse: bool,
-    include_eq: bool,
-) -> Expression<'static> {
-    let order_column = field.as_column();
-    let cmp_column = Column::from((ORDER_TABLE_ALIAS, field.db_name().to_owned()));
-
-    let order_expr: Expression<'static> = match order {
+fn map_orderby_condition(order_by: &OrderBy, index: usize, reverse: bool, include_eq: bool) -> Expression<'static> {
+    let order_column = order_expression(order_by);
+    let cmp_column = Column::from((ORDER_TABLE_ALIAS, cmp_column_name(order_by, index)));
+
+    let order_expr: Expression<'static> = match order_by.sort_order {
         // If it's ASC but we want to take from the back, the ORDER BY will be DESC, meaning that comparisons done need to be lt(e).
         SortOrder::Ascending if reverse => {
             if include_eq {
@@ -201,23 +216,23 @@ fn map_orderby_condition(
 
     // If we have null values in the ordering or comparison row, those are automatically included because we can't make a
     // statement over their order relative to the cursor.
-    if !field.is_required {
+    if order_by.is_nullable() {
         order_expr
-            .or(field.as_column().is_null())
-            .or(Column::from((ORDER_TABLE_ALIAS, field.db_name().to_owned())).is_null())
+            .or(order_expression(order_by).is_null())
+            .or(Column::from((ORDER_TABLE_ALIAS, cmp_column_name(order_by, index))).is_null())
             .into()
     } else {
         order_expr
     }
 }
 
-fn map_equality_condition(field: &ScalarFieldRef) -> Expression<'static> {
-    let order_column = field.as_column();
-    let cmp_column = Column::from((ORDER_TABLE_ALIAS, field.db_name().to_owned()));
+fn map_equality_condition(order_by: &OrderBy, index: usize) -> Expression<'static> {
+    let order_column = order_expression(order_by);
+    let cmp_column = Column::from((ORDER_TABLE_ALIAS, cmp_column_name(order_by, index)));
 
     // If we have null values in the ordering or comparison row, those are automatically included because we can't make a
     // statement over their order relative to the cursor.
-    if !field.is_required {
+    if order_by.is_nullable() {
         order_column
             .clone()
             .equals(cmp_column.clone())
@@ -229,20 +244,20 @@ fn map_equality_condition(field: &ScalarFieldRef) -> Expression<'static> {
     }
 }
 
-fn order_definitions(query_arguments: &QueryArguments, model: &ModelRef) -> Vec<(ScalarFieldRef, SortOrder)> {
-    let defined_ordering: Vec<_> = query_arguments
-        .order_by
-        .iter()
-        .map(|o| (o.field.clone(), o.sort_order))
-        .collect();
-
-    if defined_ordering.is_empty() {
-        model
-            .primary_identifier()
-            .scalar_fields()
-            .map(|f| (f, SortOrder::Ascending))
-            .collect()
+/// The name of the value of the ordering in the `order_cmp` subquery: the column of fields of the model itself, an alias
+/// for the value of related records.
+fn cmp_column_name(order_by: &OrderBy, index: usize) -> String {
+    if order_by.is_on_model() {
+        order_by.field.db_name().to_owned()
+    } else {
+        format!("{}_{}", ORDER_TABLE_ALIAS, index)
+    }
+}
+
+fn order_definitions(query_arguments: &QueryArguments, model: &ModelRef) -> Vec<OrderBy> {
+    if query_arguments.order_by.is_empty() {
+        model.primary_identifier().scalar_fields().map(OrderBy::from).collect()
     } else {
-        defined_ordering
+        query_arguments.order_by.clone()
     }
 }
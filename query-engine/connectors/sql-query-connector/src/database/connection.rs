@@ -1,9 +1,12 @@
 use super::transaction::SqlConnectorTransaction;
-use crate::{database::operations::*, QueryExt, SqlError};
+use crate::{
+    database::operations::*, field_transformation::*, ConcurrencyPermit, QueryExt, RawStrictness, SqlCommenter,
+    SqlError,
+};
 use async_trait::async_trait;
 use connector_interface::{
-    self as connector, filter::Filter, AggregationResult, Aggregator, Connection, QueryArguments, ReadOperations,
-    RecordFilter, Transaction, WriteArgs, WriteOperations,
+    self as connector, filter::Filter, AggregationResult, Aggregator, Connection, FieldTransformers, QueryArguments,
+    QueryTags, ReadOperations, RecordFilter, Transaction, WriteArgs, WriteOperations,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
@@ -13,6 +16,11 @@ use std::future::Future;
 pub struct SqlConnection<C> {
     inner: C,
     connection_info: ConnectionInfo,
+    commenter: SqlCommenter,
+    raw_strictness: RawStrictness,
+    field_transformers: FieldTransformers,
+    /// Held until the connection is dropped, when the connector limits concurrency.
+    _permit: Option<ConcurrencyPermit>,
 }
 
 impl<C> SqlConnection<C>
@@ -21,7 +29,31 @@ where
 {
     pub fn new(inner: C, connection_info: &ConnectionInfo) -> Self {
         let connection_info = connection_info.clone();
-        Self { inner, connection_info }
+        let commenter = SqlCommenter::disabled(&connection_info);
+
+        Self {
+            inner,
+            connection_info,
+            commenter,
+            raw_strictness: RawStrictness::default(),
+            field_transformers: FieldTransformers::new(),
+            _permit: None,
+        }
+    }
+
+    pub fn with_permit(mut self, permit: Option<ConcurrencyPermit>) -> Self {
+        self._permit = permit;
+        self
+    }
+
+    pub fn with_raw_strictness(mut self, raw_strictness: RawStrictness) -> Self {
+        self.raw_strictness = raw_strictness;
+        self
+    }
+
+    pub fn with_field_transformers(mut self, field_transformers: FieldTransformers) -> Self {
+        self.field_transformers = field_transformers;
+        self
     }
 
     async fn catch<O>(
@@ -43,12 +75,23 @@ where
     async fn start_transaction<'a>(&'a self) -> connector::Result<Box<dyn Transaction + 'a>> {
         let fut_tx = self.inner.start_transaction();
         let connection_info = &self.connection_info;
+        let commenter = self.commenter.clone();
+        let raw_strictness = self.raw_strictness;
+        let field_transformers = self.field_transformers.clone();
+
         self.catch(async move {
             let tx: quaint::connector::Transaction = fut_tx.await.map_err(SqlError::from)?;
-            Ok(Box::new(SqlConnectorTransaction::new(tx, &connection_info)) as Box<dyn Transaction>)
+            let tx = SqlConnectorTransaction::new(tx, &connection_info, commenter, raw_strictness)
+                .with_field_transformers(field_transformers);
+
+            Ok(Box::new(tx) as Box<dyn Transaction>)
         })
         .await
     }
+
+    fn set_query_tags(&mut self, tags: QueryTags) {
+        self.commenter = SqlCommenter::new(&self.connection_info, &tags);
+    }
 }
 
 #[async_trait]
@@ -61,9 +104,21 @@ where
         model: &ModelRef,
         filter: &Filter,
         selected_fields: &ModelProjection,
+        relation_counts: &[RelationFieldRef],
     ) -> connector::Result<Option<SingleRecord>> {
-        self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await })
-            .await
+        self.catch(async move {
+            let record = read::get_single_record(
+                &self.inner,
+                &self.commenter,
+                model,
+                filter,
+                selected_fields,
+                relation_counts,
+            )
+            .await?;
+            transform_single_record(&self.field_transformers, model, record)
+        })
+        .await
     }
 
     async fn get_many_records(
@@ -71,9 +126,48 @@ where
         model: &ModelRef,
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
+        relation_counts: &[RelationFieldRef],
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
-            .await
+        self.catch(async move {
+            let records = read::get_many_records(
+                &self.inner,
+                &self.commenter,
+                model,
+                query_arguments,
+                selected_fields,
+                relation_counts,
+            )
+            .await?;
+            transform_many_records(&self.field_transformers, model, records)
+        })
+        .await
+    }
+
+    async fn get_many_records_per_partition(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        partition_by: &ModelProjection,
+        selected_fields: &ModelProjection,
+    ) -> connector::Result<Option<ManyRecords>> {
+        if !read::can_paginate_per_partition(&self.connection_info) {
+            return Ok(None);
+        }
+
+        self.catch(async move {
+            let records = read::get_many_records_per_partition(
+                &self.inner,
+                &self.commenter,
+                model,
+                query_arguments,
+                partition_by,
+                selected_fields,
+            )
+            .await?;
+
+            transform_many_records(&self.field_transformers, model, records).map(Some)
+        })
+        .await
     }
 
     async fn get_related_m2m_record_ids(
@@ -81,8 +175,10 @@ where
         from_field: &RelationFieldRef,
         from_record_ids: &[RecordProjection],
     ) -> connector::Result<Vec<(RecordProjection, RecordProjection)>> {
-        self.catch(async move { read::get_related_m2m_record_ids(&self.inner, from_field, from_record_ids).await })
-            .await
+        self.catch(async move {
+            read::get_related_m2m_record_ids(&self.inner, &self.commenter, from_field, from_record_ids).await
+        })
+        .await
     }
 
     async fn aggregate_records(
@@ -91,8 +187,53 @@ where
         aggregators: Vec<Aggregator>,
         query_arguments: QueryArguments,
     ) -> connector::Result<Vec<AggregationResult>> {
-        self.catch(async move { read::aggregate(&self.inner, model, aggregators, query_arguments).await })
+        self.catch(
+            async move { read::aggregate(&self.inner, &self.commenter, model, aggregators, query_arguments).await },
+        )
+        .await
+    }
+
+    async fn group_by_records(
+        &self,
+        model: &ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+    ) -> connector::Result<Vec<Vec<AggregationResult>>> {
+        self.catch(async move {
+            read::group_by(
+                &self.inner,
+                &self.commenter,
+                model,
+                group_by,
+                aggregators,
+                query_arguments,
+            )
             .await
+        })
+        .await
+    }
+
+    async fn count_records(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        estimate: bool,
+    ) -> connector::Result<usize> {
+        let sql_family = self.connection_info.sql_family();
+
+        self.catch(async move {
+            read::count(
+                &self.inner,
+                &self.commenter,
+                sql_family,
+                model,
+                query_arguments,
+                estimate,
+            )
+            .await
+        })
+        .await
     }
 }
 
@@ -102,8 +243,11 @@ where
     C: QueryExt + Send + Sync + 'static,
 {
     async fn create_record(&self, model: &ModelRef, args: WriteArgs) -> connector::Result<RecordProjection> {
-        self.catch(async move { write::create_record(&self.inner, model, args).await })
-            .await
+        self.catch(async move {
+            let args = transform_write_args(&self.field_transformers, model, args)?;
+            write::create_record(&self.inner, &self.commenter, model, args).await
+        })
+        .await
     }
 
     async fn update_records(
@@ -112,22 +256,46 @@ where
         record_filter: RecordFilter,
         args: WriteArgs,
     ) -> connector::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.inner, model, record_filter, args).await })
-            .await
+        self.catch(async move {
+            let args = transform_write_args(&self.field_transformers, model, args)?;
+            write::update_records(&self.inner, &self.commenter, model, record_filter, args).await
+        })
+        .await
     }
 
-    async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
-        self.catch(async move { write::delete_records(&self.inner, model, record_filter).await })
+    async fn delete_records(
+        &self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+    ) -> connector::Result<Vec<RecordProjection>> {
+        self.catch(async move { write::delete_records(&self.inner, &self.commenter, model, record_filter).await })
             .await
     }
 
+    async fn upsert_records(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        conflict_target: &[ScalarFieldRef],
+    ) -> connector::Result<usize> {
+        self.catch(async move {
+            let args = args
+                .into_iter()
+                .map(|args| transform_write_args(&self.field_transformers, model, args))
+                .collect::<crate::Result<Vec<_>>>()?;
+
+            write::upsert_records(&self.inner, &self.commenter, model, args, conflict_target).await
+        })
+        .await
+    }
+
     async fn connect(
         &self,
         field: &RelationFieldRef,
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::connect(&self.inner, field, parent_id, child_ids).await })
+        self.catch(async move { write::connect(&self.inner, &self.commenter, field, parent_id, child_ids).await })
             .await
     }
 
@@ -137,7 +305,7 @@ where
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::disconnect(&self.inner, field, parent_id, child_ids).await })
+        self.catch(async move { write::disconnect(&self.inner, &self.commenter, field, parent_id, child_ids).await })
             .await
     }
 
@@ -147,7 +315,7 @@ where
     }
 
     async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<serde_json::Value> {
-        self.catch(async move { write::query_raw(&self.inner, query, parameters).await })
+        self.catch(async move { write::query_raw(&self.inner, query, parameters, self.raw_strictness).await })
             .await
     }
 }
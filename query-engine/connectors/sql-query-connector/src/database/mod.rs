@@ -8,8 +8,9 @@ mod transaction;
 pub(crate) mod operations;
 
 use async_trait::async_trait;
-use connector_interface::{error::ConnectorError, Connector};
+use connector_interface::{error::ConnectorError, Connector, PoolConfig, PoolStats};
 use datamodel::Datasource;
+use quaint::{pooled::Quaint, prelude::ConnectionInfo};
 
 pub use mssql::*;
 pub use mysql::*;
@@ -18,13 +19,26 @@ pub use sqlite::*;
 
 #[async_trait]
 pub trait FromSource {
-    async fn from_source(source: &Datasource) -> connector_interface::Result<Self>
+    /// Loads the connector of the datasource. The values set in `pool_overrides` replace the pool
+    /// configuration of the connection string. The queries that only read go to the read replicas
+    /// at `read_replica_urls`, if any. The `tenants` are the schemas or databases of the server the
+    /// requests may select, on connectors serving one per tenant.
+    async fn from_source(
+        source: &Datasource,
+        pool_overrides: PoolConfig,
+        read_replica_urls: &[String],
+        tenants: &[String],
+    ) -> connector_interface::Result<Self>
     where
         Self: Connector + Sized;
 }
 
+async fn pool_stats(pool: &Quaint, pool_config: &PoolConfig) -> PoolStats {
+    PoolStats::new(pool.capacity().await, pool_config)
+}
+
 async fn catch<O>(
-    connection_info: &quaint::prelude::ConnectionInfo,
+    connection_info: &ConnectionInfo,
     fut: impl std::future::Future<Output = Result<O, crate::SqlError>>,
 ) -> Result<O, ConnectorError> {
     match fut.await {
@@ -1,54 +1,145 @@
 use super::connection::SqlConnection;
-use crate::{FromSource, SqlError};
+use crate::{
+    pool_config::{configure_pool, pool_config},
+    read_replicas::ReadReplicas,
+    tenants::use_database_statement,
+    ConcurrencyLimiter, FromSource, RawStrictness, SearchPath, SqlError, Tenants,
+};
 use async_trait::async_trait;
 use connector_interface::{
     self as connector,
     error::{ConnectorError, ErrorKind},
-    Connection, Connector,
+    Connection, Connector, FieldTransformers, PoolConfig, PoolStats,
 };
 use datamodel::Datasource;
-use quaint::{pooled::Quaint, prelude::ConnectionInfo};
+use quaint::{
+    pooled::Quaint,
+    prelude::{ConnectionInfo, Queryable},
+};
 use std::time::Duration;
 
 pub struct Mysql {
     pool: Quaint,
+    pool_config: PoolConfig,
+    read_replicas: ReadReplicas,
     connection_info: ConnectionInfo,
+    concurrency_limiter: Option<ConcurrencyLimiter>,
+    raw_strictness: RawStrictness,
+    tenants: Tenants,
+    field_transformers: FieldTransformers,
+}
+
+impl Mysql {
+    /// Sets the transformers applied to the values of the configured fields, e.g. to encrypt them.
+    pub fn with_field_transformers(mut self, field_transformers: FieldTransformers) -> Self {
+        self.field_transformers = field_transformers;
+        self
+    }
+
+    /// A connection of `pool`, the pool of the primary, of a read replica or of the database of `tenant`.
+    async fn connection_from(
+        &self,
+        pool: &Quaint,
+        tenant: Option<&str>,
+    ) -> connector::Result<Box<dyn Connection + 'static>> {
+        super::catch(&self.connection_info, async move {
+            let permit = match &self.concurrency_limiter {
+                Some(limiter) => Some(limiter.acquire().await?),
+                None => None,
+            };
+
+            let conn = pool.check_out().await.map_err(SqlError::from)?;
+
+            // Serving tenants, the tables are not qualified with the database: the connection must be on the
+            // one of the request, whatever a raw `USE` of a previous request did.
+            if !self.tenants.is_empty() {
+                let database = tenant.unwrap_or_else(|| self.connection_info.schema_name());
+
+                conn.raw_cmd(&use_database_statement(database))
+                    .await
+                    .map_err(SqlError::from)?;
+            }
+
+            let conn = SqlConnection::new(conn, &self.connection_info)
+                .with_permit(permit)
+                .with_raw_strictness(self.raw_strictness)
+                .with_field_transformers(self.field_transformers.clone());
+
+            Ok(Box::new(conn) as Box<dyn Connection>)
+        })
+        .await
+    }
 }
 
 #[async_trait]
 impl FromSource for Mysql {
-    async fn from_source(source: &Datasource) -> connector_interface::Result<Self> {
-        let connection_info = ConnectionInfo::from_url(&source.url().value)
+    async fn from_source(
+        source: &Datasource,
+        pool_overrides: PoolConfig,
+        read_replica_urls: &[String],
+        tenants: &[String],
+    ) -> connector_interface::Result<Self> {
+        let connection_info = ConnectionInfo::from_url(source.connection_url())
             .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err.into())))?;
 
-        let mut builder = Quaint::builder(&source.url().value)
+        let concurrency_limiter = ConcurrencyLimiter::from_url(source.connection_url())
+            .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err)))?;
+
+        let raw_strictness = RawStrictness::from_url(source.connection_url())
+            .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err)))?;
+
+        // Search paths only exist on PostgreSQL, reject them instead of ignoring them.
+        SearchPath::from_url(source.connection_url(), &connection_info)
+            .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err)))?;
+
+        let pool_config = pool_config(source.connection_url(), pool_overrides)
+            .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err)))?;
+
+        let mut builder = Quaint::builder(source.connection_url())
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
-        builder.max_idle_lifetime(Duration::from_secs(300));
+        configure_pool(&mut builder, &pool_config);
         builder.health_check_interval(Duration::from_secs(15));
         builder.test_on_check_out(true);
 
         let pool = builder.build();
         let connection_info = pool.connection_info().to_owned();
+        let read_replicas = ReadReplicas::new(read_replica_urls, &connection_info, &pool_config)?;
+        let tenants = Tenants::new(tenants, source.connection_url(), &connection_info, &pool_config)?;
 
-        Ok(Mysql { pool, connection_info })
+        Ok(Mysql {
+            pool,
+            pool_config,
+            read_replicas,
+            connection_info,
+            concurrency_limiter,
+            raw_strictness,
+            tenants,
+            field_transformers: FieldTransformers::new(),
+        })
     }
 }
 
 #[async_trait]
 impl Connector for Mysql {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
-        super::catch(&self.connection_info, async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, &self.connection_info);
+        self.connection_from(&self.pool, None).await
+    }
 
-            Ok(Box::new(conn) as Box<dyn Connection>)
-        })
-        .await
+    async fn get_read_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
+        self.connection_from(self.read_replicas.next().unwrap_or(&self.pool), None)
+            .await
+    }
+
+    async fn get_tenant_connection(&self, tenant: &str) -> connector::Result<Box<dyn Connection + 'static>> {
+        self.connection_from(self.tenants.pool(tenant)?, Some(tenant)).await
     }
 
     fn name(&self) -> String {
         "mysql".to_owned()
     }
+    async fn pool_stats(&self) -> Option<PoolStats> {
+        Some(super::pool_stats(&self.pool, &self.pool_config).await)
+    }
 }
@@ -1,24 +1,32 @@
 use crate::{
     query_arguments_ext::QueryArgumentsExt,
     query_builder::{self, read},
-    QueryExt, SqlError,
+    QueryExt, SqlCommenter, SqlError,
 };
 use connector_interface::*;
+use datamodel::FieldArity;
 use futures::stream::{FuturesUnordered, StreamExt};
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{
+    ast::*,
+    prelude::{ConnectionInfo, SqlFamily},
+    visitor::{self, Visitor},
+};
 
 pub async fn get_single_record(
     conn: &dyn QueryExt,
+    commenter: &SqlCommenter,
     model: &ModelRef,
     filter: &Filter,
     selected_fields: &ModelProjection,
+    relation_counts: &[RelationFieldRef],
 ) -> crate::Result<Option<SingleRecord>> {
     let query = read::get_records(&model, selected_fields.as_columns(), filter);
-    let field_names = selected_fields.db_names().map(String::from).collect();
-    let idents: Vec<_> = selected_fields.type_identifiers_with_arities();
+    let query = read::count_related_records(query, relation_counts);
+    let field_names = field_names(selected_fields, relation_counts);
+    let idents = idents(selected_fields, relation_counts);
 
-    let record = (match conn.find(query, idents.as_slice()).await {
+    let record = (match conn.find(query, idents.as_slice(), commenter).await {
         Ok(result) => Ok(Some(result)),
         Err(_e @ SqlError::RecordNotFoundForWhere(_)) => Ok(None),
         Err(_e @ SqlError::RecordDoesNotExist) => Ok(None),
@@ -32,13 +40,15 @@ pub async fn get_single_record(
 
 pub async fn get_many_records(
     conn: &dyn QueryExt,
+    commenter: &SqlCommenter,
     model: &ModelRef,
     mut query_arguments: QueryArguments,
     selected_fields: &ModelProjection,
+    relation_counts: &[RelationFieldRef],
 ) -> crate::Result<ManyRecords> {
     let reversed = query_arguments.needs_reversed_order();
-    let field_names = selected_fields.db_names().map(String::from).collect();
-    let idents: Vec<_> = selected_fields.type_identifiers_with_arities();
+    let field_names = field_names(selected_fields, relation_counts);
+    let idents = idents(selected_fields, relation_counts);
     let mut records = ManyRecords::new(field_names);
 
     if let Some(0) = query_arguments.take {
@@ -57,7 +67,8 @@ pub async fn get_many_records(
 
         for args in batches.into_iter() {
             let query = read::get_records(model, selected_fields.as_columns(), args);
-            futures.push(conn.filter(query.into(), idents.as_slice()));
+            let query = read::count_related_records(query, relation_counts);
+            futures.push(conn.filter(query.into(), idents.as_slice(), commenter));
         }
 
         while let Some(result) = futures.next().await {
@@ -71,8 +82,13 @@ pub async fn get_many_records(
         }
     } else {
         let query = read::get_records(model, selected_fields.as_columns(), query_arguments);
+        let query = read::count_related_records(query, relation_counts);
 
-        for item in conn.filter(query.into(), idents.as_slice()).await?.into_iter() {
+        for item in conn
+            .filter(query.into(), idents.as_slice(), commenter)
+            .await?
+            .into_iter()
+        {
             records.push(Record::from(item))
         }
     };
@@ -84,8 +100,67 @@ pub async fn get_many_records(
     Ok(records)
 }
 
+/// The relation counts are read after the selected fields, see `relation_count_field_name`.
+fn field_names(selected_fields: &ModelProjection, relation_counts: &[RelationFieldRef]) -> Vec<String> {
+    selected_fields
+        .db_names()
+        .map(String::from)
+        .chain(relation_counts.iter().map(relation_count_field_name))
+        .collect()
+}
+
+fn idents(
+    selected_fields: &ModelProjection,
+    relation_counts: &[RelationFieldRef],
+) -> Vec<(TypeIdentifier, FieldArity)> {
+    let mut idents = selected_fields.type_identifiers_with_arities();
+    idents.extend(
+        relation_counts
+            .iter()
+            .map(|_| (TypeIdentifier::Int, FieldArity::Required)),
+    );
+
+    idents
+}
+
+/// Window functions are required to paginate partitions. MySQL only has them since 8.0, and the connector can't
+/// tell the server versions apart.
+pub fn can_paginate_per_partition(connection_info: &ConnectionInfo) -> bool {
+    connection_info.sql_family() != SqlFamily::Mysql
+}
+
+pub async fn get_many_records_per_partition(
+    conn: &dyn QueryExt,
+    commenter: &SqlCommenter,
+    model: &ModelRef,
+    query_arguments: QueryArguments,
+    partition_by: &ModelProjection,
+    selected_fields: &ModelProjection,
+) -> crate::Result<ManyRecords> {
+    let field_names = selected_fields.db_names().map(String::from).collect();
+    let idents: Vec<_> = selected_fields.type_identifiers_with_arities();
+    let mut records = ManyRecords::new(field_names);
+
+    if let Some(0) = query_arguments.take {
+        return Ok(records);
+    };
+
+    let query = read::get_records_per_partition(model, selected_fields, partition_by, query_arguments);
+
+    for item in conn
+        .filter(query.into(), idents.as_slice(), commenter)
+        .await?
+        .into_iter()
+    {
+        records.push(Record::from(item))
+    }
+
+    Ok(records)
+}
+
 pub async fn get_related_m2m_record_ids(
     conn: &dyn QueryExt,
+    commenter: &SqlCommenter,
     from_field: &RelationFieldRef,
     from_record_ids: &[RecordProjection],
 ) -> crate::Result<Vec<(RecordProjection, RecordProjection)>> {
@@ -117,7 +192,7 @@ pub async fn get_related_m2m_record_ids(
 
     // first parent id, then child id
     Ok(conn
-        .filter(select.into(), idents.as_slice())
+        .filter(select.into(), idents.as_slice(), commenter)
         .await?
         .into_iter()
         .map(|row| {
@@ -147,6 +222,7 @@ pub async fn get_related_m2m_record_ids(
 
 pub async fn aggregate(
     conn: &dyn QueryExt,
+    commenter: &SqlCommenter,
     model: &ModelRef,
     aggregators: Vec<Aggregator>,
     query_arguments: QueryArguments,
@@ -157,10 +233,113 @@ pub async fn aggregate(
         .flat_map(|aggregator| aggregator.identifiers())
         .collect();
 
-    let mut rows = conn.filter(query.into(), idents.as_slice()).await?;
+    let mut rows = conn.filter(query.into(), idents.as_slice(), commenter).await?;
     let row = rows
         .pop()
         .expect("Expected exactly one return row for aggregation query.");
 
     Ok(row.into_aggregation_results(&aggregators))
 }
+
+pub async fn group_by(
+    conn: &dyn QueryExt,
+    commenter: &SqlCommenter,
+    model: &ModelRef,
+    group_by: Vec<ScalarFieldRef>,
+    aggregators: Vec<Aggregator>,
+    query_arguments: QueryArguments,
+) -> crate::Result<Vec<Vec<AggregationResult>>> {
+    let query = read::group_by_aggregate(model, &group_by, &aggregators, query_arguments);
+    let idents: Vec<_> = group_by
+        .iter()
+        .map(|field| field.type_identifier_with_arity())
+        .chain(aggregators.iter().flat_map(|aggregator| aggregator.identifiers()))
+        .collect();
+
+    let rows = conn.filter(query.into(), idents.as_slice(), commenter).await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| row.into_grouped_aggregation_results(&group_by, &aggregators))
+        .collect())
+}
+
+/// Below this number of rows, estimated counts are replaced by exact ones: counting is cheap, and
+/// the planner statistics can be far off on small tables.
+const ESTIMATE_THRESHOLD: usize = 100_000;
+
+/// Counts the records matching the query arguments. With `estimate`, Postgres answers with the
+/// number of rows the planner expects the query to return, if that is above `ESTIMATE_THRESHOLD`.
+pub async fn count(
+    conn: &dyn QueryExt,
+    commenter: &SqlCommenter,
+    sql_family: SqlFamily,
+    model: &ModelRef,
+    query_arguments: QueryArguments,
+    estimate: bool,
+) -> crate::Result<usize> {
+    if estimate && matches!(sql_family, SqlFamily::Postgres) {
+        let query = read::get_records(model, model.primary_identifier().as_columns(), query_arguments.clone());
+
+        match estimate_rows(conn, query).await? {
+            Some(rows) if rows >= ESTIMATE_THRESHOLD => return Ok(rows),
+            _ => (),
+        }
+    }
+
+    let results = aggregate(conn, commenter, model, vec![Aggregator::Count], query_arguments).await?;
+
+    match results.into_iter().next() {
+        Some(AggregationResult::Count(PrismaValue::Int(count))) => Ok(count as usize),
+        other => Err(SqlError::ConversionError(anyhow::anyhow!(
+            "Expected an integer count, got {:?}.",
+            other
+        ))),
+    }
+}
+
+/// Asks the Postgres planner how many rows the query returns, without running it.
+async fn estimate_rows(conn: &dyn QueryExt, query: Select<'static>) -> crate::Result<Option<usize>> {
+    let (sql, params) = visitor::Postgres::build(query)?;
+    let result_set = conn
+        .query_raw(&format!("EXPLAIN (FORMAT JSON) {}", sql), &params)
+        .await?;
+
+    let plan = match result_set.into_iter().next().and_then(|row| row.into_iter().next()) {
+        Some(quaint::ast::Value::Json(Some(json))) => Some(json),
+        Some(quaint::ast::Value::Text(Some(text))) => serde_json::from_str(&text).ok(),
+        _ => None,
+    };
+
+    Ok(plan.and_then(|plan| planned_rows(&plan)))
+}
+
+/// The estimated number of rows of the top node of a plan, in the `EXPLAIN (FORMAT JSON)` format.
+fn planned_rows(plan: &serde_json::Value) -> Option<usize> {
+    plan.get(0)?
+        .get("Plan")?
+        .get("Plan Rows")?
+        .as_f64()
+        .map(|rows| rows as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn planned_rows_are_read_from_the_top_plan_node() {
+        let plan = json!([{
+            "Plan": {
+                "Node Type": "Limit",
+                "Plan Rows": 250000,
+                "Plans": [{ "Node Type": "Seq Scan", "Plan Rows": 4000000 }]
+            }
+        }]);
+
+        assert_eq!(planned_rows(&plan), Some(250_000));
+        assert_eq!(planned_rows(&json!([{ "Plan": {} }])), None);
+        assert_eq!(planned_rows(&json!({})), None);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{error::SqlError, query_builder::write, QueryExt};
+use crate::{error::SqlError, query_builder::write, QueryExt, RawStrictness, SqlCommenter};
 use connector_interface::*;
 use prisma_models::*;
 use prisma_value::PrismaValue;
@@ -8,10 +8,15 @@ use user_facing_errors::query_engine::DatabaseConstraint;
 
 /// Create a single record to the database defined in `conn`, resulting into a
 /// `RecordProjection` as an identifier pointing to the just-created record.
-pub async fn create_record(conn: &dyn QueryExt, model: &ModelRef, args: WriteArgs) -> crate::Result<RecordProjection> {
+pub async fn create_record(
+    conn: &dyn QueryExt,
+    commenter: &SqlCommenter,
+    model: &ModelRef,
+    args: WriteArgs,
+) -> crate::Result<RecordProjection> {
     let (insert, returned_id) = write::create_record(model, args);
 
-    let result_set = match conn.insert(insert).await {
+    let result_set = match commenter.query(conn, insert.into()).await {
         Ok(id) => id,
         Err(e) => match e.kind() {
             ErrorKind::UniqueConstraintViolation { constraint } => match constraint {
@@ -68,11 +73,12 @@ pub async fn create_record(conn: &dyn QueryExt, model: &ModelRef, args: WriteArg
 /// operation.
 pub async fn update_records(
     conn: &dyn QueryExt,
+    commenter: &SqlCommenter,
     model: &ModelRef,
     record_filter: RecordFilter,
     args: WriteArgs,
 ) -> crate::Result<Vec<RecordProjection>> {
-    let ids = conn.filter_selectors(model, record_filter).await?;
+    let ids = conn.filter_selectors(model, record_filter, commenter).await?;
     let id_args = pick_args(&model.primary_identifier(), &args);
 
     if ids.len() == 0 {
@@ -85,28 +91,55 @@ pub async fn update_records(
     };
 
     for update in updates {
-        conn.query(update).await?;
+        commenter.query(conn, update).await?;
     }
 
     Ok(merge_write_args(ids, id_args))
 }
 
-/// Delete multiple records in `conn`, defined in the `Filter`. Result is the number of items deleted.
+/// Delete multiple records in `conn`, defined in the `Filter`. Result is the identifiers of the
+/// deleted records, as selected before deleting them.
 pub async fn delete_records(
     conn: &dyn QueryExt,
+    commenter: &SqlCommenter,
     model: &ModelRef,
     record_filter: RecordFilter,
+) -> crate::Result<Vec<RecordProjection>> {
+    let ids = conn.filter_selectors(model, record_filter, commenter).await?;
+
+    if ids.len() == 0 {
+        return Ok(ids);
+    }
+
+    let deletes = {
+        let ids: Vec<&RecordProjection> = ids.iter().map(|id| &*id).collect();
+        write::delete_many(model, ids.as_slice())
+    };
+
+    for delete in deletes {
+        commenter.query(conn, delete).await?;
+    }
+
+    Ok(ids)
+}
+
+/// Insert the records in `conn`, updating the existing records that conflict with them on the
+/// `conflict_target` fields instead. Result is the number of records written.
+pub async fn upsert_records(
+    conn: &dyn QueryExt,
+    commenter: &SqlCommenter,
+    model: &ModelRef,
+    args: Vec<WriteArgs>,
+    conflict_target: &[ScalarFieldRef],
 ) -> crate::Result<usize> {
-    let ids = conn.filter_selectors(model, record_filter).await?;
-    let ids: Vec<&RecordProjection> = ids.iter().map(|id| &*id).collect();
-    let count = ids.len();
+    let count = args.len();
 
     if count == 0 {
         return Ok(count);
     }
 
-    for delete in write::delete_many(model, ids.as_slice()) {
-        conn.query(delete).await?;
+    for (insert, on_conflict) in write::upsert_many(model, args, conflict_target, commenter.sql_family())? {
+        commenter.query_on_conflict(conn, insert, &on_conflict).await?;
     }
 
     Ok(count)
@@ -116,12 +149,13 @@ pub async fn delete_records(
 /// The relation information is in the `RelationFieldRef`.
 pub async fn connect(
     conn: &dyn QueryExt,
+    commenter: &SqlCommenter,
     field: &RelationFieldRef,
     parent_id: &RecordProjection,
     child_ids: &[RecordProjection],
 ) -> crate::Result<()> {
     let query = write::create_relation_table_records(field, parent_id, child_ids);
-    conn.query(query).await?;
+    commenter.query(conn, query).await?;
 
     Ok(())
 }
@@ -130,12 +164,13 @@ pub async fn connect(
 /// The relation information is in the `RelationFieldRef`.
 pub async fn disconnect(
     conn: &dyn QueryExt,
+    commenter: &SqlCommenter,
     field: &RelationFieldRef,
     parent_id: &RecordProjection,
     child_ids: &[RecordProjection],
 ) -> crate::Result<()> {
     let query = write::delete_relation_table_records(field, parent_id, child_ids);
-    conn.delete(query).await?;
+    commenter.query(conn, query.into()).await?;
 
     Ok(())
 }
@@ -153,8 +188,9 @@ pub async fn query_raw(
     conn: &dyn QueryExt,
     query: String,
     parameters: Vec<PrismaValue>,
+    strictness: RawStrictness,
 ) -> crate::Result<serde_json::Value> {
-    let value = conn.raw_json(query, parameters).await?;
+    let value = conn.raw_json(query, parameters, strictness).await?;
     Ok(value)
 }
 
@@ -210,5 +246,12 @@ fn apply_expression(val: PrismaValue, expr: WriteExpression) -> PrismaValue {
         WriteExpression::Substract(rhs) => val - rhs,
         WriteExpression::Multiply(rhs) => val * rhs,
         WriteExpression::Divide(rhs) => val / rhs,
+        WriteExpression::Push(rhs) => match (val, rhs) {
+            (PrismaValue::List(mut values), PrismaValue::List(pushed)) => {
+                values.extend(pushed);
+                PrismaValue::List(values)
+            }
+            (_, pushed) => pushed,
+        },
     }
 }
@@ -1,25 +1,57 @@
 use super::connection::SqlConnection;
-use crate::{FromSource, SqlError};
+use crate::{
+    pool_config::{configure_pool, pool_config},
+    ConcurrencyLimiter, FromSource, RawStrictness, SearchPath, SqlError, Tenants,
+};
 use async_trait::async_trait;
 use connector_interface::{
     self as connector,
     error::{ConnectorError, ErrorKind},
-    Connection, Connector,
+    Connection, Connector, FieldTransformers, PoolConfig, PoolStats,
+};
+use datamodel::{common::SqliteInMemory, Datasource};
+use quaint::{
+    connector::{Queryable, SqliteParams},
+    error::ErrorKind as QuaintKind,
+    pooled::{PooledConnection, Quaint},
+    prelude::ConnectionInfo,
 };
-use datamodel::Datasource;
-use quaint::{connector::SqliteParams, error::ErrorKind as QuaintKind, pooled::Quaint, prelude::ConnectionInfo};
 use std::{convert::TryFrom, time::Duration};
 
+/// The schema name in-memory databases are attached under, as they have no file name to derive it from.
+const IN_MEMORY_DB_NAME: &str = "main_memory";
+
+/// The schema name quaint attaches the private database of new connections under, when they get the
+/// shared in-memory database attached instead.
+const PRIVATE_DB_NAME: &str = "main_memory_private";
+
 pub struct Sqlite {
     pool: Quaint,
+    pool_config: PoolConfig,
     file_path: String,
+    db_name: String,
+    in_memory: Option<SqliteInMemory>,
+    concurrency_limiter: Option<ConcurrencyLimiter>,
+    raw_strictness: RawStrictness,
+    field_transformers: FieldTransformers,
 }
 
 impl Sqlite {
+    /// Sets the transformers applied to the values of the configured fields, e.g. to encrypt them.
+    pub fn with_field_transformers(mut self, field_transformers: FieldTransformers) -> Self {
+        self.field_transformers = field_transformers;
+        self
+    }
+
     pub fn file_path(&self) -> &str {
         self.file_path.as_str()
     }
 
+    /// The schema name the database is attached under.
+    pub fn db_name(&self) -> &str {
+        self.db_name.as_str()
+    }
+
     fn connection_info(&self) -> &ConnectionInfo {
         self.pool.connection_info()
     }
@@ -27,24 +59,73 @@ impl Sqlite {
 
 #[async_trait]
 impl FromSource for Sqlite {
-    async fn from_source(source: &Datasource) -> connector_interface::Result<Sqlite> {
-        let connection_info = ConnectionInfo::from_url(&source.url().value)
+    async fn from_source(
+        source: &Datasource,
+        pool_overrides: PoolConfig,
+        read_replica_urls: &[String],
+        tenants: &[String],
+    ) -> connector_interface::Result<Sqlite> {
+        let connection_info = ConnectionInfo::from_url(source.connection_url())
             .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err.into())))?;
 
-        let params = SqliteParams::try_from(source.url().value.as_str())
+        let concurrency_limiter = ConcurrencyLimiter::from_url(source.connection_url())
+            .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err)))?;
+
+        let raw_strictness = RawStrictness::from_url(source.connection_url())
+            .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err)))?;
+
+        // Search paths only exist on PostgreSQL, reject them instead of ignoring them.
+        SearchPath::from_url(source.connection_url(), &connection_info)
+            .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err)))?;
+
+        // Tenants are only served on PostgreSQL and MySQL, reject them the same way.
+        Tenants::new(
+            tenants,
+            source.connection_url(),
+            &connection_info,
+            &PoolConfig::default(),
+        )?;
+
+        if !read_replica_urls.is_empty() {
+            return Err(ConnectorError::from_kind(ErrorKind::ConnectionError(anyhow::anyhow!(
+                "Read replicas are not supported on SQLite."
+            ))));
+        }
+
+        let mut pool_config = pool_config(source.connection_url(), pool_overrides)
+            .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err)))?;
+
+        let params = SqliteParams::try_from(source.connection_url())
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
         let file_path = params.file_path;
 
-        let url_with_db = {
-            let db_name = std::path::Path::new(&file_path)
+        let in_memory = SqliteInMemory::from_url(source.connection_url());
+
+        let db_name = if in_memory.is_some() {
+            IN_MEMORY_DB_NAME.to_owned()
+        } else {
+            std::path::Path::new(&file_path)
                 .file_stem()
                 .and_then(|stem| stem.to_str())
                 .ok_or_else(|| invalid_file_path_error(&file_path, &connection_info))?
-                .to_owned();
+                .to_owned()
+        };
 
-            let mut splitted = source.url().value.split("?");
+        let url_with_db = {
+            let (url, attached_name) = match &in_memory {
+                Some(db) if db.is_shared() => {
+                    (SqliteInMemory::connection_url(source.connection_url()), PRIVATE_DB_NAME)
+                }
+                Some(_) => (
+                    SqliteInMemory::connection_url(source.connection_url()),
+                    db_name.as_str(),
+                ),
+                None => (source.connection_url().to_owned(), db_name.as_str()),
+            };
+
+            let mut splitted = url.split("?");
             let url = splitted.next().unwrap();
             let params = splitted.next();
 
@@ -53,7 +134,7 @@ impl FromSource for Sqlite {
                 None => Vec::with_capacity(1),
             };
 
-            let db_name_param = format!("db_name={}", db_name);
+            let db_name_param = format!("db_name={}", attached_name);
             params.push(&db_name_param);
 
             format!("{}?{}", url, params.join("&"))
@@ -63,14 +144,56 @@ impl FromSource for Sqlite {
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
-        builder.max_idle_lifetime(Duration::from_secs(300));
+        if let Some(db) = &in_memory {
+            // An in-memory database only lives as long as a connection to it is open, so we must
+            // never close connections. Without a shared cache, every connection has its own
+            // database, hence a single connection.
+            if !db.is_shared() {
+                pool_config.connection_limit = Some(1);
+            }
+
+            pool_config.max_idle_lifetime = None;
+            pool_config.max_lifetime = None;
+        }
+
+        configure_pool(&mut builder, &pool_config);
         builder.health_check_interval(Duration::from_secs(15));
         builder.test_on_check_out(true);
 
         let pool = builder.build();
 
-        Ok(Sqlite { pool, file_path })
+        Ok(Sqlite {
+            pool,
+            pool_config,
+            file_path,
+            db_name,
+            in_memory,
+            concurrency_limiter,
+            raw_strictness,
+            field_transformers: FieldTransformers::new(),
+        })
+    }
+}
+
+/// Replaces the private database quaint attached to a new connection by the shared in-memory
+/// database. Connections that have it already are left untouched.
+async fn attach_shared_database(conn: &PooledConnection, db: &SqliteInMemory) -> quaint::Result<()> {
+    let private_db = conn
+        .query_raw(
+            "SELECT name FROM pragma_database_list WHERE name = ?",
+            &[PRIVATE_DB_NAME.into()],
+        )
+        .await?;
+
+    if private_db.is_empty() {
+        return Ok(());
     }
+
+    conn.execute_raw("DETACH DATABASE ?", &[PRIVATE_DB_NAME.into()]).await?;
+    conn.execute_raw("ATTACH DATABASE ? AS ?", &[db.uri().into(), IN_MEMORY_DB_NAME.into()])
+        .await?;
+
+    Ok(())
 }
 
 fn invalid_file_path_error(file_path: &str, connection_info: &ConnectionInfo) -> ConnectorError {
@@ -85,8 +208,21 @@ fn invalid_file_path_error(file_path: &str, connection_info: &ConnectionInfo) ->
 impl Connector for Sqlite {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info(), async move {
+            let permit = match &self.concurrency_limiter {
+                Some(limiter) => Some(limiter.acquire().await?),
+                None => None,
+            };
+
             let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, self.connection_info());
+
+            if let Some(db) = self.in_memory.as_ref().filter(|db| db.is_shared()) {
+                attach_shared_database(&conn, db).await.map_err(SqlError::from)?;
+            }
+
+            let conn = SqlConnection::new(conn, self.connection_info())
+                .with_permit(permit)
+                .with_raw_strictness(self.raw_strictness)
+                .with_field_transformers(self.field_transformers.clone());
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
@@ -96,4 +232,7 @@ impl Connector for Sqlite {
     fn name(&self) -> String {
         "sqlite".to_owned()
     }
+    async fn pool_stats(&self) -> Option<PoolStats> {
+        Some(super::pool_stats(&self.pool, &self.pool_config).await)
+    }
 }
@@ -1,28 +1,50 @@
 use crate::database::operations::*;
-use crate::SqlError;
+use crate::{field_transformation::*, RawStrictness, SqlCommenter, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
-    self as connector, filter::Filter, AggregationResult, Aggregator, QueryArguments, ReadOperations, RecordFilter,
-    Transaction, WriteArgs, WriteOperations,
+    self as connector, filter::Filter, AggregationResult, Aggregator, FieldTransformers, QueryArguments, QueryTags,
+    ReadOperations, RecordFilter, Transaction, WriteArgs, WriteOperations,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
-use quaint::prelude::ConnectionInfo;
+use quaint::prelude::{ConnectionInfo, Queryable, SqlFamily};
+use std::sync::Mutex;
 
 pub struct SqlConnectorTransaction<'tx> {
     inner: quaint::connector::Transaction<'tx>,
     connection_info: ConnectionInfo,
+    /// Behind a lock, as the tags can change between the operations of an interactive transaction.
+    commenter: Mutex<SqlCommenter>,
+    raw_strictness: RawStrictness,
+    field_transformers: FieldTransformers,
 }
 
 impl<'tx> SqlConnectorTransaction<'tx> {
-    pub fn new<'b: 'tx>(tx: quaint::connector::Transaction<'tx>, connection_info: &ConnectionInfo) -> Self {
+    pub fn new<'b: 'tx>(
+        tx: quaint::connector::Transaction<'tx>,
+        connection_info: &ConnectionInfo,
+        commenter: SqlCommenter,
+        raw_strictness: RawStrictness,
+    ) -> Self {
         let connection_info = connection_info.clone();
         Self {
             inner: tx,
             connection_info,
+            commenter: Mutex::new(commenter),
+            raw_strictness,
+            field_transformers: FieldTransformers::new(),
         }
     }
 
+    pub fn with_field_transformers(mut self, field_transformers: FieldTransformers) -> Self {
+        self.field_transformers = field_transformers;
+        self
+    }
+
+    fn commenter(&self) -> SqlCommenter {
+        self.commenter.lock().unwrap().clone()
+    }
+
     async fn catch<O>(
         &self,
         fut: impl std::future::Future<Output = Result<O, SqlError>>,
@@ -32,6 +54,16 @@ impl<'tx> SqlConnectorTransaction<'tx> {
             Err(err) => Err(err.into_connector_error(&self.connection_info)),
         }
     }
+
+    async fn savepoint_cmd(&self, cmd: Option<String>) -> connector::Result<()> {
+        match cmd {
+            Some(cmd) => {
+                self.catch(async move { Ok(self.inner.raw_cmd(&cmd).await.map_err(SqlError::from)?) })
+                    .await
+            }
+            None => Ok(()),
+        }
+    }
 }
 
 #[async_trait]
@@ -45,6 +77,42 @@ impl<'tx> Transaction for SqlConnectorTransaction<'tx> {
         self.catch(async move { Ok(self.inner.rollback().await.map_err(SqlError::from)?) })
             .await
     }
+
+    fn supports_savepoints(&self) -> bool {
+        true
+    }
+
+    fn set_query_tags(&self, tags: QueryTags) {
+        *self.commenter.lock().unwrap() = SqlCommenter::new(&self.connection_info, &tags);
+    }
+
+    async fn create_savepoint(&self, name: &str) -> connector::Result<()> {
+        let cmd = match self.connection_info.sql_family() {
+            SqlFamily::Mssql => format!("SAVE TRANSACTION {}", name),
+            _ => format!("SAVEPOINT {}", name),
+        };
+
+        self.savepoint_cmd(Some(cmd)).await
+    }
+
+    async fn rollback_to_savepoint(&self, name: &str) -> connector::Result<()> {
+        let cmd = match self.connection_info.sql_family() {
+            SqlFamily::Mssql => format!("ROLLBACK TRANSACTION {}", name),
+            _ => format!("ROLLBACK TO SAVEPOINT {}", name),
+        };
+
+        self.savepoint_cmd(Some(cmd)).await
+    }
+
+    async fn release_savepoint(&self, name: &str) -> connector::Result<()> {
+        // SQL Server has no notion of releasing a savepoint, they live until the transaction ends.
+        let cmd = match self.connection_info.sql_family() {
+            SqlFamily::Mssql => None,
+            _ => Some(format!("RELEASE SAVEPOINT {}", name)),
+        };
+
+        self.savepoint_cmd(cmd).await
+    }
 }
 
 #[async_trait]
@@ -54,9 +122,21 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         model: &ModelRef,
         filter: &Filter,
         selected_fields: &ModelProjection,
+        relation_counts: &[RelationFieldRef],
     ) -> connector::Result<Option<SingleRecord>> {
-        self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await })
-            .await
+        self.catch(async move {
+            let record = read::get_single_record(
+                &self.inner,
+                &self.commenter(),
+                model,
+                filter,
+                selected_fields,
+                relation_counts,
+            )
+            .await?;
+            transform_single_record(&self.field_transformers, model, record)
+        })
+        .await
     }
 
     async fn get_many_records(
@@ -64,9 +144,48 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         model: &ModelRef,
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
+        relation_counts: &[RelationFieldRef],
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
-            .await
+        self.catch(async move {
+            let records = read::get_many_records(
+                &self.inner,
+                &self.commenter(),
+                model,
+                query_arguments,
+                selected_fields,
+                relation_counts,
+            )
+            .await?;
+            transform_many_records(&self.field_transformers, model, records)
+        })
+        .await
+    }
+
+    async fn get_many_records_per_partition(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        partition_by: &ModelProjection,
+        selected_fields: &ModelProjection,
+    ) -> connector::Result<Option<ManyRecords>> {
+        if !read::can_paginate_per_partition(&self.connection_info) {
+            return Ok(None);
+        }
+
+        self.catch(async move {
+            let records = read::get_many_records_per_partition(
+                &self.inner,
+                &self.commenter(),
+                model,
+                query_arguments,
+                partition_by,
+                selected_fields,
+            )
+            .await?;
+
+            transform_many_records(&self.field_transformers, model, records).map(Some)
+        })
+        .await
     }
 
     async fn get_related_m2m_record_ids(
@@ -74,8 +193,10 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         from_field: &RelationFieldRef,
         from_record_ids: &[RecordProjection],
     ) -> connector::Result<Vec<(RecordProjection, RecordProjection)>> {
-        self.catch(async move { read::get_related_m2m_record_ids(&self.inner, from_field, from_record_ids).await })
-            .await
+        self.catch(async move {
+            read::get_related_m2m_record_ids(&self.inner, &self.commenter(), from_field, from_record_ids).await
+        })
+        .await
     }
 
     async fn aggregate_records(
@@ -84,16 +205,64 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         aggregators: Vec<Aggregator>,
         query_arguments: QueryArguments,
     ) -> connector::Result<Vec<AggregationResult>> {
-        self.catch(async move { read::aggregate(&self.inner, model, aggregators, query_arguments).await })
+        self.catch(
+            async move { read::aggregate(&self.inner, &self.commenter(), model, aggregators, query_arguments).await },
+        )
+        .await
+    }
+
+    async fn group_by_records(
+        &self,
+        model: &ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+    ) -> connector::Result<Vec<Vec<AggregationResult>>> {
+        self.catch(async move {
+            read::group_by(
+                &self.inner,
+                &self.commenter(),
+                model,
+                group_by,
+                aggregators,
+                query_arguments,
+            )
+            .await
+        })
+        .await
+    }
+
+    async fn count_records(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        estimate: bool,
+    ) -> connector::Result<usize> {
+        let sql_family = self.connection_info.sql_family();
+
+        self.catch(async move {
+            read::count(
+                &self.inner,
+                &self.commenter(),
+                sql_family,
+                model,
+                query_arguments,
+                estimate,
+            )
             .await
+        })
+        .await
     }
 }
 
 #[async_trait]
 impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
     async fn create_record(&self, model: &ModelRef, args: WriteArgs) -> connector::Result<RecordProjection> {
-        self.catch(async move { write::create_record(&self.inner, model, args).await })
-            .await
+        self.catch(async move {
+            let args = transform_write_args(&self.field_transformers, model, args)?;
+            write::create_record(&self.inner, &self.commenter(), model, args).await
+        })
+        .await
     }
 
     async fn update_records(
@@ -102,22 +271,46 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
         record_filter: RecordFilter,
         args: WriteArgs,
     ) -> connector::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.inner, model, record_filter, args).await })
-            .await
+        self.catch(async move {
+            let args = transform_write_args(&self.field_transformers, model, args)?;
+            write::update_records(&self.inner, &self.commenter(), model, record_filter, args).await
+        })
+        .await
     }
 
-    async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
-        self.catch(async move { write::delete_records(&self.inner, model, record_filter).await })
+    async fn delete_records(
+        &self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+    ) -> connector::Result<Vec<RecordProjection>> {
+        self.catch(async move { write::delete_records(&self.inner, &self.commenter(), model, record_filter).await })
             .await
     }
 
+    async fn upsert_records(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        conflict_target: &[ScalarFieldRef],
+    ) -> connector::Result<usize> {
+        self.catch(async move {
+            let args = args
+                .into_iter()
+                .map(|args| transform_write_args(&self.field_transformers, model, args))
+                .collect::<crate::Result<Vec<_>>>()?;
+
+            write::upsert_records(&self.inner, &self.commenter(), model, args, conflict_target).await
+        })
+        .await
+    }
+
     async fn connect(
         &self,
         field: &RelationFieldRef,
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::connect(&self.inner, field, parent_id, child_ids).await })
+        self.catch(async move { write::connect(&self.inner, &self.commenter(), field, parent_id, child_ids).await })
             .await
     }
 
@@ -127,7 +320,7 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::disconnect(&self.inner, field, parent_id, child_ids).await })
+        self.catch(async move { write::disconnect(&self.inner, &self.commenter(), field, parent_id, child_ids).await })
             .await
     }
 
@@ -137,7 +330,7 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
     }
 
     async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<serde_json::Value> {
-        self.catch(async move { write::query_raw(&self.inner, query, parameters).await })
+        self.catch(async move { write::query_raw(&self.inner, query, parameters, self.raw_strictness).await })
             .await
     }
 }
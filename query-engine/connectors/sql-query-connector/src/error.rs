@@ -1,7 +1,7 @@
 use connector_interface::{error::*, Filter};
 use prisma_models::prelude::DomainError;
 use quaint::error::ErrorKind as QuaintKind;
-use std::{any::Any, string::FromUtf8Error};
+use std::{any::Any, string::FromUtf8Error, time::Duration};
 use thiserror::Error;
 use user_facing_errors::query_engine::DatabaseConstraint;
 
@@ -107,6 +107,20 @@ pub enum SqlError {
 
     #[error("Database error. error code: {}, error message: {}", code, message)]
     RawError { code: String, message: String },
+
+    #[error(
+        "Timed out after {:?} waiting for one of the {} concurrent queries allowed.",
+        timeout,
+        limit
+    )]
+    ConcurrencyLimitReached { limit: usize, timeout: Duration },
+
+    #[error("Transforming the value of the field `{}.{}` failed: {}", model, field, cause)]
+    FieldTransformationFailed {
+        model: String,
+        field: String,
+        cause: Box<dyn std::error::Error + Send + Sync>,
+    },
 }
 
 impl SqlError {
@@ -159,6 +173,12 @@ impl SqlError {
                 child_name,
             }),
             SqlError::ConversionError(e) => ConnectorError::from_kind(ErrorKind::ConversionError(e)),
+            SqlError::ConcurrencyLimitReached { limit, timeout } => {
+                ConnectorError::from_kind(ErrorKind::ConcurrencyLimitReached { limit, timeout })
+            }
+            SqlError::FieldTransformationFailed { model, field, cause } => {
+                ConnectorError::from_kind(ErrorKind::FieldTransformationFailed { model, field, cause })
+            }
             SqlError::QueryError(e) => {
                 let quaint_error: Option<&QuaintKind> = e.downcast_ref();
                 match quaint_error {
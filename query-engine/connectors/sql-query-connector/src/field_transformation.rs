@@ -0,0 +1,126 @@
+//! Applies the field transformers of the engine configuration to the values the connector writes and
+//! reads, e.g. to encrypt them at rest. The database only ever sees the transformed values.
+
+use crate::SqlError;
+use connector_interface::{FieldTransformer, FieldTransformers, WriteArgs, WriteExpression};
+use prisma_models::prelude::*;
+use std::sync::Arc;
+
+/// Transforms the values written to the fields of the model.
+pub(crate) fn transform_write_args(
+    transformers: &FieldTransformers,
+    model: &ModelRef,
+    mut args: WriteArgs,
+) -> crate::Result<WriteArgs> {
+    if !transformers.applies_to_model(&model.name) {
+        return Ok(args);
+    }
+
+    for (db_name, expression) in args.args.iter_mut() {
+        let (field, transformer) = match transformer_for_db_name(transformers, model, db_name) {
+            Some(found) => found,
+            None => continue,
+        };
+
+        *expression = match std::mem::replace(expression, WriteExpression::Value(PrismaValue::Null)) {
+            WriteExpression::Value(PrismaValue::Null) => WriteExpression::Value(PrismaValue::Null),
+            WriteExpression::Value(value) => WriteExpression::Value(
+                transformer
+                    .on_write(value)
+                    .map_err(|cause| transformation_failed(model, &field, cause))?,
+            ),
+            _ => {
+                return Err(transformation_failed(
+                    model,
+                    &field,
+                    "The stored value is transformed, it can only be set.".into(),
+                ))
+            }
+        };
+    }
+
+    Ok(args)
+}
+
+/// Transforms the values read from the fields of the model back.
+pub(crate) fn transform_single_record(
+    transformers: &FieldTransformers,
+    model: &ModelRef,
+    record: Option<SingleRecord>,
+) -> crate::Result<Option<SingleRecord>> {
+    match record {
+        Some(mut record) => {
+            transform_record(transformers, model, &record.field_names, &mut record.record)?;
+            Ok(Some(record))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Transforms the values read from the fields of the model back.
+pub(crate) fn transform_many_records(
+    transformers: &FieldTransformers,
+    model: &ModelRef,
+    mut records: ManyRecords,
+) -> crate::Result<ManyRecords> {
+    for record in records.records.iter_mut() {
+        transform_record(transformers, model, &records.field_names, record)?;
+    }
+
+    Ok(records)
+}
+
+fn transform_record(
+    transformers: &FieldTransformers,
+    model: &ModelRef,
+    field_names: &[String],
+    record: &mut Record,
+) -> crate::Result<()> {
+    if !transformers.applies_to_model(&model.name) {
+        return Ok(());
+    }
+
+    for (db_name, value) in field_names.iter().zip(record.values.iter_mut()) {
+        let (field, transformer) = match transformer_for_db_name(transformers, model, db_name) {
+            Some(found) => found,
+            None => continue,
+        };
+
+        if *value == PrismaValue::Null {
+            continue;
+        }
+
+        let stored = std::mem::replace(value, PrismaValue::Null);
+
+        *value = transformer
+            .on_read(stored)
+            .map_err(|cause| transformation_failed(model, &field, cause))?;
+    }
+
+    Ok(())
+}
+
+/// The records and write arguments of the connector are keyed by database names, the configuration
+/// by field names.
+fn transformer_for_db_name<'a>(
+    transformers: &'a FieldTransformers,
+    model: &ModelRef,
+    db_name: &str,
+) -> Option<(ScalarFieldRef, &'a Arc<dyn FieldTransformer>)> {
+    let field = model.fields().scalar().into_iter().find(|f| f.db_name() == db_name)?;
+    let transformer = transformers.get(&model.name, &field.name)?;
+
+    Some((field, transformer))
+}
+
+fn transformation_failed(
+    model: &ModelRef,
+    field: &ScalarFieldRef,
+    cause: Box<dyn std::error::Error + Send + Sync>,
+) -> SqlError {
+    SqlError::FieldTransformationFailed {
+        model: model.name.clone(),
+        field: field.name.clone(),
+        cause,
+    }
+}
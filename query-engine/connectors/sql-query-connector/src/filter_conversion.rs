@@ -1,4 +1,6 @@
+use crate::query_builder::read::related_records_count;
 use connector_interface::filter::*;
+use datamodel_connector::ConnectorCapability;
 use prisma_models::prelude::*;
 use quaint::ast::*;
 
@@ -116,8 +118,10 @@ impl AliasedCondition for Filter {
                 }
             },
             Filter::Scalar(filter) => filter.aliased_cond(alias),
+            Filter::ScalarList(filter) => filter.aliased_cond(alias),
             Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias),
             Filter::Relation(filter) => filter.aliased_cond(alias),
+            Filter::RelationCount(filter) => filter.aliased_cond(alias),
             Filter::BoolFilter(b) => {
                 if b {
                     ConditionTree::NoCondition
@@ -169,6 +173,30 @@ impl AliasedCondition for ScalarFilter {
     }
 }
 
+impl AliasedCondition for ScalarListFilter {
+    /// Conversion from a `ScalarListFilter` to a query condition tree, with the array operators of Postgres: `@>`
+    /// contains all the elements of the other array, `&&` has elements in common with it. Aliased when in a nested
+    /// `SELECT`.
+    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+        let column = match alias {
+            Some(alias) => self.field.as_column().table(alias.to_string(None)),
+            None => self.field.as_column(),
+        };
+
+        let field = self.field;
+        let array = |values: Vec<PrismaValue>| field.value(PrismaValue::List(values));
+
+        let condition: Expression<'static> = match self.condition {
+            ScalarListCondition::Contains(value) => column.compare_raw("@>", array(vec![value])).into(),
+            ScalarListCondition::ContainsEvery(values) => column.compare_raw("@>", array(values)).into(),
+            ScalarListCondition::ContainsSome(values) => column.compare_raw("&&", array(values)).into(),
+            ScalarListCondition::ContainsNone => column.equals(array(Vec::new())).into(),
+        };
+
+        ConditionTree::single(condition)
+    }
+}
+
 impl AliasedCondition for RelationFilter {
     /// Conversion from a `RelationFilter` to a query condition tree. Aliased when in a nested `SELECT`.
     fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
@@ -295,8 +323,41 @@ impl AliasedCondition for OneRelationIsNullFilter {
     }
 }
 
+impl AliasedCondition for RelationCountFilter {
+    /// Conversion from a `RelationCountFilter` to a query condition tree, comparing a correlated subquery counting the
+    /// related records. Aliased when in a nested `SELECT`.
+    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+        let parent_alias = alias.map(|a| a.to_string(None));
+        let count: Expression<'static> = related_records_count(&self.field, parent_alias.as_deref()).into();
+
+        let condition = match self.condition {
+            ScalarCondition::Equals(value) => count.equals(count_value(value)),
+            ScalarCondition::NotEquals(value) => count.not_equals(count_value(value)),
+            ScalarCondition::LessThan(value) => count.less_than(count_value(value)),
+            ScalarCondition::LessThanOrEquals(value) => count.less_than_or_equals(count_value(value)),
+            ScalarCondition::GreaterThan(value) => count.greater_than(count_value(value)),
+            ScalarCondition::GreaterThanOrEquals(value) => count.greater_than_or_equals(count_value(value)),
+            ScalarCondition::In(values) => count.in_selection(values.into_iter().map(count_value).collect::<Vec<_>>()),
+            ScalarCondition::NotIn(values) => {
+                count.not_in_selection(values.into_iter().map(count_value).collect::<Vec<_>>())
+            }
+            condition => unreachable!("Invalid relation count condition: {:?}", condition), // Validation guarantees this.
+        };
+
+        ConditionTree::single(condition)
+    }
+}
+
+/// Relation counts are only compared with integers, as the query schema guarantees.
+fn count_value(value: PrismaValue) -> Value<'static> {
+    match value {
+        PrismaValue::Int(i) => Value::from(i),
+        value => unreachable!("Invalid relation count value: {:?}", value),
+    }
+}
+
 fn convert_scalar_filter(
-    comparable: impl Comparable<'static>,
+    comparable: impl Comparable<'static> + Into<Expression<'static>>,
     cond: ScalarCondition,
     mode: QueryMode,
     fields: &[ScalarFieldRef],
@@ -308,11 +369,14 @@ fn convert_scalar_filter(
 }
 
 fn default_scalar_filter(
-    comparable: impl Comparable<'static>,
+    comparable: impl Comparable<'static> + Into<Expression<'static>>,
     cond: ScalarCondition,
     fields: &[ScalarFieldRef],
 ) -> ConditionTree<'static> {
     let condition = match cond {
+        ScalarCondition::JsonCompare(condition) => return json_filter(comparable.into(), condition),
+        ScalarCondition::Search(value) => return search_filter(comparable.into(), value, fields),
+        ScalarCondition::NotSearch(value) => return search_filter(comparable.into(), value, fields).not(),
         ScalarCondition::Equals(PrismaValue::Null) => comparable.is_null(),
         ScalarCondition::NotEquals(PrismaValue::Null) => comparable.is_not_null(),
         ScalarCondition::Equals(value) => comparable.equals(convert_value(fields, value)),
@@ -359,7 +423,7 @@ fn default_scalar_filter(
 }
 
 fn insensitive_scalar_filter(
-    comparable: impl Comparable<'static>,
+    comparable: impl Comparable<'static> + Into<Expression<'static>>,
     cond: ScalarCondition,
     fields: &[ScalarFieldRef],
 ) -> ConditionTree<'static> {
@@ -368,6 +432,9 @@ fn insensitive_scalar_filter(
     let condition = match cond {
         ScalarCondition::Equals(PrismaValue::Null) => comparable.is_null(),
         ScalarCondition::NotEquals(PrismaValue::Null) => comparable.is_not_null(),
+        ScalarCondition::JsonCompare(_) => unreachable!(), // Json fields have no query mode.
+        ScalarCondition::Search(value) => return search_filter(comparable.into(), value, fields),
+        ScalarCondition::NotSearch(value) => return search_filter(comparable.into(), value, fields).not(),
         ScalarCondition::Equals(value) => comparable.equals(lower(convert_value(fields, value))),
         ScalarCondition::NotEquals(value) => comparable.not_equals(convert_value(fields, value)),
         ScalarCondition::Contains(value) => comparable.compare_raw("ILIKE", format!("%{}%", value)),
@@ -429,6 +496,110 @@ fn insensitive_scalar_filter(
     ConditionTree::single(condition)
 }
 
+/// Quaint has no JSON functions: the path operators and functions are rendered as raw comparisons. Array paths
+/// are the ones of Postgres, and JSON path strings the ones of MySQL.
+fn json_filter(column: Expression<'static>, condition: JsonCondition) -> ConditionTree<'static> {
+    let condition = match condition.path {
+        JsonFilterPath::Array(path) => postgres_json_filter(column, path, condition.operation),
+        JsonFilterPath::String(path) => mysql_json_filter(column, path, condition.operation),
+    };
+
+    ConditionTree::single(condition)
+}
+
+/// `#>` extracts the value at the path as `jsonb`, `#>>` as text.
+fn postgres_json_filter(
+    column: Expression<'static>,
+    path: Vec<String>,
+    operation: JsonOperation,
+) -> Expression<'static> {
+    let path = Value::Array(Some(path.into_iter().map(Value::from).collect()));
+    let at_path = |operator: &'static str| -> Expression<'static> { column.compare_raw(operator, path).into() };
+
+    match operation {
+        JsonOperation::Equals(value) => at_path("#>").equals(Value::Json(Some(json_value(value)))).into(),
+        JsonOperation::NotEquals(value) => at_path("#>").not_equals(Value::Json(Some(json_value(value)))).into(),
+        JsonOperation::StringContains(value) => at_path("#>>").like(format!("{}", value)).into(),
+        JsonOperation::StringStartsWith(value) => at_path("#>>").begins_with(format!("{}", value)).into(),
+        JsonOperation::StringEndsWith(value) => at_path("#>>").ends_into(format!("{}", value)).into(),
+        JsonOperation::ArrayContains(value) => {
+            let elements = match json_value(value) {
+                array @ serde_json::Value::Array(_) => array,
+                element => serde_json::Value::Array(vec![element]),
+            };
+
+            at_path("#>").compare_raw("@>", Value::Json(Some(elements))).into()
+        }
+    }
+}
+
+/// `->` extracts the value at the path as JSON, `->>` as text. Their path can only be a literal.
+fn mysql_json_filter(column: Expression<'static>, path: String, operation: JsonOperation) -> Expression<'static> {
+    let path_literal = format!("'{}'", path.replace('\\', "\\\\").replace('\'', "''"));
+
+    // `JSON_EXTRACT(?, '$')` parses the parameter as a JSON document.
+    let json_document =
+        |value: PrismaValue| Row::from(vec![Value::from(json_value(value).to_string()), Value::from("$")]);
+
+    match operation {
+        JsonOperation::Equals(value) => column
+            .compare_raw(format!("-> {} = JSON_EXTRACT", path_literal), json_document(value))
+            .into(),
+        JsonOperation::NotEquals(value) => column
+            .compare_raw(format!("-> {} <> JSON_EXTRACT", path_literal), json_document(value))
+            .into(),
+        JsonOperation::StringContains(value) => column
+            .compare_raw(format!("->> {} LIKE", path_literal), format!("%{}%", value))
+            .into(),
+        JsonOperation::StringStartsWith(value) => column
+            .compare_raw(format!("->> {} LIKE", path_literal), format!("{}%", value))
+            .into(),
+        JsonOperation::StringEndsWith(value) => column
+            .compare_raw(format!("->> {} LIKE", path_literal), format!("%{}", value))
+            .into(),
+        JsonOperation::ArrayContains(value) => {
+            let arguments: Vec<Expression<'static>> = vec![
+                column,
+                Value::from(json_value(value).to_string()).into(),
+                Value::from(path).into(),
+            ];
+
+            Expression::from(Value::from(1i64))
+                .compare_raw("= JSON_CONTAINS", Row::from(arguments))
+                .into()
+        }
+    }
+}
+
+/// Quaint has no full-text search functions either. Postgres matches the text of the column, converted with
+/// `to_tsvector` by the `text @@ tsquery` operator, against the query parsed by `to_tsquery`. MySQL matches
+/// in natural language mode, which requires a full-text index on the column.
+fn search_filter(column: Expression<'static>, query: PrismaValue, fields: &[ScalarFieldRef]) -> ConditionTree<'static> {
+    let query = Row::from(vec![Value::from(format!("{}", query))]);
+    let capabilities = &fields.first().unwrap().internal_data_model().capabilities;
+
+    let condition: Expression<'static> = if capabilities.contains(ConnectorCapability::FullTextSearchWithIndex) {
+        let matched: Expression<'static> = Expression::from(Value::from(0i64))
+            .compare_raw("< MATCH", Row::from(vec![column]))
+            .into();
+
+        matched.compare_raw("AGAINST", query).into()
+    } else {
+        column.compare_raw("@@ to_tsquery", query).into()
+    };
+
+    ConditionTree::single(condition)
+}
+
+/// The values of Json filters are JSON documents, or `null` for the JSON `null`.
+fn json_value(value: PrismaValue) -> serde_json::Value {
+    match value {
+        PrismaValue::Json(json) => serde_json::from_str(&json).unwrap_or(serde_json::Value::String(json)),
+        PrismaValue::String(s) => serde_json::Value::String(s),
+        _ => serde_json::Value::Null,
+    }
+}
+
 fn convert_value<'a>(fields: &[ScalarFieldRef], value: PrismaValue) -> Value<'a> {
     fields.first().unwrap().value(value)
 }
@@ -1,18 +1,32 @@
+mod concurrency_limiter;
 mod cursor_condition;
 mod database;
 mod error;
+mod field_transformation;
 mod filter_conversion;
 mod ordering;
+mod pool_config;
 mod query_arguments_ext;
 mod query_builder;
 mod query_ext;
+mod raw_values;
+mod read_replicas;
 mod row;
+mod search_path;
+mod sql_commenter;
+mod tenants;
 
+use concurrency_limiter::{ConcurrencyLimiter, ConcurrencyPermit};
 use filter_conversion::*;
 use query_ext::QueryExt;
+use raw_values::RawStrictness;
 use row::*;
+use search_path::SearchPath;
+use sql_commenter::SqlCommenter;
+use tenants::Tenants;
 
 pub use database::*;
 pub use error::SqlError;
+pub use sql_commenter::{enable_query_logging, QUERY_LOG_TARGET};
 
 type Result<T> = std::result::Result<T, error::SqlError>;
@@ -1,20 +1,92 @@
-use crate::query_arguments_ext::QueryArgumentsExt;
+use crate::{query_arguments_ext::QueryArgumentsExt, query_builder::read::related_records_count};
 use connector_interface::QueryArguments;
 use prisma_models::*;
 use quaint::ast::*;
 
+const ORDER_TABLE_ALIAS: &str = "orderby";
+
 /// Builds all expressions for an `ORDER BY` clause based on the query arguments.
 pub fn build(query_arguments: &QueryArguments) -> Vec<OrderDefinition<'static>> {
     let needs_reversed_order = query_arguments.needs_reversed_order();
 
     query_arguments.order_by.iter().fold(vec![], |mut acc, next_order_by| {
+        let expression = order_expression(next_order_by);
+
         match (next_order_by.sort_order, needs_reversed_order) {
-            (SortOrder::Ascending, true) => acc.push(next_order_by.field.as_column().descend()),
-            (SortOrder::Descending, true) => acc.push(next_order_by.field.as_column().ascend()),
-            (SortOrder::Ascending, false) => acc.push(next_order_by.field.as_column().ascend()),
-            (SortOrder::Descending, false) => acc.push(next_order_by.field.as_column().descend()),
+            (SortOrder::Ascending, true) => acc.push(expression.descend()),
+            (SortOrder::Descending, true) => acc.push(expression.ascend()),
+            (SortOrder::Ascending, false) => acc.push(expression.ascend()),
+            (SortOrder::Descending, false) => acc.push(expression.descend()),
         }
 
         acc
     })
 }
+
+/// The value the records are ordered by. Fields of the model itself are read from their column. Related records are
+/// read with a correlated subquery per to-one relation on the path, and relation counts are counted in the last one.
+/// For posts ordered by the name of the company of their author:
+/// ```sql
+/// ORDER BY
+///     (
+///         SELECT
+///             (
+///                 SELECT `orderby_1`.`name`
+///                 FROM `Company` AS `orderby_1`
+///                 WHERE `orderby_1`.`id` = `orderby_0`.`companyId`
+///             )
+///         FROM `User` AS `orderby_0`
+///         WHERE `orderby_0`.`id` = `Post`.`authorId`
+///     ) ASC
+/// ```
+///
+/// Records without a related record get a `NULL` value.
+pub(crate) fn order_expression(order_by: &OrderBy) -> Expression<'static> {
+    let alias = |depth: usize| format!("{}_{}", ORDER_TABLE_ALIAS, depth);
+    let to_one_len = match order_by.sort_aggregation {
+        Some(_) => order_by.path.len() - 1,
+        None => order_by.path.len(),
+    };
+
+    let value: Expression<'static> = match order_by.sort_aggregation {
+        Some(SortAggregation::Count) => {
+            let to_many = order_by.path.last().unwrap();
+            let parent_alias = to_one_len.checked_sub(1).map(alias);
+
+            related_records_count(to_many, parent_alias.as_deref()).into()
+        }
+        None if to_one_len == 0 => order_by.field.as_column().into(),
+        None => order_by.field.as_column().table(alias(to_one_len - 1)).into(),
+    };
+
+    order_by.path[..to_one_len]
+        .iter()
+        .enumerate()
+        .rev()
+        .fold(value, |value, (depth, field)| {
+            let parent_alias = depth.checked_sub(1).map(alias);
+
+            let conditions = field
+                .related_field()
+                .linking_fields()
+                .as_columns()
+                .zip(field.linking_fields().as_columns())
+                .fold(ConditionTree::NoCondition, |acc, (related, parent)| {
+                    let parent = match parent_alias {
+                        Some(ref parent_alias) => parent.table(parent_alias.clone()),
+                        None => parent,
+                    };
+                    let linked = related.table(alias(depth)).equals(parent);
+
+                    match acc {
+                        ConditionTree::NoCondition => linked.into(),
+                        cond => cond.and(linked),
+                    }
+                });
+
+            Select::from_table(field.related_model().as_table().alias(alias(depth)))
+                .value(value)
+                .so_that(conditions)
+                .into()
+        })
+}
@@ -0,0 +1,142 @@
+//! The configuration of the connection pools, from the connection string parameters:
+//!
+//! - `connection_limit`: the maximum number of open connections.
+//! - `connect_timeout`: how long, in seconds, opening a connection may take.
+//! - `pool_timeout`: how long, in seconds, a query waits for a free connection.
+//! - `max_idle_connection_lifetime`: how long, in seconds, a connection stays open while idle.
+//!   Defaults to 300 seconds.
+//! - `max_connection_lifetime`: how long, in seconds, a connection stays open at most.
+//!
+//! The values set by the engine, e.g. with command line flags, take precedence.
+
+use crate::concurrency_limiter::url_params;
+use connector_interface::PoolConfig;
+use quaint::pooled::Builder;
+use std::time::Duration;
+
+const CONNECTION_LIMIT_PARAM: &str = "connection_limit";
+const CONNECT_TIMEOUT_PARAM: &str = "connect_timeout";
+const POOL_TIMEOUT_PARAM: &str = "pool_timeout";
+const MAX_IDLE_LIFETIME_PARAM: &str = "max_idle_connection_lifetime";
+const MAX_LIFETIME_PARAM: &str = "max_connection_lifetime";
+
+/// How long a connection stays open while idle when `max_idle_connection_lifetime` is not set.
+const DEFAULT_MAX_IDLE_LIFETIME: Duration = Duration::from_secs(300);
+
+/// The configuration of the pool of the connection string, with the values of `overrides` replacing
+/// the ones of the connection string.
+pub(crate) fn pool_config(url: &str, overrides: PoolConfig) -> anyhow::Result<PoolConfig> {
+    let defaults = PoolConfig {
+        max_idle_lifetime: Some(DEFAULT_MAX_IDLE_LIFETIME),
+        ..Default::default()
+    };
+
+    Ok(defaults
+        .overridden_by(pool_config_from_url(url)?)
+        .overridden_by(overrides))
+}
+
+fn pool_config_from_url(url: &str) -> anyhow::Result<PoolConfig> {
+    let mut config = PoolConfig::default();
+
+    for (key, value) in url_params(url) {
+        match key {
+            CONNECTION_LIMIT_PARAM => {
+                let limit: usize = parse_param(key, value)?;
+
+                if limit == 0 {
+                    anyhow::bail!("`{}` must be at least 1.", CONNECTION_LIMIT_PARAM);
+                }
+
+                config.connection_limit = Some(limit);
+            }
+            CONNECT_TIMEOUT_PARAM => config.connect_timeout = Some(Duration::from_secs(parse_param(key, value)?)),
+            POOL_TIMEOUT_PARAM => config.pool_timeout = Some(Duration::from_secs(parse_param(key, value)?)),
+            MAX_IDLE_LIFETIME_PARAM => config.max_idle_lifetime = Some(Duration::from_secs(parse_param(key, value)?)),
+            MAX_LIFETIME_PARAM => config.max_lifetime = Some(Duration::from_secs(parse_param(key, value)?)),
+            _ => (),
+        }
+    }
+
+    Ok(config)
+}
+
+fn parse_param<T: std::str::FromStr>(key: &str, value: &str) -> anyhow::Result<T> {
+    value
+        .parse()
+        .map_err(|_| anyhow::anyhow!("Invalid value for `{}`: `{}`.", key, value))
+}
+
+/// Applies the configuration to the pool under construction.
+pub(crate) fn configure_pool(builder: &mut Builder, config: &PoolConfig) {
+    if let Some(limit) = config.connection_limit {
+        builder.connection_limit(limit);
+    }
+
+    if let Some(timeout) = config.connect_timeout {
+        builder.connect_timeout(timeout);
+    }
+
+    if let Some(timeout) = config.pool_timeout {
+        builder.pool_timeout(timeout);
+    }
+
+    if let Some(lifetime) = config.max_idle_lifetime {
+        builder.max_idle_lifetime(lifetime);
+    }
+
+    if let Some(lifetime) = config.max_lifetime {
+        builder.max_lifetime(lifetime);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_pool_is_configured_from_the_query_string() {
+        let config = pool_config(
+            "postgresql://localhost:5432/db?schema=s&connection_limit=4&pool_timeout=5&max_connection_lifetime=600",
+            PoolConfig::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            PoolConfig {
+                connection_limit: Some(4),
+                connect_timeout: None,
+                pool_timeout: Some(Duration::from_secs(5)),
+                max_idle_lifetime: Some(DEFAULT_MAX_IDLE_LIFETIME),
+                max_lifetime: Some(Duration::from_secs(600)),
+            }
+        );
+    }
+
+    #[test]
+    fn overrides_take_precedence_over_the_connection_string() {
+        let overrides = PoolConfig {
+            connection_limit: Some(1),
+            max_idle_lifetime: Some(Duration::from_secs(30)),
+            ..Default::default()
+        };
+
+        let config = pool_config(
+            "sqlserver://localhost:1433;database=master;connection_limit=10;connect_timeout=3",
+            overrides,
+        )
+        .unwrap();
+
+        assert_eq!(config.connection_limit, Some(1));
+        assert_eq!(config.connect_timeout, Some(Duration::from_secs(3)));
+        assert_eq!(config.max_idle_lifetime, Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn invalid_values_are_rejected() {
+        assert!(pool_config("mysql://localhost/db?connection_limit=0", PoolConfig::default()).is_err());
+        assert!(pool_config("mysql://localhost/db?pool_timeout=soon", PoolConfig::default()).is_err());
+        assert!(pool_config("mysql://localhost/db?max_connection_lifetime=-1", PoolConfig::default()).is_err());
+    }
+}
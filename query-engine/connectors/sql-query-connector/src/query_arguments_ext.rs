@@ -3,10 +3,34 @@ use connector_interface::QueryArguments;
 pub trait QueryArgumentsExt {
     /// If we need to take rows before a cursor position, then we need to reverse the order in SQL.
     fn needs_reversed_order(&self) -> bool;
+
+    /// With a stable ordering, the cursor row is the only one equal to the cursor in all the ordered fields. Skipping
+    /// it is then part of the cursor condition, instead of an `OFFSET`.
+    fn skips_cursor_row(&self) -> bool;
+
+    /// The number of rows to skip with an `OFFSET`.
+    fn offset(&self) -> usize;
 }
 
 impl QueryArgumentsExt for QueryArguments {
     fn needs_reversed_order(&self) -> bool {
         self.take.map(|t| t < 0).unwrap_or(false)
     }
+
+    fn skips_cursor_row(&self) -> bool {
+        self.cursor.is_some()
+            && !self.ignore_skip
+            && self.skip.unwrap_or(0) > 0
+            && self.is_stable_ordering()
+            && !self.contains_null_cursor()
+    }
+
+    fn offset(&self) -> usize {
+        match self.skip {
+            _ if self.ignore_skip => 0,
+            Some(skip) if self.skips_cursor_row() => (skip - 1) as usize,
+            Some(skip) => skip as usize,
+            None => 0,
+        }
+    }
 }
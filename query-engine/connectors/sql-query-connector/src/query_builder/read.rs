@@ -1,4 +1,4 @@
-use crate::{cursor_condition, filter_conversion::AliasedCondition, ordering};
+use crate::{cursor_condition, filter_conversion::AliasedCondition, ordering, query_arguments_ext::QueryArgumentsExt};
 use connector_interface::{filter::Filter, Aggregator, QueryArguments};
 use itertools::Itertools;
 use prisma_models::*;
@@ -33,22 +33,27 @@ impl SelectDefinition for QueryArguments {
         let orderings = ordering::build(&self);
 
         let limit = if self.ignore_take { None } else { self.take_abs() };
-        let skip = if self.ignore_skip { 0 } else { self.skip.unwrap_or(0) };
+        let offset = self.offset();
+        let distinct_condition = distinct_condition(&self, model);
 
         let filter: ConditionTree = self
             .filter
             .map(|f| f.aliased_cond(None))
             .unwrap_or(ConditionTree::NoCondition);
 
+        let filter = match (filter, distinct_condition) {
+            (filter, None) => filter,
+            (ConditionTree::NoCondition, Some(distinct)) => distinct,
+            (filter, Some(distinct)) => ConditionTree::and(filter, distinct),
+        };
+
         let conditions = match (filter, cursor_condition) {
             (ConditionTree::NoCondition, cursor) => cursor,
             (filter, ConditionTree::NoCondition) => filter,
             (filter, cursor) => ConditionTree::and(filter, cursor),
         };
 
-        let select_ast = Select::from_table(model.as_table())
-            .so_that(conditions)
-            .offset(skip as usize);
+        let select_ast = Select::from_table(model.as_table()).so_that(conditions).offset(offset);
 
         let select_ast = if let Some(table) = table_opt {
             select_ast.and_from(table)
@@ -65,6 +70,50 @@ impl SelectDefinition for QueryArguments {
     }
 }
 
+/// Deduplicates the records on the `distinct` fields by keeping the record with the lowest primary identifier of
+/// every distinct group, or the highest one if the records are read in descending order:
+/// ```sql
+/// "Table"."id" IN (SELECT MIN("Table"."id") FROM "Table" WHERE ... GROUP BY "Table"."a", "Table"."b")
+/// ```
+///
+/// Returns `None` if the records have no `distinct` or the core has to deduplicate them in memory.
+fn distinct_condition(args: &QueryArguments, model: &ModelRef) -> Option<ConditionTree<'static>> {
+    if !args.can_distinct_in_datasource() {
+        return None;
+    }
+
+    let distinct = args.distinct.as_ref()?;
+    let id_column = model.primary_identifier().scalar_fields().next()?.as_column();
+
+    let descending = args
+        .order_by
+        .first()
+        .map(|order_by| order_by.sort_order == SortOrder::Descending)
+        .unwrap_or(false);
+
+    // Reading backwards from the end of the result reverses the order in SQL.
+    let first_in_group = if descending != args.needs_reversed_order() {
+        max(id_column.clone())
+    } else {
+        min(id_column.clone())
+    };
+
+    let filter = args
+        .filter
+        .clone()
+        .map(|f| f.aliased_cond(None))
+        .unwrap_or(ConditionTree::NoCondition);
+
+    let groups = distinct.as_columns().fold(
+        Select::from_table(model.as_table())
+            .value(first_in_group)
+            .so_that(filter),
+        |acc, col| acc.group_by(col),
+    );
+
+    Some(id_column.in_selection(groups).into())
+}
+
 pub fn get_records<T>(model: &ModelRef, columns: impl Iterator<Item = Column<'static>>, query: T) -> Select<'static>
 where
     T: SelectDefinition,
@@ -72,6 +121,144 @@ where
     columns.fold(query.into_select(model), |acc, col| acc.column(col))
 }
 
+const COUNT_TABLE_ALIAS: &str = "prisma_count";
+
+/// Adds the number of related records of the given to-many relation fields to the selection, as correlated
+/// subqueries. The related records are only counted, never read. For the posts of every user:
+/// ```sql
+/// SELECT
+///     `User`.`id`,
+///     (
+///         SELECT
+///             COUNT(*)
+///         FROM
+///             `Post` AS `prisma_count`
+///         WHERE
+///             `prisma_count`.`authorId` = `User`.`id`
+///     )
+/// FROM
+///     `User`;
+/// ```
+///
+/// Many-to-many relations count the rows of their join table instead.
+pub fn count_related_records(select: Select<'static>, relation_counts: &[RelationFieldRef]) -> Select<'static> {
+    relation_counts
+        .iter()
+        .fold(select, |acc, field| acc.value(related_records_count(field, None)))
+}
+
+/// Counts the related records of the records of the table aliased `parent_alias`, or of the table of the model if
+/// it is not aliased.
+pub(crate) fn related_records_count(field: &RelationFieldRef, parent_alias: Option<&str>) -> Select<'static> {
+    // The related model may be the model itself, the counted table is aliased to tell them apart.
+    let (table, related_columns) = if field.relation().is_many_to_many() {
+        (field.relation().as_table(), field.related_field().m2m_columns())
+    } else {
+        let columns = field.related_field().linking_fields().as_columns().collect();
+        (field.related_model().as_table(), columns)
+    };
+
+    let conditions = related_columns
+        .into_iter()
+        .zip(field.linking_fields().as_columns())
+        .fold(ConditionTree::NoCondition, |acc, (related, parent)| {
+            let parent = match parent_alias {
+                Some(alias) => parent.table(alias.to_owned()),
+                None => parent,
+            };
+            let linked = related.table(COUNT_TABLE_ALIAS).equals(parent);
+
+            match acc {
+                ConditionTree::NoCondition => linked.into(),
+                cond => cond.and(linked),
+            }
+        });
+
+    Select::from_table(table.alias(COUNT_TABLE_ALIAS))
+        .value(count(asterisk()))
+        .so_that(conditions)
+}
+
+const PARTITION_TABLE_ALIAS: &str = "partitioned";
+const ROW_NUMBER_ALIAS: &str = "prisma_row_number";
+
+/// Generates a query paginating every partition of the records on its own, numbering the records of every partition
+/// in their order. For the second and third comment of every post:
+/// ```sql
+/// SELECT
+///     `partitioned`.`id`,
+///     `partitioned`.`postId`
+/// FROM
+///     (
+///         SELECT
+///             `Comment`.`id`,
+///             `Comment`.`postId`,
+///             ROW_NUMBER() OVER (PARTITION BY `Comment`.`postId` ORDER BY `Comment`.`id` ASC) AS `prisma_row_number`
+///         FROM
+///             `Comment`
+///         WHERE
+///             `Comment`.`postId` IN (...)
+///     ) AS `partitioned`
+/// WHERE
+///     `partitioned`.`prisma_row_number` > 1
+///     AND `partitioned`.`prisma_row_number` <= 3
+/// ORDER BY
+///     `partitioned`.`postId` ASC,
+///     `partitioned`.`prisma_row_number` ASC;
+/// ```
+///
+/// Records are ordered by the primary identifier after the ordering of the query arguments, unless that one is stable.
+pub fn get_records_per_partition(
+    model: &ModelRef,
+    selected_fields: &ModelProjection,
+    partition_by: &ModelProjection,
+    args: QueryArguments,
+) -> Select<'static> {
+    let mut orderings = ordering::build(&args);
+
+    if !args.is_stable_ordering() {
+        orderings.extend(model.primary_identifier().as_columns().map(|col| col.ascend()));
+    }
+
+    let numbering = orderings.into_iter().fold(row_number(), |acc, ord| acc.order_by(ord));
+    let numbering = partition_by
+        .as_columns()
+        .fold(numbering, |acc, col| acc.partition_by(col));
+
+    let filter = args
+        .filter
+        .map(|f| f.aliased_cond(None))
+        .unwrap_or(ConditionTree::NoCondition);
+
+    let numbered_records = selected_fields
+        .as_columns()
+        .fold(Select::from_table(model.as_table()).so_that(filter), |acc, col| {
+            acc.column(col)
+        })
+        .value(Function::from(numbering).alias(ROW_NUMBER_ALIAS));
+
+    let row_number_column = || Column::from((PARTITION_TABLE_ALIAS, ROW_NUMBER_ALIAS));
+    let skip = args.skip.unwrap_or(0);
+    let after_skip = row_number_column().greater_than(skip);
+
+    let conditions = match args.take {
+        Some(take) => ConditionTree::and(after_skip, row_number_column().less_than_or_equals(skip + take)),
+        None => after_skip.into(),
+    };
+
+    let select = selected_fields.db_names().fold(
+        Select::from_table(Table::from(numbered_records).alias(PARTITION_TABLE_ALIAS)).so_that(conditions),
+        |acc, name| acc.column(Column::from((PARTITION_TABLE_ALIAS, name))),
+    );
+
+    partition_by
+        .db_names()
+        .fold(select, |acc, name| {
+            acc.order_by(Column::from((PARTITION_TABLE_ALIAS, name)).ascend())
+        })
+        .order_by(row_number_column().ascend())
+}
+
 /// Generates a query of the form:
 /// ```sql
 /// SELECT
@@ -96,34 +283,93 @@ where
 ///             1 = 1
 ///     ) AS `sub`;
 /// ```
+///
+/// Without pagination or distinct, the subquery is not needed and the aggregations run on the table directly:
+/// `SELECT COUNT(*) FROM "Table" WHERE ...`.
 pub fn aggregate(model: &ModelRef, aggregators: &[Aggregator], args: QueryArguments) -> Select<'static> {
-    let columns = extract_columns(model, &aggregators);
-    let sub_query = get_records(model, columns.into_iter(), args);
-    let sub_table = Table::from(sub_query).alias("sub");
+    let needs_sub_query =
+        args.cursor.is_some() || args.take.is_some() || args.skip.is_some() || args.distinct.is_some();
 
-    aggregators
-        .into_iter()
-        .fold(Select::from_table(sub_table), |select, next_op| match next_op {
-            Aggregator::Count => select.value(count(asterisk())),
+    let aggregated_rows = if needs_sub_query {
+        let columns = extract_columns(model, &aggregators);
+        let sub_query = get_records(model, columns.into_iter(), args);
 
-            Aggregator::Average(fields) => fields.into_iter().fold(select, |select, next_field| {
-                select.value(avg(Column::from(next_field.db_name().to_owned())))
-            }),
+        Select::from_table(Table::from(sub_query).alias("sub"))
+    } else {
+        let filter = args
+            .filter
+            .map(|f| f.aliased_cond(None))
+            .unwrap_or(ConditionTree::NoCondition);
+
+        Select::from_table(model.as_table()).so_that(filter)
+    };
 
-            Aggregator::Sum(fields) => fields.into_iter().fold(select, |select, next_field| {
-                select.value(sum(Column::from(next_field.db_name().to_owned())))
-            }),
+    select_aggregations(aggregated_rows, aggregators)
+}
+
+/// Generates a query of the form:
+/// ```sql
+/// SELECT
+///     `Table`.`name`,
+///     COUNT(*),
+///     SUM(`int`)
+/// FROM
+///     `Table`
+/// WHERE
+///     1 = 1
+/// GROUP BY
+///     `Table`.`name`
+/// ORDER BY
+///     `Table`.`name` ASC;
+/// ```
+///
+/// The grouped fields come first in the selection, followed by the aggregations, in order.
+pub fn group_by_aggregate(
+    model: &ModelRef,
+    group_by: &[ScalarFieldRef],
+    aggregators: &[Aggregator],
+    args: QueryArguments,
+) -> Select<'static> {
+    let filter = args
+        .filter
+        .map(|f| f.aliased_cond(None))
+        .unwrap_or(ConditionTree::NoCondition);
 
-            Aggregator::Min(fields) => fields.into_iter().fold(select, |select, next_field| {
-                select.value(min(Column::from(next_field.db_name().to_owned())))
-            }),
+    let select = group_by
+        .iter()
+        .fold(Select::from_table(model.as_table()).so_that(filter), |select, field| {
+            select.column(field.as_column())
+        });
 
-            Aggregator::Max(fields) => fields.into_iter().fold(select, |select, next_field| {
-                select.value(max(Column::from(next_field.db_name().to_owned())))
-            }),
+    group_by
+        .iter()
+        .fold(select_aggregations(select, aggregators), |select, field| {
+            select.group_by(field.as_column()).order_by(field.as_column().ascend())
         })
 }
 
+fn select_aggregations(select: Select<'static>, aggregators: &[Aggregator]) -> Select<'static> {
+    aggregators.into_iter().fold(select, |select, next_op| match next_op {
+        Aggregator::Count => select.value(count(asterisk())),
+
+        Aggregator::Average(fields) => fields.into_iter().fold(select, |select, next_field| {
+            select.value(avg(Column::from(next_field.db_name().to_owned())))
+        }),
+
+        Aggregator::Sum(fields) => fields.into_iter().fold(select, |select, next_field| {
+            select.value(sum(Column::from(next_field.db_name().to_owned())))
+        }),
+
+        Aggregator::Min(fields) => fields.into_iter().fold(select, |select, next_field| {
+            select.value(min(Column::from(next_field.db_name().to_owned())))
+        }),
+
+        Aggregator::Max(fields) => fields.into_iter().fold(select, |select, next_field| {
+            select.value(max(Column::from(next_field.db_name().to_owned())))
+        }),
+    })
+}
+
 fn extract_columns(model: &ModelRef, aggregators: &[Aggregator]) -> Vec<Column<'static>> {
     let fields: Vec<_> = aggregators
         .iter()
@@ -1,7 +1,9 @@
+use crate::error::SqlError;
 use connector_interface::{DatasourceFieldName, WriteArgs, WriteExpression};
+use itertools::Itertools;
 use prisma_models::*;
-use quaint::ast::*;
-use std::convert::TryInto;
+use quaint::{ast::*, prelude::SqlFamily};
+use std::{collections::BTreeMap, convert::TryInto};
 
 /// `INSERT` a new record to the database. Resulting an `INSERT` ast and an
 /// optional `RecordProjection` if available from the arguments or model.
@@ -33,6 +35,195 @@ pub fn create_record(model: &ModelRef, mut args: WriteArgs) -> (Insert<'static>,
     )
 }
 
+/// `INSERT` the records, updating the existing records that conflict with them on the
+/// `conflict_target` fields. Quaint can't express the update on conflict, so every insert comes
+/// with the `OnConflictUpdate` to render after it.
+///
+/// The records are grouped by the fields they set, as a single insert has the same columns for all
+/// rows. Every group results in one statement, or more if it exceeds the parameter limit.
+///
+/// MySQL updates the record conflicting on any unique constraint, not only on the target. The
+/// upsert is rejected there if the records set all fields of another unique constraint.
+pub fn upsert_many(
+    model: &ModelRef,
+    args: Vec<WriteArgs>,
+    conflict_target: &[ScalarFieldRef],
+    sql_family: SqlFamily,
+) -> crate::Result<Vec<(Query<'static>, OnConflictUpdate)>> {
+    let mut groups: BTreeMap<Vec<String>, Vec<WriteArgs>> = BTreeMap::new();
+
+    for args in args {
+        let mut keys: Vec<String> = args.keys().map(|key| key.0.clone()).collect();
+        keys.sort();
+
+        groups.entry(keys).or_default().push(args);
+    }
+
+    let mut queries = Vec::new();
+
+    for (keys, records) in groups {
+        let fields: Vec<ScalarFieldRef> = model
+            .fields()
+            .scalar()
+            .into_iter()
+            .filter(|field| keys.iter().any(|key| key == field.db_name()))
+            .collect();
+
+        let on_conflict = OnConflictUpdate::new(model, &fields, conflict_target, sql_family)?;
+        let columns: Vec<_> = fields.iter().map(|field| field.db_name().to_owned()).collect();
+        let rows_per_query = std::cmp::max(1, super::PARAMETER_LIMIT / fields.len());
+
+        for chunk in records.chunks(rows_per_query) {
+            let insert = chunk
+                .iter()
+                .fold(Insert::multi_into(model.as_table(), columns.clone()), |insert, args| {
+                    let values: Vec<_> = fields
+                        .iter()
+                        .map(|field| {
+                            let value: PrismaValue = args
+                                .get_field_value(field.db_name())
+                                .unwrap()
+                                .clone()
+                                .try_into()
+                                .expect("Upsert calls can only use PrismaValue write expressions.");
+
+                            field.value(value)
+                        })
+                        .collect();
+
+                    insert.values(values)
+                });
+
+            queries.push((insert.build().into(), on_conflict.clone()));
+        }
+    }
+
+    Ok(queries)
+}
+
+/// The update of the records an insert conflicts with, set to the inserted values. Holds the column
+/// names, the identifiers are quoted when rendering the clause.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OnConflictUpdate {
+    /// `ON CONFLICT (target) DO UPDATE SET column = excluded.column`, or `DO NOTHING` without
+    /// columns to update.
+    OnConflict { target: Vec<String>, update: Vec<String> },
+    /// `ON DUPLICATE KEY UPDATE column = VALUES(column)`. MySQL requires at least one column.
+    OnDuplicateKey { update: Vec<String> },
+}
+
+impl OnConflictUpdate {
+    /// The conflict target fields and the creation timestamps keep their values.
+    fn new(
+        model: &ModelRef,
+        fields: &[ScalarFieldRef],
+        conflict_target: &[ScalarFieldRef],
+        sql_family: SqlFamily,
+    ) -> crate::Result<Self> {
+        let update: Vec<String> = fields
+            .iter()
+            .filter(|field| !field.is_created_at())
+            .filter(|field| !conflict_target.iter().any(|target| target.name == field.name))
+            .map(|field| field.db_name().to_owned())
+            .collect();
+
+        match sql_family {
+            SqlFamily::Postgres | SqlFamily::Sqlite => Ok(Self::OnConflict {
+                target: conflict_target.iter().map(|field| field.db_name().to_owned()).collect(),
+                update,
+            }),
+            SqlFamily::Mysql => {
+                let other_constraint = unique_criterias(model)
+                    .into_iter()
+                    .filter(|criteria| !same_fields(criteria, conflict_target))
+                    .find(|criteria| criteria.iter().all(|field| fields.iter().any(|f| f.name == field.name)));
+
+                if let Some(criteria) = other_constraint {
+                    return Err(SqlError::QueryError(
+                        format!(
+                            "Upserts on MySQL conflict on any unique constraint, and the records also set all fields of ({}).",
+                            criteria.iter().map(|field| field.name.as_str()).join(", ")
+                        )
+                        .into(),
+                    ));
+                }
+
+                let update = if update.is_empty() {
+                    vec![conflict_target[0].db_name().to_owned()]
+                } else {
+                    update
+                };
+
+                Ok(Self::OnDuplicateKey { update })
+            }
+            SqlFamily::Mssql => Err(SqlError::QueryError(
+                "Bulk upserts are not supported on SQL Server.".into(),
+            )),
+        }
+    }
+
+    /// Renders the clause to append to the insert, with the identifiers quoted by `quote`.
+    pub fn render<F>(&self, quote: F) -> quaint::Result<String>
+    where
+        F: Fn(&str) -> quaint::Result<String>,
+    {
+        match self {
+            Self::OnConflict { target, update } => {
+                let target = target
+                    .iter()
+                    .map(|column| quote(column))
+                    .collect::<quaint::Result<Vec<_>>>()?;
+
+                if update.is_empty() {
+                    return Ok(format!("ON CONFLICT ({}) DO NOTHING", target.join(", ")));
+                }
+
+                let assignments = update
+                    .iter()
+                    .map(|column| quote(column).map(|column| format!("{} = excluded.{}", column, column)))
+                    .collect::<quaint::Result<Vec<_>>>()?;
+
+                Ok(format!(
+                    "ON CONFLICT ({}) DO UPDATE SET {}",
+                    target.join(", "),
+                    assignments.join(", ")
+                ))
+            }
+            Self::OnDuplicateKey { update } => {
+                let assignments = update
+                    .iter()
+                    .map(|column| quote(column).map(|column| format!("{} = VALUES({})", column, column)))
+                    .collect::<quaint::Result<Vec<_>>>()?;
+
+                Ok(format!("ON DUPLICATE KEY UPDATE {}", assignments.join(", ")))
+            }
+        }
+    }
+}
+
+/// The field sets a record is unique by: the id, the unique fields and the unique indexes.
+fn unique_criterias(model: &ModelRef) -> Vec<Vec<ScalarFieldRef>> {
+    let singular = model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|field| field.unique())
+        .map(|field| vec![field]);
+
+    let compound = model
+        .fields()
+        .id()
+        .into_iter()
+        .chain(model.unique_indexes().into_iter().map(|index| index.fields()))
+        .filter(|fields| fields.len() > 1);
+
+    singular.chain(compound).collect()
+}
+
+fn same_fields(left: &[ScalarFieldRef], right: &[ScalarFieldRef]) -> bool {
+    left.len() == right.len() && left.iter().all(|field| right.iter().any(|f| f.name == field.name))
+}
+
 pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs) -> crate::Result<Vec<Query<'static>>> {
     if args.args.is_empty() || ids.is_empty() {
         return Ok(Vec::new());
@@ -72,6 +263,9 @@ pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs)
                     let e: Expression<'_> = Column::from(name.clone()).into();
                     e / field.value(rhs).into()
                 }
+
+                // Postgres concatenates arrays with `||`, quaint has no operator for it.
+                WriteExpression::Push(rhs) => Column::from(name.clone()).compare_raw("||", field.value(rhs)).into(),
             };
 
             acc.set(name, value)
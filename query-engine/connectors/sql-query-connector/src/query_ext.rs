@@ -1,4 +1,8 @@
-use crate::{error::*, AliasedCondition, SqlRow, ToSqlRow};
+use crate::{
+    error::*,
+    raw_values::{encode_raw_value, RawStrictness},
+    AliasedCondition, SqlCommenter, SqlRow, ToSqlRow,
+};
 use async_trait::async_trait;
 use connector_interface::{filter::Filter, RecordFilter};
 use datamodel::FieldArity;
@@ -21,8 +25,13 @@ impl QueryExt for PooledConnection {}
 #[async_trait]
 pub trait QueryExt: Queryable + Send + Sync {
     /// Filter and map the resulting types with the given identifiers.
-    async fn filter(&self, q: Query<'_>, idents: &[(TypeIdentifier, FieldArity)]) -> crate::Result<Vec<SqlRow>> {
-        let result_set = self.query(q).await?;
+    async fn filter(
+        &self,
+        q: Query<'_>,
+        idents: &[(TypeIdentifier, FieldArity)],
+        commenter: &SqlCommenter,
+    ) -> crate::Result<Vec<SqlRow>> {
+        let result_set = commenter.query(self, q).await?;
         let mut sql_rows = Vec::new();
 
         for row in result_set {
@@ -33,14 +42,20 @@ pub trait QueryExt: Queryable + Send + Sync {
     }
 
     /// Execute a singular SQL query in the database, returning an arbitrary
-    /// JSON `Value` as a result.
+    /// JSON `Value` as a result. The values are encoded as described in the
+    /// `raw_values` module.
     async fn raw_json<'a>(
         &'a self,
         q: String,
         params: Vec<PrismaValue>,
-    ) -> std::result::Result<Value, crate::error::RawError> {
+        strictness: RawStrictness,
+    ) -> crate::Result<Value> {
         let params: Vec<_> = params.into_iter().map(convert_lossy).collect();
-        let result_set = AssertUnwindSafe(self.query_raw(&q, &params)).catch_unwind().await??;
+        let result_set = AssertUnwindSafe(self.query_raw(&q, &params))
+            .catch_unwind()
+            .await
+            .map_err(RawError::from)?
+            .map_err(RawError::from)?;
 
         let columns: Vec<String> = result_set.columns().into_iter().map(ToString::to_string).collect();
         let mut result = Vec::new();
@@ -50,7 +65,8 @@ pub trait QueryExt: Queryable + Send + Sync {
 
             for (idx, p_value) in row.into_iter().enumerate() {
                 let column_name: String = columns[idx].clone();
-                object.insert(column_name, Value::from(p_value));
+                let value = encode_raw_value(&column_name, p_value, strictness)?;
+                object.insert(column_name, value);
             }
 
             result.push(Value::Object(object));
@@ -73,8 +89,13 @@ pub trait QueryExt: Queryable + Send + Sync {
     }
 
     /// Select one row from the database.
-    async fn find(&self, q: Select<'_>, idents: &[(TypeIdentifier, FieldArity)]) -> crate::Result<SqlRow> {
-        self.filter(q.limit(1).into(), idents)
+    async fn find(
+        &self,
+        q: Select<'_>,
+        idents: &[(TypeIdentifier, FieldArity)],
+        commenter: &SqlCommenter,
+    ) -> crate::Result<SqlRow> {
+        self.filter(q.limit(1).into(), idents, commenter)
             .await?
             .into_iter()
             .next()
@@ -82,10 +103,10 @@ pub trait QueryExt: Queryable + Send + Sync {
     }
 
     /// Read the first column from the first row as an integer.
-    async fn find_int(&self, q: Select<'_>) -> crate::Result<i64> {
+    async fn find_int(&self, q: Select<'_>, commenter: &SqlCommenter) -> crate::Result<i64> {
         // UNWRAP: A dataset will always have at least one column, even if it contains no data.
         let id = self
-            .find(q, &[(TypeIdentifier::Int, FieldArity::Required)])
+            .find(q, &[(TypeIdentifier::Int, FieldArity::Required)], commenter)
             .await?
             .values
             .into_iter()
@@ -104,16 +125,22 @@ pub trait QueryExt: Queryable + Send + Sync {
         &self,
         model: &ModelRef,
         record_filter: RecordFilter,
+        commenter: &SqlCommenter,
     ) -> crate::Result<Vec<RecordProjection>> {
         if let Some(selectors) = record_filter.selectors {
             Ok(selectors)
         } else {
-            self.filter_ids(model, record_filter.filter).await
+            self.filter_ids(model, record_filter.filter, commenter).await
         }
     }
 
     /// Read the all columns as a (primary) identifier.
-    async fn filter_ids(&self, model: &ModelRef, filter: Filter) -> crate::Result<Vec<RecordProjection>> {
+    async fn filter_ids(
+        &self,
+        model: &ModelRef,
+        filter: Filter,
+        commenter: &SqlCommenter,
+    ) -> crate::Result<Vec<RecordProjection>> {
         let model_id = model.primary_identifier();
         let id_cols: Vec<Column<'static>> = model_id.as_columns().collect();
 
@@ -121,10 +148,15 @@ pub trait QueryExt: Queryable + Send + Sync {
             .columns(id_cols)
             .so_that(filter.aliased_cond(None));
 
-        self.select_ids(select, model_id).await
+        self.select_ids(select, model_id, commenter).await
     }
 
-    async fn select_ids(&self, select: Select<'_>, model_id: ModelProjection) -> crate::Result<Vec<RecordProjection>> {
+    async fn select_ids(
+        &self,
+        select: Select<'_>,
+        model_id: ModelProjection,
+        commenter: &SqlCommenter,
+    ) -> crate::Result<Vec<RecordProjection>> {
         let idents: Vec<_> = model_id
             .fields()
             .into_iter()
@@ -134,7 +166,7 @@ pub trait QueryExt: Queryable + Send + Sync {
             })
             .collect();
 
-        let mut rows = self.filter(select.into(), &idents).await?;
+        let mut rows = self.filter(select.into(), &idents, commenter).await?;
         let mut result = Vec::new();
 
         for row in rows.drain(0..) {
@@ -0,0 +1,207 @@
+//! The JSON encoding of the values returned by raw queries.
+//!
+//! Raw queries can return columns of any database type, so their values are encoded from the
+//! value kinds of the driver, whatever the query:
+//!
+//! | Database types                                            | JSON                                          |
+//! |-----------------------------------------------------------|-----------------------------------------------|
+//! | `NULL`                                                    | `null`                                        |
+//! | integers (`integer`, `bigint`, MySQL `tinyint`...)        | number                                        |
+//! | `numeric`, `decimal`, `real`, `double precision`, `float` | number, see below                             |
+//! | `boolean`                                                 | boolean                                       |
+//! | text types, `char`, enums                                 | string                                        |
+//! | `bytea`, `blob`, `binary`, `varbinary`                    | string, base64 encoded                        |
+//! | `json`, `jsonb`                                           | the JSON value itself                         |
+//! | `uuid`, `uniqueidentifier`                                | string, hyphenated                            |
+//! | timestamps                                                | string, RFC 3339: `1996-12-19T16:39:57+00:00` |
+//! | `date`                                                    | string: `1996-12-19`                          |
+//! | `time`                                                    | string: `16:39:57`, or `16:39:57.250`         |
+//! | arrays                                                    | array of the encoded elements                 |
+//!
+//! Decimal values are encoded as JSON numbers when they can be read back without losing
+//! precision, as a 64-bit float. The others can not be represented faithfully: they are
+//! stringified by default, or rejected in strict mode.
+//!
+//! The strictness is configured with the `raw_query_strictness` connection string parameter,
+//! set to `lenient` (the default) or `strict`.
+
+use crate::{concurrency_limiter::url_params, SqlError};
+use quaint::ast::Value;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde_json::{Number, Value as JsonValue};
+use std::str::FromStr;
+
+const STRICTNESS_PARAM: &str = "raw_query_strictness";
+
+/// What to do with values that have no faithful JSON encoding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RawStrictness {
+    /// Stringify them.
+    Lenient,
+    /// Fail the query.
+    Strict,
+}
+
+impl Default for RawStrictness {
+    fn default() -> Self {
+        RawStrictness::Lenient
+    }
+}
+
+impl RawStrictness {
+    /// Reads the strictness from the connection string parameters.
+    pub fn from_url(url: &str) -> anyhow::Result<Self> {
+        match url_params(url).find(|(key, _)| *key == STRICTNESS_PARAM) {
+            None => Ok(RawStrictness::default()),
+            Some((_, "lenient")) => Ok(RawStrictness::Lenient),
+            Some((_, "strict")) => Ok(RawStrictness::Strict),
+            Some((_, value)) => Err(anyhow::anyhow!(
+                "Invalid value for `{}`: `{}`. Expected `lenient` or `strict`.",
+                STRICTNESS_PARAM,
+                value
+            )),
+        }
+    }
+}
+
+/// Encodes the value of the given column of a raw query result.
+pub fn encode_raw_value(column: &str, value: Value<'_>, strictness: RawStrictness) -> crate::Result<JsonValue> {
+    if value.is_null() {
+        return Ok(JsonValue::Null);
+    }
+
+    let encoded = match value {
+        Value::Integer(Some(i)) => JsonValue::from(i),
+        Value::Real(Some(decimal)) => encode_decimal(column, decimal, strictness)?,
+        Value::Boolean(Some(b)) => JsonValue::Bool(b),
+        Value::Text(Some(text)) => JsonValue::String(text.into_owned()),
+        Value::Enum(Some(variant)) => JsonValue::String(variant.into_owned()),
+        Value::Char(Some(c)) => JsonValue::String(c.to_string()),
+        Value::Bytes(Some(bytes)) => JsonValue::String(base64::encode(&bytes)),
+        Value::Json(Some(json)) => json,
+        Value::Uuid(Some(uuid)) => JsonValue::String(uuid.to_hyphenated().to_string()),
+        Value::DateTime(Some(dt)) => JsonValue::String(dt.to_rfc3339()),
+        Value::Date(Some(date)) => JsonValue::String(date.format("%Y-%m-%d").to_string()),
+        Value::Time(Some(time)) => JsonValue::String(time.format("%H:%M:%S%.f").to_string()),
+        Value::Array(Some(values)) => JsonValue::Array(
+            values
+                .into_iter()
+                .map(|value| encode_raw_value(column, value, strictness))
+                .collect::<crate::Result<Vec<_>>>()?,
+        ),
+        // Checked above.
+        _ => unreachable!("null raw value"),
+    };
+
+    Ok(encoded)
+}
+
+fn encode_decimal(column: &str, decimal: Decimal, strictness: RawStrictness) -> crate::Result<JsonValue> {
+    let decimal = decimal.normalize();
+
+    // Decimal::from_f64 is buggy. Issue: https://github.com/paupino/rust-decimal/issues/228
+    let float = decimal
+        .to_f64()
+        .filter(|float| Decimal::from_str(&float.to_string()).ok() == Some(decimal));
+
+    match (float.and_then(Number::from_f64), strictness) {
+        (Some(number), _) => Ok(JsonValue::Number(number)),
+        (None, RawStrictness::Lenient) => Ok(JsonValue::String(decimal.to_string())),
+        (None, RawStrictness::Strict) => Err(SqlError::ConversionError(anyhow::anyhow!(
+            "The value `{}` of the column `{}` can not be represented as a JSON number without losing precision. Cast it to a string in the query, or use `{}=lenient`.",
+            decimal,
+            column,
+            STRICTNESS_PARAM
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+    use serde_json::json;
+    use uuid::Uuid;
+
+    fn encode(value: Value<'_>) -> JsonValue {
+        encode_raw_value("col", value, RawStrictness::Strict).unwrap()
+    }
+
+    #[test]
+    fn scalar_values_are_encoded_as_documented() {
+        let dt: DateTime<Utc> = DateTime::parse_from_rfc3339("1996-12-19T16:39:57+00:00")
+            .unwrap()
+            .into();
+        let uuid = Uuid::parse_str("a1b2c3d4-e5f6-4a1b-8c2d-3e4f5a6b7c8d").unwrap();
+
+        assert_eq!(encode(Value::Integer(None)), json!(null));
+        assert_eq!(encode(Value::Integer(Some(-3))), json!(-3));
+        assert_eq!(encode(Value::Boolean(Some(true))), json!(true));
+        assert_eq!(encode(Value::Text(Some("cat".into()))), json!("cat"));
+        assert_eq!(encode(Value::Enum(Some("BLUE".into()))), json!("BLUE"));
+        assert_eq!(encode(Value::Char(Some('c'))), json!("c"));
+        assert_eq!(
+            encode(Value::Bytes(Some(vec![0, 159, 146, 150].into()))),
+            json!("AJ+Slg==")
+        );
+        assert_eq!(encode(Value::Json(Some(json!({ "a": [1] })))), json!({ "a": [1] }));
+        assert_eq!(
+            encode(Value::Uuid(Some(uuid))),
+            json!("a1b2c3d4-e5f6-4a1b-8c2d-3e4f5a6b7c8d")
+        );
+        assert_eq!(encode(Value::DateTime(Some(dt))), json!("1996-12-19T16:39:57+00:00"));
+        assert_eq!(
+            encode(Value::Date(Some(NaiveDate::from_ymd(1996, 12, 19)))),
+            json!("1996-12-19")
+        );
+        assert_eq!(
+            encode(Value::Time(Some(NaiveTime::from_hms_milli(16, 39, 57, 250)))),
+            json!("16:39:57.250")
+        );
+    }
+
+    #[test]
+    fn arrays_are_encoded_element_by_element() {
+        let array = Value::Array(Some(vec![
+            Value::Bytes(Some(vec![1, 2].into())),
+            Value::Bytes(None),
+            Value::Real(Some(Decimal::from_str("1.50").unwrap())),
+        ]));
+
+        assert_eq!(encode(array), json!(["AQI=", null, 1.5]));
+    }
+
+    #[test]
+    fn decimals_are_numbers_only_when_they_do_not_lose_precision() {
+        let exact = Value::Real(Some(Decimal::from_str("12345.678").unwrap()));
+        let precise = || Value::Real(Some(Decimal::from_str("1234567890.1234567890123456789").unwrap()));
+
+        assert_eq!(encode(exact), json!(12345.678));
+        assert_eq!(
+            encode_raw_value("col", precise(), RawStrictness::Lenient).unwrap(),
+            json!("1234567890.1234567890123456789")
+        );
+
+        let err = encode_raw_value("col", precise(), RawStrictness::Strict).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("The value `1234567890.1234567890123456789` of the column `col`"));
+    }
+
+    #[test]
+    fn the_strictness_is_configured_from_the_connection_string() {
+        assert_eq!(
+            RawStrictness::from_url("postgresql://localhost/db?schema=s").unwrap(),
+            RawStrictness::Lenient
+        );
+        assert_eq!(
+            RawStrictness::from_url("mysql://localhost/db?raw_query_strictness=strict").unwrap(),
+            RawStrictness::Strict
+        );
+        assert_eq!(
+            RawStrictness::from_url("sqlserver://localhost:1433;raw_query_strictness=lenient").unwrap(),
+            RawStrictness::Lenient
+        );
+        assert!(RawStrictness::from_url("mysql://localhost/db?raw_query_strictness=yes").is_err());
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{pool_config::configure_pool, SqlError};
+use connector_interface::{
+    error::{ConnectorError, ErrorKind},
+    PoolConfig,
+};
+use quaint::{pooled::Quaint, prelude::ConnectionInfo};
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+/// The connection pools of the read replicas of a database. The queries that only read are spread
+/// over them, in turn. Each replica gets a pool of its own, configured like the one of the primary.
+pub(crate) struct ReadReplicas {
+    pools: Vec<Quaint>,
+    next: AtomicUsize,
+}
+
+impl ReadReplicas {
+    /// Opens the pools of the replicas at `urls`. They must be databases of the same kind as the
+    /// primary.
+    pub(crate) fn new(
+        urls: &[String],
+        primary: &ConnectionInfo,
+        pool_config: &PoolConfig,
+    ) -> connector_interface::Result<Self> {
+        let mut pools = Vec::with_capacity(urls.len());
+
+        for url in urls {
+            let connection_info = ConnectionInfo::from_url(url)
+                .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err.into())))?;
+
+            if connection_info.sql_family() != primary.sql_family() {
+                return Err(ConnectorError::from_kind(ErrorKind::ConnectionError(anyhow::anyhow!(
+                    "The read replica `{}` is not a {} database, like the primary.",
+                    connection_info.host(),
+                    primary.sql_family().as_str()
+                ))));
+            }
+
+            let mut builder = Quaint::builder(url)
+                .map_err(SqlError::from)
+                .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
+
+            configure_pool(&mut builder, pool_config);
+            builder.health_check_interval(Duration::from_secs(15));
+            builder.test_on_check_out(true);
+
+            pools.push(builder.build());
+        }
+
+        Ok(Self {
+            pools,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// The pool of the replica whose turn it is, `None` without replicas.
+    pub(crate) fn next(&self) -> Option<&Quaint> {
+        if self.pools.is_empty() {
+            return None;
+        }
+
+        let idx = self.next.fetch_add(1, Ordering::Relaxed) % self.pools.len();
+
+        self.pools.get(idx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn without_replicas_reads_stay_on_the_primary() {
+        let primary = ConnectionInfo::from_url("postgresql://localhost:5432/db").unwrap();
+        let replicas = ReadReplicas::new(&[], &primary, &PoolConfig::default()).unwrap();
+
+        assert!(replicas.next().is_none());
+    }
+
+    #[test]
+    fn replicas_must_be_databases_of_the_kind_of_the_primary() {
+        let primary = ConnectionInfo::from_url("postgresql://localhost:5432/db").unwrap();
+        let urls = vec![String::from("mysql://replica:3306/db")];
+
+        assert!(ReadReplicas::new(&urls, &primary, &PoolConfig::default()).is_err());
+    }
+}
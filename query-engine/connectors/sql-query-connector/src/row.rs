@@ -2,7 +2,7 @@ use crate::error::SqlError;
 use chrono::{DateTime, NaiveDate, Utc};
 use connector_interface::{AggregationResult, Aggregator};
 use datamodel::FieldArity;
-use prisma_models::{PrismaValue, Record, TypeIdentifier};
+use prisma_models::{PrismaValue, Record, ScalarFieldRef, TypeIdentifier};
 use quaint::{
     ast::{Expression, Value},
     connector::ResultRow,
@@ -59,6 +59,25 @@ impl SqlRow {
             })
             .collect()
     }
+
+    /// Like `into_aggregation_results`, with the values of the grouped fields first.
+    pub fn into_grouped_aggregation_results(
+        mut self,
+        group_by: &[ScalarFieldRef],
+        aggregators: &[Aggregator],
+    ) -> Vec<AggregationResult> {
+        let aggregations = SqlRow {
+            values: self.values.split_off(group_by.len()),
+        }
+        .into_aggregation_results(aggregators);
+
+        group_by
+            .iter()
+            .zip(self.values)
+            .map(|(field, value)| AggregationResult::Field(field.clone(), value))
+            .chain(aggregations)
+            .collect()
+    }
 }
 
 fn coerce_null_to_zero_value(value: PrismaValue) -> PrismaValue {
@@ -148,6 +167,14 @@ pub fn row_value_to_prisma_value(p_value: Value, type_identifier: &TypeIdentifie
                 return Err(SqlError::ConversionError(error.into()));
             }
         },
+        TypeIdentifier::Bytes => match p_value {
+            value if value.is_null() => PrismaValue::Null,
+            Value::Bytes(Some(bytes)) => PrismaValue::Bytes(bytes.into_owned()),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Bytes value not stored as bytes");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
         TypeIdentifier::UUID => match p_value {
             value if value.is_null() => PrismaValue::Null,
             Value::Text(Some(uuid)) => PrismaValue::Uuid(Uuid::parse_str(&uuid)?),
@@ -195,7 +222,7 @@ pub fn row_value_to_prisma_value(p_value: Value, type_identifier: &TypeIdentifie
                 return Err(SqlError::ConversionError(error.into()));
             }
         },
-        TypeIdentifier::Float => match p_value {
+        TypeIdentifier::Float | TypeIdentifier::Decimal => match p_value {
             value if value.is_null() => PrismaValue::Null,
             Value::Real(Some(f)) => PrismaValue::Float(f.normalize()),
             Value::Integer(Some(i)) => {
@@ -219,7 +246,7 @@ pub fn row_value_to_prisma_value(p_value: Value, type_identifier: &TypeIdentifie
                 return Err(SqlError::ConversionError(error.into()));
             }
         },
-        TypeIdentifier::Int => match p_value {
+        TypeIdentifier::Int | TypeIdentifier::BigInt => match p_value {
             Value::Integer(Some(i)) => PrismaValue::Int(i),
             Value::Bytes(Some(bytes)) => PrismaValue::Int(interpret_bytes_as_i64(&bytes)),
             Value::Text(Some(txt)) => PrismaValue::Int(
@@ -0,0 +1,136 @@
+use crate::concurrency_limiter::url_params;
+use quaint::prelude::{ConnectionInfo, SqlFamily};
+
+const SEARCH_PATH_PARAM: &str = "search_path";
+
+/// The schemas that names not qualified with a schema resolve to, on PostgreSQL. Generated queries always qualify
+/// their tables with the schema of the datasource, raw queries usually don't: the schema of the datasource comes
+/// first in the search path, so both hit the same tables.
+///
+/// Configured with the `search_path` connection string parameter, a comma-separated list of the schemas searched
+/// after the one of the datasource, e.g. `?schema=app&search_path=shared,extensions`.
+///
+/// The search path is set again every time a connection is checked out of the pool, a raw `SET search_path` of a
+/// previous request can't leak into the next one. MySQL has no search path, the schema is the database of the
+/// connection. SQL Server only knows default schemas of users.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchPath {
+    schemas: Vec<String>,
+}
+
+impl SearchPath {
+    /// The search path with only the schema of the datasource.
+    pub fn new(datasource_schema: &str) -> Self {
+        Self {
+            schemas: vec![datasource_schema.to_owned()],
+        }
+    }
+
+    /// Reads the search path from the connection string parameters. Returns `None` if no search path is set.
+    pub fn from_url(url: &str, connection_info: &ConnectionInfo) -> anyhow::Result<Option<Self>> {
+        let value = match url_params(url).find(|(key, _)| *key == SEARCH_PATH_PARAM) {
+            Some((_, value)) => value,
+            None => return Ok(None),
+        };
+
+        if connection_info.sql_family() != SqlFamily::Postgres {
+            anyhow::bail!("`{}` is only supported on PostgreSQL.", SEARCH_PATH_PARAM);
+        }
+
+        let datasource_schema = connection_info.schema_name().to_owned();
+        let mut schemas = vec![datasource_schema];
+
+        for schema in value.split(',').map(str::trim).filter(|schema| !schema.is_empty()) {
+            if !schemas.iter().any(|known| known == schema) {
+                schemas.push(schema.to_owned());
+            }
+        }
+
+        Ok(Some(Self { schemas }))
+    }
+
+    /// The search path of the connections of a tenant: the schema of the tenant takes the place of the one of the
+    /// datasource.
+    pub fn for_tenant(&self, tenant_schema: &str) -> Self {
+        let mut schemas = vec![tenant_schema.to_owned()];
+        schemas.extend(
+            self.schemas[1..]
+                .iter()
+                .filter(|schema| *schema != tenant_schema)
+                .cloned(),
+        );
+
+        Self { schemas }
+    }
+
+    /// The statement setting the search path of a connection.
+    pub fn set_statement(&self) -> String {
+        let schemas: Vec<String> = self
+            .schemas
+            .iter()
+            .map(|schema| format!("\"{}\"", schema.replace('"', "\"\"")))
+            .collect();
+
+        format!("SET search_path TO {}", schemas.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn search_path(url: &str) -> anyhow::Result<Option<SearchPath>> {
+        let connection_info = ConnectionInfo::from_url(url).unwrap();
+        SearchPath::from_url(url, &connection_info)
+    }
+
+    #[test]
+    fn the_datasource_schema_comes_first() {
+        let search_path = search_path("postgresql://localhost:5432/db?schema=app&search_path=shared, extensions")
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            search_path.set_statement(),
+            r#"SET search_path TO "app", "shared", "extensions""#
+        );
+    }
+
+    #[test]
+    fn schemas_are_listed_once() {
+        let search_path = search_path("postgresql://localhost:5432/db?schema=app&search_path=shared,app,shared")
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(search_path.set_statement(), r#"SET search_path TO "app", "shared""#);
+    }
+
+    #[test]
+    fn tenants_replace_the_datasource_schema() {
+        let search_path = search_path("postgresql://localhost:5432/db?schema=app&search_path=shared")
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            search_path.for_tenant("acme").set_statement(),
+            r#"SET search_path TO "acme", "shared""#
+        );
+        assert_eq!(
+            SearchPath::new("app").for_tenant("acme").set_statement(),
+            r#"SET search_path TO "acme""#
+        );
+    }
+
+    #[test]
+    fn there_is_no_search_path_without_the_parameter() {
+        assert!(search_path("postgresql://localhost:5432/db?schema=app")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn search_paths_are_rejected_outside_postgres() {
+        assert!(search_path("mysql://localhost:3306/db?search_path=shared").is_err());
+        assert!(search_path("sqlserver://localhost:1433;database=master;search_path=shared").is_err());
+    }
+}
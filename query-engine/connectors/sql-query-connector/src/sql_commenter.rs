@@ -0,0 +1,242 @@
+use crate::{query_builder::OnConflictUpdate, raw_values::encode_raw_value, RawStrictness};
+use connector_interface::QueryTags;
+use quaint::{
+    ast::{Query, Select, Value},
+    connector::{Queryable, ResultSet},
+    prelude::{ConnectionInfo, SqlFamily},
+    visitor::{self, Visitor},
+};
+use serde_json::Value as JsonValue;
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Instant,
+};
+
+/// The tracing target of the executed queries.
+pub const QUERY_LOG_TARGET: &str = "prisma::query";
+
+static LOG_QUERIES: AtomicBool = AtomicBool::new(false);
+
+/// Logs every query run by the connector as an event with the `prisma::query` target: the SQL,
+/// its parameters as a JSON array, the duration in milliseconds and the operation it was run for.
+pub fn enable_query_logging() {
+    LOG_QUERIES.store(true, Ordering::Relaxed);
+}
+
+/// Prepends the query tags of a connection to the SQL of every query it runs, as a comment in the
+/// [sqlcommenter](https://google.github.io/sqlcommenter/spec/) format:
+///
+/// ```sql
+/// /*action='findManyUser',application='my-app'*/ SELECT ...
+/// ```
+#[derive(Debug, Clone)]
+pub struct SqlCommenter {
+    sql_family: SqlFamily,
+    comment: Option<String>,
+    operation: Option<String>,
+}
+
+impl SqlCommenter {
+    pub fn new(connection_info: &ConnectionInfo, tags: &QueryTags) -> Self {
+        Self {
+            sql_family: connection_info.sql_family(),
+            comment: render_comment(tags),
+            operation: tags
+                .iter()
+                .find(|(key, _)| *key == "action")
+                .map(|(_, value)| value.to_owned()),
+        }
+    }
+
+    /// A commenter that leaves the queries untouched.
+    pub fn disabled(connection_info: &ConnectionInfo) -> Self {
+        Self {
+            sql_family: connection_info.sql_family(),
+            comment: None,
+            operation: None,
+        }
+    }
+
+    /// The SQL family the queries are rendered for.
+    pub fn sql_family(&self) -> SqlFamily {
+        self.sql_family
+    }
+
+    /// Runs the query on `conn`, with the comment in front of the generated SQL.
+    pub async fn query<Q>(&self, conn: &Q, q: Query<'_>) -> quaint::Result<ResultSet>
+    where
+        Q: Queryable + ?Sized,
+    {
+        if self.comment.is_none() && !LOG_QUERIES.load(Ordering::Relaxed) {
+            return conn.query(q).await;
+        }
+
+        let (sql, params) = self.build(q)?;
+
+        let sql = match &self.comment {
+            Some(comment) => format!("{} {}", comment, sql),
+            None => sql,
+        };
+
+        self.run(conn, &sql, &params).await
+    }
+
+    /// Runs the insert on `conn`, with the update on conflict rendered after the generated SQL.
+    pub async fn query_on_conflict<Q>(
+        &self,
+        conn: &Q,
+        q: Query<'_>,
+        on_conflict: &OnConflictUpdate,
+    ) -> quaint::Result<ResultSet>
+    where
+        Q: Queryable + ?Sized,
+    {
+        let (sql, params) = self.build(q)?;
+        let clause = on_conflict.render(|column| self.quote(column))?;
+
+        let sql = match &self.comment {
+            Some(comment) => format!("{} {} {}", comment, sql, clause),
+            None => format!("{} {}", sql, clause),
+        };
+
+        self.run(conn, &sql, &params).await
+    }
+
+    async fn run<Q>(&self, conn: &Q, sql: &str, params: &[Value<'_>]) -> quaint::Result<ResultSet>
+    where
+        Q: Queryable + ?Sized,
+    {
+        if !LOG_QUERIES.load(Ordering::Relaxed) {
+            return conn.query_raw(sql, params).await;
+        }
+
+        let start = Instant::now();
+        let result = conn.query_raw(sql, params).await;
+
+        tracing::info!(
+            target: QUERY_LOG_TARGET,
+            query = sql,
+            params = render_params(params).as_str(),
+            duration_ms = start.elapsed().as_millis() as u64,
+            operation = self.operation.as_deref().unwrap_or(""),
+            success = result.is_ok(),
+        );
+
+        result
+    }
+
+    /// Quotes the identifier the way the visitor of the SQL family does.
+    fn quote(&self, identifier: &str) -> quaint::Result<String> {
+        let (sql, _) = self.build(Select::default().column(identifier.to_owned()).into())?;
+
+        Ok(sql.trim_start_matches("SELECT ").to_owned())
+    }
+
+    fn build<'a>(&self, q: Query<'a>) -> quaint::Result<(String, Vec<Value<'a>>)> {
+        match self.sql_family {
+            SqlFamily::Postgres => visitor::Postgres::build(q),
+            SqlFamily::Mysql => visitor::Mysql::build(q),
+            SqlFamily::Sqlite => visitor::Sqlite::build(q),
+            SqlFamily::Mssql => visitor::Mssql::build(q),
+        }
+    }
+}
+
+/// Keys and values are percent-encoded, which also keeps them from closing the comment early.
+fn render_comment(tags: &QueryTags) -> Option<String> {
+    if tags.is_empty() {
+        return None;
+    }
+
+    let pairs: Vec<String> = tags
+        .iter()
+        .map(|(key, value)| format!("{}='{}'", percent_encode(key), percent_encode(value)))
+        .collect();
+
+    Some(format!("/*{}*/", pairs.join(",")))
+}
+
+/// The parameters of a query as a JSON array, encoded like the values returned by raw queries.
+fn render_params(params: &[Value<'_>]) -> String {
+    let params: Vec<JsonValue> = params
+        .iter()
+        .map(|param| encode_raw_value("", param.clone(), RawStrictness::Lenient).unwrap_or(JsonValue::Null))
+        .collect();
+
+    JsonValue::Array(params).to_string()
+}
+
+fn percent_encode(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+
+    for byte in s.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comments_are_rendered_in_the_sqlcommenter_format() {
+        let tags = QueryTags::new()
+            .with("traceparent", "00-5bd66ef5095369c7b0d1f8f4bd33716a-c532cb4098ac3dd2-01")
+            .with("action", "findManyUser")
+            .with("application", "my app/1.0");
+
+        assert_eq!(
+            render_comment(&tags).unwrap(),
+            "/*action='findManyUser',application='my%20app%2F1.0',traceparent='00-5bd66ef5095369c7b0d1f8f4bd33716a-c532cb4098ac3dd2-01'*/"
+        );
+    }
+
+    #[test]
+    fn tag_values_cannot_close_the_comment() {
+        let tags = QueryTags::new().with("application", "*/ DROP TABLE \"User\"; /*'");
+
+        assert_eq!(
+            render_comment(&tags).unwrap(),
+            "/*application='%2A%2F%20DROP%20TABLE%20%22User%22%3B%20%2F%2A%27'*/"
+        );
+    }
+
+    #[test]
+    fn no_comment_is_rendered_without_tags() {
+        assert_eq!(render_comment(&QueryTags::new()), None);
+    }
+
+    #[test]
+    fn params_are_logged_as_a_json_array() {
+        let params = [
+            Value::Integer(Some(1)),
+            Value::Text(Some("cat".into())),
+            Value::Boolean(None),
+        ];
+
+        assert_eq!(render_params(&params), r#"[1,"cat",null]"#);
+    }
+
+    #[test]
+    fn on_conflict_identifiers_are_quoted_by_the_visitor() {
+        let commenter = SqlCommenter {
+            sql_family: SqlFamily::Mysql,
+            comment: None,
+            operation: None,
+        };
+
+        let on_conflict = OnConflictUpdate::OnDuplicateKey {
+            update: vec!["name".to_owned()],
+        };
+
+        assert_eq!(
+            on_conflict.render(|column| commenter.quote(column)).unwrap(),
+            "ON DUPLICATE KEY UPDATE `name` = VALUES(`name`)"
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{pool_config::configure_pool, SqlError};
+use connector_interface::{
+    error::{ConnectorError, ErrorKind},
+    PoolConfig,
+};
+use quaint::{
+    pooled::Quaint,
+    prelude::{ConnectionInfo, SqlFamily},
+};
+use std::{collections::BTreeMap, time::Duration};
+
+/// The tenants of an engine serving one schema (PostgreSQL) or database (MySQL) per tenant from one datasource,
+/// all with the same tables. Requests pick theirs among the allow-list, the other ones run on the schema or
+/// database of the datasource.
+///
+/// Each tenant gets a connection pool of its own, configured like the one of the datasource, so a busy tenant
+/// can't take the connections of the others. The connections are switched to the schema or database of their
+/// tenant every time they are checked out, and the generated queries leave their tables unqualified, so they
+/// resolve to it.
+pub(crate) struct Tenants {
+    pools: BTreeMap<String, Quaint>,
+}
+
+impl Tenants {
+    /// Opens the pools of the tenants `names`, to the server of the datasource at `url`.
+    pub(crate) fn new(
+        names: &[String],
+        url: &str,
+        connection_info: &ConnectionInfo,
+        pool_config: &PoolConfig,
+    ) -> connector_interface::Result<Self> {
+        if !names.is_empty() && !matches!(connection_info.sql_family(), SqlFamily::Postgres | SqlFamily::Mysql) {
+            return Err(ConnectorError::from_kind(ErrorKind::ConnectionError(anyhow::anyhow!(
+                "Tenants are only supported on PostgreSQL and MySQL."
+            ))));
+        }
+
+        let mut pools = BTreeMap::new();
+
+        for name in names.iter().filter(|name| !name.is_empty()) {
+            let mut builder = Quaint::builder(url)
+                .map_err(SqlError::from)
+                .map_err(|sql_error| sql_error.into_connector_error(connection_info))?;
+
+            configure_pool(&mut builder, pool_config);
+            builder.health_check_interval(Duration::from_secs(15));
+            builder.test_on_check_out(true);
+
+            pools.insert(name.to_owned(), builder.build());
+        }
+
+        Ok(Self { pools })
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.pools.is_empty()
+    }
+
+    /// The pool of `tenant`. Tenants outside of the allow-list are rejected.
+    pub(crate) fn pool(&self, tenant: &str) -> connector_interface::Result<&Quaint> {
+        self.pools.get(tenant).ok_or_else(|| {
+            ConnectorError::from_kind(ErrorKind::UnknownTenant {
+                tenant: tenant.to_owned(),
+            })
+        })
+    }
+}
+
+/// The statement switching a MySQL connection to `database`.
+pub(crate) fn use_database_statement(database: &str) -> String {
+    format!("USE `{}`", database.replace('`', "``"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn database_names_are_escaped() {
+        assert_eq!(use_database_statement("acme"), "USE `acme`");
+        assert_eq!(use_database_statement("ac`me"), "USE `ac``me`");
+    }
+
+    #[test]
+    fn tenants_are_rejected_outside_postgres_and_mysql() {
+        let url = "file:dev.db";
+        let connection_info = ConnectionInfo::from_url(url).unwrap();
+        let tenants = [String::from("acme")];
+
+        assert!(Tenants::new(&tenants, url, &connection_info, &PoolConfig::default()).is_err());
+        assert!(Tenants::new(&[], url, &connection_info, &PoolConfig::default())
+            .unwrap()
+            .is_empty());
+    }
+}
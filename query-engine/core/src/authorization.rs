@@ -0,0 +1,298 @@
+//! Authorization of the operations of requests against a policy configured at engine startup, for
+//! engines reachable by clients that can not be trusted with the whole database, e.g. behind a gateway.
+//!
+//! The policy is a list of rules, each allowing operations on a model to requests carrying some
+//! claims. The claims of a request are a JSON object, set by the gateway after authenticating the
+//! client. Operations allowed by none of the rules are rejected, before anything is executed:
+//!
+//! ```json
+//! {
+//!   "rules": [
+//!     { "model": "Post", "operations": ["findMany", "findOne"] },
+//!     { "model": "Post", "operations": ["*"], "claims": { "role": "editor" } },
+//!     { "model": "*", "operations": ["*"], "claims": { "role": "admin" } }
+//!   ]
+//! }
+//! ```
+//!
+//! Every model an operation reaches must be allowed too: the related records it selects
+//! (`findOne` or `findMany`, `count` for `_count`), the relations its filters and orderings go
+//! through (`findMany`), and its nested writes (`createOne`, `updateOne`, `deleteMany`, ...).
+//!
+//! The endpoints of the server that aren't operations, e.g. `metrics`, are authorized like
+//! operations without a model.
+
+use crate::{CoreError, Operation, QuerySchema, QueryValue, SchemaQueryBuilder, Selection, RELATION_COUNT_FIELD};
+use prisma_models::ModelRef;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// The claims of a request, e.g. `{ "role": "editor", "teams": ["a", "b"] }`.
+pub type Claims = serde_json::Map<String, Value>;
+
+/// Matches any model, or any operation.
+const WILDCARD: &str = "*";
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AuthorizationPolicy {
+    rules: Vec<AuthorizationRule>,
+}
+
+/// Allows operations on a model to the requests carrying the claims of the rule.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AuthorizationRule {
+    /// The name of the model, or `*` for all of them. Operations without a model, e.g. `executeRaw`,
+    /// are only matched by `*`.
+    model: String,
+    /// The allowed operations, e.g. `findMany` or `executeRaw`, or `*` for all of them.
+    operations: Vec<String>,
+    /// The claims required, with their values. A claim that is a list in the request only needs to
+    /// contain the value. No claims are required when empty.
+    #[serde(default)]
+    claims: Claims,
+}
+
+impl AuthorizationPolicy {
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// The models the rules refer to, except the wildcard.
+    pub fn models(&self) -> impl Iterator<Item = &str> {
+        self.rules
+            .iter()
+            .map(|rule| rule.model.as_str())
+            .filter(|model| *model != WILDCARD)
+    }
+
+    /// Checks that the policy allows `operation`, and all the operations on other models it
+    /// amounts to, to a request with `claims`.
+    pub fn authorize(&self, operation: &Operation, query_schema: &QuerySchema, claims: &Claims) -> crate::Result<()> {
+        let (field, selection) = match operation {
+            Operation::Read(selection) => (query_schema.find_query_field(operation.name()), selection),
+            Operation::Write(selection) => (query_schema.find_mutation_field(operation.name()), selection),
+        };
+
+        match field.as_ref().and_then(|field| field.query_builder()) {
+            Some(SchemaQueryBuilder::ModelQueryBuilder(builder)) => {
+                let mut checks = vec![(builder.model.name.clone(), builder.tag.to_string())];
+                selection_checks(&builder.model, selection, &mut checks);
+
+                for (model, operation) in checks {
+                    self.check(Some(model), operation, claims)?;
+                }
+
+                Ok(())
+            }
+            _ => self.check(None, operation.name().to_owned(), claims),
+        }
+    }
+
+    /// Checks that the policy allows a request with `claims` to use the `endpoint` of the server,
+    /// e.g. `transaction`, `metrics`, `dmmf` or `sdl`.
+    pub fn authorize_endpoint(&self, endpoint: &str, claims: &Claims) -> crate::Result<()> {
+        self.check(None, endpoint.to_owned(), claims)
+    }
+
+    fn check(&self, model: Option<String>, operation: String, claims: &Claims) -> crate::Result<()> {
+        if self.allows(model.as_deref(), &operation, claims) {
+            Ok(())
+        } else {
+            Err(CoreError::OperationNotAuthorized { operation, model })
+        }
+    }
+
+    fn allows(&self, model: Option<&str>, operation: &str, claims: &Claims) -> bool {
+        self.rules.iter().any(|rule| rule.allows(model, operation, claims))
+    }
+}
+
+impl AuthorizationRule {
+    fn allows(&self, model: Option<&str>, operation: &str, claims: &Claims) -> bool {
+        let model_matches = self.model == WILDCARD || Some(self.model.as_str()) == model;
+        let operation_matches = self
+            .operations
+            .iter()
+            .any(|allowed| allowed == WILDCARD || allowed == operation);
+
+        model_matches
+            && operation_matches
+            && self
+                .claims
+                .iter()
+                .all(|(name, required)| claim_matches(claims.get(name), required))
+    }
+}
+
+/// Collects the operations on related models that reading `selection` of `model` amounts to: the
+/// related records it selects, and those its arguments filter, order or write through.
+fn selection_checks(model: &ModelRef, selection: &Selection, checks: &mut Vec<(String, String)>) {
+    for (_, value) in selection.arguments() {
+        value_checks(model, value, checks);
+    }
+
+    for nested in selection.nested_selections() {
+        if nested.name() == RELATION_COUNT_FIELD {
+            for counted in nested.nested_selections() {
+                if let Ok(rf) = model.fields().find_from_relation_fields(counted.name()) {
+                    checks.push((rf.related_model().name.clone(), String::from("count")));
+                }
+            }
+        } else if let Ok(rf) = model.fields().find_from_relation_fields(nested.name()) {
+            let related_model = rf.related_model();
+            let operation = if rf.is_list { "findMany" } else { "findOne" };
+
+            checks.push((related_model.name.clone(), String::from(operation)));
+            selection_checks(&related_model, nested, checks);
+        } else {
+            selection_checks(model, nested, checks);
+        }
+    }
+}
+
+/// Collects the operations on related models of an argument value of `model`: the keys naming a
+/// relation field hold nested writes, relation filters or orderings on the related model.
+fn value_checks(model: &ModelRef, value: &QueryValue, checks: &mut Vec<(String, String)>) {
+    match value {
+        QueryValue::Object(map) => {
+            for (key, value) in map {
+                match model.fields().find_from_relation_fields(key) {
+                    Ok(rf) => relation_value_checks(&rf.related_model(), value, checks),
+                    Err(_) => value_checks(model, value, checks),
+                }
+            }
+        }
+        QueryValue::List(values) => {
+            for value in values {
+                value_checks(model, value, checks);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn relation_value_checks(related_model: &ModelRef, value: &QueryValue, checks: &mut Vec<(String, String)>) {
+    match value {
+        QueryValue::Object(map) => {
+            for (key, value) in map {
+                for operation in nested_operations(key) {
+                    checks.push((related_model.name.clone(), String::from(*operation)));
+                }
+
+                value_checks(related_model, value, checks);
+            }
+        }
+        QueryValue::List(values) => {
+            for value in values {
+                relation_value_checks(related_model, value, checks);
+            }
+        }
+        _ => checks.push((related_model.name.clone(), String::from("findMany"))),
+    }
+}
+
+/// The operations on the related model of a key under a relation field. Anything that isn't a
+/// nested write is a filter or an ordering, which reads the related records.
+fn nested_operations(key: &str) -> &'static [&'static str] {
+    match key {
+        "create" => &["createOne"],
+        "createMany" => &["createMany"],
+        "connectOrCreate" => &["createOne", "updateOne"],
+        "connect" | "disconnect" | "set" => &["updateOne"],
+        "update" => &["updateOne"],
+        "updateMany" => &["updateMany"],
+        "upsert" => &["upsertOne"],
+        "delete" => &["deleteOne"],
+        "deleteMany" => &["deleteMany"],
+        _ => &["findMany"],
+    }
+}
+
+fn claim_matches(claim: Option<&Value>, required: &Value) -> bool {
+    match claim {
+        Some(Value::Array(values)) if !required.is_array() => values.contains(required),
+        Some(value) => value == required,
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn policy() -> AuthorizationPolicy {
+        AuthorizationPolicy::from_json(
+            &json!({
+                "rules": [
+                    { "model": "Post", "operations": ["findMany", "findOne"] },
+                    { "model": "Post", "operations": ["*"], "claims": { "role": "editor" } },
+                    { "model": "*", "operations": ["*"], "claims": { "role": "admin" } },
+                ]
+            })
+            .to_string(),
+        )
+        .unwrap()
+    }
+
+    fn claims(value: Value) -> Claims {
+        value.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn operations_are_allowed_by_matching_rules() {
+        let policy = policy();
+
+        assert!(policy.allows(Some("Post"), "findMany", &Claims::new()));
+        assert!(policy.allows(Some("Post"), "deleteOne", &claims(json!({ "role": "editor" }))));
+        assert!(policy.allows(Some("User"), "updateOne", &claims(json!({ "role": "admin" }))));
+        assert!(policy.allows(None, "executeRaw", &claims(json!({ "role": "admin" }))));
+    }
+
+    #[test]
+    fn operations_without_a_matching_rule_are_rejected() {
+        let policy = policy();
+
+        assert!(!policy.allows(Some("Post"), "deleteOne", &Claims::new()));
+        assert!(!policy.allows(Some("User"), "findMany", &claims(json!({ "role": "editor" }))));
+        assert!(!policy.allows(None, "executeRaw", &claims(json!({ "role": "editor" }))));
+    }
+
+    #[test]
+    fn list_claims_match_the_values_they_contain() {
+        let policy = policy();
+
+        assert!(policy.allows(
+            Some("User"),
+            "findMany",
+            &claims(json!({ "role": ["reader", "admin"] }))
+        ));
+        assert!(!policy.allows(Some("User"), "findMany", &claims(json!({ "role": ["reader"] }))));
+    }
+
+    #[test]
+    fn endpoints_are_only_allowed_by_wildcard_rules() {
+        let policy = policy();
+
+        assert!(policy.authorize_endpoint("metrics", &Claims::new()).is_err());
+        assert!(policy
+            .authorize_endpoint("metrics", &claims(json!({ "role": "editor" })))
+            .is_err());
+        assert!(policy
+            .authorize_endpoint("metrics", &claims(json!({ "role": "admin" })))
+            .is_ok());
+    }
+
+    #[test]
+    fn nested_writes_amount_to_operations_on_the_related_model() {
+        assert_eq!(nested_operations("create"), &["createOne"]);
+        assert_eq!(nested_operations("connectOrCreate"), &["createOne", "updateOne"]);
+        assert_eq!(nested_operations("deleteMany"), &["deleteMany"]);
+        assert_eq!(nested_operations("some"), &["findMany"]);
+    }
+
+    #[test]
+    fn the_models_of_the_rules_are_listed_without_the_wildcard() {
+        assert_eq!(policy().models().collect::<Vec<_>>(), vec!["Post", "Post"]);
+    }
+}
@@ -35,6 +35,29 @@ pub enum CoreError {
 
     #[error("{}", _0)]
     InterpreterError(InterpreterError),
+
+    #[error("Transaction not found: {}", _0)]
+    TransactionNotFound(String),
+
+    #[error("Too many open transactions, the limit is {}", _0)]
+    TooManyOpenTransactions(usize),
+
+    #[error(
+        "Value '{}' of field '{}' on model '{}' not found in enum '{}'",
+        value,
+        field_name,
+        model_name,
+        enum_name
+    )]
+    UnknownEnumValue {
+        model_name: String,
+        field_name: String,
+        enum_name: String,
+        value: String,
+    },
+
+    #[error("Operation '{}' is not authorized", operation)]
+    OperationNotAuthorized { operation: String, model: Option<String> },
 }
 
 impl From<QueryGraphBuilderError> for CoreError {
@@ -139,6 +162,14 @@ impl From<CoreError> for user_facing_errors::Error {
             })
             .unwrap()
             .into(),
+            CoreError::QueryGraphBuilderError(QueryGraphBuilderError::UniqueConstraintViolation { fields })
+            | CoreError::InterpreterError(InterpreterError::QueryGraphBuilderError(
+                QueryGraphBuilderError::UniqueConstraintViolation { fields },
+            )) => user_facing_errors::KnownError::new(user_facing_errors::query_engine::UniqueKeyViolation {
+                constraint: fields.into(),
+            })
+            .unwrap()
+            .into(),
             CoreError::QueryGraphBuilderError(QueryGraphBuilderError::InputError(details)) => {
                 user_facing_errors::KnownError::new(user_facing_errors::query_engine::InputError { details })
                     .unwrap()
@@ -170,6 +201,13 @@ impl From<CoreError> for user_facing_errors::Error {
                     })
                     .unwrap()
                     .into(),
+                    InterpreterError::QueryGraphBuilderError(QueryGraphBuilderError::UniqueConstraintViolation {
+                        fields,
+                    }) => user_facing_errors::KnownError::new(user_facing_errors::query_engine::UniqueKeyViolation {
+                        constraint: fields.clone().into(),
+                    })
+                    .unwrap()
+                    .into(),
                     _ => user_facing_errors::KnownError::new(user_facing_errors::query_engine::InterpretationError {
                         details: format!("{}: {}", msg, cause),
                     })
@@ -177,6 +215,37 @@ impl From<CoreError> for user_facing_errors::Error {
                     .into(),
                 }
             }
+            CoreError::TransactionNotFound(id) => {
+                user_facing_errors::KnownError::new(user_facing_errors::query_engine::TransactionNotFound { id })
+                    .unwrap()
+                    .into()
+            }
+            CoreError::TooManyOpenTransactions(limit) => {
+                user_facing_errors::KnownError::new(user_facing_errors::query_engine::TooManyOpenTransactions { limit })
+                    .unwrap()
+                    .into()
+            }
+            CoreError::UnknownEnumValue {
+                model_name,
+                field_name,
+                enum_name,
+                value,
+            } => user_facing_errors::KnownError::new(user_facing_errors::query_engine::UnknownEnumValue {
+                model_name,
+                field_name,
+                enum_name,
+                value,
+            })
+            .unwrap()
+            .into(),
+            CoreError::OperationNotAuthorized { operation, model } => {
+                user_facing_errors::KnownError::new(user_facing_errors::query_engine::OperationNotAuthorized {
+                    operation,
+                    model_name: model,
+                })
+                .unwrap()
+                .into()
+            }
             _ => user_facing_errors::Error::from_dyn_error(&err),
         }
     }
@@ -0,0 +1,255 @@
+//! Interactive transactions span several requests: the client starts a transaction, runs operations in it
+//! by its id, then commits or rolls it back.
+//!
+//! Every open transaction is owned by a task of its own, holding the connection and the transaction. The
+//! executor talks to the task through a channel, so the operations of a transaction run one after the other.
+//! A transaction that sees no activity for its timeout is rolled back, and its id becomes unknown.
+//!
+//! As each of them holds a connection, an executor keeps at most `MAX_OPEN_TRANSACTIONS` transactions open at once.
+
+use super::pipeline::QueryPipeline;
+use crate::{
+    metrics::{self, METRICS},
+    CoreError, Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData,
+};
+use connector::{Connection, ConnectionLike, QueryTags, Transaction};
+use futures::{
+    channel::{mpsc, oneshot},
+    StreamExt,
+};
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+use tracing::Span;
+use tracing_futures::Instrument;
+
+/// How many interactive transactions an executor keeps open at once. Starting another one fails until one of them
+/// is committed, rolled back or expires.
+pub const MAX_OPEN_TRANSACTIONS: usize = 100;
+
+/// The id of an interactive transaction, handed out to the client when the transaction starts.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TxId(String);
+
+impl TxId {
+    fn new() -> Self {
+        Self(uuid::Uuid::new_v4().to_string())
+    }
+}
+
+impl From<String> for TxId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+
+impl fmt::Display for TxId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+enum TxMessage {
+    Execute {
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        /// The tags of the request and the operation, added to the ones of the transaction for its queries.
+        query_tags: QueryTags,
+        /// The span of the request, the operation runs in the task of the transaction.
+        span: Span,
+        respond: oneshot::Sender<crate::Result<ResponseData>>,
+    },
+    Commit(oneshot::Sender<crate::Result<()>>),
+    Rollback(oneshot::Sender<crate::Result<()>>),
+}
+
+/// The open interactive transactions of an executor.
+#[derive(Default)]
+pub(crate) struct OpenTransactions {
+    transactions: Mutex<HashMap<TxId, mpsc::UnboundedSender<TxMessage>>>,
+}
+
+impl OpenTransactions {
+    /// Starts a transaction on `conn`, which is used for nothing else until the transaction ends.
+    pub(crate) async fn start(
+        &self,
+        mut conn: Box<dyn Connection>,
+        query_tags: QueryTags,
+        timeout: Duration,
+    ) -> crate::Result<TxId> {
+        conn.set_query_tags(query_tags.clone());
+
+        let id = TxId::new();
+        let (sender, messages) = mpsc::unbounded();
+        let (started, has_started) = oneshot::channel();
+
+        // The transaction takes its place before it starts, so concurrent starts can't exceed the limit.
+        {
+            let mut transactions = self.transactions.lock().unwrap();
+
+            // Forget the transactions that ended without a commit or rollback, i.e. expired.
+            transactions.retain(|_, sender| !sender.is_closed());
+
+            if transactions.len() >= MAX_OPEN_TRANSACTIONS {
+                return Err(CoreError::TooManyOpenTransactions(MAX_OPEN_TRANSACTIONS));
+            }
+
+            transactions.insert(id.clone(), sender);
+        }
+
+        tokio::spawn(run_transaction(
+            id.clone(),
+            conn,
+            query_tags,
+            messages,
+            started,
+            timeout,
+        ));
+
+        let result = has_started
+            .await
+            .unwrap_or_else(|_| Err(CoreError::TransactionNotFound(id.to_string())));
+
+        if let Err(err) = result {
+            self.transactions.lock().unwrap().remove(&id);
+            return Err(err);
+        }
+
+        Ok(id)
+    }
+
+    pub(crate) async fn execute(
+        &self,
+        id: &TxId,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        query_tags: QueryTags,
+    ) -> crate::Result<ResponseData> {
+        let (respond, response) = oneshot::channel();
+
+        self.send(
+            id,
+            TxMessage::Execute {
+                operation,
+                query_schema,
+                query_tags,
+                span: Span::current(),
+                respond,
+            },
+        )?;
+
+        response
+            .await
+            .unwrap_or_else(|_| Err(CoreError::TransactionNotFound(id.to_string())))
+    }
+
+    pub(crate) async fn commit(&self, id: &TxId) -> crate::Result<()> {
+        let (respond, response) = oneshot::channel();
+
+        self.send(id, TxMessage::Commit(respond))?;
+        self.transactions.lock().unwrap().remove(id);
+
+        response
+            .await
+            .unwrap_or_else(|_| Err(CoreError::TransactionNotFound(id.to_string())))
+    }
+
+    pub(crate) async fn rollback(&self, id: &TxId) -> crate::Result<()> {
+        let (respond, response) = oneshot::channel();
+
+        self.send(id, TxMessage::Rollback(respond))?;
+        self.transactions.lock().unwrap().remove(id);
+
+        response
+            .await
+            .unwrap_or_else(|_| Err(CoreError::TransactionNotFound(id.to_string())))
+    }
+
+    fn send(&self, id: &TxId, message: TxMessage) -> crate::Result<()> {
+        let transactions = self.transactions.lock().unwrap();
+
+        transactions
+            .get(id)
+            .and_then(|sender| sender.unbounded_send(message).ok())
+            .ok_or_else(|| CoreError::TransactionNotFound(id.to_string()))
+    }
+}
+
+async fn run_transaction(
+    id: TxId,
+    conn: Box<dyn Connection>,
+    query_tags: QueryTags,
+    mut messages: mpsc::UnboundedReceiver<TxMessage>,
+    started: oneshot::Sender<crate::Result<()>>,
+    timeout: Duration,
+) {
+    let _active_connection = METRICS.connection_checked_out();
+
+    let tx = match conn.start_transaction().await {
+        Ok(tx) => {
+            let _ = started.send(Ok(()));
+            tx
+        }
+        Err(err) => {
+            let _ = started.send(Err(err.into()));
+            return;
+        }
+    };
+
+    loop {
+        match tokio::time::timeout(timeout, messages.next()).await {
+            Ok(Some(TxMessage::Execute {
+                operation,
+                query_schema,
+                query_tags: operation_tags,
+                span,
+                respond,
+            })) => {
+                tx.set_query_tags(query_tags.clone().merge(operation_tags));
+
+                let operation_type = metrics::operation_type(&operation, &query_schema);
+                let start = Instant::now();
+                let result = execute_operation(tx.as_ref(), operation, query_schema)
+                    .instrument(span)
+                    .await;
+                METRICS.record_operation(&operation_type, start.elapsed(), result.is_ok());
+
+                let _ = respond.send(result);
+            }
+            Ok(Some(TxMessage::Commit(respond))) => {
+                let _ = respond.send(tx.commit().await.map_err(Into::into));
+                break;
+            }
+            Ok(Some(TxMessage::Rollback(respond))) => {
+                let _ = respond.send(tx.rollback().await.map_err(Into::into));
+                break;
+            }
+            // Expired, or the executor is gone.
+            Ok(None) | Err(_) => {
+                debug!("Rolling back the expired interactive transaction `{}`.", id);
+
+                if let Err(err) = tx.rollback().await {
+                    error!("Rolling back the interactive transaction `{}` failed: {}", id, err);
+                }
+
+                break;
+            }
+        }
+    }
+}
+
+/// Executes an operation in the transaction. A failing operation does not roll back the transaction, the client
+/// decides whether to go on or roll back.
+async fn execute_operation(
+    tx: &dyn Transaction,
+    operation: Operation,
+    query_schema: QuerySchemaRef,
+) -> crate::Result<ResponseData> {
+    let (query, serializer) = QueryGraphBuilder::new(query_schema).build(operation)?;
+    let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx));
+
+    QueryPipeline::new(query, interpreter, serializer).execute().await
+}
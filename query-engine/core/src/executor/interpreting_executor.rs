@@ -1,8 +1,12 @@
-use super::{pipeline::QueryPipeline, QueryExecutor};
-use crate::{Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData};
+use super::{interactive_transactions::OpenTransactions, pipeline::QueryPipeline, QueryExecutor, ReadPreference, TxId};
+use crate::{
+    metrics::{self, METRICS},
+    Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData,
+};
 use async_trait::async_trait;
-use connector::{Connection, ConnectionLike, Connector};
+use connector::{Connection, ConnectionLike, Connector, QueryTags};
 use futures::future;
+use std::time::{Duration, Instant};
 
 /// Central query executor and main entry point into the query core.
 pub struct InterpretingExecutor<C> {
@@ -12,6 +16,12 @@ pub struct InterpretingExecutor<C> {
     /// Flag that forces individual operations to run in a transaction.
     /// Does _not_ force batches to use transactions.
     force_transactions: bool,
+
+    /// Tags attached to the queries of every operation, e.g. the application name.
+    query_tags: QueryTags,
+
+    /// The interactive transactions started by clients and not ended yet.
+    open_transactions: OpenTransactions,
 }
 
 impl<C> InterpretingExecutor<C>
@@ -22,16 +32,82 @@ where
         InterpretingExecutor {
             connector,
             force_transactions,
+            query_tags: QueryTags::new(),
+            open_transactions: OpenTransactions::default(),
         }
     }
 
+    pub fn with_query_tags(mut self, query_tags: QueryTags) -> Self {
+        self.query_tags = query_tags;
+        self
+    }
+
+    /// The tags for the queries of a single operation: the configured tags, the ones of the request,
+    /// and the name of the operation as `action`.
+    fn operation_query_tags(&self, operation: &Operation, request_tags: &QueryTags) -> QueryTags {
+        self.query_tags
+            .clone()
+            .merge(request_tags.clone())
+            .with("action", operation.name())
+    }
+
+    /// The connection to run a single operation on: a read connection for operations that only read, unless the
+    /// request wants them on the primary. Operations that write always run on the primary. Tenants have no
+    /// read replicas, all their operations run on a connection to their schema or database.
+    async fn connection_for(
+        &self,
+        operation: &Operation,
+        read_preference: ReadPreference,
+        tenant: Option<&str>,
+    ) -> crate::Result<Box<dyn Connection>> {
+        let conn = match (tenant, operation, read_preference) {
+            (Some(tenant), _, _) => self.connector.get_tenant_connection(tenant).await?,
+            (None, Operation::Read(_), ReadPreference::Replica) => self.connector.get_read_connection().await?,
+            (None, _, _) => self.connector.get_connection().await?,
+        };
+
+        Ok(conn)
+    }
+
+    /// The connection for transactions: to the primary, or to the schema or database of the tenant.
+    async fn primary_connection(&self, tenant: Option<&str>) -> crate::Result<Box<dyn Connection>> {
+        let conn = match tenant {
+            Some(tenant) => self.connector.get_tenant_connection(tenant).await?,
+            None => self.connector.get_connection().await?,
+        };
+
+        Ok(conn)
+    }
+
     /// Async wrapper for executing an individual operation to allow code sharing with `execute_batch`.
+    /// The operation and the connection it holds are recorded in the metrics.
     async fn execute_single_operation(
         operation: Operation,
         conn: Box<dyn Connection>,
         force_transactions: bool,
         query_schema: QuerySchemaRef,
+        query_tags: QueryTags,
     ) -> crate::Result<ResponseData> {
+        let _active_connection = METRICS.connection_checked_out();
+        let operation_type = metrics::operation_type(&operation, &query_schema);
+        let start = Instant::now();
+
+        let result = Self::execute_operation_on(operation, conn, force_transactions, query_schema, query_tags).await;
+        METRICS.record_operation(&operation_type, start.elapsed(), result.is_ok());
+
+        result
+    }
+
+    /// Executes the operation on `conn`, in a transaction if the query needs one.
+    async fn execute_operation_on(
+        operation: Operation,
+        mut conn: Box<dyn Connection>,
+        force_transactions: bool,
+        query_schema: QuerySchemaRef,
+        query_tags: QueryTags,
+    ) -> crate::Result<ResponseData> {
+        conn.set_query_tags(query_tags);
+
         // Parse, validate, and extract query graph from query document.
         let (query, serializer) = QueryGraphBuilder::new(query_schema).build(operation)?;
         let needs_transaction = force_transactions || query.needs_transaction();
@@ -67,15 +143,22 @@ where
     /// returning the error.
     ///
     /// If the batch is not transactional:
-    /// All operations are fanned out onto as many connections as possible and executed independently.
+    /// All operations are fanned out onto as many connections as possible and executed independently. The ones
+    /// that only read run on read replicas, according to the `read_preference`.
     /// A failing operation does not fail the batch, instead, an error is returned alongside other responses.
     /// Note that individual operations executed in non-transactional mode can still be transactions in themselves
     /// if the query (e.g. a write op) requires it.
+    ///
+    /// Batches are flat. Nested batches of the request protocol are flattened beforehand, they never
+    /// open transactions or savepoints of their own.
     async fn execute_batch(
         &self,
         operations: Vec<Operation>,
         transactional: bool,
         query_schema: QuerySchemaRef,
+        query_tags: QueryTags,
+        read_preference: ReadPreference,
+        tenant: Option<String>,
     ) -> crate::Result<Vec<crate::Result<ResponseData>>> {
         if transactional {
             // Transactional batches are currently experimental
@@ -85,24 +168,40 @@ where
                 ));
             }
 
+            let operation_types: Vec<String> = operations
+                .iter()
+                .map(|op| metrics::operation_type(op, &query_schema))
+                .collect();
+
             let queries = operations
                 .into_iter()
                 .map(|op| QueryGraphBuilder::new(query_schema.clone()).build(op))
                 .collect::<std::result::Result<Vec<_>, _>>()?;
 
-            let conn = self.connector.get_connection().await?;
+            let mut conn = self.primary_connection(tenant.as_deref()).await?;
+            let _active_connection = METRICS.connection_checked_out();
+            conn.set_query_tags(self.query_tags.clone().merge(query_tags));
+
             let tx = conn.start_transaction().await?;
             let mut results = Vec::with_capacity(queries.len());
 
-            for (query, info) in queries {
+            for ((query, info), operation_type) in queries.into_iter().zip(operation_types) {
                 let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
+                let start = Instant::now();
                 let result = QueryPipeline::new(query, interpreter, info).execute().await;
+                METRICS.record_operation(&operation_type, start.elapsed(), result.is_ok());
 
-                if !result.is_ok() {
-                    tx.rollback().await?;
-                }
+                match result {
+                    Ok(response) => results.push(Ok(response)),
+                    Err(err) => {
+                        // The failing operation is what the client needs to know about, not a failing rollback.
+                        if let Err(rollback_err) = tx.rollback().await {
+                            error!("Rolling back the transactional batch failed: {}", rollback_err);
+                        }
 
-                results.push(Ok(result?));
+                        return Err(err);
+                    }
+                }
             }
 
             tx.commit().await?;
@@ -111,12 +210,17 @@ where
             let mut futures = Vec::with_capacity(operations.len());
 
             for operation in operations {
-                let conn = self.connector.get_connection().await?;
+                let conn = self
+                    .connection_for(&operation, read_preference, tenant.as_deref())
+                    .await?;
+                let operation_query_tags = self.operation_query_tags(&operation, &query_tags);
+
                 futures.push(tokio::spawn(Self::execute_single_operation(
                     operation,
                     conn,
                     self.force_transactions,
                     query_schema.clone(),
+                    operation_query_tags,
                 )));
             }
 
@@ -131,9 +235,64 @@ where
     }
 
     /// Executes a single operation. Execution will be inside of a transaction or not depending on the needs of the query.
-    async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<ResponseData> {
-        let conn = self.connector.get_connection().await?;
-        Self::execute_single_operation(operation, conn, self.force_transactions, query_schema.clone()).await
+    async fn execute(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        query_tags: QueryTags,
+        read_preference: ReadPreference,
+        tenant: Option<String>,
+    ) -> crate::Result<ResponseData> {
+        let conn = self
+            .connection_for(&operation, read_preference, tenant.as_deref())
+            .await?;
+        let query_tags = self.operation_query_tags(&operation, &query_tags);
+
+        Self::execute_single_operation(
+            operation,
+            conn,
+            self.force_transactions,
+            query_schema.clone(),
+            query_tags,
+        )
+        .await
+    }
+
+    async fn start_tx(&self, timeout: Duration, query_tags: QueryTags, tenant: Option<String>) -> crate::Result<TxId> {
+        // Interactive transactions are currently experimental
+        if !feature_flags::get().interactiveTransactions {
+            return Err(crate::CoreError::UnsupportedFeatureError(
+                "Interactive transactions (experimental feature, needs to be enabled).".to_owned(),
+            ));
+        }
+
+        let conn = self.primary_connection(tenant.as_deref()).await?;
+        let query_tags = self.query_tags.clone().merge(query_tags);
+
+        self.open_transactions.start(conn, query_tags, timeout).await
+    }
+
+    async fn execute_in_tx(
+        &self,
+        tx_id: &TxId,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        query_tags: QueryTags,
+    ) -> crate::Result<ResponseData> {
+        // The configured tags are part of the ones of the transaction already.
+        let query_tags = query_tags.with("action", operation.name());
+
+        self.open_transactions
+            .execute(tx_id, operation, query_schema, query_tags)
+            .await
+    }
+
+    async fn commit_tx(&self, tx_id: &TxId) -> crate::Result<()> {
+        self.open_transactions.commit(tx_id).await
+    }
+
+    async fn rollback_tx(&self, tx_id: &TxId) -> crate::Result<()> {
+        self.open_transactions.rollback(tx_id).await
     }
 
     fn primary_connector(&self) -> &dyn Connector {
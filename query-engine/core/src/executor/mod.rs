@@ -5,19 +5,46 @@
 //!
 //! What the executor module DOES NOT DO:
 //! - Define low level execution of queries. This is considered an implementation detail of the modules used by the executors.
+mod interactive_transactions;
 mod interpreting_executor;
 mod pipeline;
 
+pub use interactive_transactions::{TxId, MAX_OPEN_TRANSACTIONS};
 pub use interpreting_executor::*;
 
 use crate::{query_document::Operation, response_ir::ResponseData, schema::QuerySchemaRef};
 use async_trait::async_trait;
-use connector::Connector;
+use connector::{Connector, QueryTags};
+use std::time::Duration;
+
+/// Where the operations that only read run, for connectors with read replicas.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReadPreference {
+    /// On a read replica. Replicas may lag behind the primary, reads may not see recent writes.
+    Replica,
+    /// On the primary, with the writes.
+    Primary,
+}
+
+impl Default for ReadPreference {
+    fn default() -> Self {
+        ReadPreference::Replica
+    }
+}
 
 #[async_trait]
 pub trait QueryExecutor {
     /// Executes a single operation and returns its result.
-    async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<ResponseData>;
+    /// The `query_tags` of the request are attached to the queries of the operation. Operations of a `tenant`
+    /// run on its schema or database.
+    async fn execute(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        query_tags: QueryTags,
+        read_preference: ReadPreference,
+        tenant: Option<String>,
+    ) -> crate::Result<ResponseData>;
 
     // Executes a batch of operations as either a fanout of individual operations (non-transactional), or in series (transactional).
     async fn execute_batch(
@@ -25,7 +52,33 @@ pub trait QueryExecutor {
         operations: Vec<Operation>,
         transactional: bool,
         query_schema: QuerySchemaRef,
+        query_tags: QueryTags,
+        read_preference: ReadPreference,
+        tenant: Option<String>,
     ) -> crate::Result<Vec<crate::Result<ResponseData>>>;
 
+    /// Starts an interactive transaction on a connection of its own and returns its id.
+    /// The transaction is rolled back if it sees no operation, commit or rollback for `timeout`.
+    /// Fails if `MAX_OPEN_TRANSACTIONS` transactions are open already.
+    /// The `query_tags` are attached to all queries of the transaction, which runs on the schema or database of
+    /// the `tenant`, if any.
+    async fn start_tx(&self, timeout: Duration, query_tags: QueryTags, tenant: Option<String>) -> crate::Result<TxId>;
+
+    /// Executes a single operation in the interactive transaction `tx_id`. The `query_tags` of the request are
+    /// attached to the queries of the operation, next to the ones of the transaction.
+    async fn execute_in_tx(
+        &self,
+        tx_id: &TxId,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        query_tags: QueryTags,
+    ) -> crate::Result<ResponseData>;
+
+    /// Commits the interactive transaction `tx_id`.
+    async fn commit_tx(&self, tx_id: &TxId) -> crate::Result<()>;
+
+    /// Rolls back the interactive transaction `tx_id`.
+    async fn rollback_tx(&self, tx_id: &TxId) -> crate::Result<()>;
+
     fn primary_connector(&self) -> &dyn Connector;
 }
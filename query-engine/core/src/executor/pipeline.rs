@@ -1,4 +1,5 @@
 use crate::{Env, Expression, Expressionista, IrSerializer, QueryInterpreter, QueryType, ResponseData};
+use tracing_futures::Instrument;
 
 pub struct QueryPipeline<'conn, 'tx> {
     query: QueryType,
@@ -16,6 +17,12 @@ impl<'conn, 'tx> QueryPipeline<'conn, 'tx> {
     }
 
     pub async fn execute(self) -> crate::Result<ResponseData> {
+        let span = info_span!("Execute");
+
+        self.execute_inner().instrument(span).await
+    }
+
+    async fn execute_inner(self) -> crate::Result<ResponseData> {
         let serializer = self.serializer;
 
         match self.query {
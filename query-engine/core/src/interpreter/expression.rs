@@ -1,5 +1,5 @@
 use super::{Env, ExpressionResult, InterpretationResult};
-use crate::{Query, RawQueryType, WriteQuery};
+use crate::{query_graph::SavepointFailure, Query, RawQueryType, WriteQuery};
 use prisma_value::PrismaValue;
 
 pub enum Expression {
@@ -37,6 +37,13 @@ pub enum Expression {
     Return {
         result: ExpressionResult,
     },
+
+    /// Executes the expression inside of a savepoint, allowing a partial rollback
+    /// of the changes made by the expression if it fails.
+    Savepoint {
+        expression: Box<Expression>,
+        on_failure: SavepointFailure,
+    },
 }
 
 impl Expression {
@@ -54,6 +54,7 @@ impl Expressionista {
         let mut child_expressions = Self::process_children(graph, direct_children)?;
 
         let is_result = graph.is_result_node(&node);
+        let savepoint = graph.savepoint(&node);
         let node_id = node.id();
         let node = graph.pluck_node(&node);
         let into_expr = Box::new(|node: Node| {
@@ -63,6 +64,16 @@ impl Expressionista {
 
         let expr = Self::transform_node(graph, parent_edges, node, into_expr)?;
 
+        // Only the query itself runs inside of the savepoint: the children see its result, or the result of the
+        // failure handling, in the binding of the node.
+        let expr = match savepoint {
+            Some(on_failure) => Expression::Savepoint {
+                expression: Box::new(expr),
+                on_failure,
+            },
+            None => expr,
+        };
+
         if child_expressions.is_empty() {
             Ok(expr)
         } else {
@@ -45,6 +45,11 @@ pub fn format_expression(expr: &Expression, indent: usize) -> String {
             else_: _,
         } => add_indent(indent, "if (Fn env)"),
         Expression::Return { result } => add_indent(indent, format!("Return {:?}", result)),
+        Expression::Savepoint { expression, on_failure } => format!(
+            "{}\n{}",
+            add_indent(indent, format!("(Savepoint {:?})", on_failure)),
+            format_expression(expression, indent + 2)
+        ),
     }
 }
 
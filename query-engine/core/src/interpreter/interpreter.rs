@@ -3,12 +3,13 @@ use super::{
     query_interpreters::{read, write},
     InterpretationResult, InterpreterError,
 };
-use crate::{Query, QueryResult};
-use connector::ConnectionLike;
+use crate::{query_graph::SavepointFailure, Query, QueryResult};
+use connector::{error::ErrorKind, ConnectionLike};
 use crossbeam_queue::SegQueue;
 use futures::future::{BoxFuture, FutureExt};
 use im::HashMap;
 use prisma_models::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 #[derive(Debug, Clone)]
 pub enum ExpressionResult {
@@ -124,6 +125,9 @@ impl Env {
 pub struct QueryInterpreter<'conn, 'tx> {
     pub(crate) conn: ConnectionLike<'conn, 'tx>,
     log: SegQueue<String>,
+
+    /// Counter used to generate unique savepoint names within the transaction.
+    savepoint_counter: AtomicUsize,
 }
 
 impl<'conn, 'tx> QueryInterpreter<'conn, 'tx>
@@ -141,7 +145,11 @@ where
             log.push("\n".to_string());
         }
 
-        Self { conn, log }
+        Self {
+            conn,
+            log,
+            savepoint_counter: AtomicUsize::new(0),
+        }
     }
 
     pub fn interpret(
@@ -270,6 +278,54 @@ where
                 Ok(result)
             }
             .boxed(),
+
+            Expression::Savepoint { expression, on_failure } => {
+                let fut = async move {
+                    let tx = match self.conn {
+                        ConnectionLike::Transaction(tx) if tx.supports_savepoints() => tx,
+
+                        // Without savepoints the expression is executed as-is, failures abort the whole transaction.
+                        _ => return self.interpret(*expression, env, level).await,
+                    };
+
+                    let name = format!("savepoint_{}", self.savepoint_counter.fetch_add(1, Ordering::SeqCst));
+
+                    self.log_line(level, || format!("SAVEPOINT {}", name));
+                    tx.create_savepoint(&name).await?;
+
+                    match self.interpret(*expression, env, level + 1).await {
+                        Ok(result) => {
+                            tx.release_savepoint(&name).await?;
+                            Ok(result)
+                        }
+                        Err(err) => {
+                            self.log_line(level, || format!("ROLLBACK TO SAVEPOINT {}", name));
+                            tx.rollback_to_savepoint(&name).await?;
+
+                            match (on_failure, &err) {
+                                (
+                                    SavepointFailure::ReadOnUniqueViolation(read),
+                                    InterpreterError::ConnectorError(connector_err),
+                                ) if matches!(connector_err.kind, ErrorKind::UniqueConstraintViolation { .. }) => {
+                                    self.log_line(level, || format!("READ {}", read));
+
+                                    match read::execute(&self.conn, read, None).await? {
+                                        QueryResult::RecordSelection(rs) if !rs.scalars.records.is_empty() => {
+                                            Ok(ExpressionResult::Query(QueryResult::RecordSelection(rs)))
+                                        }
+
+                                        // The violated constraint is not the one the record was looked up by.
+                                        _ => Err(err),
+                                    }
+                                }
+                                _ => Err(err),
+                            }
+                        }
+                    }
+                };
+
+                fut.boxed()
+            }
         }
     }
 
@@ -30,6 +30,20 @@ impl InMemoryRecordProcessor {
         processor
     }
 
+    /// Checks whether the pagination of related records can happen in the data source, for every parent on its own.
+    /// Cursors, distinct and negative `take`s are only processed in memory.
+    pub fn can_paginate_per_parent(&self) -> bool {
+        (self.take.is_some() || self.skip.is_some())
+            && !self.needs_reversed_order()
+            && self.cursor.is_none()
+            && self.distinct.is_none()
+    }
+
+    /// The original query arguments, with pagination and distinct.
+    pub fn args(&self) -> &QueryArguments {
+        &self.args
+    }
+
     fn take_abs(&self) -> Option<i64> {
         self.take.clone().map(|t| if t < 0 { t * -1 } else { t })
     }
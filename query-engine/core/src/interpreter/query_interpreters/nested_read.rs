@@ -44,7 +44,8 @@ pub async fn m2m<'a, 'b>(
         .collect::<std::result::Result<Vec<_>, _>>()?;
 
     // a roundtrip can be avoided if: there is no additional filter AND the selection set is the child_link_id
-    let mut scalars = if query.args.do_nothing() && child_link_id == query.selected_fields {
+    let only_ids = child_link_id == query.selected_fields && query.relation_counts.is_empty();
+    let mut scalars = if query.args.do_nothing() && only_ids {
         ManyRecords::from_projection(child_ids, &query.selected_fields)
     } else {
         let mut args = query.args.clone();
@@ -54,8 +55,13 @@ pub async fn m2m<'a, 'b>(
             Some(existing_filter) => Some(Filter::and(vec![existing_filter, filter])),
             None => Some(filter),
         };
-        tx.get_many_records(&query.parent_field.related_model(), args, &query.selected_fields)
-            .await?
+        tx.get_many_records(
+            &query.parent_field.related_model(),
+            args,
+            &query.selected_fields,
+            &query.relation_counts,
+        )
+        .await?
     };
 
     // Child id to parent ids
@@ -114,6 +120,7 @@ pub async fn one2m<'a, 'b>(
     parent_result: Option<&'a ManyRecords>,
     query_args: QueryArguments,
     selected_fields: &ModelProjection,
+    relation_counts: &[RelationFieldRef],
     processor: InMemoryRecordProcessor,
 ) -> InterpretationResult<ManyRecords> {
     let parent_model_id = parent_field.model().primary_identifier();
@@ -164,8 +171,19 @@ pub async fn one2m<'a, 'b>(
         return Ok(ManyRecords::empty(selected_fields));
     }
 
+    // The related records can be paginated for every parent in the data source if the children hold the link.
+    let paginate_per_parent = processor.can_paginate_per_parent()
+        && relation_counts.is_empty()
+        && parent_field.related_field().is_inlined_on_enclosing_model()
+        && child_link_id
+            .scalar_fields()
+            .all(|link| selected_fields.scalar_fields().any(|field| field.name == link.name));
+
+    let mut paginated = false;
+
     // a roundtrip can be avoided if: there is no additional filter AND the selection set is the child_link_id
-    let mut scalars = if query_args.do_nothing() && &child_link_id == selected_fields {
+    let only_links = &child_link_id == selected_fields && relation_counts.is_empty();
+    let mut scalars = if query_args.do_nothing() && only_links {
         ManyRecords::from_projection(uniq_projections, selected_fields)
     } else {
         let filter = child_link_id.is_in(uniq_projections);
@@ -175,8 +193,32 @@ pub async fn one2m<'a, 'b>(
             Some(existing_filter) => Some(Filter::and(vec![existing_filter, filter])),
             None => Some(filter),
         };
-        tx.get_many_records(&parent_field.related_model(), args, selected_fields)
+
+        let partitioned = if paginate_per_parent {
+            let mut paginated_args = processor.args().clone();
+            paginated_args.filter = args.filter.clone();
+
+            tx.get_many_records_per_partition(
+                &parent_field.related_model(),
+                paginated_args,
+                &child_link_id,
+                selected_fields,
+            )
             .await?
+        } else {
+            None
+        };
+
+        match partitioned {
+            Some(records) => {
+                paginated = true;
+                records
+            }
+            None => {
+                tx.get_many_records(&parent_field.related_model(), args, selected_fields, relation_counts)
+                    .await?
+            }
+        }
     };
 
     // Inlining is done on the parent, this means that we need to write the primary parent ID
@@ -225,5 +267,9 @@ pub async fn one2m<'a, 'b>(
         ));
     }
 
-    Ok(processor.apply(scalars))
+    if paginated {
+        Ok(scalars)
+    } else {
+        Ok(processor.apply(scalars))
+    }
 }
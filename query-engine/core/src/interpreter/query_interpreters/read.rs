@@ -16,6 +16,8 @@ pub fn execute<'a, 'b>(
             ReadQuery::ManyRecordsQuery(q) => read_many(tx, q).await,
             ReadQuery::RelatedRecordsQuery(q) => read_related(tx, q, parent_result).await,
             ReadQuery::AggregateRecordsQuery(q) => aggregate(tx, q).await,
+            ReadQuery::CountRecordsQuery(q) => count(tx, q).await,
+            ReadQuery::GroupByRecordsQuery(q) => group_by(tx, q).await,
         }
     };
 
@@ -31,7 +33,9 @@ fn read_one<'conn, 'tx>(
         let model = query.model;
         let model_id = model.primary_identifier();
         let filter = query.filter.expect("Expected filter to be set for ReadOne query.");
-        let scalars = tx.get_single_record(&model, &filter, &query.selected_fields).await?;
+        let scalars = tx
+            .get_single_record(&model, &filter, &query.selected_fields, &query.relation_counts)
+            .await?;
 
         match scalars {
             Some(record) => {
@@ -63,29 +67,39 @@ fn read_one<'conn, 'tx>(
 }
 
 /// Queries a set of records.
-/// If the query specifies distinct, we need to lift up pagination (and distinct) processing to the core with in-memory record processing.
-/// -> Distinct can't be processed in the DB with our current query API model.
-///    We need to select IDs / uniques alongside the distincts, which doesn't work in SQL, as all records
-///    are distinct by definition if a unique is in the selection set.
+/// If the query specifies distinct that the datasource can't process, we need to lift up pagination (and distinct) processing
+/// to the core with in-memory record processing.
+/// -> The datasource only keeps the first record of every distinct group if that is the record with the lowest or highest ID,
+///    as the IDs are selected alongside the distincts and all records are distinct by definition if a unique is in the selection set.
 /// -> Unstable cursors can't reliably be fetched by the underlying datasource, so we need to process part of it in-memory.
 fn read_many<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     mut query: ManyRecordsQuery,
 ) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
     let fut = async move {
-        let scalars = if query.args.distinct.is_some()
+        let scalars = if (query.args.distinct.is_some() && !query.args.can_distinct_in_datasource())
             || query.args.contains_unstable_cursor()
             || query.args.contains_null_cursor()
         {
             let processor = InMemoryRecordProcessor::new_from_query_args(&mut query.args);
             let scalars = tx
-                .get_many_records(&query.model, query.args.clone(), &query.selected_fields)
+                .get_many_records(
+                    &query.model,
+                    query.args.clone(),
+                    &query.selected_fields,
+                    &query.relation_counts,
+                )
                 .await?;
 
             processor.apply(scalars)
         } else {
-            tx.get_many_records(&query.model, query.args.clone(), &query.selected_fields)
-                .await?
+            tx.get_many_records(
+                &query.model,
+                query.args.clone(),
+                &query.selected_fields,
+                &query.relation_counts,
+            )
+            .await?
         };
 
         let model_id = query.model.primary_identifier();
@@ -125,6 +139,7 @@ fn read_related<'a, 'b>(
                 parent_result,
                 query.args.clone(),
                 &query.selected_fields,
+                &query.relation_counts,
                 processor,
             )
             .await?
@@ -162,6 +177,27 @@ async fn aggregate<'a, 'b>(
     }))
 }
 
+async fn count<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, query: CountRecordsQuery) -> InterpretationResult<QueryResult> {
+    let count = tx.count_records(&query.model, query.args, query.estimate).await?;
+
+    Ok(QueryResult::RecordCount(count))
+}
+
+async fn group_by<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    query: GroupByRecordsQuery,
+) -> InterpretationResult<QueryResult> {
+    let selection_order = query.selection_order;
+    let groups = tx
+        .group_by_records(&query.model, query.group_by, query.aggregators, query.args)
+        .await?;
+
+    Ok(QueryResult::RecordGroups(RecordGroups {
+        selection_order,
+        groups,
+    }))
+}
+
 fn process_nested<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     nested: Vec<ReadQuery>,
@@ -4,6 +4,7 @@ use crate::{
     QueryResult, RawQueryType,
 };
 use connector::{ConnectionLike, WriteOperations};
+use prisma_models::RecordProjection;
 use prisma_value::PrismaValue;
 
 pub async fn execute<'a, 'b>(
@@ -16,6 +17,7 @@ pub async fn execute<'a, 'b>(
         WriteQuery::DeleteRecord(q) => delete_one(tx, q).await,
         WriteQuery::UpdateManyRecords(q) => update_many(tx, q).await,
         WriteQuery::DeleteManyRecords(q) => delete_many(tx, q).await,
+        WriteQuery::UpsertManyRecords(q) => upsert_many(tx, q).await,
         WriteQuery::ConnectRecords(q) => connect(tx, q).await,
         WriteQuery::DisconnectRecords(q) => disconnect(tx, q).await,
         WriteQuery::Raw {
@@ -73,7 +75,7 @@ async fn delete_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: DeleteRecord) ->
 
     let res = tx.delete_records(&q.model, filter).await?;
 
-    Ok(QueryResult::Count(res))
+    Ok(QueryResult::Count(res.len()))
 }
 
 async fn update_many<'a, 'b>(
@@ -82,7 +84,7 @@ async fn update_many<'a, 'b>(
 ) -> InterpretationResult<QueryResult> {
     let res = tx.update_records(&q.model, q.record_filter, q.args).await?;
 
-    Ok(QueryResult::Count(res.len()))
+    Ok(batch_payload(res, q.return_ids))
 }
 
 async fn delete_many<'a, 'b>(
@@ -91,6 +93,23 @@ async fn delete_many<'a, 'b>(
 ) -> InterpretationResult<QueryResult> {
     let res = tx.delete_records(&q.model, q.record_filter).await?;
 
+    Ok(batch_payload(res, q.return_ids))
+}
+
+fn batch_payload(ids: Vec<RecordProjection>, return_ids: bool) -> QueryResult {
+    if return_ids {
+        QueryResult::AffectedIds(ids)
+    } else {
+        QueryResult::Count(ids.len())
+    }
+}
+
+async fn upsert_many<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    q: UpsertManyRecords,
+) -> InterpretationResult<QueryResult> {
+    let res = tx.upsert_records(&q.model, q.args, &q.conflict_target).await?;
+
     Ok(QueryResult::Count(res))
 }
 
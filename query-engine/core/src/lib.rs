@@ -3,9 +3,11 @@
 #[macro_use]
 extern crate tracing;
 
+pub mod authorization;
 pub mod error;
 pub mod executor;
 pub mod interpreter;
+pub mod metrics;
 pub mod query_ast;
 pub mod query_document;
 pub mod query_graph;
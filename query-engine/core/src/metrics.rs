@@ -0,0 +1,280 @@
+//! Metrics of the query engine, for operators: how many operations of each type ran, how long they took, and
+//! how many database connections are in use. They are process-wide, and rendered either in the Prometheus text
+//! format or as JSON.
+
+use crate::{Operation, QuerySchema, SchemaQueryBuilder};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+/// The upper bounds of the buckets of the latency histograms, in seconds.
+const LATENCY_BUCKETS: &[f64] = &[0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// The metrics of the process.
+pub static METRICS: Lazy<Metrics> = Lazy::new(Metrics::default);
+
+#[derive(Debug, Default)]
+pub struct Metrics {
+    operations: Mutex<BTreeMap<String, OperationMetrics>>,
+    active_connections: AtomicU64,
+}
+
+/// The metrics of the operations of one type, e.g. `findMany`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationMetrics {
+    /// The number of operations executed.
+    pub count: u64,
+    /// The number of operations that failed.
+    pub errors: u64,
+    /// The total time spent executing the operations, in seconds.
+    pub duration_seconds_sum: f64,
+    /// The latency histogram. The counts are cumulative, as in Prometheus.
+    pub duration_buckets: Vec<HistogramBucket>,
+}
+
+impl Default for OperationMetrics {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            errors: 0,
+            duration_seconds_sum: 0.0,
+            duration_buckets: LATENCY_BUCKETS
+                .iter()
+                .map(|le| HistogramBucket { le: *le, count: 0 })
+                .collect(),
+        }
+    }
+}
+
+/// The number of operations that took at most `le` seconds.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HistogramBucket {
+    pub le: f64,
+    pub count: u64,
+}
+
+/// The metrics at a point in time, as served in JSON.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricsSnapshot {
+    /// The operations by type.
+    pub operations: BTreeMap<String, OperationMetrics>,
+    /// The connections of the pool checked out by the engine, for operations or open transactions.
+    pub active_connections: u64,
+}
+
+/// Counts a connection as active for as long as it is alive.
+#[derive(Debug)]
+pub struct ActiveConnection<'a> {
+    metrics: &'a Metrics,
+}
+
+impl Drop for ActiveConnection<'_> {
+    fn drop(&mut self) {
+        self.metrics.active_connections.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl Metrics {
+    /// Records an executed operation of the given type.
+    pub fn record_operation(&self, operation_type: &str, duration: Duration, success: bool) {
+        let seconds = duration.as_secs_f64();
+        let mut operations = self.operations.lock().unwrap();
+        let metrics = operations.entry(operation_type.to_owned()).or_default();
+
+        metrics.count += 1;
+        metrics.duration_seconds_sum += seconds;
+
+        if !success {
+            metrics.errors += 1;
+        }
+
+        for bucket in metrics
+            .duration_buckets
+            .iter_mut()
+            .filter(|bucket| seconds <= bucket.le)
+        {
+            bucket.count += 1;
+        }
+    }
+
+    /// Counts a connection checked out of the pool as active, until the returned guard is dropped.
+    pub fn connection_checked_out(&self) -> ActiveConnection<'_> {
+        self.active_connections.fetch_add(1, Ordering::Relaxed);
+
+        ActiveConnection { metrics: self }
+    }
+
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            operations: self.operations.lock().unwrap().clone(),
+            active_connections: self.active_connections.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Renders the metrics in the Prometheus text exposition format.
+    pub fn render_prometheus(&self) -> String {
+        let snapshot = self.snapshot();
+        let mut out = String::new();
+
+        writeln!(out, "# HELP prisma_operations_total The operations executed, by type.").unwrap();
+        writeln!(out, "# TYPE prisma_operations_total counter").unwrap();
+
+        for (operation, metrics) in &snapshot.operations {
+            writeln!(
+                out,
+                "prisma_operations_total{{operation=\"{}\"}} {}",
+                operation, metrics.count
+            )
+            .unwrap();
+        }
+
+        writeln!(
+            out,
+            "# HELP prisma_operation_errors_total The operations that failed, by type."
+        )
+        .unwrap();
+        writeln!(out, "# TYPE prisma_operation_errors_total counter").unwrap();
+
+        for (operation, metrics) in &snapshot.operations {
+            writeln!(
+                out,
+                "prisma_operation_errors_total{{operation=\"{}\"}} {}",
+                operation, metrics.errors
+            )
+            .unwrap();
+        }
+
+        writeln!(
+            out,
+            "# HELP prisma_operation_duration_seconds The time spent executing operations, by type."
+        )
+        .unwrap();
+        writeln!(out, "# TYPE prisma_operation_duration_seconds histogram").unwrap();
+
+        for (operation, metrics) in &snapshot.operations {
+            for bucket in &metrics.duration_buckets {
+                writeln!(
+                    out,
+                    "prisma_operation_duration_seconds_bucket{{operation=\"{}\",le=\"{}\"}} {}",
+                    operation, bucket.le, bucket.count
+                )
+                .unwrap();
+            }
+
+            writeln!(
+                out,
+                "prisma_operation_duration_seconds_bucket{{operation=\"{}\",le=\"+Inf\"}} {}",
+                operation, metrics.count
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "prisma_operation_duration_seconds_sum{{operation=\"{}\"}} {}",
+                operation, metrics.duration_seconds_sum
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "prisma_operation_duration_seconds_count{{operation=\"{}\"}} {}",
+                operation, metrics.count
+            )
+            .unwrap();
+        }
+
+        writeln!(
+            out,
+            "# HELP prisma_active_connections The connections of the pool checked out by the engine."
+        )
+        .unwrap();
+        writeln!(out, "# TYPE prisma_active_connections gauge").unwrap();
+        writeln!(out, "prisma_active_connections {}", snapshot.active_connections).unwrap();
+
+        out
+    }
+}
+
+/// The type of an operation: the kind of model query, e.g. `findMany` or `createOne`, and the name of the field
+/// for the other ones, e.g. `executeRaw`.
+pub fn operation_type(operation: &Operation, query_schema: &QuerySchema) -> String {
+    let field = match operation {
+        Operation::Read(_) => query_schema.find_query_field(operation.name()),
+        Operation::Write(_) => query_schema.find_mutation_field(operation.name()),
+    };
+
+    match field.as_ref().and_then(|field| field.query_builder()) {
+        Some(SchemaQueryBuilder::ModelQueryBuilder(builder)) => builder.tag.to_string(),
+        _ => operation.name().to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operations_are_counted_in_cumulative_latency_buckets() {
+        let metrics = Metrics::default();
+
+        metrics.record_operation("findMany", Duration::from_millis(3), true);
+        metrics.record_operation("findMany", Duration::from_millis(300), false);
+
+        let snapshot = metrics.snapshot();
+        let find_many = &snapshot.operations["findMany"];
+
+        assert_eq!(find_many.count, 2);
+        assert_eq!(find_many.errors, 1);
+
+        let count_at = |le: f64| {
+            find_many
+                .duration_buckets
+                .iter()
+                .find(|bucket| bucket.le == le)
+                .unwrap()
+                .count
+        };
+
+        assert_eq!(count_at(0.001), 0);
+        assert_eq!(count_at(0.005), 1);
+        assert_eq!(count_at(0.25), 1);
+        assert_eq!(count_at(0.5), 2);
+    }
+
+    #[test]
+    fn active_connections_are_counted_until_released() {
+        let metrics = Metrics::default();
+
+        let first = metrics.connection_checked_out();
+        let second = metrics.connection_checked_out();
+        assert_eq!(metrics.snapshot().active_connections, 2);
+
+        drop(first);
+        assert_eq!(metrics.snapshot().active_connections, 1);
+
+        drop(second);
+        assert_eq!(metrics.snapshot().active_connections, 0);
+    }
+
+    #[test]
+    fn metrics_are_rendered_in_the_prometheus_format() {
+        let metrics = Metrics::default();
+        metrics.record_operation("createOne", Duration::from_millis(20), true);
+
+        let rendered = metrics.render_prometheus();
+
+        assert!(rendered.contains("prisma_operations_total{operation=\"createOne\"} 1\n"));
+        assert!(rendered.contains("prisma_operation_duration_seconds_bucket{operation=\"createOne\",le=\"0.01\"} 0\n"));
+        assert!(rendered.contains("prisma_operation_duration_seconds_bucket{operation=\"createOne\",le=\"0.025\"} 1\n"));
+        assert!(rendered.contains("prisma_operation_duration_seconds_count{operation=\"createOne\"} 1\n"));
+        assert!(rendered.contains("prisma_active_connections 0\n"));
+    }
+}
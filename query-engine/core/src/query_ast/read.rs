@@ -10,6 +10,8 @@ pub enum ReadQuery {
     ManyRecordsQuery(ManyRecordsQuery),
     RelatedRecordsQuery(RelatedRecordsQuery),
     AggregateRecordsQuery(AggregateRecordsQuery),
+    CountRecordsQuery(CountRecordsQuery),
+    GroupByRecordsQuery(GroupByRecordsQuery),
 }
 
 impl ReadQuery {
@@ -19,6 +21,8 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => &x.name,
             ReadQuery::RelatedRecordsQuery(x) => &x.name,
             ReadQuery::AggregateRecordsQuery(x) => &x.name,
+            ReadQuery::CountRecordsQuery(x) => &x.name,
+            ReadQuery::GroupByRecordsQuery(x) => &x.name,
         }
     }
 
@@ -30,6 +34,8 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => x.selected_fields.contains_all_db_names(db_names),
             ReadQuery::RelatedRecordsQuery(x) => x.selected_fields.contains_all_db_names(db_names),
             ReadQuery::AggregateRecordsQuery(_x) => false,
+            ReadQuery::CountRecordsQuery(_x) => false,
+            ReadQuery::GroupByRecordsQuery(_x) => false,
         }
     }
 
@@ -39,6 +45,8 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => x.model.clone(),
             ReadQuery::RelatedRecordsQuery(x) => x.parent_field.related_field().model().clone(),
             ReadQuery::AggregateRecordsQuery(x) => x.model.clone(),
+            ReadQuery::CountRecordsQuery(x) => x.model.clone(),
+            ReadQuery::GroupByRecordsQuery(x) => x.model.clone(),
         }
     }
 }
@@ -88,6 +96,12 @@ impl Display for ReadQuery {
                 q.selected_fields.names().collect::<Vec<_>>()
             ),
             Self::AggregateRecordsQuery(q) => write!(f, "AggregateRecordsQuery: {}", q.name),
+            Self::CountRecordsQuery(q) => write!(
+                f,
+                "CountRecordsQuery(name: '{}', model: {}, args: {:?}, estimate: {})",
+                q.name, q.model.name, q.args, q.estimate
+            ),
+            Self::GroupByRecordsQuery(q) => write!(f, "GroupByRecordsQuery: {}", q.name),
         }
     }
 }
@@ -101,6 +115,9 @@ pub struct RecordQuery {
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
     pub selection_order: Vec<String>,
+
+    /// To-many relations whose related records are only counted, selected with `_count`.
+    pub relation_counts: Vec<RelationFieldRef>,
 }
 
 #[derive(Debug, Clone)]
@@ -112,6 +129,9 @@ pub struct ManyRecordsQuery {
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
     pub selection_order: Vec<String>,
+
+    /// To-many relations whose related records are only counted, selected with `_count`.
+    pub relation_counts: Vec<RelationFieldRef>,
 }
 
 #[derive(Debug, Clone)]
@@ -124,6 +144,9 @@ pub struct RelatedRecordsQuery {
     pub nested: Vec<ReadQuery>,
     pub selection_order: Vec<String>,
 
+    /// To-many relations whose related records are only counted, selected with `_count`.
+    pub relation_counts: Vec<RelationFieldRef>,
+
     /// Fields and values of the parent to satisfy the relation query without
     /// relying on the parent result passed by the interpreter.
     pub parent_projections: Option<Vec<RecordProjection>>,
@@ -139,6 +162,28 @@ pub struct AggregateRecordsQuery {
     pub aggregators: Vec<Aggregator>,
 }
 
+#[derive(Debug, Clone)]
+pub struct CountRecordsQuery {
+    pub name: String,
+    pub alias: Option<String>,
+    pub model: ModelRef,
+    pub args: QueryArguments,
+
+    /// Whether an estimate is good enough. Connectors may still count exactly.
+    pub estimate: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct GroupByRecordsQuery {
+    pub name: String,
+    pub alias: Option<String>,
+    pub model: ModelRef,
+    pub selection_order: Vec<(String, Option<Vec<String>>)>,
+    pub args: QueryArguments,
+    pub group_by: Vec<ScalarFieldRef>,
+    pub aggregators: Vec<Aggregator>,
+}
+
 impl FilteredQuery for RecordQuery {
     fn get_filter(&mut self) -> Option<&mut Filter> {
         self.filter.as_mut()
@@ -12,6 +12,7 @@ pub enum WriteQuery {
     DeleteRecord(DeleteRecord),
     UpdateManyRecords(UpdateManyRecords),
     DeleteManyRecords(DeleteManyRecords),
+    UpsertManyRecords(UpsertManyRecords),
     ConnectRecords(ConnectRecords),
     DisconnectRecords(DisconnectRecords),
     Raw {
@@ -60,6 +61,7 @@ impl WriteQuery {
             Self::DeleteRecord(_) => returns_id,
             Self::UpdateManyRecords(_) => returns_id,
             Self::DeleteManyRecords(_) => false,
+            Self::UpsertManyRecords(_) => false,
             Self::ConnectRecords(_) => false,
             Self::DisconnectRecords(_) => false,
             Self::Raw { .. } => unimplemented!(),
@@ -73,6 +75,7 @@ impl WriteQuery {
             Self::DeleteRecord(q) => Arc::clone(&q.model),
             Self::UpdateManyRecords(q) => Arc::clone(&q.model),
             Self::DeleteManyRecords(q) => Arc::clone(&q.model),
+            Self::UpsertManyRecords(q) => Arc::clone(&q.model),
             Self::ConnectRecords(q) => q.relation_field.model(),
             Self::DisconnectRecords(q) => q.relation_field.model(),
             Self::Raw { .. } => unimplemented!(),
@@ -114,6 +117,13 @@ impl std::fmt::Display for WriteQuery {
             Self::DeleteRecord(q) => write!(f, "DeleteRecord: {}, {:?}", q.model.name, q.record_filter),
             Self::UpdateManyRecords(q) => write!(f, "UpdateManyRecords(model: {}, args: {:?})", q.model.name, q.args),
             Self::DeleteManyRecords(q) => write!(f, "DeleteManyRecords: {}", q.model.name),
+            Self::UpsertManyRecords(q) => write!(
+                f,
+                "UpsertManyRecords(model: {}, records: {}, conflict target: {:?})",
+                q.model.name,
+                q.args.len(),
+                q.conflict_target.iter().map(|field| &field.name).collect::<Vec<_>>()
+            ),
             Self::ConnectRecords(_) => write!(f, "ConnectRecords"),
             Self::DisconnectRecords(_) => write!(f, "DisconnectRecords"),
             Self::Raw {
@@ -143,6 +153,9 @@ pub struct UpdateManyRecords {
     pub model: ModelRef,
     pub record_filter: RecordFilter,
     pub args: WriteArgs,
+
+    /// Whether the result holds the identifiers of the updated records, instead of their count only.
+    pub return_ids: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -155,6 +168,18 @@ pub struct DeleteRecord {
 pub struct DeleteManyRecords {
     pub model: ModelRef,
     pub record_filter: RecordFilter,
+
+    /// Whether the result holds the identifiers of the deleted records, instead of their count only.
+    pub return_ids: bool,
+}
+
+/// Inserts the records, updating the existing records that conflict with them on the
+/// `conflict_target` fields instead.
+#[derive(Debug, Clone)]
+pub struct UpsertManyRecords {
+    pub model: ModelRef,
+    pub args: Vec<WriteArgs>,
+    pub conflict_target: Vec<ScalarFieldRef>,
 }
 
 #[derive(Debug, Clone)]
@@ -48,6 +48,7 @@ pub enum QueryParserErrorKind {
     AssertionError(String),
     RequiredValueNotSetError,
     FieldNotFoundError,
+    ReadOnlyFieldError,
     ArgumentNotFoundError,
     FieldCountError(FieldCountError),
     ValueParseError(String),
@@ -61,6 +62,7 @@ impl Display for QueryParserErrorKind {
             Self::AssertionError(reason) => write!(f, "Assertion error: {}.", reason),
             Self::RequiredValueNotSetError => write!(f, "A value is required but not set."),
             Self::FieldNotFoundError => write!(f, "Field does not exist on enclosing type."),
+            Self::ReadOnlyFieldError => write!(f, "Field is read-only, its value is computed by the database."),
             Self::ArgumentNotFoundError => write!(f, "Argument does not exist on enclosing type."),
             Self::FieldCountError(err) => write!(f, "{}", err),
             Self::ValueParseError(reason) => write!(f, "Error parsing value: {}.", reason),
@@ -233,10 +233,25 @@ impl QueryDocumentParser {
             (QueryValue::String(s), ScalarType::UUID) => {
                 Self::parse_uuid(parent_path, s.as_str()).map(PrismaValue::Uuid)
             }
+            (QueryValue::String(s), ScalarType::Decimal) => {
+                Self::parse_decimal(parent_path, s.as_str()).map(PrismaValue::Float)
+            }
+            (QueryValue::String(s), ScalarType::Bytes) => {
+                Self::parse_bytes(parent_path, s.as_str()).map(PrismaValue::Bytes)
+            }
+            (QueryValue::String(s), ScalarType::BigInt) => {
+                Self::parse_big_int(parent_path, s.as_str()).map(PrismaValue::Int)
+            }
             (QueryValue::Int(i), ScalarType::Float) => Ok(PrismaValue::Float(Decimal::from(i))),
+            (QueryValue::Int(i), ScalarType::Decimal) => Ok(PrismaValue::Float(Decimal::from(i))),
             (QueryValue::Int(i), ScalarType::Int) => Ok(PrismaValue::Int(i)),
+            (QueryValue::Int(i), ScalarType::BigInt) => Ok(PrismaValue::Int(i)),
             (QueryValue::Float(f), ScalarType::Float) => Ok(PrismaValue::Float(f)),
+            (QueryValue::Float(f), ScalarType::Decimal) => Ok(PrismaValue::Float(f)),
             (QueryValue::Float(f), ScalarType::Int) => Ok(PrismaValue::Int(f.to_i64().unwrap())),
+            (QueryValue::Float(f), ScalarType::BigInt) => {
+                Self::parse_big_int_from_float(parent_path, f).map(PrismaValue::Int)
+            }
             (QueryValue::Boolean(b), ScalarType::Boolean) => Ok(PrismaValue::Boolean(b)),
 
             // All other combinations are value type mismatches.
@@ -293,10 +308,45 @@ impl QueryDocumentParser {
         })
     }
 
+    pub fn parse_decimal(path: &QueryPath, s: &str) -> QueryParserResult<Decimal> {
+        s.parse().map_err(|err| QueryParserError {
+            path: path.clone(),
+            error_kind: QueryParserErrorKind::ValueParseError(format!("Invalid Decimal `{}`: {}", s, err)),
+        })
+    }
+
+    pub fn parse_big_int(path: &QueryPath, s: &str) -> QueryParserResult<i64> {
+        s.parse().map_err(|err| QueryParserError {
+            path: path.clone(),
+            error_kind: QueryParserErrorKind::ValueParseError(format!("Invalid BigInt `{}`: {}", s, err)),
+        })
+    }
+
+    /// Floats are only accepted as BigInts if they are whole numbers within the 64 bit range.
+    pub fn parse_big_int_from_float(path: &QueryPath, f: Decimal) -> QueryParserResult<i64> {
+        match f.to_i64() {
+            Some(i) if f.fract().is_zero() => Ok(i),
+            _ => Err(QueryParserError {
+                path: path.clone(),
+                error_kind: QueryParserErrorKind::ValueParseError(format!(
+                    "Invalid BigInt `{}`: not a whole number within the 64 bit range",
+                    f
+                )),
+            }),
+        }
+    }
+
+    pub fn parse_bytes(path: &QueryPath, s: &str) -> QueryParserResult<Vec<u8>> {
+        base64::decode(s).map_err(|err| QueryParserError {
+            path: path.clone(),
+            error_kind: QueryParserErrorKind::ValueParseError(format!("Invalid base64 in Bytes `{}`: {}", s, err)),
+        })
+    }
+
     pub fn parse_uuid(path: &QueryPath, s: &str) -> QueryParserResult<Uuid> {
         Uuid::parse_str(s).map_err(|err| QueryParserError {
             path: path.clone(),
-            error_kind: QueryParserErrorKind::ValueParseError(format!("Invalid UUID: {}", err)),
+            error_kind: QueryParserErrorKind::ValueParseError(format!("Invalid UUID `{}`: {}", s, err)),
         })
     }
 
@@ -409,6 +459,11 @@ impl QueryDocumentParser {
                         Some(field) => Self::parse_input_value(path.add(field.name.clone()), v, &field.field_types)
                             .map(|parsed| (k, parsed)),
 
+                        None if schema_object.is_read_only_field(&k) => Err(QueryParserError {
+                            path: path.add(k),
+                            error_kind: QueryParserErrorKind::ReadOnlyFieldError,
+                        }),
+
                         None => Err(QueryParserError {
                             path: path.add(k),
                             error_kind: QueryParserErrorKind::FieldNotFoundError,
@@ -55,6 +55,7 @@ impl From<PrismaValue> for QueryValue {
             PrismaValue::Null => Self::Null,
             PrismaValue::Uuid(u) => Self::String(u.to_hyphenated().to_string()),
             PrismaValue::Json(s) => Self::String(s),
+            PrismaValue::Bytes(b) => Self::String(base64::encode(&b)),
         }
     }
 }
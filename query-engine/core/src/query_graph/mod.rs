@@ -79,6 +79,17 @@ impl Computation {
     }
 }
 
+/// Determines how a failure of a query executed inside of a savepoint is handled. Only queries with a way to recover
+/// from their failure get a savepoint: rolling back to it only to fail anyway would abort the transaction all the same.
+#[derive(Debug, Clone)]
+pub enum SavepointFailure {
+    /// Rolls back to the savepoint and runs the read instead, if the failure was caused by a unique
+    /// constraint violation (e.g. a concurrent insert of the same record). The record selection read
+    /// stands in for the result of the query, so that the children of the node continue with the
+    /// conflicting record. Any other error, or a read finding no record, propagates the original error.
+    ReadOnUniqueViolation(ReadQuery),
+}
+
 pub struct DiffNode {
     pub left: HashSet<RecordProjection>,
     pub right: HashSet<RecordProjection>,
@@ -174,6 +185,9 @@ pub struct QueryGraph {
     /// More docs can be found on `swap_marked`.
     marked_node_pairs: Vec<(NodeRef, NodeRef)>,
 
+    /// Nodes whose subgraph is executed inside of a savepoint, if the connector supports it.
+    savepoints: Vec<(NodeIndex, SavepointFailure)>,
+
     finalized: bool,
 
     /// For now a stupid marker if the query graph needs to be run inside a
@@ -231,6 +245,20 @@ impl QueryGraph {
         self.result_nodes.push(node.node_ix.clone());
     }
 
+    /// Marks the query of `node` to be executed inside of a savepoint, its children run after the savepoint
+    /// has been released or rolled back to. If the connector doesn't support savepoints, the query is executed as usual.
+    pub fn add_savepoint(&mut self, node: &NodeRef, on_failure: SavepointFailure) {
+        self.savepoints.push((node.node_ix, on_failure));
+    }
+
+    /// Returns the savepoint failure handling of the given node, if its query is executed inside of a savepoint.
+    pub fn savepoint(&self, node: &NodeRef) -> Option<SavepointFailure> {
+        self.savepoints
+            .iter()
+            .find(|(ix, _)| ix.index() == node.node_ix.index())
+            .map(|(_, on_failure)| on_failure.clone())
+    }
+
     pub fn mark_visited(&mut self, node: &NodeRef) {
         if !self.visited.contains(&node.node_ix) {
             trace!("Visited: {}", node.id());
@@ -673,6 +701,7 @@ impl QueryGraph {
                 selected_fields: ModelProjection::union(identifiers),
                 nested: vec![],
                 selection_order: vec![],
+                relation_counts: vec![],
             });
 
             let query = Query::Read(read_query);
@@ -62,6 +62,9 @@ impl QueryGraphBuilder {
 
     /// Maps an operation to a query.
     pub fn build(self, operation: Operation) -> QueryGraphBuilderResult<(QueryType, IrSerializer)> {
+        let span = info_span!("BuildQueryGraph", operation = operation.name());
+        let _guard = span.enter();
+
         match operation {
             Operation::Read(selection) => self.map_read_operation(selection),
             Operation::Write(selection) => self.map_write_operation(selection),
@@ -39,6 +39,11 @@ pub enum QueryGraphBuilderError {
 
     RecordNotFound(String),
 
+    /// A record with the same values for the given unique fields already exists.
+    UniqueConstraintViolation {
+        fields: Vec<String>,
+    },
+
     QueryGraphError(QueryGraphError),
 }
 
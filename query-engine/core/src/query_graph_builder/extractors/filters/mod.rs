@@ -21,6 +21,8 @@ pub fn extract_unique_filter(value_map: ParsedInputMap, model: &ModelRef) -> Que
             match model.fields().find_from_scalar(&field_name) {
                 Ok(field) => {
                     let value: PrismaValue = value.try_into()?;
+                    utils::validate_native_value(&field, &value)?;
+
                     Ok(field.equals(value))
                 }
                 Err(_) => utils::resolve_compound_field(&field_name, &model)
@@ -43,6 +45,8 @@ fn handle_compound_field(fields: Vec<ScalarFieldRef>, value: ParsedInputValue) -
         .into_iter()
         .map(|sf| {
             let pv: PrismaValue = input_map.remove(&sf.name).unwrap().try_into()?;
+            utils::validate_native_value(&sf, &pv)?;
+
             Ok(sf.equals(pv))
         })
         .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
@@ -94,16 +98,30 @@ pub fn extract_filter(value_map: ParsedInputMap, model: &ModelRef) -> QueryGraph
 /// `value` can be either a flat scalar (for shorthand filter notation) or an object (full filter syntax).
 fn extract_scalar_filters(field: &ScalarFieldRef, value: ParsedInputValue) -> QueryGraphBuilderResult<Vec<Filter>> {
     match value {
-        ParsedInputValue::Single(pv) => Ok(vec![field.equals(pv)]),
+        ParsedInputValue::Single(pv) => {
+            utils::validate_native_value(field, &pv)?;
+
+            Ok(vec![field.equals(pv)])
+        }
         ParsedInputValue::Map(mut filter_map) => {
             let mode = match filter_map.remove("mode") {
                 Some(i) => parse_query_mode(i)?,
                 None => QueryMode::Default,
             };
 
+            // Only Json filters have a path.
+            let json_path = match filter_map.remove("path") {
+                Some(i) => Some(scalar::parse_json_path(i)?),
+                None => None,
+            };
+
             let mut filters = filter_map
                 .into_iter()
-                .map(|(k, v)| scalar::parse(&k, field, v, false))
+                .map(|(k, v)| match json_path {
+                    Some(ref path) => scalar::parse_json(&k, field, path.clone(), v),
+                    None if field.is_list => scalar::parse_list(&k, field, v),
+                    None => scalar::parse(&k, field, v, false),
+                })
                 .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
 
             filters.iter_mut().for_each(|f| f.set_mode(mode.clone()));
@@ -1,10 +1,14 @@
 use super::extract_filter;
 use crate::{ParsedInputMap, ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult};
-use connector::{Filter, RelationCompare};
-use prisma_models::RelationFieldRef;
+use connector::{Filter, RelationCompare, ScalarCondition};
+use prisma_models::{PrismaValue, RelationFieldRef};
 use std::convert::TryInto;
 
 pub fn parse(filter_key: &str, field: &RelationFieldRef, input: ParsedInputValue) -> QueryGraphBuilderResult<Filter> {
+    if filter_key == "count" && field.is_list {
+        return parse_count(field, input, false);
+    }
+
     let value: Option<ParsedInputMap> = input.try_into()?;
 
     match (filter_key, value) {
@@ -25,3 +29,55 @@ pub fn parse(filter_key: &str, field: &RelationFieldRef, input: ParsedInputValue
         ))),
     }
 }
+
+/// Parses a filter on the number of related records, `count: 5` or the full filter syntax, e.g.
+/// `count: { gt: 5, not: { in: [7, 9] } }`.
+fn parse_count(field: &RelationFieldRef, input: ParsedInputValue, reverse: bool) -> QueryGraphBuilderResult<Filter> {
+    match input {
+        ParsedInputValue::Single(value) if reverse => Ok(field.related_count(ScalarCondition::NotEquals(value))),
+        ParsedInputValue::Single(value) => Ok(field.related_count(ScalarCondition::Equals(value))),
+        _ => {
+            let object: ParsedInputMap = input.try_into()?;
+
+            let filters = object
+                .into_iter()
+                .map(|(filter_key, value)| {
+                    if filter_key == "not" {
+                        return parse_count(field, value, !reverse);
+                    }
+
+                    let value: PrismaValue = value.try_into()?;
+                    let condition = match (filter_key.as_str(), value) {
+                        ("in", PrismaValue::List(values)) if reverse => ScalarCondition::NotIn(values),
+                        ("notIn", PrismaValue::List(values)) if reverse => ScalarCondition::In(values),
+                        ("in", PrismaValue::List(values)) => ScalarCondition::In(values),
+                        ("notIn", PrismaValue::List(values)) => ScalarCondition::NotIn(values),
+
+                        ("equals", value) if reverse => ScalarCondition::NotEquals(value),
+                        ("lt", value) if reverse => ScalarCondition::GreaterThanOrEquals(value),
+                        ("gt", value) if reverse => ScalarCondition::LessThanOrEquals(value),
+                        ("lte", value) if reverse => ScalarCondition::GreaterThan(value),
+                        ("gte", value) if reverse => ScalarCondition::LessThan(value),
+
+                        ("equals", value) => ScalarCondition::Equals(value),
+                        ("lt", value) => ScalarCondition::LessThan(value),
+                        ("gt", value) => ScalarCondition::GreaterThan(value),
+                        ("lte", value) => ScalarCondition::LessThanOrEquals(value),
+                        ("gte", value) => ScalarCondition::GreaterThanOrEquals(value),
+
+                        _ => {
+                            return Err(QueryGraphBuilderError::InputError(format!(
+                                "{} is not a valid relation count filter operation",
+                                filter_key
+                            )))
+                        }
+                    };
+
+                    Ok(field.related_count(condition))
+                })
+                .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+
+            Ok(Filter::and(filters))
+        }
+    }
+}
@@ -1,5 +1,6 @@
+use super::utils::validate_native_value;
 use crate::{ParsedInputMap, ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult};
-use connector::{Filter, ScalarCompare};
+use connector::{Filter, JsonCompare, JsonFilterPath, JsonOperation, ScalarCompare, ScalarListCompare};
 use prisma_models::{PrismaValue, ScalarFieldRef};
 use std::convert::TryInto;
 
@@ -13,7 +14,10 @@ pub fn parse(
         "not" => {
             match input {
                 // Support for syntax `{ scalarField: { not: null } }` and `{ scalarField: { not: <value> } }`
-                ParsedInputValue::Single(value) => field.not_equals(value),
+                ParsedInputValue::Single(value) => {
+                    validate_native_value(field, &value)?;
+                    field.not_equals(value)
+                }
                 _ => {
                     let inner_object: ParsedInputMap = input.try_into()?;
 
@@ -28,7 +32,7 @@ pub fn parse(
         }
 
         "in" => {
-            let value: PrismaValue = input.try_into()?;
+            let value = as_field_value(field, input)?;
             match value {
                 PrismaValue::Null if reverse => field.not_equals(value),
                 PrismaValue::List(values) if reverse => field.not_in(values),
@@ -42,7 +46,7 @@ pub fn parse(
 
         "notIn" => {
             // Legacy operation
-            let value: PrismaValue = input.try_into()?;
+            let value = as_field_value(field, input)?;
             match value {
                 PrismaValue::Null if reverse => field.equals(value), // not not in null => in null
                 PrismaValue::List(values) if reverse => field.is_in(values), // not not in values => in values
@@ -54,25 +58,28 @@ pub fn parse(
             }
         }
 
-        "equals" if reverse => field.not_equals(as_prisma_value(input)?),
+        "equals" if reverse => field.not_equals(as_field_value(field, input)?),
         "contains" if reverse => field.not_contains(as_prisma_value(input)?),
         "startsWith" if reverse => field.not_starts_with(as_prisma_value(input)?),
         "endsWith" if reverse => field.not_ends_with(as_prisma_value(input)?),
 
-        "equals" => field.equals(as_prisma_value(input)?),
+        "equals" => field.equals(as_field_value(field, input)?),
         "contains" => field.contains(as_prisma_value(input)?),
         "startsWith" => field.starts_with(as_prisma_value(input)?),
         "endsWith" => field.ends_with(as_prisma_value(input)?),
 
-        "lt" if reverse => field.greater_than_or_equals(as_prisma_value(input)?),
-        "gt" if reverse => field.less_than_or_equals(as_prisma_value(input)?),
-        "lte" if reverse => field.greater_than(as_prisma_value(input)?),
-        "gte" if reverse => field.less_than(as_prisma_value(input)?),
+        "lt" if reverse => field.greater_than_or_equals(as_field_value(field, input)?),
+        "gt" if reverse => field.less_than_or_equals(as_field_value(field, input)?),
+        "lte" if reverse => field.greater_than(as_field_value(field, input)?),
+        "gte" if reverse => field.less_than(as_field_value(field, input)?),
+
+        "lt" => field.less_than(as_field_value(field, input)?),
+        "gt" => field.greater_than(as_field_value(field, input)?),
+        "lte" => field.less_than_or_equals(as_field_value(field, input)?),
+        "gte" => field.greater_than_or_equals(as_field_value(field, input)?),
 
-        "lt" => field.less_than(as_prisma_value(input)?),
-        "gt" => field.greater_than(as_prisma_value(input)?),
-        "lte" => field.less_than_or_equals(as_prisma_value(input)?),
-        "gte" => field.greater_than_or_equals(as_prisma_value(input)?),
+        "search" if reverse => field.not_search(as_prisma_value(input)?),
+        "search" => field.search(as_prisma_value(input)?),
 
         _ => Err(QueryGraphBuilderError::InputError(format!(
             "{} is not a valid scalar filter operation",
@@ -83,6 +90,87 @@ pub fn parse(
     Ok(filter)
 }
 
+/// Parses the operations of Json filters, which apply to the value at `path` of the field. `equals` and `not` on the
+/// whole value are the regular scalar filters.
+pub fn parse_json(
+    filter_key: &str,
+    field: &ScalarFieldRef,
+    path: JsonFilterPath,
+    input: ParsedInputValue,
+) -> QueryGraphBuilderResult<Filter> {
+    let operation = match filter_key {
+        "equals" | "not" if path.is_root() => return parse(filter_key, field, input, false),
+
+        "equals" => JsonOperation::Equals(as_prisma_value(input)?),
+        "not" => JsonOperation::NotEquals(as_prisma_value(input)?),
+        "stringContains" => JsonOperation::StringContains(as_prisma_value(input)?),
+        "stringStartsWith" => JsonOperation::StringStartsWith(as_prisma_value(input)?),
+        "stringEndsWith" => JsonOperation::StringEndsWith(as_prisma_value(input)?),
+        "arrayContains" => JsonOperation::ArrayContains(as_prisma_value(input)?),
+
+        _ => Err(QueryGraphBuilderError::InputError(format!(
+            "{} is not a valid Json filter operation",
+            filter_key
+        )))?,
+    };
+
+    Ok(field.json_compare(path, operation))
+}
+
+/// Parses the filters of scalar list fields. `equals` compares the whole list, the other ones its elements.
+pub fn parse_list(
+    filter_key: &str,
+    field: &ScalarFieldRef,
+    input: ParsedInputValue,
+) -> QueryGraphBuilderResult<Filter> {
+    let filter = match filter_key {
+        "equals" => return parse(filter_key, field, input, false),
+
+        "has" => field.contains_element(as_field_value(field, input)?),
+        "hasEvery" => field.contains_every_element(as_prisma_list(field, input)?),
+        "hasSome" => field.contains_some_element(as_prisma_list(field, input)?),
+        "isEmpty" => match as_prisma_value(input)? {
+            PrismaValue::Boolean(true) => field.contains_none(),
+            _ => Filter::not(vec![field.contains_none()]),
+        },
+
+        _ => Err(QueryGraphBuilderError::InputError(format!(
+            "{} is not a valid scalar list filter operation",
+            filter_key
+        )))?,
+    };
+
+    Ok(filter)
+}
+
+/// The path of Json filters: a list of keys or a JSON path string, depending on the connector.
+pub fn parse_json_path(input: ParsedInputValue) -> QueryGraphBuilderResult<JsonFilterPath> {
+    let value: PrismaValue = input.try_into()?;
+
+    match value {
+        PrismaValue::String(path) => Ok(JsonFilterPath::String(path)),
+        PrismaValue::List(keys) => Ok(JsonFilterPath::Array(
+            keys.into_iter().filter_map(PrismaValue::into_string).collect(),
+        )),
+        _ => unreachable!(), // Validation guarantees this.
+    }
+}
+
 fn as_prisma_value(input: ParsedInputValue) -> QueryGraphBuilderResult<PrismaValue> {
     Ok(input.try_into()?)
 }
+
+/// The value compared with the whole value of the field, which has to fit its column.
+fn as_field_value(field: &ScalarFieldRef, input: ParsedInputValue) -> QueryGraphBuilderResult<PrismaValue> {
+    let value = as_prisma_value(input)?;
+    validate_native_value(field, &value)?;
+
+    Ok(value)
+}
+
+fn as_prisma_list(field: &ScalarFieldRef, input: ParsedInputValue) -> QueryGraphBuilderResult<Vec<PrismaValue>> {
+    match as_field_value(field, input)? {
+        PrismaValue::List(values) => Ok(values),
+        _ => unreachable!(), // Validation guarantees this.
+    }
+}
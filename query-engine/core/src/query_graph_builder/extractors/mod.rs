@@ -4,5 +4,6 @@ mod utils;
 
 pub use filters::*;
 pub use query_arguments::*;
+pub(crate) use utils::validate_native_value;
 
 use crate::query_document::*;
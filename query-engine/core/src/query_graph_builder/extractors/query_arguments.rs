@@ -5,7 +5,8 @@ use crate::{
 };
 use connector::QueryArguments;
 use prisma_models::{
-    Field, ModelProjection, ModelRef, OrderBy, PrismaValue, RecordProjection, ScalarFieldRef, SortOrder,
+    Field, ModelProjection, ModelRef, OrderBy, PrismaValue, RecordProjection, RelationFieldRef, ScalarFieldRef,
+    SortOrder,
 };
 use std::convert::{identity, TryInto};
 
@@ -62,7 +63,7 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
         },
     )?;
 
-    Ok(finalize_arguments(query_args, model))
+    Ok(finalize_arguments(query_args))
 }
 
 /// Extracts order by conditions in order of appearance, as defined in
@@ -72,26 +73,12 @@ fn extract_order_by(model: &ModelRef, value: ParsedInputValue) -> QueryGraphBuil
             .into_iter()
             .map(|list_value| {
                 let object: ParsedInputMap = list_value.try_into()?;
-
-                match object.into_iter().next() {
-                    None => Ok(None),
-                    Some((field_name, sort_order)) => {
-                        let field = model.fields().find_from_scalar(&field_name)?;
-                        let value: PrismaValue = sort_order.try_into()?;
-                        let sort_order = match value.into_string().unwrap().to_lowercase().as_str() {
-                            "asc" => SortOrder::Ascending,
-                            "desc" => SortOrder::Descending,
-                            _ => unreachable!(),
-                        };
-
-                        Ok(Some(OrderBy::new(field, sort_order)))
-                    }
-                }
+                process_order_object(model, object, vec![])
             })
             .collect::<QueryGraphBuilderResult<Vec<_>>>()
             .map(|results| results.into_iter().filter_map(identity).collect()),
 
-        ParsedInputValue::Map(map) => Ok(match process_order_object(model, map)? {
+        ParsedInputValue::Map(map) => Ok(match process_order_object(model, map, vec![])? {
             Some(order) => vec![order],
             None => vec![],
         }),
@@ -100,25 +87,53 @@ fn extract_order_by(model: &ModelRef, value: ParsedInputValue) -> QueryGraphBuil
     }
 }
 
-fn process_order_object(model: &ModelRef, object: ParsedInputMap) -> QueryGraphBuilderResult<Option<OrderBy>> {
-    // let object: ParsedInputMap = list_value.try_into()?;
-
+/// Orderings by related records nest an order object per relation, `path` holds the relations seen so far.
+fn process_order_object(
+    model: &ModelRef,
+    object: ParsedInputMap,
+    mut path: Vec<RelationFieldRef>,
+) -> QueryGraphBuilderResult<Option<OrderBy>> {
     match object.into_iter().next() {
         None => Ok(None),
-        Some((field_name, sort_order)) => {
-            let field = model.fields().find_from_scalar(&field_name)?;
-            let value: PrismaValue = sort_order.try_into()?;
-            let sort_order = match value.into_string().unwrap().to_lowercase().as_str() {
-                "asc" => SortOrder::Ascending,
-                "desc" => SortOrder::Descending,
-                _ => unreachable!(),
-            };
-
-            Ok(Some(OrderBy::new(field, sort_order)))
-        }
+        Some((field_name, value)) => match model.fields().find_from_all(&field_name)? {
+            Field::Scalar(field) => Ok(Some(OrderBy::new_through_relations(
+                field.clone(),
+                path,
+                extract_sort_order(value)?,
+            ))),
+
+            Field::Relation(field) if field.is_list => {
+                let object: ParsedInputMap = value.try_into()?;
+                path.push(field.clone());
+
+                match object.into_iter().next() {
+                    None => Ok(None),
+                    Some((_count, sort_order)) => {
+                        Ok(Some(OrderBy::new_relation_count(path, extract_sort_order(sort_order)?)))
+                    }
+                }
+            }
+
+            Field::Relation(field) => {
+                let object: ParsedInputMap = value.try_into()?;
+                path.push(field.clone());
+
+                process_order_object(&field.related_model(), object, path)
+            }
+        },
     }
 }
 
+fn extract_sort_order(value: ParsedInputValue) -> QueryGraphBuilderResult<SortOrder> {
+    let value: PrismaValue = value.try_into()?;
+
+    Ok(match value.into_string().unwrap().to_lowercase().as_str() {
+        "asc" => SortOrder::Ascending,
+        "desc" => SortOrder::Descending,
+        _ => unreachable!(),
+    })
+}
+
 fn extract_distinct(value: ParsedInputValue) -> QueryGraphBuilderResult<ModelProjection> {
     let fields: Vec<Field> = match value {
         ParsedInputValue::List(list) => list
@@ -174,6 +189,8 @@ fn extract_cursor_field(
     input_value: ParsedInputValue,
 ) -> QueryGraphBuilderResult<Vec<(ScalarFieldRef, PrismaValue)>> {
     let value = input_value.try_into()?;
+    utils::validate_native_value(&field, &value)?;
+
     Ok(vec![(field, value)])
 }
 
@@ -193,21 +210,13 @@ fn extract_compound_cursor_field(
 }
 
 /// Runs final transformations on the QueryArguments.
-fn finalize_arguments(mut args: QueryArguments, model: &ModelRef) -> QueryArguments {
+fn finalize_arguments(mut args: QueryArguments) -> QueryArguments {
     // Check if the query requires an implicit ordering added to the arguments.
-    // An implicit ordering is convenient for deterministic results for take and skip, for cursor it's _required_
-    // as a cursor needs a direction to page. We simply take the primary identifier as a default order-by.
-    let add_implicit_ordering =
-        (args.skip.is_some() || args.cursor.is_some() || args.take.is_some()) && args.order_by.is_empty();
-
-    if add_implicit_ordering {
-        let primary_identifier = model.primary_identifier();
-        let order_bys = primary_identifier.into_iter().map(|f| match f {
-            Field::Scalar(f) => f.into(),
-            _ => unreachable!(),
-        });
-
-        args.order_by.extend(order_bys);
+    // A stable ordering is convenient for deterministic results for take and skip, for cursor it's _required_
+    // as a cursor needs a direction to page, and pages are only consistent if no two records compare equal.
+    // We simply take the primary identifier as the default order-by, and as the tie breaker of the given ones.
+    if args.skip.is_some() || args.cursor.is_some() || args.take.is_some() {
+        args.stabilize_ordering();
     }
 
     args
@@ -1,5 +1,6 @@
-use crate::schema_builder;
-use prisma_models::{ModelRef, ScalarFieldRef};
+use crate::{schema_builder, QueryGraphBuilderError, QueryGraphBuilderResult};
+use prisma_models::{ModelRef, PrismaValue, ScalarFieldRef};
+use uuid::Uuid;
 
 /// Attempts to resolve a field name to a compound field.
 pub fn resolve_compound_field(name: &str, model: &ModelRef) -> Option<Vec<ScalarFieldRef>> {
@@ -27,3 +28,25 @@ pub fn resolve_index_fields(name: &str, model: &ModelRef) -> Option<Vec<ScalarFi
         .find(|index| &schema_builder::compound_index_field_name(index) == name)
         .map(|index| index.fields())
 }
+
+/// Native UUID columns (`@pg.Uuid`) are String fields in the query schema, which keeps their string filters,
+/// but only UUIDs fit into them. Their values are validated before they are used in writes or comparisons.
+pub fn validate_native_value(field: &ScalarFieldRef, value: &PrismaValue) -> QueryGraphBuilderResult<()> {
+    if !field.is_native_uuid {
+        return Ok(());
+    }
+
+    match value {
+        PrismaValue::String(s) => Uuid::parse_str(s).map(|_| ()).map_err(|err| {
+            QueryGraphBuilderError::InputError(format!(
+                "Invalid UUID `{}` for the field `{}` of the model `{}`: {}",
+                s,
+                field.name,
+                field.model().name,
+                err
+            ))
+        }),
+        PrismaValue::List(values) => values.iter().try_for_each(|value| validate_native_value(field, value)),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,52 @@
+use super::*;
+use crate::{query_document::ParsedField, CountRecordsQuery, ReadQuery};
+use prisma_models::{ModelRef, PrismaValue};
+use std::convert::TryInto;
+
+pub struct CountRecordsBuilder {
+    field: ParsedField,
+    model: ModelRef,
+}
+
+impl CountRecordsBuilder {
+    pub fn new(field: ParsedField, model: ModelRef) -> Self {
+        Self { field, model }
+    }
+}
+
+impl Builder<ReadQuery> for CountRecordsBuilder {
+    fn build(self) -> QueryGraphBuilderResult<ReadQuery> {
+        let name = self.field.name;
+        let alias = self.field.alias;
+        let model = self.model;
+
+        let (estimate_args, arguments): (Vec<_>, Vec<_>) =
+            self.field.arguments.into_iter().partition(|arg| arg.name == "estimate");
+
+        let estimate = match estimate_args.into_iter().next() {
+            Some(arg) => {
+                let val: PrismaValue = arg.value.try_into()?;
+                val == PrismaValue::Boolean(true)
+            }
+            None => false,
+        };
+
+        let args = extractors::extract_query_args(arguments, &model)?;
+
+        // Same as for aggregations, there is no in-memory post-processing of counts.
+        if args.contains_unstable_cursor() {
+            return Err(QueryGraphBuilderError::InputError(
+                "The chosen cursor and orderBy combination is not stable (unique) and can't be used for counts."
+                    .to_owned(),
+            ));
+        }
+
+        Ok(ReadQuery::CountRecordsQuery(CountRecordsQuery {
+            name,
+            alias,
+            model,
+            args,
+            estimate,
+        }))
+    }
+}
@@ -0,0 +1,89 @@
+use super::*;
+use crate::{
+    query_document::{ParsedField, ParsedInputValue},
+    GroupByRecordsQuery, ReadQuery,
+};
+use prisma_models::{ModelRef, ScalarFieldRef};
+use std::convert::TryInto;
+
+/// The names of the aggregation fields of a group. All other selected fields are grouped fields.
+pub(crate) const AGGREGATION_FIELD_NAMES: &[&str] = &["count", "avg", "sum", "min", "max"];
+
+pub struct GroupByRecordsBuilder {
+    field: ParsedField,
+    model: ModelRef,
+}
+
+impl GroupByRecordsBuilder {
+    pub fn new(field: ParsedField, model: ModelRef) -> Self {
+        Self { field, model }
+    }
+
+    fn extract_group_by(value: ParsedInputValue) -> QueryGraphBuilderResult<Vec<ScalarFieldRef>> {
+        match value {
+            ParsedInputValue::List(list) => list
+                .into_iter()
+                .map(|element| {
+                    let field: ScalarFieldRef = element.try_into()?;
+                    Ok(field)
+                })
+                .collect(),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Builder<ReadQuery> for GroupByRecordsBuilder {
+    fn build(self) -> QueryGraphBuilderResult<ReadQuery> {
+        let name = self.field.name;
+        let alias = self.field.alias;
+        let model = self.model;
+        let nested_fields = self.field.nested_fields.unwrap().fields;
+        let selection_order = AggregateRecordsBuilder::collect_selection_tree(&nested_fields);
+
+        let (by_args, arguments): (Vec<_>, Vec<_>) = self.field.arguments.into_iter().partition(|arg| arg.name == "by");
+
+        let group_by = match by_args.into_iter().next() {
+            Some(arg) => Self::extract_group_by(arg.value)?,
+            None => Vec::new(),
+        };
+
+        if group_by.is_empty() {
+            return Err(QueryGraphBuilderError::InputError(
+                "At least one field to group by is required.".to_owned(),
+            ));
+        }
+
+        let args = extractors::extract_query_args(arguments, &model)?;
+
+        let (aggregations, selected_fields): (Vec<_>, Vec<_>) = nested_fields
+            .into_iter()
+            .partition(|field| AGGREGATION_FIELD_NAMES.contains(&field.name.as_str()));
+
+        // The values of fields that are not grouped by differ within a group.
+        if let Some(field) = selected_fields
+            .iter()
+            .find(|field| !group_by.iter().any(|sf| sf.name == field.name))
+        {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "The field `{}` is selected, but not grouped by. Add it to `by` or remove it from the selection.",
+                field.name
+            )));
+        }
+
+        let aggregators: Vec<_> = aggregations
+            .into_iter()
+            .map(|field| AggregateRecordsBuilder::resolve_query(field, &model))
+            .collect::<QueryGraphBuilderResult<_>>()?;
+
+        Ok(ReadQuery::GroupByRecordsQuery(GroupByRecordsQuery {
+            name,
+            alias,
+            model,
+            selection_order,
+            args,
+            group_by,
+            aggregators,
+        }))
+    }
+}
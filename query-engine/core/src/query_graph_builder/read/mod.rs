@@ -1,9 +1,13 @@
 mod aggregate;
+mod count;
+mod group_by;
 mod many;
 mod one;
 mod related;
 
 pub use aggregate::*;
+pub use count::*;
+pub use group_by::*;
 pub use many::*;
 pub use one::*;
 pub use related::*;
@@ -13,11 +17,16 @@ use crate::{query_document::ParsedField, ReadQuery};
 use prisma_models::{Field, ModelProjection, ModelRef, RecordProjection, RelationFieldRef};
 use std::sync::Arc;
 
+/// The field of the model output types counting the related records of the to-many relations.
+pub(crate) const RELATION_COUNT_FIELD: &str = "_count";
+
 pub enum ReadQueryBuilder {
     ReadOneRecordBuilder(ReadOneRecordBuilder),
     ReadManyRecordsBuilder(ReadManyRecordsBuilder),
     ReadRelatedRecordsBuilder(ReadRelatedRecordsBuilder),
     AggregateRecordsBuilder(AggregateRecordsBuilder),
+    CountRecordsBuilder(CountRecordsBuilder),
+    GroupByRecordsBuilder(GroupByRecordsBuilder),
 }
 
 impl Builder<ReadQuery> for ReadQueryBuilder {
@@ -27,6 +36,8 @@ impl Builder<ReadQuery> for ReadQueryBuilder {
             ReadQueryBuilder::ReadManyRecordsBuilder(b) => b.build(),
             ReadQueryBuilder::ReadRelatedRecordsBuilder(b) => b.build(),
             ReadQueryBuilder::AggregateRecordsBuilder(b) => b.build(),
+            ReadQueryBuilder::CountRecordsBuilder(b) => b.build(),
+            ReadQueryBuilder::GroupByRecordsBuilder(b) => b.build(),
         }
     }
 }
@@ -44,17 +55,16 @@ pub fn collect_selection_order(from: &[ParsedField]) -> Vec<String> {
 
 /// Creates SelectedFields from a query selection.
 /// Automatically adds model IDs to the selected fields as well.
+/// Computed fields are replaced by the scalars they are computed from.
 /// Unwraps are safe due to query validation.
 pub fn collect_selected_fields(from: &[ParsedField], model: &ModelRef) -> ModelProjection {
     let selected_fields = from
         .iter()
-        .filter_map(|selected_field| {
-            model
-                .fields()
-                .find_from_scalar(&selected_field.name)
-                .ok()
-                .map(|sf| sf.into())
+        .flat_map(|selected_field| match selected_field.schema_field.computed {
+            Some(ref computed) => computed.dependencies(),
+            None => vec![selected_field.name.as_str()],
         })
+        .filter_map(|name| model.fields().find_from_scalar(name).ok().map(|sf| sf.into()))
         .collect::<Vec<Field>>();
 
     let selected_projection = ModelProjection::new(selected_fields);
@@ -65,6 +75,8 @@ pub fn collect_selected_fields(from: &[ParsedField], model: &ModelRef) -> ModelP
 
 pub fn collect_nested_queries(from: Vec<ParsedField>, model: &ModelRef) -> QueryGraphBuilderResult<Vec<ReadQuery>> {
     from.into_iter()
+        .filter(|selected_field| selected_field.schema_field.computed.is_none())
+        .filter(|selected_field| selected_field.name != RELATION_COUNT_FIELD)
         .filter_map(|selected_field| {
             let model_field = model.fields().find_from_all(&selected_field.name).unwrap();
             match model_field {
@@ -85,6 +97,16 @@ pub fn collect_nested_queries(from: Vec<ParsedField>, model: &ModelRef) -> Query
         .collect::<QueryGraphBuilderResult<Vec<ReadQuery>>>()
 }
 
+/// Collects the to-many relations selected in the `_count` field, in selection order.
+/// Unwraps are safe due to query validation.
+pub fn collect_relation_counts(from: &[ParsedField], model: &ModelRef) -> Vec<RelationFieldRef> {
+    from.iter()
+        .filter(|selected_field| selected_field.name == RELATION_COUNT_FIELD)
+        .flat_map(|selected_field| selected_field.nested_fields.as_ref().unwrap().fields.iter())
+        .map(|counted_field| model.fields().find_from_relation_fields(&counted_field.name).unwrap())
+        .collect()
+}
+
 /// Performs a lookahead based on the nested queries and merges fields required
 /// to resolve the nested queries.
 /// A lookback on the parent is also performed to ensure that fields required for
@@ -32,6 +32,7 @@ impl Builder<ReadQuery> for ReadOneRecordBuilder {
         let nested_fields = self.field.nested_fields.unwrap().fields;
         let selection_order: Vec<String> = collect_selection_order(&nested_fields);
         let selected_fields = collect_selected_fields(&nested_fields, &model);
+        let relation_counts = collect_relation_counts(&nested_fields, &model);
         let nested = collect_nested_queries(nested_fields, &model)?;
         let selected_fields = merge_relation_selections(selected_fields, None, &nested);
 
@@ -43,6 +44,7 @@ impl Builder<ReadQuery> for ReadOneRecordBuilder {
             selected_fields,
             nested,
             selection_order,
+            relation_counts,
         }))
     }
 }
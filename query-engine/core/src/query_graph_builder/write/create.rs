@@ -53,17 +53,33 @@ pub fn create_record_node(
     model: ModelRef,
     data_map: ParsedInputMap,
 ) -> QueryGraphBuilderResult<NodeRef> {
+    create_record_branch(graph, model, data_map).map(|(_, create_node)| create_node)
+}
+
+/// Creates a create record query node for a conditional branch. Returns the node the branch has to
+/// point to, the first of the unique checks of the create if there are any, and the create node.
+pub fn create_record_branch(
+    graph: &mut QueryGraph,
+    model: ModelRef,
+    data_map: ParsedInputMap,
+) -> QueryGraphBuilderResult<(NodeRef, NodeRef)> {
     let create_args = WriteArgsParser::from(&model, data_map)?;
     let mut args = create_args.args;
 
     args.add_datetimes(Arc::clone(&model));
 
-    let cr = CreateRecord { model, args };
+    let unique_checks_args = args.clone();
+    let cr = CreateRecord {
+        model: Arc::clone(&model),
+        args,
+    };
     let create_node = graph.create_node(Query::Write(WriteQuery::CreateRecord(cr)));
 
+    let entry_node = utils::insert_unique_checks(graph, &model, &unique_checks_args, &create_node, None)?;
+
     for (relation_field, data_map) in create_args.nested {
         nested::connect_nested_query(graph, create_node, relation_field, data_map)?;
     }
 
-    Ok(create_node)
+    Ok((entry_node, create_node))
 }
@@ -65,6 +65,7 @@ pub fn delete_many_records(
     let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
         model: model.clone(),
         record_filter,
+        return_ids: utils::selects_affected_ids(&field),
     });
 
     let read_query_node = graph.create_node(read_query);
@@ -15,4 +15,4 @@ use super::*;
 pub use create::create_record;
 pub use delete::{delete_many_records, delete_record};
 pub use update::{update_many_records, update_record};
-pub use upsert::upsert_record;
+pub use upsert::{upsert_many_records, upsert_record};
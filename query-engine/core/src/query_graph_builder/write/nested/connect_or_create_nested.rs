@@ -1,11 +1,12 @@
 use super::*;
 use crate::{
+    interpreter::ExpressionResult,
     query_ast::*,
-    query_graph::{Flow, Node, NodeRef, QueryGraph, QueryGraphDependency},
-    ParsedInputMap, ParsedInputValue,
+    query_graph::{Flow, Node, NodeRef, QueryGraph, QueryGraphDependency, SavepointFailure},
+    ParsedInputMap, ParsedInputValue, QueryResult,
 };
 use connector::{Filter, IdFilter};
-use prisma_models::{ModelRef, RelationFieldRef};
+use prisma_models::{ModelProjection, ModelRef, RelationFieldRef};
 use std::{convert::TryInto, sync::Arc};
 
 /// Handles nested connect or create cases.
@@ -85,10 +86,11 @@ fn handle_many_to_many(
         let read_node = graph.create_node(utils::read_ids_infallible(
             child_model.clone(),
             child_model.primary_identifier(),
-            filter,
+            filter.clone(),
         ));
 
-        let create_node = create::create_record_node(graph, Arc::clone(child_model), create_map)?;
+        let (create_entry_node, create_node) =
+            create::create_record_branch(graph, Arc::clone(child_model), create_map)?;
         let if_node = graph.create_node(Flow::default_if());
 
         let connect_exists_node =
@@ -114,7 +116,15 @@ fn handle_many_to_many(
         )?;
 
         graph.create_edge(&if_node, &connect_exists_node, QueryGraphDependency::Then)?;
-        graph.create_edge(&if_node, &create_node, QueryGraphDependency::Else)?;
+        graph.create_edge(&if_node, &create_entry_node, QueryGraphDependency::Else)?;
+
+        add_create_savepoint(
+            graph,
+            &create_node,
+            child_model,
+            child_model.primary_identifier(),
+            filter,
+        );
     }
 
     Ok(())
@@ -231,12 +241,20 @@ fn one_to_many_inlined_child(
         ));
 
         let if_node = graph.create_node(Flow::default_if());
-        let update_child_node = utils::update_records_node_placeholder(graph, filter, Arc::clone(child_model));
-        let create_node = create::create_record_node(graph, Arc::clone(child_model), create_map)?;
+        let update_child_node = utils::update_records_node_placeholder(graph, filter.clone(), Arc::clone(child_model));
+        let (create_entry_node, create_node) =
+            create::create_record_branch(graph, Arc::clone(child_model), create_map)?;
 
         graph.create_edge(&parent_node, &read_node, QueryGraphDependency::ExecutionOrder)?;
         graph.create_edge(&if_node, &update_child_node, QueryGraphDependency::Then)?;
-        graph.create_edge(&if_node, &create_node, QueryGraphDependency::Else)?;
+        graph.create_edge(&if_node, &create_entry_node, QueryGraphDependency::Else)?;
+        add_create_savepoint(
+            graph,
+            &create_node,
+            child_model,
+            child_model.primary_identifier(),
+            filter,
+        );
 
         graph.create_edge(
             &read_node,
@@ -297,6 +315,8 @@ fn one_to_many_inlined_child(
                 }),
             ),
         )?;
+
+        connect_on_create_conflict(graph, &parent_node, &create_node, parent_relation_field, child_model)?;
     }
 
     Ok(())
@@ -365,7 +385,7 @@ fn one_to_many_inlined_parent(
     graph.create_edge(&parent_node, &read_node, QueryGraphDependency::ExecutionOrder)?;
 
     let if_node = graph.create_node(Flow::default_if());
-    let create_node = create::create_record_node(graph, Arc::clone(child_model), create_map)?;
+    let (create_entry_node, create_node) = create::create_record_branch(graph, Arc::clone(child_model), create_map)?;
     let return_existing = graph.create_node(Flow::Return(None));
     let return_create = graph.create_node(Flow::Return(None));
 
@@ -385,7 +405,7 @@ fn one_to_many_inlined_parent(
     )?;
 
     graph.create_edge(&if_node, &return_existing, QueryGraphDependency::Then)?;
-    graph.create_edge(&if_node, &create_node, QueryGraphDependency::Else)?;
+    graph.create_edge(&if_node, &create_entry_node, QueryGraphDependency::Else)?;
 
     graph.create_edge(
         &if_node,
@@ -433,6 +453,9 @@ fn one_to_many_inlined_parent(
         ),
     )?;
 
+    let child_link = parent_relation_field.related_field().linking_fields();
+    add_create_savepoint(graph, &create_node, child_model, child_link, filter);
+
     Ok(())
 }
 
@@ -526,7 +549,7 @@ fn one_to_one_inlined_parent(
     graph.create_edge(&parent_node, &read_node, QueryGraphDependency::ExecutionOrder)?;
 
     let if_node = graph.create_node(Flow::default_if());
-    let create_node = create::create_record_node(graph, Arc::clone(child_model), create_data)?;
+    let (create_entry_node, create_node) = create::create_record_branch(graph, Arc::clone(child_model), create_data)?;
     let return_existing = graph.create_node(Flow::Return(None));
     let return_create = graph.create_node(Flow::Return(None));
 
@@ -572,7 +595,7 @@ fn one_to_one_inlined_parent(
     )?;
 
     // Else branch handling
-    graph.create_edge(&if_node, &create_node, QueryGraphDependency::Else)?;
+    graph.create_edge(&if_node, &create_entry_node, QueryGraphDependency::Else)?;
     graph.create_edge(
         &create_node,
         &return_create,
@@ -648,6 +671,9 @@ fn one_to_one_inlined_parent(
         )?;
     }
 
+    let child_link = parent_relation_field.related_field().linking_fields();
+    add_create_savepoint(graph, &create_node, child_model, child_link, filter);
+
     Ok(())
 }
 
@@ -740,7 +766,7 @@ fn one_to_one_inlined_child(
     graph.create_edge(&parent_node, &read_node, QueryGraphDependency::ExecutionOrder)?;
 
     let if_node = graph.create_node(Flow::default_if());
-    let create_node = create::create_record_node(graph, Arc::clone(child_model), create_data)?;
+    let (create_entry_node, create_node) = create::create_record_branch(graph, Arc::clone(child_model), create_data)?;
 
     graph.create_edge(
         &read_node,
@@ -811,7 +837,7 @@ fn one_to_one_inlined_child(
     // Else branch handling
     let child_link = parent_relation_field.related_field().linking_fields();
 
-    graph.create_edge(&if_node, &create_node, QueryGraphDependency::Else)?;
+    graph.create_edge(&if_node, &create_entry_node, QueryGraphDependency::Else)?;
     graph.create_edge(
         &parent_node,
         &create_node,
@@ -829,5 +855,108 @@ fn one_to_one_inlined_child(
         })),
     )?;
 
+    add_create_savepoint(
+        graph,
+        &create_node,
+        &child_model,
+        child_model.primary_identifier(),
+        filter,
+    );
+    connect_on_create_conflict(graph, &parent_node, &create_node, parent_relation_field, &child_model)?;
+
+    Ok(())
+}
+
+/// Executes the create of the child inside of a savepoint. If it fails on a unique constraint, because the record was
+/// inserted concurrently after the existence check, the record is read by the `where` selector instead, selecting
+/// `projection`, and the children of the create node connect it as if it had existed all along.
+fn add_create_savepoint(
+    graph: &mut QueryGraph,
+    create_node: &NodeRef,
+    child_model: &ModelRef,
+    projection: ModelProjection,
+    filter: Filter,
+) {
+    if let Query::Read(read) = utils::read_ids_infallible(child_model.clone(), projection, filter) {
+        graph.add_savepoint(create_node, SavepointFailure::ReadOnUniqueViolation(read));
+    }
+}
+
+/// For relations inlined on the child: connects the child to the parent if the create of the child read the
+/// conflicting record instead of creating it (see `add_create_savepoint`), as the parent link was only part of the
+/// failed create then. A successful create already set the link, and returns the id instead of a record selection.
+fn connect_on_create_conflict(
+    graph: &mut QueryGraph,
+    parent_node: &NodeRef,
+    create_node: &NodeRef,
+    parent_relation_field: &RelationFieldRef,
+    child_model: &ModelRef,
+) -> QueryGraphBuilderResult<()> {
+    let parent_link = parent_relation_field.linking_fields();
+    let child_link = parent_relation_field.related_field().linking_fields();
+
+    let if_node = graph.create_node(Flow::default_if());
+    let update_child_node = utils::update_records_node_placeholder(graph, Filter::empty(), Arc::clone(child_model));
+
+    graph.create_edge(
+        create_node,
+        &if_node,
+        QueryGraphDependency::ParentResult(Box::new(move |if_node, result| {
+            let conflict = matches!(result, ExpressionResult::Query(QueryResult::RecordSelection(_)));
+
+            if let Node::Flow(Flow::If(_)) = if_node {
+                Ok(Node::Flow(Flow::If(Box::new(move || conflict))))
+            } else {
+                Ok(if_node)
+            }
+        })),
+    )?;
+
+    graph.create_edge(&if_node, &update_child_node, QueryGraphDependency::Then)?;
+
+    graph.create_edge(
+        create_node,
+        &update_child_node,
+        QueryGraphDependency::ParentProjection(
+            child_model.primary_identifier(),
+            Box::new(move |mut update_child_node, mut child_ids| {
+                let child_id = match child_ids.pop() {
+                    Some(id) => Ok(id),
+                    None => Err(QueryGraphBuilderError::AssertionError(format!(
+                        "[Query Graph] Expected a valid child ID to be present for a nested connect or create conflict."
+                    ))),
+                }?;
+
+                if let Node::Query(ref mut q) = update_child_node {
+                    q.add_filter(child_id.filter());
+                }
+
+                Ok(update_child_node)
+            }),
+        ),
+    )?;
+
+    graph.create_edge(
+        parent_node,
+        &update_child_node,
+        QueryGraphDependency::ParentProjection(
+            parent_link,
+            Box::new(move |mut update_child_node, mut parent_ids| {
+                let parent_id = match parent_ids.pop() {
+                    Some(id) => Ok(id),
+                    None => Err(QueryGraphBuilderError::AssertionError(format!(
+                        "[Query Graph] Expected a valid parent ID to be present for a nested connect or create conflict."
+                    ))),
+                }?;
+
+                if let Node::Query(Query::Write(ref mut wq)) = update_child_node {
+                    wq.inject_projection_into_args(child_link.assimilate(parent_id)?);
+                }
+
+                Ok(update_child_node)
+            }),
+        ),
+    )?;
+
     Ok(())
 }
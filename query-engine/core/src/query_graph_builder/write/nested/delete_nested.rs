@@ -43,6 +43,7 @@ pub fn nested_delete(
         let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
             model: Arc::clone(&child_model),
             record_filter: or_filter.clone().into(),
+            return_ids: false,
         });
 
         let delete_many_node = graph.create_node(Query::Write(delete_many));
@@ -116,6 +117,11 @@ pub fn nested_delete(
     Ok(())
 }
 
+/// Adds a delete many records node to the graph and connects it to the parent.
+///
+/// If the relation is inlined on the child model and no other relation requires the deleted records,
+/// the delete is scoped to the children of the parent by filtering on their foreign key, in a single filtered write.
+/// Otherwise the children are read first, to check the relations requiring them and to select them on many-to-many relations.
 pub fn nested_delete_many(
     graph: &mut QueryGraph,
     parent: &NodeRef,
@@ -124,17 +130,35 @@ pub fn nested_delete_many(
     child_model: &ModelRef,
 ) -> QueryGraphBuilderResult<()> {
     let child_model_identifier = parent_relation_field.related_model().primary_identifier();
+    let requires_deletion_checks = !child_model
+        .internal_data_model()
+        .fields_requiring_model(child_model)
+        .is_empty();
 
     for value in utils::coerce_vec(value) {
         let as_map: ParsedInputMap = value.try_into()?;
         let filter = extract_filter(as_map, child_model)?;
 
+        if parent_relation_field.related_field().is_inlined_on_enclosing_model() && !requires_deletion_checks {
+            let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
+                model: Arc::clone(&child_model),
+                record_filter: filter.into(),
+                return_ids: false,
+            });
+
+            let delete_many_node = graph.create_node(Query::Write(delete_many));
+            utils::insert_children_by_parent_filter_edge(graph, parent, parent_relation_field, &delete_many_node)?;
+
+            continue;
+        }
+
         let find_child_records_node =
             utils::insert_find_children_by_parent_node(graph, parent, parent_relation_field, filter.clone())?;
 
         let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
             model: Arc::clone(&child_model),
             record_filter: RecordFilter::empty(),
+            return_ids: false,
         });
 
         let delete_many_node = graph.create_node(Query::Write(delete_many));
@@ -83,6 +83,21 @@ pub fn nested_update(
     Ok(())
 }
 
+/// Handles nested update many cases.
+///
+/// If the relation is inlined on the child model, the update is scoped to the children
+/// of the parent by filtering on their foreign key, in a single filtered write:
+/// ```text
+///    ┌ ─ ─ ─ ─ ─ ─
+/// ┌──    Parent   │─ ─ ─ ─ ─
+/// │  └ ─ ─ ─ ─ ─ ─          │
+/// │                         ▼
+/// │  ┌────────────┐   ┌ ─ ─ ─ ─ ─
+/// └─▶│Update many │      Result  │
+///    └────────────┘   └ ─ ─ ─ ─ ─
+/// ```
+///
+/// Otherwise (many-to-many relations), the connected children are read first.
 pub fn nested_update_many(
     graph: &mut QueryGraph,
     parent: &NodeRef,
@@ -101,6 +116,20 @@ pub fn nested_update_many(
         let filter = extract_filter(where_map, child_model)?;
         let update_args = WriteArgsParser::from(&child_model, data_map)?;
 
+        if parent_relation_field.related_field().is_inlined_on_enclosing_model() {
+            let update_many = WriteQuery::UpdateManyRecords(UpdateManyRecords {
+                model: Arc::clone(&child_model),
+                record_filter: filter.into(),
+                args: update_args.args,
+                return_ids: false,
+            });
+
+            let update_many_node = graph.create_node(Query::Write(update_many));
+            utils::insert_children_by_parent_filter_edge(graph, parent, parent_relation_field, &update_many_node)?;
+
+            continue;
+        }
+
         let find_child_records_node =
             utils::insert_find_children_by_parent_node(graph, parent, parent_relation_field, filter)?;
 
@@ -108,6 +137,7 @@ pub fn nested_update_many(
             model: Arc::clone(&child_model),
             record_filter: RecordFilter::empty(),
             args: update_args.args,
+            return_ids: false,
         });
 
         let update_many_node = graph.create_node(Query::Write(update_many));
@@ -113,7 +113,8 @@ pub fn nested_upsert(
             utils::insert_find_children_by_parent_node(graph, &parent_node, parent_relation_field, filter)?;
 
         let if_node = graph.create_node(Flow::default_if());
-        let create_node = create::create_record_node(graph, Arc::clone(&child_model), create_input.try_into()?)?;
+        let (create_entry_node, create_node) =
+            create::create_record_branch(graph, Arc::clone(&child_model), create_input.try_into()?)?;
         let update_node = update::update_record_node(
             graph,
             Filter::empty(),
@@ -156,7 +157,7 @@ pub fn nested_upsert(
         )?;
 
         graph.create_edge(&if_node, &update_node, QueryGraphDependency::Then)?;
-        graph.create_edge(&if_node, &create_node, QueryGraphDependency::Else)?;
+        graph.create_edge(&if_node, &create_entry_node, QueryGraphDependency::Else)?;
 
         // Specific handling based on relation type and inlining side.
         if parent_relation_field.relation().is_many_to_many() {
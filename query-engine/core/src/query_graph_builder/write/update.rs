@@ -73,6 +73,7 @@ pub fn update_many_records(
         model,
         record_filter,
         args,
+        return_ids: utils::selects_affected_ids(&field),
     });
     graph.create_node(Query::Write(update_many));
 
@@ -86,6 +87,23 @@ pub fn update_record_node<T>(
     model: ModelRef,
     data_map: ParsedInputMap,
 ) -> QueryGraphBuilderResult<NodeRef>
+where
+    T: Into<Filter>,
+{
+    update_record_branch(graph, filter, model, data_map).map(|(_, update_node)| update_node)
+}
+
+/// Creates an update record query node for a conditional branch. Returns the node the branch has to
+/// point to, the first of the unique checks of the update if there are any, and the update node.
+///
+/// The unique checks need the filter of the updated record, they are left out for the updates that
+/// only get the filter from their parent (empty `filter`).
+pub fn update_record_branch<T>(
+    graph: &mut QueryGraph,
+    filter: T,
+    model: ModelRef,
+    data_map: ParsedInputMap,
+) -> QueryGraphBuilderResult<(NodeRef, NodeRef)>
 where
     T: Into<Filter>,
 {
@@ -94,18 +112,28 @@ where
 
     args.update_datetimes(Arc::clone(&model));
 
-    let filter = filter.into();
+    let filter: Filter = filter.into();
+    let unique_checks = match filter {
+        Filter::Empty => None,
+        _ => Some((args.clone(), filter.clone())),
+    };
+
     let record_filter = filter.into();
     let ur = UpdateRecord {
-        model,
+        model: Arc::clone(&model),
         record_filter,
         args,
     };
 
     let node = graph.create_node(Query::Write(WriteQuery::UpdateRecord(ur)));
+    let entry_node = match unique_checks {
+        Some((args, filter)) => utils::insert_unique_checks(graph, &model, &args, &node, Some(&filter))?,
+        None => node,
+    };
+
     for (relation_field, data_map) in update_args.nested {
         nested::connect_nested_query(graph, node, relation_field, data_map)?;
     }
 
-    Ok(node)
+    Ok((entry_node, node))
 }
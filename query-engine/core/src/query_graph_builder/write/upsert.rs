@@ -4,9 +4,10 @@ use crate::{
     query_graph::{Flow, Node, QueryGraph, QueryGraphDependency},
     ArgumentListLookup, ParsedField, ParsedInputMap, ReadOneRecordBuilder,
 };
-use connector::IdFilter;
+use connector::{IdFilter, WriteArgs};
 use prisma_models::ModelRef;
 use std::{convert::TryInto, sync::Arc};
+use write_args_parser::*;
 
 pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedField) -> QueryGraphBuilderResult<()> {
     let where_arg: ParsedInputMap = field.arguments.lookup("where").unwrap().value.try_into()?;
@@ -20,8 +21,10 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
     let read_parent_records = utils::read_ids_infallible(model.clone(), model_id.clone(), filter.clone());
     let read_parent_records_node = graph.create_node(read_parent_records);
 
-    let create_node = create::create_record_node(graph, Arc::clone(&model), create_argument.value.try_into()?)?;
-    let update_node = update::update_record_node(graph, filter, Arc::clone(&model), update_argument.value.try_into()?)?;
+    let (create_entry_node, create_node) =
+        create::create_record_branch(graph, Arc::clone(&model), create_argument.value.try_into()?)?;
+    let (update_entry_node, update_node) =
+        update::update_record_branch(graph, filter, Arc::clone(&model), update_argument.value.try_into()?)?;
 
     let read_query = ReadOneRecordBuilder::new(field, Arc::clone(&model)).build()?;
     let read_node_create = graph.create_node(Query::Read(read_query.clone()));
@@ -48,8 +51,8 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
         ),
     )?;
 
-    graph.create_edge(&if_node, &update_node, QueryGraphDependency::Then)?;
-    graph.create_edge(&if_node, &create_node, QueryGraphDependency::Else)?;
+    graph.create_edge(&if_node, &update_entry_node, QueryGraphDependency::Then)?;
+    graph.create_edge(&if_node, &create_entry_node, QueryGraphDependency::Else)?;
     graph.create_edge(
         &update_node,
         &read_node_update,
@@ -96,3 +99,38 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
 
     Ok(())
 }
+
+/// Creates an upsert many records query and adds it to the query graph. The records are inserted with
+/// a single statement, that updates the existing records conflicting with them on a unique criterion.
+pub fn upsert_many_records(
+    graph: &mut QueryGraph,
+    model: ModelRef,
+    mut field: ParsedField,
+) -> QueryGraphBuilderResult<()> {
+    let data_list = match field.arguments.lookup("data") {
+        Some(data) => utils::coerce_vec(data.value),
+        None => vec![],
+    };
+
+    let args = data_list
+        .into_iter()
+        .map(|data_value| -> QueryGraphBuilderResult<WriteArgs> {
+            let data_map: ParsedInputMap = data_value.try_into()?;
+            let mut args = WriteArgsParser::from(&model, data_map)?.args;
+
+            args.add_datetimes(Arc::clone(&model));
+            Ok(args)
+        })
+        .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+
+    let conflict_target = utils::upsert_conflict_target(&model, &args)?;
+    let upsert_many = WriteQuery::UpsertManyRecords(UpsertManyRecords {
+        model,
+        args,
+        conflict_target,
+    });
+
+    graph.create_node(Query::Write(upsert_many));
+
+    Ok(())
+}
@@ -1,11 +1,11 @@
 use crate::{
     query_ast::*,
     query_graph::{Flow, Node, NodeRef, QueryGraph, QueryGraphDependency},
-    ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult,
+    ParsedField, ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::{Filter, WriteArgs};
+use connector::{Filter, IdFilter, WriteArgs, WriteExpression};
 use itertools::Itertools;
-use prisma_models::{ModelProjection, ModelRef, RelationFieldRef};
+use prisma_models::{ModelProjection, ModelRef, PrismaValue, RecordProjection, RelationFieldRef, ScalarFieldRef};
 use std::sync::Arc;
 
 /// Coerces single values (`ParsedInputValue::Single` and `ParsedInputValue::Map`) into a vector.
@@ -18,6 +18,15 @@ pub fn coerce_vec(val: ParsedInputValue) -> Vec<ParsedInputValue> {
     }
 }
 
+/// Whether the selection of the batch payload of `field` asks for the identifiers of the affected records.
+pub fn selects_affected_ids(field: &ParsedField) -> bool {
+    field
+        .nested_fields
+        .as_ref()
+        .map(|nested| nested.fields.iter().any(|f| f.name == "ids"))
+        .unwrap_or(false)
+}
+
 pub fn node_is_create(graph: &QueryGraph, node: &NodeRef) -> bool {
     match graph.node_content(node).unwrap() {
         Node::Query(Query::Write(WriteQuery::CreateRecord(_))) => true,
@@ -41,6 +50,7 @@ where
         selected_fields,
         nested: vec![],
         selection_order: vec![],
+        relation_counts: vec![],
     });
 
     Query::Read(read_query)
@@ -114,6 +124,7 @@ where
         selected_fields,
         nested: vec![],
         selection_order: vec![],
+        relation_counts: vec![],
     })));
 
     graph.create_edge(
@@ -134,6 +145,44 @@ where
     Ok(read_children_node)
 }
 
+/// Scopes the filtered write in `write_children_node` (update many or delete many) to the children of the parent,
+/// with a filter on the foreign key of the child model. The write doesn't need the children to be read first.
+///
+/// Only valid for relations inlined on the child model.
+pub fn insert_children_by_parent_filter_edge(
+    graph: &mut QueryGraph,
+    parent_node: &NodeRef,
+    parent_relation_field: &RelationFieldRef,
+    write_children_node: &NodeRef,
+) -> QueryGraphBuilderResult<()> {
+    let parent_link = parent_relation_field.linking_fields();
+    let child_link = parent_relation_field.related_field().linking_fields();
+
+    graph.create_edge(
+        parent_node,
+        write_children_node,
+        QueryGraphDependency::ParentProjection(
+            parent_link,
+            Box::new(move |mut write_children_node, mut parent_links| {
+                let parent_link = match parent_links.pop() {
+                    Some(link) => Ok(link),
+                    None => Err(QueryGraphBuilderError::AssertionError(format!(
+                        "[Query Graph] Expected a valid parent ID to be present for a nested write on the children of a one-to-many relation."
+                    ))),
+                }?;
+
+                if let Node::Query(Query::Write(ref mut wq)) = write_children_node {
+                    wq.add_filter(child_link.assimilate(parent_link)?.filter());
+                }
+
+                Ok(write_children_node)
+            }),
+        ),
+    )?;
+
+    Ok(())
+}
+
 /// Creates an update many records query node and adds it to the query graph.
 /// Used to have a skeleton update node in the graph that can be further transformed during query execution based
 /// on available information.
@@ -153,6 +202,7 @@ where
         model,
         record_filter,
         args,
+        return_ids: false,
     };
 
     graph.create_node(Query::Write(WriteQuery::UpdateManyRecords(ur)))
@@ -353,3 +403,160 @@ pub fn insert_deletion_checks(
 
     Ok(())
 }
+
+/// Inserts checks for every unique criterion of the model that is fully set by the write arguments,
+/// failing with a unique constraint violation on the criterion fields if a record with the same values
+/// exists already. For updates, `updated` is the filter of the updated record, which can't conflict
+/// with itself. Some connectors (SQLite, some MySQL setups) report unique violations without
+/// identifying the violated fields, the checks produce the precise error instead.
+/// Only done if the `uniqueValidation` feature flag is enabled.
+///
+/// The criteria that are not fully set by the arguments (e.g. values generated by the database or
+/// foreign keys set later by the graph) are left to the database.
+///
+/// Returns the node to execute the write through: the first check, or the write node itself without
+/// checks. Conditional branches have to point to it, for the checks to only run with the write.
+///
+/// ```text
+/// ┌────────────────────┐
+/// │ Find Record With   │
+/// │   Unique Values A  │──┐
+/// └────────────────────┘  │
+///            │            │
+///            ▼            │
+/// ┌────────────────────┐  │
+/// │ Find Record With   │  │ Fail if > 0
+/// │   Unique Values B  │  │
+/// └────────────────────┘  │
+///            │Fail if > 0 │
+///            ▼            │
+/// ┌────────────────────┐  │
+/// │       Empty        │◀─┘
+/// └────────────────────┘
+///            │
+///            ▼
+/// ┌────────────────────┐
+/// │       Create       │
+/// └────────────────────┘
+/// ```
+pub fn insert_unique_checks(
+    graph: &mut QueryGraph,
+    model: &ModelRef,
+    args: &WriteArgs,
+    write_node: &NodeRef,
+    updated: Option<&Filter>,
+) -> QueryGraphBuilderResult<NodeRef> {
+    if !feature_flags::get().uniqueValidation {
+        return Ok(*write_node);
+    }
+
+    let filters: Vec<(Vec<String>, Filter)> = unique_criteria(model)
+        .into_iter()
+        .filter_map(|fields| {
+            let pairs: Option<Vec<(ScalarFieldRef, PrismaValue)>> = fields
+                .iter()
+                .map(|field| match args.get_field_value(field.db_name()) {
+                    // Nulls never conflict with each other.
+                    Some(WriteExpression::Value(PrismaValue::Null)) => None,
+                    Some(WriteExpression::Value(value)) => Some((Arc::clone(field), value.clone())),
+                    _ => None,
+                })
+                .collect();
+
+            let names = fields.iter().map(|field| field.name.clone()).collect();
+
+            pairs.map(|pairs| {
+                let filter = RecordProjection::from(pairs).filter();
+
+                match updated {
+                    Some(updated) => (names, Filter::and(vec![filter, Filter::not(vec![updated.clone()])])),
+                    None => (names, filter),
+                }
+            })
+        })
+        .collect();
+
+    if filters.is_empty() {
+        return Ok(*write_node);
+    }
+
+    let noop_node = graph.create_node(Node::Empty);
+    let mut check_nodes = vec![];
+
+    for (fields, filter) in filters {
+        let read_node = graph.create_node(read_ids_infallible(
+            Arc::clone(model),
+            model.primary_identifier(),
+            filter,
+        ));
+
+        graph.create_edge(
+            &read_node,
+            &noop_node,
+            QueryGraphDependency::ParentProjection(
+                model.primary_identifier(),
+                Box::new(move |noop_node, existing_ids| {
+                    if !existing_ids.is_empty() {
+                        return Err(QueryGraphBuilderError::UniqueConstraintViolation { fields });
+                    }
+
+                    Ok(noop_node)
+                }),
+            ),
+        )?;
+
+        check_nodes.push(read_node);
+    }
+
+    for (prev, next) in check_nodes.iter().tuple_windows() {
+        graph.create_edge(prev, next, QueryGraphDependency::ExecutionOrder)?;
+    }
+
+    graph.create_edge(&noop_node, write_node, QueryGraphDependency::ExecutionOrder)?;
+
+    Ok(check_nodes[0])
+}
+
+/// The unique criterion the records of a bulk upsert conflict on: the first one all records set
+/// values for. Records leaving a criterion to the database (e.g. generated ids) can't conflict on it.
+pub fn upsert_conflict_target(model: &ModelRef, args: &[WriteArgs]) -> QueryGraphBuilderResult<Vec<ScalarFieldRef>> {
+    unique_criteria(model)
+        .into_iter()
+        .find(|fields| {
+            args.iter().all(|args| {
+                fields.iter().all(|field| match args.get_field_value(field.db_name()) {
+                    Some(WriteExpression::Value(PrismaValue::Null)) | None => false,
+                    Some(_) => true,
+                })
+            })
+        })
+        .ok_or_else(|| {
+            QueryGraphBuilderError::InputError(format!(
+                "The records to upsert into `{}` must all set the id or the same unique fields, to find the existing records.",
+                model.name
+            ))
+        })
+}
+
+/// All sets of scalar fields that uniquely identify a record of the model: the primary identifier,
+/// unique fields and unique indexes.
+fn unique_criteria(model: &ModelRef) -> Vec<Vec<ScalarFieldRef>> {
+    let mut criteria: Vec<Vec<ScalarFieldRef>> = vec![model.primary_identifier().scalar_fields().collect()];
+
+    criteria.extend(
+        model
+            .fields()
+            .scalar()
+            .into_iter()
+            .filter(|field| field.unique())
+            .map(|field| vec![field]),
+    );
+
+    criteria.extend(model.unique_indexes().into_iter().map(|index| index.fields()));
+
+    criteria
+        .into_iter()
+        .filter(|fields| !fields.is_empty())
+        .unique_by(|fields| fields.iter().map(|field| field.name.clone()).collect::<Vec<_>>())
+        .collect()
+}
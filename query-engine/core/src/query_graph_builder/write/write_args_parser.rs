@@ -1,5 +1,8 @@
 use super::*;
-use crate::query_document::{ParsedInputMap, ParsedInputValue};
+use crate::{
+    query_document::{ParsedInputMap, ParsedInputValue},
+    query_graph_builder::validate_native_value,
+};
 use connector::{WriteArgs, WriteExpression};
 use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef};
 use std::{convert::TryInto, sync::Arc};
@@ -21,21 +24,44 @@ impl WriteArgsParser {
 
                 match field {
                     Field::Scalar(sf) if sf.is_list => {
-                        let set_value: PrismaValue = match v {
-                            ParsedInputValue::List(_) => v.try_into()?,
-                            ParsedInputValue::Map(mut map) => map.remove("set").unwrap().try_into()?,
+                        let expr: WriteExpression = match v {
+                            ParsedInputValue::List(_) => {
+                                let value: PrismaValue = v.try_into()?;
+                                validate_native_value(&sf, &value)?;
+
+                                WriteExpression::Value(value)
+                            }
+                            ParsedInputValue::Map(map) => {
+                                let (operation, value) = map.into_iter().next().unwrap();
+                                let value: PrismaValue = value.try_into()?;
+                                validate_native_value(&sf, &value)?;
+
+                                match operation.as_str() {
+                                    "set" => WriteExpression::Value(value),
+                                    // A single element is pushed as a list of one.
+                                    "push" => match value {
+                                        PrismaValue::List(values) => WriteExpression::Push(PrismaValue::List(values)),
+                                        value => WriteExpression::Push(PrismaValue::List(vec![value])),
+                                    },
+                                    _ => unreachable!("Invalid scalar list update operation"),
+                                }
+                            }
                             _ => unreachable!(),
                         };
 
-                        args.args.insert(sf, set_value)
+                        args.args.insert(sf, expr)
                     }
 
                     Field::Scalar(sf) => {
                         let expr: WriteExpression = match v {
-                            ParsedInputValue::Single(v) => v.into(),
+                            ParsedInputValue::Single(v) => {
+                                validate_native_value(&sf, &v)?;
+                                v.into()
+                            }
                             ParsedInputValue::Map(map) => {
                                 let (operation, value) = map.into_iter().next().unwrap();
                                 let value: PrismaValue = value.try_into()?;
+                                validate_native_value(&sf, &value)?;
 
                                 match operation.as_str() {
                                     "set" => WriteExpression::Value(value),
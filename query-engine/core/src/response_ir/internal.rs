@@ -1,11 +1,12 @@
 use super::*;
 use crate::{
     schema::{IntoArc, ObjectTypeStrongRef, OutputType, OutputTypeRef, ScalarType},
-    CoreError, EnumType, OutputFieldRef, QueryResult, RecordAggregation, RecordSelection,
+    CoreError, EnumType, OutputFieldRef, QueryResult, RecordAggregation, RecordGroups, RecordSelection,
+    UnknownEnumValues, RELATION_COUNT_FIELD,
 };
-use connector::AggregationResult;
+use connector::{AggregationResult, RELATION_COUNT_PREFIX};
 use indexmap::IndexMap;
-use prisma_models::{InternalEnum, PrismaValue, RecordProjection};
+use prisma_models::{InternalEnum, PrismaValue, Record, RecordProjection};
 use rust_decimal::prelude::ToPrimitive;
 use std::{borrow::Borrow, collections::HashMap};
 
@@ -41,6 +42,7 @@ pub fn serialize_internal(
     match result {
         QueryResult::RecordSelection(rs) => serialize_record_selection(rs, field, &field.field_type, is_list),
         QueryResult::RecordAggregation(ra) => serialize_aggregation(ra),
+        QueryResult::RecordGroups(rg) => serialize_groups(rg),
 
         QueryResult::Count(c) => {
             // Todo needs a real implementation or needs to move to RecordAggregation
@@ -53,16 +55,69 @@ pub fn serialize_internal(
             Ok(result)
         }
 
+        QueryResult::AffectedIds(ids) => {
+            let mut map: Map = IndexMap::with_capacity(2);
+            let mut result = CheckedItemsWithParents::new();
+
+            map.insert("count".into(), Item::Value(PrismaValue::Int(ids.len() as i64)));
+            map.insert(
+                "ids".into(),
+                Item::list(ids.into_iter().map(serialize_record_projection).collect()),
+            );
+            result.insert(None, Item::Map(map));
+
+            Ok(result)
+        }
+
+        QueryResult::RecordCount(count) => {
+            let mut result = CheckedItemsWithParents::new();
+            result.insert(None, Item::Value(PrismaValue::Int(count as i64)));
+
+            Ok(result)
+        }
+
         QueryResult::Json(_) => unimplemented!(),
         QueryResult::Id(_) => unimplemented!(),
         QueryResult::Unit => unimplemented!(),
     }
 }
 
+/// Serializes the identifier of a record as a JSON object of its field names to their values, e.g. `{ "id": 1 }`.
+fn serialize_record_projection(projection: RecordProjection) -> Item {
+    let object: serde_json::Map<String, serde_json::Value> = projection
+        .pairs
+        .into_iter()
+        .map(|(field, value)| (field.name.clone(), serde_json::to_value(value).unwrap()))
+        .collect();
+
+    Item::Value(PrismaValue::Json(serde_json::Value::Object(object).to_string()))
+}
+
 fn serialize_aggregation(record_aggregation: RecordAggregation) -> crate::Result<CheckedItemsWithParents> {
-    let ordering = record_aggregation.selection_order;
-    let results = record_aggregation.results;
+    let inner_map = aggregation_map(&record_aggregation.selection_order, record_aggregation.results);
+
+    let mut envelope = CheckedItemsWithParents::new();
+    envelope.insert(None, Item::Map(inner_map));
+
+    Ok(envelope)
+}
+
+fn serialize_groups(record_groups: RecordGroups) -> crate::Result<CheckedItemsWithParents> {
+    let ordering = record_groups.selection_order;
+    let groups = record_groups
+        .groups
+        .into_iter()
+        .map(|results| Item::Map(aggregation_map(&ordering, results)))
+        .collect();
+
+    let mut envelope = CheckedItemsWithParents::new();
+    envelope.insert(None, Item::list(groups));
+
+    Ok(envelope)
+}
 
+/// Maps the aggregation results to the selection of the original query, in order.
+fn aggregation_map(ordering: &[(String, Option<Vec<String>>)], results: Vec<AggregationResult>) -> Map {
     let mut flattened = HashMap::with_capacity(ordering.len());
 
     for result in results {
@@ -86,6 +141,10 @@ fn serialize_aggregation(record_aggregation: RecordAggregation) -> crate::Result
             AggregationResult::Max(field, value) => {
                 flattened.insert(format!("max_{}", &field.name), Item::Value(value));
             }
+
+            AggregationResult::Field(field, value) => {
+                flattened.insert(field.name.clone(), Item::Value(value));
+            }
         }
     }
 
@@ -97,20 +156,17 @@ fn serialize_aggregation(record_aggregation: RecordAggregation) -> crate::Result
 
             for field in order {
                 let item = flattened.remove(&format!("{}_{}", query, field)).unwrap();
-                nested_map.insert(field, item);
+                nested_map.insert(field.clone(), item);
             }
 
-            inner_map.insert(query, Item::Map(nested_map));
+            inner_map.insert(query.clone(), Item::Map(nested_map));
         } else {
-            let item = flattened.remove(&query).unwrap();
-            inner_map.insert(query, item);
+            let item = flattened.remove(query).unwrap();
+            inner_map.insert(query.clone(), item);
         }
     }
 
-    let mut envelope = CheckedItemsWithParents::new();
-    envelope.insert(None, Item::Map(inner_map));
-
-    Ok(envelope)
+    inner_map
 }
 
 fn serialize_record_selection(
@@ -219,6 +275,28 @@ fn serialize_objects(
         .filter_map(|f| model.map_scalar_db_field_name(f).map(|x| x.name.clone()))
         .collect();
 
+    // Computed fields are derived from the scalars of the record, which are selected alongside them.
+    let computed_fields: Vec<_> = result
+        .fields
+        .iter()
+        .filter_map(|field_name| typ.find_field(field_name))
+        .filter(|field| field.computed.is_some())
+        .collect();
+
+    // Relation counts are read after the scalars, named after the counted relation fields.
+    let relation_counts: Vec<(usize, String)> = scalar_db_field_names
+        .iter()
+        .enumerate()
+        .filter(|(_, name)| name.starts_with(RELATION_COUNT_PREFIX))
+        .map(|(idx, name)| (idx, name[RELATION_COUNT_PREFIX.len()..].to_owned()))
+        .collect();
+
+    if nested_mapping.is_empty() && computed_fields.is_empty() && relation_counts.is_empty() {
+        if let Some(selection) = scalar_selection(&result.fields, &field_names, &typ) {
+            return serialize_scalar_objects(&model.name, result.scalars.records, &selection, object_mapping);
+        }
+    }
+
     // Write all fields, nested and list fields unordered into a map, afterwards order all into the final order.
     // If nothing is written to the object, write null instead.
     for record in result.scalars.records.into_iter() {
@@ -230,13 +308,42 @@ fn serialize_objects(
 
         // Write scalars, but skip objects and lists, which while they are in the selection, are handled separately.
         let values = record.values;
-        let mut object = HashMap::with_capacity(values.len());
+        let mut object = HashMap::with_capacity(values.len() + computed_fields.len());
+
+        for field in computed_fields.iter() {
+            let computed = field.computed.as_ref().unwrap();
+            let value = computed.evaluate(|name| {
+                field_names
+                    .iter()
+                    .position(|field_name| field_name == name)
+                    .map(|idx| &values[idx])
+            });
+
+            object.insert(field.name.clone(), serialize_scalar(&model.name, field, value)?);
+        }
+
+        if !relation_counts.is_empty() {
+            let mut counts = Map::with_capacity(relation_counts.len());
+
+            for (idx, name) in relation_counts.iter() {
+                counts.insert(name.clone(), Item::Value(values[*idx].clone()));
+            }
+
+            object.insert(RELATION_COUNT_FIELD.to_owned(), Item::Map(counts));
+        }
 
         for (val, scalar_field_name) in values.into_iter().zip(field_names.iter()) {
-            let field = typ.find_field(scalar_field_name).unwrap();
+            // Scalars that are only selected to compute other fields may be omitted from the output type.
+            let field = match typ.find_field(scalar_field_name) {
+                Some(field) => field,
+                None => continue,
+            };
 
             if !field.field_type.is_object() {
-                object.insert(scalar_field_name.to_owned(), serialize_scalar(&field, val)?);
+                object.insert(
+                    scalar_field_name.to_owned(),
+                    serialize_scalar(&model.name, &field, val)?,
+                );
             }
         }
 
@@ -266,6 +373,55 @@ fn serialize_objects(
     Ok(object_mapping)
 }
 
+/// A selected scalar field, with the index of its value in the records.
+type SelectedScalar = (String, OutputFieldRef, usize);
+
+/// Resolves the output fields and value indexes of a selection once for all records, if all the selected
+/// fields are scalars read from the records. Returns `None` otherwise.
+fn scalar_selection(
+    selected_fields: &[String],
+    field_names: &[String],
+    typ: &ObjectTypeStrongRef,
+) -> Option<Vec<SelectedScalar>> {
+    selected_fields
+        .iter()
+        .map(|field_name| {
+            let field = typ
+                .find_field(field_name)
+                .filter(|field| !field.field_type.is_object())?;
+            let idx = field_names.iter().position(|name| name == field_name)?;
+
+            Some((field_name.clone(), field, idx))
+        })
+        .collect()
+}
+
+/// Fast path of `serialize_objects` for selections of scalars only: the values are moved from the records
+/// straight into the objects, in selection order, without intermediate maps or per-record field lookups.
+fn serialize_scalar_objects(
+    model_name: &str,
+    records: Vec<Record>,
+    selection: &[SelectedScalar],
+    mut object_mapping: UncheckedItemsWithParents,
+) -> crate::Result<UncheckedItemsWithParents> {
+    for record in records {
+        let mut values = record.values;
+        let mut map = Map::with_capacity(selection.len());
+
+        for (field_name, field, idx) in selection {
+            let value = std::mem::replace(&mut values[*idx], PrismaValue::Null);
+            map.insert(field_name.clone(), serialize_scalar(model_name, field, value)?);
+        }
+
+        object_mapping
+            .entry(record.parent_id)
+            .or_insert_with(Vec::new)
+            .push(Item::Map(map));
+    }
+
+    Ok(object_mapping)
+}
+
 /// Unwraps are safe due to query validation.
 fn write_nested_items(
     record_id: &Option<RecordProjection>,
@@ -324,11 +480,11 @@ fn process_nested_results(
     Ok(nested_mapping)
 }
 
-fn serialize_scalar(field: &OutputFieldRef, value: PrismaValue) -> crate::Result<Item> {
+fn serialize_scalar(model_name: &str, field: &OutputFieldRef, value: PrismaValue) -> crate::Result<Item> {
     match (&value, field.field_type.as_ref()) {
         (PrismaValue::Null, _) if !field.is_required => Ok(Item::Value(PrismaValue::Null)),
         (_, OutputType::Enum(et)) => match et.borrow() {
-            EnumType::Internal(ref i) => convert_enum(value, i),
+            EnumType::Internal(ref i) => convert_enum(model_name, field, value, i),
             _ => unreachable!(),
         },
         (PrismaValue::List(_), OutputType::List(arc_type)) => match arc_type.as_ref() {
@@ -344,7 +500,7 @@ fn serialize_scalar(field: &OutputFieldRef, value: PrismaValue) -> crate::Result
                 let items = unwrap_prisma_value(value)
                     .into_iter()
                     .map(|v| match et.borrow() {
-                        EnumType::Internal(ref i) => convert_enum(v, i),
+                        EnumType::Internal(ref i) => convert_enum(model_name, field, v, i),
                         _ => unreachable!(),
                     })
                     .collect::<Result<Vec<Item>, CoreError>>()?;
@@ -363,6 +519,9 @@ fn serialize_scalar(field: &OutputFieldRef, value: PrismaValue) -> crate::Result
     }
 }
 
+/// 2^53 - 1, the largest integer JS numbers represent exactly, as is every integer below it.
+const MAX_SAFE_INTEGER: i64 = 9_007_199_254_740_991;
+
 fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaValue, CoreError> {
     let item_value = match (st, value) {
         (ScalarType::String, PrismaValue::String(s)) => PrismaValue::String(s),
@@ -373,11 +532,24 @@ fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaVal
         (ScalarType::Int, PrismaValue::Float(f)) => PrismaValue::Int(f.to_i64().unwrap()),
         (ScalarType::Int, PrismaValue::Int(i)) => PrismaValue::Int(i),
 
+        // BigInts beyond the range JS numbers can represent exactly are returned as strings.
+        (ScalarType::BigInt, PrismaValue::Int(i)) if !(-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&i) => {
+            PrismaValue::String(i.to_string())
+        }
+        (ScalarType::BigInt, PrismaValue::Int(i)) => PrismaValue::Int(i),
+
         (ScalarType::Float, PrismaValue::Float(f)) => PrismaValue::Float(f),
         (ScalarType::Float, PrismaValue::Int(i)) => {
             PrismaValue::Int(i.to_i64().expect("Unable to convert Decimal to i64."))
         }
 
+        // Decimals are returned as strings, so that clients don't lose precision parsing them as floats.
+        (ScalarType::Decimal, PrismaValue::Float(f)) => PrismaValue::String(f.normalize().to_string()),
+        (ScalarType::Decimal, PrismaValue::Int(i)) => PrismaValue::String(i.to_string()),
+
+        // Bytes are serialized base64-encoded.
+        (ScalarType::Bytes, PrismaValue::Bytes(b)) => PrismaValue::Bytes(b),
+
         (ScalarType::Boolean, PrismaValue::Boolean(b)) => PrismaValue::Boolean(b),
         (ScalarType::DateTime, PrismaValue::DateTime(dt)) => PrismaValue::DateTime(dt),
         (ScalarType::UUID, PrismaValue::Uuid(u)) => PrismaValue::Uuid(u),
@@ -393,14 +565,26 @@ fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaVal
     Ok(item_value)
 }
 
-fn convert_enum(value: PrismaValue, i: &InternalEnum) -> Result<Item, CoreError> {
+/// Values the datamodel doesn't know are handled according to the `UnknownEnumValues` policy of the field.
+fn convert_enum(
+    model_name: &str,
+    field: &OutputFieldRef,
+    value: PrismaValue,
+    i: &InternalEnum,
+) -> Result<Item, CoreError> {
     match value {
         PrismaValue::String(s) | PrismaValue::Enum(s) => match i.map_output_value(&s) {
             Some(inum) => Ok(Item::Value(inum)),
-            None => Err(CoreError::SerializationError(format!(
-                "Value '{}' not found in enum '{:?}'",
-                s, i
-            ))),
+            None => match field.unknown_enum_values {
+                UnknownEnumValues::Error => Err(CoreError::UnknownEnumValue {
+                    model_name: model_name.to_owned(),
+                    field_name: field.name.clone(),
+                    enum_name: i.name.clone(),
+                    value: s,
+                }),
+                UnknownEnumValues::RawString => Ok(Item::Value(PrismaValue::String(s))),
+                UnknownEnumValues::Null => Ok(Item::Value(PrismaValue::Null)),
+            },
         },
 
         val => Err(CoreError::SerializationError(format!(
@@ -5,9 +5,12 @@ use prisma_models::{ManyRecords, ModelProjection, RecordProjection};
 pub enum QueryResult {
     Id(Option<RecordProjection>),
     Count(usize),
+    AffectedIds(Vec<RecordProjection>),
     RecordSelection(RecordSelection),
     Json(serde_json::Value),
     RecordAggregation(RecordAggregation),
+    RecordCount(usize),
+    RecordGroups(RecordGroups),
     Unit,
 }
 
@@ -42,3 +45,12 @@ pub struct RecordAggregation {
     /// Actual aggregation results.
     pub results: Vec<AggregationResult>,
 }
+
+#[derive(Debug, Clone)]
+pub struct RecordGroups {
+    /// Ordered list of selected fields as defined by the original incoming query.
+    pub selection_order: Vec<(String, Option<Vec<String>>)>,
+
+    /// The grouped field values and aggregation results of every group.
+    pub groups: Vec<Vec<AggregationResult>>,
+}
@@ -1,5 +1,5 @@
 use super::*;
-use crate::{ParsedField, QueryGraph, QueryGraphBuilderResult};
+use crate::{ComputedFieldRef, ParsedField, QueryGraph, QueryGraphBuilderResult, UnknownEnumValues};
 use fmt::Debug;
 use once_cell::sync::OnceCell;
 use prisma_models::{dml, InternalDataModelRef, ModelRef};
@@ -167,6 +167,17 @@ pub struct OutputField {
     /// As opposed to input fields, optional output fields are also automatically nullable.
     pub is_required: bool,
     pub query_builder: Option<SchemaQueryBuilder>,
+
+    /// Set on computed fields, whose values are derived from the other fields of the record
+    /// during serialization instead of being read from the database.
+    pub computed: Option<ComputedFieldRef>,
+
+    /// What enum fields of models return for values read from the database that the datamodel
+    /// doesn't know.
+    pub unknown_enum_values: UnknownEnumValues,
+
+    /// The reason the field is deprecated for, from `@deprecated` or `@@deprecated` in the datamodel.
+    pub deprecation: Option<String>,
 }
 
 impl OutputField {
@@ -179,6 +190,16 @@ impl OutputField {
         self
     }
 
+    pub fn computed(mut self, computed: ComputedFieldRef) -> Self {
+        self.computed = Some(computed);
+        self
+    }
+
+    pub fn unknown_enum_values(mut self, policy: UnknownEnumValues) -> Self {
+        self.unknown_enum_values = policy;
+        self
+    }
+
     pub fn optional_if(self, condition: bool) -> Self {
         if condition {
             self.optional()
@@ -186,6 +207,11 @@ impl OutputField {
             self
         }
     }
+
+    pub fn deprecation(mut self, reason: Option<&str>) -> Self {
+        self.deprecation = reason.map(ToOwned::to_owned);
+        self
+    }
 }
 
 /// Todo rework description.
@@ -256,7 +282,10 @@ pub enum QueryTag {
     DeleteOne,
     DeleteMany,
     UpsertOne,
+    UpsertMany,
     Aggregate,
+    Count,
+    GroupBy,
 }
 
 impl fmt::Display for QueryTag {
@@ -270,7 +299,10 @@ impl fmt::Display for QueryTag {
             QueryTag::DeleteOne => "deleteOne",
             QueryTag::DeleteMany => "deleteMany",
             QueryTag::UpsertOne => "upsertOne",
+            QueryTag::UpsertMany => "upsertMany",
             QueryTag::Aggregate => "aggregate",
+            QueryTag::Count => "count",
+            QueryTag::GroupBy => "groupBy",
         };
 
         write!(f, "{}", s)
@@ -287,6 +319,9 @@ pub struct InputObjectType {
     pub name: String,
     pub constraints: InputObjectTypeConstraints,
     pub fields: OnceCell<Vec<InputFieldRef>>,
+
+    /// Fields of the model computed by the database, they are not part of the input.
+    pub read_only_fields: Vec<String>,
 }
 
 #[derive(Debug, Default, PartialEq)]
@@ -304,6 +339,7 @@ impl Debug for InputObjectType {
             .field("name", &self.name)
             .field("constraints", &self.constraints)
             .field("fields", &"#Input Fields Cell#")
+            .field("read_only_fields", &self.read_only_fields)
             .finish()
     }
 }
@@ -332,6 +368,22 @@ impl InputObjectType {
         self.get_fields().into_iter().find(|f| f.name == name).cloned()
     }
 
+    /// Whether the field is computed by the database, and can not be part of the input.
+    pub fn is_read_only_field(&self, name: &str) -> bool {
+        self.read_only_fields.iter().any(|f| f == name)
+    }
+
+    /// Rejects writes to the fields of the model computed by the database with a dedicated error.
+    pub fn reject_computed_fields_of(&mut self, model: &ModelRef) {
+        self.read_only_fields = model
+            .fields()
+            .scalar()
+            .into_iter()
+            .filter(|f| f.is_computed)
+            .map(|f| f.name.clone())
+            .collect();
+    }
+
     /// Allow exactly one field of the possible ones to be in the input.
     pub fn require_exactly_one_field(&mut self) {
         self.set_max_fields(1);
@@ -366,6 +418,9 @@ pub struct InputField {
     /// Indicates if the presence of the field on the higher input objects
     /// is required, but doesn't state whether or not the input can be null.
     pub is_required: bool,
+
+    /// The reason the field is deprecated for, from `@deprecated` in the datamodel.
+    pub deprecation: Option<String>,
 }
 
 impl InputField {
@@ -394,6 +449,12 @@ impl InputField {
         self.field_types.push(typ);
         self
     }
+
+    /// Marks the field as deprecated for `reason`, if there is one.
+    pub fn deprecation(mut self, reason: Option<&str>) -> Self {
+        self.deprecation = reason.map(ToOwned::to_owned);
+        self
+    }
 }
 
 #[derive(Clone)]
@@ -448,6 +509,18 @@ impl InputType {
         InputType::Scalar(ScalarType::Float)
     }
 
+    pub fn bytes() -> InputType {
+        InputType::Scalar(ScalarType::Bytes)
+    }
+
+    pub fn big_int() -> InputType {
+        InputType::Scalar(ScalarType::BigInt)
+    }
+
+    pub fn decimal() -> InputType {
+        InputType::Scalar(ScalarType::Decimal)
+    }
+
     pub fn boolean() -> InputType {
         InputType::Scalar(ScalarType::Boolean)
     }
@@ -506,6 +579,18 @@ impl OutputType {
         OutputType::Scalar(ScalarType::Float)
     }
 
+    pub fn bytes() -> OutputType {
+        OutputType::Scalar(ScalarType::Bytes)
+    }
+
+    pub fn big_int() -> OutputType {
+        OutputType::Scalar(ScalarType::BigInt)
+    }
+
+    pub fn decimal() -> OutputType {
+        OutputType::Scalar(ScalarType::Decimal)
+    }
+
     pub fn boolean() -> OutputType {
         OutputType::Scalar(ScalarType::Boolean)
     }
@@ -553,7 +638,10 @@ pub enum ScalarType {
     Null,
     String,
     Int,
+    BigInt,
     Float,
+    Decimal,
+    Bytes,
     Boolean,
     Enum(EnumTypeRef),
     DateTime,
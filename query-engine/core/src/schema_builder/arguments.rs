@@ -70,6 +70,17 @@ pub(crate) fn update_many_arguments(ctx: &mut BuilderContext, model: &ModelRef)
     vec![input_field("data", InputType::object(update_object), None), where_arg]
 }
 
+/// Builds "data" argument intended for the upsert many field.
+pub(crate) fn upsert_many_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
+    let input_object = input_types::create_input_objects::upsert_many_input_type(ctx, model);
+
+    vec![input_field(
+        "data",
+        InputType::list(InputType::object(input_object)),
+        None,
+    )]
+}
+
 /// Builds "where" argument intended for the delete many field.
 pub(crate) fn delete_many_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
     let where_arg = where_argument(ctx, model);
@@ -92,30 +103,62 @@ pub(crate) fn many_records_field_arguments(ctx: &mut BuilderContext, field: &Mod
 
 /// Builds "many records where" arguments solely based on the given model.
 pub(crate) fn many_records_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
-    let unique_input_type = InputType::object(input_types::filter_input_objects::where_unique_object_type(ctx, model));
+    let mut args = filter_and_pagination_arguments(ctx, model);
+    let enum_type = scalar_field_enum_type(ctx, model, "DistinctFieldEnum");
 
-    let mut args = vec![
+    args.push(input_field("distinct", InputType::list(InputType::Enum(enum_type)), None).optional());
+    args
+}
+
+/// Builds the arguments of the groupBy field: "where", and the fields to group by.
+pub(crate) fn group_by_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
+    let enum_type = scalar_field_enum_type(ctx, model, "GroupByFieldEnum");
+
+    vec![
         where_argument(ctx, &model),
-        order_by_argument(ctx, &model),
-        input_field("cursor", unique_input_type.clone(), None).optional(),
-        input_field("take", InputType::int(), None).optional(),
-        input_field("skip", InputType::int(), None).optional(),
-    ];
+        input_field("by", InputType::list(InputType::Enum(enum_type)), None),
+    ]
+}
 
-    let enum_type = Arc::new(EnumType::FieldRef(FieldRefEnumType {
-        name: format!("{}DistinctFieldEnum", capitalize(&model.name)),
+/// An enum referencing the scalar fields of the model, named after the model and the suffix. Omitted
+/// fields are left out, their values can't be told apart through grouping, and so are transformed
+/// fields, the database only holds their transformed values.
+fn scalar_field_enum_type(ctx: &BuilderContext, model: &ModelRef, suffix: &str) -> EnumTypeRef {
+    Arc::new(EnumType::FieldRef(FieldRefEnumType {
+        name: format!("{}{}", capitalize(&model.name), suffix),
         values: model
             .fields()
             .scalar()
             .into_iter()
+            .filter(|field| {
+                let field = ModelField::Scalar(field.clone());
+                !ctx.is_omitted(model, &field) && !ctx.is_transformed(model, &field)
+            })
             .map(|field| (field.name.clone(), field))
             .collect(),
-    }));
+    }))
+}
 
-    args.push(input_field("distinct", InputType::list(InputType::Enum(enum_type)), None).optional());
+/// Builds the arguments of the count field: the "many records where" arguments without "distinct", and "estimate".
+pub(crate) fn count_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
+    let mut args = filter_and_pagination_arguments(ctx, model);
+
+    args.push(input_field("estimate", InputType::boolean(), None).optional());
     args
 }
 
+fn filter_and_pagination_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
+    let unique_input_type = InputType::object(input_types::filter_input_objects::where_unique_object_type(ctx, model));
+
+    vec![
+        where_argument(ctx, &model),
+        order_by_argument(ctx, &model),
+        input_field("cursor", unique_input_type, None).optional(),
+        input_field("take", InputType::int(), None).optional(),
+        input_field("skip", InputType::int(), None).optional(),
+    ]
+}
+
 // Builds "orderBy" argument.
 pub(crate) fn order_by_argument(ctx: &mut BuilderContext, model: &ModelRef) -> InputField {
     let order_object_type = InputType::object(input_types::order_by_object_type(ctx, model));
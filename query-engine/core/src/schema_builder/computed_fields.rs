@@ -0,0 +1,544 @@
+use prisma_models::{InternalDataModel, ModelRef, PrismaValue, TypeIdentifier};
+use rust_decimal::Decimal;
+use std::{collections::HashMap, fmt, iter::Peekable, str::Chars, str::FromStr, sync::Arc};
+
+pub type ComputedFieldRef = Arc<ComputedField>;
+
+/// Read-only fields added to the model output object types, whose values are derived from the
+/// other scalar fields of the record at serialization time, e.g. `fullName` from `firstName` and
+/// `lastName`. They are declared as `Model.field = expression`, separated by semicolons:
+///
+/// ```text
+/// User.fullName = firstName + ' ' + lastName; Order.total = (price * quantity) - discount
+/// ```
+///
+/// Expressions are made of the names of `String`, `Int` and `Float` fields of the model, string
+/// literals in single quotes, numbers, parentheses and the `+`, `-`, `*` and `/` operators.
+/// `+` concatenates as soon as one of its operands is a string. Divisions are always `Float`.
+///
+/// Computed fields are nullable: they are `null` if one of the values they depend on is `null`,
+/// and for divisions by zero and overflows.
+#[derive(Debug, Clone, Default)]
+pub struct ComputedFields {
+    fields: HashMap<String, Vec<ComputedFieldRef>>,
+}
+
+impl ComputedFields {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses semicolon-separated `Model.field = expression` declarations.
+    pub fn parse(declarations: &str) -> Result<Self, String> {
+        let mut fields = Self::new();
+
+        for declaration in split_declarations(declarations) {
+            let mut parts = declaration.splitn(2, '=');
+            let target = parts.next().unwrap_or("").trim();
+            let expression = parts.next().map(str::trim).unwrap_or("");
+
+            match target.split('.').map(str::trim).collect::<Vec<_>>().as_slice() {
+                [model, field] if !model.is_empty() && !field.is_empty() && !expression.is_empty() => {
+                    fields.insert(*model, ComputedField::parse(*field, expression)?);
+                }
+                _ => {
+                    return Err(format!(
+                        "Invalid computed field `{}`, expected `Model.field = expression`.",
+                        declaration
+                    ))
+                }
+            }
+        }
+
+        Ok(fields)
+    }
+
+    pub fn insert(&mut self, model: impl Into<String>, field: ComputedField) {
+        self.fields.entry(model.into()).or_default().push(Arc::new(field));
+    }
+
+    pub fn extend(&mut self, other: ComputedFields) {
+        for (model, fields) in other.fields {
+            self.fields.entry(model).or_default().extend(fields);
+        }
+    }
+
+    /// The computed fields of the given model, in declaration order.
+    pub fn on_model<'a>(&'a self, model: &str) -> impl Iterator<Item = &'a ComputedFieldRef> + 'a {
+        self.fields.get(model).into_iter().flatten()
+    }
+
+    /// Iterates over all computed fields as `(model, field)` pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ComputedFieldRef)> {
+        self.fields
+            .iter()
+            .flat_map(|(model, fields)| fields.iter().map(move |field| (model.as_str(), field)))
+    }
+
+    /// Checks that the computed fields are declared on existing models, don't shadow other fields,
+    /// and only depend on scalars of supported types.
+    pub fn validate(&self, internal_data_model: &InternalDataModel) -> Result<(), String> {
+        for (model_name, field) in self.iter() {
+            let model = internal_data_model.find_model(model_name).map_err(|_| {
+                format!(
+                    "Cannot compute `{}.{}`: the model does not exist.",
+                    model_name, field.name
+                )
+            })?;
+
+            if model.fields().find_from_all(&field.name).is_ok() {
+                return Err(format!(
+                    "Cannot compute `{}.{}`: the model already has a field with that name.",
+                    model_name, field.name
+                ));
+            }
+
+            if self
+                .on_model(model_name)
+                .filter(|other| other.name == field.name)
+                .count()
+                > 1
+            {
+                return Err(format!("`{}.{}` is computed more than once.", model_name, field.name));
+            }
+
+            field
+                .output_type(&model)
+                .map_err(|err| format!("Cannot compute `{}.{}`: {}", model_name, field.name, err))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Splits the declarations on the semicolons outside of string literals.
+fn split_declarations(declarations: &str) -> Vec<&str> {
+    let mut result = vec![];
+    let mut start = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (idx, c) in declarations.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '\'' => in_string = !in_string,
+            ';' if !in_string => {
+                result.push(&declarations[start..idx]);
+                start = idx + 1;
+            }
+            _ => (),
+        }
+    }
+
+    result.push(&declarations[start..]);
+    result.into_iter().map(str::trim).filter(|d| !d.is_empty()).collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComputedField {
+    pub name: String,
+    pub expression: Expression,
+}
+
+/// The type of the values of a computed field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ComputedType {
+    String,
+    Int,
+    Float,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression {
+    Field(String),
+    String(String),
+    Int(i64),
+    Float(Decimal),
+    Binary(Box<Expression>, Operator, Box<Expression>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operator::Add => f.write_str("+"),
+            Operator::Subtract => f.write_str("-"),
+            Operator::Multiply => f.write_str("*"),
+            Operator::Divide => f.write_str("/"),
+        }
+    }
+}
+
+impl ComputedField {
+    pub fn parse(name: impl Into<String>, expression: &str) -> Result<Self, String> {
+        let name = name.into();
+        let mut parser = Parser {
+            chars: expression.chars().peekable(),
+        };
+
+        let parsed = parser
+            .expression()
+            .and_then(|parsed| match parser.next_token()? {
+                None => Ok(parsed),
+                Some(token) => Err(format!("unexpected `{}`", token)),
+            })
+            .map_err(|err| format!("Invalid expression for computed field `{}`: {}.", name, err))?;
+
+        Ok(Self {
+            name,
+            expression: parsed,
+        })
+    }
+
+    /// The names of the scalar fields the value is computed from.
+    pub fn dependencies(&self) -> Vec<&str> {
+        let mut dependencies = vec![];
+        self.expression.collect_fields(&mut dependencies);
+        dependencies
+    }
+
+    /// The type of the computed values on the given model.
+    pub fn output_type(&self, model: &ModelRef) -> Result<ComputedType, String> {
+        self.expression.output_type(model)
+    }
+
+    /// Computes the value from the values of the other fields of the record.
+    pub fn evaluate<'a>(&self, value_of: impl Fn(&str) -> Option<&'a PrismaValue>) -> PrismaValue {
+        self.expression.evaluate(&value_of).unwrap_or(PrismaValue::Null)
+    }
+}
+
+impl Expression {
+    fn collect_fields<'a>(&'a self, into: &mut Vec<&'a str>) {
+        match self {
+            Expression::Field(name) if !into.contains(&name.as_str()) => into.push(name),
+            Expression::Binary(left, _, right) => {
+                left.collect_fields(into);
+                right.collect_fields(into);
+            }
+            _ => (),
+        }
+    }
+
+    fn output_type(&self, model: &ModelRef) -> Result<ComputedType, String> {
+        match self {
+            Expression::Field(name) => match model.fields().find_from_scalar(name) {
+                Ok(field) if !field.is_list => match field.type_identifier {
+                    TypeIdentifier::String => Ok(ComputedType::String),
+                    TypeIdentifier::Int | TypeIdentifier::BigInt => Ok(ComputedType::Int),
+                    TypeIdentifier::Float => Ok(ComputedType::Float),
+                    _ => Err(format!("`{}` is not a String, Int or Float field.", name)),
+                },
+                Ok(_) => Err(format!("`{}` is a list.", name)),
+                Err(_) => Err(format!("`{}` is not a scalar field of the model.", name)),
+            },
+            Expression::String(_) => Ok(ComputedType::String),
+            Expression::Int(_) => Ok(ComputedType::Int),
+            Expression::Float(_) => Ok(ComputedType::Float),
+            Expression::Binary(left, op, right) => match (left.output_type(model)?, *op, right.output_type(model)?) {
+                (ComputedType::String, Operator::Add, _) | (_, Operator::Add, ComputedType::String) => {
+                    Ok(ComputedType::String)
+                }
+                (ComputedType::String, op, _) | (_, op, ComputedType::String) => {
+                    Err(format!("`{}` can not be applied to strings.", op))
+                }
+                (_, Operator::Divide, _) => Ok(ComputedType::Float),
+                (ComputedType::Int, _, ComputedType::Int) => Ok(ComputedType::Int),
+                _ => Ok(ComputedType::Float),
+            },
+        }
+    }
+
+    /// `None` stands for `null`.
+    fn evaluate<'a>(&self, value_of: &dyn Fn(&str) -> Option<&'a PrismaValue>) -> Option<PrismaValue> {
+        match self {
+            Expression::Field(name) => match value_of(name) {
+                None | Some(PrismaValue::Null) => None,
+                Some(value) => Some(value.clone()),
+            },
+            Expression::String(s) => Some(PrismaValue::String(s.clone())),
+            Expression::Int(i) => Some(PrismaValue::Int(*i)),
+            Expression::Float(f) => Some(PrismaValue::Float(*f)),
+            Expression::Binary(left, op, right) => {
+                let left = left.evaluate(value_of)?;
+                let right = right.evaluate(value_of)?;
+
+                match (left, *op, right) {
+                    (PrismaValue::String(l), Operator::Add, r) => Some(PrismaValue::String(l + &to_text(r)?)),
+                    (l, Operator::Add, PrismaValue::String(r)) => Some(PrismaValue::String(to_text(l)? + &r)),
+                    (PrismaValue::Int(l), Operator::Add, PrismaValue::Int(r)) => l.checked_add(r).map(PrismaValue::Int),
+                    (PrismaValue::Int(l), Operator::Subtract, PrismaValue::Int(r)) => {
+                        l.checked_sub(r).map(PrismaValue::Int)
+                    }
+                    (PrismaValue::Int(l), Operator::Multiply, PrismaValue::Int(r)) => {
+                        l.checked_mul(r).map(PrismaValue::Int)
+                    }
+                    (l, op, r) => {
+                        let (l, r) = (to_decimal(l)?, to_decimal(r)?);
+
+                        let result = match op {
+                            Operator::Add => l.checked_add(r),
+                            Operator::Subtract => l.checked_sub(r),
+                            Operator::Multiply => l.checked_mul(r),
+                            Operator::Divide => l.checked_div(r),
+                        };
+
+                        result.map(|decimal| PrismaValue::Float(decimal.normalize()))
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn to_text(value: PrismaValue) -> Option<String> {
+    match value {
+        PrismaValue::String(s) => Some(s),
+        PrismaValue::Int(i) => Some(i.to_string()),
+        PrismaValue::Float(f) => Some(f.normalize().to_string()),
+        _ => None,
+    }
+}
+
+fn to_decimal(value: PrismaValue) -> Option<Decimal> {
+    match value {
+        PrismaValue::Int(i) => Some(Decimal::from(i)),
+        PrismaValue::Float(f) => Some(f),
+        _ => None,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Identifier(String),
+    String(String),
+    Number(String),
+    Operator(Operator),
+    OpenParen,
+    CloseParen,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Identifier(name) => f.write_str(name),
+            Token::String(s) => write!(f, "'{}'", s),
+            Token::Number(n) => f.write_str(n),
+            Token::Operator(op) => write!(f, "{}", op),
+            Token::OpenParen => f.write_str("("),
+            Token::CloseParen => f.write_str(")"),
+        }
+    }
+}
+
+/// Recursive descent parser, `*` and `/` binding tighter than `+` and `-`.
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn expression(&mut self) -> Result<Expression, String> {
+        self.binary(&[Operator::Add, Operator::Subtract], Self::term)
+    }
+
+    fn term(&mut self) -> Result<Expression, String> {
+        self.binary(&[Operator::Multiply, Operator::Divide], Self::atom)
+    }
+
+    fn binary(
+        &mut self,
+        operators: &[Operator],
+        operand: fn(&mut Self) -> Result<Expression, String>,
+    ) -> Result<Expression, String> {
+        let mut expression = operand(self)?;
+
+        loop {
+            match self.peek_operator() {
+                Some(op) if operators.contains(&op) => {
+                    self.next_token()?;
+                    expression = Expression::Binary(Box::new(expression), op, Box::new(operand(self)?));
+                }
+                _ => return Ok(expression),
+            }
+        }
+    }
+
+    fn atom(&mut self) -> Result<Expression, String> {
+        match self.next_token()? {
+            Some(Token::Identifier(name)) => Ok(Expression::Field(name)),
+            Some(Token::String(s)) => Ok(Expression::String(s)),
+            Some(Token::Number(n)) if n.contains('.') => Decimal::from_str(&n)
+                .map(Expression::Float)
+                .map_err(|_| format!("invalid number `{}`", n)),
+            Some(Token::Number(n)) => n
+                .parse()
+                .map(Expression::Int)
+                .map_err(|_| format!("invalid number `{}`", n)),
+            Some(Token::OpenParen) => {
+                let expression = self.expression()?;
+
+                match self.next_token()? {
+                    Some(Token::CloseParen) => Ok(expression),
+                    Some(token) => Err(format!("expected `)`, found `{}`", token)),
+                    None => Err("expected `)`".to_owned()),
+                }
+            }
+            Some(token) => Err(format!("unexpected `{}`", token)),
+            None => Err("unexpected end of the expression".to_owned()),
+        }
+    }
+
+    fn peek_operator(&mut self) -> Option<Operator> {
+        self.skip_whitespace();
+
+        match self.chars.peek() {
+            Some('+') => Some(Operator::Add),
+            Some('-') => Some(Operator::Subtract),
+            Some('*') => Some(Operator::Multiply),
+            Some('/') => Some(Operator::Divide),
+            _ => None,
+        }
+    }
+
+    fn next_token(&mut self) -> Result<Option<Token>, String> {
+        self.skip_whitespace();
+
+        let token = match self.chars.next() {
+            None => return Ok(None),
+            Some('+') => Token::Operator(Operator::Add),
+            Some('-') => Token::Operator(Operator::Subtract),
+            Some('*') => Token::Operator(Operator::Multiply),
+            Some('/') => Token::Operator(Operator::Divide),
+            Some('(') => Token::OpenParen,
+            Some(')') => Token::CloseParen,
+            Some('\'') => {
+                let mut s = String::new();
+
+                loop {
+                    match self.chars.next() {
+                        Some('\'') => break,
+                        Some('\\') => match self.chars.next() {
+                            Some(c) => s.push(c),
+                            None => return Err("unterminated string".to_owned()),
+                        },
+                        Some(c) => s.push(c),
+                        None => return Err("unterminated string".to_owned()),
+                    }
+                }
+
+                Token::String(s)
+            }
+            Some(c) if c.is_ascii_digit() => Token::Number(self.take_while(c, |c| c.is_ascii_digit() || c == '.')),
+            Some(c) if c.is_alphabetic() || c == '_' => {
+                Token::Identifier(self.take_while(c, |c| c.is_alphanumeric() || c == '_'))
+            }
+            Some(c) => return Err(format!("unexpected `{}`", c)),
+        };
+
+        Ok(Some(token))
+    }
+
+    fn take_while(&mut self, first: char, predicate: impl Fn(char) -> bool) -> String {
+        let mut s = first.to_string();
+
+        while let Some(c) = self.chars.peek().copied().filter(|c| predicate(*c)) {
+            s.push(c);
+            self.chars.next();
+        }
+
+        s
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.peek().map(|c| c.is_whitespace()).unwrap_or(false) {
+            self.chars.next();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluate(expression: &str, values: &[(&str, PrismaValue)]) -> PrismaValue {
+        ComputedField::parse("computed", expression)
+            .unwrap()
+            .evaluate(|name| values.iter().find(|(n, _)| *n == name).map(|(_, value)| value))
+    }
+
+    #[test]
+    fn declarations_are_parsed_per_model() {
+        let fields =
+            ComputedFields::parse("User.fullName = firstName + '; ' + lastName; Order.total = price * quantity;")
+                .unwrap();
+
+        let user_fields: Vec<_> = fields.on_model("User").map(|f| f.name.as_str()).collect();
+        let order_field = fields.on_model("Order").next().unwrap();
+
+        assert_eq!(user_fields, vec!["fullName"]);
+        assert_eq!(order_field.dependencies(), vec!["price", "quantity"]);
+
+        assert!(ComputedFields::parse("User.fullName").is_err());
+        assert!(ComputedFields::parse("fullName = firstName").is_err());
+    }
+
+    #[test]
+    fn invalid_expressions_are_rejected() {
+        assert!(ComputedField::parse("f", "a +").is_err());
+        assert!(ComputedField::parse("f", "(a + b").is_err());
+        assert!(ComputedField::parse("f", "a b").is_err());
+        assert!(ComputedField::parse("f", "'unterminated").is_err());
+        assert!(ComputedField::parse("f", "a % b").is_err());
+    }
+
+    #[test]
+    fn strings_are_concatenated() {
+        let values = [
+            ("firstName", PrismaValue::String("Ada".into())),
+            ("lastName", PrismaValue::String("Lovelace".into())),
+            ("age", PrismaValue::Int(36)),
+        ];
+
+        assert_eq!(
+            evaluate("firstName + ' ' + lastName + ' (' + age + ')'", &values),
+            PrismaValue::String("Ada Lovelace (36)".into())
+        );
+    }
+
+    #[test]
+    fn arithmetic_follows_the_usual_precedence() {
+        let values = [
+            ("price", PrismaValue::Float(Decimal::from_str("2.5").unwrap())),
+            ("quantity", PrismaValue::Int(4)),
+        ];
+
+        assert_eq!(evaluate("1 + 2 * 3", &[]), PrismaValue::Int(7));
+        assert_eq!(evaluate("(1 + 2) * 3", &[]), PrismaValue::Int(9));
+        assert_eq!(evaluate("10 - 4 - 3", &[]), PrismaValue::Int(3));
+        assert_eq!(
+            evaluate("7 / 2", &[]),
+            PrismaValue::Float(Decimal::from_str("3.5").unwrap())
+        );
+        assert_eq!(
+            evaluate("price * quantity - 1", &values),
+            PrismaValue::Float(Decimal::from(9))
+        );
+    }
+
+    #[test]
+    fn nulls_divisions_by_zero_and_overflows_are_null() {
+        let values = [("a", PrismaValue::Null), ("b", PrismaValue::Int(i64::MAX))];
+
+        assert_eq!(evaluate("a + 'suffix'", &values), PrismaValue::Null);
+        assert_eq!(evaluate("b + 1", &values), PrismaValue::Null);
+        assert_eq!(evaluate("1 / 0", &values), PrismaValue::Null);
+    }
+}
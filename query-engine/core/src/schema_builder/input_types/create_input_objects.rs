@@ -51,7 +51,10 @@ pub(crate) fn create_input_type(
 
     return_cached_input!(ctx, &name);
 
-    let input_object = Arc::new(init_input_object_type(name.clone()));
+    let mut input_object = init_input_object_type(name.clone());
+    input_object.reject_computed_fields_of(model);
+
+    let input_object = Arc::new(input_object);
     ctx.cache_input_type(name, input_object.clone());
 
     // Compute input fields for scalar fields.
@@ -67,21 +70,9 @@ pub(crate) fn create_input_type(
         model.name.clone(),
         "Create",
         scalar_fields,
-        |_, f: ScalarFieldRef, default: Option<DefaultValue>| {
-            let typ = map_scalar_input_type(&f);
-            if f.is_required && f.default_value.is_none() && (f.is_created_at() || f.is_updated_at()) {
-                input_field(f.name.clone(), typ, default)
-                    .optional()
-                    .nullable_if(!f.is_required)
-            } else if f.is_required && f.default_value.is_none() {
-                input_field(f.name.clone(), typ, default)
-            } else {
-                input_field(f.name.clone(), typ, default)
-                    .optional()
-                    .nullable_if(!f.is_required)
-            }
-        },
+        create_scalar_input_field,
         true,
+        false,
     );
 
     // Compute input fields for relational fields.
@@ -93,6 +84,55 @@ pub(crate) fn create_input_type(
     Arc::downgrade(&input_object)
 }
 
+/// Builds the input type of the records to upsert in bulk (<x>UpsertManyInput). Only the scalar
+/// fields, the records are written with a single statement that can't reach related records.
+pub(crate) fn upsert_many_input_type(ctx: &mut BuilderContext, model: &ModelRef) -> InputObjectTypeWeakRef {
+    let name = format!("{}UpsertManyInput", model.name);
+    return_cached_input!(ctx, &name);
+
+    let mut input_object = init_input_object_type(name.clone());
+    input_object.reject_computed_fields_of(model);
+
+    let input_object = Arc::new(input_object);
+    ctx.cache_input_type(name, input_object.clone());
+
+    let scalar_fields: Vec<ScalarFieldRef> = model
+        .fields()
+        .scalar_writable()
+        .into_iter()
+        .filter(|f| field_should_be_kept_for_create_input_type(&f))
+        .collect();
+
+    let fields = input_fields::scalar_input_fields(
+        ctx,
+        model.name.clone(),
+        "Create",
+        scalar_fields,
+        create_scalar_input_field,
+        true,
+        false,
+    );
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+/// Maps a scalar field to its input field on the create input types.
+fn create_scalar_input_field(_: &mut BuilderContext, f: ScalarFieldRef, default: Option<DefaultValue>) -> InputField {
+    let typ = map_scalar_input_type(&f);
+    if f.is_required && f.default_value.is_none() && (f.is_created_at() || f.is_updated_at()) {
+        input_field(f.name.clone(), typ, default)
+            .optional()
+            .nullable_if(!f.is_required)
+    } else if f.is_required && f.default_value.is_none() {
+        input_field(f.name.clone(), typ, default)
+    } else {
+        input_field(f.name.clone(), typ, default)
+            .optional()
+            .nullable_if(!f.is_required)
+    }
+}
+
 /// For create input types only. Compute input fields for relational fields.
 fn relation_input_fields_for_create(
     ctx: &mut BuilderContext,
@@ -139,7 +179,8 @@ fn relation_input_fields_for_create(
                     .scalar_fields()
                     .all(|scalar_field| scalar_field.default_value.is_some());
 
-                let input_field = input_field(rf.name.clone(), InputType::object(input_object), None);
+                let input_field = input_field(rf.name.clone(), InputType::object(input_object), None)
+                    .deprecation(rf.deprecation.as_deref());
 
                 if rf.is_required && !all_required_scalar_fields_have_defaults {
                     Some(input_field)
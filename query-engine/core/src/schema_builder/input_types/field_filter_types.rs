@@ -56,10 +56,13 @@ fn full_relation_filter(ctx: &mut BuilderContext, rf: &RelationFieldRef) -> Inpu
     ctx.cache_input_type(filter_name, object.clone());
 
     let fields = if rf.is_list {
+        let count_filter_type = InputType::object(relation_count_filter_type(ctx));
+
         vec![
             input_field("every", InputType::object(related_input_type.clone()), None).optional(),
             input_field("some", InputType::object(related_input_type.clone()), None).optional(),
             input_field("none", InputType::object(related_input_type.clone()), None).optional(),
+            input_field("count", vec![InputType::int(), count_filter_type], None).optional(),
         ]
     } else {
         vec![
@@ -76,6 +79,30 @@ fn full_relation_filter(ctx: &mut BuilderContext, rf: &RelationFieldRef) -> Inpu
     Arc::downgrade(&object)
 }
 
+/// Builds the filter on the number of related records of to-many relations: `where: { posts: { count: { gt: 5 } } }`.
+fn relation_count_filter_type(ctx: &mut BuilderContext) -> InputObjectTypeWeakRef {
+    let name = String::from("RelationCountFilter");
+    return_cached_input!(ctx, &name);
+
+    let object = Arc::new(init_input_object_type(name.clone()));
+    ctx.cache_input_type(name, object.clone());
+
+    let object_type = InputType::object(Arc::downgrade(&object));
+    let fields = vec![
+        input_field("equals", InputType::int(), None).optional(),
+        input_field("in", InputType::list(InputType::int()), None).optional(),
+        input_field("notIn", InputType::list(InputType::int()), None).optional(),
+        input_field("lt", InputType::int(), None).optional(),
+        input_field("lte", InputType::int(), None).optional(),
+        input_field("gt", InputType::int(), None).optional(),
+        input_field("gte", InputType::int(), None).optional(),
+        input_field("not", vec![InputType::int(), object_type], None).optional(),
+    ];
+
+    object.set_fields(fields);
+    Arc::downgrade(&object)
+}
+
 fn scalar_list_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> InputObjectTypeWeakRef {
     let name = scalar_filter_name(sf, false);
     return_cached_input!(ctx, &name);
@@ -83,12 +110,30 @@ fn scalar_list_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> Inp
     let object = Arc::new(init_input_object_type(name.clone()));
     ctx.cache_input_type(name, object.clone());
 
-    let fields = equality_filters(sf).collect();
+    let fields = equality_filters(sf).chain(scalar_list_filters(sf)).collect();
     object.set_fields(fields);
 
     Arc::downgrade(&object)
 }
 
+/// Filters on the elements of scalar lists: `has` one element, `hasEvery` or `hasSome` of the given ones.
+fn scalar_list_filters(sf: &ScalarFieldRef) -> impl Iterator<Item = InputField> {
+    let fields = if feature_flags::get().scalarLists {
+        let element_type = map_scalar_element_input_type(sf);
+
+        vec![
+            input_field("has", element_type.clone(), None).optional(),
+            input_field("hasEvery", InputType::list(element_type.clone()), None).optional(),
+            input_field("hasSome", InputType::list(element_type), None).optional(),
+            input_field("isEmpty", InputType::boolean(), None).optional(),
+        ]
+    } else {
+        vec![]
+    };
+
+    fields.into_iter()
+}
+
 fn full_scalar_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef, nested: bool) -> InputObjectTypeWeakRef {
     let name = scalar_filter_name(sf, nested);
     return_cached_input!(ctx, &name);
@@ -101,15 +146,21 @@ fn full_scalar_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef, nested
             .chain(inclusion_filters(sf))
             .chain(alphanumeric_filters(sf))
             .chain(string_filters(sf))
+            .chain(search_filter(ctx, sf))
             .chain(query_mode_field(ctx, nested))
             .collect(),
 
-        TypeIdentifier::Int | TypeIdentifier::Float | TypeIdentifier::DateTime => equality_filters(sf)
+        TypeIdentifier::Int
+        | TypeIdentifier::BigInt
+        | TypeIdentifier::Float
+        | TypeIdentifier::Decimal
+        | TypeIdentifier::DateTime => equality_filters(sf)
             .chain(inclusion_filters(sf))
             .chain(alphanumeric_filters(sf))
             .collect(),
 
-        TypeIdentifier::Boolean | TypeIdentifier::Json => equality_filters(sf).collect(),
+        TypeIdentifier::Json => equality_filters(sf).chain(json_filters(ctx)).collect(),
+        TypeIdentifier::Boolean | TypeIdentifier::Bytes => equality_filters(sf).collect(),
         TypeIdentifier::Enum(_) => equality_filters(sf).chain(inclusion_filters(sf)).collect(),
     };
 
@@ -175,6 +226,57 @@ fn string_filters(sf: &ScalarFieldRef) -> impl Iterator<Item = InputField> {
     .into_iter()
 }
 
+/// Full-text search on String fields, for connectors with full-text search.
+fn search_filter(ctx: &BuilderContext, sf: &ScalarFieldRef) -> impl Iterator<Item = InputField> {
+    let fields = if feature_flags::get().fullTextSearch
+        && sf.type_identifier == TypeIdentifier::String
+        && (ctx
+            .capabilities
+            .contains(ConnectorCapability::FullTextSearchWithoutIndex)
+            || ctx.capabilities.contains(ConnectorCapability::FullTextSearchWithIndex))
+    {
+        vec![input_field("search", InputType::string(), None).optional()]
+    } else {
+        vec![]
+    };
+
+    fields.into_iter()
+}
+
+/// Filters on the value at a path of a Json field. `equals` and `not` compare the value at the path too.
+/// The path is a list of keys and array indexes on connectors with array paths, and a JSON path string on
+/// the ones with JSON paths. It defaults to the whole value.
+fn json_filters(ctx: &BuilderContext) -> impl Iterator<Item = InputField> {
+    let path_field = if ctx.capabilities.contains(ConnectorCapability::JsonFilteringArrayPath) {
+        Some(input_field(
+            "path",
+            InputType::list(InputType::string()),
+            Some(DefaultValue::Single(PrismaValue::List(vec![]))),
+        ))
+    } else if ctx.capabilities.contains(ConnectorCapability::JsonFilteringJsonPath) {
+        Some(input_field(
+            "path",
+            InputType::string(),
+            Some(DefaultValue::Single(PrismaValue::String("$".to_owned()))),
+        ))
+    } else {
+        None
+    };
+
+    let fields = match path_field {
+        Some(path_field) if feature_flags::get().filterJson => vec![
+            path_field.optional(),
+            input_field("stringContains", InputType::string(), None).optional(),
+            input_field("stringStartsWith", InputType::string(), None).optional(),
+            input_field("stringEndsWith", InputType::string(), None).optional(),
+            input_field("arrayContains", InputType::json(), None).optional(),
+        ],
+        _ => vec![],
+    };
+
+    fields.into_iter()
+}
+
 fn query_mode_field(ctx: &BuilderContext, nested: bool) -> impl Iterator<Item = InputField> {
     // Limit query mode field to the topmost filter level.
     // Only build mode field for connectors with insensitive filter support.
@@ -211,10 +313,13 @@ fn scalar_filter_name(sf: &ScalarFieldRef, nested: bool) -> String {
         TypeIdentifier::UUID => format!("{}Uuid{}{}Filter", nested, nullable, list),
         TypeIdentifier::String => format!("{}String{}{}Filter", nested, nullable, list),
         TypeIdentifier::Int => format!("{}Int{}{}Filter", nested, nullable, list),
+        TypeIdentifier::BigInt => format!("{}BigInt{}{}Filter", nested, nullable, list),
         TypeIdentifier::Float => format!("{}Float{}{}Filter", nested, nullable, list),
+        TypeIdentifier::Decimal => format!("{}Decimal{}{}Filter", nested, nullable, list),
         TypeIdentifier::Boolean => format!("{}Bool{}{}Filter", nested, nullable, list),
         TypeIdentifier::DateTime => format!("{}DateTime{}{}Filter", nested, nullable, list),
         TypeIdentifier::Json => format!("{}Json{}{}Filter", nested, nullable, list),
+        TypeIdentifier::Bytes => format!("{}Bytes{}{}Filter", nested, nullable, list),
         TypeIdentifier::Enum(ref e) => format!("{}Enum{}{}{}Filter", nested, e, nullable, list),
     }
 }
@@ -32,10 +32,15 @@ pub(crate) fn scalar_filter_object_type(ctx: &mut BuilderContext, model: &ModelR
         .optional(),
     ];
 
-    input_fields.extend(model.fields().all.iter().filter_map(|f| match f {
-        ModelField::Scalar(_) => Some(input_fields::filter_input_field(ctx, f)),
-        ModelField::Relation(_) => None,
-    }));
+    let scalar_fields: Vec<_> = model
+        .fields()
+        .all
+        .iter()
+        .filter(|f| matches!(f, ModelField::Scalar(_)) && !ctx.is_transformed(model, f))
+        .cloned()
+        .collect();
+
+    input_fields.extend(scalar_fields.iter().map(|f| input_fields::filter_input_field(ctx, f)));
 
     input_object.set_fields(input_fields);
     weak_ref
@@ -72,13 +77,15 @@ pub(crate) fn where_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> I
         .optional(),
     ];
 
-    fields.extend(
-        model
-            .fields()
-            .all
-            .iter()
-            .map(|f| input_fields::filter_input_field(ctx, f)),
-    );
+    let filter_fields: Vec<_> = model
+        .fields()
+        .all
+        .iter()
+        .filter(|f| !ctx.is_transformed(model, f))
+        .cloned()
+        .collect();
+
+    fields.extend(filter_fields.iter().map(|f| input_fields::filter_input_field(ctx, f)));
 
     input_object.set_fields(fields);
     weak_ref
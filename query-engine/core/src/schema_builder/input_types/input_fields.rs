@@ -3,7 +3,9 @@ use prisma_models::dml::DefaultValue;
 
 pub(crate) fn filter_input_field(ctx: &mut BuilderContext, field: &ModelField) -> InputField {
     let types = field_filter_types::get_field_filter_types(ctx, field);
-    input_field(field.name().to_owned(), types, None).optional()
+    input_field(field.name().to_owned(), types, None)
+        .optional()
+        .deprecation(field.deprecation())
 }
 
 pub(crate) fn nested_create_input_field(ctx: &mut BuilderContext, field: &RelationFieldRef) -> InputField {
@@ -105,7 +107,8 @@ pub(crate) fn nested_update_input_field(ctx: &mut BuilderContext, field: &Relati
 }
 
 /// Builds scalar input fields using the mapper and the given, prefiltered, scalar fields.
-/// The mapper is responsible for mapping the fields to input types.
+/// The mapper is responsible for mapping the fields to input types. Scalar lists can be
+/// `set`, and also appended to with `push` if `with_list_push` is set.
 pub(crate) fn scalar_input_fields<T, F>(
     ctx: &mut BuilderContext,
     model_name: String,
@@ -113,6 +116,7 @@ pub(crate) fn scalar_input_fields<T, F>(
     prefiltered_fields: Vec<ScalarFieldRef>,
     non_list_field_mapper: F,
     with_defaults: bool,
+    with_list_push: bool,
 ) -> Vec<InputField>
 where
     T: Into<String>,
@@ -124,7 +128,7 @@ where
         .filter(|f| !f.is_list)
         .map(|f| {
             let default = if with_defaults { f.default_value.clone() } else { None };
-            non_list_field_mapper(ctx, f.clone(), default)
+            non_list_field_mapper(ctx, f.clone(), default).deprecation(f.deprecation.as_deref())
         })
         .collect();
 
@@ -137,6 +141,21 @@ where
             let set_object_name = format!("{}{}{}Input", model_name, input_object_name, f.name);
             let input_object = match ctx.get_input_type(&set_object_name) {
                 Some(t) => t,
+                None if with_list_push && feature_flags::get().scalarLists => {
+                    let element_input_type = map_scalar_element_input_type(&f);
+                    let fields = vec![
+                        input_field("set", list_input_type.clone(), None).optional(),
+                        input_field("push", vec![element_input_type, list_input_type.clone()], None).optional(),
+                    ];
+
+                    let mut input_object = input_object_type(set_object_name.clone(), fields);
+                    input_object.require_exactly_one_field();
+
+                    let input_object = Arc::new(input_object);
+
+                    ctx.cache_input_type(set_object_name, input_object.clone());
+                    Arc::downgrade(&input_object)
+                }
                 None => {
                     let set_fields = vec![input_field("set", list_input_type.clone(), None)];
                     let input_object = Arc::new(input_object_type(set_object_name.clone(), set_fields));
@@ -147,7 +166,9 @@ where
             };
 
             let set_input_type = InputType::object(input_object);
-            input_field(name, vec![set_input_type, list_input_type], None).optional()
+            input_field(name, vec![set_input_type, list_input_type], None)
+                .optional()
+                .deprecation(f.deprecation.as_deref())
         })
         .collect();
 
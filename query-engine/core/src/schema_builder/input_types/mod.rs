@@ -8,9 +8,10 @@ use super::*;
 use crate::schema::*;
 use prisma_models::{RelationFieldRef, ScalarFieldRef};
 
-/// Builds "<Model>OrderByInput" object types.
+/// Builds "<Model>OrderByInput" object types. To-one relation fields order by a field of the related record, to-many
+/// relation fields by an aggregation of the related records. Omitted fields can't be ordered by.
 pub(crate) fn order_by_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> InputObjectTypeWeakRef {
-    let enum_type = Arc::new(string_enum_type("SortOrder", vec!["asc".to_owned(), "desc".to_owned()]));
+    let enum_type = sort_order_enum();
     let name = format!("{}OrderByInput", model.name);
 
     return_cached_input!(ctx, &name);
@@ -21,33 +22,86 @@ pub(crate) fn order_by_object_type(ctx: &mut BuilderContext, model: &ModelRef) -
     let input_object = Arc::new(input_object);
     ctx.cache_input_type(name, input_object.clone());
 
-    let fields = model
+    let mut fields: Vec<InputField> = model
         .fields()
         .scalar()
         .iter()
+        .filter(|sf| {
+            let field = ModelField::Scalar(Arc::clone(sf));
+            !ctx.is_omitted(model, &field) && !ctx.is_transformed(model, &field)
+        })
         .map(|sf| input_field(sf.name.clone(), InputType::Enum(enum_type.clone()), None).optional())
         .collect();
 
+    let relation_fields: Vec<_> = model
+        .fields()
+        .relation()
+        .into_iter()
+        .filter(|rf| !ctx.is_omitted(model, &ModelField::Relation(Arc::clone(rf))))
+        .collect();
+
+    fields.extend(relation_fields.iter().map(|rf| {
+        let related_model = rf.related_model();
+        let object_type = if rf.is_list {
+            order_by_relation_aggregate_object_type(ctx, &related_model)
+        } else {
+            order_by_object_type(ctx, &related_model)
+        };
+
+        input_field(rf.name.clone(), InputType::object(object_type), None).optional()
+    }));
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+/// Builds "<Model>OrderByRelationAggregateInput" object types, ordering by an aggregation of the records of a to-many
+/// relation.
+fn order_by_relation_aggregate_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> InputObjectTypeWeakRef {
+    let name = format!("{}OrderByRelationAggregateInput", model.name);
+
+    return_cached_input!(ctx, &name);
+
+    let mut input_object = init_input_object_type(name.clone());
+    input_object.allow_at_most_one_field();
+
+    let input_object = Arc::new(input_object);
+    ctx.cache_input_type(name, input_object.clone());
+
+    let fields = vec![input_field("count", InputType::Enum(sort_order_enum()), None).optional()];
+
     input_object.set_fields(fields);
     Arc::downgrade(&input_object)
 }
 
+fn sort_order_enum() -> EnumTypeRef {
+    Arc::new(string_enum_type("SortOrder", vec!["asc".to_owned(), "desc".to_owned()]))
+}
+
 fn map_scalar_input_type(field: &ScalarFieldRef) -> InputType {
-    let typ = match field.type_identifier {
+    let typ = map_scalar_element_input_type(field);
+
+    if field.is_list {
+        InputType::list(typ)
+    } else {
+        typ
+    }
+}
+
+/// The input type of the values of the field, of its elements for scalar lists.
+fn map_scalar_element_input_type(field: &ScalarFieldRef) -> InputType {
+    match field.type_identifier {
         TypeIdentifier::String => InputType::string(),
         TypeIdentifier::Int => InputType::int(),
+        TypeIdentifier::BigInt => InputType::big_int(),
         TypeIdentifier::Float => InputType::float(),
+        TypeIdentifier::Decimal => InputType::decimal(),
         TypeIdentifier::Boolean => InputType::boolean(),
         TypeIdentifier::UUID => InputType::uuid(),
         TypeIdentifier::DateTime => InputType::date_time(),
         TypeIdentifier::Json => InputType::json(),
+        TypeIdentifier::Bytes => InputType::bytes(),
         TypeIdentifier::Enum(_) => map_enum_input_type(&field),
-    };
-
-    if field.is_list {
-        InputType::list(typ)
-    } else {
-        typ
     }
 }
 
@@ -6,7 +6,10 @@ pub(crate) fn update_input_type(ctx: &mut BuilderContext, model: &ModelRef) -> I
     let name = format!("{}UpdateInput", model.name);
     return_cached_input!(ctx, &name);
 
-    let input_object = Arc::new(init_input_object_type(name.clone()));
+    let mut input_object = init_input_object_type(name.clone());
+    input_object.reject_computed_fields_of(model);
+
+    let input_object = Arc::new(input_object);
     ctx.cache_input_type(name, input_object.clone());
 
     // Compute input fields for scalar fields.
@@ -26,7 +29,10 @@ pub(crate) fn update_many_input_type(ctx: &mut BuilderContext, model: &ModelRef)
     return_cached_input!(ctx, &object_name);
 
     let input_fields = scalar_input_fields_for_update(ctx, model);
-    let input_object = Arc::new(input_object_type(object_name.clone(), input_fields));
+    let mut input_object = input_object_type(object_name.clone(), input_fields);
+    input_object.reject_computed_fields_of(model);
+
+    let input_object = Arc::new(input_object);
 
     ctx.cache_input_type(object_name, input_object.clone());
     Arc::downgrade(&input_object)
@@ -44,6 +50,7 @@ fn scalar_input_fields_for_update(ctx: &mut BuilderContext, model: &ModelRef) ->
             .collect(),
         |ctx, f: ScalarFieldRef, default| non_list_scalar_update_field_mapper(ctx, &f, default),
         false,
+        true,
     )
 }
 
@@ -54,11 +61,14 @@ fn non_list_scalar_update_field_mapper(
 ) -> InputField {
     let base_update_type = match &field.type_identifier {
         TypeIdentifier::Float => InputType::object(operations_object_type(ctx, "Float", field, true)),
+        TypeIdentifier::Decimal => InputType::object(operations_object_type(ctx, "Decimal", field, true)),
         TypeIdentifier::Int => InputType::object(operations_object_type(ctx, "Int", field, true)),
+        TypeIdentifier::BigInt => InputType::object(operations_object_type(ctx, "BigInt", field, true)),
         TypeIdentifier::String => InputType::object(operations_object_type(ctx, "String", field, false)),
         TypeIdentifier::Boolean => InputType::object(operations_object_type(ctx, "Bool", field, false)),
         TypeIdentifier::Enum(e) => InputType::object(operations_object_type(ctx, &format!("Enum{}", e), field, false)),
         TypeIdentifier::Json => map_scalar_input_type(field),
+        TypeIdentifier::Bytes => InputType::object(operations_object_type(ctx, "Bytes", field, false)),
         TypeIdentifier::DateTime => InputType::object(operations_object_type(ctx, "DateTime", field, false)),
         TypeIdentifier::UUID => InputType::object(operations_object_type(ctx, "Uuid", field, false)),
     };
@@ -157,7 +167,11 @@ fn relation_input_fields_for_update(
                     }
                 };
 
-                Some(input_field(rf.name.clone(), InputType::object(input_object), None).optional())
+                Some(
+                    input_field(rf.name.clone(), InputType::object(input_object), None)
+                        .optional()
+                        .deprecation(rf.deprecation.as_deref()),
+                )
             }
         })
         .collect()
@@ -324,7 +338,10 @@ fn nested_update_data(ctx: &mut BuilderContext, parent_field: &RelationFieldRef)
 
     return_cached_input!(ctx, &type_name);
 
-    let input_object = Arc::new(init_input_object_type(&type_name));
+    let mut input_object = init_input_object_type(&type_name);
+    input_object.reject_computed_fields_of(&related_model);
+
+    let input_object = Arc::new(input_object);
     ctx.cache_input_type(type_name, input_object.clone());
 
     let mut fields = scalar_input_fields_for_update(ctx, &related_model);
@@ -343,7 +360,10 @@ fn nested_update_many_data(ctx: &mut BuilderContext, parent_field: &RelationFiel
 
     return_cached_input!(ctx, &type_name);
 
-    let input_object = Arc::new(init_input_object_type(type_name.clone()));
+    let mut input_object = init_input_object_type(type_name.clone());
+    input_object.reject_computed_fields_of(&related_model);
+
+    let input_object = Arc::new(input_object);
     ctx.cache_input_type(type_name, input_object.clone());
 
     let fields = scalar_input_fields_for_update(ctx, &related_model);
@@ -358,6 +378,6 @@ fn field_should_be_kept_for_update_input_type(field: &ScalarFieldRef) -> bool {
     !field.is_auto_generated_int_id
         && !matches!(
             (&field.type_identifier, field.unique(), field.is_autoincrement),
-            (TypeIdentifier::Int, true, true)
+            (TypeIdentifier::Int, true, true) | (TypeIdentifier::BigInt, true, true)
         )
 }
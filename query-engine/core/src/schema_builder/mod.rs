@@ -33,16 +33,24 @@
 #[macro_use]
 mod cache;
 mod arguments;
+mod computed_fields;
 mod input_types;
+mod omitted_fields;
 mod output_types;
+mod transformed_fields;
+mod unknown_enum_values;
 mod utils;
 
 use crate::schema::*;
 use cache::TypeRefCache;
 use datamodel_connector::ConnectorCapabilities;
 use prisma_models::{Field as ModelField, Index, InternalDataModelRef, ModelRef, RelationFieldRef, TypeIdentifier};
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
+pub use computed_fields::*;
+pub use omitted_fields::*;
+pub use transformed_fields::*;
+pub use unknown_enum_values::*;
 pub use utils::*;
 
 // [DTODO] Remove
@@ -61,10 +69,17 @@ pub(crate) struct BuilderContext {
     mode: BuildMode,
     internal_data_model: InternalDataModelRef,
     enable_raw_queries: bool,
+    read_only: bool,
+    hide_deprecated: bool,
     cache: TypeCache,
     capabilities: ConnectorCapabilities,
     nested_create_inputs_queue: NestedInputsQueue,
     nested_update_inputs_queue: NestedInputsQueue,
+    plural_overrides: HashMap<String, String>,
+    omitted_fields: OmittedFields,
+    computed_fields: ComputedFields,
+    transformed_fields: TransformedFields,
+    unknown_enum_values: UnknownEnumValues,
 }
 
 impl BuilderContext {
@@ -72,19 +87,62 @@ impl BuilderContext {
         mode: BuildMode,
         internal_data_model: InternalDataModelRef,
         enable_raw_queries: bool,
+        read_only: bool,
+        hide_deprecated: bool,
         capabilities: ConnectorCapabilities,
+        plural_overrides: HashMap<String, String>,
+        omitted_fields: OmittedFields,
+        computed_fields: ComputedFields,
+        transformed_fields: TransformedFields,
+        unknown_enum_values: UnknownEnumValues,
     ) -> Self {
         Self {
             mode,
             internal_data_model,
             enable_raw_queries,
+            read_only,
+            hide_deprecated,
             cache: TypeCache::new(),
             capabilities,
             nested_create_inputs_queue: Vec::new(),
             nested_update_inputs_queue: Vec::new(),
+            plural_overrides,
+            omitted_fields,
+            computed_fields,
+            transformed_fields,
+            unknown_enum_values,
         }
     }
 
+    /// Pluralizes the given model name. User-provided overrides take precedence over the inflector.
+    pub fn pluralize(&self, name: &str) -> String {
+        match self.plural_overrides.get(name) {
+            Some(plural) => plural.clone(),
+            None => pluralize(name),
+        }
+    }
+
+    /// Whether the field is left out of the output object type of its model.
+    pub fn is_omitted(&self, model: &ModelRef, field: &ModelField) -> bool {
+        self.omitted_fields.contains(&model.name, field.name())
+    }
+
+    /// Whether the values of the field are transformed in the database, so it can't be filtered,
+    /// ordered, grouped or aggregated on.
+    pub fn is_transformed(&self, model: &ModelRef, field: &ModelField) -> bool {
+        self.transformed_fields.contains(&model.name, field.name())
+    }
+
+    /// The computed fields added to the output object type of the model.
+    pub fn computed_fields(&self, model: &ModelRef) -> Vec<ComputedFieldRef> {
+        self.computed_fields.on_model(&model.name).cloned().collect()
+    }
+
+    /// What enum fields return for values the datamodel doesn't know.
+    pub fn unknown_enum_values(&self) -> UnknownEnumValues {
+        self.unknown_enum_values
+    }
+
     // Just here for convenience, will be removed soon.
     pub fn pluralize_internal(&self, legacy: String, modern: String) -> String {
         match self.mode {
@@ -140,13 +198,35 @@ impl TypeCache {
     }
 }
 
+/// With `read_only`, the `Mutation` type has no fields: every mutation is rejected when the query is parsed.
+/// With `hide_deprecated`, the operations of the models deprecated with `@@deprecated` are left out of the
+/// `Query` and `Mutation` types, instead of only being marked as deprecated.
 pub fn build(
     internal_data_model: InternalDataModelRef,
     mode: BuildMode,
     enable_raw_queries: bool,
+    read_only: bool,
+    hide_deprecated: bool,
     capabilities: ConnectorCapabilities,
+    plural_overrides: HashMap<String, String>,
+    omitted_fields: OmittedFields,
+    computed_fields: ComputedFields,
+    transformed_fields: TransformedFields,
+    unknown_enum_values: UnknownEnumValues,
 ) -> QuerySchema {
-    let mut ctx = BuilderContext::new(mode, internal_data_model, enable_raw_queries, capabilities);
+    let mut ctx = BuilderContext::new(
+        mode,
+        internal_data_model,
+        enable_raw_queries,
+        read_only,
+        hide_deprecated,
+        capabilities,
+        plural_overrides,
+        omitted_fields,
+        computed_fields,
+        transformed_fields,
+        unknown_enum_values,
+    );
     output_types::output_objects::initialize_model_object_type_cache(&mut ctx);
 
     let (query_type, query_object_ref) = output_types::query_type::build(&mut ctx);
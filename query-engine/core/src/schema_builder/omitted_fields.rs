@@ -0,0 +1,37 @@
+use std::collections::{HashMap, HashSet};
+
+/// Model fields that are left out of the model output object types, e.g. password hashes.
+/// They can still be written, but never be selected, so their values can't leave the engine.
+#[derive(Debug, Clone, Default)]
+pub struct OmittedFields {
+    fields: HashMap<String, HashSet<String>>,
+}
+
+impl OmittedFields {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, model: impl Into<String>, field: impl Into<String>) {
+        self.fields.entry(model.into()).or_default().insert(field.into());
+    }
+
+    pub fn with(mut self, model: impl Into<String>, field: impl Into<String>) -> Self {
+        self.insert(model, field);
+        self
+    }
+
+    pub fn contains(&self, model: &str, field: &str) -> bool {
+        self.fields
+            .get(model)
+            .map(|fields| fields.contains(field))
+            .unwrap_or(false)
+    }
+
+    /// Iterates over all omitted fields as `(model, field)` pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.fields
+            .iter()
+            .flat_map(|(model, fields)| fields.iter().map(move |field| (model.as_str(), field.as_str())))
+    }
+}
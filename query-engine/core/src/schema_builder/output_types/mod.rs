@@ -3,3 +3,11 @@ pub(crate) mod output_objects;
 pub(crate) mod query_type;
 
 use super::*;
+
+/// Marks the operations of `model` as deprecated if the model is (`@@deprecated`).
+fn deprecate_operations(operations: Vec<OutputField>, model: &ModelRef) -> Vec<OutputField> {
+    operations
+        .into_iter()
+        .map(|operation| operation.deprecation(model.deprecation()))
+        .collect()
+}
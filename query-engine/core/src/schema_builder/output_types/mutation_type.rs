@@ -1,13 +1,22 @@
 use super::*;
 use crate::{write, QueryGraph};
+use datamodel_connector::ConnectorCapability;
 use input_types::input_fields;
 use prisma_models::{dml, PrismaValue};
 
-/// Builds the root `Mutation` type.
+/// Builds the root `Mutation` type. It is empty in read-only mode.
 pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRef) {
+    if ctx.read_only {
+        let strong_ref = Arc::new(object_type("Mutation", Vec::new(), None));
+
+        return (OutputType::Object(Arc::downgrade(&strong_ref)), strong_ref);
+    }
+
+    let hide_deprecated = ctx.hide_deprecated;
     let non_embedded_models = ctx.internal_data_model.non_embedded_models();
     let mut fields: Vec<OutputField> = non_embedded_models
         .into_iter()
+        .filter(|model| !(hide_deprecated && model.deprecation().is_some()))
         .map(|model| {
             let mut vec = vec![create_item_field(ctx, &model)];
 
@@ -18,7 +27,11 @@ pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRe
             vec.push(update_many_field(ctx, &model));
             vec.push(delete_many_field(ctx, &model));
 
-            vec
+            if feature_flags::get().upsertMany && ctx.capabilities.contains(ConnectorCapability::UpsertMany) {
+                vec.push(upsert_many_field(ctx, &model));
+            }
+
+            deprecate_operations(vec, &model)
         })
         .flatten()
         .collect();
@@ -164,14 +177,14 @@ fn delete_item_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<Outpu
 fn delete_many_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
     let arguments = arguments::delete_many_arguments(ctx, model);
     let field_name = ctx.pluralize_internal(
-        format!("deleteMany{}", pluralize(&model.name)),
+        format!("deleteMany{}", ctx.pluralize(&model.name)),
         format!("deleteMany{}", model.name),
     );
 
     field(
         field_name,
         arguments,
-        OutputType::object(output_objects::batch_payload_object_type(ctx)),
+        OutputType::object(output_objects::affected_records_payload_object_type(ctx)),
         Some(SchemaQueryBuilder::ModelQueryBuilder(ModelQueryBuilder::new(
             model.clone(),
             QueryTag::DeleteMany,
@@ -213,14 +226,14 @@ fn update_item_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<Outpu
 fn update_many_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
     let arguments = arguments::update_many_arguments(ctx, model);
     let field_name = ctx.pluralize_internal(
-        format!("updateMany{}", pluralize(model.name.as_str())),
+        format!("updateMany{}", ctx.pluralize(&model.name)),
         format!("updateMany{}", model.name),
     );
 
     field(
         field_name,
         arguments,
-        OutputType::object(output_objects::batch_payload_object_type(ctx)),
+        OutputType::object(output_objects::affected_records_payload_object_type(ctx)),
         Some(SchemaQueryBuilder::ModelQueryBuilder(ModelQueryBuilder::new(
             model.clone(),
             QueryTag::UpdateMany,
@@ -256,3 +269,28 @@ fn upsert_item_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<Outpu
         )
     })
 }
+
+/// Builds an upsert many mutation field (e.g. upsertManyUsers) for given model.
+fn upsert_many_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
+    let arguments = arguments::upsert_many_arguments(ctx, model);
+    let field_name = ctx.pluralize_internal(
+        format!("upsertMany{}", ctx.pluralize(&model.name)),
+        format!("upsertMany{}", model.name),
+    );
+
+    field(
+        field_name,
+        arguments,
+        OutputType::object(output_objects::batch_payload_object_type(ctx)),
+        Some(SchemaQueryBuilder::ModelQueryBuilder(ModelQueryBuilder::new(
+            model.clone(),
+            QueryTag::UpsertMany,
+            Box::new(|model, parsed_field| {
+                let mut graph = QueryGraph::new();
+
+                write::upsert_many_records(&mut graph, model, parsed_field)?;
+                Ok(graph)
+            }),
+        ))),
+    )
+}
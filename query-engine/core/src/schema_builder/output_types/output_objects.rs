@@ -35,12 +35,69 @@ pub(crate) fn initialize_model_object_type_cache(ctx: &mut BuilderContext) {
 /// Computes model output type fields.
 /// Important: This requires that the cache has already been initialized.
 fn compute_model_object_type_fields(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<OutputField> {
-    model
+    let model_fields: Vec<_> = model
         .fields()
         .all
         .iter()
-        .map(|f| output_objects::map_field(ctx, f))
-        .collect()
+        .filter(|f| !ctx.is_omitted(model, f))
+        .cloned()
+        .collect();
+
+    let mut fields: Vec<OutputField> = model_fields.iter().map(|f| output_objects::map_field(ctx, f)).collect();
+
+    fields.extend(
+        ctx.computed_fields(model)
+            .into_iter()
+            .map(|computed| map_computed_field(model, computed)),
+    );
+
+    append_opt(&mut fields, relation_count_field(ctx, model));
+
+    fields
+}
+
+/// Returns the field counting the related records of every to-many relation of the model (e.g. `_count { posts }`),
+/// if the model has any. The related records are counted by the data source, without reading them.
+fn relation_count_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<OutputField> {
+    let relation_fields: Vec<_> = model
+        .fields()
+        .relation()
+        .into_iter()
+        .filter(|rf| rf.is_list && !ctx.is_omitted(model, &ModelField::Relation(rf.clone())))
+        .collect();
+
+    if relation_fields.is_empty() {
+        return None;
+    }
+
+    let name = format!("{}CountOutputType", capitalize(&model.name));
+    let fields = relation_fields
+        .into_iter()
+        .map(|rf| field(rf.name.clone(), vec![], OutputType::int(), None))
+        .collect();
+
+    let object = Arc::new(object_type(name.clone(), fields, None));
+    ctx.cache_output_type(name, object.clone());
+
+    Some(field(
+        crate::RELATION_COUNT_FIELD,
+        vec![],
+        OutputType::object(Arc::downgrade(&object)),
+        None,
+    ))
+}
+
+/// Computed fields are validated upfront, their type can be inferred.
+fn map_computed_field(model: &ModelRef, computed: ComputedFieldRef) -> OutputField {
+    let output_type = match computed.output_type(model).unwrap() {
+        ComputedType::String => OutputType::string(),
+        ComputedType::Int => OutputType::int(),
+        ComputedType::Float => OutputType::float(),
+    };
+
+    field(computed.name.clone(), vec![], output_type, None)
+        .computed(computed)
+        .optional()
 }
 
 /// Returns an output object type for the given model.
@@ -57,7 +114,9 @@ pub(crate) fn map_field(ctx: &mut BuilderContext, model_field: &ModelField) -> O
         map_output_type(ctx, &model_field),
         None,
     )
+    .unknown_enum_values(ctx.unknown_enum_values())
     .optional_if(!model_field.is_required())
+    .deprecation(model_field.deprecation())
 }
 
 pub(crate) fn map_output_type(ctx: &mut BuilderContext, model_field: &ModelField) -> OutputType {
@@ -75,12 +134,15 @@ pub(crate) fn map_output_type(ctx: &mut BuilderContext, model_field: &ModelField
         ModelField::Scalar(sf) => match sf.type_identifier {
             TypeIdentifier::String => OutputType::string(),
             TypeIdentifier::Float => OutputType::float(),
+            TypeIdentifier::Decimal => OutputType::decimal(),
             TypeIdentifier::Boolean => OutputType::boolean(),
             TypeIdentifier::Enum(_) => map_enum_field(sf).into(),
             TypeIdentifier::Json => OutputType::json(),
+            TypeIdentifier::Bytes => OutputType::bytes(),
             TypeIdentifier::DateTime => OutputType::date_time(),
             TypeIdentifier::UUID => OutputType::uuid(),
             TypeIdentifier::Int => OutputType::int(),
+            TypeIdentifier::BigInt => OutputType::big_int(),
         },
     };
 
@@ -118,6 +180,25 @@ pub(crate) fn batch_payload_object_type(ctx: &mut BuilderContext) -> ObjectTypeW
     Arc::downgrade(&object_type)
 }
 
+/// The payload of updateMany and deleteMany, which can also return the identifiers of the affected records.
+pub(crate) fn affected_records_payload_object_type(ctx: &mut BuilderContext) -> ObjectTypeWeakRef {
+    return_cached_output!(ctx, "AffectedRecordsPayload");
+
+    let object_type = Arc::new(object_type(
+        "AffectedRecordsPayload",
+        vec![
+            field("count", vec![], OutputType::int(), None),
+            // The identifiers of the affected records as JSON objects, e.g. `{ "id": 1 }`. They don't cost a
+            // query: the records to write are selected by their identifiers first.
+            field("ids", vec![], OutputType::list(OutputType::json()), None),
+        ],
+        None,
+    ));
+
+    ctx.cache_output_type("AffectedRecordsPayload".into(), object_type.clone());
+    Arc::downgrade(&object_type)
+}
+
 /// Builds aggregation object type for given model (e.g. AggregateUser).
 pub(crate) fn aggregation_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> ObjectTypeWeakRef {
     let name = format!("Aggregate{}", capitalize(&model.name));
@@ -141,6 +222,55 @@ pub(crate) fn aggregation_object_type(ctx: &mut BuilderContext, model: &ModelRef
     ObjectTypeStrongRef::downgrade(&object)
 }
 
+/// Builds the object type of a group for given model (e.g. GroupByUser): the scalar fields of the
+/// model and the aggregations of the group. Scalar fields named like an aggregation can be grouped
+/// by, but not selected. Omitted fields are neither.
+pub(crate) fn group_by_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> ObjectTypeWeakRef {
+    let name = format!("GroupBy{}", capitalize(&model.name));
+    return_cached_output!(ctx, &name);
+
+    let object = ObjectTypeStrongRef::new(ObjectType::new(&name, Some(ModelRef::clone(model))));
+    let scalar_fields: Vec<_> = model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|sf| !crate::AGGREGATION_FIELD_NAMES.contains(&sf.name.as_str()))
+        .filter(|sf| {
+            let field = ModelField::Scalar(sf.clone());
+            !ctx.is_omitted(model, &field) && !ctx.is_transformed(model, &field)
+        })
+        .collect();
+
+    let mut fields: Vec<OutputField> = scalar_fields
+        .into_iter()
+        .map(|sf| {
+            field(
+                sf.name.clone(),
+                vec![],
+                map_output_type(ctx, &ModelField::Scalar(sf.clone())),
+                None,
+            )
+            .optional_if(!sf.is_required)
+        })
+        .collect();
+
+    fields.push(count_field());
+
+    append_opt(
+        &mut fields,
+        numeric_aggregation_field(ctx, "avg", &model, Some(OutputType::float())),
+    );
+
+    append_opt(&mut fields, numeric_aggregation_field(ctx, "sum", &model, None));
+    append_opt(&mut fields, numeric_aggregation_field(ctx, "min", &model, None));
+    append_opt(&mut fields, numeric_aggregation_field(ctx, "max", &model, None));
+
+    object.set_fields(fields);
+    ctx.cache_output_type(name, ObjectTypeStrongRef::clone(&object));
+
+    ObjectTypeStrongRef::downgrade(&object)
+}
+
 pub(crate) fn count_field() -> OutputField {
     field("count", vec![], OutputType::int(), None)
 }
@@ -153,7 +283,7 @@ pub(crate) fn numeric_aggregation_field(
     model: &ModelRef,
     fixed_field_type: Option<OutputType>,
 ) -> Option<OutputField> {
-    let numeric_fields = collect_numeric_fields(model);
+    let numeric_fields = collect_numeric_fields(ctx, model);
 
     if numeric_fields.is_empty() {
         None
@@ -203,15 +333,23 @@ pub(crate) fn map_numeric_field_aggregation_object(
     Arc::downgrade(&object)
 }
 
-fn collect_numeric_fields(model: &ModelRef) -> Vec<ScalarFieldRef> {
+/// The numeric fields of the model that can be aggregated: omitted fields can't be read through aggregations, and
+/// the database only holds the transformed values of transformed fields.
+fn collect_numeric_fields(ctx: &BuilderContext, model: &ModelRef) -> Vec<ScalarFieldRef> {
     model
         .fields()
         .scalar()
         .into_iter()
         .filter(|f| match f.type_identifier {
             TypeIdentifier::Int => true,
+            TypeIdentifier::BigInt => true,
             TypeIdentifier::Float => true,
+            TypeIdentifier::Decimal => true,
             _ => false,
         })
+        .filter(|f| {
+            let field = ModelField::Scalar(f.clone());
+            !ctx.is_omitted(model, &field) && !ctx.is_transformed(model, &field)
+        })
         .collect()
 }
@@ -1,16 +1,26 @@
 use super::*;
-use crate::{AggregateRecordsBuilder, Builder, Query, QueryGraph, ReadManyRecordsBuilder, ReadOneRecordBuilder};
+use crate::{
+    AggregateRecordsBuilder, Builder, CountRecordsBuilder, GroupByRecordsBuilder, Query, QueryGraph,
+    ReadManyRecordsBuilder, ReadOneRecordBuilder,
+};
 
 /// Builds the root `Query` type.
 pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRef) {
+    let hide_deprecated = ctx.hide_deprecated;
     let non_embedded_models = ctx.internal_data_model.non_embedded_models();
     let fields = non_embedded_models
         .into_iter()
+        .filter(|model| !(hide_deprecated && model.deprecation().is_some()))
         .map(|model| {
-            let mut vec = vec![all_items_field(ctx, &model), aggregation_field(ctx, &model)];
+            let mut vec = vec![
+                all_items_field(ctx, &model),
+                aggregation_field(ctx, &model),
+                count_records_field(ctx, &model),
+                group_by_field(ctx, &model),
+            ];
 
             append_opt(&mut vec, single_item_field(ctx, &model));
-            vec
+            deprecate_operations(vec, &model)
         })
         .flatten()
         .collect();
@@ -50,7 +60,10 @@ fn single_item_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<Outpu
 /// Builds a "multiple" query arity items field (e.g. "users", "posts", ...) for given model.
 fn all_items_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
     let args = arguments::many_records_arguments(ctx, &model);
-    let field_name = ctx.pluralize_internal(camel_case(pluralize(&model.name)), format!("findMany{}", model.name));
+    let field_name = ctx.pluralize_internal(
+        camel_case(ctx.pluralize(&model.name)),
+        format!("findMany{}", model.name),
+    );
 
     field(
         field_name,
@@ -95,3 +108,56 @@ fn aggregation_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField
         ))),
     )
 }
+
+/// Builds a "count" query field (e.g. "countUser") for given model.
+/// With `estimate: true`, connectors may answer with an estimate for large results.
+fn count_records_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
+    let args = arguments::count_arguments(ctx, &model);
+    let field_name = ctx.pluralize_internal(
+        format!("count{}", model.name), // Has no legacy counterpart.
+        format!("count{}", model.name),
+    );
+
+    field(
+        field_name,
+        args,
+        OutputType::int(),
+        Some(SchemaQueryBuilder::ModelQueryBuilder(ModelQueryBuilder::new(
+            model.clone(),
+            QueryTag::Count,
+            Box::new(|model, parsed_field| {
+                let mut graph = QueryGraph::new();
+                let query = CountRecordsBuilder::new(parsed_field, model).build()?;
+
+                graph.create_node(Query::Read(query));
+                Ok(graph)
+            }),
+        ))),
+    )
+}
+
+/// Builds a "groupBy" query field (e.g. "groupByUser") for given model.
+fn group_by_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
+    let args = arguments::group_by_arguments(ctx, &model);
+    let field_name = ctx.pluralize_internal(
+        format!("groupBy{}", model.name), // Has no legacy counterpart.
+        format!("groupBy{}", model.name),
+    );
+
+    field(
+        field_name,
+        args,
+        OutputType::list(OutputType::object(output_objects::group_by_object_type(ctx, &model))),
+        Some(SchemaQueryBuilder::ModelQueryBuilder(ModelQueryBuilder::new(
+            model.clone(),
+            QueryTag::GroupBy,
+            Box::new(|model, parsed_field| {
+                let mut graph = QueryGraph::new();
+                let query = GroupByRecordsBuilder::new(parsed_field, model).build()?;
+
+                graph.create_node(Query::Read(query));
+                Ok(graph)
+            }),
+        ))),
+    )
+}
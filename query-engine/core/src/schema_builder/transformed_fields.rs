@@ -0,0 +1,31 @@
+use std::collections::{HashMap, HashSet};
+
+/// Model fields whose values are transformed on their way to the database, e.g. encrypted at rest.
+/// The database only sees the transformed values, so the fields can't be filtered, ordered,
+/// grouped or aggregated on: they are left out of the input types doing so.
+#[derive(Debug, Clone, Default)]
+pub struct TransformedFields {
+    fields: HashMap<String, HashSet<String>>,
+}
+
+impl TransformedFields {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, model: impl Into<String>, field: impl Into<String>) {
+        self.fields.entry(model.into()).or_default().insert(field.into());
+    }
+
+    pub fn with(mut self, model: impl Into<String>, field: impl Into<String>) -> Self {
+        self.insert(model, field);
+        self
+    }
+
+    pub fn contains(&self, model: &str, field: &str) -> bool {
+        self.fields
+            .get(model)
+            .map(|fields| fields.contains(field))
+            .unwrap_or(false)
+    }
+}
@@ -0,0 +1,35 @@
+use std::str::FromStr;
+
+/// What to return for enum values read from the database that the datamodel doesn't know, e.g. values
+/// added to the database enum out-of-band.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnknownEnumValues {
+    /// Fail the query, with an error naming the model, the field and the value.
+    Error,
+    /// Return the value as it is in the database, as a string.
+    RawString,
+    /// Return null instead of the value.
+    Null,
+}
+
+impl Default for UnknownEnumValues {
+    fn default() -> Self {
+        UnknownEnumValues::Error
+    }
+}
+
+impl FromStr for UnknownEnumValues {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(UnknownEnumValues::Error),
+            "string" => Ok(UnknownEnumValues::RawString),
+            "null" => Ok(UnknownEnumValues::Null),
+            _ => Err(format!(
+                "Invalid policy for unknown enum values: `{}`. Expected one of `error`, `string` or `null`.",
+                s
+            )),
+        }
+    }
+}
@@ -36,6 +36,7 @@ where
         name: name.into(),
         constraints: InputObjectTypeConstraints::default(),
         fields: OnceCell::new(),
+        read_only_fields: Vec::new(),
     }
 }
 
@@ -66,6 +67,9 @@ where
         field_type: Arc::new(field_type),
         query_builder,
         is_required: true,
+        computed: None,
+        unknown_enum_values: UnknownEnumValues::default(),
+        deprecation: None,
     }
 }
 
@@ -80,6 +84,7 @@ where
         field_types: field_types.into(),
         default_value,
         is_required: true,
+        deprecation: None,
     }
 }
 
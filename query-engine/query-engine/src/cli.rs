@@ -1,16 +1,22 @@
 use crate::request_handlers::graphql::{self, GraphQlBody};
 
 use crate::{
-    context::PrismaContext,
+    context::{self, PrismaContext},
     dmmf,
     opt::{CliOpt, PrismaOpt, Subcommand},
     PrismaResult,
 };
 
+use connector::{FieldTransformers, QueryTags};
 use datamodel::{Configuration, Datamodel};
 use datamodel_connector::ConnectorCapabilities;
 use prisma_models::DatamodelConverter;
-use query_core::{schema::QuerySchemaRef, schema_builder, BuildMode};
+use query_core::{
+    authorization::Claims,
+    schema::QuerySchemaRef,
+    schema_builder::{self, ComputedFields, OmittedFields, TransformedFields, UnknownEnumValues},
+    BuildMode, ReadPreference,
+};
 use std::sync::Arc;
 
 pub struct ExecuteRequest {
@@ -19,13 +25,24 @@ pub struct ExecuteRequest {
     datamodel: Datamodel,
     config: Configuration,
     enable_raw_queries: bool,
+    read_only: bool,
+    hide_deprecated: bool,
+    query_tags: QueryTags,
+    omitted_fields: OmittedFields,
+    computed_fields: ComputedFields,
+    unknown_enum_values: UnknownEnumValues,
+    field_transformers: FieldTransformers,
 }
 
 pub struct DmmfRequest {
     datamodel: Datamodel,
     build_mode: BuildMode,
     enable_raw_queries: bool,
+    read_only: bool,
+    hide_deprecated: bool,
     config: Configuration,
+    omitted_fields: OmittedFields,
+    computed_fields: ComputedFields,
 }
 
 pub struct GetConfigRequest {
@@ -60,19 +77,35 @@ impl CliCommand {
                         datamodel: opts.datamodel(true)?,
                         build_mode,
                         enable_raw_queries: opts.enable_raw_queries,
+                        read_only: opts.read_only,
+                        hide_deprecated: opts.hide_deprecated,
                         config: opts.configuration(true)?,
+                        omitted_fields: opts.omitted_fields(),
+                        computed_fields: opts.computed_fields(),
                     })))
                 }
                 CliOpt::GetConfig(input) => Ok(Some(CliCommand::GetConfig(GetConfigRequest {
                     config: opts.configuration(input.ignore_env_var_errors)?,
                 }))),
-                CliOpt::ExecuteRequest(input) => Ok(Some(CliCommand::ExecuteRequest(ExecuteRequest {
-                    query: input.query.clone(),
-                    enable_raw_queries: opts.enable_raw_queries,
-                    legacy: input.legacy,
-                    datamodel: opts.datamodel(false)?,
-                    config: opts.configuration(false)?,
-                }))),
+                CliOpt::ExecuteRequest(input) => {
+                    let datamodel = opts.datamodel(false)?;
+                    let field_transformers = opts.field_transformers(&datamodel)?;
+
+                    Ok(Some(CliCommand::ExecuteRequest(ExecuteRequest {
+                        query: input.query.clone(),
+                        enable_raw_queries: opts.enable_raw_queries,
+                        read_only: opts.read_only,
+                        hide_deprecated: opts.hide_deprecated,
+                        legacy: input.legacy,
+                        datamodel,
+                        config: opts.configuration(false)?,
+                        query_tags: opts.query_tags(),
+                        omitted_fields: opts.omitted_fields(),
+                        computed_fields: opts.computed_fields(),
+                        unknown_enum_values: opts.unknown_enum_values,
+                        field_transformers,
+                    })))
+                }
             },
         }
     }
@@ -86,6 +119,8 @@ impl CliCommand {
     }
 
     async fn dmmf(request: DmmfRequest) -> PrismaResult<()> {
+        context::validate_omitted_fields(&request.datamodel, &request.omitted_fields)?;
+
         let template = DatamodelConverter::convert(&request.datamodel);
 
         let capabilities = match request.config.datasources.first() {
@@ -95,11 +130,19 @@ impl CliCommand {
 
         // temporary code duplication
         let internal_data_model = template.build("".into());
+        let computed_fields = context::computed_fields(&request.config, request.computed_fields, &internal_data_model)?;
         let query_schema: QuerySchemaRef = Arc::new(schema_builder::build(
             internal_data_model,
             request.build_mode,
             request.enable_raw_queries,
+            request.read_only,
+            request.hide_deprecated,
             capabilities,
+            context::plural_overrides(&request.config)?,
+            request.omitted_fields,
+            computed_fields,
+            TransformedFields::default(),
+            UnknownEnumValues::default(),
         ));
 
         let dmmf = dmmf::render_dmmf(&request.datamodel, query_schema);
@@ -129,12 +172,27 @@ impl CliCommand {
         )
         .legacy(request.legacy)
         .enable_raw_queries(request.enable_raw_queries)
+        .read_only(request.read_only)
+        .hide_deprecated(request.hide_deprecated)
+        .query_tags(request.query_tags)
+        .omitted_fields(request.omitted_fields)
+        .computed_fields(request.computed_fields)
+        .unknown_enum_values(request.unknown_enum_values)
+        .field_transformers(request.field_transformers)
         .build()
         .await?;
         let cx = Arc::new(cx);
 
         let body: GraphQlBody = serde_json::from_str(&decoded_request)?;
-        let res = graphql::handle(body, cx).await;
+        let res = graphql::handle(
+            body,
+            cx,
+            QueryTags::new(),
+            &Claims::new(),
+            ReadPreference::default(),
+            None,
+        )
+        .await;
         let res = serde_json::to_string(&res).unwrap();
 
         let encoded_response = base64::encode(&res);
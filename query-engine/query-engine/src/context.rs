@@ -1,8 +1,14 @@
 use crate::{exec_loader, PrismaError, PrismaResult};
+use connector::{FieldTransformers, PoolConfig, QueryTags};
 use datamodel::{Configuration, Datamodel};
-use prisma_models::DatamodelConverter;
-use query_core::{schema::QuerySchemaRef, schema_builder, BuildMode, QueryExecutor};
-use std::sync::Arc;
+use prisma_models::{DatamodelConverter, InternalDataModel};
+use query_core::{
+    authorization::{AuthorizationPolicy, Claims},
+    schema::QuerySchemaRef,
+    schema_builder::{self, ComputedFields, OmittedFields, TransformedFields, UnknownEnumValues},
+    BatchDocument, BuildMode, Operation, QueryDocument, QueryExecutor,
+};
+use std::{collections::HashMap, sync::Arc};
 
 /// Prisma request context containing all immutable state of the process.
 /// There is usually only one context initialized per process.
@@ -13,11 +19,24 @@ pub struct PrismaContext {
     dm: Datamodel,
     /// Central query executor.
     pub executor: Box<dyn QueryExecutor + Send + Sync + 'static>,
+    /// The rules the operations of requests are authorized against. All operations are allowed without.
+    authorization_policy: Option<AuthorizationPolicy>,
 }
 
 pub struct ContextBuilder {
     legacy: bool,
     enable_raw_queries: bool,
+    read_only: bool,
+    hide_deprecated: bool,
+    query_tags: QueryTags,
+    omitted_fields: OmittedFields,
+    computed_fields: ComputedFields,
+    unknown_enum_values: UnknownEnumValues,
+    field_transformers: FieldTransformers,
+    pool_config: PoolConfig,
+    authorization_policy: Option<AuthorizationPolicy>,
+    read_replicas: Vec<String>,
+    tenants: Vec<String>,
     datamodel: Datamodel,
     config: Configuration,
 }
@@ -33,15 +52,109 @@ impl ContextBuilder {
         self
     }
 
+    pub fn read_only(mut self, val: bool) -> Self {
+        self.read_only = val;
+        self
+    }
+
+    pub fn hide_deprecated(mut self, val: bool) -> Self {
+        self.hide_deprecated = val;
+        self
+    }
+
+    pub fn query_tags(mut self, val: QueryTags) -> Self {
+        self.query_tags = val;
+        self
+    }
+
+    pub fn omitted_fields(mut self, val: OmittedFields) -> Self {
+        self.omitted_fields = val;
+        self
+    }
+
+    pub fn computed_fields(mut self, val: ComputedFields) -> Self {
+        self.computed_fields = val;
+        self
+    }
+
+    pub fn unknown_enum_values(mut self, val: UnknownEnumValues) -> Self {
+        self.unknown_enum_values = val;
+        self
+    }
+
+    pub fn field_transformers(mut self, val: FieldTransformers) -> Self {
+        self.field_transformers = val;
+        self
+    }
+
+    pub fn pool_config(mut self, val: PoolConfig) -> Self {
+        self.pool_config = val;
+        self
+    }
+
+    pub fn authorization_policy(mut self, val: Option<AuthorizationPolicy>) -> Self {
+        self.authorization_policy = val;
+        self
+    }
+
+    pub fn read_replicas(mut self, val: Vec<String>) -> Self {
+        self.read_replicas = val;
+        self
+    }
+
+    pub fn tenants(mut self, val: Vec<String>) -> Self {
+        self.tenants = val;
+        self
+    }
+
     pub async fn build(self) -> PrismaResult<PrismaContext> {
-        PrismaContext::new(self.config, self.datamodel, self.legacy, self.enable_raw_queries).await
+        PrismaContext::new(
+            self.config,
+            self.datamodel,
+            self.legacy,
+            self.enable_raw_queries,
+            self.read_only,
+            self.hide_deprecated,
+            self.query_tags,
+            self.omitted_fields,
+            self.computed_fields,
+            self.unknown_enum_values,
+            self.field_transformers,
+            self.pool_config,
+            self.authorization_policy,
+            self.read_replicas,
+            self.tenants,
+        )
+        .await
     }
 }
 
 impl PrismaContext {
     /// Initializes a new Prisma context.
-    async fn new(config: Configuration, dm: Datamodel, legacy: bool, enable_raw_queries: bool) -> PrismaResult<Self> {
-        let template = DatamodelConverter::convert(&dm);
+    async fn new(
+        config: Configuration,
+        dm: Datamodel,
+        legacy: bool,
+        enable_raw_queries: bool,
+        read_only: bool,
+        hide_deprecated: bool,
+        query_tags: QueryTags,
+        omitted_fields: OmittedFields,
+        computed_fields: ComputedFields,
+        unknown_enum_values: UnknownEnumValues,
+        field_transformers: FieldTransformers,
+        pool_config: PoolConfig,
+        authorization_policy: Option<AuthorizationPolicy>,
+        read_replicas: Vec<String>,
+        tenants: Vec<String>,
+    ) -> PrismaResult<Self> {
+        validate_omitted_fields(&dm, &omitted_fields)?;
+
+        if let Some(policy) = &authorization_policy {
+            validate_authorization_policy(&dm, policy)?;
+        }
+
+        let mut template = DatamodelConverter::convert(&dm);
 
         // We only support one data source at the moment, so take the first one (default not exposed yet).
         let data_source = config
@@ -49,11 +162,32 @@ impl PrismaContext {
             .first()
             .ok_or_else(|| PrismaError::ConfigurationError("No valid data source found".into()))?;
 
+        template.capabilities = data_source.capabilities();
+
+        let transformed_fields = field_transformers
+            .fields()
+            .fold(TransformedFields::new(), |fields, (model, field)| {
+                fields.with(model, field)
+            });
+
         // Load executor
-        let (db_name, executor) = exec_loader::load(&data_source).await?;
+        let (db_name, executor) = exec_loader::load(
+            &data_source,
+            query_tags,
+            field_transformers,
+            pool_config,
+            &read_replicas,
+            &tenants,
+        )
+        .await?;
+
+        // The tenants have the same tables in schemas or databases of their own: the tables are left unqualified,
+        // to resolve to the one the connection of the request is switched to.
+        let db_name = if tenants.is_empty() { db_name } else { String::new() };
 
         // Build internal data model
         let internal_data_model = template.build(db_name);
+        let computed_fields = self::computed_fields(&config, computed_fields, &internal_data_model)?;
 
         // Construct query schema
         let build_mode = if legacy { BuildMode::Legacy } else { BuildMode::Modern };
@@ -61,13 +195,21 @@ impl PrismaContext {
             internal_data_model,
             build_mode,
             enable_raw_queries,
+            read_only,
+            hide_deprecated,
             data_source.capabilities(),
+            plural_overrides(&config)?,
+            omitted_fields,
+            computed_fields,
+            transformed_fields,
+            unknown_enum_values,
         ));
 
         Ok(Self {
             query_schema,
             dm,
             executor,
+            authorization_policy,
         })
     }
 
@@ -75,6 +217,17 @@ impl PrismaContext {
         ContextBuilder {
             legacy: false,
             enable_raw_queries: false,
+            read_only: false,
+            hide_deprecated: false,
+            query_tags: QueryTags::new(),
+            omitted_fields: OmittedFields::new(),
+            computed_fields: ComputedFields::new(),
+            unknown_enum_values: UnknownEnumValues::default(),
+            field_transformers: FieldTransformers::new(),
+            pool_config: PoolConfig::default(),
+            authorization_policy: None,
+            read_replicas: Vec::new(),
+            tenants: Vec::new(),
             datamodel,
             config,
         }
@@ -91,4 +244,120 @@ impl PrismaContext {
     pub fn primary_connector(&self) -> String {
         self.executor.primary_connector().name()
     }
+
+    /// Checks that the authorization policy allows all operations of the document to a request with
+    /// `claims`. A single unauthorized operation rejects the entire document, nothing is executed.
+    pub fn authorize(&self, document: &QueryDocument, claims: &Claims) -> PrismaResult<()> {
+        let policy = match &self.authorization_policy {
+            Some(policy) => policy,
+            None => return Ok(()),
+        };
+
+        let operations: Vec<&Operation> = match document {
+            QueryDocument::Single(operation) => vec![operation],
+            QueryDocument::Multi(BatchDocument::Multi(operations, _)) => operations.iter().collect(),
+            QueryDocument::Multi(BatchDocument::Compact(compacted)) => vec![&compacted.operation],
+        };
+
+        for operation in operations {
+            policy.authorize(operation, &self.query_schema, claims)?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the authorization policy allows a request with `claims` to use the `endpoint` of the server,
+    /// e.g. `metrics`.
+    pub fn authorize_endpoint(&self, endpoint: &str, claims: &Claims) -> PrismaResult<()> {
+        match &self.authorization_policy {
+            Some(policy) => Ok(policy.authorize_endpoint(endpoint, claims)?),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Collects the pluralization overrides of all generators. They are configured as comma-separated
+/// `Singular:Plural` pairs, e.g. `pluralOverrides = "Person:People,Cactus:Cacti"`.
+pub(crate) fn plural_overrides(config: &Configuration) -> PrismaResult<HashMap<String, String>> {
+    let mut overrides = HashMap::new();
+
+    let values = config
+        .generators
+        .iter()
+        .filter_map(|generator| generator.config.get("pluralOverrides"));
+
+    for value in values {
+        for pair in value.split(',').map(str::trim).filter(|pair| !pair.is_empty()) {
+            match pair.split(':').map(str::trim).collect::<Vec<_>>().as_slice() {
+                [singular, plural] if !singular.is_empty() && !plural.is_empty() => {
+                    overrides.insert(singular.to_string(), plural.to_string());
+                }
+                _ => {
+                    return Err(PrismaError::ConfigurationError(format!(
+                        "Invalid pluralization override `{}`, expected `Singular:Plural`.",
+                        pair
+                    )))
+                }
+            }
+        }
+    }
+
+    Ok(overrides)
+}
+
+/// Merges the computed fields declared in the generators, as semicolon-separated
+/// `Model.field = expression` declarations in their `computedFields` option, with the ones of the
+/// engine configuration, and validates them against the data model.
+pub(crate) fn computed_fields(
+    config: &Configuration,
+    engine_computed_fields: ComputedFields,
+    internal_data_model: &InternalDataModel,
+) -> PrismaResult<ComputedFields> {
+    let mut computed_fields = ComputedFields::new();
+
+    let declarations = config
+        .generators
+        .iter()
+        .filter_map(|generator| generator.config.get("computedFields"));
+
+    for declarations in declarations {
+        computed_fields.extend(ComputedFields::parse(declarations).map_err(PrismaError::ConfigurationError)?);
+    }
+
+    computed_fields.extend(engine_computed_fields);
+    computed_fields
+        .validate(internal_data_model)
+        .map_err(PrismaError::ConfigurationError)?;
+
+    Ok(computed_fields)
+}
+
+/// A rule on a model that does not exist is most likely a typo that would leave the model unreachable.
+pub(crate) fn validate_authorization_policy(dm: &Datamodel, policy: &AuthorizationPolicy) -> PrismaResult<()> {
+    match policy.models().find(|model| dm.find_model(model).is_none()) {
+        Some(model) => Err(PrismaError::ConfigurationError(format!(
+            "Invalid authorization rule on `{}`: the model does not exist.",
+            model
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Omitting a field that does not exist is most likely a typo that would leave the field exposed.
+pub(crate) fn validate_omitted_fields(dm: &Datamodel, omitted_fields: &OmittedFields) -> PrismaResult<()> {
+    for (model, field) in omitted_fields.iter() {
+        let exists = dm
+            .find_model(model)
+            .map(|model| model.find_field(field).is_some())
+            .unwrap_or(false);
+
+        if !exists {
+            return Err(PrismaError::ConfigurationError(format!(
+                "Cannot omit `{}.{}`: the field does not exist.",
+                model, field
+            )));
+        }
+    }
+
+    Ok(())
 }
@@ -18,6 +18,8 @@ pub struct DmmfOutputField {
     pub is_required: bool,
     pub is_nullable: bool,
     pub output_type: DmmfTypeReference,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -49,6 +51,8 @@ pub struct DmmfInputField {
     pub is_required: bool,
     pub is_nullable: bool,
     pub input_types: Vec<DmmfTypeReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -21,6 +21,7 @@ pub(super) fn render_input_field(input_field: &InputFieldRef, ctx: &mut RenderCo
         input_types: type_references,
         is_required: input_field.is_required,
         is_nullable: nullable,
+        deprecation: input_field.deprecation.clone(),
     };
 
     field
@@ -36,6 +37,7 @@ pub(super) fn render_output_field(field: &OutputFieldRef, ctx: &mut RenderContex
         output_type,
         is_required: field.is_required,
         is_nullable: !field.is_required,
+        deprecation: field.deprecation.clone(),
     };
 
     ctx.add_mapping(field.name.clone(), field.query_builder.as_ref());
@@ -48,8 +48,11 @@ pub(super) fn render_output_type(output_type: &OutputType, ctx: &mut RenderConte
                 ScalarType::Null => "Null",
                 ScalarType::String => "String",
                 ScalarType::Int => "Int",
+                ScalarType::BigInt => "BigInt",
                 ScalarType::Boolean => "Boolean",
                 ScalarType::Float => "Float",
+                ScalarType::Decimal => "Decimal",
+                ScalarType::Bytes => "Bytes",
                 ScalarType::DateTime => "DateTime",
                 ScalarType::Json => "Json",
                 ScalarType::UUID => "UUID",
@@ -122,8 +125,11 @@ pub(super) fn render_input_type(input_type: &InputType, ctx: &mut RenderContext)
                 ScalarType::Null => "Null",
                 ScalarType::String => "String",
                 ScalarType::Int => "Int",
+                ScalarType::BigInt => "BigInt",
                 ScalarType::Boolean => "Boolean",
                 ScalarType::Float => "Float",
+                ScalarType::Decimal => "Decimal",
+                ScalarType::Bytes => "Bytes",
                 ScalarType::DateTime => "DateTime",
                 ScalarType::Json => "Json",
                 ScalarType::UUID => "UUID",
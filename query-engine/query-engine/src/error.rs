@@ -44,6 +44,10 @@ pub enum PrismaError {
 
     #[error("{}", _0)]
     FeatureError(String),
+
+    /// A transactional batch nested in a non-transactional one, at the given path.
+    #[error("Transactional batch at `{}` is nested in a non-transactional batch.", _0)]
+    NestedTransactionError(String),
 }
 
 impl PrismaError {
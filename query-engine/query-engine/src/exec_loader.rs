@@ -1,27 +1,64 @@
 use crate::{PrismaError, PrismaResult};
-use connector::Connector;
+use connector::{Connector, FieldTransformers, PoolConfig, QueryTags};
 
 use datamodel::{
     common::provider_names::{MSSQL_SOURCE_NAME, MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME},
     Datasource,
 };
 use query_core::executor::{InterpretingExecutor, QueryExecutor};
-use std::{collections::HashMap, path::PathBuf};
+use std::collections::HashMap;
 use url::Url;
 
 #[cfg(feature = "sql")]
 use sql_connector::*;
 
-pub async fn load(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+pub async fn load(
+    source: &Datasource,
+    query_tags: QueryTags,
+    field_transformers: FieldTransformers,
+    pool_config: PoolConfig,
+    read_replicas: &[String],
+    tenants: &[String],
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     match source.active_provider.as_str() {
         #[cfg(feature = "sql")]
-        SQLITE_SOURCE_NAME => sqlite(source).await,
+        SQLITE_SOURCE_NAME => {
+            sqlite(
+                source,
+                query_tags,
+                field_transformers,
+                pool_config,
+                read_replicas,
+                tenants,
+            )
+            .await
+        }
 
         #[cfg(feature = "sql")]
-        MYSQL_SOURCE_NAME => mysql(source).await,
+        MYSQL_SOURCE_NAME => {
+            mysql(
+                source,
+                query_tags,
+                field_transformers,
+                pool_config,
+                read_replicas,
+                tenants,
+            )
+            .await
+        }
 
         #[cfg(feature = "sql")]
-        POSTGRES_SOURCE_NAME => postgres(source).await,
+        POSTGRES_SOURCE_NAME => {
+            postgres(
+                source,
+                query_tags,
+                field_transformers,
+                pool_config,
+                read_replicas,
+                tenants,
+            )
+            .await
+        }
 
         #[cfg(feature = "sql")]
         MSSQL_SOURCE_NAME => {
@@ -33,7 +70,15 @@ pub async fn load(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExe
                 return Err(PrismaError::CoreError(error));
             }
 
-            mssql(source).await
+            mssql(
+                source,
+                query_tags,
+                field_transformers,
+                pool_config,
+                read_replicas,
+                tenants,
+            )
+            .await
         }
 
         x => Err(PrismaError::ConfigurationError(format!(
@@ -44,22 +89,37 @@ pub async fn load(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExe
 }
 
 #[cfg(feature = "sql")]
-async fn sqlite(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+async fn sqlite(
+    source: &Datasource,
+    query_tags: QueryTags,
+    field_transformers: FieldTransformers,
+    pool_config: PoolConfig,
+    read_replicas: &[String],
+    tenants: &[String],
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading SQLite connector...");
 
-    let sqlite = Sqlite::from_source(source).await?;
-    let path = PathBuf::from(sqlite.file_path());
-    let db_name = path.file_stem().unwrap().to_str().unwrap().to_owned(); // Safe due to previous validations.
+    let sqlite = Sqlite::from_source(source, pool_config, read_replicas, tenants)
+        .await?
+        .with_field_transformers(field_transformers);
+    let db_name = sqlite.db_name().to_owned();
 
     trace!("Loaded SQLite connector.");
-    Ok((db_name, sql_executor(sqlite, false)))
+    Ok((db_name, sql_executor(sqlite, false, query_tags)))
 }
 
 #[cfg(feature = "sql")]
-async fn postgres(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+async fn postgres(
+    source: &Datasource,
+    query_tags: QueryTags,
+    field_transformers: FieldTransformers,
+    pool_config: PoolConfig,
+    read_replicas: &[String],
+    tenants: &[String],
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading Postgres connector...");
 
-    let url = Url::parse(&source.url().value)?;
+    let url = Url::parse(source.connection_url())?;
     let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
 
     let db_name = params
@@ -67,7 +127,9 @@ async fn postgres(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExe
         .map(ToString::to_string)
         .unwrap_or_else(|| String::from("public"));
 
-    let psql = PostgreSql::from_source(source).await?;
+    let psql = PostgreSql::from_source(source, pool_config, read_replicas, tenants)
+        .await?
+        .with_field_transformers(field_transformers);
 
     let force_transactions = params
         .get("pgbouncer")
@@ -75,15 +137,24 @@ async fn postgres(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExe
         .unwrap_or(false);
 
     trace!("Loaded Postgres connector.");
-    Ok((db_name, sql_executor(psql, force_transactions)))
+    Ok((db_name, sql_executor(psql, force_transactions, query_tags)))
 }
 
 #[cfg(feature = "sql")]
-async fn mysql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+async fn mysql(
+    source: &Datasource,
+    query_tags: QueryTags,
+    field_transformers: FieldTransformers,
+    pool_config: PoolConfig,
+    read_replicas: &[String],
+    tenants: &[String],
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading MySQL connector...");
 
-    let mysql = Mysql::from_source(source).await?;
-    let url = Url::parse(&source.url().value)?;
+    let mysql = Mysql::from_source(source, pool_config, read_replicas, tenants)
+        .await?
+        .with_field_transformers(field_transformers);
+    let url = Url::parse(source.connection_url())?;
     let err_str = "No database found in connection string";
 
     let mut db_name = url
@@ -93,16 +164,25 @@ async fn mysql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecut
     let db_name = db_name.next().expect(err_str).to_owned();
 
     trace!("Loaded MySQL connector.");
-    Ok((db_name, sql_executor(mysql, false)))
+    Ok((db_name, sql_executor(mysql, false, query_tags)))
 }
 
 #[cfg(feature = "sql")]
-async fn mssql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+async fn mssql(
+    source: &Datasource,
+    query_tags: QueryTags,
+    field_transformers: FieldTransformers,
+    pool_config: PoolConfig,
+    read_replicas: &[String],
+    tenants: &[String],
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading SQL Server connector...");
 
-    let mssql = Mssql::from_source(source).await?;
+    let mssql = Mssql::from_source(source, pool_config, read_replicas, tenants)
+        .await?
+        .with_field_transformers(field_transformers);
 
-    let mut splitted = source.url().value.split(";");
+    let mut splitted = source.connection_url().split(";");
     splitted.next();
 
     let mut params: HashMap<String, String> = splitted
@@ -118,13 +198,17 @@ async fn mssql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecut
     let db_name = params.remove("schema").unwrap_or_else(|| String::from("dbo"));
 
     trace!("Loaded SQL Server connector.");
-    Ok((db_name, sql_executor(mssql, false)))
+    Ok((db_name, sql_executor(mssql, false, query_tags)))
 }
 
 #[cfg(feature = "sql")]
-fn sql_executor<T>(connector: T, force_transactions: bool) -> Box<dyn QueryExecutor + Send + Sync + 'static>
+fn sql_executor<T>(
+    connector: T,
+    force_transactions: bool,
+    query_tags: QueryTags,
+) -> Box<dyn QueryExecutor + Send + Sync + 'static>
 where
     T: Connector + Send + Sync + 'static,
 {
-    Box::new(InterpretingExecutor::new(connector, force_transactions))
+    Box::new(InterpretingExecutor::new(connector, force_transactions).with_query_tags(query_tags))
 }
@@ -0,0 +1,141 @@
+//! Encryption at rest for the fields listed in the engine configuration, with AES-256-GCM.
+//!
+//! Values are stored as the base64 encoding of a random 96 bit nonce followed by the ciphertext, so
+//! encrypting the same value twice gives different results. Only String fields can be encrypted.
+
+use crate::{PrismaError, PrismaResult};
+use aes_gcm::{
+    aead::{generic_array::GenericArray, Aead, NewAead},
+    Aes256Gcm,
+};
+use connector::{FieldTransformationResult, FieldTransformer, FieldTransformers};
+use datamodel::{Datamodel, IndexType, ScalarType};
+use prisma_models::PrismaValue;
+use std::{fmt, sync::Arc};
+
+const KEY_LENGTH: usize = 32;
+const NONCE_LENGTH: usize = 12;
+
+pub struct FieldEncryption {
+    cipher: Aes256Gcm,
+}
+
+impl fmt::Debug for FieldEncryption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldEncryption")
+            .field("cipher", &"AES-256-GCM")
+            .finish()
+    }
+}
+
+impl FieldEncryption {
+    /// Takes the base64-encoded 256 bit key.
+    pub fn from_base64_key(key: &str) -> PrismaResult<Self> {
+        let key = base64::decode(key.trim())
+            .map_err(|_| PrismaError::ConfigurationError("The encryption key is not valid base64.".into()))?;
+
+        if key.len() != KEY_LENGTH {
+            return Err(PrismaError::ConfigurationError(format!(
+                "The encryption key must be {} bytes long, got {}.",
+                KEY_LENGTH,
+                key.len()
+            )));
+        }
+
+        Ok(Self {
+            cipher: Aes256Gcm::new(GenericArray::from_slice(&key)),
+        })
+    }
+}
+
+impl FieldTransformer for FieldEncryption {
+    fn on_write(&self, value: PrismaValue) -> FieldTransformationResult {
+        let plaintext = match value {
+            PrismaValue::String(s) => s,
+            other => return Err(format!("Only String values can be encrypted, got {:?}.", other).into()),
+        };
+
+        let nonce: [u8; NONCE_LENGTH] = rand::random();
+        let ciphertext = self
+            .cipher
+            .encrypt(GenericArray::from_slice(&nonce), plaintext.as_bytes())
+            .map_err(|_| "Encrypting the value failed.")?;
+
+        let mut stored = Vec::with_capacity(NONCE_LENGTH + ciphertext.len());
+        stored.extend_from_slice(&nonce);
+        stored.extend_from_slice(&ciphertext);
+
+        Ok(PrismaValue::String(base64::encode(&stored)))
+    }
+
+    fn on_read(&self, value: PrismaValue) -> FieldTransformationResult {
+        let stored = match value {
+            PrismaValue::String(s) => base64::decode(&s).map_err(|_| "The stored value is not encrypted.")?,
+            other => return Err(format!("Only String values can be decrypted, got {:?}.", other).into()),
+        };
+
+        if stored.len() < NONCE_LENGTH {
+            return Err("The stored value is not encrypted.".into());
+        }
+
+        let (nonce, ciphertext) = stored.split_at(NONCE_LENGTH);
+        let plaintext = self
+            .cipher
+            .decrypt(GenericArray::from_slice(nonce), ciphertext)
+            .map_err(|_| "Decrypting the value failed, it was encrypted with another key or tampered with.")?;
+
+        Ok(PrismaValue::String(String::from_utf8(plaintext)?))
+    }
+}
+
+/// The transformers encrypting the given `Model.field` pairs with the key. Fails if the fields do
+/// not exist or are not String fields, as they would end up stored in plain text otherwise, and if
+/// they are part of an id or unique constraint: encrypting the same value twice gives different
+/// ciphertexts, so the database could neither enforce the constraint nor find records by it.
+pub(crate) fn field_encryption_transformers(
+    dm: &Datamodel,
+    key: Option<&str>,
+    fields: &[(String, String)],
+) -> PrismaResult<FieldTransformers> {
+    if fields.is_empty() {
+        return Ok(FieldTransformers::new());
+    }
+
+    let key = key.ok_or_else(|| {
+        PrismaError::ConfigurationError("Encrypting fields requires an encryption key to be configured.".into())
+    })?;
+
+    let encryption = Arc::new(FieldEncryption::from_base64_key(key)?);
+    let mut transformers = FieldTransformers::new();
+
+    for (model_name, field_name) in fields {
+        let (model, field) = match dm
+            .find_model(model_name)
+            .and_then(|model| model.find_scalar_field(field_name).map(|field| (model, field)))
+        {
+            Some((model, field)) if field.field_type.scalar_type() == Some(ScalarType::String) => (model, field),
+            _ => {
+                return Err(PrismaError::ConfigurationError(format!(
+                    "Cannot encrypt `{}.{}`: the field does not exist or is not a String field.",
+                    model_name, field_name
+                )))
+            }
+        };
+
+        let in_unique_index = model
+            .indices
+            .iter()
+            .any(|index| index.tpe == IndexType::Unique && index.fields.contains(field_name));
+
+        if field.is_id || field.is_unique || model.id_fields.contains(field_name) || in_unique_index {
+            return Err(PrismaError::ConfigurationError(format!(
+                "Cannot encrypt `{}.{}`: the field is part of an id or unique constraint.",
+                model_name, field_name
+            )));
+        }
+
+        transformers.insert(model_name.as_str(), field_name.as_str(), encryption.clone());
+    }
+
+    Ok(transformers)
+}
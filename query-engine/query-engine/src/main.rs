@@ -1,6 +1,7 @@
 #[macro_use]
 extern crate tracing;
 
+use chrono::{TimeZone, Utc};
 use cli::CliCommand;
 use error::PrismaError;
 use opt::PrismaOpt;
@@ -8,7 +9,7 @@ use request_handlers::PrismaResponse;
 use std::{error::Error, process};
 use structopt::StructOpt;
 use tracing::subscriber;
-use tracing_subscriber::{EnvFilter, FmtSubscriber};
+use tracing_subscriber::{fmt::format::FmtSpan, EnvFilter, FmtSubscriber};
 
 use tide_server_timing::TimingLayer;
 use tracing_subscriber::layer::SubscriberExt;
@@ -18,6 +19,7 @@ mod context;
 mod dmmf;
 mod error;
 mod exec_loader;
+mod field_encryption;
 mod opt;
 mod request_handlers;
 mod server;
@@ -44,8 +46,19 @@ async fn main() -> Result<(), AnyError> {
 
     async fn main() -> Result<(), PrismaError> {
         let opts = PrismaOpt::from_args();
-        init_logger(opts.log_format());
+        init_logger(opts.log_format(), opts.log_queries, opts.log_spans);
         feature_flags::initialize(opts.raw_feature_flags.as_slice())?;
+
+        #[cfg(feature = "sql")]
+        if opts.log_queries {
+            sql_connector::enable_query_logging();
+        }
+
+        if let Some(seed) = opts.deterministic_seed {
+            let now = Utc.ymd(2020, 1, 1).and_hms(0, 0, 0);
+            datamodel::enable_deterministic_generators(seed, now);
+        }
+
         match CliCommand::from_opt(&opts)? {
             Some(cmd) => cmd.execute().await?,
             None => {
@@ -57,14 +70,21 @@ async fn main() -> Result<(), AnyError> {
     }
 }
 
-fn init_logger(log_format: LogFormat) {
+fn init_logger(log_format: LogFormat, log_queries: bool, log_spans: bool) {
     // Enable `tide` logs to be captured.
-    let filter = EnvFilter::from_default_env().add_directive("tide=info".parse().unwrap());
+    let mut filter = EnvFilter::from_default_env().add_directive("tide=info".parse().unwrap());
+
+    if log_queries {
+        filter = filter.add_directive("prisma::query=info".parse().unwrap());
+    }
+
+    let span_events = if log_spans { FmtSpan::CLOSE } else { FmtSpan::NONE };
 
     match log_format {
         LogFormat::Text => {
             let subscriber = FmtSubscriber::builder()
                 .with_max_level(tracing::Level::TRACE)
+                .with_span_events(span_events)
                 .finish()
                 .with(TimingLayer::new());
 
@@ -74,6 +94,7 @@ fn init_logger(log_format: LogFormat) {
             let subscriber = FmtSubscriber::builder()
                 .json()
                 .with_env_filter(filter)
+                .with_span_events(span_events)
                 .finish()
                 .with(TimingLayer::new());
             subscriber::set_global_default(subscriber).expect("Could not initialize logger");
@@ -1,7 +1,12 @@
-use crate::{error::PrismaError, PrismaResult};
+use crate::{error::PrismaError, field_encryption, PrismaResult};
+use connector::{FieldTransformers, PoolConfig, QueryTags};
 use datamodel::{Configuration, Datamodel};
+use query_core::{
+    authorization::AuthorizationPolicy,
+    schema_builder::{ComputedFields, OmittedFields, UnknownEnumValues},
+};
 use serde::Deserialize;
-use std::{ffi::OsStr, fs::File, io::Read};
+use std::{ffi::OsStr, fs::File, io::Read, time::Duration};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt, Clone)]
@@ -79,6 +84,17 @@ pub struct PrismaOpt {
     #[structopt(long, short = "r")]
     pub enable_raw_queries: bool,
 
+    /// Serves reads only: the query schema has no mutations, so mutations are rejected before
+    /// reaching the database. The engine makes no writes of its own, so the database user only
+    /// needs privileges to read.
+    #[structopt(long, env = "PRISMA_READ_ONLY")]
+    pub read_only: bool,
+
+    /// Leaves the operations of the models deprecated with `@@deprecated` out of the query schema.
+    /// Without it, they are served and marked as deprecated in the DMMF.
+    #[structopt(long, env = "PRISMA_HIDE_DEPRECATED")]
+    pub hide_deprecated: bool,
+
     /// Enables the GraphQL playground
     #[structopt(long, short = "g")]
     pub enable_playground: bool,
@@ -91,11 +107,101 @@ pub struct PrismaOpt {
     #[structopt(long = "log-format", env = "RUST_LOG_FORMAT")]
     log_format: Option<String>,
 
+    /// Logs every SQL query with its parameters, its duration and the operation it was run for,
+    /// as events of the `prisma::query` target.
+    #[structopt(long)]
+    pub log_queries: bool,
+
+    /// Logs the spans of the engine when they close, with the time spent in them. Requests are traced
+    /// in the `Request` span, with the trace id of their `traceparent` header, and its `Parse`,
+    /// `BuildQueryGraph` and `Execute` children.
+    #[structopt(long)]
+    pub log_spans: bool,
+
     #[structopt(subcommand)]
     pub subcommand: Option<Subcommand>,
 
     #[structopt(long = "enable-experimental", use_delimiter = true)]
     pub raw_feature_flags: Vec<String>,
+
+    /// Tags prepended as a comment to every SQL query, as comma-separated `key=value` pairs,
+    /// e.g. `application=my-app`. The name of the operation is added as `action`.
+    #[structopt(long, env = "PRISMA_QUERY_TAGS", use_delimiter = true, parse(try_from_str = parse_query_tag))]
+    query_tags: Vec<(String, String)>,
+
+    /// Fields left out of the query results entirely, as comma-separated `Model.field` pairs,
+    /// e.g. `User.passwordHash`. They can still be written, but never be selected.
+    #[structopt(long, env = "PRISMA_OMIT_FIELDS", use_delimiter = true, parse(try_from_str = parse_omitted_field))]
+    omit_fields: Vec<(String, String)>,
+
+    /// Fields computed from the other fields of the records at read time, as semicolon-separated
+    /// `Model.field = expression` declarations, e.g. `User.fullName = firstName + ' ' + lastName`.
+    /// Added to the ones declared with the `computedFields` option of the generators.
+    #[structopt(long, env = "PRISMA_COMPUTED_FIELDS", parse(try_from_str = parse_computed_fields))]
+    computed_fields: Option<ComputedFields>,
+
+    /// String fields encrypted at rest, as comma-separated `Model.field` pairs, e.g.
+    /// `User.socialSecurityNumber`. The database only sees the encrypted values, filtering and
+    /// ordering on these fields operates on them.
+    #[structopt(long, env = "PRISMA_ENCRYPT_FIELDS", use_delimiter = true, parse(try_from_str = parse_encrypted_field))]
+    encrypt_fields: Vec<(String, String)>,
+
+    /// The base64-encoded 256 bit AES key the fields are encrypted with.
+    #[structopt(long, env = "PRISMA_FIELD_ENCRYPTION_KEY", hide_env_values = true)]
+    field_encryption_key: Option<String>,
+
+    /// What to return for enum values in the database that the datamodel doesn't know: `error`
+    /// fails the query, `string` returns the raw database value and `null` returns null.
+    #[structopt(long, env = "PRISMA_UNKNOWN_ENUM_VALUES", default_value = "error")]
+    pub unknown_enum_values: UnknownEnumValues,
+
+    /// Makes the `now()`, `uuid()` and `cuid()` defaults deterministic, for snapshot tests: `now()`
+    /// and `@updatedAt` always return 2020-01-01T00:00:00Z, and UUIDs and CUIDs are drawn from a
+    /// random number generator seeded with the given value.
+    #[structopt(long, env = "PRISMA_DETERMINISTIC_SEED")]
+    pub deterministic_seed: Option<u64>,
+
+    /// The maximum number of open connections to the database. Overrides the `connection_limit`
+    /// parameter of the datasource URL.
+    #[structopt(long, env = "PRISMA_CONNECTION_LIMIT")]
+    connection_limit: Option<usize>,
+
+    /// How long, in seconds, opening a connection may take. Overrides the `connect_timeout`
+    /// parameter of the datasource URL.
+    #[structopt(long, env = "PRISMA_CONNECT_TIMEOUT")]
+    connect_timeout: Option<u64>,
+
+    /// How long, in seconds, a query waits for a free connection. Overrides the `pool_timeout`
+    /// parameter of the datasource URL.
+    #[structopt(long, env = "PRISMA_POOL_TIMEOUT")]
+    pool_timeout: Option<u64>,
+
+    /// How long, in seconds, a connection stays open while idle. Overrides the
+    /// `max_idle_connection_lifetime` parameter of the datasource URL.
+    #[structopt(long, env = "PRISMA_MAX_IDLE_CONNECTION_LIFETIME")]
+    max_idle_connection_lifetime: Option<u64>,
+
+    /// How long, in seconds, a connection stays open at most. Overrides the
+    /// `max_connection_lifetime` parameter of the datasource URL.
+    #[structopt(long, env = "PRISMA_MAX_CONNECTION_LIFETIME")]
+    max_connection_lifetime: Option<u64>,
+
+    /// Path to a JSON file with the rules authorizing the operations of requests, against the claims
+    /// of their `x-prisma-claims` header. Operations allowed by none of the rules are rejected.
+    #[structopt(long, env = "PRISMA_AUTHORIZATION_POLICY")]
+    authorization_policy: Option<String>,
+
+    /// The URLs of read replicas of the datasource, comma-separated. The operations that only read,
+    /// e.g. `findMany`, are spread over them, unless the request has an `x-prisma-read-from: primary`
+    /// header. Writes and transactions always run on the primary.
+    #[structopt(long, env = "PRISMA_READ_REPLICAS", use_delimiter = true, hide_env_values = true)]
+    read_replicas: Vec<String>,
+
+    /// The tenants the engine serves, comma-separated: schemas on PostgreSQL, databases on MySQL, all with the
+    /// tables of the datamodel. Requests run on the one of their `x-prisma-tenant` header, or on the one of the
+    /// datasource without the header. Each tenant gets a connection pool of its own.
+    #[structopt(long, env = "PRISMA_TENANTS", use_delimiter = true)]
+    tenants: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -105,6 +211,17 @@ struct SourceOverride {
 }
 
 impl PrismaOpt {
+    /// The connection pool configuration set on the command line, taking precedence over the datasource URL.
+    pub fn pool_config(&self) -> PoolConfig {
+        PoolConfig {
+            connection_limit: self.connection_limit,
+            connect_timeout: self.connect_timeout.map(Duration::from_secs),
+            pool_timeout: self.pool_timeout.map(Duration::from_secs),
+            max_idle_lifetime: self.max_idle_connection_lifetime.map(Duration::from_secs),
+            max_lifetime: self.max_connection_lifetime.map(Duration::from_secs),
+        }
+    }
+
     fn datamodel_str(&self) -> PrismaResult<&str> {
         let res = self
             .datamodel
@@ -166,6 +283,62 @@ impl PrismaOpt {
     pub(crate) fn unix_path(&self) -> Option<&String> {
         self.unix_path.as_ref()
     }
+
+    /// The tags to prepend to every SQL query.
+    pub(crate) fn query_tags(&self) -> QueryTags {
+        self.query_tags.iter().fold(QueryTags::new(), |tags, (key, value)| {
+            tags.with(key.as_str(), value.as_str())
+        })
+    }
+
+    /// The fields to leave out of the model output types.
+    pub(crate) fn omitted_fields(&self) -> OmittedFields {
+        self.omit_fields
+            .iter()
+            .fold(OmittedFields::new(), |fields, (model, field)| {
+                fields.with(model.as_str(), field.as_str())
+            })
+    }
+
+    /// The computed fields declared in the engine configuration.
+    pub(crate) fn computed_fields(&self) -> ComputedFields {
+        self.computed_fields.clone().unwrap_or_default()
+    }
+
+    /// The URLs of the read replicas of the datasource.
+    pub(crate) fn read_replicas(&self) -> Vec<String> {
+        self.read_replicas.clone()
+    }
+
+    /// The schemas or databases of the tenants the engine serves.
+    pub(crate) fn tenants(&self) -> Vec<String> {
+        self.tenants.iter().map(|tenant| tenant.trim().to_owned()).collect()
+    }
+
+    /// The authorization policy of the configured file, if any.
+    pub(crate) fn authorization_policy(&self) -> PrismaResult<Option<AuthorizationPolicy>> {
+        let path = match &self.authorization_policy {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+
+        let json = std::fs::read_to_string(path).map_err(|err| {
+            PrismaError::ConfigurationError(format!("Could not read the authorization policy `{}`: {}", path, err))
+        })?;
+
+        AuthorizationPolicy::from_json(&json)
+            .map(Some)
+            .map_err(|err| PrismaError::ConfigurationError(format!("Invalid authorization policy `{}`: {}", path, err)))
+    }
+
+    /// The transformers encrypting the configured fields.
+    pub(crate) fn field_transformers(&self, dm: &Datamodel) -> PrismaResult<FieldTransformers> {
+        field_encryption::field_encryption_transformers(
+            dm,
+            self.field_encryption_key.as_ref().map(String::as_str),
+            &self.encrypt_fields,
+        )
+    }
 }
 
 fn parse_base64_string(s: &str) -> PrismaResult<String> {
@@ -182,6 +355,44 @@ fn parse_base64_string(s: &str) -> PrismaResult<String> {
     }
 }
 
+fn parse_query_tag(s: &str) -> PrismaResult<(String, String)> {
+    let mut parts = s.splitn(2, '=');
+
+    match (parts.next(), parts.next()) {
+        (Some(key), Some(value)) if !key.trim().is_empty() => Ok((key.trim().to_owned(), value.trim().to_owned())),
+        _ => Err(PrismaError::ConfigurationError(format!(
+            "Invalid query tag `{}`, expected `key=value`.",
+            s
+        ))),
+    }
+}
+
+fn parse_omitted_field(s: &str) -> PrismaResult<(String, String)> {
+    parse_model_field(s, "omitted field")
+}
+
+fn parse_encrypted_field(s: &str) -> PrismaResult<(String, String)> {
+    parse_model_field(s, "encrypted field")
+}
+
+fn parse_model_field(s: &str, description: &str) -> PrismaResult<(String, String)> {
+    let mut parts = s.splitn(2, '.');
+
+    match (parts.next(), parts.next()) {
+        (Some(model), Some(field)) if !model.trim().is_empty() && !field.trim().is_empty() => {
+            Ok((model.trim().to_owned(), field.trim().to_owned()))
+        }
+        _ => Err(PrismaError::ConfigurationError(format!(
+            "Invalid {} `{}`, expected `Model.field`.",
+            description, s
+        ))),
+    }
+}
+
+fn parse_computed_fields(s: &str) -> PrismaResult<ComputedFields> {
+    ComputedFields::parse(s).map_err(PrismaError::ConfigurationError)
+}
+
 fn load_datamodel_file(path: &OsStr) -> String {
     let mut f = File::open(path).expect(&format!("Could not open datamodel file {:?}", path));
     let mut datamodel = String::new();
@@ -1,9 +1,13 @@
 use super::{protocol_adapter::GraphQLProtocolAdapter, GQLResponse};
-use crate::{context::PrismaContext, PrismaResponse, PrismaResult};
+use crate::{context::PrismaContext, PrismaError, PrismaResponse, PrismaResult};
+use connector::QueryTags;
 use futures::FutureExt;
 use graphql_parser as gql;
 use indexmap::IndexMap;
-use query_core::{BatchDocument, CompactedDocument, Item, Operation, QueryDocument, QueryValue, ResponseData};
+use query_core::{
+    authorization::Claims, BatchDocument, CompactedDocument, Item, Operation, QueryDocument, QueryValue,
+    ReadPreference, ResponseData, TxId,
+};
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, panic::AssertUnwindSafe, sync::Arc};
 
@@ -22,13 +26,89 @@ pub struct SingleQuery {
     variables: HashMap<String, String>,
 }
 
+/// A batch of queries. Entries of the batch can be batches themselves: they are flattened into the
+/// enclosing batch, and their responses are nested like in the request.
+///
+/// With `transaction: true`, all queries of the batch run in one transaction: the first failing query
+/// rolls back the changes of all of them and fails the entire batch. Otherwise, the default, the queries
+/// run independently and every query gets a response of its own.
+///
+/// Nested batches never open transactions of their own, there are no savepoints:
+/// - In a transactional batch, nested batches run in the transaction of the outermost batch,
+///   whatever their `transaction` flag. One failing query rolls back the entire request.
+/// - In a non-transactional batch, nested transactional batches are rejected with a
+///   `NestedTransaction` (P2024) error, as they could not be rolled back on their own.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MultiQuery {
-    batch: Vec<SingleQuery>,
+    batch: Vec<GraphQlBody>,
+    #[serde(default)]
     transaction: bool,
 }
 
+impl MultiQuery {
+    /// Appends the queries of the batch and its nested batches to `queries`, in order.
+    /// The `prefix` is the path of the batch in the request, for error messages.
+    fn flatten(self, prefix: &str, in_transaction: bool, queries: &mut Vec<SingleQuery>) -> PrismaResult<BatchShape> {
+        let mut shapes = Vec::with_capacity(self.batch.len());
+
+        for (idx, body) in self.batch.into_iter().enumerate() {
+            match body {
+                GraphQlBody::Single(query) => {
+                    queries.push(query);
+                    shapes.push(BatchShape::Single);
+                }
+                GraphQlBody::Multi(nested) => {
+                    let path = format!("{}batch[{}]", prefix, idx);
+
+                    if nested.transaction && !in_transaction {
+                        return Err(PrismaError::NestedTransactionError(path));
+                    }
+
+                    shapes.push(nested.flatten(&format!("{}.", path), in_transaction, queries)?);
+                }
+            }
+        }
+
+        Ok(BatchShape::Multi(shapes))
+    }
+}
+
+/// How the queries of a request are nested, to nest the responses the same way.
+#[derive(Debug, PartialEq)]
+enum BatchShape {
+    Single,
+    Multi(Vec<BatchShape>),
+}
+
+impl BatchShape {
+    /// Nests the flat responses of a batch. A response failing the entire batch is returned as is.
+    fn nest(self, response: PrismaResponse) -> PrismaResponse {
+        match (self, response) {
+            (BatchShape::Multi(shapes), PrismaResponse::Multi(responses)) => {
+                let mut responses = responses.into_iter();
+
+                PrismaResponse::Multi(shapes.into_iter().map(|shape| shape.take(&mut responses)).collect())
+            }
+            (_, response) => response,
+        }
+    }
+
+    fn take<I>(self, responses: &mut I) -> PrismaResponse
+    where
+        I: Iterator<Item = PrismaResponse>,
+    {
+        match self {
+            BatchShape::Single => responses
+                .next()
+                .expect("Invariant violation: expected one response per query of the batch."),
+            BatchShape::Multi(shapes) => {
+                PrismaResponse::Multi(shapes.into_iter().map(|shape| shape.take(responses)).collect())
+            }
+        }
+    }
+}
+
 impl From<String> for SingleQuery {
     fn from(query: String) -> Self {
         SingleQuery {
@@ -46,18 +126,21 @@ impl From<&str> for SingleQuery {
 }
 
 impl GraphQlBody {
-    /// Convert a `GraphQlBody` into a `QueryDocument`.
-    pub(crate) fn into_doc(self) -> PrismaResult<QueryDocument> {
+    /// Convert a `GraphQlBody` into a `QueryDocument`, flattening nested batches.
+    fn into_doc(self) -> PrismaResult<(QueryDocument, BatchShape)> {
         match self {
             GraphQlBody::Single(body) => {
                 let gql_doc = gql::parse_query(&body.query)?;
                 let operation = GraphQLProtocolAdapter::convert(gql_doc, body.operation_name)?;
 
-                Ok(QueryDocument::Single(operation))
+                Ok((QueryDocument::Single(operation), BatchShape::Single))
             }
             GraphQlBody::Multi(bodies) => {
-                let operations: PrismaResult<Vec<Operation>> = bodies
-                    .batch
+                let transaction = bodies.transaction;
+                let mut queries = Vec::with_capacity(bodies.batch.len());
+                let shape = bodies.flatten("", transaction, &mut queries)?;
+
+                let operations: PrismaResult<Vec<Operation>> = queries
                     .into_iter()
                     .map(|body| {
                         let gql_doc = gql::parse_query(&body.query)?;
@@ -65,33 +148,131 @@ impl GraphQlBody {
                     })
                     .collect();
 
-                Ok(QueryDocument::Multi(BatchDocument::new(
-                    operations?,
-                    bodies.transaction,
-                )))
+                Ok((
+                    QueryDocument::Multi(BatchDocument::new(operations?, transaction)),
+                    shape,
+                ))
             }
         }
     }
 }
 
-/// Handle a Graphql request.
-pub(crate) async fn handle(body: GraphQlBody, cx: Arc<PrismaContext>) -> PrismaResponse {
+/// Handle a Graphql request. The `query_tags` are attached to all queries run for the request, and its
+/// operations are authorized against the `claims` of the request. Its operations that only read run where
+/// the `read_preference` says, on the schema or database of the `tenant` if it has one.
+pub(crate) async fn handle(
+    body: GraphQlBody,
+    cx: Arc<PrismaContext>,
+    query_tags: QueryTags,
+    claims: &Claims,
+    read_preference: ReadPreference,
+    tenant: Option<String>,
+) -> PrismaResponse {
     debug!("Incoming GraphQL query: {:?}", body);
 
-    match body.into_doc() {
-        Ok(QueryDocument::Single(query)) => handle_single_query(query, cx.clone()).await,
-        Ok(QueryDocument::Multi(batch)) => match batch.compact() {
-            BatchDocument::Multi(batch, transactional) => handle_batch(batch, transactional, &cx).await,
-            BatchDocument::Compact(compacted) => handle_compacted(compacted, &cx).await,
-        },
+    match info_span!("Parse")
+        .in_scope(|| body.into_doc())
+        .and_then(|doc| authorize(doc, &cx, claims))
+    {
+        Ok((QueryDocument::Single(query), _)) => {
+            handle_single_query(query, cx.clone(), query_tags, read_preference, tenant).await
+        }
+        Ok((QueryDocument::Multi(batch), shape)) => {
+            let response = match batch.compact() {
+                BatchDocument::Multi(batch, transactional) => {
+                    handle_batch(batch, transactional, &cx, query_tags, read_preference, tenant).await
+                }
+                BatchDocument::Compact(compacted) => {
+                    handle_compacted(compacted, &cx, query_tags, read_preference, tenant).await
+                }
+            };
+
+            shape.nest(response)
+        }
         Err(err) => PrismaResponse::Single(err.into()),
     }
 }
 
-async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> PrismaResponse {
+/// Handle a GraphQL request in the interactive transaction `tx_id`. The queries of a batch run one after the other
+/// in the transaction, whatever the `transaction` flag of the batch. A failing query doesn't roll back the
+/// transaction, the client does if it wants to. The `query_tags` of the request are attached to its queries.
+pub(crate) async fn handle_in_transaction(
+    body: GraphQlBody,
+    cx: Arc<PrismaContext>,
+    tx_id: TxId,
+    query_tags: QueryTags,
+    claims: &Claims,
+) -> PrismaResponse {
+    debug!("Incoming GraphQL query in transaction {}: {:?}", tx_id, body);
+
+    match info_span!("Parse")
+        .in_scope(|| body.into_doc())
+        .and_then(|doc| authorize(doc, &cx, claims))
+    {
+        Ok((QueryDocument::Single(query), _)) => {
+            handle_query_in_transaction(query, &cx, &tx_id, query_tags.clone()).await
+        }
+        Ok((QueryDocument::Multi(BatchDocument::Multi(queries, _)), shape)) => {
+            let mut responses = Vec::with_capacity(queries.len());
+
+            for query in queries {
+                responses.push(handle_query_in_transaction(query, &cx, &tx_id, query_tags.clone()).await);
+            }
+
+            shape.nest(PrismaResponse::Multi(responses))
+        }
+        Ok((QueryDocument::Multi(BatchDocument::Compact(_)), _)) => {
+            unreachable!("Batches are only compacted when they are handled.")
+        }
+        Err(err) => PrismaResponse::Single(err.into()),
+    }
+}
+
+fn authorize(
+    (document, shape): (QueryDocument, BatchShape),
+    cx: &PrismaContext,
+    claims: &Claims,
+) -> PrismaResult<(QueryDocument, BatchShape)> {
+    cx.authorize(&document, claims)?;
+
+    Ok((document, shape))
+}
+
+async fn handle_query_in_transaction(
+    query: Operation,
+    ctx: &PrismaContext,
+    tx_id: &TxId,
+    query_tags: QueryTags,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
-    let gql_response = match AssertUnwindSafe(handle_graphql_query(query, &*ctx))
+    let response = ctx
+        .executor
+        .execute_in_tx(tx_id, query, Arc::clone(ctx.query_schema()), query_tags);
+
+    let gql_response = match AssertUnwindSafe(response).catch_unwind().await {
+        Ok(Ok(responses)) => responses.into(),
+        Ok(Err(err)) => err.into(),
+        Err(err) => {
+            // panicked
+            let error = Error::from_panic_payload(&err);
+            error.into()
+        }
+    };
+
+    PrismaResponse::Single(gql_response)
+}
+
+async fn handle_single_query(
+    query: Operation,
+    ctx: Arc<PrismaContext>,
+    query_tags: QueryTags,
+    read_preference: ReadPreference,
+    tenant: Option<String>,
+) -> PrismaResponse {
+    use user_facing_errors::Error;
+
+    let gql_response = match AssertUnwindSafe(handle_graphql_query(query, &*ctx, query_tags, read_preference, tenant))
         .catch_unwind()
         .await
     {
@@ -107,13 +288,24 @@ async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> Prism
     PrismaResponse::Single(gql_response)
 }
 
-async fn handle_batch(queries: Vec<Operation>, transactional: bool, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_batch(
+    queries: Vec<Operation>,
+    transactional: bool,
+    ctx: &Arc<PrismaContext>,
+    query_tags: QueryTags,
+    read_preference: ReadPreference,
+    tenant: Option<String>,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
-    match AssertUnwindSafe(
-        ctx.executor
-            .execute_batch(queries, transactional, ctx.query_schema().clone()),
-    )
+    match AssertUnwindSafe(ctx.executor.execute_batch(
+        queries,
+        transactional,
+        ctx.query_schema().clone(),
+        query_tags,
+        read_preference,
+        tenant,
+    ))
     .catch_unwind()
     .await
     {
@@ -139,7 +331,13 @@ async fn handle_batch(queries: Vec<Operation>, transactional: bool, ctx: &Arc<Pr
     }
 }
 
-async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_compacted(
+    document: CompactedDocument,
+    ctx: &Arc<PrismaContext>,
+    query_tags: QueryTags,
+    read_preference: ReadPreference,
+    tenant: Option<String>,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
     let plural_name = document.plural_name();
@@ -148,9 +346,15 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     let arguments = document.arguments;
     let nested_selection = document.nested_selection;
 
-    match AssertUnwindSafe(handle_graphql_query(document.operation, ctx))
-        .catch_unwind()
-        .await
+    match AssertUnwindSafe(handle_graphql_query(
+        document.operation,
+        ctx,
+        query_tags,
+        read_preference,
+        tenant,
+    ))
+    .catch_unwind()
+    .await
     {
         Ok(Ok(response_data)) => {
             let mut gql_response: GQLResponse = response_data.into();
@@ -206,6 +410,21 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     }
 }
 
-async fn handle_graphql_query(query_doc: Operation, ctx: &PrismaContext) -> PrismaResult<ResponseData> {
-    Ok(ctx.executor.execute(query_doc, Arc::clone(ctx.query_schema())).await?)
+async fn handle_graphql_query(
+    query_doc: Operation,
+    ctx: &PrismaContext,
+    query_tags: QueryTags,
+    read_preference: ReadPreference,
+    tenant: Option<String>,
+) -> PrismaResult<ResponseData> {
+    Ok(ctx
+        .executor
+        .execute(
+            query_doc,
+            Arc::clone(ctx.query_schema()),
+            query_tags,
+            read_preference,
+            tenant,
+        )
+        .await?)
 }
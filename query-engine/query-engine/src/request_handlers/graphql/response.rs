@@ -89,6 +89,10 @@ impl From<PrismaError> for GQLError {
     fn from(other: PrismaError) -> Self {
         match other {
             PrismaError::CoreError(core_error) => GQLError::from(core_error),
+            PrismaError::NestedTransactionError(path) => GQLError::from(user_facing_errors::Error::from(
+                user_facing_errors::KnownError::new(user_facing_errors::query_engine::NestedTransaction { path })
+                    .unwrap(),
+            )),
             err => GQLError::from(user_facing_errors::Error::from_dyn_error(&err)),
         }
     }
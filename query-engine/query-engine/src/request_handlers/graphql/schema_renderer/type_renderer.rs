@@ -43,8 +43,11 @@ impl<'a> GqlTypeRenderer<'a> {
                 let stringified = match scalar {
                     ScalarType::String => "String",
                     ScalarType::Int => "Int",
+                    ScalarType::BigInt => "BigInt",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "DateTime",
                     ScalarType::UUID => "UUID",
@@ -85,8 +88,11 @@ impl<'a> GqlTypeRenderer<'a> {
                 let stringified = match scalar {
                     ScalarType::String => "String",
                     ScalarType::Int => "Int",
+                    ScalarType::BigInt => "BigInt",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "Json",
                     ScalarType::UUID => "UUID",
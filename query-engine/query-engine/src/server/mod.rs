@@ -3,19 +3,44 @@
 use crate::context::PrismaContext;
 use crate::dmmf;
 use crate::opt::PrismaOpt;
-use crate::request_handlers::graphql::{self, GraphQLSchemaRenderer, GraphQlBody};
+use crate::request_handlers::graphql::{self, GQLResponse, GraphQLSchemaRenderer, GraphQlBody};
 use crate::PrismaResult;
+use connector::QueryTags;
 use elapsed_middleware::ElapsedMiddleware;
+use trace_context::TraceContext;
 
-use query_core::schema::QuerySchemaRenderer;
+use query_core::{authorization::Claims, metrics::METRICS, schema::QuerySchemaRenderer, ReadPreference, TxId};
+use serde::Deserialize;
 use serde_json::json;
 use tide::http::{mime, StatusCode};
 use tide::{Body, Request, Response};
 use tide_server_timing::TimingMiddleware;
+use tracing_futures::Instrument;
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 mod elapsed_middleware;
+mod trace_context;
+
+/// Header to run the queries of a GraphQL request in an interactive transaction.
+static TRANSACTION_ID_HEADER: &str = "x-transaction-id";
+
+/// Header to run the reads of a request on the primary rather than on a read replica, with `primary`, e.g. to
+/// read the writes of a previous request.
+static READ_FROM_HEADER: &str = "x-prisma-read-from";
+
+/// Header with the tenant of a request, one of the schemas or databases of the `--tenants` option.
+static TENANT_HEADER: &str = "x-prisma-tenant";
+
+/// Header with the claims of a request, as a JSON object, for the authorization policy. It must be set
+/// by a trusted gateway, never by the clients themselves.
+static CLAIMS_HEADER: &str = "x-prisma-claims";
+
+/// How long an interactive transaction stays open without activity, if the client doesn't say.
+const DEFAULT_TRANSACTION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The longest timeout a client can ask for, so idle transactions don't hold their connections and locks for long.
+const MAX_TRANSACTION_TIMEOUT: Duration = Duration::from_secs(60);
 
 //// Shared application state.
 pub(crate) struct State {
@@ -49,9 +74,24 @@ impl Clone for State {
 pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
     let config = opts.configuration(false)?.validate_that_one_datasource_is_provided()?;
     let datamodel = opts.datamodel(false)?;
+    let field_transformers = opts.field_transformers(&datamodel)?;
+    let authorization_policy = opts.authorization_policy()?;
+    let read_replicas = opts.read_replicas();
+    let tenants = opts.tenants();
     let cx = PrismaContext::builder(config, datamodel)
         .legacy(opts.legacy)
         .enable_raw_queries(opts.enable_raw_queries)
+        .read_only(opts.read_only)
+        .hide_deprecated(opts.hide_deprecated)
+        .query_tags(opts.query_tags())
+        .omitted_fields(opts.omitted_fields())
+        .computed_fields(opts.computed_fields())
+        .unknown_enum_values(opts.unknown_enum_values)
+        .field_transformers(field_transformers)
+        .pool_config(opts.pool_config())
+        .authorization_policy(authorization_policy)
+        .read_replicas(read_replicas)
+        .tenants(tenants)
         .build()
         .await?;
 
@@ -64,9 +104,14 @@ pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
 
     app.at("/").post(graphql_handler);
     app.at("/").get(playground_handler);
+    app.at("/transaction/start").post(start_transaction_handler);
+    app.at("/transaction/:id/commit").post(commit_transaction_handler);
+    app.at("/transaction/:id/rollback").post(rollback_transaction_handler);
     app.at("/sdl").get(sdl_handler);
     app.at("/dmmf").get(dmmf_handler);
     app.at("/server_info").get(server_info_handler);
+    app.at("/metrics").get(metrics_handler);
+    app.at("/pool").get(pool_handler);
     app.at("/status").get(|_| async move { Ok(json!({"status": "ok"})) });
 
     // NOTE: This println is essential for the correct working of the client.
@@ -92,14 +137,179 @@ async fn graphql_handler(mut req: Request<State>) -> tide::Result {
         }
     }
 
+    let trace_context = request_trace_context(&req);
+    let query_tags = request_query_tags(&req, &trace_context);
+    let claims = request_claims(&req)?;
+    let read_preference = request_read_preference(&req);
+    let tenant = request_tenant(&req);
+    let tx_id = req
+        .header(TRANSACTION_ID_HEADER)
+        .map(|id| TxId::from(id.last().as_str().to_owned()));
+
     let body: GraphQlBody = req.body_json().await?;
     let cx = req.state().cx.clone();
-    let result = graphql::handle(body, cx).await;
+
+    let result = async move {
+        match tx_id {
+            Some(tx_id) => graphql::handle_in_transaction(body, cx, tx_id, query_tags, &claims).await,
+            None => graphql::handle(body, cx, query_tags, &claims, read_preference, tenant).await,
+        }
+    }
+    .instrument(trace_context.span())
+    .await;
+
     let mut res = Response::new(StatusCode::Ok);
     res.set_body(Body::from_json(&result)?);
     Ok(res)
 }
 
+/// The body of a request starting an interactive transaction.
+#[derive(Debug, Default, Deserialize)]
+struct StartTransactionBody {
+    /// The milliseconds without activity after which the transaction is rolled back.
+    timeout: Option<u64>,
+}
+
+/// Starts an interactive transaction and responds with its id. The id is passed in the `x-transaction-id` header
+/// of GraphQL requests to run their queries in the transaction. Requests with a body that isn't a valid
+/// `StartTransactionBody`, or with a timeout above `MAX_TRANSACTION_TIMEOUT`, get a `400 Bad Request`.
+async fn start_transaction_handler(mut req: Request<State>) -> tide::Result {
+    authorize_endpoint(&req, "transaction")?;
+
+    let trace_context = request_trace_context(&req);
+    let query_tags = request_query_tags(&req, &trace_context);
+    let tenant = request_tenant(&req);
+    let timeout = start_transaction_timeout(&req.body_string().await?)?;
+
+    let started = req
+        .state()
+        .cx
+        .executor
+        .start_tx(timeout, query_tags, tenant)
+        .instrument(trace_context.span())
+        .await;
+
+    match started {
+        Ok(tx_id) => transaction_response(json!({ "id": tx_id.to_string() })),
+        Err(err) => transaction_response(GQLResponse::from(err)),
+    }
+}
+
+/// The timeout of the transaction a request starts, from its body. An empty body asks for the default.
+fn start_transaction_timeout(body: &str) -> tide::Result<Duration> {
+    let body: StartTransactionBody = if body.trim().is_empty() {
+        StartTransactionBody::default()
+    } else {
+        serde_json::from_str(body).map_err(|err| {
+            tide::Error::from_str(
+                StatusCode::BadRequest,
+                format!("The body must be a JSON object with an optional `timeout`: {}", err),
+            )
+        })?
+    };
+
+    let timeout = body
+        .timeout
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_TRANSACTION_TIMEOUT);
+
+    if timeout > MAX_TRANSACTION_TIMEOUT {
+        return Err(tide::Error::from_str(
+            StatusCode::BadRequest,
+            format!(
+                "The transaction timeout must be at most {} milliseconds.",
+                MAX_TRANSACTION_TIMEOUT.as_millis()
+            ),
+        ));
+    }
+
+    Ok(timeout)
+}
+
+/// Commits the interactive transaction with the id in the path.
+async fn commit_transaction_handler(req: Request<State>) -> tide::Result {
+    authorize_endpoint(&req, "transaction")?;
+
+    let tx_id: TxId = req.param::<String>("id")?.into();
+
+    match req.state().cx.executor.commit_tx(&tx_id).await {
+        Ok(()) => transaction_response(json!({})),
+        Err(err) => transaction_response(GQLResponse::from(err)),
+    }
+}
+
+/// Rolls back the interactive transaction with the id in the path.
+async fn rollback_transaction_handler(req: Request<State>) -> tide::Result {
+    authorize_endpoint(&req, "transaction")?;
+
+    let tx_id: TxId = req.param::<String>("id")?.into();
+
+    match req.state().cx.executor.rollback_tx(&tx_id).await {
+        Ok(()) => transaction_response(json!({})),
+        Err(err) => transaction_response(GQLResponse::from(err)),
+    }
+}
+
+fn transaction_response(body: impl serde::Serialize) -> tide::Result {
+    let mut res = Response::new(StatusCode::Ok);
+    res.set_body(Body::from_json(&body)?);
+    Ok(res)
+}
+
+/// The trace context of the `traceparent` header of a request.
+fn request_trace_context(req: &Request<State>) -> TraceContext {
+    TraceContext::from_traceparent(req.header("traceparent").map(|value| value.last().as_str()))
+}
+
+/// The query tags of a request: for requests with a W3C `traceparent` header, the `traceparent` of the
+/// span of the engine, so queries can be matched to the trace of the request that caused them.
+fn request_query_tags(req: &Request<State>, trace_context: &TraceContext) -> QueryTags {
+    let mut query_tags = QueryTags::new();
+
+    if req.header("traceparent").is_some() {
+        query_tags.insert("traceparent", trace_context.traceparent());
+    }
+
+    query_tags
+}
+
+/// Where the reads of a request run: on the primary with `x-prisma-read-from: primary`, on a replica otherwise.
+fn request_read_preference(req: &Request<State>) -> ReadPreference {
+    match req.header(READ_FROM_HEADER).map(|value| value.last().as_str()) {
+        Some("primary") => ReadPreference::Primary,
+        _ => ReadPreference::Replica,
+    }
+}
+
+/// The tenant of the `x-prisma-tenant` header of a request. Requests without one run on the schema or database of
+/// the datasource.
+fn request_tenant(req: &Request<State>) -> Option<String> {
+    req.header(TENANT_HEADER).map(|value| value.last().as_str().to_owned())
+}
+
+/// The claims of the `x-prisma-claims` header of a request, none without the header.
+fn request_claims(req: &Request<State>) -> tide::Result<Claims> {
+    match req.header(CLAIMS_HEADER) {
+        Some(value) => serde_json::from_str(value.last().as_str()).map_err(|err| {
+            tide::Error::from_str(
+                StatusCode::BadRequest,
+                format!("The `{}` header must be a JSON object: {}", CLAIMS_HEADER, err),
+            )
+        }),
+        None => Ok(Claims::new()),
+    }
+}
+
+/// Rejects requests with claims the authorization policy doesn't allow to use `endpoint`, with a `403 Forbidden`.
+fn authorize_endpoint(req: &Request<State>, endpoint: &str) -> tide::Result<()> {
+    let claims = request_claims(req)?;
+
+    req.state()
+        .cx
+        .authorize_endpoint(endpoint, &claims)
+        .map_err(|err| tide::Error::from_str(StatusCode::Forbidden, err.to_string()))
+}
+
 /// Expose the GraphQL playground if enabled.
 ///
 /// # Security
@@ -120,6 +330,8 @@ async fn playground_handler(req: Request<State>) -> tide::Result {
 /// Handler for the playground to work with the SDL-rendered query schema.
 /// Serves a raw SDL string created from the query schema.
 async fn sdl_handler(req: Request<State>) -> tide::Result<impl Into<Response>> {
+    authorize_endpoint(&req, "sdl")?;
+
     let schema = Arc::clone(&req.state().cx.query_schema());
     Ok(GraphQLSchemaRenderer::render(schema))
 }
@@ -127,6 +339,8 @@ async fn sdl_handler(req: Request<State>) -> tide::Result<impl Into<Response>> {
 /// Renders the Data Model Meta Format.
 /// Only callable if prisma was initialized using a v2 data model.
 async fn dmmf_handler(req: Request<State>) -> tide::Result {
+    authorize_endpoint(&req, "dmmf")?;
+
     let result = dmmf::render_dmmf(req.state().cx.datamodel(), Arc::clone(req.state().cx.query_schema()));
     let mut res = Response::new(StatusCode::Ok);
     res.set_body(Body::from_json(&result)?);
@@ -142,6 +356,44 @@ async fn server_info_handler(req: Request<State>) -> tide::Result<impl Into<Resp
     }))
 }
 
+/// The query parameters of the metrics endpoint.
+#[derive(Debug, Default, Deserialize)]
+struct MetricsQuery {
+    /// `json` for the metrics as JSON, the Prometheus text format otherwise.
+    format: Option<String>,
+}
+
+/// Serves the metrics of the engine, in the Prometheus text format for scraping, or as JSON with
+/// `?format=json`.
+async fn metrics_handler(req: Request<State>) -> tide::Result {
+    authorize_endpoint(&req, "metrics")?;
+
+    let query: MetricsQuery = req.query().unwrap_or_default();
+    let mut res = Response::new(StatusCode::Ok);
+
+    if query.format.as_deref() == Some("json") {
+        res.set_body(Body::from_json(&METRICS.snapshot())?);
+    } else {
+        res.set_body(METRICS.render_prometheus());
+        res.set_content_type("text/plain; version=0.0.4");
+    }
+
+    Ok(res)
+}
+
+/// Serves the configuration of the connection pool and the number of connections in use, as JSON.
+/// Connectors without a pool have no stats.
+async fn pool_handler(req: Request<State>) -> tide::Result<impl Into<Response>> {
+    authorize_endpoint(&req, "metrics")?;
+
+    let stats = req.state().cx.executor.primary_connector().pool_stats().await;
+
+    Ok(json!({
+        "pool": stats,
+        "activeConnections": METRICS.snapshot().active_connections,
+    }))
+}
+
 /// Handle debug headers inside the main GraphQL endpoint.
 async fn handle_debug_headers(req: &Request<State>) -> tide::Result<Option<impl Into<Response>>> {
     /// Debug header that triggers a panic in the request thread.
@@ -162,3 +414,34 @@ async fn handle_debug_headers(req: &Request<State>) -> tide::Result<Option<impl
         Ok(None)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transactions_without_a_timeout_get_the_default_one() {
+        assert_eq!(start_transaction_timeout("").unwrap(), DEFAULT_TRANSACTION_TIMEOUT);
+        assert_eq!(start_transaction_timeout("{}").unwrap(), DEFAULT_TRANSACTION_TIMEOUT);
+        assert_eq!(
+            start_transaction_timeout(r#"{ "timeout": 2000 }"#).unwrap(),
+            Duration::from_secs(2)
+        );
+    }
+
+    #[test]
+    fn malformed_bodies_and_long_timeouts_are_bad_requests() {
+        let invalid = [
+            "timeout=2000",
+            r#"{ "timeout": "2000" }"#,
+            r#"{ "timeout": -1 }"#,
+            r#"{ "timeout": 3600000 }"#,
+        ];
+
+        for body in &invalid {
+            let err = start_transaction_timeout(body).unwrap_err();
+
+            assert_eq!(err.status(), StatusCode::BadRequest, "{}", body);
+        }
+    }
+}
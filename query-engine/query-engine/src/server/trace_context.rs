@@ -0,0 +1,139 @@
+//! The [W3C trace context](https://www.w3.org/TR/trace-context/) of requests, read from their
+//! `traceparent` header, so the spans of the engine join the trace of the caller:
+//!
+//! ```text
+//! traceparent: 00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01
+//!              ^  ^                                ^                ^
+//!              |  trace id                         parent span id   flags
+//!              version
+//! ```
+
+use tracing::Span;
+
+/// The trace of a request, and the id of the span of the engine for it.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TraceContext {
+    /// 32 lowercase hex digits.
+    pub(crate) trace_id: String,
+    /// The span of the caller, 16 lowercase hex digits. None when the request starts a new trace.
+    pub(crate) parent_span_id: Option<String>,
+    /// The span of the engine, 16 lowercase hex digits.
+    pub(crate) span_id: String,
+    /// The trace flags of the caller, e.g. `01` for sampled.
+    pub(crate) flags: String,
+}
+
+impl TraceContext {
+    /// Continues the trace of the `traceparent` header, or starts a new one when there is no valid header.
+    pub(crate) fn from_traceparent(traceparent: Option<&str>) -> Self {
+        traceparent
+            .and_then(parse_traceparent)
+            .map(|(trace_id, parent_span_id, flags)| Self {
+                trace_id,
+                parent_span_id: Some(parent_span_id),
+                span_id: new_span_id(),
+                flags,
+            })
+            .unwrap_or_else(|| Self {
+                trace_id: format!("{:032x}", rand::random::<u128>().max(1)),
+                parent_span_id: None,
+                span_id: new_span_id(),
+                flags: String::from("01"),
+            })
+    }
+
+    /// The `traceparent` of the span of the engine, for the calls it makes.
+    pub(crate) fn traceparent(&self) -> String {
+        format!("00-{}-{}-{}", self.trace_id, self.span_id, self.flags)
+    }
+
+    /// The root span of the request. The spans of the query pipeline are nested in it.
+    pub(crate) fn span(&self) -> Span {
+        info_span!(
+            "Request",
+            trace_id = self.trace_id.as_str(),
+            span_id = self.span_id.as_str(),
+            parent_span_id = self.parent_span_id.as_deref().unwrap_or(""),
+        )
+    }
+}
+
+fn new_span_id() -> String {
+    format!("{:016x}", rand::random::<u64>().max(1))
+}
+
+/// The trace id, parent span id and flags of a `traceparent` header. All-zero ids are invalid, and so is
+/// the version `ff`.
+fn parse_traceparent(traceparent: &str) -> Option<(String, String, String)> {
+    let parts: Vec<&str> = traceparent.trim().split('-').collect();
+
+    let (version, trace_id, parent_span_id, flags) = match parts.as_slice() {
+        [version, trace_id, parent_span_id, flags, ..] => (*version, *trace_id, *parent_span_id, *flags),
+        _ => return None,
+    };
+
+    let is_hex = |s: &str, len: usize| s.len() == len && s.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'));
+    let is_zero = |s: &str| s.bytes().all(|b| b == b'0');
+
+    if !is_hex(version, 2) || version == "ff" || (version == "00" && parts.len() != 4) {
+        return None;
+    }
+
+    if !is_hex(trace_id, 32) || is_zero(trace_id) || !is_hex(parent_span_id, 16) || is_zero(parent_span_id) {
+        return None;
+    }
+
+    if !is_hex(flags, 2) {
+        return None;
+    }
+
+    Some((trace_id.to_owned(), parent_span_id.to_owned(), flags.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_trace_of_the_caller_is_continued() {
+        let context = TraceContext::from_traceparent(Some("00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01"));
+
+        assert_eq!(context.trace_id, "0af7651916cd43dd8448eb211c80319c");
+        assert_eq!(context.parent_span_id.as_deref(), Some("b7ad6b7169203331"));
+        assert_eq!(context.flags, "01");
+        assert_ne!(context.span_id, "b7ad6b7169203331");
+        assert_eq!(
+            context.traceparent(),
+            format!("00-0af7651916cd43dd8448eb211c80319c-{}-01", context.span_id)
+        );
+    }
+
+    #[test]
+    fn invalid_headers_start_a_new_trace() {
+        let invalid = [
+            "",
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331",
+            "00-0AF7651916CD43DD8448EB211C80319C-b7ad6b7169203331-01",
+            "00-00000000000000000000000000000000-b7ad6b7169203331-01",
+            "00-0af7651916cd43dd8448eb211c80319c-0000000000000000-01",
+            "ff-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01-extra",
+        ];
+
+        for traceparent in &invalid {
+            let context = TraceContext::from_traceparent(Some(traceparent));
+
+            assert_eq!(context.parent_span_id, None, "{}", traceparent);
+            assert_eq!(context.trace_id.len(), 32);
+            assert_eq!(context.span_id.len(), 16);
+        }
+    }
+
+    #[test]
+    fn future_versions_may_have_more_fields() {
+        let context =
+            TraceContext::from_traceparent(Some("01-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01-extra"));
+
+        assert_eq!(context.parent_span_id.as_deref(), Some("b7ad6b7169203331"));
+    }
+}
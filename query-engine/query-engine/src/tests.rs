@@ -1,5 +1,27 @@
+mod affected_ids;
+mod authorization;
+mod big_int;
+mod bytes;
+mod conformance;
+mod connect_or_create;
+mod cursor_pagination;
 mod decimal;
 mod dmmf;
 mod execute_raw;
+mod field_encryption;
+mod full_text_search;
+mod interactive_transactions;
+mod json_filters;
+mod native_uuid;
+mod nested_many_writes;
+mod order_by_relations;
+mod read_only;
+mod relation_count_filters;
+mod scalar_lists;
+mod sqlite_in_memory;
+mod tenants;
 mod test_api;
 mod type_mappings;
+mod unique_validation;
+mod unknown_enum_values;
+mod upsert_many;
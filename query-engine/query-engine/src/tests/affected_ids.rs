@@ -0,0 +1,73 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static MODELS: &str = indoc! {"
+    model Item {
+        id     Int    @id
+        status String
+    }
+"};
+
+async fn create_items(query_engine: &QueryEngine) {
+    for (id, status) in &[(1, "draft"), (2, "draft"), (3, "live")] {
+        let mutation = format!(
+            r#"mutation {{ createOneItem(data: {{ id: {}, status: "{}" }}) {{ id }} }}"#,
+            id, status
+        );
+
+        query_engine.request(mutation).await;
+    }
+}
+
+#[test_each_connector]
+async fn update_many_returns_the_ids_of_the_updated_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(MODELS).await?;
+    create_items(&query_engine).await;
+
+    let res = query_engine
+        .request(
+            r#"mutation { updateManyItem(where: { status: "live" }, data: { status: "archived" }) { count ids } }"#,
+        )
+        .await;
+
+    assert_eq!(
+        res,
+        json!({ "data": { "updateManyItem": { "count": 1, "ids": [r#"{"id":3}"#] } } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn delete_many_returns_the_ids_of_the_deleted_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(MODELS).await?;
+    create_items(&query_engine).await;
+
+    let res = query_engine
+        .request(r#"mutation { deleteManyItem(where: { status: "draft" }) { count ids } }"#)
+        .await;
+
+    let mut ids: Vec<String> = serde_json::from_value(res["data"]["deleteManyItem"]["ids"].clone())?;
+    ids.sort();
+
+    assert_eq!(res["data"]["deleteManyItem"]["count"], json!(2));
+    assert_eq!(ids, vec![r#"{"id":1}"#, r#"{"id":2}"#]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn batch_payloads_only_return_the_count_unless_asked_for_the_ids(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(MODELS).await?;
+    create_items(&query_engine).await;
+
+    let res = query_engine
+        .request(r#"mutation { deleteManyItem(where: { status: "draft" }) { count } }"#)
+        .await;
+
+    assert_eq!(res, json!({ "data": { "deleteManyItem": { "count": 2 } } }));
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+use super::test_api::*;
+use indoc::indoc;
+use query_core::authorization::{AuthorizationPolicy, Claims};
+use serde_json::json;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static POST: &str = indoc! {"
+    model Post {
+        id    Int    @id
+        title String
+    }
+"};
+
+static POST_WITH_AUTHOR: &str = indoc! {"
+    model Post {
+        id       Int    @id
+        title    String
+        authorId Int
+        author   User   @relation(fields: [authorId], references: [id])
+    }
+
+    model User {
+        id    Int    @id
+        email String
+        posts Post[]
+    }
+"};
+
+fn policy() -> AuthorizationPolicy {
+    AuthorizationPolicy::from_json(
+        &json!({
+            "rules": [
+                { "model": "Post", "operations": ["findMany"] },
+                { "model": "Post", "operations": ["*"], "claims": { "role": "editor" } },
+                { "model": "*", "operations": ["*"], "claims": { "role": "admin" } },
+            ]
+        })
+        .to_string(),
+    )
+    .unwrap()
+}
+
+fn editor() -> Claims {
+    json!({ "role": "editor" }).as_object().unwrap().clone()
+}
+
+fn admin() -> Claims {
+    json!({ "role": "admin" }).as_object().unwrap().clone()
+}
+
+async fn create_post_with_author(query_engine: &QueryEngine) {
+    query_engine
+        .request_with_claims(
+            r#"mutation { createOnePost(data: { id: 1, title: "Hello", author: { create: { id: 1, email: "ada@prisma.io" } } }) { id } }"#,
+            admin(),
+        )
+        .await;
+}
+
+#[test_each_connector]
+async fn operations_allowed_by_the_policy_are_executed(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine_with_authorization_policy(POST, policy()).await?;
+
+    query_engine
+        .request_with_claims(
+            r#"mutation { createOnePost(data: { id: 1, title: "Hello" }) { id } }"#,
+            editor(),
+        )
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [{ "id": 1, "title": "Hello" }] } }),
+        query_engine.request("query { findManyPost { id title } }").await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn operations_without_the_required_claims_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine_with_authorization_policy(POST, policy()).await?;
+
+    let res = query_engine
+        .request(r#"mutation { createOnePost(data: { id: 1, title: "Hello" }) { id } }"#)
+        .await;
+    let error = &res["errors"][0]["user_facing_error"];
+
+    assert_eq!(error["error_code"], json!("P2027"));
+    assert_eq!(error["meta"], json!({ "operation": "createOne", "model_name": "Post" }));
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [] } }),
+        query_engine.request("query { findManyPost { id title } }").await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn nested_reads_of_models_without_a_rule_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api
+        .create_engine_with_authorization_policy(POST_WITH_AUTHOR, policy())
+        .await?;
+
+    create_post_with_author(&query_engine).await;
+
+    let res = query_engine
+        .request("query { findManyPost { id author { email } } }")
+        .await;
+    let error = &res["errors"][0]["user_facing_error"];
+
+    assert_eq!(error["error_code"], json!("P2027"));
+    assert_eq!(error["meta"], json!({ "operation": "findOne", "model_name": "User" }));
+
+    let res = query_engine
+        .request(r#"query { findManyPost(where: { author: { email: { equals: "ada@prisma.io" } } }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        res["errors"][0]["user_facing_error"]["meta"],
+        json!({ "operation": "findMany", "model_name": "User" })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn nested_writes_to_models_without_a_rule_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api
+        .create_engine_with_authorization_policy(POST_WITH_AUTHOR, policy())
+        .await?;
+
+    create_post_with_author(&query_engine).await;
+
+    let res = query_engine
+        .request_with_claims(
+            r#"mutation { updateOnePost(where: { id: 1 }, data: { author: { update: { email: "eve@prisma.io" } } }) { id } }"#,
+            editor(),
+        )
+        .await;
+    let error = &res["errors"][0]["user_facing_error"];
+
+    assert_eq!(error["error_code"], json!("P2027"));
+    assert_eq!(error["meta"], json!({ "operation": "updateOne", "model_name": "User" }));
+
+    assert_eq!(
+        json!({ "data": { "findManyUser": [{ "email": "ada@prisma.io" }] } }),
+        query_engine
+            .request_with_claims("query { findManyUser { email } }", admin())
+            .await
+    );
+
+    Ok(())
+}
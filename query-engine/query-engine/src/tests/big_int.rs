@@ -0,0 +1,104 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static MODEL: &str = indoc! {"
+    model Counter {
+        id    Int    @id
+        value BigInt
+    }
+"};
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn big_ints_beyond_the_js_safe_range_are_returned_as_strings(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&MODEL).await?;
+
+    query_engine
+        .request(r#"mutation { createOneCounter(data: { id: 1, value: 42 }) { id } }"#)
+        .await;
+    query_engine
+        .request(r#"mutation { createOneCounter(data: { id: 2, value: "9223372036854775807" }) { id } }"#)
+        .await;
+    query_engine
+        .request(r#"mutation { createOneCounter(data: { id: 3, value: "-9223372036854775808" }) { id } }"#)
+        .await;
+    query_engine
+        .request(r#"mutation { createOneCounter(data: { id: 4, value: 9007199254740991 }) { id } }"#)
+        .await;
+    query_engine
+        .request(r#"mutation { createOneCounter(data: { id: 5, value: "9007199254740992" }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        json!({
+            "data": {
+                "findManyCounter": [
+                    { "id": 1, "value": 42 },
+                    { "id": 2, "value": "9223372036854775807" },
+                    { "id": 3, "value": "-9223372036854775808" },
+                    { "id": 4, "value": 9007199254740991i64 },
+                    { "id": 5, "value": "9007199254740992" },
+                ]
+            }
+        }),
+        query_engine
+            .request("query { findManyCounter(orderBy: { id: asc }) { id value } }")
+            .await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn big_int_fields_can_be_filtered_and_incremented(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&MODEL).await?;
+
+    query_engine
+        .request(r#"mutation { createOneCounter(data: { id: 1, value: "9007199254740990" }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "updateOneCounter": { "value": "9007199254741000" } } }),
+        query_engine
+            .request(r#"mutation { updateOneCounter(where: { id: 1 }, data: { value: { increment: 10 } }) { value } }"#)
+            .await
+    );
+
+    assert_eq!(
+        json!({ "data": { "findManyCounter": [{ "id": 1 }] } }),
+        query_engine
+            .request(r#"query { findManyCounter(where: { value: { gt: "9007199254740992" } }) { id } }"#)
+            .await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn floats_must_be_whole_numbers_in_range_to_be_big_ints(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&MODEL).await?;
+
+    for value in &["1.5", "1e20"] {
+        let mutation = format!(
+            "mutation {{ createOneCounter(data: {{ id: 1, value: {} }}) {{ id }} }}",
+            value
+        );
+        let res = query_engine.request(mutation).await;
+        let error = res["errors"][0]["error"].as_str().unwrap();
+
+        assert!(error.contains("Invalid BigInt"), "{}", error);
+    }
+
+    assert_eq!(
+        json!({ "data": { "createOneCounter": { "value": 2 } } }),
+        query_engine
+            .request("mutation { createOneCounter(data: { id: 1, value: 2.0 }) { value } }")
+            .await
+    );
+
+    Ok(())
+}
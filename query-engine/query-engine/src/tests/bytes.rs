@@ -0,0 +1,62 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static MODEL: &str = indoc! {"
+    model Attachment {
+        id      Int   @id
+        content Bytes
+    }
+"};
+
+#[test_each_connector]
+async fn bytes_fields_are_written_and_returned_base64_encoded(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&MODEL).await?;
+
+    assert_eq!(
+        json!({ "data": { "createOneAttachment": { "content": "3q2+7w==" } } }),
+        query_engine
+            .request(r#"mutation { createOneAttachment(data: { id: 1, content: "3q2+7w==" }) { content } }"#)
+            .await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn bytes_fields_can_be_filtered_by_equality(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&MODEL).await?;
+
+    query_engine
+        .request(r#"mutation { createOneAttachment(data: { id: 1, content: "AQID" }) { id } }"#)
+        .await;
+    query_engine
+        .request(r#"mutation { createOneAttachment(data: { id: 2, content: "BAUG" }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findManyAttachment": [{ "id": 2 }] } }),
+        query_engine
+            .request(r#"query { findManyAttachment(where: { content: { equals: "BAUG" } }) { id } }"#)
+            .await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn invalid_base64_in_bytes_fields_is_rejected(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&MODEL).await?;
+
+    let res = query_engine
+        .request(r#"mutation { createOneAttachment(data: { id: 1, content: "not base64!" }) { id } }"#)
+        .await;
+
+    assert!(res.to_string().contains("Invalid base64 in Bytes `not base64!`"));
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+//! Connector conformance fixtures: the same scenarios, run against every connector.
+//!
+//! A fixture in `conformance/` holds a datamodel, the mutations setting up the data and the cases to
+//! check. A case expects one result, optionally overridden per SQL family, and lists the families
+//! not supporting it yet. These gaps are checked too: a case starting to pass on a family it is
+//! marked as unsupported on fails the fixture, so the list stays accurate.
+
+use super::test_api::*;
+use quaint::connector::SqlFamily;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Fixture {
+    #[allow(dead_code)]
+    description: String,
+    datamodel: Vec<String>,
+    #[serde(default)]
+    setup: Vec<String>,
+    cases: Vec<Case>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Case {
+    name: String,
+    query: String,
+    /// The `data` of the response.
+    expected: Value,
+    #[serde(default)]
+    expected_by_family: HashMap<String, Value>,
+    #[serde(default)]
+    unsupported_on: Vec<String>,
+}
+
+#[test_each_connector]
+async fn aggregations(api: &TestApi) -> anyhow::Result<()> {
+    run_fixture(api, include_str!("conformance/aggregations.json")).await
+}
+
+#[test_each_connector]
+async fn distinct(api: &TestApi) -> anyhow::Result<()> {
+    run_fixture(api, include_str!("conformance/distinct.json")).await
+}
+
+#[test_each_connector]
+async fn filters(api: &TestApi) -> anyhow::Result<()> {
+    run_fixture(api, include_str!("conformance/filters.json")).await
+}
+
+#[test_each_connector]
+async fn group_by(api: &TestApi) -> anyhow::Result<()> {
+    run_fixture(api, include_str!("conformance/group_by.json")).await
+}
+
+#[test_each_connector]
+async fn relation_pagination(api: &TestApi) -> anyhow::Result<()> {
+    run_fixture(api, include_str!("conformance/relation_pagination.json")).await
+}
+
+#[test_each_connector]
+async fn relation_counts(api: &TestApi) -> anyhow::Result<()> {
+    run_fixture(api, include_str!("conformance/relation_counts.json")).await
+}
+
+/// Runs all cases of the fixture, failing with the list of the ones that don't conform.
+async fn run_fixture(api: &TestApi, fixture: &str) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+
+    let fixture: Fixture = serde_json::from_str(fixture)?;
+    let family = family_name(api.connection_info().sql_family());
+    let query_engine = api.create_engine(&fixture.datamodel.join("\n")).await?;
+
+    for mutation in fixture.setup.iter() {
+        let response = query_engine.request(mutation.as_str()).await;
+        anyhow::ensure!(
+            response.get("errors").is_none(),
+            "Setup mutation `{}` failed: {}",
+            mutation,
+            response
+        );
+    }
+
+    let mut failures = Vec::new();
+
+    for case in fixture.cases.iter() {
+        let expected = case.expected_by_family.get(family).unwrap_or(&case.expected);
+        let response = query_engine.request(case.query.as_str()).await;
+        let passes = response.get("errors").is_none() && results_match(expected, &response["data"]);
+        let unsupported = case.unsupported_on.iter().any(|f| f == family);
+
+        match (passes, unsupported) {
+            (false, false) => failures.push(format!("`{}`: expected {}, got {}", case.name, expected, response)),
+            (true, true) => failures.push(format!(
+                "`{}`: passes, remove `{}` from its `unsupported_on`",
+                case.name, family
+            )),
+            _ => (),
+        }
+    }
+
+    anyhow::ensure!(
+        failures.is_empty(),
+        "{} case(s) don't conform on {}:\n{}",
+        failures.len(),
+        family,
+        failures.join("\n")
+    );
+
+    Ok(())
+}
+
+fn family_name(family: SqlFamily) -> &'static str {
+    match family {
+        SqlFamily::Postgres => "postgresql",
+        SqlFamily::Mysql => "mysql",
+        SqlFamily::Sqlite => "sqlite",
+        SqlFamily::Mssql => "mssql",
+    }
+}
+
+/// Numbers are compared by value, the connectors don't agree on rendering e.g. `10` or `10.0`.
+fn results_match(expected: &Value, actual: &Value) -> bool {
+    match (expected, actual) {
+        (Value::Number(expected), Value::Number(actual)) => expected.as_f64() == actual.as_f64(),
+        (Value::Array(expected), Value::Array(actual)) => {
+            expected.len() == actual.len() && expected.iter().zip(actual).all(|(e, a)| results_match(e, a))
+        }
+        (Value::Object(expected), Value::Object(actual)) => {
+            expected.len() == actual.len()
+                && expected
+                    .iter()
+                    .all(|(key, e)| actual.get(key).map(|a| results_match(e, a)).unwrap_or(false))
+        }
+        (expected, actual) => expected == actual,
+    }
+}
@@ -0,0 +1,86 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use std::time::Duration;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static USER_POSTS: &str = indoc! {"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int    @id @default(autoincrement())
+        slug     String @unique
+        author   User?  @relation(fields: [authorId], references: [id])
+        authorId Int?
+    }
+"};
+
+static CONNECT_OR_CREATE_HELLO: &str = indoc! {r#"
+    mutation {
+        createOneUser(data: {
+            id: 1,
+            posts: { connectOrCreate: { where: { slug: "hello" }, create: { slug: "hello" } } }
+        }) { id }
+    }
+"#};
+
+async fn post_authors(query_engine: &QueryEngine) -> serde_json::Value {
+    query_engine
+        .request("query { findManyPost { slug author { id } } }")
+        .await
+}
+
+#[test_each_connector]
+async fn connect_or_create_connects_existing_records(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&USER_POSTS).await?;
+
+    query_engine
+        .request(r#"mutation { createOnePost(data: { slug: "hello" }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "createOneUser": { "id": 1 } } }),
+        query_engine.request(CONNECT_OR_CREATE_HELLO).await
+    );
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [{ "slug": "hello", "author": { "id": 1 } }] } }),
+        post_authors(&query_engine).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn connect_or_create_connects_records_created_concurrently(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&USER_POSTS).await?;
+
+    // The post is created in a transaction that is still open when the connectOrCreate looks for it: the lookup
+    // doesn't see it, and the create waits on the unique index of the slug until the transaction commits.
+    let tx_id = query_engine.start_transaction(Duration::from_secs(5)).await.unwrap();
+
+    query_engine
+        .request_in_transaction(&tx_id, r#"mutation { createOnePost(data: { slug: "hello" }) { id } }"#)
+        .await;
+
+    let commit = async {
+        tokio::time::delay_for(Duration::from_millis(500)).await;
+        query_engine.commit_transaction(&tx_id).await.unwrap();
+    };
+
+    let (response, _) = futures::join!(query_engine.request(CONNECT_OR_CREATE_HELLO), commit);
+
+    assert_eq!(json!({ "data": { "createOneUser": { "id": 1 } } }), response);
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [{ "slug": "hello", "author": { "id": 1 } }] } }),
+        post_authors(&query_engine).await
+    );
+
+    Ok(())
+}
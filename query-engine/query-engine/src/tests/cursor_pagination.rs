@@ -0,0 +1,110 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static MODELS: &str = indoc! {"
+    model Post {
+        id       Int    @id
+        category String
+        title    String
+        rank     Int
+
+        @@unique([category, rank])
+    }
+"};
+
+async fn create_posts(query_engine: &QueryEngine) {
+    let posts = [
+        (1, "news", "a", 2),
+        (2, "tech", "b", 1),
+        (3, "news", "c", 1),
+        (4, "tech", "d", 2),
+        (5, "news", "e", 3),
+        (6, "tech", "f", 3),
+    ];
+
+    for (id, category, title, rank) in &posts {
+        let mutation = format!(
+            r#"mutation {{ createOnePost(data: {{ id: {}, category: "{}", title: "{}", rank: {} }}) {{ id }} }}"#,
+            id, category, title, rank
+        );
+
+        query_engine.request(mutation).await;
+    }
+}
+
+/// Pages through the posts in the given order, `take` at a time, starting after the cursor of the previous page.
+async fn page_through(query_engine: &QueryEngine, order_by: &str, take: i64) -> Vec<Vec<i64>> {
+    let mut pages = Vec::new();
+    let mut cursor: Option<i64> = None;
+
+    loop {
+        let pagination = match cursor {
+            Some(id) => format!("cursor: {{ id: {} }}, skip: 1, take: {}", id, take),
+            None => format!("take: {}", take),
+        };
+        let query = format!(
+            "query {{ findManyPost({}, orderBy: {}) {{ id }} }}",
+            pagination, order_by
+        );
+        let res = query_engine.request(query).await;
+
+        let ids: Vec<i64> = res["data"]["findManyPost"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|post| post["id"].as_i64().unwrap())
+            .collect();
+
+        match ids.last() {
+            Some(last) => cursor = Some(*last),
+            None => break,
+        }
+
+        pages.push(ids);
+    }
+
+    pages
+}
+
+#[test_each_connector]
+async fn pages_over_a_non_unique_ordering_are_broken_by_the_id(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(MODELS).await?;
+    create_posts(&query_engine).await;
+
+    assert_eq!(
+        page_through(&query_engine, "{ category: desc }", 2).await,
+        vec![vec![2, 4], vec![6, 1], vec![3, 5]]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn pages_over_a_compound_unique_ordering_follow_all_the_fields(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(MODELS).await?;
+    create_posts(&query_engine).await;
+
+    assert_eq!(
+        page_through(&query_engine, "[{ category: asc }, { rank: desc }]", 4).await,
+        vec![vec![5, 1, 3, 6], vec![4, 2]]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn records_before_the_cursor_can_be_skipped_past_the_cursor(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(MODELS).await?;
+    create_posts(&query_engine).await;
+
+    let query = "query { findManyPost(cursor: { id: 6 }, skip: 2, take: -2, orderBy: { category: asc }) { id } }";
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [{ "id": 5 }, { "id": 2 }] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
@@ -34,3 +34,51 @@ async fn decimal_conversion(api: &TestApi) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+static DECIMAL_MODEL: &str = indoc! {"
+    model Invoice {
+        id    Int     @id
+        total Decimal
+    }
+"};
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn decimal_fields_are_returned_as_strings_without_losing_precision(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&DECIMAL_MODEL).await?;
+
+    let query = indoc! {r#"
+        mutation {
+            createOneInvoice(data: { id: 1, total: "12345678901234567.0123456789" }) { total }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "createOneInvoice": { "total": "12345678901234567.0123456789" } } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn decimal_fields_can_be_filtered_with_comparison_operators(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&DECIMAL_MODEL).await?;
+
+    query_engine
+        .request(r#"mutation { createOneInvoice(data: { id: 1, total: "0.1" }) { id } }"#)
+        .await;
+    query_engine
+        .request(r#"mutation { createOneInvoice(data: { id: 2, total: 2 }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findManyInvoice": [{ "id": 2, "total": "2" }] } }),
+        query_engine
+            .request(r#"query { findManyInvoice(where: { total: { gt: "0.1" } }) { id total } }"#)
+            .await
+    );
+
+    Ok(())
+}
@@ -1,9 +1,12 @@
 use datamodel::transform::ast_to_dml::reserved_model_names::TypeNameValidator;
 use datamodel_connector::ConnectorCapabilities;
 use prisma_models::DatamodelConverter;
-use query_core::{schema_builder, BuildMode, QuerySchema};
+use query_core::{
+    schema_builder::{self, ComputedFields, OmittedFields, TransformedFields, UnknownEnumValues},
+    BuildMode, QuerySchema,
+};
 use serial_test::serial;
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 // Tests in this file run serially because the function `get_query_schema` depends on setting an env var.
 
@@ -94,7 +97,475 @@ fn list_of_reserved_model_names_must_be_up_to_date() {
     }
 }
 
+#[test]
+#[serial]
+fn omitted_fields_must_not_be_part_of_the_output_types() {
+    let dm = r#"
+        datasource pg {
+            provider = "postgresql"
+            url = "postgresql://localhost"
+        }
+
+        model User {
+            id           Int    @id
+            email        String
+            passwordHash String
+        }
+    "#;
+    let (query_schema, datamodel) = get_query_schema_with_fields(
+        dm,
+        OmittedFields::new().with("User", "passwordHash"),
+        ComputedFields::new(),
+    );
+
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+    let user_type = dmmf
+        .schema
+        .output_types
+        .iter()
+        .find(|output| output.name == "User")
+        .expect("finding User output type");
+    let output_fields: Vec<_> = user_type.fields.iter().map(|f| f.name.as_str()).collect();
+
+    assert_eq!(output_fields, vec!["id", "email"]);
+
+    // The field can still be written.
+    let create_input = dmmf
+        .schema
+        .input_types
+        .iter()
+        .find(|input| input.name == "UserCreateInput")
+        .expect("finding UserCreateInput");
+
+    assert!(create_input.fields.iter().any(|f| f.name == "passwordHash"));
+}
+
+#[test]
+#[serial]
+fn omitted_fields_can_not_be_grouped_aggregated_or_ordered_by() {
+    let dm = r#"
+        datasource pg {
+            provider = "postgresql"
+            url = "postgresql://localhost"
+        }
+
+        model User {
+            id     Int    @id
+            email  String
+            age    Int
+            salary Int
+        }
+    "#;
+    let (query_schema, datamodel) =
+        get_query_schema_with_fields(dm, OmittedFields::new().with("User", "salary"), ComputedFields::new());
+
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+    let output_fields = |name: &str| -> Vec<String> {
+        dmmf.schema
+            .output_types
+            .iter()
+            .find(|output| output.name == name)
+            .unwrap_or_else(|| panic!("finding {}", name))
+            .fields
+            .iter()
+            .map(|f| f.name.clone())
+            .collect()
+    };
+
+    assert!(!output_fields("GroupByUser").contains(&"salary".to_owned()));
+
+    for suffix in &["Avg", "Sum", "Min", "Max"] {
+        assert_eq!(
+            output_fields(&format!("User{}AggregateOutputType", suffix)),
+            vec!["id", "age"]
+        );
+    }
+
+    let group_by_enum = dmmf
+        .schema
+        .enums
+        .iter()
+        .find(|e| e.name == "UserGroupByFieldEnum")
+        .expect("finding UserGroupByFieldEnum");
+
+    assert_eq!(group_by_enum.values, vec!["id", "email", "age"]);
+
+    let order_by_input = dmmf
+        .schema
+        .input_types
+        .iter()
+        .find(|input| input.name == "UserOrderByInput")
+        .expect("finding UserOrderByInput");
+
+    assert!(!order_by_input.fields.iter().any(|f| f.name == "salary"));
+}
+
+#[test]
+#[serial]
+fn transformed_fields_can_not_be_filtered_ordered_grouped_or_aggregated_by() {
+    let dm = r#"
+        datasource pg {
+            provider = "postgresql"
+            url = "postgresql://localhost"
+        }
+
+        model User {
+            id   Int    @id
+            name String
+            ssn  String
+        }
+    "#;
+    let (query_schema, datamodel) = build_query_schema(
+        dm,
+        OmittedFields::new(),
+        ComputedFields::new(),
+        TransformedFields::new().with("User", "ssn"),
+        false,
+    );
+
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+    let input_fields = |name: &str| -> Vec<String> {
+        dmmf.schema
+            .input_types
+            .iter()
+            .find(|input| input.name == name)
+            .unwrap_or_else(|| panic!("finding {}", name))
+            .fields
+            .iter()
+            .map(|f| f.name.clone())
+            .collect()
+    };
+
+    for name in &["UserWhereInput", "UserOrderByInput"] {
+        assert!(!input_fields(name).contains(&"ssn".to_owned()), "{}", name);
+        assert!(input_fields(name).contains(&"name".to_owned()), "{}", name);
+    }
+
+    for name in &["UserGroupByFieldEnum", "UserDistinctFieldEnum"] {
+        let field_enum = dmmf
+            .schema
+            .enums
+            .iter()
+            .find(|e| &e.name == name)
+            .unwrap_or_else(|| panic!("finding {}", name));
+
+        assert_eq!(field_enum.values, vec!["id", "name"]);
+    }
+
+    let group_by = dmmf
+        .schema
+        .output_types
+        .iter()
+        .find(|output| output.name == "GroupByUser")
+        .expect("finding GroupByUser");
+
+    assert!(!group_by.fields.iter().any(|f| f.name == "ssn"));
+}
+
+#[test]
+#[serial]
+fn computed_fields_are_nullable_output_fields_of_the_inferred_type() {
+    let dm = r#"
+        datasource pg {
+            provider = "postgresql"
+            url = "postgresql://localhost"
+        }
+
+        generator client {
+            provider = "prisma-client-js"
+            computedFields = "User.fullName = firstName + ' ' + lastName; User.score = (wins - losses) / 2"
+        }
+
+        model User {
+            id        Int    @id
+            firstName String
+            lastName  String
+            wins      Int
+            losses    Int
+        }
+    "#;
+    let (query_schema, datamodel) = get_query_schema_with_fields(dm, OmittedFields::new(), ComputedFields::new());
+
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+    let user_type = dmmf
+        .schema
+        .output_types
+        .iter()
+        .find(|output| output.name == "User")
+        .expect("finding User output type");
+    let computed: Vec<_> = user_type
+        .fields
+        .iter()
+        .filter(|f| f.name == "fullName" || f.name == "score")
+        .map(|f| (f.name.as_str(), f.output_type.typ.as_str(), f.is_nullable))
+        .collect();
+
+    assert_eq!(computed, vec![("fullName", "String", true), ("score", "Float", true)]);
+}
+
+#[test]
+#[serial]
+fn native_uuid_fields_keep_the_string_types_and_filters() {
+    let dm = r#"
+        datasource pg {
+            provider = "postgresql"
+            url = "postgresql://localhost"
+            previewFeatures = ["nativeTypes"]
+        }
+
+        model Session {
+            id    String @id @pg.Uuid
+            token String
+        }
+    "#;
+    let (query_schema, datamodel) = get_query_schema(dm);
+
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+    let session_type = dmmf
+        .schema
+        .output_types
+        .iter()
+        .find(|output| output.name == "Session")
+        .expect("finding Session output type");
+    let id_field = session_type.fields.iter().find(|f| f.name == "id").unwrap();
+
+    assert_eq!(id_field.output_type.typ, "String");
+
+    let where_input = dmmf
+        .schema
+        .input_types
+        .iter()
+        .find(|input| input.name == "SessionWhereInput")
+        .expect("finding SessionWhereInput");
+    let id_filter_types: Vec<&str> = where_input
+        .fields
+        .iter()
+        .find(|f| f.name == "id")
+        .unwrap()
+        .input_types
+        .iter()
+        .map(|t| t.typ.as_str())
+        .collect();
+
+    assert_eq!(id_filter_types, vec!["StringFilter", "String"]);
+}
+
+#[test]
+#[serial]
+fn relation_counts_are_selected_with_the_count_field() {
+    let dm = r#"
+        datasource pg {
+            provider = "postgresql"
+            url = "postgresql://localhost"
+        }
+
+        model User {
+            id       Int       @id
+            posts    Post[]
+            comments Comment[]
+            drafts   Draft[]
+        }
+
+        model Post {
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id])
+        }
+
+        model Comment {
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id])
+        }
+
+        model Draft {
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id])
+        }
+    "#;
+    let (query_schema, datamodel) =
+        get_query_schema_with_fields(dm, OmittedFields::new().with("User", "drafts"), ComputedFields::new());
+
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+    let output_type = |name: &str| {
+        dmmf.schema
+            .output_types
+            .iter()
+            .find(|output| output.name == name)
+            .expect("finding output type")
+    };
+
+    let count_field = output_type("User")
+        .fields
+        .iter()
+        .find(|f| f.name == "_count")
+        .expect("finding the _count field");
+
+    assert_eq!(count_field.output_type.typ, "UserCountOutputType");
+
+    let counts: Vec<_> = output_type("UserCountOutputType")
+        .fields
+        .iter()
+        .map(|f| (f.name.as_str(), f.output_type.typ.as_str(), f.is_nullable))
+        .collect();
+
+    assert_eq!(counts, vec![("posts", "Int", false), ("comments", "Int", false)]);
+
+    // Models without to-many relations have nothing to count.
+    assert!(output_type("Post").fields.iter().all(|f| f.name != "_count"));
+}
+
+#[test]
+#[serial]
+fn deprecated_fields_and_operations_are_marked_in_the_dmmf() {
+    let dm = r#"
+        model User {
+            id       Int    @id
+            nickname String @deprecated("Use `name` instead.")
+        }
+
+        model Post {
+            id Int @id
+
+            @@deprecated("Use the blog service.")
+        }
+    "#;
+    let (query_schema, datamodel) = get_query_schema(dm);
+
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+    let output_type = |name: &str| {
+        dmmf.schema
+            .output_types
+            .iter()
+            .find(|output| output.name == name)
+            .expect("finding output type")
+    };
+    let deprecation = |type_name: &str, field_name: &str| {
+        output_type(type_name)
+            .fields
+            .iter()
+            .find(|f| f.name == field_name)
+            .expect("finding output field")
+            .deprecation
+            .clone()
+    };
+
+    assert_eq!(deprecation("User", "nickname").as_deref(), Some("Use `name` instead."));
+    assert_eq!(deprecation("User", "id"), None);
+    assert_eq!(
+        deprecation("Query", "findManyPost").as_deref(),
+        Some("Use the blog service.")
+    );
+    assert_eq!(
+        deprecation("Mutation", "createOnePost").as_deref(),
+        Some("Use the blog service.")
+    );
+    assert_eq!(deprecation("Query", "findManyUser"), None);
+
+    let where_input = dmmf
+        .schema
+        .input_types
+        .iter()
+        .find(|input| input.name == "UserWhereInput")
+        .expect("finding UserWhereInput");
+    let nickname_filter = where_input.fields.iter().find(|f| f.name == "nickname").unwrap();
+
+    assert_eq!(nickname_filter.deprecation.as_deref(), Some("Use `name` instead."));
+}
+
+#[test]
+#[serial]
+fn deprecated_operations_can_be_hidden() {
+    let dm = r#"
+        model User {
+            id Int @id
+        }
+
+        model Post {
+            id Int @id
+
+            @@deprecated("Use the blog service.")
+        }
+    "#;
+    let (query_schema, _) = build_query_schema(
+        dm,
+        OmittedFields::new(),
+        ComputedFields::new(),
+        TransformedFields::new(),
+        true,
+    );
+
+    assert!(query_schema.find_query_field("findManyUser").is_some());
+    assert!(query_schema.find_mutation_field("createOneUser").is_some());
+    assert!(query_schema.find_query_field("findManyPost").is_none());
+    assert!(query_schema.find_mutation_field("createOnePost").is_none());
+}
+
+#[test]
+#[serial]
+fn computed_fields_must_depend_on_supported_scalars() {
+    let dm = r#"
+        model User {
+            id     Int     @id
+            name   String
+            active Boolean
+        }
+    "#;
+
+    let err = try_get_computed_fields(dm, "User.label = name + active").unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("`active` is not a String, Int or Float field."));
+
+    let err = try_get_computed_fields(dm, "User.name = name + '!'").unwrap_err();
+    assert!(err.to_string().contains("the model already has a field with that name"));
+
+    let err = try_get_computed_fields(dm, "Post.title = name").unwrap_err();
+    assert!(err.to_string().contains("the model does not exist"));
+
+    assert!(try_get_computed_fields(dm, "User.shout = name + '!'").is_ok());
+}
+
+fn try_get_computed_fields(datamodel_string: &str, declarations: &str) -> crate::PrismaResult<ComputedFields> {
+    let dm = datamodel::parse_datamodel_and_ignore_datasource_urls(datamodel_string).unwrap();
+    let config = datamodel::parse_configuration_and_ignore_datasource_urls(datamodel_string).unwrap();
+    let internal_dm = DatamodelConverter::convert(&dm).build("db".to_owned());
+    let computed_fields = ComputedFields::parse(declarations).unwrap();
+
+    crate::context::computed_fields(&config, computed_fields, &internal_dm)
+}
+
 fn get_query_schema(datamodel_string: &str) -> (QuerySchema, datamodel::dml::Datamodel) {
+    get_query_schema_with_fields(datamodel_string, OmittedFields::new(), ComputedFields::new())
+}
+
+fn get_query_schema_with_fields(
+    datamodel_string: &str,
+    omitted_fields: OmittedFields,
+    computed_fields: ComputedFields,
+) -> (QuerySchema, datamodel::dml::Datamodel) {
+    build_query_schema(
+        datamodel_string,
+        omitted_fields,
+        computed_fields,
+        TransformedFields::new(),
+        false,
+    )
+}
+
+fn build_query_schema(
+    datamodel_string: &str,
+    omitted_fields: OmittedFields,
+    computed_fields: ComputedFields,
+    transformed_fields: TransformedFields,
+    hide_deprecated: bool,
+) -> (QuerySchema, datamodel::dml::Datamodel) {
     feature_flags::initialize(&vec![String::from("all")]).unwrap();
 
     let dm = datamodel::parse_datamodel_and_ignore_datasource_urls(datamodel_string).unwrap();
@@ -105,9 +576,22 @@ fn get_query_schema(datamodel_string: &str) -> (QuerySchema, datamodel::dml::Dat
     };
     let internal_dm_template = DatamodelConverter::convert(&dm);
     let internal_ref = internal_dm_template.build("db".to_owned());
+    let computed_fields = crate::context::computed_fields(&config, computed_fields, &internal_ref).unwrap();
 
     (
-        schema_builder::build(internal_ref, BuildMode::Modern, false, capabilities),
+        schema_builder::build(
+            internal_ref,
+            BuildMode::Modern,
+            false,
+            false,
+            hide_deprecated,
+            capabilities,
+            HashMap::new(),
+            omitted_fields,
+            computed_fields,
+            transformed_fields,
+            UnknownEnumValues::default(),
+        ),
         dm,
     )
 }
@@ -295,6 +295,36 @@ async fn arrays(api: &TestApi) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test_each_connector(tags("postgres"))]
+async fn postgres_values_are_encoded_as_documented(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = concat!(
+        r#"SELECT '\\x00ff'::bytea AS bytes, 12.50::numeric AS exact, "#,
+        r#"1234567890.1234567890123::numeric AS precise, ARRAY[1, 2] AS ints, "#,
+        r#"'{"a": [true]}'::jsonb AS json, '1996-12-19'::date AS date"#,
+    );
+
+    assert_eq!(
+        json!({
+            "data": {
+                "queryRaw": [{
+                    "bytes": "AP8=",
+                    "exact": 12.5,
+                    "precise": "1234567890.1234567890123",
+                    "ints": [1, 2],
+                    "json": { "a": [true] },
+                    "date": "1996-12-19",
+                }]
+            }
+        }),
+        query_engine.request(query_raw(query, vec![])).await
+    );
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn syntactic_errors_bubbling_through_to_the_user(api: &TestApi) -> anyhow::Result<()> {
     feature_flags::initialize(&vec![String::from("all")]).unwrap();
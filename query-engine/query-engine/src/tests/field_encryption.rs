@@ -0,0 +1,163 @@
+use super::test_api::*;
+use crate::field_encryption::{field_encryption_transformers, FieldEncryption};
+use connector::{FieldTransformer, FieldTransformers};
+use indoc::indoc;
+use prisma_models::PrismaValue;
+use quaint::ast::*;
+use serde_json::json;
+use std::sync::Arc;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static USER: &str = indoc! {"
+    model User {
+        id String @id @default(cuid())
+        ssn String?
+    }
+"};
+
+// Base64 of 32 bytes, `0` to `31`.
+static KEY: &str = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+
+fn query_raw(query: &str) -> String {
+    format!(
+        r#"mutation {{ queryRaw(query: "{}", parameters: "[]") }}"#,
+        query.replace("\"", "\\\"")
+    )
+}
+
+#[test_each_connector]
+async fn encrypted_fields_are_stored_encrypted_and_read_decrypted(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+
+    let encryption = Arc::new(FieldEncryption::from_base64_key(KEY).unwrap());
+    let transformers = FieldTransformers::new().with("User", "ssn", encryption);
+    let query_engine = api.create_engine_with_field_transformers(USER, transformers).await?;
+
+    let mutation = indoc! {r#"
+        mutation {
+            createOneUser(data: { ssn: "123-45-6789" }) { id ssn }
+        }
+    "#};
+
+    let res = query_engine.request(mutation).await;
+    assert_eq!(res["data"]["createOneUser"]["ssn"], json!("123-45-6789"));
+
+    let (query, _) = api.to_sql_string(Select::from_table("User").column("ssn"))?;
+    let res = query_engine.request(query_raw(&query)).await;
+    let stored = res["data"]["queryRaw"][0]["ssn"].as_str().unwrap();
+
+    assert_ne!(stored, "123-45-6789");
+    assert!(!stored.contains("123-45-6789"));
+
+    let query = indoc! {r#"
+        mutation {
+            updateManyUser(data: { ssn: "987-65-4321" }) { count }
+        }
+    "#};
+
+    query_engine.request(query).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyUser { ssn }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyUser": [{ "ssn": "987-65-4321" }] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn null_values_of_encrypted_fields_are_stored_as_null(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+
+    let encryption = Arc::new(FieldEncryption::from_base64_key(KEY).unwrap());
+    let transformers = FieldTransformers::new().with("User", "ssn", encryption);
+    let query_engine = api.create_engine_with_field_transformers(USER, transformers).await?;
+
+    let mutation = indoc! {r#"
+        mutation {
+            createOneUser(data: {}) { ssn }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "createOneUser": { "ssn": null } } }),
+        query_engine.request(mutation).await
+    );
+
+    let (query, _) = api.to_sql_string(Select::from_table("User").column("ssn"))?;
+
+    assert_eq!(
+        json!({ "data": { "queryRaw": [{ "ssn": null }] } }),
+        query_engine.request(query_raw(&query)).await
+    );
+
+    Ok(())
+}
+
+#[test]
+fn encrypting_the_same_value_twice_gives_different_ciphertexts() {
+    let encryption = FieldEncryption::from_base64_key(KEY).unwrap();
+    let value = PrismaValue::String("secret".into());
+
+    let first = encryption.on_write(value.clone()).unwrap();
+    let second = encryption.on_write(value.clone()).unwrap();
+
+    assert_ne!(first, second);
+    assert_eq!(encryption.on_read(first).unwrap(), value);
+    assert_eq!(encryption.on_read(second).unwrap(), value);
+}
+
+#[test]
+fn decrypting_with_another_key_fails() {
+    let encryption = FieldEncryption::from_base64_key(KEY).unwrap();
+    let other = FieldEncryption::from_base64_key(&base64::encode(&[7u8; 32])).unwrap();
+
+    let stored = encryption.on_write(PrismaValue::String("secret".into())).unwrap();
+
+    assert!(other.on_read(stored).is_err());
+}
+
+#[test]
+fn keys_must_be_32_bytes_long() {
+    let err = FieldEncryption::from_base64_key(&base64::encode(&[7u8; 16])).unwrap_err();
+
+    assert_eq!(err.to_string(), "The encryption key must be 32 bytes long, got 16.");
+}
+
+#[test]
+fn fields_of_id_or_unique_constraints_can_not_be_encrypted() {
+    let dm = datamodel::parse_datamodel_and_ignore_datasource_urls(indoc! {"
+        model User {
+            id    String @id
+            email String @unique
+            first String
+            last  String
+            ssn   String
+
+            @@unique([first, last])
+        }
+    "})
+    .unwrap();
+
+    for field in &["id", "email", "last"] {
+        let fields = vec![("User".to_owned(), field.to_string())];
+        let err = field_encryption_transformers(&dm, Some(KEY), &fields).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Cannot encrypt `User.{}`: the field is part of an id or unique constraint.",
+                field
+            )
+        );
+    }
+
+    let fields = vec![("User".to_owned(), "ssn".to_owned())];
+    assert!(field_encryption_transformers(&dm, Some(KEY), &fields).is_ok());
+}
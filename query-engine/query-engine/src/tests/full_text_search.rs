@@ -0,0 +1,81 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static MODELS: &str = indoc! {"
+    model Post {
+        id    Int    @id
+        title String
+    }
+"};
+
+async fn create_posts(query_engine: &QueryEngine) {
+    let titles = ["Cats and dogs", "Dogs are loyal", "Birds can fly"];
+
+    for (id, title) in titles.iter().enumerate() {
+        let mutation = format!(
+            r#"mutation {{ createOnePost(data: {{ id: {}, title: "{}" }}) {{ id }} }}"#,
+            id + 1,
+            title
+        );
+
+        query_engine.request(mutation).await;
+    }
+}
+
+async fn post_ids(query_engine: &QueryEngine, filter: &str) -> serde_json::Value {
+    let query = format!(
+        "query {{ findManyPost(where: {{ title: {} }}, orderBy: {{ id: asc }}) {{ id }} }}",
+        filter
+    );
+
+    query_engine.request(query).await["data"]["findManyPost"].clone()
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn string_fields_can_be_searched_without_an_index(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+
+    let query_engine = api.create_engine(MODELS).await?;
+    create_posts(&query_engine).await;
+
+    assert_eq!(
+        post_ids(&query_engine, r#"{ search: "dog" }"#).await,
+        json!([{ "id": 1 }, { "id": 2 }])
+    );
+    assert_eq!(
+        post_ids(&query_engine, r#"{ search: "dogs & loyal" }"#).await,
+        json!([{ "id": 2 }])
+    );
+    assert_eq!(
+        post_ids(&query_engine, r#"{ not: { search: "dogs" } }"#).await,
+        json!([{ "id": 3 }])
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"), ignore("mysql_5_6"))]
+async fn string_fields_with_a_full_text_index_can_be_searched(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+
+    let query_engine = api.create_engine(MODELS).await?;
+
+    query_engine
+        .request(r#"mutation { executeRaw(query: "CREATE FULLTEXT INDEX Post_title_idx ON Post(title)", parameters: "[]") }"#)
+        .await;
+
+    create_posts(&query_engine).await;
+
+    assert_eq!(
+        post_ids(&query_engine, r#"{ search: "dogs" }"#).await,
+        json!([{ "id": 1 }, { "id": 2 }])
+    );
+    assert_eq!(
+        post_ids(&query_engine, r#"{ not: { search: "dogs" } }"#).await,
+        json!([{ "id": 3 }])
+    );
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use super::test_api::*;
+use indoc::indoc;
+use query_core::CoreError;
+use serde_json::json;
+use std::time::Duration;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id    Int    @id
+        title String
+    }
+"};
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+async fn titles(query_engine: &QueryEngine) -> serde_json::Value {
+    query_engine
+        .request("query { findManyTodo(orderBy: { id: asc }) { title } }")
+        .await
+}
+
+#[test_each_connector]
+async fn committed_writes_are_persisted(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+    let tx_id = query_engine.start_transaction(TIMEOUT).await.unwrap();
+
+    for (id, title) in &[(1, "a"), (2, "b")] {
+        let mutation = format!(
+            r#"mutation {{ createOneTodo(data: {{ id: {}, title: "{}" }}) {{ id }} }}"#,
+            id, title
+        );
+        let response = query_engine.request_in_transaction(&tx_id, mutation).await;
+
+        assert_eq!(json!({ "data": { "createOneTodo": { "id": id } } }), response);
+    }
+
+    let response = query_engine
+        .request_in_transaction(&tx_id, "query { findManyTodo(orderBy: { id: asc }) { title } }")
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "title": "a" }, { "title": "b" }] } }),
+        response
+    );
+
+    query_engine.commit_transaction(&tx_id).await.unwrap();
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "title": "a" }, { "title": "b" }] } }),
+        titles(&query_engine).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn rolled_back_writes_are_discarded(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+    let tx_id = query_engine.start_transaction(TIMEOUT).await.unwrap();
+
+    query_engine
+        .request_in_transaction(
+            &tx_id,
+            r#"mutation { createOneTodo(data: { id: 1, title: "a" }) { id } }"#,
+        )
+        .await;
+
+    query_engine.rollback_transaction(&tx_id).await.unwrap();
+
+    assert_eq!(json!({ "data": { "findManyTodo": [] } }), titles(&query_engine).await);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn ended_transactions_are_not_found(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+    let tx_id = query_engine.start_transaction(TIMEOUT).await.unwrap();
+
+    query_engine.commit_transaction(&tx_id).await.unwrap();
+
+    let response = query_engine
+        .request_in_transaction(
+            &tx_id,
+            r#"mutation { createOneTodo(data: { id: 1, title: "a" }) { id } }"#,
+        )
+        .await;
+
+    assert_eq!(
+        Some("P2025"),
+        response["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    match query_engine.rollback_transaction(&tx_id).await {
+        Err(CoreError::TransactionNotFound(_)) => (),
+        result => panic!("Expected the transaction not to be found, got {:?}", result),
+    }
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn expired_transactions_are_rolled_back(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+    let tx_id = query_engine
+        .start_transaction(Duration::from_millis(200))
+        .await
+        .unwrap();
+
+    query_engine
+        .request_in_transaction(
+            &tx_id,
+            r#"mutation { createOneTodo(data: { id: 1, title: "a" }) { id } }"#,
+        )
+        .await;
+
+    tokio::time::delay_for(Duration::from_millis(500)).await;
+
+    match query_engine.commit_transaction(&tx_id).await {
+        Err(CoreError::TransactionNotFound(_)) => (),
+        result => panic!("Expected the transaction not to be found, got {:?}", result),
+    }
+
+    assert_eq!(json!({ "data": { "findManyTodo": [] } }), titles(&query_engine).await);
+
+    Ok(())
+}
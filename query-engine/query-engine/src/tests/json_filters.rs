@@ -0,0 +1,101 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static MODELS: &str = indoc! {"
+    model Item {
+        id   Int  @id
+        meta Json
+    }
+"};
+
+/// Item 1 is red with tags `a` and `b`, item 2 is dark blue with tag `b`, item 3 has no color.
+async fn create_items(query_engine: &QueryEngine) {
+    let metas = [
+        r#"{ "color": "red", "size": { "width": 10 }, "tags": ["a", "b"] }"#,
+        r#"{ "color": "dark blue", "size": { "width": 20 }, "tags": ["b"] }"#,
+        r#"{ "size": { "width": 10 }, "tags": [] }"#,
+    ];
+
+    for (id, meta) in metas.iter().enumerate() {
+        let mutation = format!(
+            "mutation {{ createOneItem(data: {{ id: {}, meta: {} }}) {{ id }} }}",
+            id + 1,
+            serde_json::to_string(meta).unwrap()
+        );
+
+        query_engine.request(mutation).await;
+    }
+}
+
+async fn item_ids(query_engine: &QueryEngine, filter: &str) -> serde_json::Value {
+    let query = format!(
+        "query {{ findManyItem(where: {{ meta: {} }}, orderBy: {{ id: asc }}) {{ id }} }}",
+        filter
+    );
+
+    query_engine.request(query).await["data"]["findManyItem"].clone()
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn json_fields_can_be_filtered_by_the_values_at_an_array_path(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+
+    let query_engine = api.create_engine(MODELS).await?;
+    create_items(&query_engine).await;
+
+    assert_eq!(
+        item_ids(&query_engine, r#"{ path: ["size", "width"], equals: "10" }"#).await,
+        json!([{ "id": 1 }, { "id": 3 }])
+    );
+    assert_eq!(
+        item_ids(&query_engine, r#"{ path: ["color"], not: "\"red\"" }"#).await,
+        json!([{ "id": 2 }])
+    );
+    assert_eq!(
+        item_ids(&query_engine, r#"{ path: ["color"], stringContains: "blue" }"#).await,
+        json!([{ "id": 2 }])
+    );
+    assert_eq!(
+        item_ids(&query_engine, r#"{ path: ["color"], stringStartsWith: "re" }"#).await,
+        json!([{ "id": 1 }])
+    );
+    assert_eq!(
+        item_ids(&query_engine, r#"{ path: ["tags"], arrayContains: "\"b\"" }"#).await,
+        json!([{ "id": 1 }, { "id": 2 }])
+    );
+    assert_eq!(
+        item_ids(&query_engine, r#"{ path: ["tags"], arrayContains: "[\"a\", \"b\"]" }"#).await,
+        json!([{ "id": 1 }])
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"), ignore("mariadb", "mysql_5_6"))]
+async fn json_fields_can_be_filtered_by_the_values_at_a_json_path(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+
+    let query_engine = api.create_engine(MODELS).await?;
+    create_items(&query_engine).await;
+
+    assert_eq!(
+        item_ids(&query_engine, r#"{ path: "$.size.width", equals: "10" }"#).await,
+        json!([{ "id": 1 }, { "id": 3 }])
+    );
+    assert_eq!(
+        item_ids(&query_engine, r#"{ path: "$.size", equals: "{ \"width\": 20 }" }"#).await,
+        json!([{ "id": 2 }])
+    );
+    assert_eq!(
+        item_ids(&query_engine, r#"{ path: "$.color", stringEndsWith: "blue" }"#).await,
+        json!([{ "id": 2 }])
+    );
+    assert_eq!(
+        item_ids(&query_engine, r#"{ path: "$.tags", arrayContains: "\"a\"" }"#).await,
+        json!([{ "id": 1 }])
+    );
+
+    Ok(())
+}
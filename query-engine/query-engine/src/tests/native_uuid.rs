@@ -0,0 +1,93 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static SESSIONS: &str = indoc! {"
+    model Session {
+        id    String @id @my_db.Uuid
+        token String
+    }
+"};
+
+const SESSION_ID: &str = "8f4c3ab2-5d1e-4c7a-9b0e-2f6d1a7c3e55";
+
+async fn engine_with_a_session(api: &TestApi) -> anyhow::Result<QueryEngine> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine_with_native_types(&SESSIONS).await?;
+
+    query_engine
+        .request(format!(
+            r#"mutation {{ createOneSession(data: {{ id: "{}", token: "abc" }}) {{ id }} }}"#,
+            SESSION_ID
+        ))
+        .await;
+
+    Ok(query_engine)
+}
+
+fn assert_invalid_uuid(res: &serde_json::Value, value: &str) {
+    let error = &res["errors"][0]["user_facing_error"];
+
+    assert_eq!(error["error_code"], json!("P2019"), "{}", res);
+    assert!(
+        error["message"].as_str().unwrap().contains(&format!(
+            "Invalid UUID `{}` for the field `id` of the model `Session`",
+            value
+        )),
+        "{}",
+        res
+    );
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn native_uuid_fields_are_written_and_filtered_as_strings(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = engine_with_a_session(api).await?;
+
+    assert_eq!(
+        json!({ "data": { "findManySession": [{ "id": SESSION_ID, "token": "abc" }] } }),
+        query_engine
+            .request(format!(
+                r#"query {{ findManySession(where: {{ id: {{ in: ["{}"] }} }}) {{ id token }} }}"#,
+                SESSION_ID
+            ))
+            .await
+    );
+
+    assert_eq!(
+        json!({ "data": { "findUniqueSession": { "token": "abc" } } }),
+        query_engine
+            .request(format!(
+                r#"query {{ findUniqueSession(where: {{ id: "{}" }}) {{ token }} }}"#,
+                SESSION_ID
+            ))
+            .await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn invalid_uuids_are_rejected_for_native_uuid_fields(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = engine_with_a_session(api).await?;
+
+    let res = query_engine
+        .request(r#"mutation { createOneSession(data: { id: "not-a-uuid", token: "def" }) { id } }"#)
+        .await;
+
+    assert_invalid_uuid(&res, "not-a-uuid");
+
+    let res = query_engine
+        .request(r#"query { findManySession(where: { id: { equals: "1234" } }) { id } }"#)
+        .await;
+
+    assert_invalid_uuid(&res, "1234");
+
+    let res = query_engine
+        .request(r#"query { findUniqueSession(where: { id: "1234" }) { id } }"#)
+        .await;
+
+    assert_invalid_uuid(&res, "1234");
+
+    Ok(())
+}
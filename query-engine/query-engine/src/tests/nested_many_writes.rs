@@ -0,0 +1,91 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static MODELS: &str = indoc! {"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id        Int     @id
+        published Boolean
+        authorId  Int
+        author    User    @relation(fields: [authorId], references: [id])
+    }
+"};
+
+/// User 1 has a published and an unpublished post, user 2 an unpublished post.
+async fn create_users_and_posts(query_engine: &QueryEngine) {
+    for id in 1..=2 {
+        let mutation = format!("mutation {{ createOneUser(data: {{ id: {} }}) {{ id }} }}", id);
+        query_engine.request(mutation).await;
+    }
+
+    for (id, published, author_id) in &[(1, true, 1), (2, false, 1), (3, false, 2)] {
+        let mutation = format!(
+            "mutation {{ createOnePost(data: {{ id: {}, published: {}, author: {{ connect: {{ id: {} }} }} }}) {{ id }} }}",
+            id, published, author_id
+        );
+
+        query_engine.request(mutation).await;
+    }
+}
+
+async fn posts(query_engine: &QueryEngine) -> serde_json::Value {
+    query_engine
+        .request("query { findManyPost(orderBy: { id: asc }) { id published } }")
+        .await["data"]["findManyPost"]
+        .clone()
+}
+
+#[test_each_connector]
+async fn nested_update_many_only_updates_the_children_of_the_parent(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(MODELS).await?;
+    create_users_and_posts(&query_engine).await;
+
+    query_engine
+        .request(indoc! {r#"
+            mutation {
+                updateOneUser(
+                    where: { id: 1 }
+                    data: { posts: { updateMany: { where: { published: false }, data: { published: true } } } }
+                ) { id }
+            }
+        "#})
+        .await;
+
+    assert_eq!(
+        posts(&query_engine).await,
+        json!([
+            { "id": 1, "published": true },
+            { "id": 2, "published": true },
+            { "id": 3, "published": false },
+        ])
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn nested_delete_many_only_deletes_the_children_of_the_parent(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(MODELS).await?;
+    create_users_and_posts(&query_engine).await;
+
+    query_engine
+        .request(indoc! {r#"
+            mutation {
+                updateOneUser(where: { id: 1 }, data: { posts: { deleteMany: { published: false } } }) { id }
+            }
+        "#})
+        .await;
+
+    assert_eq!(
+        posts(&query_engine).await,
+        json!([{ "id": 1, "published": true }, { "id": 3, "published": false }])
+    );
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static MODELS: &str = indoc! {"
+    model User {
+        id    Int    @id
+        name  String
+        posts Post[]
+    }
+
+    model Post {
+        id       Int    @id
+        title    String
+        authorId Int
+        author   User   @relation(fields: [authorId], references: [id])
+    }
+"};
+
+async fn create_users_and_posts(query_engine: &QueryEngine) {
+    for (id, name) in &[(1, "Bob"), (2, "Alice"), (3, "Carol")] {
+        let mutation = format!(
+            r#"mutation {{ createOneUser(data: {{ id: {}, name: "{}" }}) {{ id }} }}"#,
+            id, name
+        );
+
+        query_engine.request(mutation).await;
+    }
+
+    for (id, author_id) in &[(1, 1), (2, 3), (3, 2), (4, 1)] {
+        let mutation = format!(
+            r#"mutation {{ createOnePost(data: {{ id: {}, title: "post", author: {{ connect: {{ id: {} }} }} }}) {{ id }} }}"#,
+            id, author_id
+        );
+
+        query_engine.request(mutation).await;
+    }
+}
+
+#[test_each_connector]
+async fn records_can_be_ordered_by_a_field_of_a_related_record(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(MODELS).await?;
+    create_users_and_posts(&query_engine).await;
+
+    let res = query_engine
+        .request("query { findManyPost(orderBy: { author: { name: asc } }) { id } }")
+        .await;
+
+    assert_eq!(
+        res,
+        json!({ "data": { "findManyPost": [{ "id": 3 }, { "id": 1 }, { "id": 4 }, { "id": 2 }] } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn records_can_be_ordered_by_the_count_of_related_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(MODELS).await?;
+    create_users_and_posts(&query_engine).await;
+
+    let res = query_engine
+        .request("query { findManyUser(orderBy: { posts: { count: desc } }) { id } }")
+        .await;
+
+    assert_eq!(
+        res,
+        json!({ "data": { "findManyUser": [{ "id": 1 }, { "id": 2 }, { "id": 3 }] } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn cursors_page_through_orderings_by_related_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(MODELS).await?;
+    create_users_and_posts(&query_engine).await;
+
+    let res = query_engine
+        .request("query { findManyPost(orderBy: { author: { name: desc } }, cursor: { id: 1 }, skip: 1) { id } }")
+        .await;
+
+    assert_eq!(res, json!({ "data": { "findManyPost": [{ "id": 4 }, { "id": 3 }] } }));
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static POST: &str = indoc! {"
+    model Post {
+        id    Int    @id
+        title String
+    }
+"};
+
+#[test_each_connector]
+async fn read_only_engines_serve_reads(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(POST).await?;
+
+    query_engine
+        .request(r#"mutation { createOnePost(data: { id: 1, title: "Hello" }) { id } }"#)
+        .await;
+
+    let read_only_engine = api.create_read_only_engine(POST).await?;
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [{ "id": 1, "title": "Hello" }] } }),
+        read_only_engine.request("query { findManyPost { id title } }").await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn read_only_engines_reject_mutations(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_read_only_engine(POST).await?;
+
+    let res = query_engine
+        .request(r#"mutation { createOnePost(data: { id: 1, title: "Hello" }) { id } }"#)
+        .await;
+
+    assert_eq!(res["errors"][0]["user_facing_error"]["error_code"], json!("P2009"));
+
+    let res = query_engine
+        .request(r#"mutation { executeRaw(query: "DELETE FROM Post", parameters: "[]") }"#)
+        .await;
+
+    assert_eq!(res["errors"][0]["user_facing_error"]["error_code"], json!("P2009"));
+
+    assert_eq!(
+        json!({ "data": { "findManyPost": [] } }),
+        query_engine.request("query { findManyPost { id title } }").await
+    );
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static MODELS: &str = indoc! {"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+"};
+
+/// User 1 has two posts, user 2 one post and user 3 none.
+async fn create_users_and_posts(query_engine: &QueryEngine) {
+    for id in 1..=3 {
+        let mutation = format!("mutation {{ createOneUser(data: {{ id: {} }}) {{ id }} }}", id);
+        query_engine.request(mutation).await;
+    }
+
+    for (id, author_id) in &[(1, 1), (2, 1), (3, 2)] {
+        let mutation = format!(
+            "mutation {{ createOnePost(data: {{ id: {}, author: {{ connect: {{ id: {} }} }} }}) {{ id }} }}",
+            id, author_id
+        );
+
+        query_engine.request(mutation).await;
+    }
+}
+
+async fn user_ids(query_engine: &QueryEngine, filter: &str) -> serde_json::Value {
+    let query = format!(
+        "query {{ findManyUser(where: {}, orderBy: {{ id: asc }}) {{ id }} }}",
+        filter
+    );
+
+    query_engine.request(query).await["data"]["findManyUser"].clone()
+}
+
+#[test_each_connector]
+async fn records_can_be_filtered_by_the_count_of_related_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(MODELS).await?;
+    create_users_and_posts(&query_engine).await;
+
+    assert_eq!(
+        user_ids(&query_engine, "{ posts: { count: { gt: 0 } } }").await,
+        json!([{ "id": 1 }, { "id": 2 }])
+    );
+    assert_eq!(
+        user_ids(&query_engine, "{ posts: { count: { gte: 1, lt: 2 } } }").await,
+        json!([{ "id": 2 }])
+    );
+    assert_eq!(
+        user_ids(&query_engine, "{ posts: { count: { in: [0, 2] } } }").await,
+        json!([{ "id": 1 }, { "id": 3 }])
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn relation_count_filters_have_an_equality_shorthand_and_negations(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(MODELS).await?;
+    create_users_and_posts(&query_engine).await;
+
+    assert_eq!(
+        user_ids(&query_engine, "{ posts: { count: 0 } }").await,
+        json!([{ "id": 3 }])
+    );
+    assert_eq!(
+        user_ids(&query_engine, "{ posts: { count: { not: { gt: 1 } } } }").await,
+        json!([{ "id": 2 }, { "id": 3 }])
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn relation_count_filters_combine_with_relation_filters(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(MODELS).await?;
+    create_users_and_posts(&query_engine).await;
+
+    let res = query_engine
+        .request(
+            "query { findManyPost(where: { author: { is: { posts: { count: 2 } } } }, orderBy: { id: asc }) { id } }",
+        )
+        .await;
+
+    assert_eq!(res, json!({ "data": { "findManyPost": [{ "id": 1 }, { "id": 2 }] } }));
+
+    Ok(())
+}
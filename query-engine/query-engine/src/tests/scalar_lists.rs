@@ -0,0 +1,97 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static MODELS: &str = indoc! {"
+    model Post {
+        id   Int      @id
+        tags String[]
+    }
+"};
+
+/// Post 1 is tagged `a` and `b`, post 2 `b` and `c`, post 3 has no tags.
+async fn create_posts(query_engine: &QueryEngine) {
+    let tags = [r#"["a", "b"]"#, r#"["b", "c"]"#, "[]"];
+
+    for (id, tags) in tags.iter().enumerate() {
+        let mutation = format!(
+            "mutation {{ createOnePost(data: {{ id: {}, tags: {{ set: {} }} }}) {{ id }} }}",
+            id + 1,
+            tags
+        );
+
+        query_engine.request(mutation).await;
+    }
+}
+
+async fn post_ids(query_engine: &QueryEngine, filter: &str) -> serde_json::Value {
+    let query = format!(
+        "query {{ findManyPost(where: {{ tags: {} }}, orderBy: {{ id: asc }}) {{ id }} }}",
+        filter
+    );
+
+    query_engine.request(query).await["data"]["findManyPost"].clone()
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn scalar_lists_can_be_filtered_by_their_elements(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+
+    let query_engine = api.create_engine(MODELS).await?;
+    create_posts(&query_engine).await;
+
+    assert_eq!(
+        post_ids(&query_engine, r#"{ has: "b" }"#).await,
+        json!([{ "id": 1 }, { "id": 2 }])
+    );
+    assert_eq!(
+        post_ids(&query_engine, r#"{ hasEvery: ["b", "c"] }"#).await,
+        json!([{ "id": 2 }])
+    );
+    assert_eq!(
+        post_ids(&query_engine, r#"{ hasSome: ["a", "c"] }"#).await,
+        json!([{ "id": 1 }, { "id": 2 }])
+    );
+    assert_eq!(post_ids(&query_engine, "{ isEmpty: true }").await, json!([{ "id": 3 }]));
+    assert_eq!(
+        post_ids(&query_engine, r#"{ equals: ["a", "b"] }"#).await,
+        json!([{ "id": 1 }])
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn elements_can_be_pushed_to_scalar_lists(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+
+    let query_engine = api.create_engine(MODELS).await?;
+    create_posts(&query_engine).await;
+
+    let res = query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { tags: { push: "c" } }) { tags } }"#)
+        .await;
+
+    assert_eq!(res, json!({ "data": { "updateOnePost": { "tags": ["a", "b", "c"] } } }));
+
+    let res = query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 3 }, data: { tags: { push: ["d", "e"] } }) { tags } }"#)
+        .await;
+
+    assert_eq!(res, json!({ "data": { "updateOnePost": { "tags": ["d", "e"] } } }));
+
+    let res = query_engine
+        .request(
+            r#"mutation { updateManyPost(where: { tags: { has: "b" } }, data: { tags: { push: "f" } }) { count } }"#,
+        )
+        .await;
+
+    assert_eq!(res, json!({ "data": { "updateManyPost": { "count": 2 } } }));
+    assert_eq!(
+        post_ids(&query_engine, r#"{ has: "f" }"#).await,
+        json!([{ "id": 1 }, { "id": 2 }])
+    );
+
+    Ok(())
+}
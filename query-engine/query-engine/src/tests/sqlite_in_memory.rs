@@ -0,0 +1,54 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+
+static DATAMODEL: &str = indoc! {"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+"};
+
+#[tokio::test]
+async fn the_migration_and_query_engines_share_in_memory_databases() -> anyhow::Result<()> {
+    let api = sqlite_in_memory_test_api("shared_in_memory").await;
+
+    // The tables are created by the migration engine, in the database of its own connection.
+    let query_engine = api.create_engine(DATAMODEL).await?;
+
+    query_engine
+        .request(r#"mutation { createOneCat(data: { id: 1, name: "Felix" }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findManyCat": [{ "name": "Felix" }] } }),
+        query_engine.request("query { findManyCat { name } }").await
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn query_engines_share_in_memory_databases() -> anyhow::Result<()> {
+    let api = sqlite_in_memory_test_api("shared_between_query_engines").await;
+    let writer = api.create_engine(DATAMODEL).await?;
+
+    writer
+        .request(r#"mutation { createOneCat(data: { id: 1, name: "Felix" }) { id } }"#)
+        .await;
+
+    let reader = api.create_engine(DATAMODEL).await?;
+
+    reader
+        .request(r#"mutation { createOneCat(data: { id: 2, name: "Garfield" }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findManyCat": [{ "name": "Felix" }, { "name": "Garfield" }] } }),
+        writer
+            .request("query { findManyCat(orderBy: { id: asc }) { name } }")
+            .await
+    );
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static POST: &str = indoc! {"
+    model Post {
+        id    Int    @id
+        title String
+    }
+"};
+
+static TENANT: &str = "tenants_acme";
+
+async fn execute_raw(query_engine: &QueryEngine, sql: &str) {
+    let mutation = format!(
+        r#"mutation {{ executeRaw(query: {}, parameters: "[]") }}"#,
+        serde_json::to_string(sql).unwrap()
+    );
+
+    query_engine.request(mutation).await;
+}
+
+/// The records of the datasource and of the tenant don't mix, and unknown tenants are rejected.
+async fn assert_tenants_are_isolated(query_engine: &QueryEngine) {
+    query_engine
+        .request(r#"mutation { createOnePost(data: { id: 1, title: "Datasource" }) { id } }"#)
+        .await;
+
+    query_engine
+        .request_for_tenant(
+            TENANT,
+            r#"mutation { createOnePost(data: { id: 1, title: "Acme" }) { id } }"#,
+        )
+        .await;
+
+    assert_eq!(
+        query_engine.request("query { findManyPost { title } }").await,
+        json!({ "data": { "findManyPost": [{ "title": "Datasource" }] } })
+    );
+    assert_eq!(
+        query_engine
+            .request_for_tenant(TENANT, "query { findManyPost { title } }")
+            .await,
+        json!({ "data": { "findManyPost": [{ "title": "Acme" }] } })
+    );
+
+    let res = query_engine
+        .request_for_tenant("globex", "query { findManyPost { title } }")
+        .await;
+
+    assert_eq!(res["errors"][0]["user_facing_error"]["error_code"], json!("P2028"));
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn requests_run_on_the_schema_of_their_tenant(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine_with_tenants(POST, vec![TENANT.to_owned()]).await?;
+
+    execute_raw(&query_engine, &format!(r#"DROP SCHEMA IF EXISTS "{}" CASCADE"#, TENANT)).await;
+    execute_raw(&query_engine, &format!(r#"CREATE SCHEMA "{}""#, TENANT)).await;
+    execute_raw(
+        &query_engine,
+        &format!(r#"CREATE TABLE "{}"."Post" (LIKE "Post" INCLUDING ALL)"#, TENANT),
+    )
+    .await;
+
+    assert_tenants_are_isolated(&query_engine).await;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn requests_run_on_the_database_of_their_tenant(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine_with_tenants(POST, vec![TENANT.to_owned()]).await?;
+
+    execute_raw(&query_engine, &format!("DROP DATABASE IF EXISTS `{}`", TENANT)).await;
+    execute_raw(&query_engine, &format!("CREATE DATABASE `{}`", TENANT)).await;
+    execute_raw(&query_engine, &format!("CREATE TABLE `{}`.`Post` LIKE `Post`", TENANT)).await;
+
+    assert_tenants_are_isolated(&query_engine).await;
+
+    Ok(())
+}
@@ -1,8 +1,9 @@
 use crate::{
-    context::PrismaContext,
+    context::{ContextBuilder, PrismaContext},
     request_handlers::{graphql, GraphQlBody, SingleQuery},
     PrismaResponse,
 };
+use connector::{FieldTransformers, QueryTags};
 use migration_core::{
     api::{GenericApi, MigrationApi},
     commands::SchemaPushInput,
@@ -12,9 +13,17 @@ use quaint::{
     connector::ConnectionInfo,
     visitor::{self, Visitor},
 };
+use query_core::{
+    authorization::{AuthorizationPolicy, Claims},
+    schema_builder::UnknownEnumValues,
+    ReadPreference, TxId,
+};
 use sql_migration_connector::{sql_migration::SqlMigration, SqlMigrationConnector};
-use std::sync::Arc;
-use test_setup::*;
+use std::{sync::Arc, time::Duration};
+use test_setup::{
+    connectors::{ExternalConnector, ExternalConnectorFamily, TestApiFactory, TestApiFuture},
+    *,
+};
 
 pub struct QueryEngine {
     context: Arc<PrismaContext>,
@@ -26,13 +35,58 @@ impl QueryEngine {
     }
 
     pub async fn request(&self, body: impl Into<SingleQuery>) -> serde_json::Value {
+        self.request_with_claims(body, Claims::new()).await
+    }
+
+    pub async fn request_with_claims(&self, body: impl Into<SingleQuery>, claims: Claims) -> serde_json::Value {
         let body = GraphQlBody::Single(body.into());
         let cx = self.context.clone();
-        match graphql::handle(body, cx).await {
+        match graphql::handle(body, cx, QueryTags::new(), &claims, ReadPreference::default(), None).await {
             PrismaResponse::Single(response) => serde_json::to_value(response).unwrap(),
             _ => unreachable!(),
         }
     }
+
+    pub async fn request_for_tenant(&self, tenant: &str, body: impl Into<SingleQuery>) -> serde_json::Value {
+        let body = GraphQlBody::Single(body.into());
+        let cx = self.context.clone();
+        let tenant = Some(tenant.to_owned());
+
+        match graphql::handle(
+            body,
+            cx,
+            QueryTags::new(),
+            &Claims::new(),
+            ReadPreference::default(),
+            tenant,
+        )
+        .await
+        {
+            PrismaResponse::Single(response) => serde_json::to_value(response).unwrap(),
+            _ => unreachable!(),
+        }
+    }
+
+    pub async fn start_transaction(&self, timeout: Duration) -> query_core::Result<TxId> {
+        self.context.executor.start_tx(timeout, QueryTags::new(), None).await
+    }
+
+    pub async fn request_in_transaction(&self, tx_id: &TxId, body: impl Into<SingleQuery>) -> serde_json::Value {
+        let body = GraphQlBody::Single(body.into());
+        let cx = self.context.clone();
+        match graphql::handle_in_transaction(body, cx, tx_id.clone(), QueryTags::new(), &Claims::new()).await {
+            PrismaResponse::Single(response) => serde_json::to_value(response).unwrap(),
+            _ => unreachable!(),
+        }
+    }
+
+    pub async fn commit_transaction(&self, tx_id: &TxId) -> query_core::Result<()> {
+        self.context.executor.commit_tx(tx_id).await
+    }
+
+    pub async fn rollback_transaction(&self, tx_id: &TxId) -> query_core::Result<()> {
+        self.context.executor.rollback_tx(tx_id).await
+    }
 }
 
 pub struct TestApi {
@@ -43,7 +97,77 @@ pub struct TestApi {
 
 impl TestApi {
     pub async fn create_engine(&self, datamodel: &str) -> anyhow::Result<QueryEngine> {
-        let datamodel_string = format!("{}\n\n{}", self.config, datamodel);
+        self.create_engine_with_field_transformers(datamodel, FieldTransformers::new())
+            .await
+    }
+
+    pub async fn create_engine_with_field_transformers(
+        &self,
+        datamodel: &str,
+        field_transformers: FieldTransformers,
+    ) -> anyhow::Result<QueryEngine> {
+        self.create_engine_with(datamodel, |builder| builder.field_transformers(field_transformers))
+            .await
+    }
+
+    pub async fn create_engine_with_unknown_enum_values(
+        &self,
+        datamodel: &str,
+        unknown_enum_values: UnknownEnumValues,
+    ) -> anyhow::Result<QueryEngine> {
+        self.create_engine_with(datamodel, |builder| builder.unknown_enum_values(unknown_enum_values))
+            .await
+    }
+
+    pub async fn create_engine_with_authorization_policy(
+        &self,
+        datamodel: &str,
+        policy: AuthorizationPolicy,
+    ) -> anyhow::Result<QueryEngine> {
+        self.create_engine_with(datamodel, |builder| builder.authorization_policy(Some(policy)))
+            .await
+    }
+
+    pub async fn create_read_only_engine(&self, datamodel: &str) -> anyhow::Result<QueryEngine> {
+        self.create_engine_with(datamodel, |builder| builder.read_only(true))
+            .await
+    }
+
+    pub async fn create_engine_with_tenants(
+        &self,
+        datamodel: &str,
+        tenants: Vec<String>,
+    ) -> anyhow::Result<QueryEngine> {
+        self.create_engine_with(datamodel, |builder| builder.tenants(tenants))
+            .await
+    }
+
+    /// Native type attributes are prefixed with the name of the datasource, e.g. `@my_db.Uuid`.
+    pub async fn create_engine_with_native_types(&self, datamodel: &str) -> anyhow::Result<QueryEngine> {
+        let config = self.config.replacen(
+            "default = true",
+            "default = true\n            previewFeatures = [\"nativeTypes\"]",
+            1,
+        );
+
+        self.create_engine_from(&config, datamodel, |builder| builder).await
+    }
+
+    async fn create_engine_with(
+        &self,
+        datamodel: &str,
+        configure: impl FnOnce(ContextBuilder) -> ContextBuilder,
+    ) -> anyhow::Result<QueryEngine> {
+        self.create_engine_from(&self.config, datamodel, configure).await
+    }
+
+    async fn create_engine_from(
+        &self,
+        config: &str,
+        datamodel: &str,
+        configure: impl FnOnce(ContextBuilder) -> ContextBuilder,
+    ) -> anyhow::Result<QueryEngine> {
+        let datamodel_string = format!("{}\n\n{}", config, datamodel);
         let dml = datamodel::parse_datamodel(&datamodel_string).unwrap();
         let config = datamodel::parse_configuration(&datamodel_string).unwrap();
 
@@ -55,8 +179,7 @@ impl TestApi {
             })
             .await?;
 
-        let context = PrismaContext::builder(config, dml)
-            .enable_raw_queries(true)
+        let context = configure(PrismaContext::builder(config, dml).enable_raw_queries(true))
             .build()
             .await
             .unwrap();
@@ -80,6 +203,28 @@ impl TestApi {
     }
 }
 
+impl TestApiFactory for TestApi {
+    fn external_test_api(connector: &'static ExternalConnector, test_name: &'static str) -> TestApiFuture<Self> {
+        Box::pin(async move {
+            let url = connector.url(test_name);
+            let connection_info = ConnectionInfo::from_url(&url).unwrap();
+
+            let migration_connector = match connector.family() {
+                ExternalConnectorFamily::Postgres => postgres_migration_connector(&url).await,
+                ExternalConnectorFamily::Mysql => mysql_migration_connector(&url).await,
+                ExternalConnectorFamily::Mssql => mssql_migration_connector(&url).await,
+                ExternalConnectorFamily::Sqlite => SqlMigrationConnector::new(&url).await.unwrap(),
+            };
+
+            Ok(TestApi {
+                connection_info,
+                migration_api: MigrationApi::new(migration_connector).await.unwrap(),
+                config: connector.test_config(test_name),
+            })
+        })
+    }
+}
+
 pub async fn mysql_8_test_api(db_name: &str) -> TestApi {
     let url = mysql_8_url(db_name);
     let connection_info = ConnectionInfo::from_url(&url).unwrap();
@@ -242,6 +387,34 @@ pub async fn sqlite_test_api(db_name: &str) -> TestApi {
     }
 }
 
+/// A SQLite database living in memory, shared by the migration engine and the query engines of the
+/// test as long as the `TestApi` is alive.
+pub async fn sqlite_in_memory_test_api(db_name: &str) -> TestApi {
+    let url = format!("file:{}?mode=memory&cache=shared&db_name=main_memory", db_name);
+    let connection_info = ConnectionInfo::from_url(&url).unwrap();
+
+    let migration_api = MigrationApi::new(SqlMigrationConnector::new(&url).await.unwrap())
+        .await
+        .unwrap();
+
+    let config = format!(
+        r#"
+        datasource my_db {{
+            provider = "sqlite"
+            url = "{}"
+            default = true
+        }}
+    "#,
+        url
+    );
+
+    TestApi {
+        connection_info,
+        migration_api,
+        config,
+    }
+}
+
 pub async fn mssql_2017_test_api(db_name: &str) -> TestApi {
     let url = mssql_2017_url(db_name);
     let connection_info = ConnectionInfo::from_url(&url).unwrap();
@@ -1,6 +1,7 @@
 use super::super::test_api::QueryEngine;
 use crate::context::PrismaContext;
 use quaint::{prelude::Queryable, single::Quaint};
+use test_setup::connectors::{ExternalConnector, ExternalConnectorFamily, TestApiFactory, TestApiFuture};
 
 pub type TestResult = anyhow::Result<()>;
 
@@ -34,9 +35,10 @@ impl TestApi {
     pub async fn introspect_and_start_query_engine(&self) -> anyhow::Result<(DatamodelAssertions, QueryEngine)> {
         let datasource = self.datasource();
 
-        let introspection_result = introspection_core::RpcImpl::introspect_internal(datasource, false)
-            .await
-            .map_err(|err| anyhow::anyhow!("{:?}", err.data))?;
+        let introspection_result =
+            introspection_core::RpcImpl::introspect_internal(datasource, false, Default::default())
+                .await
+                .map_err(|err| anyhow::anyhow!("{:?}", err.data))?;
 
         let dml = datamodel::parse_datamodel(&introspection_result.datamodel).unwrap();
         let config = datamodel::parse_configuration(&introspection_result.datamodel).unwrap();
@@ -116,6 +118,43 @@ impl<'a> ModelAssertions<'a> {
     }
 }
 
+impl TestApiFactory for TestApi {
+    fn external_test_api(connector: &'static ExternalConnector, test_name: &'static str) -> TestApiFuture<Self> {
+        Box::pin(async move {
+            let url = connector.url(test_name);
+
+            let provider = match connector.family() {
+                ExternalConnectorFamily::Postgres => {
+                    test_setup::create_postgres_database(&url.parse().unwrap())
+                        .await
+                        .unwrap();
+
+                    "postgres"
+                }
+                ExternalConnectorFamily::Mysql => {
+                    test_setup::create_mysql_database(&url.parse().unwrap()).await.unwrap();
+
+                    "mysql"
+                }
+                ExternalConnectorFamily::Mssql => {
+                    test_setup::create_mssql_database(&url).await.unwrap();
+
+                    "sqlserver"
+                }
+                // The type mappings are only tested on the server databases.
+                ExternalConnectorFamily::Sqlite => return Err(connector.unsupported_by("type mappings")),
+            };
+
+            Ok(TestApi {
+                connector_name: connector.name(),
+                connection: Quaint::new(&url).await.unwrap(),
+                database_string: url,
+                provider,
+            })
+        })
+    }
+}
+
 pub async fn mysql_8_test_api(db_name: &str) -> TestApi {
     let mysql_url = test_setup::mysql_8_url(db_name);
 
@@ -0,0 +1,118 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static USERS: &str = indoc! {"
+    model User {
+        id    Int    @id
+        email String @unique
+    }
+"};
+
+async fn engine_with_users(api: &TestApi) -> anyhow::Result<QueryEngine> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&USERS).await?;
+
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, email: "a@prisma.io" }) { id } }"#)
+        .await;
+
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 2, email: "b@prisma.io" }) { id } }"#)
+        .await;
+
+    Ok(query_engine)
+}
+
+fn assert_unique_violation(res: &serde_json::Value) {
+    let error = &res["errors"][0]["user_facing_error"];
+
+    assert_eq!(error["error_code"], json!("P2002"));
+    assert_eq!(error["meta"]["target"], json!(["email"]));
+}
+
+#[test_each_connector]
+async fn creates_are_checked_for_unique_violations(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = engine_with_users(api).await?;
+
+    let res = query_engine
+        .request(r#"mutation { createOneUser(data: { id: 3, email: "a@prisma.io" }) { id } }"#)
+        .await;
+
+    assert_unique_violation(&res);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn updates_are_checked_for_unique_violations_with_other_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = engine_with_users(api).await?;
+
+    let res = query_engine
+        .request(r#"mutation { updateOneUser(where: { id: 2 }, data: { email: "a@prisma.io" }) { id } }"#)
+        .await;
+
+    assert_unique_violation(&res);
+
+    // The updated record keeping its value doesn't conflict with itself.
+    assert_eq!(
+        json!({ "data": { "updateOneUser": { "email": "a@prisma.io" } } }),
+        query_engine
+            .request(r#"mutation { updateOneUser(where: { id: 1 }, data: { email: "a@prisma.io" }) { email } }"#)
+            .await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn upserts_are_checked_for_unique_violations_of_the_executed_branch(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = engine_with_users(api).await?;
+
+    let res = query_engine
+        .request(indoc! {r#"
+            mutation {
+                upsertOneUser(
+                    where: { id: 3 },
+                    create: { id: 3, email: "a@prisma.io" },
+                    update: { email: "c@prisma.io" }
+                ) { id }
+            }
+        "#})
+        .await;
+
+    assert_unique_violation(&res);
+
+    let res = query_engine
+        .request(indoc! {r#"
+            mutation {
+                upsertOneUser(
+                    where: { id: 2 },
+                    create: { id: 2, email: "c@prisma.io" },
+                    update: { email: "a@prisma.io" }
+                ) { id }
+            }
+        "#})
+        .await;
+
+    assert_unique_violation(&res);
+
+    // The create values conflict with the existing record, but only the update runs.
+    assert_eq!(
+        json!({ "data": { "upsertOneUser": { "email": "c@prisma.io" } } }),
+        query_engine
+            .request(indoc! {r#"
+                mutation {
+                    upsertOneUser(
+                        where: { id: 1 },
+                        create: { id: 1, email: "a@prisma.io" },
+                        update: { email: "c@prisma.io" }
+                    ) { email }
+                }
+            "#})
+            .await
+    );
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use super::test_api::*;
+use indoc::indoc;
+use query_core::schema_builder::UnknownEnumValues;
+use serde_json::json;
+use test_macros::test_each_connector_mssql as test_each_connector;
+
+static CAT: &str = indoc! {"
+    model Cat {
+        id    Int   @id
+        color Color
+    }
+
+    enum Color {
+        BLACK
+        WHITE
+    }
+"};
+
+fn execute_raw(query: &str) -> String {
+    format!(
+        r#"mutation {{ executeRaw(query: "{}", parameters: "[]") }}"#,
+        query.replace("\"", "\\\"")
+    )
+}
+
+/// Adds a value to the `Color` enum behind the back of the datamodel, and a cat of that color.
+async fn insert_ginger_cat(query_engine: &QueryEngine) {
+    query_engine
+        .request(execute_raw(r#"ALTER TYPE "Color" ADD VALUE 'GINGER'"#))
+        .await;
+
+    query_engine
+        .request(execute_raw(r#"INSERT INTO "Cat" ("id", "color") VALUES (1, 'GINGER')"#))
+        .await;
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn unknown_enum_values_fail_the_query_by_default(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+
+    let query_engine = api.create_engine(CAT).await?;
+    insert_ginger_cat(&query_engine).await;
+
+    let res = query_engine.request("query { findManyCat { id color } }").await;
+    let error = &res["errors"][0]["user_facing_error"];
+
+    assert_eq!(error["error_code"], json!("P2026"));
+    assert_eq!(
+        error["meta"],
+        json!({ "model_name": "Cat", "field_name": "color", "enum_name": "Color", "value": "GINGER" })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn unknown_enum_values_can_be_returned_as_strings(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+
+    let query_engine = api
+        .create_engine_with_unknown_enum_values(CAT, UnknownEnumValues::RawString)
+        .await?;
+    insert_ginger_cat(&query_engine).await;
+
+    assert_eq!(
+        json!({ "data": { "findManyCat": [{ "id": 1, "color": "GINGER" }] } }),
+        query_engine.request("query { findManyCat { id color } }").await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn unknown_enum_values_can_be_returned_as_null(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+
+    let query_engine = api
+        .create_engine_with_unknown_enum_values(CAT, UnknownEnumValues::Null)
+        .await?;
+    insert_ginger_cat(&query_engine).await;
+
+    assert_eq!(
+        json!({ "data": { "findManyCat": [{ "id": 1, "color": null }] } }),
+        query_engine.request("query { findManyCat { id color } }").await
+    );
+
+    Ok(())
+}
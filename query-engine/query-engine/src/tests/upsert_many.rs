@@ -0,0 +1,115 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static MODELS: &str = indoc! {"
+    model Product {
+        id    Int    @id
+        name  String
+        price Float
+    }
+
+    model Customer {
+        id    Int    @id @default(autoincrement())
+        email String @unique
+        name  String
+    }
+"};
+
+#[test_each_connector]
+async fn upsert_many_inserts_new_records_and_updates_existing_ones(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&MODELS).await?;
+
+    let mutation = r#"mutation { createOneProduct(data: { id: 1, name: "chair", price: 10.5 }) { id } }"#;
+    query_engine.request(mutation).await;
+
+    let mutation = indoc! {r#"
+        mutation {
+            upsertManyProduct(data: [
+                { id: 1, name: "armchair", price: 20.0 },
+                { id: 2, name: "table", price: 30.5 }
+            ]) {
+                count
+            }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "upsertManyProduct": { "count": 2 } } }),
+        query_engine.request(mutation).await
+    );
+
+    let query = "query { findManyProduct(orderBy: { id: asc }) { id name price } }";
+
+    assert_eq!(
+        json!({
+            "data": {
+                "findManyProduct": [
+                    { "id": 1, "name": "armchair", "price": 20.0 },
+                    { "id": 2, "name": "table", "price": 30.5 },
+                ]
+            }
+        }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn upsert_many_conflicts_on_unique_fields_when_ids_are_generated(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&MODELS).await?;
+
+    let mutation = r#"mutation { createOneCustomer(data: { email: "ada@example.com", name: "Ada" }) { id } }"#;
+    query_engine.request(mutation).await;
+
+    let mutation = indoc! {r#"
+        mutation {
+            upsertManyCustomer(data: [
+                { email: "ada@example.com", name: "Ada Lovelace" },
+                { email: "grace@example.com", name: "Grace Hopper" }
+            ]) {
+                count
+            }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "upsertManyCustomer": { "count": 2 } } }),
+        query_engine.request(mutation).await
+    );
+
+    let query = "query { findManyCustomer(orderBy: { email: asc }) { email name } }";
+
+    assert_eq!(
+        json!({
+            "data": {
+                "findManyCustomer": [
+                    { "email": "ada@example.com", "name": "Ada Lovelace" },
+                    { "email": "grace@example.com", "name": "Grace Hopper" },
+                ]
+            }
+        }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn upsert_many_payloads_have_no_ids(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&MODELS).await?;
+
+    let res = query_engine
+        .request(r#"mutation { upsertManyProduct(data: [{ id: 1, name: "chair", price: 10.5 }]) { count ids } }"#)
+        .await;
+
+    let error = res["errors"][0]["error"].as_str().unwrap();
+    assert!(error.contains("ids"), "{}", error);
+
+    Ok(())
+}